@@ -0,0 +1,299 @@
+use std::path::Path;
+
+use crate::backend::c::ctype::CType;
+use crate::backend::c::formatting::string_literal;
+use crate::backend::*;
+use crate::conformance::{echoed_struct, leaf_fields, values, Case, Suite, Value};
+use crate::model::*;
+
+/// Write the conformance tests of the C bindings, run by calling `conformance_tests()`
+pub(crate) fn generate(lib: &Library, suite: &Suite, path: &Path) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::C))?;
+    let prefix = &lib.settings.c_ffi_prefix;
+
+    f.writeln(&format!(
+        "// Conformance tests of the {} C bindings generated by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.newline()?;
+    f.writeln("#include <assert.h>")?;
+    f.writeln("#include <float.h>")?;
+    f.writeln("#include <stdint.h>")?;
+    f.writeln("#include <string.h>")?;
+    f.newline()?;
+    f.writeln(&format!("#include \"{}.h\"", lib.settings.name))?;
+
+    let mut tests = Vec::new();
+    for case in &suite.cases {
+        let name = match case {
+            Case::Echo { method, value_type } => {
+                let name = format!("test_{}", method.name);
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| {
+                    write_echo(f, prefix, &method.function.name, value_type)
+                })?;
+                name
+            }
+            Case::Raise { method, error } => {
+                let name = format!("test_{}", method.name);
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| {
+                    for variant in &error.inner.variants {
+                        f.writeln(&format!(
+                            "assert({}_{}({}) == {}_{}_{});",
+                            prefix,
+                            method.function.name,
+                            variant.value,
+                            prefix.capital_snake_case(),
+                            error.inner.name.capital_snake_case(),
+                            variant.name.capital_snake_case()
+                        ))?;
+                    }
+                    Ok(())
+                })?;
+                name
+            }
+            Case::List {
+                collection,
+                size,
+                get,
+            } => {
+                let name = format!("test_{}", collection.name());
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| {
+                    write_list(f, prefix, collection, &size.function, &get.function)
+                })?;
+                name
+            }
+            Case::Iterate {
+                method,
+                collection,
+                receiver,
+                iterator,
+            } => {
+                let name = format!("test_{}", method.name);
+                let callback = format!("receive_{}", iterator.name());
+                f.newline()?;
+                write_receiver(&mut f, &callback, collection, receiver, iterator)?;
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| {
+                    write_iterate(
+                        f,
+                        prefix,
+                        &method.function.name,
+                        &callback,
+                        collection,
+                        receiver,
+                    )
+                })?;
+                name
+            }
+        };
+        tests.push(name);
+    }
+
+    f.newline()?;
+    f.writeln("void conformance_tests()")?;
+    blocked(&mut f, |f| {
+        for test in &tests {
+            f.writeln(&format!("{}();", test))?;
+        }
+        Ok(())
+    })
+}
+
+fn write_echo(
+    f: &mut dyn Printer,
+    prefix: &Name,
+    function: &Name,
+    value_type: &FunctionArgument,
+) -> FormattingResult<()> {
+    if let Some(handle) = echoed_struct(value_type) {
+        let c_type = format!("{}_{}_t", prefix, handle.name().as_ref());
+        let initializer = handle
+            .get_default_initializer()
+            .ok_or("conformance structs have a default initializer")?;
+        f.writeln(&format!(
+            "{} value = {}_{}_{}();",
+            c_type,
+            prefix,
+            handle.name().as_ref(),
+            initializer.name
+        ))?;
+        f.writeln(&format!(
+            "{} result = {}_{}(value);",
+            c_type, prefix, function
+        ))?;
+        for path in leaf_fields(handle) {
+            let field = path
+                .iter()
+                .map(|x| x.snake_case(f.naming()))
+                .collect::<Vec<_>>()
+                .join(".");
+            f.writeln(&format!("assert(result.{} == value.{});", field, field))?;
+        }
+        return Ok(());
+    }
+
+    for value in values(value_type) {
+        let value = render(value_type, &value, prefix)?;
+        let result = format!("{}_{}({})", prefix, function, value);
+        f.writeln(&format!("assert({});", equals(value_type, &result, &value)))?;
+    }
+    Ok(())
+}
+
+fn write_list(
+    f: &mut dyn Printer,
+    prefix: &Name,
+    collection: &Collection<Validated>,
+    size: &Function<Validated>,
+    get: &Function<Validated>,
+) -> FormattingResult<()> {
+    let values = write_fill(f, prefix, collection)?;
+    f.writeln(&format!(
+        "assert({}_{}(list) == {});",
+        prefix,
+        size.name,
+        values.len()
+    ))?;
+    for (index, value) in values.iter().enumerate() {
+        let result = format!("{}_{}(list, {})", prefix, get.name, index);
+        let value = render(&collection.item_type, value, prefix)?;
+        f.writeln(&format!(
+            "assert({});",
+            equals(&collection.item_type, &result, &value)
+        ))?;
+    }
+    f.writeln(&format!(
+        "{}_{}(list);",
+        prefix, collection.delete_func.name
+    ))
+}
+
+/// Create a `list` holding the values exercising the edges of the items of the collection
+fn write_fill(
+    f: &mut dyn Printer,
+    prefix: &Name,
+    collection: &Collection<Validated>,
+) -> FormattingResult<Vec<Value>> {
+    let values = values(&collection.item_type);
+
+    f.writeln(&format!(
+        "{}_{}_t* list = {}_{}();",
+        prefix,
+        collection.name().as_ref(),
+        prefix,
+        collection.create_func.name
+    ))?;
+    for value in &values {
+        f.writeln(&format!(
+            "{}_{}(list, {});",
+            prefix,
+            collection.add_func.name,
+            render(&collection.item_type, value, prefix)?
+        ))?;
+    }
+    Ok(values)
+}
+
+/// Callback of the receiver checking the values of the iterator and counting its calls in `ctx`
+fn write_receiver(
+    f: &mut dyn Printer,
+    name: &str,
+    collection: &Collection<Validated>,
+    receiver: &InterfaceHandle,
+    iterator: &Handle<AbstractIterator<Validated>>,
+) -> FormattingResult<()> {
+    let prefix = &receiver.settings.c_ffi_prefix;
+    f.writeln(&format!(
+        "static void {}({} values, void* ctx)",
+        name,
+        iterator.to_c_type()
+    ))?;
+    blocked(f, |f| {
+        let next = format!("{}_{}(values)", prefix, iterator.next_function.name);
+        f.writeln(&format!("{} value = NULL;", iterator.item_type.to_c_type()))?;
+        for value in values(&collection.item_type) {
+            f.writeln(&format!("value = {};", next))?;
+            let value = render(&collection.item_type, &value, prefix)?;
+            f.writeln(&format!("assert(value && *value == {});", value))?;
+        }
+        f.writeln(&format!("assert(!{});", next))?;
+        f.writeln("++*(int*)ctx;")
+    })
+}
+
+fn write_iterate(
+    f: &mut dyn Printer,
+    prefix: &Name,
+    function: &Name,
+    callback: &str,
+    collection: &Collection<Validated>,
+    receiver: &InterfaceHandle,
+) -> FormattingResult<()> {
+    write_fill(f, prefix, collection)?;
+    f.writeln("int calls = 0;")?;
+    f.writeln(&format!(
+        "{}_{}(list, {}_{}_init({}, NULL, &calls));",
+        prefix,
+        function,
+        prefix,
+        receiver.name.as_ref(),
+        callback
+    ))?;
+    f.writeln("assert(calls == 1);")?;
+    f.writeln(&format!(
+        "{}_{}(list);",
+        prefix, collection.delete_func.name
+    ))
+}
+
+fn equals(value_type: &FunctionArgument, result: &str, value: &str) -> String {
+    match value_type {
+        FunctionArgument::String(_) => format!("strcmp({}, {}) == 0", result, value),
+        _ => format!("{} == {}", result, value),
+    }
+}
+
+fn render(value_type: &FunctionArgument, value: &Value, prefix: &Name) -> FormattingResult<String> {
+    let rendered = match (value_type, value) {
+        (_, Value::Bool(x)) => x.to_string(),
+        (FunctionArgument::Basic(BasicType::Primitive(x)), value) => primitive(*x, value),
+        (_, Value::Duration(x)) => x.to_string(),
+        (_, Value::Text(x)) => string_literal(x),
+        (FunctionArgument::Basic(BasicType::Enum(x)), Value::Variant(variant)) => format!(
+            "{}_{}_{}",
+            prefix.capital_snake_case(),
+            x.name.capital_snake_case(),
+            variant.capital_snake_case()
+        ),
+        _ => return Err("conformance value does not match its type".into()),
+    };
+    Ok(rendered)
+}
+
+fn primitive(primitive: Primitive, value: &Value) -> String {
+    let (min, max) = match primitive {
+        Primitive::Bool => ("false", "true"),
+        Primitive::U8 => ("0", "UINT8_MAX"),
+        Primitive::S8 => ("INT8_MIN", "INT8_MAX"),
+        Primitive::U16 => ("0", "UINT16_MAX"),
+        Primitive::S16 => ("INT16_MIN", "INT16_MAX"),
+        Primitive::U32 => ("0", "UINT32_MAX"),
+        Primitive::S32 => ("INT32_MIN", "INT32_MAX"),
+        Primitive::U64 => ("0", "UINT64_MAX"),
+        Primitive::S64 => ("INT64_MIN", "INT64_MAX"),
+        Primitive::Float => ("-FLT_MAX", "FLT_MAX"),
+        Primitive::Double => ("-DBL_MAX", "DBL_MAX"),
+    };
+    match value {
+        Value::Min => min.to_string(),
+        Value::Max => max.to_string(),
+        _ => "0".to_string(),
+    }
+}
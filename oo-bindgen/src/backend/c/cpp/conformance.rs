@@ -0,0 +1,309 @@
+use std::path::Path;
+
+use crate::backend::c::cpp::conversion::CoreCppType;
+use crate::backend::c::ctype::CType;
+use crate::backend::c::formatting::string_literal;
+use crate::backend::*;
+use crate::conformance::{echoed_struct, leaf_fields, values, Case, Method, Suite, Value};
+use crate::model::*;
+
+/// Write the conformance tests of the C++ bindings, run by calling `conformance_tests()`
+pub(crate) fn generate(lib: &Library, suite: &Suite, path: &Path) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::Cpp))?;
+    let namespace = &lib.settings.name;
+    let class = format!("{}::{}", namespace, suite.class.name.camel_case(f.naming()));
+
+    f.writeln(&format!(
+        "// Conformance tests of the {} C++ bindings generated by oo-bindgen",
+        namespace
+    ))?;
+    f.newline()?;
+    f.writeln("#include <cassert>")?;
+    f.writeln("#include <chrono>")?;
+    f.writeln("#include <limits>")?;
+    f.writeln("#include <string>")?;
+    f.writeln("#include <vector>")?;
+    f.newline()?;
+    f.writeln(&format!("#include \"{}.hpp\"", namespace))?;
+
+    let mut tests = Vec::new();
+    for case in &suite.cases {
+        let name = match case {
+            Case::Echo { method, value_type } => {
+                let name = format!("test_{}", method.name);
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| {
+                    write_echo(f, namespace, &class, &method.name, value_type)
+                })?;
+                name
+            }
+            Case::Raise { method, error } => {
+                let name = format!("test_{}", method.name);
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| write_raise(f, namespace, &class, method, error))?;
+                name
+            }
+            Case::List {
+                collection,
+                size,
+                get,
+            } => {
+                let name = format!("test_{}", collection.name());
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| {
+                    write_list(f, namespace, &class, &collection.item_type, size, get)
+                })?;
+                name
+            }
+            Case::Iterate {
+                method,
+                collection,
+                receiver,
+                iterator,
+            } => {
+                let name = format!("test_{}", method.name);
+                f.newline()?;
+                f.writeln(&format!("static void {}()", name))?;
+                blocked(&mut f, |f| {
+                    write_iterate(
+                        f,
+                        namespace,
+                        &class,
+                        &method.name,
+                        &collection.item_type,
+                        receiver,
+                        iterator,
+                    )
+                })?;
+                name
+            }
+        };
+        tests.push(name);
+    }
+
+    f.newline()?;
+    f.writeln("void conformance_tests()")?;
+    blocked(&mut f, |f| {
+        for test in &tests {
+            f.writeln(&format!("{}();", test))?;
+        }
+        Ok(())
+    })
+}
+
+fn write_echo(
+    f: &mut dyn Printer,
+    namespace: &Name,
+    class: &str,
+    method: &Name,
+    value_type: &FunctionArgument,
+) -> FormattingResult<()> {
+    if let Some(handle) = echoed_struct(value_type) {
+        f.writeln(&format!(
+            "const {}::{} value;",
+            namespace,
+            handle.core_cpp_type(f.naming())
+        ))?;
+        f.writeln(&format!(
+            "const auto result = {}::{}(value);",
+            class,
+            method.snake_case(f.naming())
+        ))?;
+        for path in leaf_fields(handle) {
+            let field = path
+                .iter()
+                .map(|x| x.snake_case(f.naming()))
+                .collect::<Vec<_>>()
+                .join(".");
+            f.writeln(&format!("assert(result.{} == value.{});", field, field))?;
+        }
+        return Ok(());
+    }
+
+    for value in values(value_type) {
+        let value = render(value_type, &value, namespace, f.naming())?;
+        f.writeln(&format!(
+            "assert({}::{}({}) == {});",
+            class,
+            method.snake_case(f.naming()),
+            value,
+            value
+        ))?;
+    }
+    Ok(())
+}
+
+fn write_raise(
+    f: &mut dyn Printer,
+    namespace: &Name,
+    class: &str,
+    method: &Method,
+    error: &ErrorType<Validated>,
+) -> FormattingResult<()> {
+    let mut variants = error.inner.variants.iter();
+    // the first variant is the absence of error
+    if let Some(ok) = variants.next() {
+        f.writeln(&format!(
+            "{}::{}({});",
+            class,
+            method.name.snake_case(f.naming()),
+            ok.value
+        ))?;
+    }
+    for variant in variants {
+        f.writeln("try")?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "{}::{}({});",
+                class,
+                method.name.snake_case(f.naming()),
+                variant.value
+            ))?;
+            f.writeln("assert(false);")
+        })?;
+        f.writeln(&format!(
+            "catch (const {}::{}& ex)",
+            namespace,
+            error.core_cpp_type(f.naming())
+        ))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "assert(ex.error == {}::{}::{});",
+                namespace,
+                error.inner.core_cpp_type(f.naming()),
+                variant.core_cpp_type(f.naming())
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+fn write_list(
+    f: &mut dyn Printer,
+    namespace: &Name,
+    class: &str,
+    item_type: &FunctionArgument,
+    size: &Method,
+    get: &Method,
+) -> FormattingResult<()> {
+    let values = write_vector(f, namespace, item_type)?;
+    f.writeln(&format!(
+        "assert({}::{}(list) == {});",
+        class,
+        size.name.snake_case(f.naming()),
+        values.len()
+    ))?;
+    for (index, value) in values.iter().enumerate() {
+        f.writeln(&format!(
+            "assert({}::{}(list, {}) == {});",
+            class,
+            get.name.snake_case(f.naming()),
+            index,
+            value
+        ))?;
+    }
+    Ok(())
+}
+
+fn write_iterate(
+    f: &mut dyn Printer,
+    namespace: &Name,
+    class: &str,
+    method: &Name,
+    item_type: &FunctionArgument,
+    receiver: &InterfaceHandle,
+    iterator: &Handle<AbstractIterator<Validated>>,
+) -> FormattingResult<()> {
+    let iterator = iterator.iter_class.name.camel_case(f.naming());
+    write_vector(f, namespace, item_type)?;
+    f.writeln(&format!("std::vector<{}> received;", cpp_type(item_type)))?;
+    f.writeln(&format!(
+        "auto receiver = {}::functional::{}([&]({}::{}& values)",
+        namespace,
+        receiver.name.snake_case(f.naming()),
+        namespace,
+        iterator
+    ))?;
+    blocked_open_close(f, "{", "});", |f| {
+        f.writeln("while (values.next())")?;
+        blocked(f, |f| f.writeln("received.push_back(values.get());"))
+    })?;
+    f.writeln(&format!(
+        "{}::{}(list, receiver);",
+        class,
+        method.snake_case(f.naming())
+    ))?;
+    f.writeln("assert(received == list);")
+}
+
+/// Write a `list` vector holding the values exercising the edges of the item type
+fn write_vector(
+    f: &mut dyn Printer,
+    namespace: &Name,
+    item_type: &FunctionArgument,
+) -> FormattingResult<Vec<String>> {
+    let values = values(item_type)
+        .iter()
+        .map(|x| render(item_type, x, namespace, f.naming()))
+        .collect::<FormattingResult<Vec<_>>>()?;
+
+    f.writeln(&format!(
+        "const std::vector<{}> list = {{",
+        cpp_type(item_type)
+    ))?;
+    indented(f, |f| {
+        for value in &values {
+            f.writeln(&format!("{},", value))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("};")?;
+    Ok(values)
+}
+
+fn cpp_type(item_type: &FunctionArgument) -> String {
+    match item_type {
+        FunctionArgument::Basic(BasicType::Primitive(x)) => x.to_c_type(),
+        _ => "std::string".to_string(),
+    }
+}
+
+fn render(
+    value_type: &FunctionArgument,
+    value: &Value,
+    namespace: &Name,
+    naming: &Naming,
+) -> FormattingResult<String> {
+    let rendered = match (value_type, value) {
+        (_, Value::Bool(x)) => x.to_string(),
+        (FunctionArgument::Basic(BasicType::Primitive(x)), value) => {
+            let limit = match value {
+                Value::Min => "lowest",
+                Value::Max => "max",
+                _ => return Ok("0".to_string()),
+            };
+            format!("std::numeric_limits<{}>::{}()", x.to_c_type(), limit)
+        }
+        (FunctionArgument::Basic(BasicType::Duration(x)), Value::Duration(value)) => {
+            let unit = match x {
+                DurationType::Milliseconds => "milliseconds",
+                DurationType::Seconds => "seconds",
+            };
+            format!("std::chrono::{}({})", unit, value)
+        }
+        (_, Value::Text(x)) => format!("std::string({})", string_literal(x)),
+        (FunctionArgument::Basic(BasicType::Enum(x)), Value::Variant(variant)) => {
+            format!(
+                "{}::{}::{}",
+                namespace,
+                x.core_cpp_type(naming),
+                variant.snake_case(naming)
+            )
+        }
+        _ => return Err("conformance value does not match its type".into()),
+    };
+    Ok(rendered)
+}
@@ -0,0 +1,107 @@
+use crate::backend::*;
+use crate::model::*;
+
+fn number_literal(value: &NumberValue) -> String {
+    match value {
+        NumberValue::U32(x) => format!("{}u", x),
+        NumberValue::U64(x) => format!("{}ull", x),
+        NumberValue::S64(x) => format!("{}ll", x),
+        NumberValue::Float(x) => format!("{:?}f", x),
+        NumberValue::Double(x) => format!("{:?}", x),
+        x => x.to_string(),
+    }
+}
+
+/// true if any constraint in the library requires `<regex>`
+pub(crate) fn uses_regex(lib: &Library) -> bool {
+    fn is_regex(x: &Constraint) -> bool {
+        matches!(x, Constraint::Regex(_))
+    }
+
+    fn struct_uses_regex<T>(x: &Struct<T, Validated>) -> bool
+    where
+        T: StructFieldType,
+    {
+        x.fields
+            .iter()
+            .flat_map(|x| x.constraints.iter())
+            .any(is_regex)
+    }
+
+    let in_functions = lib
+        .functions()
+        .flat_map(|x| x.arguments.iter())
+        .flat_map(|x| x.constraints.iter())
+        .any(is_regex);
+
+    let in_structs = lib.structs().any(|x| match x {
+        StructType::FunctionArg(x) => struct_uses_regex(x),
+        StructType::FunctionReturn(x) => struct_uses_regex(x),
+        StructType::CallbackArg(x) => struct_uses_regex(x),
+        StructType::Universal(x) => struct_uses_regex(x),
+    });
+
+    in_functions || in_structs
+}
+
+/// Write a check that throws `std::invalid_argument` if `value` violates the constraint
+pub(crate) fn write_constraint_check(
+    f: &mut dyn Printer,
+    constraint: &Constraint,
+    target: ConstrainedType,
+    value: &str,
+    name: &str,
+) -> FormattingResult<()> {
+    let throw = |f: &mut dyn Printer| -> FormattingResult<()> {
+        f.writeln(&format!(
+            "throw std::invalid_argument(\"{}\");",
+            constraint.violation_message(name)
+        ))
+    };
+
+    let condition = match constraint {
+        Constraint::Range { .. } => {
+            let conditions: Vec<String> = constraint
+                .lower_bound()
+                .map(|min| format!("{} < {}", value, number_literal(&min)))
+                .into_iter()
+                .chain(
+                    constraint
+                        .upper_bound()
+                        .map(|max| format!("{} > {}", value, number_literal(&max))),
+                )
+                .collect();
+            if conditions.is_empty() {
+                // the range covers every value of the type
+                return Ok(());
+            }
+            conditions.join(" || ")
+        }
+        Constraint::NonZero => match target {
+            // check the value that actually crosses the FFI boundary
+            ConstrainedType::Duration(DurationType::Milliseconds) => {
+                format!("::convert::to_milli_sec_u64({}) == 0", value)
+            }
+            ConstrainedType::Duration(DurationType::Seconds) => {
+                format!("::convert::to_sec_u64({}) == 0", value)
+            }
+            _ => format!("{} == 0", value),
+        },
+        Constraint::NonEmpty => format!("{}.empty()", value),
+        Constraint::MaxLength(x) => format!("{}.size() > {}", value, x),
+        Constraint::Regex(x) => {
+            // the pattern is compiled once on first use
+            return blocked(f, |f| {
+                f.writeln(&format!(
+                    "static const std::regex pattern(\"{}\");",
+                    escape_string_literal(x)
+                ))?;
+                f.writeln(&format!("if(!std::regex_match({}, pattern))", value))?;
+                blocked(f, throw)
+            });
+        }
+    };
+
+    f.writeln(&format!("if({})", condition))?;
+    blocked(f, throw)
+}
@@ -1,11 +1,12 @@
+use crate::backend::c::cpp::formatting::qualified;
 use crate::model::*;
 
 pub(crate) trait CoreCppType {
-    fn core_cpp_type(&self) -> String;
+    fn core_cpp_type(&self, naming: &Naming) -> String;
 }
 
 impl CoreCppType for Primitive {
-    fn core_cpp_type(&self) -> String {
+    fn core_cpp_type(&self, _naming: &Naming) -> String {
         match self {
             Primitive::Bool => "bool".to_string(),
             Primitive::U8 => "uint8_t".to_string(),
@@ -23,17 +24,17 @@ impl CoreCppType for Primitive {
 }
 
 impl CoreCppType for BasicType {
-    fn core_cpp_type(&self) -> String {
+    fn core_cpp_type(&self, naming: &Naming) -> String {
         match self {
-            BasicType::Primitive(x) => x.core_cpp_type(),
+            BasicType::Primitive(x) => x.core_cpp_type(naming),
             BasicType::Duration(_) => "std::chrono::steady_clock::duration".to_string(),
-            BasicType::Enum(x) => x.core_cpp_type(),
+            BasicType::Enum(x) => x.core_cpp_type(naming),
         }
     }
 }
 
 impl CoreCppType for StringType {
-    fn core_cpp_type(&self) -> String {
+    fn core_cpp_type(&self, _naming: &Naming) -> String {
         "std::string".to_string()
     }
 }
@@ -43,8 +44,8 @@ where
     D: DocReference,
     T: StructFieldType,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name().camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(self.module(), self.name().camel_case(naming), naming)
     }
 }
 
@@ -52,14 +53,14 @@ impl<T> CoreCppType for UniversalOr<T>
 where
     T: StructFieldType,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name().camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(self.module(), self.name().camel_case(naming), naming)
     }
 }
 
 impl CoreCppType for StructDeclaration {
-    fn core_cpp_type(&self) -> String {
-        self.name.camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(&self.module, self.name.camel_case(naming), naming)
     }
 }
 
@@ -67,8 +68,8 @@ impl<D> CoreCppType for StructType<D>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.declaration().core_cpp_type()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        self.declaration().core_cpp_type(naming)
     }
 }
 
@@ -76,8 +77,8 @@ impl<D> CoreCppType for Handle<Enum<D>>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name.camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(&self.module, self.name.camel_case(naming), naming)
     }
 }
 
@@ -85,8 +86,8 @@ impl<D> CoreCppType for EnumVariant<D>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name.to_string()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        self.name.snake_case(naming)
     }
 }
 
@@ -94,8 +95,12 @@ impl<D> CoreCppType for ErrorType<D>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.exception_name.camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(
+            &self.inner.module,
+            self.exception_name.camel_case(naming),
+            naming,
+        )
     }
 }
 
@@ -103,8 +108,8 @@ impl<D> CoreCppType for Handle<Interface<D>>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name.camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(&self.module, self.name.camel_case(naming), naming)
     }
 }
 
@@ -112,16 +117,16 @@ impl<D> CoreCppType for Handle<AbstractIterator<D>>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.iter_class.name.camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        self.iter_class.core_cpp_type(naming)
     }
 }
 
 impl CoreCppType for IteratorItemType {
-    fn core_cpp_type(&self) -> String {
+    fn core_cpp_type(&self, naming: &Naming) -> String {
         match self {
-            IteratorItemType::Struct(x) => x.core_cpp_type(),
-            IteratorItemType::Primitive(x) => x.core_cpp_type(),
+            IteratorItemType::Struct(x) => x.core_cpp_type(naming),
+            IteratorItemType::Primitive(x) => x.core_cpp_type(naming),
         }
     }
 }
@@ -131,8 +136,8 @@ where
     T: Clone,
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name.to_string()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        self.name.snake_case(naming)
     }
 }
 
@@ -140,8 +145,8 @@ impl<D> CoreCppType for CallbackFunction<D>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name.to_string()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        self.name.snake_case(naming)
     }
 }
 
@@ -149,14 +154,14 @@ impl<D> CoreCppType for Constant<D>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name.to_string()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        self.name.snake_case(naming)
     }
 }
 
 impl CoreCppType for ClassDeclarationHandle {
-    fn core_cpp_type(&self) -> String {
-        self.name.camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(&self.module, self.name.camel_case(naming), naming)
     }
 }
 
@@ -164,8 +169,8 @@ impl<D> CoreCppType for Handle<Class<D>>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name().camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        self.declaration.core_cpp_type(naming)
     }
 }
 
@@ -173,8 +178,8 @@ impl<D> CoreCppType for Handle<StaticClass<D>>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
-        self.name.camel_case()
+    fn core_cpp_type(&self, naming: &Naming) -> String {
+        qualified(&self.module, self.name.camel_case(naming), naming)
     }
 }
 
@@ -182,15 +187,15 @@ impl<D> CoreCppType for Handle<Collection<D>>
 where
     D: DocReference,
 {
-    fn core_cpp_type(&self) -> String {
+    fn core_cpp_type(&self, naming: &Naming) -> String {
         let inner = match &self.item_type {
-            FunctionArgument::Basic(x) => x.core_cpp_type(),
-            FunctionArgument::String(x) => x.core_cpp_type(),
-            FunctionArgument::Collection(x) => x.core_cpp_type(),
-            FunctionArgument::Struct(x) => x.core_cpp_type(),
-            FunctionArgument::StructRef(x) => x.inner.core_cpp_type(),
-            FunctionArgument::ClassRef(x) => x.core_cpp_type(),
-            FunctionArgument::Interface(x) => x.core_cpp_type(),
+            FunctionArgument::Basic(x) => x.core_cpp_type(naming),
+            FunctionArgument::String(x) => x.core_cpp_type(naming),
+            FunctionArgument::Collection(x) => x.core_cpp_type(naming),
+            FunctionArgument::Struct(x) => x.core_cpp_type(naming),
+            FunctionArgument::StructRef(x) => x.inner.core_cpp_type(naming),
+            FunctionArgument::ClassRef(x) => x.core_cpp_type(naming),
+            FunctionArgument::Interface(x) => x.core_cpp_type(naming),
         };
         format!("std::vector<{}>", inner)
     }
@@ -4,7 +4,7 @@ use crate::backend::c::cpp::conversion::*;
 use crate::backend::c::cpp::formatting::*;
 
 pub(crate) trait CppFunctionArgType {
-    fn get_cpp_function_arg_type(&self) -> String;
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String;
 }
 
 impl<T, D> CppFunctionArgType for Handle<Struct<T, D>>
@@ -12,34 +12,34 @@ where
     D: DocReference,
     T: StructFieldType + TypeInfo,
 {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self.pass_by() {
-            PassBy::Copy => self.core_cpp_type(),
-            PassBy::ConstRef => const_ref(self.core_cpp_type()),
-            PassBy::Move => self.core_cpp_type(),
-            PassBy::MutRef => mut_ref(self.core_cpp_type()),
+            PassBy::Copy => self.core_cpp_type(naming),
+            PassBy::ConstRef => const_ref(self.core_cpp_type(naming)),
+            PassBy::Move => self.core_cpp_type(naming),
+            PassBy::MutRef => mut_ref(self.core_cpp_type(naming)),
         }
     }
 }
 
 impl CppFunctionArgType for StringType {
-    fn get_cpp_function_arg_type(&self) -> String {
-        const_ref(self.core_cpp_type())
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
+        const_ref(self.core_cpp_type(naming))
     }
 }
 
 impl CppFunctionArgType for BasicType {
-    fn get_cpp_function_arg_type(&self) -> String {
-        self.core_cpp_type()
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
+        self.core_cpp_type(naming)
     }
 }
 
 impl CppFunctionArgType for Handle<Interface<Unvalidated>> {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self.mode {
-            InterfaceCategory::Synchronous => mut_ref(self.core_cpp_type()),
-            InterfaceCategory::Asynchronous => unique_ptr(self.core_cpp_type()),
-            InterfaceCategory::Future => unique_ptr(self.core_cpp_type()),
+            InterfaceCategory::Synchronous => mut_ref(self.core_cpp_type(naming)),
+            InterfaceCategory::Asynchronous => unique_ptr(self.core_cpp_type(naming)),
+            InterfaceCategory::Future => unique_ptr(self.core_cpp_type(naming)),
         }
     }
 }
@@ -48,65 +48,65 @@ impl<T> CppFunctionArgType for UniversalOr<T>
 where
     T: StructFieldType + TypeInfo,
 {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self {
-            UniversalOr::Specific(x) => x.get_cpp_function_arg_type(),
-            UniversalOr::Universal(x) => x.get_cpp_function_arg_type(),
+            UniversalOr::Specific(x) => x.get_cpp_function_arg_type(naming),
+            UniversalOr::Universal(x) => x.get_cpp_function_arg_type(naming),
         }
     }
 }
 
 impl CppFunctionArgType for FunctionArgument {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgument::Basic(x) => x.get_cpp_function_arg_type(),
-            FunctionArgument::String(x) => x.get_cpp_function_arg_type(),
-            FunctionArgument::Collection(x) => const_ref(x.core_cpp_type()),
-            FunctionArgument::Struct(x) => x.get_cpp_function_arg_type(),
-            FunctionArgument::StructRef(x) => const_ref(x.inner.core_cpp_type()),
-            FunctionArgument::ClassRef(x) => mut_ref(x.core_cpp_type()),
-            FunctionArgument::Interface(x) => x.get_cpp_function_arg_type(),
+            FunctionArgument::Basic(x) => x.get_cpp_function_arg_type(naming),
+            FunctionArgument::String(x) => x.get_cpp_function_arg_type(naming),
+            FunctionArgument::Collection(x) => const_ref(x.core_cpp_type(naming)),
+            FunctionArgument::Struct(x) => x.get_cpp_function_arg_type(naming),
+            FunctionArgument::StructRef(x) => const_ref(x.inner.core_cpp_type(naming)),
+            FunctionArgument::ClassRef(x) => mut_ref(x.core_cpp_type(naming)),
+            FunctionArgument::Interface(x) => x.get_cpp_function_arg_type(naming),
         }
     }
 }
 
 impl CppFunctionArgType for FunctionArgStructField {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_cpp_function_arg_type(),
-            Self::String(x) => x.get_cpp_function_arg_type(),
-            Self::Interface(x) => x.inner.get_cpp_function_arg_type(),
-            Self::Struct(x) => x.get_cpp_function_arg_type(),
+            Self::Basic(x) => x.get_cpp_function_arg_type(naming),
+            Self::String(x) => x.get_cpp_function_arg_type(naming),
+            Self::Interface(x) => x.inner.get_cpp_function_arg_type(naming),
+            Self::Struct(x) => x.get_cpp_function_arg_type(naming),
         }
     }
 }
 
 impl CppFunctionArgType for FunctionReturnStructField {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_cpp_function_arg_type(),
-            Self::ClassRef(x) => x.core_cpp_type(),
-            Self::Iterator(x) => x.core_cpp_type(),
-            Self::Struct(x) => const_ref(x.core_cpp_type()),
+            Self::Basic(x) => x.get_cpp_function_arg_type(naming),
+            Self::ClassRef(x) => x.core_cpp_type(naming),
+            Self::Iterator(x) => x.core_cpp_type(naming),
+            Self::Struct(x) => const_ref(x.core_cpp_type(naming)),
         }
     }
 }
 
 impl CppFunctionArgType for CallbackArgStructField {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_cpp_function_arg_type(),
-            Self::Iterator(x) => x.core_cpp_type(),
-            Self::Struct(x) => const_ref(x.core_cpp_type()),
+            Self::Basic(x) => x.get_cpp_function_arg_type(naming),
+            Self::Iterator(x) => x.core_cpp_type(naming),
+            Self::Struct(x) => const_ref(x.core_cpp_type(naming)),
         }
     }
 }
 
 impl CppFunctionArgType for UniversalStructField {
-    fn get_cpp_function_arg_type(&self) -> String {
+    fn get_cpp_function_arg_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_cpp_function_arg_type(),
-            Self::Struct(x) => x.get_cpp_function_arg_type(),
+            Self::Basic(x) => x.get_cpp_function_arg_type(naming),
+            Self::Struct(x) => x.get_cpp_function_arg_type(naming),
         }
     }
 }
@@ -11,6 +11,7 @@ pub(crate) use to_native::*;
 pub(crate) use to_native_callback_return_value::*;
 pub(crate) use to_native_function_argument::*;
 pub(crate) use to_native_struct_field::*;
+pub(crate) use to_stream::*;
 pub(crate) use type_info::*;
 
 mod callback_arg_type;
@@ -26,4 +27,5 @@ mod to_native;
 mod to_native_callback_return_value;
 mod to_native_function_argument;
 mod to_native_struct_field;
+mod to_stream;
 mod type_info;
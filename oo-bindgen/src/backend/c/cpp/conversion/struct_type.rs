@@ -4,58 +4,58 @@ use crate::backend::c::cpp::conversion::*;
 use crate::backend::c::cpp::formatting::*;
 
 pub(crate) trait CppStructType {
-    fn struct_member_type(&self) -> String;
+    fn struct_member_type(&self, naming: &Naming) -> String;
 }
 
 impl CppStructType for BasicType {
-    fn struct_member_type(&self) -> String {
-        self.core_cpp_type()
+    fn struct_member_type(&self, naming: &Naming) -> String {
+        self.core_cpp_type(naming)
     }
 }
 
 impl CppStructType for StringType {
-    fn struct_member_type(&self) -> String {
-        self.core_cpp_type()
+    fn struct_member_type(&self, naming: &Naming) -> String {
+        self.core_cpp_type(naming)
     }
 }
 
 impl CppStructType for FunctionArgStructField {
-    fn struct_member_type(&self) -> String {
+    fn struct_member_type(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgStructField::Basic(x) => x.struct_member_type(),
-            FunctionArgStructField::String(x) => x.struct_member_type(),
-            FunctionArgStructField::Interface(x) => unique_ptr(x.inner.core_cpp_type()),
-            FunctionArgStructField::Struct(x) => x.core_cpp_type(),
+            FunctionArgStructField::Basic(x) => x.struct_member_type(naming),
+            FunctionArgStructField::String(x) => x.struct_member_type(naming),
+            FunctionArgStructField::Interface(x) => unique_ptr(x.inner.core_cpp_type(naming)),
+            FunctionArgStructField::Struct(x) => x.core_cpp_type(naming),
         }
     }
 }
 
 impl CppStructType for FunctionReturnStructField {
-    fn struct_member_type(&self) -> String {
+    fn struct_member_type(&self, naming: &Naming) -> String {
         match self {
-            FunctionReturnStructField::Basic(x) => x.struct_member_type(),
-            FunctionReturnStructField::ClassRef(x) => x.core_cpp_type(),
-            FunctionReturnStructField::Iterator(x) => x.core_cpp_type(),
-            FunctionReturnStructField::Struct(x) => x.core_cpp_type(),
+            FunctionReturnStructField::Basic(x) => x.struct_member_type(naming),
+            FunctionReturnStructField::ClassRef(x) => x.core_cpp_type(naming),
+            FunctionReturnStructField::Iterator(x) => x.core_cpp_type(naming),
+            FunctionReturnStructField::Struct(x) => x.core_cpp_type(naming),
         }
     }
 }
 
 impl CppStructType for CallbackArgStructField {
-    fn struct_member_type(&self) -> String {
+    fn struct_member_type(&self, naming: &Naming) -> String {
         match self {
-            CallbackArgStructField::Basic(x) => x.struct_member_type(),
-            CallbackArgStructField::Iterator(x) => mut_ref(x.core_cpp_type()),
-            CallbackArgStructField::Struct(x) => x.core_cpp_type(),
+            CallbackArgStructField::Basic(x) => x.struct_member_type(naming),
+            CallbackArgStructField::Iterator(x) => mut_ref(x.core_cpp_type(naming)),
+            CallbackArgStructField::Struct(x) => x.core_cpp_type(naming),
         }
     }
 }
 
 impl CppStructType for UniversalStructField {
-    fn struct_member_type(&self) -> String {
+    fn struct_member_type(&self, naming: &Naming) -> String {
         match self {
-            UniversalStructField::Basic(x) => x.struct_member_type(),
-            UniversalStructField::Struct(x) => x.core_cpp_type(),
+            UniversalStructField::Basic(x) => x.struct_member_type(naming),
+            UniversalStructField::Struct(x) => x.core_cpp_type(naming),
         }
     }
 }
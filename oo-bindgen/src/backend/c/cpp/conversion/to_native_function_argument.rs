@@ -4,25 +4,25 @@ use crate::backend::c::cpp::conversion::{CoreCppType, ToNative};
 use crate::backend::c::cpp::formatting::FriendClass;
 
 pub(crate) trait ToNativeFunctionArgument {
-    fn to_native_function_argument(&self, expr: String) -> String;
+    fn to_native_function_argument(&self, expr: String, naming: &Naming) -> String;
 
     // some function arguments cannot be converted at the call site
     // and require a shadow parameter. The shadow parameter itself
     // map require some mapping at the call site.
-    fn shadow_parameter_mapping(&self) -> Option<Box<dyn Fn(String) -> String>>;
+    fn shadow_parameter_mapping(&self, naming: &Naming) -> Option<Box<dyn Fn(String) -> String>>;
 
-    fn requires_shadow_parameter(&self) -> bool {
-        self.shadow_parameter_mapping().is_some()
+    fn requires_shadow_parameter(&self, naming: &Naming) -> bool {
+        self.shadow_parameter_mapping(naming).is_some()
     }
 }
 
 impl ToNativeFunctionArgument for FunctionArgument {
-    fn to_native_function_argument(&self, expr: String) -> String {
+    fn to_native_function_argument(&self, expr: String, naming: &Naming) -> String {
         match self {
             FunctionArgument::Basic(x) => x.to_native(expr),
             FunctionArgument::String(x) => x.to_native(expr),
             FunctionArgument::Collection(x) => {
-                format!("{}({})", x.collection_class.core_cpp_type(), expr)
+                format!("{}({})", x.collection_class.core_cpp_type(naming), expr)
             }
             FunctionArgument::Struct(_) => {
                 format!("::convert::to_native({})", expr)
@@ -31,7 +31,7 @@ impl ToNativeFunctionArgument for FunctionArgument {
                 format!("::convert::to_native({})", expr)
             }
             FunctionArgument::ClassRef(x) => {
-                format!("{}::get({})", x.friend_class(), expr)
+                format!("{}::get({})", x.friend_class(naming), expr)
             }
             FunctionArgument::Interface(x) => match x.mode {
                 InterfaceCategory::Synchronous => {
@@ -47,12 +47,12 @@ impl ToNativeFunctionArgument for FunctionArgument {
         }
     }
 
-    fn shadow_parameter_mapping(&self) -> Option<Box<dyn Fn(String) -> String>> {
+    fn shadow_parameter_mapping(&self, naming: &Naming) -> Option<Box<dyn Fn(String) -> String>> {
         match self {
             FunctionArgument::Basic(_) => None,
             FunctionArgument::String(_) => None,
             FunctionArgument::Collection(x) => {
-                let friend_class = x.collection_class.friend_class();
+                let friend_class = x.collection_class.friend_class(naming);
                 Some(Box::new(move |e| format!("{}::get({})", friend_class, e)))
             }
             FunctionArgument::Struct(_) => None,
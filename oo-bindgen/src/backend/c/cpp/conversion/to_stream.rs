@@ -0,0 +1,108 @@
+use crate::model::*;
+
+pub(crate) trait ToCppStream {
+    /// expression that can be inserted into a std::ostream to print the value for debugging
+    fn to_cpp_stream(&self, expr: String) -> String;
+}
+
+impl ToCppStream for Primitive {
+    fn to_cpp_stream(&self, expr: String) -> String {
+        match self {
+            Primitive::Bool => format!("({} ? \"true\" : \"false\")", expr),
+            // 8-bit integers would otherwise be printed as characters
+            Primitive::U8 => format!("static_cast<uint32_t>({})", expr),
+            Primitive::S8 => format!("static_cast<int32_t>({})", expr),
+            Primitive::U16 => expr,
+            Primitive::S16 => expr,
+            Primitive::U32 => expr,
+            Primitive::S32 => expr,
+            Primitive::U64 => expr,
+            Primitive::S64 => expr,
+            Primitive::Float => expr,
+            Primitive::Double => expr,
+        }
+    }
+}
+
+impl ToCppStream for BasicType {
+    fn to_cpp_stream(&self, expr: String) -> String {
+        match self {
+            BasicType::Primitive(x) => x.to_cpp_stream(expr),
+            BasicType::Duration(_) => format!(
+                "std::chrono::duration_cast<std::chrono::milliseconds>({}).count() << \"ms\"",
+                expr
+            ),
+            BasicType::Enum(_) => format!("to_string({})", expr),
+        }
+    }
+}
+
+impl ToCppStream for StringType {
+    fn to_cpp_stream(&self, expr: String) -> String {
+        format!("'\"' << {} << '\"'", expr)
+    }
+}
+
+impl<T, D> ToCppStream for Handle<Struct<T, D>>
+where
+    D: DocReference,
+    T: StructFieldType,
+{
+    fn to_cpp_stream(&self, expr: String) -> String {
+        // nested structs with value semantics have their own operator<<
+        expr
+    }
+}
+
+impl<T> ToCppStream for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn to_cpp_stream(&self, expr: String) -> String {
+        match self {
+            UniversalOr::Specific(x) => x.to_cpp_stream(expr),
+            UniversalOr::Universal(x) => x.to_cpp_stream(expr),
+        }
+    }
+}
+
+impl ToCppStream for FunctionArgStructField {
+    fn to_cpp_stream(&self, expr: String) -> String {
+        match self {
+            FunctionArgStructField::Basic(x) => x.to_cpp_stream(expr),
+            FunctionArgStructField::String(x) => x.to_cpp_stream(expr),
+            FunctionArgStructField::Interface(_) => "\"{interface}\"".to_string(),
+            FunctionArgStructField::Struct(x) => x.to_cpp_stream(expr),
+        }
+    }
+}
+
+impl ToCppStream for FunctionReturnStructField {
+    fn to_cpp_stream(&self, expr: String) -> String {
+        match self {
+            FunctionReturnStructField::Basic(x) => x.to_cpp_stream(expr),
+            FunctionReturnStructField::ClassRef(_) => "\"{class}\"".to_string(),
+            FunctionReturnStructField::Iterator(_) => "\"{iterator}\"".to_string(),
+            FunctionReturnStructField::Struct(x) => x.to_cpp_stream(expr),
+        }
+    }
+}
+
+impl ToCppStream for CallbackArgStructField {
+    fn to_cpp_stream(&self, expr: String) -> String {
+        match self {
+            CallbackArgStructField::Basic(x) => x.to_cpp_stream(expr),
+            CallbackArgStructField::Iterator(_) => "\"{iterator}\"".to_string(),
+            CallbackArgStructField::Struct(x) => x.to_cpp_stream(expr),
+        }
+    }
+}
+
+impl ToCppStream for UniversalStructField {
+    fn to_cpp_stream(&self, expr: String) -> String {
+        match self {
+            UniversalStructField::Basic(x) => x.to_cpp_stream(expr),
+            UniversalStructField::Struct(x) => x.to_cpp_stream(expr),
+        }
+    }
+}
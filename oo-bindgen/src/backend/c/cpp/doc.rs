@@ -5,7 +5,7 @@ use crate::backend::c::cpp::conversion::{CoreCppType, CppFunctionArgType};
 use crate::backend::c::doc::{docstring_print_generic, doxygen_print_generic};
 
 pub(crate) fn print_cpp_doc(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
-    doxygen_print_generic(f, print_cpp_reference, doc)
+    doxygen_print_generic(f, print_cpp_reference, ExampleLanguage::Cpp, doc)
 }
 
 pub(crate) fn print_cpp_docstring(
@@ -67,7 +67,7 @@ fn print_cpp_function_docs(
             print_cpp_return_type_doc(f, &function.return_type)?;
         }
         if let Some(err) = &function.error_type.get() {
-            f.writeln(&format!("@throws {}", err.exception_name.camel_case()))?;
+            f.writeln(&format!("@throws {}", err.core_cpp_type(f.naming())))?;
         }
         Ok(())
     })
@@ -80,7 +80,7 @@ pub(crate) fn print_cpp_argument_doc<T>(
 where
     T: Clone,
 {
-    f.write(&format!("@param {} ", arg.name))?;
+    f.write(&format!("@param {} ", arg.name.snake_case(f.naming())))?;
     print_cpp_doc_string(f, &arg.doc)
 }
 
@@ -118,77 +118,83 @@ fn highlight(expr: String) -> String {
 }
 
 fn print_cpp_reference(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
+    let name = cpp_reference_name(reference, f.naming());
     match reference {
-        Validated::Argument(param_name) => f.write(&format!("@p {}", param_name))?,
-        Validated::Class(class) => {
-            f.write(&format!("@ref {}", class.core_cpp_type()))?;
-        }
+        Validated::Argument(_) => f.write(&format!("@p {}", name)),
+        // explicit links to class methods are broken when they take parameters, but implicit links always work
+        // since we don't allow overloading in the model, we don't need to reference parameters
+        Validated::ClassMethod(..) | Validated::InterfaceMethod(..) => f.write(&name),
+        /*
+           Explicit links to destructors are just plain broken in doxygen v1.9.2
+           It generates correct links however without an explicit @ref or #!
+        */
+        Validated::ClassDestructor(..) => f.write(&name),
+        // explicit links to structs don't always work :(
+        Validated::Struct(_) => f.write(&name),
+        /*
+            Links to enum classes and their variants (explicit and implicit) are hopelessly broken in doxygen v1.9.2
+
+            Just use italic text for now
+        */
+        Validated::Enum(_) | Validated::EnumVariant(..) => f.write(&highlight(name)),
+        Validated::Class(_)
+        | Validated::ClassConstructor(..)
+        | Validated::StructField(..)
+        | Validated::Interface(_) => f.write(&format!("@ref {}", name)),
+    }
+}
+
+/// Name of a referenced item in the C++ API, without any Doxygen markup
+pub(crate) fn cpp_reference_name(reference: &Validated, naming: &Naming) -> String {
+    match reference {
+        Validated::Argument(param_name) => param_name.snake_case(naming),
+        Validated::Class(class) => class.core_cpp_type(naming),
         Validated::ClassMethod(class, method_name, _) => {
-            // explicit links to class methods are broken when they take parameters, but implicit links always work
-            // since we don't allow overloading in the model, we don't need to reference parameters
-            f.write(&format!("{}::{}()", class.core_cpp_type(), method_name))?;
+            format!(
+                "{}::{}()",
+                class.core_cpp_type(naming),
+                method_name.snake_case(naming)
+            )
         }
         Validated::ClassConstructor(class, constructor) => {
-            let cpp_type = class.core_cpp_type();
+            let cpp_type = class.core_cpp_type(naming);
             let args = constructor
                 .function
                 .arguments
                 .iter()
-                .map(|x| x.arg_type.get_cpp_function_arg_type())
+                .map(|x| x.arg_type.get_cpp_function_arg_type(naming))
                 .collect::<Vec<String>>()
                 .join(",");
 
-            f.write(&format!("@ref {}::{}({})", cpp_type, cpp_type, args))?;
+            format!("{}::{}({})", cpp_type, cpp_type, args)
         }
         Validated::ClassDestructor(class, _) => {
-            let cpp_type = class.core_cpp_type();
-
-            /*
-               Explicit links to destructors are just plain broken in doxygen v1.9.2
-               It generates correct links however without an explicit @ref or #!
-            */
-            f.write(&format!("{}::~{}()", cpp_type, cpp_type))?;
-        }
-        Validated::Struct(st) => {
-            // explicit links to structs don't always work :(
-            f.write(&st.core_cpp_type())?;
+            let cpp_type = class.core_cpp_type(naming);
+            format!("{}::~{}()", cpp_type, cpp_type)
         }
+        Validated::Struct(st) => st.core_cpp_type(naming),
         Validated::StructField(st, field_name) => {
-            f.write(&format!("@ref {}.{}", st.core_cpp_type(), field_name))?;
-        }
-        Validated::Enum(handle) => {
-            /*
-                Links to enum classes (explicit and implicit) are hopelessly broken in doxygen v1.9.2
-
-                Just use italic text for now
-            */
-            f.write(&highlight(handle.core_cpp_type()))?;
+            format!(
+                "{}.{}",
+                st.core_cpp_type(naming),
+                field_name.snake_case(naming)
+            )
         }
+        Validated::Enum(handle) => handle.core_cpp_type(naming),
         Validated::EnumVariant(handle, variant_name) => {
-            /*
-                Links to enum variants (explicit and implicit) are hopelessly broken in doxygen v1.9.2
-
-                Just use italic text for now
-            */
-            f.write(&highlight(format!(
+            format!(
                 "{}::{}",
-                handle.core_cpp_type(),
-                variant_name
-            )))?;
-        }
-        Validated::Interface(interface) => {
-            f.write(&format!("@ref {}", interface.core_cpp_type()))?;
+                handle.core_cpp_type(naming),
+                variant_name.snake_case(naming)
+            )
         }
+        Validated::Interface(interface) => interface.core_cpp_type(naming),
         Validated::InterfaceMethod(interface, callback_name) => {
-            // explicit links to class methods are broken when they take parameters, but implicit links always work
-            // since we don't allow overloading in the model, we don't need to reference parameters
-            f.write(&format!(
+            format!(
                 "{}::{}()",
-                interface.core_cpp_type(),
-                callback_name
-            ))?;
+                interface.core_cpp_type(naming),
+                callback_name.snake_case(naming)
+            )
         }
     }
-
-    Ok(())
 }
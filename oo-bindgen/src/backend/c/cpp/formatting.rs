@@ -1,3 +1,5 @@
+use crate::backend::c::cpp::doc::cpp_reference_name;
+use crate::backend::c::formatting::string_literal;
 use crate::backend::*;
 use crate::model::*;
 
@@ -28,19 +30,90 @@ where
     Ok(())
 }
 
+/// Write the items of a module in its namespaces, nested in the one of the library
+///
+/// The names of the types written by the callback are qualified relative to the module.
+pub(crate) fn module_namespace<F>(
+    f: &mut dyn Printer,
+    module: &Module,
+    cb: F,
+) -> FormattingResult<()>
+where
+    F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+{
+    fn nested<F>(f: &mut dyn Printer, names: &[Name], cb: F) -> FormattingResult<()>
+    where
+        F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+    {
+        match names.split_first() {
+            None => cb(f),
+            Some((first, rest)) => {
+                let first = first.snake_case(f.naming());
+                namespace(f, &first, |f| nested(f, rest, cb))?;
+                f.newline()
+            }
+        }
+    }
+
+    let naming = f.naming().within(module);
+    nested(&mut ModulePrinter { inner: f, naming }, module.path(), cb)
+}
+
+/// Printer of the items of a module, which spells their names relative to it
+struct ModulePrinter<'a> {
+    inner: &'a mut dyn Printer,
+    naming: Naming,
+}
+
+impl<'a> Printer for ModulePrinter<'a> {
+    fn write(&mut self, s: &str) -> FormattingResult<()> {
+        self.inner.write(s)
+    }
+
+    fn newline(&mut self) -> FormattingResult<()> {
+        self.inner.newline()
+    }
+
+    fn naming(&self) -> &Naming {
+        &self.naming
+    }
+}
+
+/// Name of a type as written in the namespace of the module of the naming
+///
+/// The name is qualified by the modules that are not shared with the current one. The names of the
+/// modules are unique in the library, so the lookup from any namespace of the library finds them.
+pub(crate) fn qualified(module: &Module, name: String, naming: &Naming) -> String {
+    let mut names: Vec<String> = module
+        .relative_to(naming.module())
+        .iter()
+        .map(|x| x.snake_case(naming))
+        .collect();
+    names.push(name);
+    names.join("::")
+}
+
 pub(crate) trait FriendClass {
-    fn friend_class(&self) -> String;
+    fn friend_class(&self, naming: &Naming) -> String;
 }
 
 impl FriendClass for ClassDeclarationHandle {
-    fn friend_class(&self) -> String {
-        format!("Cpp{}Friend", self.name.camel_case())
+    fn friend_class(&self, naming: &Naming) -> String {
+        qualified(
+            &self.module,
+            format!("Cpp{}Friend", self.name.camel_case(naming)),
+            naming,
+        )
     }
 }
 
 impl FriendClass for StructDeclarationHandle {
-    fn friend_class(&self) -> String {
-        format!("Cpp{}Friend", self.name.camel_case())
+    fn friend_class(&self, naming: &Naming) -> String {
+        qualified(
+            &self.module,
+            format!("Cpp{}Friend", self.name.camel_case(naming)),
+            naming,
+        )
     }
 }
 
@@ -48,8 +121,8 @@ impl<D> FriendClass for Handle<AbstractIterator<D>>
 where
     D: DocReference,
 {
-    fn friend_class(&self) -> String {
-        self.iter_class.friend_class()
+    fn friend_class(&self, naming: &Naming) -> String {
+        self.iter_class.friend_class(naming)
     }
 }
 
@@ -58,8 +131,8 @@ where
     D: DocReference,
     T: StructFieldType,
 {
-    fn friend_class(&self) -> String {
-        self.declaration.inner.friend_class()
+    fn friend_class(&self, naming: &Naming) -> String {
+        self.declaration.inner.friend_class(naming)
     }
 }
 
@@ -67,7 +140,18 @@ impl<D> FriendClass for Handle<Class<D>>
 where
     D: DocReference,
 {
-    fn friend_class(&self) -> String {
-        self.declaration.friend_class()
+    fn friend_class(&self, naming: &Naming) -> String {
+        self.declaration.friend_class(naming)
+    }
+}
+
+/// Standard attribute of a deprecated item, followed by a space, or nothing if the item isn't deprecated
+pub(crate) fn deprecated_attribute(doc: &Doc<Validated>, naming: &Naming) -> String {
+    match &doc.deprecated {
+        Some(x) => format!(
+            "[[deprecated({})]] ",
+            string_literal(&x.message(&|reference| cpp_reference_name(reference, naming)))
+        ),
+        None => String::new(),
     }
 }
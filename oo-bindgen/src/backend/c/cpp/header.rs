@@ -2,6 +2,7 @@ use std::path::Path;
 
 use crate::backend::c::cpp::conversion::*;
 use crate::backend::c::cpp::doc::*;
+use crate::backend::c::cpp::implementation::get_default_value;
 use crate::backend::*;
 use crate::model::*;
 
@@ -12,7 +13,7 @@ pub(crate) fn generate_header(lib: &Library, path: &Path) -> FormattingResult<()
     // Open the file
     logged::create_dir_all(&path)?;
     let filename = path.join(format!("{}.hpp", lib.settings.name));
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::Cpp))?;
 
     print_license(&mut f, lib)?;
 
@@ -24,6 +25,9 @@ pub(crate) fn generate_header(lib: &Library, path: &Path) -> FormattingResult<()
     f.writeln("#include <chrono>")?;
     f.writeln("#include <memory>")?;
     f.writeln("#include <vector>")?;
+    if lib.structs().any(|x| x.has_value_semantics()) {
+        f.writeln("#include <ostream>")?;
+    }
     f.newline()?;
 
     doxygen(&mut f, |f| {
@@ -59,8 +63,11 @@ pub(crate) fn generate_header(lib: &Library, path: &Path) -> FormattingResult<()
         "/// main namespace for the {} library",
         lib.settings.name
     ))?;
-    namespace(&mut f, &lib.settings.c_ffi_prefix, |f| {
-        print_header_namespace_contents(lib, f)
+    let prefix = lib.settings.c_ffi_prefix.to_string();
+    namespace(&mut f, &prefix, |f| {
+        allow_deprecated(f, lib.has_deprecated_items(), |f| {
+            print_header_namespace_contents(lib, f)
+        })
     })?;
 
     Ok(())
@@ -72,51 +79,82 @@ fn print_header_namespace_contents(lib: &Library, f: &mut dyn Printer) -> Format
 
     let mut documented_functional_ns = false;
 
-    for statement in lib.statements() {
-        match &statement {
-            Statement::Constants(x) => print_constants(f, x)?,
-            Statement::EnumDefinition(x) => print_enum(f, x)?,
-            Statement::ErrorType(x) => print_exception(f, x)?,
-            Statement::StructDeclaration(x) => print_struct_decl(f, x)?,
-            Statement::StructDefinition(x) => match x {
-                StructType::FunctionArg(x) => print_struct_definition(f, x)?,
-                StructType::FunctionReturn(x) => print_struct_definition(f, x)?,
-                StructType::CallbackArg(x) => print_struct_definition(f, x)?,
-                StructType::Universal(x) => print_struct_definition(f, x)?,
-            },
-            Statement::InterfaceDefinition(x) => {
-                print_interface(f, x.untyped())?;
-
-                if let Some(callback) = x.untyped().get_functional_callback() {
-                    if !documented_functional_ns {
-                        documented_functional_ns = true;
-                        f.writeln("/// helpers functions to create interface implementations using lambdas")?;
-                    }
-                    namespace(f, "functional", |f| {
-                        write_functional_interface_helpers(f, x.untyped(), callback)
-                    })?;
-                    f.newline()?;
-                }
+    for (module, statements) in module_runs(lib) {
+        module_namespace(f, module, |f| {
+            for statement in statements {
+                print_statement(f, statement, &mut documented_functional_ns)?;
             }
-            Statement::ClassDeclaration(x) => {
-                match x.class_type {
-                    ClassType::Normal => print_class_decl(f, x)?,
-                    ClassType::Iterator => print_class_decl(f, x)?,
-                    // collections are mapped to Vec<T> in C++ and therefore
-                    // have no opaque declaration in the header
-                    ClassType::Collection => {}
-                }
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Consecutive statements of the same module, without the native functions that C++ does not use
+fn module_runs(lib: &Library) -> Vec<(&Module, Vec<&Statement<Validated>>)> {
+    let mut runs: Vec<(&Module, Vec<&Statement<Validated>>)> = Vec::new();
+    for statement in lib.statements().filter(|x| x.is_type()) {
+        match runs.last_mut() {
+            Some((module, statements)) if *module == statement.module() => {
+                statements.push(statement)
             }
-            Statement::ClassDefinition(x) => print_class_definition(f, x)?,
-            Statement::StaticClassDefinition(x) => print_static_class(f, x)?,
-            Statement::IteratorDeclaration(x) => print_iterator_definition(f, x)?,
-            Statement::CollectionDeclaration(_) => {
-                // collections are just vectors in C++
+            _ => runs.push((statement.module(), vec![statement])),
+        }
+    }
+    runs
+}
+
+fn print_statement(
+    f: &mut dyn Printer,
+    statement: &Statement<Validated>,
+    documented_functional_ns: &mut bool,
+) -> FormattingResult<()> {
+    match statement {
+        Statement::Constants(x) => print_constants(f, x)?,
+        Statement::EnumDefinition(x) => print_enum(f, x)?,
+        Statement::ErrorType(x) => print_exception(f, x)?,
+        Statement::StructDeclaration(x) => print_struct_decl(f, x)?,
+        Statement::StructDefinition(x) => match x {
+            StructType::FunctionArg(x) => print_struct_definition(f, x)?,
+            StructType::FunctionReturn(x) => print_struct_definition(f, x)?,
+            StructType::CallbackArg(x) => print_struct_definition(f, x)?,
+            StructType::Universal(x) => print_struct_definition(f, x)?,
+        },
+        Statement::InterfaceDefinition(x) => {
+            print_interface(f, x.untyped())?;
+
+            if let Some(callback) = x.untyped().get_functional_callback() {
+                if !*documented_functional_ns {
+                    *documented_functional_ns = true;
+                    f.writeln(
+                        "/// helpers functions to create interface implementations using lambdas",
+                    )?;
+                }
+                namespace(f, "functional", |f| {
+                    write_functional_interface_helpers(f, x.untyped(), callback)
+                })?;
+                f.newline()?;
             }
-            Statement::FunctionDefinition(_) => {
-                // not used in C++
+        }
+        Statement::ClassDeclaration(x) => {
+            match x.class_type {
+                ClassType::Normal => print_class_decl(f, x)?,
+                ClassType::Iterator => print_class_decl(f, x)?,
+                // collections are mapped to Vec<T> in C++ and therefore
+                // have no opaque declaration in the header
+                ClassType::Collection => {}
             }
         }
+        Statement::ClassDefinition(x) => print_class_definition(f, x)?,
+        Statement::StaticClassDefinition(x) => print_static_class(f, x)?,
+        Statement::IteratorDeclaration(x) => print_iterator_definition(f, x)?,
+        Statement::CollectionDeclaration(_) => {
+            // collections are just vectors in C++
+        }
+        Statement::FunctionDefinition(_) => {
+            // not used in C++
+        }
     }
 
     Ok(())
@@ -127,7 +165,7 @@ fn write_functional_interface_helpers(
     interface: &Handle<Interface<Validated>>,
     callback: &CallbackFunction<Validated>,
 ) -> FormattingResult<()> {
-    let interface_name = interface.core_cpp_type();
+    let interface_name = interface.core_cpp_type(f.naming());
 
     doxygen(f, |f| {
         f.writeln(&format!(
@@ -136,7 +174,7 @@ fn write_functional_interface_helpers(
         ))?;
         f.writeln(&format!(
             "@note this class can only be constructed using @ref {}() helper function",
-            interface.name
+            interface.name.snake_case(f.naming())
         ))?;
         Ok(())
     })?;
@@ -166,23 +204,33 @@ fn write_functional_interface_helpers(
         ))?;
         f.newline()?;
 
-        let return_type = callback.return_type.get_cpp_callback_return_type();
+        let return_type = callback
+            .return_type
+            .get_cpp_callback_return_type(f.naming());
         let args = callback
             .arguments
             .iter()
-            .map(|x| format!("{} {}", x.arg_type.get_cpp_callback_arg_type(), x.name))
+            .map(|x| {
+                format!(
+                    "{} {}",
+                    x.arg_type.get_cpp_callback_arg_type(f.naming()),
+                    x.name.snake_case(f.naming())
+                )
+            })
             .collect::<Vec<String>>()
             .join(", ");
 
         f.writeln("/// @brief implement virtual method from base class")?;
         f.writeln(&format!(
             "{} {}({}) override",
-            return_type, callback.name, args
+            return_type,
+            callback.name.snake_case(f.naming()),
+            args
         ))?;
         let args = callback
             .arguments
             .iter()
-            .map(|x| x.name.to_string())
+            .map(|x| x.name.snake_case(f.naming()))
             .collect::<Vec<String>>()
             .join(", ");
         let invocation = &format!("lambda({});", args);
@@ -232,7 +280,8 @@ fn write_functional_interface_helpers(
     f.writeln("template <class T>")?;
     f.writeln(&format!(
         "{} {}(const T& lambda)",
-        return_type, interface.name
+        return_type,
+        interface.name.snake_case(f.naming())
     ))?;
     blocked(f, |f| f.writeln(&format!("return {}; ", return_expr)))?;
 
@@ -246,16 +295,16 @@ fn print_iterator_definition(
     let iterator = include_str!("snippet/iterator.hpp");
     for line in iterator.lines() {
         let substituted = line
-            .replace("<name>", &iter.core_cpp_type())
-            .replace("<snake_name>", &iter.core_cpp_type())
-            .replace("<iter_type>", &iter.item_type.core_cpp_type());
+            .replace("<name>", &iter.core_cpp_type(f.naming()))
+            .replace("<snake_name>", &iter.core_cpp_type(f.naming()))
+            .replace("<iter_type>", &iter.item_type.core_cpp_type(f.naming()));
         f.writeln(&substituted)?;
     }
     f.newline()
 }
 
 fn print_class_decl(f: &mut dyn Printer, handle: &ClassDeclarationHandle) -> FormattingResult<()> {
-    f.writeln(&format!("class {};", handle.core_cpp_type()))?;
+    f.writeln(&format!("class {};", handle.core_cpp_type(f.naming())))?;
     f.newline()
 }
 
@@ -305,14 +354,14 @@ fn print_constants(
     }
 
     print_commented_cpp_doc(f, &set.doc)?;
-    f.writeln(&format!("namespace {} {{", set.name))?;
+    f.writeln(&format!("namespace {} {{", set.name.snake_case(f.naming())))?;
     indented(f, |f| {
         for value in &set.values {
             print_commented_cpp_doc(f, &value.doc)?;
             f.writeln(&format!(
                 "constexpr {} {} = {};",
                 get_type(value.value),
-                value.core_cpp_type(),
+                value.core_cpp_type(f.naming()),
                 get_value(value.value)
             ))?;
         }
@@ -324,11 +373,20 @@ fn print_constants(
 
 fn print_enum(f: &mut dyn Printer, e: &Handle<Enum<Validated>>) -> FormattingResult<()> {
     print_commented_cpp_doc(f, &e.doc)?;
-    f.writeln(&format!("enum class {} {{", e.core_cpp_type()))?;
+    f.writeln(&format!("enum class {} {{", e.core_cpp_type(f.naming())))?;
     indented(f, |f| {
         for v in &e.variants {
             print_commented_cpp_doc(f, &v.doc)?;
-            f.writeln(&format!("{} = {},", v.core_cpp_type(), v.value))?;
+            match &v.doc.deprecated {
+                // the attribute of an enumerator goes after its name
+                Some(_) => f.writeln(&format!(
+                    "{} {}= {},",
+                    v.core_cpp_type(f.naming()),
+                    deprecated_attribute(&v.doc, f.naming()),
+                    v.value
+                ))?,
+                None => f.writeln(&format!("{} = {},", v.core_cpp_type(f.naming()), v.value))?,
+            }
         }
         Ok(())
     })?;
@@ -337,14 +395,14 @@ fn print_enum(f: &mut dyn Printer, e: &Handle<Enum<Validated>>) -> FormattingRes
     doxygen(f, |f| {
         f.writeln(&format!(
             "@brief convert an instance of enum {} into a C-style string",
-            e.core_cpp_type()
+            e.core_cpp_type(f.naming())
         ))?;
         f.writeln("@param value enum value")?;
         f.writeln("@return C-style string constant")
     })?;
     f.writeln(&format!(
         "const char* to_string({} value);",
-        e.core_cpp_type()
+        e.core_cpp_type(f.naming())
     ))?;
     f.newline()
 }
@@ -352,21 +410,21 @@ fn print_enum(f: &mut dyn Printer, e: &Handle<Enum<Validated>>) -> FormattingRes
 fn print_exception(f: &mut dyn Printer, e: &ErrorType<Validated>) -> FormattingResult<()> {
     f.writeln(&format!(
         "/// @brief Exception type corresponding to the underlying error enum #{}",
-        e.inner.core_cpp_type()
+        e.inner.core_cpp_type(f.naming())
     ))?;
     f.writeln(&format!(
         "class {} : public std::logic_error {{",
-        e.core_cpp_type()
+        e.core_cpp_type(f.naming())
     ))?;
     f.writeln("public:")?;
     indented(f, |f| {
         f.writeln("/// @brief underlying error enum")?;
-        f.writeln(&format!("{} error;", e.inner.core_cpp_type()))?;
+        f.writeln(&format!("{} error;", e.inner.core_cpp_type(f.naming())))?;
         f.writeln("/// @brief construct the exception with an instance of the enum")?;
         f.writeln(&format!(
             "{}({} error) : std::logic_error(to_string(error)), error(error) {{}}",
-            e.core_cpp_type(),
-            e.inner.core_cpp_type()
+            e.core_cpp_type(f.naming()),
+            e.inner.core_cpp_type(f.naming())
         ))?;
         Ok(())
     })?;
@@ -375,7 +433,7 @@ fn print_exception(f: &mut dyn Printer, e: &ErrorType<Validated>) -> FormattingR
 }
 
 fn print_struct_decl(f: &mut dyn Printer, s: &StructDeclaration) -> FormattingResult<()> {
-    f.writeln(&format!("struct {};", s.core_cpp_type()))?;
+    f.writeln(&format!("struct {};", s.core_cpp_type(f.naming())))?;
     f.newline()
 }
 
@@ -387,10 +445,13 @@ where
     T: StructFieldType + CppStructType + CppFunctionArgType,
 {
     print_commented_cpp_doc(f, &handle.doc)?;
-    f.writeln(&format!("struct {} {{", handle.core_cpp_type()))?;
+    f.writeln(&format!("struct {} {{", handle.core_cpp_type(f.naming())))?;
 
     indented(f, |f| {
-        f.writeln(&format!("friend class {};", handle.friend_class()))?;
+        f.writeln(&format!(
+            "friend class {};",
+            handle.friend_class(f.naming())
+        ))?;
         f.newline()
     })?;
 
@@ -419,7 +480,7 @@ where
     indented(f, |f| {
         // delete the default constructor unless the struct has one
         if !handle.has_default_initializer() {
-            f.writeln(&format!("{}() = delete;", handle.core_cpp_type()))?;
+            f.writeln(&format!("{}() = delete;", handle.core_cpp_type(f.naming())))?;
         }
 
         // write the constructors
@@ -432,9 +493,10 @@ where
         for field in &handle.fields {
             print_commented_cpp_doc(f, &field.doc)?;
             f.writeln(&format!(
-                "{} {};",
-                field.field_type.struct_member_type(),
-                field.name
+                "{}{} {};",
+                deprecated_attribute(&field.doc, f.naming()),
+                field.field_type.struct_member_type(f.naming()),
+                field.name.snake_case(f.naming())
             ))?;
         }
 
@@ -442,15 +504,76 @@ where
     })?;
 
     f.writeln("};")?;
+    f.newline()?;
+
+    if handle.has_value_semantics() {
+        print_struct_value_semantics(f, handle)?;
+    }
+
+    Ok(())
+}
+
+fn print_struct_value_semantics<T>(
+    f: &mut dyn Printer,
+    handle: &Handle<Struct<T, Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType,
+{
+    let struct_name = handle.core_cpp_type(f.naming());
+
+    doxygen(f, |f| {
+        f.writeln(&format!(
+            "@brief compare two instances of @ref {} field by field",
+            struct_name
+        ))?;
+        f.writeln("@param lhs left hand side")?;
+        f.writeln("@param rhs right hand side")?;
+        f.writeln("@return true if all the fields are equal")
+    })?;
+    f.writeln(&format!(
+        "bool operator==(const {}& lhs, const {}& rhs);",
+        struct_name, struct_name
+    ))?;
+    f.newline()?;
+
+    doxygen(f, |f| {
+        f.writeln(&format!(
+            "@brief compare two instances of @ref {} field by field",
+            struct_name
+        ))?;
+        f.writeln("@param lhs left hand side")?;
+        f.writeln("@param rhs right hand side")?;
+        f.writeln("@return true if any of the fields differ")
+    })?;
+    f.writeln(&format!(
+        "bool operator!=(const {}& lhs, const {}& rhs);",
+        struct_name, struct_name
+    ))?;
+    f.newline()?;
+
+    doxygen(f, |f| {
+        f.writeln(&format!(
+            "@brief write a representation of @ref {} to a stream for debugging",
+            struct_name
+        ))?;
+        f.writeln("@param os output stream")?;
+        f.writeln("@param value value to print")?;
+        f.writeln("@return the output stream")
+    })?;
+    f.writeln(&format!(
+        "std::ostream& operator<<(std::ostream& os, const {}& value);",
+        struct_name
+    ))?;
     f.newline()
 }
 
 trait ToConstantCpp {
-    fn to_constant_cpp(&self) -> String;
+    fn to_constant_cpp(&self, naming: &Naming) -> String;
 }
 
 impl ToConstantCpp for PrimitiveValue {
-    fn to_constant_cpp(&self) -> String {
+    fn to_constant_cpp(&self, _naming: &Naming) -> String {
         match self {
             PrimitiveValue::Bool(x) => x.to_string(),
             PrimitiveValue::U8(x) => x.to_string(),
@@ -468,7 +591,7 @@ impl ToConstantCpp for PrimitiveValue {
 }
 
 impl ToConstantCpp for DurationValue {
-    fn to_constant_cpp(&self) -> String {
+    fn to_constant_cpp(&self, _naming: &Naming) -> String {
         match self {
             DurationValue::Milliseconds(x) => format!("std::chrono::milliseconds({})", x),
             DurationValue::Seconds(x) => format!("std::chrono::Duration::seconds({})", x),
@@ -477,53 +600,57 @@ impl ToConstantCpp for DurationValue {
 }
 
 impl ToConstantCpp for EnumValue {
-    fn to_constant_cpp(&self) -> String {
-        format!("{}::{}", self.handle.core_cpp_type(), self.variant.name)
+    fn to_constant_cpp(&self, naming: &Naming) -> String {
+        format!(
+            "{}::{}",
+            self.handle.core_cpp_type(naming),
+            self.variant.core_cpp_type(naming)
+        )
     }
 }
 
 impl ToConstantCpp for BasicValue {
-    fn to_constant_cpp(&self) -> String {
+    fn to_constant_cpp(&self, naming: &Naming) -> String {
         match self {
-            BasicValue::Primitive(x) => x.to_constant_cpp(),
-            BasicValue::Duration(x) => x.to_constant_cpp(),
-            BasicValue::Enum(x) => x.to_constant_cpp(),
+            BasicValue::Primitive(x) => x.to_constant_cpp(naming),
+            BasicValue::Duration(x) => x.to_constant_cpp(naming),
+            BasicValue::Enum(x) => x.to_constant_cpp(naming),
         }
     }
 }
 
 impl ToConstantCpp for ZeroParameterStructInitializer {
-    fn to_constant_cpp(&self) -> String {
+    fn to_constant_cpp(&self, naming: &Naming) -> String {
         match self.initializer.initializer_type {
-            InitializerType::Normal => format!("{}()", self.handle.core_cpp_type()),
+            InitializerType::Normal => format!("{}()", self.handle.core_cpp_type(naming)),
             InitializerType::Static => format!(
                 "{}::{}()",
-                self.handle.core_cpp_type(),
-                self.initializer.name
+                self.handle.core_cpp_type(naming),
+                self.initializer.name.snake_case(naming)
             ),
         }
     }
 }
 
 trait TryToConstantCpp {
-    fn try_to_constant_cpp(&self) -> Option<String>;
+    fn try_to_constant_cpp(&self, naming: &Naming) -> Option<String>;
 }
 
 impl<T> TryToConstantCpp for T
 where
     T: ToConstantCpp,
 {
-    fn try_to_constant_cpp(&self) -> Option<String> {
-        Some(self.to_constant_cpp())
+    fn try_to_constant_cpp(&self, naming: &Naming) -> Option<String> {
+        Some(self.to_constant_cpp(naming))
     }
 }
 
 impl TryToConstantCpp for DefaultCallbackReturnValue {
-    fn try_to_constant_cpp(&self) -> Option<String> {
+    fn try_to_constant_cpp(&self, naming: &Naming) -> Option<String> {
         match self {
             DefaultCallbackReturnValue::Void => None,
-            DefaultCallbackReturnValue::Basic(x) => x.try_to_constant_cpp(),
-            DefaultCallbackReturnValue::InitializedStruct(x) => x.try_to_constant_cpp(),
+            DefaultCallbackReturnValue::Basic(x) => x.try_to_constant_cpp(naming),
+            DefaultCallbackReturnValue::InitializedStruct(x) => x.try_to_constant_cpp(naming),
         }
     }
 }
@@ -537,10 +664,13 @@ fn print_interface(
         f.newline()?;
         f.writeln("@note this class is an \"interface\" and only has virtual methods, some of which may have default implementations.")
     })?;
-    f.writeln(&format!("class {} {{", handle.core_cpp_type()))?;
+    f.writeln(&format!("class {} {{", handle.core_cpp_type(f.naming())))?;
     f.writeln("public:")?;
     indented(f, |f| {
-        f.writeln(&format!("virtual ~{}() = default;", handle.core_cpp_type()))?;
+        f.writeln(&format!(
+            "virtual ~{}() = default;",
+            handle.core_cpp_type(f.naming())
+        ))?;
         f.newline()?;
         for cb in &handle.callbacks {
             let args: String = cb
@@ -549,8 +679,8 @@ fn print_interface(
                 .map(|arg| {
                     format!(
                         "{} {}",
-                        arg.arg_type.get_cpp_callback_arg_type(),
-                        arg.core_cpp_type()
+                        arg.arg_type.get_cpp_callback_arg_type(f.naming()),
+                        arg.core_cpp_type(f.naming())
                     )
                 })
                 .collect::<Vec<String>>()
@@ -559,7 +689,7 @@ fn print_interface(
             let default_value = cb
                 .default_implementation
                 .as_ref()
-                .map(|v| v.try_to_constant_cpp());
+                .map(|v| v.try_to_constant_cpp(f.naming()));
 
             doxygen(f, |f| {
                 print_cpp_doc(f, &cb.doc)?;
@@ -592,25 +722,28 @@ fn print_interface(
             match &default_value {
                 None => {
                     f.writeln(&format!(
-                        "virtual {} {}({}) = 0;",
-                        cb.return_type.get_cpp_callback_return_type(),
-                        cb.core_cpp_type(),
+                        "{}virtual {} {}({}) = 0;",
+                        deprecated_attribute(&cb.doc, f.naming()),
+                        cb.return_type.get_cpp_callback_return_type(f.naming()),
+                        cb.core_cpp_type(f.naming()),
                         args
                     ))?;
                 }
                 Some(None) => {
                     f.writeln(&format!(
-                        "virtual {} {}({}) {{}};",
-                        cb.return_type.get_cpp_callback_return_type(),
-                        cb.core_cpp_type(),
+                        "{}virtual {} {}({}) {{}};",
+                        deprecated_attribute(&cb.doc, f.naming()),
+                        cb.return_type.get_cpp_callback_return_type(f.naming()),
+                        cb.core_cpp_type(f.naming()),
                         args
                     ))?;
                 }
                 Some(Some(value)) => {
                     f.writeln(&format!(
-                        "virtual {} {}({}) {{",
-                        cb.return_type.get_cpp_callback_return_type(),
-                        cb.core_cpp_type(),
+                        "{}virtual {} {}({}) {{",
+                        deprecated_attribute(&cb.doc, f.naming()),
+                        cb.return_type.get_cpp_callback_return_type(f.naming()),
+                        cb.core_cpp_type(f.naming()),
                         args
                     ))?;
                     indented(f, |f| f.writeln(&format!("return {};", value)))?;
@@ -625,7 +758,7 @@ fn print_interface(
     f.newline()
 }
 
-fn get_default_value_doc(default: &ValidatedDefaultValue) -> String {
+fn get_default_value_doc(default: &ValidatedDefaultValue, naming: &Naming) -> String {
     match default {
         ValidatedDefaultValue::Bool(x) => format!("@p {}", x),
         ValidatedDefaultValue::Number(x) => x.to_string(),
@@ -633,10 +766,16 @@ fn get_default_value_doc(default: &ValidatedDefaultValue) -> String {
             format!("{}ms", x.as_millis())
         }
         ValidatedDefaultValue::Duration(DurationType::Seconds, x) => format!("{}s", x.as_secs()),
-        ValidatedDefaultValue::Enum(x, variant) => format!("{}::{}", x.core_cpp_type(), variant),
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!(
+                "{}::{}",
+                x.core_cpp_type(naming),
+                variant.snake_case(naming)
+            )
+        }
         ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
         ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
-            format!("Default @ref {}", handle.core_cpp_type())
+            format!("Default @ref {}", handle.core_cpp_type(naming))
         }
     }
 }
@@ -651,7 +790,13 @@ where
 {
     let args = handle
         .initializer_args(initializer.clone())
-        .map(|x| format!("{} {}", x.field_type.get_cpp_function_arg_type(), x.name))
+        .map(|x| {
+            format!(
+                "{} {}",
+                x.field_type.get_cpp_function_arg_type(f.naming()),
+                x.name.snake_case(f.naming())
+            )
+        })
         .collect::<Vec<String>>()
         .join(", ");
 
@@ -664,32 +809,34 @@ where
             for value in initializer.values.iter() {
                 f.writeln(&format!(
                     "- @ref {}.{} : {}",
-                    handle.core_cpp_type(),
-                    value.name,
-                    get_default_value_doc(&value.value)
+                    handle.core_cpp_type(f.naming()),
+                    value.name.snake_case(f.naming()),
+                    get_default_value_doc(&value.value, f.naming())
                 ))?;
             }
             f.newline()?;
         }
 
         for field in handle.initializer_args(initializer.clone()) {
-            f.writeln(&format!("@param {} ", field.name))?;
+            f.writeln(&format!("@param {} ", field.name.snake_case(f.naming())))?;
             print_cpp_docstring(f, &field.doc.brief)?;
         }
 
         f.writeln(&format!(
             "@returns New instance of @ref {}",
-            handle.core_cpp_type()
+            handle.core_cpp_type(f.naming())
         ))?;
 
         Ok(())
     })?;
     match initializer.initializer_type {
-        InitializerType::Normal => f.writeln(&format!("{}({});", handle.core_cpp_type(), args))?,
+        InitializerType::Normal => {
+            f.writeln(&format!("{}({});", handle.core_cpp_type(f.naming()), args))?
+        }
         InitializerType::Static => f.writeln(&format!(
             "static {} {}({});",
-            handle.core_cpp_type(),
-            initializer.name,
+            handle.core_cpp_type(f.naming()),
+            initializer.name.snake_case(f.naming()),
             args
         ))?,
     }
@@ -701,18 +848,21 @@ fn print_class_definition(
     f: &mut dyn Printer,
     handle: &Handle<Class<Validated>>,
 ) -> FormattingResult<()> {
-    let class_name = handle.core_cpp_type();
+    let class_name = handle.core_cpp_type(f.naming());
 
     print_commented_cpp_doc(f, &handle.doc)?;
     f.writeln(&format!("class {} {{", class_name))?;
     indented(f, |f| {
-        f.writeln(&format!("friend class {};", handle.friend_class()))?;
+        f.writeln(&format!(
+            "friend class {};",
+            handle.friend_class(f.naming())
+        ))?;
         f.writeln("// pointer to the underlying C type")?;
         f.writeln("void* self;")?;
         f.writeln("// constructor only accessible internally")?;
         f.writeln(&format!(
             "{}(void* self): self(self) {{}}",
-            handle.core_cpp_type()
+            handle.core_cpp_type(f.naming())
         ))?;
         print_deleted_class_functions(f, &class_name)
     })?;
@@ -730,11 +880,16 @@ fn print_class_definition(
         ))?;
 
         if let Some(x) = &handle.constructor {
-            let args = cpp_arguments(x.function.arguments.iter());
+            let args = cpp_arguments(x.function.arguments.iter(), f.naming());
 
             f.newline()?;
             print_cpp_constructor_docs(f, x)?;
-            f.writeln(&format!("{}({});", class_name, args))?;
+            f.writeln(&format!(
+                "{}{}({});",
+                deprecated_attribute(&x.function.doc, f.naming()),
+                class_name,
+                args
+            ))?;
         };
         if let Some(x) = &handle.destructor {
             f.newline()?;
@@ -764,16 +919,17 @@ fn print_class_definition(
 }
 
 fn print_method(f: &mut dyn Printer, method: &Method<Validated>) -> FormattingResult<()> {
-    let args = cpp_arguments(method.native_function.arguments.iter().skip(1));
+    let args = cpp_arguments(method.native_function.arguments.iter().skip(1), f.naming());
 
     print_cpp_method_docs(f, method)?;
     f.writeln(&format!(
-        "{} {}({});",
+        "{}{} {}({});",
+        deprecated_attribute(&method.native_function.doc, f.naming()),
         method
             .native_function
             .return_type
-            .get_cpp_function_return_type(),
-        method.name,
+            .get_cpp_function_return_type(f.naming()),
+        method.name.snake_case(f.naming()),
         args
     ))
 }
@@ -782,16 +938,17 @@ fn print_static_method(
     f: &mut dyn Printer,
     method: &StaticMethod<Validated>,
 ) -> FormattingResult<()> {
-    let args = cpp_arguments(method.native_function.arguments.iter());
+    let args = cpp_arguments(method.native_function.arguments.iter(), f.naming());
 
     print_cpp_static_method_docs(f, method)?;
     f.writeln(&format!(
-        "static {} {}({});",
+        "{}static {} {}({});",
+        deprecated_attribute(&method.native_function.doc, f.naming()),
         method
             .native_function
             .return_type
-            .get_cpp_function_return_type(),
-        method.name,
+            .get_cpp_function_return_type(f.naming()),
+        method.name.snake_case(f.naming()),
         args
     ))
 }
@@ -800,16 +957,17 @@ fn print_future_method(
     f: &mut dyn Printer,
     method: &FutureMethod<Validated>,
 ) -> FormattingResult<()> {
-    let args: String = cpp_arguments(method.native_function.arguments.iter().skip(1));
+    let args: String = cpp_arguments(method.native_function.arguments.iter().skip(1), f.naming());
 
     print_cpp_future_method_docs(f, method)?;
     f.writeln(&format!(
-        "{} {}({});",
+        "{}{} {}({});",
+        deprecated_attribute(&method.native_function.doc, f.naming()),
         method
             .native_function
             .return_type
-            .get_cpp_function_return_type(),
-        method.name,
+            .get_cpp_function_return_type(f.naming()),
+        method.name.snake_case(f.naming()),
         args
     ))?;
 
@@ -821,9 +979,9 @@ fn print_static_class(
     handle: &Handle<StaticClass<Validated>>,
 ) -> FormattingResult<()> {
     print_commented_cpp_doc(f, &handle.doc)?;
-    f.writeln(&format!("class {} {{", handle.core_cpp_type()))?;
+    f.writeln(&format!("class {} {{", handle.core_cpp_type(f.naming())))?;
     indented(f, |f| {
-        f.writeln(&format!("{}() = delete;", handle.core_cpp_type()))
+        f.writeln(&format!("{}() = delete;", handle.core_cpp_type(f.naming())))
     })?;
     f.writeln("public:")?;
     indented(f, |f| {
@@ -845,17 +1003,32 @@ fn print_deleted_class_functions(f: &mut dyn Printer, name: &str) -> FormattingR
     f.writeln(&format!("{}& operator=({}&& other) = delete;", name, name))
 }
 
-fn cpp_arguments<'a, T>(iter: T) -> String
+pub(crate) fn cpp_arguments<'a, T>(iter: T, naming: &Naming) -> String
 where
     T: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
 {
-    iter.map(|p| {
-        format!(
+    iter.map(|p| match &p.default {
+        Some(default) => format!(
+            "{} {} = {}",
+            p.arg_type.get_cpp_function_arg_type(naming),
+            p.core_cpp_type(naming),
+            get_default_argument(default, naming)
+        ),
+        None => format!(
             "{} {}",
-            p.arg_type.get_cpp_function_arg_type(),
-            p.core_cpp_type(),
-        )
+            p.arg_type.get_cpp_function_arg_type(naming),
+            p.core_cpp_type(naming),
+        ),
     })
     .collect::<Vec<String>>()
     .join(", ")
 }
+
+fn get_default_argument(default: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match default {
+        ValidatedDefaultValue::DefaultStruct(handle, InitializerType::Static, name) => {
+            format!("{}::{}()", handle.core_cpp_type(naming), name)
+        }
+        _ => get_default_value(default, naming),
+    }
+}
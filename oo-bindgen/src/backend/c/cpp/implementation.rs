@@ -3,62 +3,69 @@ use std::path::Path;
 use crate::backend::*;
 use crate::model::*;
 
+use crate::backend::c::cpp::constraint::*;
 use crate::backend::c::cpp::conversion::*;
 use crate::backend::c::cpp::formatting::*;
 use crate::backend::c::ctype::CType;
+use crate::backend::c::formatting::allow_deprecated;
 
 pub(crate) fn generate_cpp_file(lib: &Library, path: &Path) -> FormattingResult<()> {
     // Open the file
     logged::create_dir_all(&path)?;
     let filename = path.join(format!("{}.cpp", lib.settings.name));
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::Cpp))?;
 
     f.writeln(&format!("#include \"{}.h\"", lib.settings.name))?;
     f.writeln(&format!("#include \"{}.hpp\"", lib.settings.name))?;
+    if uses_regex(lib) {
+        f.writeln("#include <regex>")?;
+    }
     f.newline()?;
 
-    namespace(&mut f, &lib.settings.c_ffi_prefix, |f| {
-        write_friend_classes(lib, f)
-    })?;
+    // the implementation has to call the deprecated functions of the C API
+    let prefix = lib.settings.c_ffi_prefix.to_string();
+    allow_deprecated(&mut f, lib.has_deprecated_items(), |f| {
+        namespace(f, &prefix, |f| write_friend_classes(lib, f))?;
 
-    f.newline()?;
-
-    // conversions
-    namespace(&mut f, "convert", |f| {
-        for line in include_str!("snippet/convert_time.cpp").lines() {
-            f.writeln(line)?;
-        }
         f.newline()?;
 
-        // emit the conversions in statement order as some conversions reference other conversions
-        for statement in lib.statements() {
-            write_conversions(f, statement)?;
-        }
+        // conversions
+        namespace(f, "convert", |f| {
+            for line in include_str!("snippet/convert_time.cpp").lines() {
+                f.writeln(line)?;
+            }
+            f.newline()?;
 
-        Ok(())
-    })?;
+            // emit the conversions in statement order as some conversions reference other conversions
+            for statement in lib.statements() {
+                write_conversions(f, statement)?;
+            }
 
-    namespace(&mut f, &lib.settings.c_ffi_prefix, |f| {
-        // definitions of the collection classes and friends
-        write_collection_class_definitions(lib, f)?;
+            Ok(())
+        })?;
 
-        // C++ wrappers around native functions that get used in class methods
-        write_function_wrappers(lib, f)?;
+        namespace(f, &prefix, |f| {
+            // definitions of the collection classes and friends
+            write_collection_class_definitions(lib, f)?;
 
-        // collection class constructors destructors that call the functions wrappers
-        write_collection_class_implementations(lib, f)?;
+            // C++ wrappers around native functions that get used in class methods
+            write_function_wrappers(lib, f)?;
 
-        // finally, we can implement the public API
-        write_api_implementation(lib, f)
-    })?;
+            // collection class constructors destructors that call the functions wrappers
+            write_collection_class_implementations(lib, f)?;
 
-    Ok(())
+            // finally, we can implement the public API
+            write_api_implementation(lib, f)
+        })
+    })
 }
 
 fn write_collection_class_definitions(lib: &Library, f: &mut dyn Printer) -> FormattingResult<()> {
     for col in lib.collections() {
-        write_collection_class_definition(f, col)?;
-        write_collection_class_friend(f, col)?;
+        module_namespace(f, &col.collection_class.module, |f| {
+            write_collection_class_definition(f, col)?;
+            write_collection_class_friend(f, col)
+        })?;
     }
     Ok(())
 }
@@ -68,7 +75,9 @@ fn write_collection_class_implementations(
     f: &mut dyn Printer,
 ) -> FormattingResult<()> {
     for col in lib.collections() {
-        write_collection_class_implementation(f, col)?;
+        module_namespace(f, &col.collection_class.module, |f| {
+            write_collection_class_implementation(f, col)
+        })?;
     }
     Ok(())
 }
@@ -78,8 +87,8 @@ fn write_collection_class_implementation(
     col: &Handle<Collection<Validated>>,
 ) -> FormattingResult<()> {
     //let c_type = col.collection_type.to_c_type(&lib.c_ffi_prefix);
-    let cpp_type = col.collection_class.core_cpp_type();
-    let constructor = format!("fn::{}", col.create_func.name);
+    let cpp_type = col.collection_class.core_cpp_type(f.naming());
+    let constructor = format!("fn::{}", col.create_func.name.snake_case(f.naming()));
 
     let construct_self = if col.has_reserve {
         format!("{}(static_cast<uint32_t>(values.size()))", constructor)
@@ -92,13 +101,16 @@ fn write_collection_class_implementation(
         "{}::{}({} values) : self({})",
         cpp_type,
         cpp_type,
-        const_ref(col.core_cpp_type()),
+        const_ref(col.core_cpp_type(f.naming())),
         construct_self
     ))?;
     blocked(f, |f| {
         f.writeln("for(const auto& x : values)")?;
         blocked(f, |f| {
-            f.writeln(&format!("fn::{}(*this, x);", col.add_func.name))
+            f.writeln(&format!(
+                "fn::{}(*this, x);",
+                col.add_func.name.snake_case(f.naming())
+            ))
         })
     })?;
     f.newline()?;
@@ -106,7 +118,10 @@ fn write_collection_class_implementation(
     // write the destructor
     f.writeln(&format!("{}::~{}()", cpp_type, cpp_type))?;
     blocked(f, |f| {
-        f.writeln(&format!("fn::{}(*this);", col.delete_func.name))
+        f.writeln(&format!(
+            "fn::{}(*this);",
+            col.delete_func.name.snake_case(f.naming())
+        ))
     })?;
     f.newline()
 }
@@ -115,14 +130,14 @@ fn write_collection_class_definition(
     f: &mut dyn Printer,
     col: &Handle<Collection<Validated>>,
 ) -> FormattingResult<()> {
-    let cpp_type = col.collection_class.core_cpp_type();
+    let cpp_type = col.collection_class.core_cpp_type(f.naming());
     let c_type = col.collection_class.to_c_type();
     f.writeln(&format!("class {}", cpp_type))?;
     f.writeln("{")?;
     indented(f, |f| {
         f.writeln(&format!(
             "friend class {};",
-            col.collection_class.friend_class()
+            col.collection_class.friend_class(f.naming())
         ))?;
         f.writeln(&format!("{}* self;", c_type))
     })?;
@@ -131,7 +146,7 @@ fn write_collection_class_definition(
         f.writeln(&format!(
             "{}({} values);",
             cpp_type,
-            const_ref(col.core_cpp_type())
+            const_ref(col.core_cpp_type(f.naming()))
         ))?;
         f.writeln(&format!("~{}();", cpp_type))
     })?;
@@ -144,14 +159,17 @@ fn write_collection_class_friend(
     col: &Handle<Collection<Validated>>,
 ) -> FormattingResult<()> {
     let c_type = col.collection_class.to_c_type();
-    f.writeln(&format!("class {}", col.collection_class.friend_class()))?;
+    f.writeln(&format!(
+        "class {}",
+        col.collection_class.friend_class(f.naming())
+    ))?;
     f.writeln("{")?;
     f.writeln("public:")?;
     indented(f, |f| {
         f.writeln(&format!(
             "static {}* get({}& value)",
             c_type,
-            col.collection_class.core_cpp_type()
+            col.collection_class.core_cpp_type(f.naming())
         ))?;
         blocked(f, |f| f.writeln("return value.self;"))?;
         Ok(())
@@ -162,28 +180,24 @@ fn write_collection_class_friend(
 
 fn write_friend_classes(lib: &Library, f: &mut dyn Printer) -> FormattingResult<()> {
     for st in lib.structs() {
-        match st {
-            StructType::FunctionArg(x) => {
-                write_cpp_struct_friend_class(f, x)?;
-            }
-            StructType::FunctionReturn(x) => {
-                write_cpp_struct_friend_class(f, x)?;
-            }
-            StructType::CallbackArg(x) => {
-                write_cpp_struct_friend_class(f, x)?;
-            }
-            StructType::Universal(x) => {
-                write_cpp_struct_friend_class(f, x)?;
-            }
-        }
+        module_namespace(f, st.module(), |f| match st {
+            StructType::FunctionArg(x) => write_cpp_struct_friend_class(f, x),
+            StructType::FunctionReturn(x) => write_cpp_struct_friend_class(f, x),
+            StructType::CallbackArg(x) => write_cpp_struct_friend_class(f, x),
+            StructType::Universal(x) => write_cpp_struct_friend_class(f, x),
+        })?;
     }
 
     for it in lib.iterators() {
-        write_iterator_friend_class(f, it)?;
+        module_namespace(f, &it.iter_class.module, |f| {
+            write_iterator_friend_class(f, it)
+        })?;
     }
 
     for class in lib.classes() {
-        print_friend_class(f, class)?;
+        module_namespace(f, &class.declaration.module, |f| {
+            print_friend_class(f, class)
+        })?;
     }
 
     Ok(())
@@ -203,31 +217,45 @@ fn write_function_wrappers(lib: &Library, f: &mut dyn Printer) -> FormattingResu
 
 fn write_api_implementation(lib: &Library, f: &mut dyn Printer) -> FormattingResult<()> {
     for e in lib.enums() {
-        write_enum_to_string_impl(f, e)?;
+        module_namespace(f, &e.module, |f| write_enum_to_string_impl(f, e))?;
     }
 
     for it in lib.iterators() {
-        write_iterator_methods(f, it)?;
+        module_namespace(f, &it.iter_class.module, |f| write_iterator_methods(f, it))?;
     }
 
     for st in lib.structs() {
-        match st {
-            StructType::FunctionArg(x) => write_struct_constructors(f, x)?,
-            StructType::FunctionReturn(x) => write_struct_constructors(f, x)?,
-            StructType::CallbackArg(x) => write_struct_constructors(f, x)?,
-            StructType::Universal(x) => write_struct_constructors(f, x)?,
-        }
+        module_namespace(f, st.module(), |f| match st {
+            StructType::FunctionArg(x) => write_struct_constructors(f, x),
+            StructType::FunctionReturn(x) => write_struct_constructors(f, x),
+            StructType::CallbackArg(x) => write_struct_constructors(f, x),
+            StructType::Universal(x) => write_struct_constructors(f, x),
+        })?;
+    }
+
+    for st in lib.structs().filter(|x| x.has_value_semantics()) {
+        module_namespace(f, st.module(), |f| match st {
+            StructType::FunctionArg(x) => write_struct_value_semantics(f, x),
+            StructType::FunctionReturn(x) => write_struct_value_semantics(f, x),
+            StructType::CallbackArg(x) => write_struct_value_semantics(f, x),
+            StructType::Universal(x) => write_struct_value_semantics(f, x),
+        })?;
     }
 
     for c in lib.classes() {
-        write_class_implementation(f, c)?;
+        module_namespace(f, &c.declaration.module, |f| {
+            write_class_implementation(f, c)
+        })?;
     }
 
     for c in lib.static_classes() {
-        for m in &c.static_methods {
-            write_static_class_method(f, c, m)?;
-            f.newline()?;
-        }
+        module_namespace(f, &c.module, |f| {
+            for m in &c.static_methods {
+                write_static_class_method(f, c, m)?;
+                f.newline()?;
+            }
+            Ok(())
+        })?;
     }
 
     Ok(())
@@ -238,9 +266,9 @@ fn write_iterator_methods(
     it: &Handle<AbstractIterator<Validated>>,
 ) -> FormattingResult<()> {
     let c_class_type = it.iter_class.to_c_type();
-    let cpp_class_type = it.iter_class.core_cpp_type();
+    let cpp_class_type = it.iter_class.core_cpp_type(f.naming());
     let c_next = it.next_function.to_c_type();
-    let cpp_value_type = it.item_type.core_cpp_type();
+    let cpp_value_type = it.item_type.core_cpp_type(f.naming());
     let c_value_type = it.item_type.to_c_type();
 
     f.writeln(&format!("bool {}::next()", cpp_class_type))?;
@@ -292,18 +320,18 @@ fn write_static_class_method(
     class: &Handle<StaticClass<Validated>>,
     method: &StaticMethod<Validated>,
 ) -> FormattingResult<()> {
-    fn get_invocation_args(args: &[Arg<FunctionArgument, Validated>]) -> String {
-        args.iter()
-            .map(transform_arg)
-            .collect::<Vec<String>>()
-            .join(", ")
-    }
-
+    let naming = f.naming().clone();
     let args: String = method
         .native_function
         .arguments
         .iter()
-        .map(|arg| format!("{} {}", arg.arg_type.get_cpp_function_arg_type(), arg.name))
+        .map(|arg| {
+            format!(
+                "{} {}",
+                arg.arg_type.get_cpp_function_arg_type(&naming),
+                arg.name.snake_case(&naming)
+            )
+        })
         .collect::<Vec<String>>()
         .join(", ");
 
@@ -312,16 +340,16 @@ fn write_static_class_method(
         method
             .native_function
             .return_type
-            .get_cpp_function_return_type(),
-        class.core_cpp_type(),
-        method.name,
+            .get_cpp_function_return_type(&naming),
+        class.core_cpp_type(&naming),
+        method.name.snake_case(&naming),
         args
     ))?;
     blocked(f, |f| {
         let invocation = format!(
             "fn::{}({})",
-            method.native_function.name,
-            get_invocation_args(&method.native_function.arguments)
+            method.native_function.name.snake_case(&naming),
+            cpp_function_arg_invocation(&method.native_function.arguments, &naming)
         );
 
         match &method.native_function.return_type.get_value() {
@@ -362,7 +390,68 @@ where
     Ok(())
 }
 
-fn get_default_value(default: &ValidatedDefaultValue) -> String {
+fn write_struct_value_semantics<T>(
+    f: &mut dyn Printer,
+    st: &Handle<Struct<T, Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + ToCppStream,
+{
+    let struct_name = st.core_cpp_type(f.naming());
+
+    f.writeln(&format!(
+        "bool operator==(const {}& lhs, const {}& rhs)",
+        struct_name, struct_name
+    ))?;
+    blocked(f, |f| {
+        if st.fields.is_empty() {
+            return f.writeln("return true;");
+        }
+        f.writeln("return")?;
+        indented(f, |f| {
+            for (field, last) in st.fields.iter().with_last() {
+                let terminator = if last { ";" } else { " &&" };
+                let name = field.name.snake_case(f.naming());
+                f.writeln(&format!("lhs.{} == rhs.{}{}", name, name, terminator))?;
+            }
+            Ok(())
+        })
+    })?;
+
+    f.newline()?;
+
+    f.writeln(&format!(
+        "bool operator!=(const {}& lhs, const {}& rhs)",
+        struct_name, struct_name
+    ))?;
+    blocked(f, |f| f.writeln("return !(lhs == rhs);"))?;
+
+    f.newline()?;
+
+    f.writeln(&format!(
+        "std::ostream& operator<<(std::ostream& os, const {}& value)",
+        struct_name
+    ))?;
+    blocked(f, |f| {
+        f.writeln(&format!("os << \"{} {{ \";", struct_name))?;
+        for (field, last) in st.fields.iter().with_last() {
+            let separator = if last { " " } else { ", " };
+            f.writeln(&format!(
+                "os << \"{}: \" << {} << \"{}\";",
+                field.name,
+                field
+                    .field_type
+                    .to_cpp_stream(format!("value.{}", field.name.snake_case(f.naming()))),
+                separator
+            ))?;
+        }
+        f.writeln("return os << \"}\";")
+    })?;
+
+    f.newline()
+}
+
+pub(crate) fn get_default_value(default: &ValidatedDefaultValue, naming: &Naming) -> String {
     match default {
         ValidatedDefaultValue::Bool(x) => x.to_string(),
         ValidatedDefaultValue::Number(x) => match x {
@@ -381,14 +470,18 @@ fn get_default_value(default: &ValidatedDefaultValue) -> String {
             format!("std::chrono::milliseconds({})", x.as_millis())
         }
         ValidatedDefaultValue::Enum(x, variant) => {
-            format!("{}::{}", x.core_cpp_type(), variant)
+            format!(
+                "{}::{}",
+                x.core_cpp_type(naming),
+                variant.snake_case(naming)
+            )
         }
         ValidatedDefaultValue::String(x) => {
             format!("\"{}\"", x)
         }
         ValidatedDefaultValue::DefaultStruct(st, ct, c_name) => match ct {
-            InitializerType::Normal => format!("{}()", st.name().camel_case()),
-            InitializerType::Static => format!("{}()", c_name.camel_case()),
+            InitializerType::Normal => format!("{}()", st.core_cpp_type(naming)),
+            InitializerType::Static => format!("{}()", c_name.camel_case(naming)),
         },
     }
 }
@@ -401,10 +494,17 @@ fn write_struct_constructor<T>(
 where
     T: StructFieldType + CppFunctionArgType + TypeInfo,
 {
-    let struct_name = st.core_cpp_type();
+    let naming = f.naming();
+    let struct_name = st.core_cpp_type(naming);
     let args = st
         .initializer_args(con.clone())
-        .map(|f| format!("{} {}", f.field_type.get_cpp_function_arg_type(), f.name))
+        .map(|f| {
+            format!(
+                "{} {}",
+                f.field_type.get_cpp_function_arg_type(naming),
+                f.name.snake_case(naming)
+            )
+        })
         .collect::<Vec<String>>()
         .join(", ");
 
@@ -413,17 +513,22 @@ where
             f.writeln(&format!("{}::{}({}) : ", struct_name, struct_name, args))?;
             indented(f, |f| {
                 for (field, last) in st.fields.iter().with_last() {
+                    let name = field.name.snake_case(f.naming());
                     let value = match con.values.iter().find(|x| x.name == field.name) {
                         None => {
                             let argument = if field.field_type.is_move_type() {
-                                std_move(field.name.clone())
+                                std_move(name.clone())
                             } else {
-                                field.name.to_string()
+                                name.clone()
                             };
-                            format!("{}({})", field.name, argument)
+                            format!("{}({})", name, argument)
                         }
                         Some(default) => {
-                            format!("{}({})", field.name, get_default_value(&default.value))
+                            format!(
+                                "{}({})",
+                                name,
+                                get_default_value(&default.value, f.naming())
+                            )
                         }
                     };
 
@@ -440,15 +545,18 @@ where
         InitializerType::Static => {
             f.writeln(&format!(
                 "{} {}::{}({})",
-                struct_name, struct_name, con.name, args
+                struct_name,
+                struct_name,
+                con.name.snake_case(f.naming()),
+                args
             ))?;
             blocked(f, |f| {
                 f.writeln(&format!("return {}(", struct_name))?;
                 indented(f, |f| {
                     for (field, last) in st.fields.iter().with_last() {
                         let value = match con.values.iter().find(|x| x.name == field.name) {
-                            None => field.name.to_string(),
-                            Some(iv) => get_default_value(&iv.value),
+                            None => field.name.snake_case(f.naming()),
+                            Some(iv) => get_default_value(&iv.value, f.naming()),
                         };
                         if last {
                             f.writeln(&value)?;
@@ -477,7 +585,7 @@ fn write_class_implementation(
     f: &mut dyn Printer,
     handle: &Handle<Class<Validated>>,
 ) -> FormattingResult<()> {
-    let cpp_name = handle.core_cpp_type();
+    let cpp_name = handle.core_cpp_type(f.naming());
 
     // write constructor
     for constructor in &handle.constructor {
@@ -485,9 +593,9 @@ fn write_class_implementation(
             "{}::{}({}) : self(fn::{}({}))",
             cpp_name,
             cpp_name,
-            cpp_function_args(&constructor.function.arguments),
-            constructor.function.name,
-            cpp_function_arg_invocation(&constructor.function.arguments)
+            cpp_function_args(&constructor.function.arguments, f.naming()),
+            constructor.function.name.snake_case(f.naming()),
+            cpp_function_arg_invocation(&constructor.function.arguments, f.naming())
         ))?;
         f.writeln("{}")?;
         f.newline()?;
@@ -499,7 +607,10 @@ fn write_class_implementation(
         blocked(f, |f| {
             f.writeln("if(self)")?;
             blocked(f, |f| {
-                f.writeln(&format!("fn::{}(*this);", destructor.function.name))
+                f.writeln(&format!(
+                    "fn::{}(*this);",
+                    destructor.function.name.snake_case(f.naming())
+                ))
             })?;
             Ok(())
         })?;
@@ -530,25 +641,24 @@ fn write_class_static_method_impl(
     handle: &Handle<Class<Validated>>,
     method: &StaticMethod<Validated>,
 ) -> FormattingResult<()> {
-    let cpp_name = handle.core_cpp_type();
+    let cpp_name = handle.core_cpp_type(f.naming());
     let return_type = method
         .native_function
         .return_type
-        .get_cpp_function_return_type();
+        .get_cpp_function_return_type(f.naming());
 
-    let native_function_name = method.native_function.name.clone();
     let invocation = format!(
         "fn::{}({})",
-        native_function_name,
-        cpp_function_arg_invocation(&method.native_function.arguments)
+        method.native_function.name.snake_case(f.naming()),
+        cpp_function_arg_invocation(&method.native_function.arguments, f.naming())
     );
 
     f.writeln(&format!(
         "{} {}::{}({})",
         return_type,
         cpp_name,
-        method.name,
-        cpp_function_args(&method.native_function.arguments)
+        method.name.snake_case(f.naming()),
+        cpp_function_args(&method.native_function.arguments, f.naming())
     ))?;
     blocked(f, |f| {
         match &method.native_function.return_type.get_value() {
@@ -574,7 +684,8 @@ fn write_class_method_impl(
     handle: &Handle<Class<Validated>>,
     method: &Method<Validated>,
 ) -> FormattingResult<()> {
-    write_class_method_impl_generic(f, handle, &method.name, &method.native_function)
+    let name = method.name.snake_case(f.naming());
+    write_class_method_impl_generic(f, handle, &name, &method.native_function)
 }
 
 fn write_class_future_method_impl(
@@ -582,7 +693,8 @@ fn write_class_future_method_impl(
     handle: &Handle<Class<Validated>>,
     method: &FutureMethod<Validated>,
 ) -> FormattingResult<()> {
-    write_class_method_impl_generic(f, handle, &method.name, &method.native_function)
+    let name = method.name.snake_case(f.naming());
+    write_class_method_impl_generic(f, handle, &name, &method.native_function)
 }
 
 fn write_class_method_impl_generic(
@@ -591,25 +703,27 @@ fn write_class_method_impl_generic(
     cpp_method_name: &str,
     native_function: &Handle<Function<Validated>>,
 ) -> FormattingResult<()> {
-    let cpp_name = handle.core_cpp_type();
+    let cpp_name = handle.core_cpp_type(f.naming());
     let args = &native_function.arguments[1..];
-    let native_function_name = native_function.name.clone();
+    let native_function_name = native_function.name.snake_case(f.naming());
     let invocation = if args.is_empty() {
         format!("fn::{}(*this)", native_function_name)
     } else {
         format!(
             "fn::{}(*this, {})",
             native_function_name,
-            cpp_function_arg_invocation(args)
+            cpp_function_arg_invocation(args, f.naming())
         )
     };
 
     f.writeln(&format!(
         "{} {}::{}({})",
-        native_function.return_type.get_cpp_function_return_type(),
+        native_function
+            .return_type
+            .get_cpp_function_return_type(f.naming()),
         cpp_name,
         cpp_method_name,
-        cpp_function_args(args)
+        cpp_function_args(args, f.naming())
     ))?;
     blocked(f, |f| {
         write_move_self_guard(f)?;
@@ -639,7 +753,7 @@ fn print_friend_class(
 ) -> FormattingResult<()> {
     let iterator = include_str!("snippet/class_friend_class.hpp");
     let c_type = handle.declaration.to_c_type();
-    let cpp_type = handle.core_cpp_type();
+    let cpp_type = handle.core_cpp_type(f.naming());
     for line in iterator.lines() {
         let substituted = line
             .replace("<c_type>", &c_type)
@@ -649,30 +763,60 @@ fn print_friend_class(
     f.newline()
 }
 
-fn cpp_function_args(args: &[Arg<FunctionArgument, Validated>]) -> String {
+fn cpp_function_args(args: &[Arg<FunctionArgument, Validated>], naming: &Naming) -> String {
     args.iter()
-        .map(|arg| format!("{} {}", arg.arg_type.get_cpp_function_arg_type(), arg.name))
+        .map(|arg| {
+            format!(
+                "{} {}",
+                arg.arg_type.get_cpp_function_arg_type(naming),
+                arg.name.snake_case(naming)
+            )
+        })
         .collect::<Vec<String>>()
         .join(", ")
 }
 
-fn transform_arg(arg: &Arg<FunctionArgument, Validated>) -> String {
+fn transform_arg(arg: &Arg<FunctionArgument, Validated>, naming: &Naming) -> String {
     if arg.arg_type.is_move_type() {
-        std_move(arg.name.clone())
+        std_move(arg.name.snake_case(naming))
     } else {
-        arg.name.to_string()
+        arg.name.snake_case(naming)
     }
 }
 
-fn cpp_function_arg_invocation(args: &[Arg<FunctionArgument, Validated>]) -> String {
+fn cpp_function_arg_invocation(
+    args: &[Arg<FunctionArgument, Validated>],
+    naming: &Naming,
+) -> String {
     args.iter()
-        .map(transform_arg)
+        .map(|arg| transform_arg(arg, naming))
         .collect::<Vec<String>>()
         .join(", ")
 }
 
 const RETURN_VALUE: &str = "_oo_bindgen_return_value";
 
+fn write_argument_constraint_checks(
+    f: &mut dyn Printer,
+    func: &Handle<Function<Validated>>,
+) -> FormattingResult<()> {
+    for arg in func.arguments.iter().filter(|x| x.has_constraints()) {
+        if let Some(target) = arg.arg_type.constrained_type() {
+            let value = arg.name.snake_case(f.naming());
+            for constraint in &arg.constraints {
+                write_constraint_check(f, constraint, target, &value, &arg.name)?;
+            }
+        }
+        if arg.arg_type.has_nested_constraints() {
+            f.writeln(&format!(
+                "::convert::check_constraints({});",
+                arg.name.snake_case(f.naming())
+            ))?;
+        }
+    }
+    Ok(())
+}
+
 fn write_function_wrapper(
     f: &mut dyn Printer,
     func: &Handle<Function<Validated>>,
@@ -693,7 +837,7 @@ fn write_function_wrapper(
         blocked(f, |f| {
             f.writeln(&format!(
                 "throw {}({});",
-                err.exception_name.camel_case(),
+                err.core_cpp_type(f.naming()),
                 err.inner.to_cpp("_error".to_string())
             ))
         })
@@ -704,12 +848,12 @@ fn write_function_wrapper(
         func: &Handle<Function<Validated>>,
     ) -> FormattingResult<()> {
         for arg in &func.arguments {
-            if arg.arg_type.requires_shadow_parameter() {
+            if arg.arg_type.requires_shadow_parameter(f.naming()) {
                 f.writeln(&format!(
                     "auto _{} = {};",
                     arg.name,
                     arg.arg_type
-                        .to_native_function_argument(arg.name.to_string())
+                        .to_native_function_argument(arg.name.snake_case(f.naming()), f.naming())
                 ))?;
             }
         }
@@ -722,10 +866,10 @@ fn write_function_wrapper(
         has_out_param: bool,
     ) -> FormattingResult<()> {
         for (arg, last) in func.arguments.iter().with_last() {
-            let conversion = match arg.arg_type.shadow_parameter_mapping() {
+            let conversion = match arg.arg_type.shadow_parameter_mapping(f.naming()) {
                 None => arg
                     .arg_type
-                    .to_native_function_argument(arg.name.to_string()),
+                    .to_native_function_argument(arg.name.snake_case(f.naming()), f.naming()),
                 Some(transform) => transform(format!("_{}", arg.name)),
             };
             if last && !has_out_param {
@@ -741,10 +885,10 @@ fn write_function_wrapper(
     }
 
     let return_type = match &func.return_type.get_value() {
-        None => func.return_type.get_cpp_function_return_type(),
+        None => func.return_type.get_cpp_function_return_type(f.naming()),
         Some(t) => {
             if t.transform_in_wrapper() {
-                func.return_type.get_cpp_function_return_type()
+                func.return_type.get_cpp_function_return_type(f.naming())
             } else {
                 t.to_c_type()
             }
@@ -754,12 +898,13 @@ fn write_function_wrapper(
     f.writeln(&format!(
         "{} {}({})",
         return_type,
-        func.name,
-        cpp_function_args(&func.arguments)
+        func.name.snake_case(f.naming()),
+        cpp_function_args(&func.arguments, f.naming())
     ))?;
 
     blocked(f, |f| {
         let c_func_name = func.to_c_type();
+        write_argument_constraint_checks(f, func)?;
         write_shadowed_conversions(f, func)?;
         match func.error_type.get() {
             None => match &func.return_type.get_value() {
@@ -812,10 +957,14 @@ fn write_conversions(
 ) -> FormattingResult<()> {
     match statement {
         Statement::StructDefinition(x) => match x {
-            StructType::FunctionArg(x) => write_cpp_to_native_struct_conversion(f, x),
+            StructType::FunctionArg(x) => {
+                write_struct_constraint_check(f, x)?;
+                write_cpp_to_native_struct_conversion(f, x)
+            }
             StructType::FunctionReturn(x) => write_native_to_cpp_struct_conversion(f, x),
             StructType::CallbackArg(x) => write_native_to_cpp_struct_conversion(f, x),
             StructType::Universal(x) => {
+                write_struct_constraint_check(f, x)?;
                 write_cpp_to_native_struct_conversion(f, x)?;
                 write_native_to_cpp_struct_conversion(f, x)
             }
@@ -840,7 +989,7 @@ fn write_iterator_construct_helper(
     f: &mut dyn Printer,
     handle: &Handle<AbstractIterator<Validated>>,
 ) -> FormattingResult<()> {
-    let cpp_type = handle.core_cpp_type();
+    let cpp_type = handle.core_cpp_type(f.naming());
     let signature = format!(
         "::{}::{} construct({}* self)",
         handle.settings.c_ffi_prefix,
@@ -852,7 +1001,7 @@ fn write_iterator_construct_helper(
         f.writeln(&format!(
             "return ::{}::{}::init(self);",
             handle.settings.c_ffi_prefix,
-            handle.friend_class()
+            handle.friend_class(f.naming())
         ))
     })?;
     f.newline()
@@ -862,7 +1011,7 @@ fn write_iterator_to_native_helper(
     f: &mut dyn Printer,
     handle: &Handle<AbstractIterator<Validated>>,
 ) -> FormattingResult<()> {
-    let cpp_type = handle.core_cpp_type();
+    let cpp_type = handle.core_cpp_type(f.naming());
     let signature = format!(
         "{}* to_native(const ::{}::{}& value)",
         handle.iter_class.to_c_type(),
@@ -874,7 +1023,7 @@ fn write_iterator_to_native_helper(
         f.writeln(&format!(
             "return ::{}::{}::get(value);",
             handle.settings.c_ffi_prefix,
-            handle.friend_class()
+            handle.friend_class(f.naming())
         ))
     })?;
     f.newline()
@@ -884,7 +1033,7 @@ fn write_class_construct_helper(
     f: &mut dyn Printer,
     handle: &Handle<Class<Validated>>,
 ) -> FormattingResult<()> {
-    let cpp_type = handle.core_cpp_type();
+    let cpp_type = handle.core_cpp_type(f.naming());
     let signature = format!(
         "::{}::{} to_cpp({}* self)",
         handle.settings.c_ffi_prefix,
@@ -896,7 +1045,7 @@ fn write_class_construct_helper(
         f.writeln(&format!(
             "return ::{}::{}::init(self);",
             handle.settings.c_ffi_prefix,
-            handle.friend_class()
+            handle.friend_class(f.naming())
         ))
     })?;
     f.newline()
@@ -912,24 +1061,31 @@ where
     let args = handle
         .fields
         .iter()
-        .map(|x| format!("{} {}", x.field_type.get_cpp_function_arg_type(), x.name))
+        .map(|x| {
+            format!(
+                "{} {}",
+                x.field_type.get_cpp_function_arg_type(f.naming()),
+                x.name.snake_case(f.naming())
+            )
+        })
         .collect::<Vec<String>>()
         .join(", ");
 
-    f.writeln(&format!("class {}", handle.friend_class()))?;
+    f.writeln(&format!("class {}", handle.friend_class(f.naming())))?;
     f.writeln("{")?;
     f.writeln("public:")?;
     indented(f, |f| {
-        let cpp_type = handle.core_cpp_type();
+        let cpp_type = handle.core_cpp_type(f.naming());
         f.writeln(&format!("static {} init({})", cpp_type, args))?;
         blocked(f, |f| {
             f.writeln(&format!("return {}(", cpp_type))?;
             indented(f, |f| {
                 for (field, last) in handle.fields().with_last() {
+                    let name = field.name.snake_case(f.naming());
                     let value = if field.field_type.is_move_type() {
-                        format!("std::move({})", field.name)
+                        format!("std::move({})", name)
                     } else {
-                        field.name.to_string()
+                        name
                     };
 
                     if last {
@@ -948,11 +1104,16 @@ where
                 f.newline()?;
                 f.writeln(&format!(
                     "static {} get_{}({} value)",
-                    field.field_type.get_cpp_function_arg_type(),
+                    field.field_type.get_cpp_function_arg_type(f.naming()),
                     field.name,
                     const_ref(cpp_type.clone())
                 ))?;
-                blocked(f, |f| f.writeln(&format!("return value.{};", field.name)))?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "return value.{};",
+                        field.name.snake_case(f.naming())
+                    ))
+                })?;
             }
         }
         Ok(())
@@ -967,24 +1128,27 @@ fn write_iterator_friend_class(
 ) -> FormattingResult<()> {
     let c_type = handle.iter_class.to_c_type();
 
-    f.writeln(&format!("class {}", handle.friend_class()))?;
+    f.writeln(&format!("class {}", handle.friend_class(f.naming())))?;
     f.writeln("{")?;
     f.writeln("public:")?;
     indented(f, |f| {
         f.newline()?;
         f.writeln(&format!(
             "static {} init({}* value)",
-            handle.core_cpp_type(),
+            handle.core_cpp_type(f.naming()),
             handle.iter_class.to_c_type()
         ))?;
         blocked(f, |f| {
-            f.writeln(&format!("return {}(value);", handle.core_cpp_type()))
+            f.writeln(&format!(
+                "return {}(value);",
+                handle.core_cpp_type(f.naming())
+            ))
         })?;
         f.newline()?;
         f.writeln(&format!(
             "static {}* get(const {}& value)",
             c_type,
-            handle.core_cpp_type()
+            handle.core_cpp_type(f.naming())
         ))?;
         blocked(f, |f| {
             f.writeln(&format!(
@@ -997,6 +1161,47 @@ fn write_iterator_friend_class(
     f.newline()
 }
 
+fn write_struct_constraint_check<T>(
+    f: &mut dyn Printer,
+    handle: &Handle<Struct<T, Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType,
+{
+    if !handle.has_constraints() {
+        return Ok(());
+    }
+
+    f.writeln(&format!(
+        "static void check_constraints(const ::{}::{}& value)",
+        handle.settings().c_ffi_prefix,
+        handle.core_cpp_type(f.naming())
+    ))?;
+    blocked(f, |f| {
+        for field in handle.fields.iter().filter(|x| x.has_constraints()) {
+            let cpp_value = match handle.visibility {
+                Visibility::Public => format!("value.{}", field.name.snake_case(f.naming())),
+                Visibility::Private => format!(
+                    "::{}::{}::get_{}(value)",
+                    handle.settings().c_ffi_prefix,
+                    handle.friend_class(f.naming()),
+                    field.name
+                ),
+            };
+            if let Some(target) = field.field_type.constrained_type() {
+                for constraint in &field.constraints {
+                    write_constraint_check(f, constraint, target, &cpp_value, &field.name)?;
+                }
+            }
+            if field.field_type.has_nested_constraints() {
+                f.writeln(&format!("check_constraints({});", cpp_value))?;
+            }
+        }
+        Ok(())
+    })?;
+    f.newline()
+}
+
 fn write_cpp_to_native_struct_conversion<T>(
     f: &mut dyn Printer,
     handle: &Handle<Struct<T, Validated>>,
@@ -1007,7 +1212,7 @@ where
     let namespaced_type = format!(
         "::{}::{}",
         handle.declaration.inner.settings.c_ffi_prefix,
-        handle.core_cpp_type()
+        handle.core_cpp_type(f.naming())
     );
     let value_type = if handle.fields.iter().any(|f| f.field_type.requires_move()) {
         mut_ref(namespaced_type)
@@ -1026,13 +1231,13 @@ where
             for field in &handle.fields {
                 let cpp_value = match handle.visibility {
                     Visibility::Public => {
-                        format!("value.{}", field.name)
+                        format!("value.{}", field.name.snake_case(f.naming()))
                     }
                     Visibility::Private => {
                         format!(
                             "::{}::{}::get_{}(value)",
                             handle.settings().c_ffi_prefix,
-                            handle.friend_class(),
+                            handle.friend_class(f.naming()),
                             field.name
                         )
                     }
@@ -1060,18 +1265,18 @@ where
     let cpp_type = format!(
         "::{}::{}",
         handle.settings().c_ffi_prefix,
-        handle.core_cpp_type()
+        handle.core_cpp_type(f.naming())
     );
     f.writeln(&format!("{} to_cpp({} value)", cpp_type, const_ref(c_type)))?;
     blocked(f, |f| {
         f.writeln(&format!(
             "return ::{}::{}::init(",
             handle.settings().c_ffi_prefix,
-            handle.friend_class()
+            handle.friend_class(f.naming())
         ))?;
         indented(f, |f| {
             for (field, last) in handle.fields.iter().with_last() {
-                let native_value = format!("value.{}", field.name);
+                let native_value = format!("value.{}", field.name.snake_case(f.naming()));
                 let conversion = field.field_type.to_cpp_struct_field(native_value);
 
                 if last {
@@ -1094,7 +1299,7 @@ fn write_enum_to_string_impl(
 ) -> FormattingResult<()> {
     f.writeln(&format!(
         "const char* to_string({} value)",
-        handle.core_cpp_type()
+        handle.core_cpp_type(f.naming())
     ))?;
     f.writeln("{")?;
     indented(f, |f| {
@@ -1104,14 +1309,14 @@ fn write_enum_to_string_impl(
             for v in &handle.variants {
                 f.writeln(&format!(
                     "case {}::{}: return \"{}\";",
-                    handle.core_cpp_type(),
-                    v.core_cpp_type(),
+                    handle.core_cpp_type(f.naming()),
+                    v.core_cpp_type(f.naming()),
                     v.name
                 ))?;
             }
             f.writeln(&format!(
                 "default: throw std::invalid_argument(\"Undefined value for enum '{}'\");",
-                handle.name.camel_case()
+                handle.name.camel_case(f.naming())
             ))
         })?;
         f.writeln("}")
@@ -1127,7 +1332,7 @@ fn write_enum_to_native_conversion(
     let cpp_type = format!(
         "::{}::{}",
         handle.settings.c_ffi_prefix,
-        handle.core_cpp_type()
+        handle.core_cpp_type(f.naming())
     );
     f.writeln(&format!(
         "{} to_native({} value)",
@@ -1143,8 +1348,8 @@ fn write_enum_to_native_conversion(
                 f.writeln(&format!(
                     "case ::{}::{}::{}: return {}_{}_{};",
                     handle.settings.c_ffi_prefix,
-                    handle.name.camel_case(),
-                    v.name,
+                    handle.core_cpp_type(f.naming()),
+                    v.core_cpp_type(f.naming()),
                     handle.settings.c_ffi_prefix.capital_snake_case(),
                     handle.name.capital_snake_case(),
                     v.name.capital_snake_case(),
@@ -1166,7 +1371,7 @@ fn write_enum_to_cpp_conversion(
     let cpp_type = format!(
         "::{}::{}",
         handle.settings.c_ffi_prefix,
-        handle.core_cpp_type()
+        handle.core_cpp_type(f.naming())
     );
     f.writeln(&format!(
         "{} to_cpp({} value)",
@@ -1185,8 +1390,8 @@ fn write_enum_to_cpp_conversion(
                     handle.name.capital_snake_case(),
                     v.name.capital_snake_case(),
                     handle.settings.c_ffi_prefix,
-                    handle.name.camel_case(),
-                    v.name
+                    handle.core_cpp_type(f.naming()),
+                    v.core_cpp_type(f.naming())
                 ))?;
             }
             f.writeln("default: throw std::invalid_argument(\"bad enum conversion\");")?;
@@ -1207,7 +1412,7 @@ fn write_callback_function(
     let cpp_type = format!(
         "::{}::{}",
         interface.settings.c_ffi_prefix,
-        interface.core_cpp_type()
+        interface.core_cpp_type(f.naming())
     );
 
     fn write_invocation_lines(
@@ -1218,7 +1423,8 @@ fn write_callback_function(
             let conversion = if arg.arg_type.requires_shadow_parameter() {
                 format!("_{}", arg.name)
             } else {
-                arg.arg_type.to_cpp_callback_argument(arg.name.to_string())
+                arg.arg_type
+                    .to_cpp_callback_argument(arg.name.snake_case(f.naming()))
             };
             if last {
                 f.writeln(&conversion)?;
@@ -1232,7 +1438,13 @@ fn write_callback_function(
     let args = cb
         .arguments
         .iter()
-        .map(|x| format!("{} {}", x.arg_type.to_c_type(), x.name))
+        .map(|x| {
+            format!(
+                "{} {}",
+                x.arg_type.to_c_type(),
+                x.name.snake_case(f.naming())
+            )
+        })
         .chain(std::iter::once("void* ctx".to_string()))
         .collect::<Vec<String>>()
         .join(", ");
@@ -1248,12 +1460,17 @@ fn write_callback_function(
                 f.writeln(&format!(
                     "auto _{} = {};",
                     arg.name,
-                    arg.arg_type.to_cpp_callback_argument(arg.name.to_string())
+                    arg.arg_type
+                        .to_cpp_callback_argument(arg.name.snake_case(f.naming()))
                 ))?;
             }
         }
 
-        let function = format!("reinterpret_cast<{}*>(ctx)->{}", cpp_type, cb.name);
+        let function = format!(
+            "reinterpret_cast<{}*>(ctx)->{}",
+            cpp_type,
+            cb.name.snake_case(f.naming())
+        );
         match &cb.return_type.get_value() {
             None => {
                 f.writeln(&format!("{}(", function))?;
@@ -1282,7 +1499,7 @@ fn write_cpp_interface_to_native_conversion(
     let cpp_type = format!(
         "::{}::{}",
         handle.settings.c_ffi_prefix,
-        handle.core_cpp_type()
+        handle.core_cpp_type(f.naming())
     );
     let argument_type = match handle.mode {
         InterfaceCategory::Synchronous => mut_ref(cpp_type.clone()),
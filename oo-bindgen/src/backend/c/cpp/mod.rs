@@ -1,6 +1,8 @@
+pub(crate) mod conformance;
 pub(crate) mod conversion;
 pub(crate) mod doc;
 pub(crate) mod header;
 pub(crate) mod implementation;
 
+mod constraint;
 mod formatting;
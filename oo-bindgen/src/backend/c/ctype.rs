@@ -78,7 +78,7 @@ impl CType for CallbackReturnValue {
 
 impl CType for StructDeclarationHandle {
     fn to_c_type(&self) -> String {
-        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
+        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name.as_ref())
     }
 }
 
@@ -100,7 +100,7 @@ where
         format!(
             "{}_{}_t",
             self.declaration.inner.settings.c_ffi_prefix,
-            self.name()
+            self.name().as_ref()
         )
     }
 }
@@ -110,13 +110,13 @@ where
     D: DocReference,
 {
     fn to_c_type(&self) -> String {
-        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
+        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name.as_ref())
     }
 }
 
 impl CType for ClassDeclarationHandle {
     fn to_c_type(&self) -> String {
-        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
+        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name.as_ref())
     }
 }
 
@@ -125,7 +125,7 @@ where
     D: DocReference,
 {
     fn to_c_type(&self) -> String {
-        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
+        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name.as_ref())
     }
 }
 
@@ -6,7 +6,7 @@ pub(crate) type ReferencePrinter =
     fn(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()>;
 
 pub(crate) fn doxygen_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
-    doxygen_print_generic(f, print_c_reference, doc)
+    doxygen_print_generic(f, print_c_reference, ExampleLanguage::C, doc)
 }
 
 pub(crate) fn docstring_print(
@@ -19,12 +19,17 @@ pub(crate) fn docstring_print(
 pub(crate) fn doxygen_print_generic(
     f: &mut dyn Printer,
     print_reference: ReferencePrinter,
+    language: ExampleLanguage,
     doc: &Doc<Validated>,
 ) -> FormattingResult<()> {
     f.writeln("@brief ")?;
     docstring_print_generic(f, print_reference, &doc.brief)?;
 
-    for detail in &doc.details {
+    for detail in doc
+        .details
+        .iter()
+        .filter(|x| x.is_printed_in(Some(language)))
+    {
         f.newline()?;
 
         match detail {
@@ -36,9 +41,28 @@ pub(crate) fn doxygen_print_generic(
                 f.writeln("@warning ")?;
                 docstring_print_generic(f, print_reference, docstring)?;
             }
+            DocParagraph::Example(example) => {
+                let code = example.get(language).unwrap_or_default();
+                f.writeln("@code")?;
+                for line in code.lines() {
+                    f.writeln(line)?;
+                }
+                f.writeln("@endcode")?;
+            }
         }
     }
 
+    if let Some(deprecated) = &doc.deprecated {
+        f.newline()?;
+        f.writeln(&format!("@deprecated Since {}: ", deprecated.since))?;
+        docstring_print_generic(f, print_reference, &deprecated.note)?;
+    }
+
+    if let Some(since) = &doc.since {
+        f.newline()?;
+        f.writeln(&format!("@since {}", since))?;
+    }
+
     Ok(())
 }
 
@@ -47,12 +71,36 @@ pub(crate) fn docstring_print_generic(
     print_reference: ReferencePrinter,
     docstring: &DocString<Validated>,
 ) -> FormattingResult<()> {
-    for el in docstring.elements() {
+    let mut elements = docstring.elements().peekable();
+    while let Some(el) = elements.next() {
         match el {
             DocStringElement::Text(text) => f.write(text)?,
             DocStringElement::Null => f.write("@p NULL")?,
             DocStringElement::Iterator => f.write("iterator")?,
             DocStringElement::Reference(reference) => print_reference(f, reference)?,
+            DocStringElement::Code(code) => {
+                // @c only applies to the next word
+                if code.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    f.write(&format!("@c {}", code))?
+                } else {
+                    f.write(&format!("<tt>{}</tt>", code))?
+                }
+            }
+            DocStringElement::Bold(text) => f.write(&format!("<b>{}</b>", text))?,
+            DocStringElement::Link { text, url } => {
+                f.write(&format!("<a href=\"{}\">{}</a>", url, text))?
+            }
+            DocStringElement::List(items) => {
+                for item in items {
+                    f.writeln("- ")?;
+                    docstring_print_generic(f, print_reference, item)?;
+                }
+                // an empty line ends the list
+                if elements.peek().is_some() {
+                    f.newline()?;
+                    f.newline()?;
+                }
+            }
         }
     }
 
@@ -61,54 +109,54 @@ pub(crate) fn docstring_print_generic(
 
 fn print_c_reference(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
     match reference {
-        Validated::Argument(param_name) => f.write(&format!("@p {}", param_name))?,
-        Validated::Class(class) => {
-            f.write(&format!("@ref {}", class.to_c_type()))?;
-        }
-        Validated::ClassMethod(class, method_name, _) => {
-            f.write(&format!(
-                "@ref {}_{}_{}",
-                class.settings.c_ffi_prefix,
-                class.name(),
-                method_name
-            ))?;
+        Validated::Argument(_) => {
+            f.write(&format!("@p {}", c_reference_name(reference, f.naming())))
         }
+        _ => f.write(&format!("@ref {}", c_reference_name(reference, f.naming()))),
+    }
+}
+
+/// Name of a referenced item in the C API, without any Doxygen markup
+pub(crate) fn c_reference_name(reference: &Validated, naming: &Naming) -> String {
+    match reference {
+        Validated::Argument(param_name) => param_name.snake_case(naming),
+        Validated::Class(class) => class.to_c_type(),
+        Validated::ClassMethod(class, method_name, _) => format!(
+            "{}_{}_{}",
+            class.settings.c_ffi_prefix,
+            class.name().as_ref(),
+            method_name
+        ),
         Validated::ClassConstructor(class, constructor) => {
-            f.write(&format!(
-                "@ref {}_{}",
+            format!(
+                "{}_{}",
                 class.settings.c_ffi_prefix, constructor.function.name
-            ))?;
+            )
         }
         Validated::ClassDestructor(class, destructor) => {
-            f.write(&format!(
-                "@ref {}_{}",
+            format!(
+                "{}_{}",
                 class.settings.c_ffi_prefix, destructor.function.name
-            ))?;
-        }
-        Validated::Struct(st) => {
-            f.write(&format!("@ref {}", st.to_c_type()))?;
+            )
         }
+        Validated::Struct(st) => st.to_c_type(),
         Validated::StructField(st, field_name) => {
-            f.write(&format!("@ref {}.{}", st.to_c_type(), field_name))?;
-        }
-        Validated::Enum(handle) => {
-            f.write(&format!("@ref {}", handle.to_c_type()))?;
-        }
-        Validated::EnumVariant(handle, variant_name) => {
-            f.write(&format!(
-                "@ref {}_{}_{}",
-                handle.settings.c_ffi_prefix.capital_snake_case(),
-                handle.name.capital_snake_case(),
-                variant_name.capital_snake_case()
-            ))?;
-        }
-        Validated::Interface(interface) => {
-            f.write(&format!("@ref {}", interface.to_c_type()))?;
+            format!("{}.{}", st.to_c_type(), field_name.snake_case(naming))
         }
+        Validated::Enum(handle) => handle.to_c_type(),
+        Validated::EnumVariant(handle, variant_name) => format!(
+            "{}_{}_{}",
+            handle.settings.c_ffi_prefix.capital_snake_case(),
+            handle.name.capital_snake_case(),
+            variant_name.capital_snake_case()
+        ),
+        Validated::Interface(interface) => interface.to_c_type(),
         Validated::InterfaceMethod(interface, callback_name) => {
-            f.write(&format!("@ref {}.{}", interface.to_c_type(), callback_name))?;
+            format!(
+                "{}.{}",
+                interface.to_c_type(),
+                callback_name.snake_case(naming)
+            )
         }
     }
-
-    Ok(())
 }
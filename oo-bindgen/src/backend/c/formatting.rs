@@ -1,5 +1,7 @@
 use crate::backend::*;
-use crate::model::Library;
+use crate::model::*;
+
+use crate::backend::c::doc::c_reference_name;
 
 pub(crate) fn cpp_guard<F, T>(f: &mut dyn Printer, cb: F) -> FormattingResult<T>
 where
@@ -26,3 +28,115 @@ pub(crate) fn print_license(f: &mut dyn Printer, lib: &Library) -> FormattingRes
         Ok(())
     })
 }
+
+/// Macros defining the deprecation attributes of the C API
+///
+/// `{PREFIX}_DEPRECATED` precedes the declaration of a function. MSVC only accepts its attribute
+/// before a declaration, so `{PREFIX}_DEPRECATED_MEMBER`, which follows struct fields, enum variants
+/// and callbacks, is limited to GCC and Clang.
+pub(crate) fn deprecated_macro(f: &mut dyn Printer, prefix: &Name) -> FormattingResult<()> {
+    let prefix = prefix.capital_snake_case();
+    let gnu = "__attribute__((deprecated(message)))";
+    f.writeln("#if defined(__GNUC__)")?;
+    f.writeln(&format!("#define {}_DEPRECATED(message) {}", prefix, gnu))?;
+    f.writeln(&format!(
+        "#define {}_DEPRECATED_MEMBER(message) {}",
+        prefix, gnu
+    ))?;
+    f.writeln("#elif defined(_MSC_VER)")?;
+    f.writeln(&format!(
+        "#define {}_DEPRECATED(message) __declspec(deprecated(message))",
+        prefix
+    ))?;
+    f.writeln(&format!("#define {}_DEPRECATED_MEMBER(message)", prefix))?;
+    f.writeln("#else")?;
+    f.writeln(&format!("#define {}_DEPRECATED(message)", prefix))?;
+    f.writeln(&format!("#define {}_DEPRECATED_MEMBER(message)", prefix))?;
+    f.writeln("#endif")
+}
+
+/// Deprecation attribute preceding a C function, if it is deprecated
+pub(crate) fn deprecation_attribute(
+    prefix: &Name,
+    doc: &Doc<Validated>,
+    naming: &Naming,
+) -> Option<String> {
+    deprecation_macro_call(prefix, "DEPRECATED", doc, naming)
+}
+
+/// Deprecation attribute following a struct field, enum variant or callback, if it is deprecated
+pub(crate) fn member_deprecation_attribute(
+    prefix: &Name,
+    doc: &Doc<Validated>,
+    naming: &Naming,
+) -> Option<String> {
+    deprecation_macro_call(prefix, "DEPRECATED_MEMBER", doc, naming)
+}
+
+fn deprecation_macro_call(
+    prefix: &Name,
+    name: &str,
+    doc: &Doc<Validated>,
+    naming: &Naming,
+) -> Option<String> {
+    doc.deprecated.as_ref().map(|x| {
+        format!(
+            "{}_{}({})",
+            prefix.capital_snake_case(),
+            name,
+            string_literal(&x.message(&|reference| c_reference_name(reference, naming)))
+        )
+    })
+}
+
+/// Silence the deprecation warnings in generated code that has to use the deprecated items
+///
+/// The warnings are still reported where the user code uses them.
+pub(crate) fn allow_deprecated<F, T>(
+    f: &mut dyn Printer,
+    enabled: bool,
+    cb: F,
+) -> FormattingResult<T>
+where
+    F: FnOnce(&mut dyn Printer) -> FormattingResult<T>,
+{
+    if enabled {
+        f.writeln("#if defined(__GNUC__)")?;
+        f.writeln("#pragma GCC diagnostic push")?;
+        f.writeln("#pragma GCC diagnostic ignored \"-Wdeprecated-declarations\"")?;
+        f.writeln("#elif defined(_MSC_VER)")?;
+        f.writeln("#pragma warning(push)")?;
+        f.writeln("#pragma warning(disable: 4996)")?;
+        f.writeln("#endif")?;
+        f.newline()?;
+    }
+
+    let result = cb(f)?;
+
+    if enabled {
+        f.writeln("#if defined(__GNUC__)")?;
+        f.writeln("#pragma GCC diagnostic pop")?;
+        f.writeln("#elif defined(_MSC_VER)")?;
+        f.writeln("#pragma warning(pop)")?;
+        f.writeln("#endif")?;
+    }
+
+    Ok(result)
+}
+
+/// C string literal with the bytes of the UTF-8 encoding outside of ASCII escaped in octal
+pub(crate) fn string_literal(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for byte in value.bytes() {
+        match byte {
+            b'"' | b'\\' => {
+                literal.push('\\');
+                literal.push(byte as char);
+            }
+            0x20..=0x7e => literal.push(byte as char),
+            _ => literal.push_str(&format!("\\{:03o}", byte)),
+        }
+    }
+    literal.push('"');
+    literal
+}
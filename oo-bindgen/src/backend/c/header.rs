@@ -7,12 +7,85 @@ use crate::backend::*;
 use crate::model::*;
 
 pub(crate) fn generate_c_header(lib: &Library, path: &Path) -> FormattingResult<()> {
+    generate_main_header(lib, path, false)
+}
+
+/// Generate the main header with the functions of the root module, and a header with the functions
+/// of each other module, e.g. `foo_master.h`
+///
+/// The types of all the modules stay in the main header, as they may reference each other. The main
+/// header includes the headers of the modules, so including it still declares the whole API.
+pub(crate) fn generate_c_module_headers(lib: &Library, path: &Path) -> FormattingResult<()> {
+    generate_main_header(lib, path, true)?;
+    for module in function_modules(lib) {
+        generate_module_header(lib, module, path)?;
+    }
+    Ok(())
+}
+
+/// Names of the header files of the C API, the main one first
+pub(crate) fn header_files(lib: &Library, module_headers: bool) -> Vec<String> {
+    let mut files = vec![format!("{}.h", lib.settings.name)];
+    if module_headers {
+        files.extend(function_modules(lib).map(|x| module_header_file(lib, x)));
+    }
+    files
+}
+
+/// Modules with native functions, other than the root one
+fn function_modules(lib: &Library) -> impl Iterator<Item = &Module> {
+    lib.modules()
+        .filter(move |module| lib.functions().any(|x| &x.module == *module))
+}
+
+fn module_header_file(lib: &Library, module: &Module) -> String {
+    let mut name = lib.settings.name.to_string();
+    for dir in module.path() {
+        name.push('_');
+        name.push_str(dir.as_ref());
+    }
+    format!("{}.h", name)
+}
+
+fn generate_module_header(lib: &Library, module: &Module, path: &Path) -> FormattingResult<()> {
+    let filename = module_header_file(lib, module);
+    let mut f = FilePrinter::new(path.join(&filename), lib.naming(TargetLanguage::C))?;
+
+    print_license(&mut f, lib)?;
+
+    f.writeln("#pragma once")?;
+    f.newline()?;
+    f.writeln(&format!("#include \"{}.h\"", lib.settings.name))?;
+    f.newline()?;
+
+    cpp_guard(&mut f, |f| {
+        f.newline()?;
+
+        doxygen(f, |f| {
+            f.writeln(&format!(
+                "@file {} C API of the {} module",
+                filename, module
+            ))
+        })?;
+        f.newline()?;
+
+        allow_deprecated(f, lib.has_deprecated_items(), |f| {
+            for function in lib.functions().filter(|x| &x.module == module) {
+                write_function(f, function)?;
+                f.newline()?;
+            }
+            Ok(())
+        })
+    })
+}
+
+fn generate_main_header(lib: &Library, path: &Path, module_headers: bool) -> FormattingResult<()> {
     let uppercase_name = lib.settings.c_ffi_prefix.to_uppercase();
 
     // Open file
     logged::create_dir_all(&path)?;
     let filename = path.join(format!("{}.h", lib.settings.name));
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::C))?;
 
     // Print license
     print_license(&mut f, lib)?;
@@ -49,6 +122,11 @@ pub(crate) fn generate_c_header(lib: &Library, path: &Path) -> FormattingResult<
         f.writeln("#include <stdint.h>")?;
         f.newline()?;
 
+        if lib.has_deprecated_items() {
+            deprecated_macro(f, &lib.settings.c_ffi_prefix)?;
+            f.newline()?;
+        }
+
         doxygen(f, |f| {
             // Doxygen needs the @file tag
             f.writeln(&format!(
@@ -79,30 +157,53 @@ pub(crate) fn generate_c_header(lib: &Library, path: &Path) -> FormattingResult<
         f.newline()?;
 
         // Iterate through each statement and print them
-        for statement in lib.statements() {
-            match statement {
-                Statement::Constants(handle) => write_constants_definition(f, handle)?,
-                Statement::StructDeclaration(handle) => {
-                    let c_type = handle.to_c_type();
-                    f.writeln(&format!("typedef struct {} {};", c_type, c_type))?;
-                }
-                Statement::StructDefinition(st) => match st {
-                    StructType::FunctionArg(x) => write_struct_definition(f, x)?,
-                    StructType::FunctionReturn(x) => write_struct_definition(f, x)?,
-                    StructType::CallbackArg(x) => write_struct_definition(f, x)?,
-                    StructType::Universal(x) => write_struct_definition(f, x)?,
-                },
-                Statement::EnumDefinition(handle) => write_enum_definition(f, handle)?,
-                Statement::ClassDeclaration(handle) => write_class_declaration(f, handle, lib)?,
-                Statement::FunctionDefinition(handle) => write_function(f, handle)?,
-                Statement::InterfaceDefinition(handle) => write_interface(f, handle.untyped())?,
-                _ => (),
+        allow_deprecated(f, lib.has_deprecated_items(), |f| {
+            write_statements(f, lib, module_headers)
+        })
+    })?;
+
+    if module_headers {
+        f.newline()?;
+        for module in function_modules(lib) {
+            f.writeln(&format!("#include \"{}\"", module_header_file(lib, module)))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_statements(
+    f: &mut dyn Printer,
+    lib: &Library,
+    module_headers: bool,
+) -> FormattingResult<()> {
+    for statement in lib.statements() {
+        if module_headers && !statement.is_type() && !statement.module().is_root() {
+            // declared in the header of the module
+            continue;
+        }
+        match statement {
+            Statement::Constants(handle) => write_constants_definition(f, handle)?,
+            Statement::StructDeclaration(handle) => {
+                let c_type = handle.to_c_type();
+                f.writeln(&format!("typedef struct {} {};", c_type, c_type))?;
             }
-            f.newline()?;
+            Statement::StructDefinition(st) => match st {
+                StructType::FunctionArg(x) => write_struct_definition(f, x)?,
+                StructType::FunctionReturn(x) => write_struct_definition(f, x)?,
+                StructType::CallbackArg(x) => write_struct_definition(f, x)?,
+                StructType::Universal(x) => write_struct_definition(f, x)?,
+            },
+            Statement::EnumDefinition(handle) => write_enum_definition(f, handle)?,
+            Statement::ClassDeclaration(handle) => write_class_declaration(f, handle, lib)?,
+            Statement::FunctionDefinition(handle) => write_function(f, handle)?,
+            Statement::InterfaceDefinition(handle) => write_interface(f, handle.untyped())?,
+            _ => (),
         }
+        f.newline()?;
+    }
 
-        Ok(())
-    })
+    Ok(())
 }
 
 fn write_constants_definition(
@@ -160,9 +261,10 @@ where
                 Ok(())
             })?;
             f.writeln(&format!(
-                "{} {};",
+                "{} {}{};",
                 element.field_type.to_c_type(),
-                element.name,
+                element.name.snake_case(f.naming()),
+                deprecation_suffix(&handle.settings().c_ffi_prefix, &element.doc, f.naming())
             ))?;
         }
         Ok(())
@@ -210,7 +312,7 @@ fn get_default_value(default: &ValidatedDefaultValue) -> String {
             format!(
                 "{}_{}_{}()",
                 &handle.settings().c_ffi_prefix,
-                handle.name(),
+                handle.name().as_ref(),
                 name
             )
         }
@@ -259,7 +361,7 @@ where
                 f.writeln(&format!(
                     "- @ref {}.{} : {}",
                     handle.to_c_type(),
-                    value.name,
+                    value.name.snake_case(f.naming()),
                     get_default_value_doc(&value.value)
                 ))?;
             }
@@ -267,7 +369,7 @@ where
         }
 
         for field in handle.initializer_args(initializer.clone()) {
-            f.writeln(&format!("@param {} ", field.name))?;
+            f.writeln(&format!("@param {} ", field.name.snake_case(f.naming())))?;
             docstring_print(f, &field.doc.brief)?;
         }
 
@@ -279,10 +381,17 @@ where
         Ok(())
     })?;
 
+    let naming = f.naming();
     let params = handle
         .fields()
         .filter(|f| !initializer.values.iter().any(|cf| cf.name == f.name))
-        .map(|el| format!("{} {}", el.field_type.to_c_type(), el.name))
+        .map(|el| {
+            format!(
+                "{} {}",
+                el.field_type.to_c_type(),
+                el.name.snake_case(naming)
+            )
+        })
         .collect::<Vec<String>>()
         .join(", ");
 
@@ -290,7 +399,7 @@ where
         "static {} {}_{}_{}({})",
         handle.to_c_type(),
         handle.declaration.inner.settings.c_ffi_prefix,
-        handle.name(),
+        handle.name().as_ref(),
         initializer.name,
         params
     ))?;
@@ -301,7 +410,7 @@ where
             for (field, last) in handle.fields.iter().with_last() {
                 let value: String = match initializer.values.iter().find(|x| x.name == field.name) {
                     Some(x) => get_default_value(&x.value),
-                    None => field.name.to_string(),
+                    None => field.name.snake_case(f.naming()),
                 };
                 let value = if last { value } else { format!("{},", value) };
                 f.writeln(&value)?;
@@ -328,10 +437,11 @@ fn write_enum_definition(
         for variant in &handle.variants {
             doxygen(f, |f| doxygen_print(f, &variant.doc))?;
             f.writeln(&format!(
-                "{}_{}_{} = {},",
+                "{}_{}_{}{} = {},",
                 handle.settings.c_ffi_prefix.capital_snake_case(),
                 handle.name.capital_snake_case(),
                 variant.name.capital_snake_case(),
+                deprecation_suffix(&handle.settings.c_ffi_prefix, &variant.doc, f.naming()),
                 variant.value
             ))?;
         }
@@ -350,7 +460,7 @@ fn write_enum_definition(
     f.writeln(&format!(
         "static const char* {}_{}_to_string({} value)",
         handle.settings.c_ffi_prefix,
-        handle.name,
+        handle.name.as_ref(),
         handle.to_c_type()
     ))?;
     blocked(f, |f| {
@@ -362,12 +472,12 @@ fn write_enum_definition(
                     handle.settings.c_ffi_prefix.capital_snake_case(),
                     handle.name.capital_snake_case(),
                     variant.name.capital_snake_case(),
-                    variant.name
+                    variant.name.as_ref()
                 ))?;
             }
             f.writeln(&format!(
                 "default: return \"unknown {} value\";",
-                handle.name
+                handle.name.as_ref()
             ))
         })
     })
@@ -423,7 +533,7 @@ fn write_function_docs(
 
         // Print each parameter value
         for param in &handle.arguments {
-            f.writeln(&format!("@param {} ", param.name))?;
+            f.writeln(&format!("@param {} ", param.name.snake_case(f.naming())))?;
             docstring_print(f, &param.doc)?;
             if let FunctionArgument::Basic(BasicType::Duration(mapping)) = param.arg_type {
                 f.write(&format!(" ({})", mapping.unit()))?;
@@ -466,41 +576,49 @@ fn write_function(
 ) -> FormattingResult<()> {
     write_function_docs(f, handle)?;
 
-    if let Some(error_type) = &handle.error_type.get() {
-        f.writeln(&format!(
-            "{} {}_{}(",
-            error_type.inner.to_c_type(),
-            &handle.settings.c_ffi_prefix,
-            handle.name
-        ))?;
-    } else {
-        f.writeln(&format!(
-            "{} {}_{}(",
-            handle.return_type.to_c_type(),
-            &handle.settings.c_ffi_prefix,
-            handle.name
-        ))?;
+    if let Some(attribute) =
+        deprecation_attribute(&handle.settings.c_ffi_prefix, &handle.doc, f.naming())
+    {
+        f.writeln(&attribute)?;
     }
 
-    f.write(
-        &handle
-            .arguments
-            .iter()
-            .map(|param| format!("{} {}", param.arg_type.to_c_type(), param.name))
-            .collect::<Vec<String>>()
-            .join(", "),
-    )?;
+    f.writeln(&format!("{};", function_signature(handle, f.naming())))
+}
+
+/// Signature of the native function, e.g. `uint32_t foo_class_get_value(foo_class_t* instance)`
+pub(crate) fn function_signature(handle: &Handle<Function<Validated>>, naming: &Naming) -> String {
+    let return_type = match &handle.error_type.get() {
+        Some(error_type) => error_type.inner.to_c_type(),
+        None => handle.return_type.to_c_type(),
+    };
+
+    let mut args: Vec<String> = handle
+        .arguments
+        .iter()
+        .map(|param| {
+            format!(
+                "{} {}",
+                param.arg_type.to_c_type(),
+                param.name.snake_case(naming)
+            )
+        })
+        .collect();
 
     if handle.error_type.is_some() {
         if let Some(x) = &handle.return_type.get_value() {
             if !handle.arguments.is_empty() {
-                f.write(", ")?;
-                f.write(&format!("{}* out", x.to_c_type()))?;
+                args.push(format!("{}* out", x.to_c_type()));
             }
         }
     }
 
-    f.write(");")
+    format!(
+        "{} {}_{}({})",
+        return_type,
+        &handle.settings.c_ffi_prefix,
+        handle.name,
+        args.join(", ")
+    )
 }
 
 pub(crate) fn callback_parameters(func: &CallbackFunction<Validated>) -> String {
@@ -536,7 +654,7 @@ fn write_interface(
 
                 // Print each argument value
                 for arg in &cb.arguments {
-                    f.writeln(&format!("@param {} ", arg.name))?;
+                    f.writeln(&format!("@param {} ", arg.name.snake_case(f.naming())))?;
                     docstring_print(f, &arg.doc)?;
                 }
 
@@ -555,11 +673,18 @@ fn write_interface(
             f.newline()?;
 
             // Print function signature
-            f.write(&format!("{} (*{})(", cb.return_type.to_c_type(), cb.name))?;
+            f.write(&format!(
+                "{} (*{})(",
+                cb.return_type.to_c_type(),
+                cb.name.snake_case(f.naming())
+            ))?;
 
             f.write(&callback_parameters(cb))?;
 
-            f.write(");")?;
+            f.write(&format!(
+                "){};",
+                deprecation_suffix(&handle.settings.c_ffi_prefix, &cb.doc, f.naming())
+            ))?;
         }
 
         doxygen(f, |f| {
@@ -584,7 +709,7 @@ fn write_interface(
         f.writeln("@brief ")?;
         docstring_print(f, &text("Initialize an instance of the interface"))?;
         for cb in &handle.callbacks {
-            f.writeln(&format!("@param {} ", cb.name))?;
+            f.writeln(&format!("@param {} ", cb.name.snake_case(f.naming())))?;
             docstring_print(f, &cb.doc.brief)?;
         }
         f.writeln(&format!(
@@ -596,11 +721,17 @@ fn write_interface(
     })?;
     f.writeln(&format!(
         "static {} {}_{}_init(",
-        struct_name, &handle.settings.c_ffi_prefix, handle.name
+        struct_name,
+        &handle.settings.c_ffi_prefix,
+        handle.name.as_ref()
     ))?;
     indented(f, |f| {
         for cb in &handle.callbacks {
-            f.writeln(&format!("{} (*{})(", cb.return_type.to_c_type(), cb.name,))?;
+            f.writeln(&format!(
+                "{} (*{})(",
+                cb.return_type.to_c_type(),
+                cb.name.snake_case(f.naming())
+            ))?;
 
             f.write(&callback_parameters(cb))?;
             f.write("),")?;
@@ -617,7 +748,7 @@ fn write_interface(
         f.writeln(&format!("{} _return_value = {{", struct_name))?;
         indented(f, |f| {
             for cb in &handle.callbacks {
-                f.writeln(&format!("{},", cb.name))?;
+                f.writeln(&format!("{},", cb.name.snake_case(f.naming())))?;
             }
             f.writeln(&format!("{},", destroy_func_name))?;
             f.writeln(ctx_variable_name.as_ref())
@@ -628,3 +759,10 @@ fn write_interface(
 
     Ok(())
 }
+
+fn deprecation_suffix(prefix: &Name, doc: &Doc<Validated>, naming: &Naming) -> String {
+    match member_deprecation_attribute(prefix, doc, naming) {
+        Some(attribute) => format!(" {}", attribute),
+        None => String::new(),
+    }
+}
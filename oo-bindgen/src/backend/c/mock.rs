@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use crate::backend::c::formatting::{cpp_guard, print_license};
+use crate::backend::rust::mock::{ControlType, CONTROL_API};
+use crate::backend::*;
+use crate::model::*;
+
+/// Write the declarations of the functions controlling the mock implementation of the library
+pub(crate) fn generate(lib: &Library, path: &Path) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::C))?;
+    let prefix = &lib.settings.c_ffi_prefix;
+
+    print_license(&mut f, lib)?;
+    f.writeln("#pragma once")?;
+    f.newline()?;
+
+    cpp_guard(&mut f, |f| {
+        f.newline()?;
+        f.writeln("#include <stdint.h>")?;
+        f.newline()?;
+        f.writeln(&format!(
+            "/// @file {}_mock.h Control API of the mock implementation of the {} library",
+            lib.settings.name, lib.settings.name
+        ))?;
+
+        for function in CONTROL_API.iter() {
+            f.newline()?;
+            f.writeln(&format!("/// @brief {}", function.doc))?;
+            let mut params = Vec::new();
+            for (name, param_type, doc) in function.params {
+                f.writeln(&format!("/// @param {} {}", name, doc))?;
+                match param_type {
+                    ControlType::U32 => params.push(format!("uint32_t {}", name)),
+                    ControlType::String => params.push(format!("const char* {}", name)),
+                    ControlType::Strings => {
+                        f.writeln("/// @param count Number of arguments")?;
+                        params.push(format!("const char* const* {}", name));
+                        params.push("uint32_t count".to_string());
+                    }
+                }
+            }
+            let return_type = match function.returns {
+                None => "void",
+                Some((ControlType::U32, doc)) => {
+                    f.writeln(&format!("/// @return {}", doc))?;
+                    "uint32_t"
+                }
+                Some((_, doc)) => {
+                    f.writeln(&format!("/// @return {}", doc))?;
+                    "const char*"
+                }
+            };
+            let params = if params.is_empty() {
+                "void".to_string()
+            } else {
+                params.join(", ")
+            };
+            f.writeln(&format!(
+                "{} {}_mock_{}({});",
+                return_type, prefix, function.name, params
+            ))?;
+        }
+
+        f.newline()
+    })
+}
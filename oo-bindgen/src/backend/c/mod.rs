@@ -7,11 +7,13 @@ use std::process::Command;
 use crate::backend::*;
 use crate::model::*;
 
-mod cpp;
-mod ctype;
+mod conformance;
+pub(crate) mod cpp;
+pub(crate) mod ctype;
 mod doc;
-mod formatting;
-mod header;
+pub(crate) mod formatting;
+pub(crate) mod header;
+mod mock;
 
 pub(crate) struct CBindgenConfig {
     pub(crate) output_dir: PathBuf,
@@ -19,6 +21,33 @@ pub(crate) struct CBindgenConfig {
     pub(crate) extra_files: Vec<PathBuf>,
     pub(crate) platform_locations: PlatformLocations,
     pub(crate) generate_doxygen: bool,
+    /// declare the functions of each module in its own header
+    pub(crate) module_headers: bool,
+}
+
+/// Write the conformance tests of the C and C++ bindings to `c/conformance_tests.c` and
+/// `cpp/conformance_tests.cpp`, each defining a `conformance_tests()` function running them
+pub(crate) fn generate_conformance_tests(
+    lib: &Library,
+    suite: &crate::conformance::Suite,
+    output_dir: &Path,
+) -> FormattingResult<()> {
+    let c_dir = output_dir.join("c");
+    let cpp_dir = output_dir.join("cpp");
+    logged::create_dir_all(&c_dir)?;
+    logged::create_dir_all(&cpp_dir)?;
+
+    conformance::generate(lib, suite, &c_dir.join("conformance_tests.c"))?;
+    cpp::conformance::generate(lib, suite, &cpp_dir.join("conformance_tests.cpp"))
+}
+
+/// Write the declarations of the control API of the mock implementation to `{name}_mock.h`
+pub(crate) fn generate_mock_header(lib: &Library, output_dir: &Path) -> FormattingResult<()> {
+    logged::create_dir_all(output_dir)?;
+    mock::generate(
+        lib,
+        &output_dir.join(format!("{}_mock.h", lib.settings.name)),
+    )
 }
 
 pub(crate) fn generate_c_package(lib: &Library, config: &CBindgenConfig) -> FormattingResult<()> {
@@ -26,7 +55,11 @@ pub(crate) fn generate_c_package(lib: &Library, config: &CBindgenConfig) -> Form
     let include_path = config.output_dir.join("include");
     let source_path = config.output_dir.join("src");
 
-    header::generate_c_header(lib, &include_path)?;
+    if config.module_headers {
+        header::generate_c_module_headers(lib, &include_path)?;
+    } else {
+        header::generate_c_header(lib, &include_path)?;
+    }
     cpp::header::generate_header(lib, &include_path)?;
     cpp::implementation::generate_cpp_file(lib, &source_path)?;
 
@@ -90,6 +123,10 @@ fn generate_doxygen(lib: &Library, config: &CBindgenConfig) -> FormattingResult<
     fs::write(config.output_dir.join("logo.png"), lib.info.logo_png)?;
 
     let include_path = "include";
+    let c_headers: Vec<String> = header::header_files(lib, config.module_headers)
+        .iter()
+        .map(|x| format!("{}/{}", include_path, x))
+        .collect();
 
     // Build C documentation
     fs::create_dir_all(config.output_dir.join("doc").join("c"))?;
@@ -98,7 +135,7 @@ fn generate_doxygen(lib: &Library, config: &CBindgenConfig) -> FormattingResult<
         &[
             &format!("PROJECT_NAME = {} (C API)", lib.settings.name),
             &format!("PROJECT_NUMBER = {}", lib.version),
-            &format!("INPUT = include/{}.h", lib.settings.name),
+            &format!("INPUT = {}", c_headers.join(" ")),
             "HTML_OUTPUT = doc/c",
             // Output customization
             "GENERATE_LATEX = NO",                          // No LaTeX
@@ -196,7 +233,7 @@ fn generate_cmake_config(
     logged::create_dir_all(&cmake_path)?;
 
     let filename = cmake_path.join(format!("{}-config.cmake", lib.settings.name));
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::C))?;
 
     // Prefix used everywhere else
     f.writeln("set(prefix \"${CMAKE_CURRENT_LIST_DIR}/..\")")?;
@@ -2,11 +2,15 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
+use crate::model::Naming;
+
 pub(crate) type FormattingResult<T> = Result<T, Box<dyn std::error::Error>>;
 
 pub(crate) trait Printer {
     fn write(&mut self, s: &str) -> FormattingResult<()>;
     fn newline(&mut self) -> FormattingResult<()>;
+    /// Spelling of the names in the printed language
+    fn naming(&self) -> &Naming;
 
     fn writeln(&mut self, s: &str) -> FormattingResult<()> {
         self.newline()?;
@@ -17,16 +21,18 @@ pub(crate) trait Printer {
 pub(crate) struct FilePrinter {
     writer: BufWriter<File>,
     first_newline: bool,
+    naming: Naming,
 }
 
 impl FilePrinter {
-    pub(crate) fn new<T: AsRef<Path>>(filepath: T) -> FormattingResult<Self> {
+    pub(crate) fn new<T: AsRef<Path>>(filepath: T, naming: Naming) -> FormattingResult<Self> {
         tracing::info!("Create file: {}", filepath.as_ref().display());
         let file = File::create(filepath)?;
         let writer = BufWriter::new(file);
         Ok(Self {
             writer,
             first_newline: false,
+            naming,
         })
     }
 }
@@ -54,6 +60,10 @@ impl Printer for FilePrinter {
             writeln!(self.writer).map_err(|e| e.into())
         }
     }
+
+    fn naming(&self) -> &Naming {
+        &self.naming
+    }
 }
 
 pub(crate) struct PrefixPrinter<'a, 'b> {
@@ -79,6 +89,10 @@ impl<'a, 'b> Printer for PrefixPrinter<'a, 'b> {
         self.inner.newline()?;
         self.inner.write(self.prefix)
     }
+
+    fn naming(&self) -> &Naming {
+        self.inner.naming()
+    }
 }
 
 pub(crate) struct IndentedPrinter<'a> {
@@ -101,6 +115,10 @@ impl<'a> Printer for IndentedPrinter<'a> {
     fn newline(&mut self) -> FormattingResult<()> {
         self.inner.newline()
     }
+
+    fn naming(&self) -> &Naming {
+        self.inner.naming()
+    }
 }
 
 pub(crate) struct CommentedPrinter<'a> {
@@ -123,6 +141,10 @@ impl<'a> Printer for CommentedPrinter<'a> {
     fn newline(&mut self) -> FormattingResult<()> {
         self.inner.newline()
     }
+
+    fn naming(&self) -> &Naming {
+        self.inner.naming()
+    }
 }
 
 pub(crate) struct DoxygenPrinter<'a> {
@@ -145,6 +167,10 @@ impl<'a> Printer for DoxygenPrinter<'a> {
     fn newline(&mut self) -> FormattingResult<()> {
         self.inner.newline()
     }
+
+    fn naming(&self) -> &Naming {
+        self.inner.naming()
+    }
 }
 
 pub(crate) fn indented<F, T>(f: &mut dyn Printer, cb: F) -> FormattingResult<T>
@@ -8,15 +8,31 @@ pub(crate) fn copy<P: AsRef<std::path::Path>, Q: AsRef<std::path::Path>>(
         from.as_ref().display(),
         to.as_ref().display()
     );
-    std::fs::copy(from, to)
+    std::fs::copy(&from, &to).map_err(|err| {
+        with_context(
+            err,
+            format!(
+                "Unable to copy {} to {}",
+                from.as_ref().display(),
+                to.as_ref().display()
+            ),
+        )
+    })
 }
 
 pub(crate) fn create_dir_all<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {
     tracing::info!("Create dir: {}", path.as_ref().display());
-    std::fs::create_dir_all(path)
+    std::fs::create_dir_all(&path)
+        .map_err(|err| with_context(err, format!("Unable to create {}", path.as_ref().display())))
 }
 
 pub(crate) fn remove_dir_all<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {
     tracing::info!("Remove dir: {}", path.as_ref().display());
-    std::fs::remove_dir_all(path)
+    std::fs::remove_dir_all(&path)
+        .map_err(|err| with_context(err, format!("Unable to remove {}", path.as_ref().display())))
+}
+
+/// Name the files of an error, which the errors of the standard library omit
+fn with_context(err: std::io::Error, context: String) -> std::io::Error {
+    std::io::Error::new(err.kind(), format!("{}: {}", context, err))
 }
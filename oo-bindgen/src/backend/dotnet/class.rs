@@ -1,18 +1,21 @@
-use super::helpers::call_native_function;
+use super::helpers::{call_native_function, dotnet_parameter};
 use super::*;
 
 pub(crate) fn generate(
     f: &mut dyn Printer,
     class: &Handle<Class<Validated>>,
     lib: &Library,
+    framework: TargetFramework,
 ) -> FormattingResult<()> {
-    let classname = class.name().camel_case();
+    let classname = class.name().camel_case(f.naming());
 
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_obsolete_pragma(f, lib)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
-    namespaced(f, &lib.settings.name, |f| {
+    let namespace = dotnet_namespace(lib, &class.declaration.module, f.naming());
+    namespaced(f, &namespace, |f| {
         documentation(f, |f| {
             // Print top-level documentation
             xmldoc_print(f, &class.doc)
@@ -49,7 +52,7 @@ pub(crate) fn generate(
             f.newline()?;
 
             if let Some(constructor) = &class.constructor {
-                generate_constructor(f, &classname, constructor)?;
+                generate_constructor(f, &classname, constructor, framework)?;
                 f.newline()?;
             }
 
@@ -59,17 +62,17 @@ pub(crate) fn generate(
             }
 
             for method in &class.methods {
-                generate_method(f, method)?;
+                generate_method(f, method, framework)?;
                 f.newline()?;
             }
 
             for method in &class.future_methods {
-                generate_async_method(f, method)?;
+                generate_async_method(f, method, framework)?;
                 f.newline()?;
             }
 
             for method in &class.static_methods {
-                generate_static_method(f, method)?;
+                generate_static_method(f, method, framework)?;
                 f.newline()?;
             }
 
@@ -82,14 +85,17 @@ pub(crate) fn generate_static(
     f: &mut dyn Printer,
     class: &Handle<StaticClass<Validated>>,
     lib: &Library,
+    framework: TargetFramework,
 ) -> FormattingResult<()> {
-    let classname = class.name.camel_case();
+    let classname = class.name.camel_case(f.naming());
 
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_obsolete_pragma(f, lib)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
-    namespaced(f, &lib.settings.name, |f| {
+    let namespace = dotnet_namespace(lib, &class.module, f.naming());
+    namespaced(f, &namespace, |f| {
         documentation(f, |f| {
             // Print top-level documentation
             xmldoc_print(f, &class.doc)
@@ -99,7 +105,7 @@ pub(crate) fn generate_static(
 
         blocked(f, |f| {
             for method in &class.static_methods {
-                generate_static_method(f, method)?;
+                generate_static_method(f, method, framework)?;
                 f.newline()?;
             }
 
@@ -112,6 +118,7 @@ fn generate_constructor(
     f: &mut dyn Printer,
     classname: &str,
     constructor: &ClassConstructor<Validated>,
+    framework: TargetFramework,
 ) -> FormattingResult<()> {
     documentation(f, |f| {
         // Print top-level documentation
@@ -120,7 +127,10 @@ fn generate_constructor(
 
         // Print each parameter value
         for param in &constructor.function.arguments {
-            f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
+            f.writeln(&format!(
+                "<param name=\"{}\">",
+                param.name.unescaped_mixed_case(f.naming())
+            ))?;
             docstring_print(f, &param.doc)?;
             f.write("</param>")?;
         }
@@ -129,12 +139,13 @@ fn generate_constructor(
         if let Some(error) = &constructor.function.error_type.get() {
             f.writeln(&format!(
                 "<exception cref=\"{}\"></exception>",
-                error.exception_name.camel_case()
+                error.exception_name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    obsolete_attribute(f, &constructor.function.doc)?;
 
     f.writeln(&format!("public {}(", classname))?;
     f.write(
@@ -142,13 +153,7 @@ fn generate_constructor(
             .function
             .arguments
             .iter()
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.get_dotnet_type(),
-                    param.name.mixed_case()
-                )
-            })
+            .map(|x| dotnet_parameter(x, framework, f.naming()))
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -170,7 +175,7 @@ fn generate_destructor(
         documentation(f, |f| xmldoc_print(f, &destructor.function.doc))?;
 
         let method_name = if let DestructionMode::Custom(name) = destruction_mode {
-            name.camel_case()
+            name.camel_case(f.naming())
         } else {
             "Dispose".to_string()
         };
@@ -204,14 +209,18 @@ fn generate_destructor(
         f.writeln(&format!(
             "{}.{}(this.self);",
             NATIVE_FUNCTIONS_CLASSNAME,
-            destructor.function.name.camel_case()
+            destructor.function.name.camel_case(f.naming())
         ))?;
         f.newline()?;
         f.writeln("this.disposed = true;")
     })
 }
 
-fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> FormattingResult<()> {
+fn generate_method(
+    f: &mut dyn Printer,
+    method: &Method<Validated>,
+    framework: TargetFramework,
+) -> FormattingResult<()> {
     documentation(f, |f| {
         // Print top-level documentation
         xmldoc_print(f, &method.native_function.doc)?;
@@ -219,7 +228,10 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
 
         // Print each parameter value
         for param in method.native_function.arguments.iter().skip(1) {
-            f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
+            f.writeln(&format!(
+                "<param name=\"{}\">",
+                param.name.unescaped_mixed_case(f.naming())
+            ))?;
             docstring_print(f, &param.doc)?;
             f.write("</param>")?;
         }
@@ -235,17 +247,21 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
         if let Some(error) = &method.native_function.error_type.get() {
             f.writeln(&format!(
                 "<exception cref=\"{}\"></exception>",
-                error.exception_name.camel_case()
+                error.exception_name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    obsolete_attribute(f, &method.native_function.doc)?;
 
     f.writeln(&format!(
         "public {} {}(",
-        method.native_function.return_type.get_dotnet_type(),
-        method.name.camel_case()
+        method
+            .native_function
+            .return_type
+            .get_dotnet_type(f.naming()),
+        method.name.camel_case(f.naming())
     ))?;
     f.write(
         &method
@@ -253,13 +269,7 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
             .arguments
             .iter()
             .skip(1)
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.get_dotnet_type(),
-                    param.name.mixed_case()
-                )
-            })
+            .map(|x| dotnet_parameter(x, framework, f.naming()))
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -279,6 +289,7 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
 fn generate_static_method(
     f: &mut dyn Printer,
     method: &StaticMethod<Validated>,
+    framework: TargetFramework,
 ) -> FormattingResult<()> {
     documentation(f, |f| {
         // Print top-level documentation
@@ -287,7 +298,10 @@ fn generate_static_method(
 
         // Print each parameter value
         for param in &method.native_function.arguments {
-            f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
+            f.writeln(&format!(
+                "<param name=\"{}\">",
+                param.name.unescaped_mixed_case(f.naming())
+            ))?;
             docstring_print(f, &param.doc)?;
             f.write("</param>")?;
         }
@@ -303,30 +317,28 @@ fn generate_static_method(
         if let Some(error) = &method.native_function.error_type.get() {
             f.writeln(&format!(
                 "<exception cref=\"{}\"></exception>",
-                error.exception_name.camel_case()
+                error.exception_name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    obsolete_attribute(f, &method.native_function.doc)?;
 
     f.writeln(&format!(
         "public static {} {}(",
-        method.native_function.return_type.get_dotnet_type(),
-        method.name.camel_case()
+        method
+            .native_function
+            .return_type
+            .get_dotnet_type(f.naming()),
+        method.name.camel_case(f.naming())
     ))?;
     f.write(
         &method
             .native_function
             .arguments
             .iter()
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.get_dotnet_type(),
-                    param.name.mixed_case()
-                )
-            })
+            .map(|x| dotnet_parameter(x, framework, f.naming()))
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -340,8 +352,9 @@ fn generate_static_method(
 fn generate_async_method(
     f: &mut dyn Printer,
     method: &FutureMethod<Validated>,
+    framework: TargetFramework,
 ) -> FormattingResult<()> {
-    let callback_success_type = method.future.value_type.get_dotnet_type();
+    let callback_success_type = method.future.value_type.get_dotnet_type(f.naming());
 
     // Documentation
     documentation(f, |f| {
@@ -351,7 +364,10 @@ fn generate_async_method(
 
         // Print each parameter value
         for param in method.arguments_without_callback() {
-            f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
+            f.writeln(&format!(
+                "<param name=\"{}\">",
+                param.name.unescaped_mixed_case(f.naming())
+            ))?;
             docstring_print(f, &param.doc)?;
             f.write("</param>")?;
         }
@@ -365,28 +381,23 @@ fn generate_async_method(
         if let Some(error) = &method.native_function.error_type.get() {
             f.writeln(&format!(
                 "<exception cref=\"{}\"></exception>",
-                error.exception_name.camel_case()
+                error.exception_name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    obsolete_attribute(f, &method.native_function.doc)?;
 
     f.writeln(&format!(
         "public Task<{}> {}(",
         callback_success_type,
-        method.name.camel_case()
+        method.name.camel_case(f.naming())
     ))?;
     f.write(
         &method
             .arguments_without_callback()
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.get_dotnet_type(),
-                    param.name.mixed_case()
-                )
-            })
+            .map(|x| dotnet_parameter(x, framework, f.naming()))
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -401,7 +412,7 @@ fn generate_async_method(
         ))?;
         f.writeln(&format!(
             "var callback = new {}({});",
-            method.future.interface.name.camel_case(),
+            method.future.interface.name.camel_case(f.naming()),
             tcs_var_name
         ))?;
         call_native_function(
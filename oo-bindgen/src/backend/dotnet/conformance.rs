@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use crate::backend::dotnet::formatting::string_literal;
+use crate::backend::dotnet::module_namespaces;
+use crate::backend::*;
+use crate::conformance::{echoed_struct, leaf_fields, values, Case, Method, Suite, Value};
+use crate::model::*;
+
+/// Write the conformance tests of the .NET bindings as the `ConformanceTest` xUnit class
+pub(crate) fn generate(lib: &Library, suite: &Suite, path: &Path) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::DotNet);
+    let mut f = FilePrinter::new(path, naming.clone())?;
+    let class = suite.class.name.camel_case(naming);
+
+    f.writeln(&format!(
+        "// Conformance tests of the {} .NET bindings generated by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.writeln("using System;")?;
+    f.writeln("using System.Collections.Generic;")?;
+    f.writeln("using Xunit;")?;
+    f.writeln(&format!("using {};", lib.settings.name))?;
+    for namespace in module_namespaces(lib, naming) {
+        f.writeln(&format!("using {};", namespace))?;
+    }
+    f.newline()?;
+    f.writeln(&format!("namespace {}.Tests", lib.settings.name))?;
+    blocked(&mut f, |f| {
+        f.writeln("public class ConformanceTest")?;
+        blocked(f, |f| {
+            for (index, case) in suite.cases.iter().enumerate() {
+                if index > 0 {
+                    f.newline()?;
+                }
+                f.writeln("[Fact]")?;
+                match case {
+                    Case::Echo { method, value_type } => {
+                        f.writeln(&format!("public void {}()", method.name.camel_case(naming)))?;
+                        blocked(f, |f| write_echo(f, &class, &method.name, value_type))?;
+                    }
+                    Case::Raise { method, error } => {
+                        f.writeln(&format!("public void {}()", method.name.camel_case(naming)))?;
+                        blocked(f, |f| write_raise(f, &class, method, error))?;
+                    }
+                    Case::List {
+                        collection,
+                        size,
+                        get,
+                    } => {
+                        f.writeln(&format!(
+                            "public void {}()",
+                            collection.name().camel_case(naming)
+                        ))?;
+                        blocked(f, |f| {
+                            write_list(f, &class, &collection.item_type, size, get)
+                        })?;
+                    }
+                    Case::Iterate {
+                        method, collection, ..
+                    } => {
+                        f.writeln(&format!("public void {}()", method.name.camel_case(naming)))?;
+                        blocked(f, |f| {
+                            write_iterate(f, &class, &method.name, &collection.item_type)
+                        })?;
+                    }
+                }
+            }
+            Ok(())
+        })
+    })
+}
+
+fn write_echo(
+    f: &mut dyn Printer,
+    class: &str,
+    method: &Name,
+    value_type: &FunctionArgument,
+) -> FormattingResult<()> {
+    if let Some(handle) = echoed_struct(value_type) {
+        f.writeln(&format!(
+            "var value = new {}();",
+            handle.name().camel_case(f.naming())
+        ))?;
+        f.writeln(&format!(
+            "var result = {}.{}(value);",
+            class,
+            method.camel_case(f.naming())
+        ))?;
+        for path in leaf_fields(handle) {
+            let field = path
+                .iter()
+                .map(|x| x.camel_case(f.naming()))
+                .collect::<Vec<_>>()
+                .join(".");
+            f.writeln(&format!("Assert.Equal(value.{}, result.{});", field, field))?;
+        }
+        return Ok(());
+    }
+
+    for value in values(value_type) {
+        let value = render(value_type, &value, f.naming())?;
+        f.writeln(&format!(
+            "Assert.Equal({}, {}.{}({}));",
+            value,
+            class,
+            method.camel_case(f.naming()),
+            value
+        ))?;
+    }
+    Ok(())
+}
+
+fn write_raise(
+    f: &mut dyn Printer,
+    class: &str,
+    method: &Method,
+    error: &ErrorType<Validated>,
+) -> FormattingResult<()> {
+    let mut variants = error.inner.variants.iter();
+    // the first variant is the absence of error
+    if let Some(ok) = variants.next() {
+        f.writeln(&format!(
+            "{}.{}({});",
+            class,
+            method.name.camel_case(f.naming()),
+            ok.value
+        ))?;
+    }
+    for variant in variants {
+        f.writeln(&format!(
+            "Assert.Equal({}.{}, Assert.Throws<{}>(() => {}.{}({})).error);",
+            error.inner.name.camel_case(f.naming()),
+            variant.name.camel_case(f.naming()),
+            error.exception_name.camel_case(f.naming()),
+            class,
+            method.name.camel_case(f.naming()),
+            variant.value
+        ))?;
+    }
+    Ok(())
+}
+
+fn write_list(
+    f: &mut dyn Printer,
+    class: &str,
+    item_type: &FunctionArgument,
+    size: &Method,
+    get: &Method,
+) -> FormattingResult<()> {
+    let values = write_array(f, item_type)?;
+    f.writeln(&format!(
+        "Assert.Equal({}u, {}.{}(list));",
+        values.len(),
+        class,
+        size.name.camel_case(f.naming())
+    ))?;
+    for (index, value) in values.iter().enumerate() {
+        f.writeln(&format!(
+            "Assert.Equal({}, {}.{}(list, {}));",
+            value,
+            class,
+            get.name.camel_case(f.naming()),
+            index
+        ))?;
+    }
+    Ok(())
+}
+
+fn write_iterate(
+    f: &mut dyn Printer,
+    class: &str,
+    method: &Name,
+    item_type: &FunctionArgument,
+) -> FormattingResult<()> {
+    write_array(f, item_type)?;
+    f.writeln(&format!(
+        "var received = new List<{}>();",
+        dotnet_type(item_type)
+    ))?;
+    f.writeln(&format!(
+        "{}.{}(list, values => received.AddRange(values));",
+        class,
+        method.camel_case(f.naming())
+    ))?;
+    f.writeln("Assert.Equal(list, received);")
+}
+
+/// Write a `list` array holding the values exercising the edges of the item type
+fn write_array(f: &mut dyn Printer, item_type: &FunctionArgument) -> FormattingResult<Vec<String>> {
+    let values = values(item_type)
+        .iter()
+        .map(|x| render(item_type, x, f.naming()))
+        .collect::<FormattingResult<Vec<_>>>()?;
+
+    // an array converts to both a collection and a span
+    f.writeln(&format!(
+        "var list = new {}[] {{ {} }};",
+        dotnet_type(item_type),
+        values.join(", ")
+    ))?;
+    Ok(values)
+}
+
+fn dotnet_type(item_type: &FunctionArgument) -> &'static str {
+    match item_type {
+        FunctionArgument::Basic(BasicType::Primitive(x)) => primitive_type(*x),
+        _ => "string",
+    }
+}
+
+fn primitive_type(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::U8 => "byte",
+        Primitive::S8 => "sbyte",
+        Primitive::U16 => "ushort",
+        Primitive::S16 => "short",
+        Primitive::U32 => "uint",
+        Primitive::S32 => "int",
+        Primitive::U64 => "ulong",
+        Primitive::S64 => "long",
+        Primitive::Float => "float",
+        Primitive::Double => "double",
+    }
+}
+
+fn render(
+    value_type: &FunctionArgument,
+    value: &Value,
+    naming: &Naming,
+) -> FormattingResult<String> {
+    let rendered = match (value_type, value) {
+        (_, Value::Bool(x)) => x.to_string(),
+        (FunctionArgument::Basic(BasicType::Primitive(x)), value) => {
+            let dotnet_type = primitive_type(*x);
+            match value {
+                Value::Min => format!("{}.MinValue", dotnet_type),
+                Value::Max => format!("{}.MaxValue", dotnet_type),
+                _ => format!("({})0", dotnet_type),
+            }
+        }
+        (FunctionArgument::Basic(BasicType::Duration(x)), Value::Duration(value)) => match x {
+            DurationType::Milliseconds => format!("TimeSpan.FromMilliseconds({})", value),
+            DurationType::Seconds => format!("TimeSpan.FromSeconds({})", value),
+        },
+        (_, Value::Text(x)) => string_literal(x),
+        (FunctionArgument::Basic(BasicType::Enum(x)), Value::Variant(variant)) => {
+            format!(
+                "{}.{}",
+                x.name.camel_case(naming),
+                variant.camel_case(naming)
+            )
+        }
+        _ => return Err("conformance value does not match its type".into()),
+    };
+    Ok(rendered)
+}
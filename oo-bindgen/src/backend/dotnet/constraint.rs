@@ -0,0 +1,77 @@
+use crate::backend::*;
+use crate::model::*;
+
+fn number_literal(value: &NumberValue) -> String {
+    match value {
+        NumberValue::U32(x) => format!("{}U", x),
+        NumberValue::U64(x) => format!("{}UL", x),
+        NumberValue::S64(x) => format!("{}L", x),
+        NumberValue::Float(x) => format!("{:?}F", x),
+        NumberValue::Double(x) => format!("{:?}", x),
+        x => x.to_string(),
+    }
+}
+
+/// Write a check that throws an `ArgumentException` if `value` violates the constraint
+pub(crate) fn write_constraint_check(
+    f: &mut dyn Printer,
+    constraint: &Constraint,
+    target: ConstrainedType,
+    value: &str,
+    param_name: &str,
+) -> FormattingResult<()> {
+    let condition = match constraint {
+        Constraint::Range { .. } => {
+            let conditions: Vec<String> = constraint
+                .lower_bound()
+                .map(|min| format!("{} < {}", value, number_literal(&min)))
+                .into_iter()
+                .chain(
+                    constraint
+                        .upper_bound()
+                        .map(|max| format!("{} > {}", value, number_literal(&max))),
+                )
+                .collect();
+            if conditions.is_empty() {
+                // the range covers every value of the type
+                return Ok(());
+            }
+            conditions.join(" || ")
+        }
+        Constraint::NonZero => match target {
+            // check the value that actually crosses the FFI boundary
+            ConstrainedType::Duration(DurationType::Milliseconds) => {
+                format!("(ulong){}.TotalMilliseconds == 0", value)
+            }
+            ConstrainedType::Duration(DurationType::Seconds) => {
+                format!("(ulong){}.TotalSeconds == 0", value)
+            }
+            _ => format!("{} == 0", value),
+        },
+        Constraint::NonEmpty => format!("{}.Length == 0", value),
+        Constraint::MaxLength(x) => {
+            format!("System.Text.Encoding.UTF8.GetByteCount({}) > {}", value, x)
+        }
+        Constraint::Regex(_) => format!(
+            "!System.Text.RegularExpressions.Regex.IsMatch({}, \"{}\")",
+            value,
+            escape_string_literal(&constraint.anchored_regex().unwrap())
+        ),
+    };
+
+    let exception = match constraint {
+        Constraint::Range { .. } => format!(
+            "ArgumentOutOfRangeException(\"{}\", \"{}\")",
+            param_name,
+            constraint.violation_message(param_name)
+        ),
+        _ => format!(
+            "ArgumentException(\"{}\", \"{}\")",
+            constraint.violation_message(param_name),
+            param_name
+        ),
+    };
+
+    f.writeln(&format!("if ({})", condition))?;
+    blocked(f, |f| f.writeln(&format!("throw new {};", exception)))
+}
@@ -3,11 +3,11 @@ use crate::model::*;
 
 pub(crate) trait ConvertToDotNet {
     /// optional conversion from native type to .NET type
-    fn convert_to_dotnet(&self, from: &str) -> Option<String>;
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String>;
 }
 
 impl ConvertToDotNet for DurationType {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, _naming: &Naming) -> Option<String> {
         match self {
             Self::Milliseconds => Some(format!("TimeSpan.FromMilliseconds({})", from)),
             Self::Seconds => Some(format!("TimeSpan.FromSeconds({})", from)),
@@ -16,7 +16,7 @@ impl ConvertToDotNet for DurationType {
 }
 
 impl ConvertToDotNet for Primitive {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, _naming: &Naming) -> Option<String> {
         match self {
             Self::Bool => Some(format!("Convert.ToBoolean({})", from)),
             Self::U8 => None,
@@ -37,23 +37,23 @@ impl<D> ConvertToDotNet for Handle<Enum<D>>
 where
     D: DocReference,
 {
-    fn convert_to_dotnet(&self, _: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, _: &str, _naming: &Naming) -> Option<String> {
         None
     }
 }
 
 impl ConvertToDotNet for BasicType {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Primitive(x) => x.convert_to_dotnet(from),
-            Self::Duration(x) => x.convert_to_dotnet(from),
-            Self::Enum(x) => x.convert_to_dotnet(from),
+            Self::Primitive(x) => x.convert_to_dotnet(from, naming),
+            Self::Duration(x) => x.convert_to_dotnet(from, naming),
+            Self::Enum(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for StringType {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, _naming: &Naming) -> Option<String> {
         Some(format!("Helpers.RustString.FromNative({})", from))
     }
 }
@@ -62,19 +62,26 @@ impl<D> ConvertToDotNet for Handle<Interface<D>>
 where
     D: DocReference,
 {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "I{}NativeAdapter.FromNative({}.{})",
-            self.name.camel_case(),
+            self.name.camel_case(naming),
             from,
-            self.settings.interface.context_variable_name.mixed_case()
+            self.settings
+                .interface
+                .context_variable_name
+                .mixed_case(naming)
         ))
     }
 }
 
 impl ConvertToDotNet for ClassDeclarationHandle {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
-        Some(format!("{}.FromNative({})", self.name.camel_case(), from))
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
+        Some(format!(
+            "{}.FromNative({})",
+            self.name.camel_case(naming),
+            from
+        ))
     }
 }
 
@@ -82,10 +89,10 @@ impl<D> ConvertToDotNet for Handle<Collection<D>>
 where
     D: DocReference + TypeInfo,
 {
-    fn convert_to_dotnet(&self, _from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, _from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "System.Collections.Immutable.ImmutableArray<{}>.Empty",
-            self.item_type.get_dotnet_type()
+            self.item_type.get_dotnet_type(naming)
         ))
     }
 }
@@ -94,77 +101,77 @@ impl<D> ConvertToDotNet for Handle<AbstractIterator<D>>
 where
     D: DocReference,
 {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Helpers.FromNative({})",
-            self.iter_class.name.camel_case(),
+            self.iter_class.name.camel_case(naming),
             from
         ))
     }
 }
 
 impl ConvertToDotNet for UniversalOr<FunctionReturnStructField> {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalOr::Specific(x) => x.convert_to_dotnet(from),
-            UniversalOr::Universal(x) => x.convert_to_dotnet(from),
+            UniversalOr::Specific(x) => x.convert_to_dotnet(from, naming),
+            UniversalOr::Universal(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for UniversalOr<CallbackArgStructField> {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalOr::Specific(x) => x.convert_to_dotnet(from),
-            UniversalOr::Universal(x) => x.convert_to_dotnet(from),
+            UniversalOr::Specific(x) => x.convert_to_dotnet(from, naming),
+            UniversalOr::Universal(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for FunctionReturnStructField {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Basic(x) => x.convert_to_dotnet(from),
-            Self::ClassRef(x) => x.convert_to_dotnet(from),
-            Self::Struct(x) => x.convert_to_dotnet(from),
-            Self::Iterator(x) => x.convert_to_dotnet(from),
+            Self::Basic(x) => x.convert_to_dotnet(from, naming),
+            Self::ClassRef(x) => x.convert_to_dotnet(from, naming),
+            Self::Struct(x) => x.convert_to_dotnet(from, naming),
+            Self::Iterator(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for CallbackArgStructField {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            CallbackArgStructField::Basic(x) => x.convert_to_dotnet(from),
-            CallbackArgStructField::Iterator(x) => x.convert_to_dotnet(from),
-            CallbackArgStructField::Struct(x) => x.convert_to_dotnet(from),
+            CallbackArgStructField::Basic(x) => x.convert_to_dotnet(from, naming),
+            CallbackArgStructField::Iterator(x) => x.convert_to_dotnet(from, naming),
+            CallbackArgStructField::Struct(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for UniversalStructField {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalStructField::Basic(x) => x.convert_to_dotnet(from),
-            UniversalStructField::Struct(x) => x.convert_to_dotnet(from),
+            UniversalStructField::Basic(x) => x.convert_to_dotnet(from, naming),
+            UniversalStructField::Struct(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for CallbackArgument {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Basic(x) => x.convert_to_dotnet(from),
-            Self::String(x) => x.convert_to_dotnet(from),
-            Self::Iterator(x) => x.convert_to_dotnet(from),
-            Self::Struct(x) => x.convert_to_dotnet(from),
-            Self::Class(x) => x.convert_to_dotnet(from),
+            Self::Basic(x) => x.convert_to_dotnet(from, naming),
+            Self::String(x) => x.convert_to_dotnet(from, naming),
+            Self::Iterator(x) => x.convert_to_dotnet(from, naming),
+            Self::Struct(x) => x.convert_to_dotnet(from, naming),
+            Self::Class(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for PrimitiveRef {
-    fn convert_to_dotnet(&self, expr: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, expr: &str, _naming: &Naming) -> Option<String> {
         match self.inner {
             Primitive::Bool => Some(format!("Helpers.PrimitivePointer.ReadBool({})", expr)),
             Primitive::U8 => Some(format!(
@@ -203,23 +210,23 @@ impl ConvertToDotNet for PrimitiveRef {
 }
 
 impl ConvertToDotNet for FunctionReturnValue {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Basic(x) => x.convert_to_dotnet(from),
-            Self::String(x) => x.convert_to_dotnet(from),
-            Self::ClassRef(x) => x.convert_to_dotnet(from),
-            Self::Struct(x) => x.convert_to_dotnet(from),
-            Self::StructRef(x) => x.untyped().convert_to_dotnet(from),
-            Self::PrimitiveRef(x) => x.convert_to_dotnet(from),
+            Self::Basic(x) => x.convert_to_dotnet(from, naming),
+            Self::String(x) => x.convert_to_dotnet(from, naming),
+            Self::ClassRef(x) => x.convert_to_dotnet(from, naming),
+            Self::Struct(x) => x.convert_to_dotnet(from, naming),
+            Self::StructRef(x) => x.untyped().convert_to_dotnet(from, naming),
+            Self::PrimitiveRef(x) => x.convert_to_dotnet(from, naming),
         }
     }
 }
 
 impl ConvertToDotNet for StructDeclarationHandle {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Native.FromNativeRef({})",
-            self.name.camel_case(),
+            self.name.camel_case(naming),
             from
         ))
     }
@@ -230,10 +237,10 @@ where
     D: DocReference,
     T: StructFieldType,
 {
-    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+    fn convert_to_dotnet(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Native.FromNative({})",
-            self.name().camel_case(),
+            self.name().camel_case(naming),
             from
         ))
     }
@@ -3,26 +3,26 @@ use crate::model::*;
 /// Conversion from .NET types to native types
 pub(crate) trait ConvertToNative {
     /// Convert the .NET type to a native type that may require cleanup
-    fn convert_to_native(&self, from: &str) -> Option<String>;
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String>;
     /// Cleanup the native type
-    fn cleanup_native(&self, from: &str) -> Option<String>;
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String>;
 }
 
 impl ConvertToNative for DurationType {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, _naming: &Naming) -> Option<String> {
         match self {
             Self::Milliseconds => Some(format!("(ulong){}.TotalMilliseconds", from)),
             Self::Seconds => Some(format!("(ulong){}.TotalSeconds", from)),
         }
     }
 
-    fn cleanup_native(&self, _from: &str) -> Option<String> {
+    fn cleanup_native(&self, _from: &str, _naming: &Naming) -> Option<String> {
         None
     }
 }
 
 impl ConvertToNative for Primitive {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, _naming: &Naming) -> Option<String> {
         match self {
             Self::Bool => Some(format!("Convert.ToByte({})", from)),
             Self::U8 => None,
@@ -38,7 +38,7 @@ impl ConvertToNative for Primitive {
         }
     }
 
-    fn cleanup_native(&self, _from: &str) -> Option<String> {
+    fn cleanup_native(&self, _from: &str, _naming: &Naming) -> Option<String> {
         match self {
             Self::Bool => None,
             Self::U8 => None,
@@ -59,39 +59,39 @@ impl<D> ConvertToNative for Handle<Enum<D>>
 where
     D: DocReference,
 {
-    fn convert_to_native(&self, _: &str) -> Option<String> {
+    fn convert_to_native(&self, _: &str, _naming: &Naming) -> Option<String> {
         None
     }
 
-    fn cleanup_native(&self, _: &str) -> Option<String> {
+    fn cleanup_native(&self, _: &str, _naming: &Naming) -> Option<String> {
         None
     }
 }
 
 impl ConvertToNative for BasicType {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Primitive(x) => x.convert_to_native(from),
-            Self::Duration(x) => x.convert_to_native(from),
-            Self::Enum(x) => x.convert_to_native(from),
+            Self::Primitive(x) => x.convert_to_native(from, naming),
+            Self::Duration(x) => x.convert_to_native(from, naming),
+            Self::Enum(x) => x.convert_to_native(from, naming),
         }
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Primitive(x) => x.cleanup_native(from),
-            Self::Duration(x) => x.cleanup_native(from),
-            Self::Enum(x) => x.cleanup_native(from),
+            Self::Primitive(x) => x.cleanup_native(from, naming),
+            Self::Duration(x) => x.cleanup_native(from, naming),
+            Self::Enum(x) => x.cleanup_native(from, naming),
         }
     }
 }
 
 impl ConvertToNative for StringType {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, _naming: &Naming) -> Option<String> {
         Some(format!("Helpers.RustString.ToNative({})", from))
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, _naming: &Naming) -> Option<String> {
         Some(format!("Helpers.RustString.Destroy({});", from))
     }
 }
@@ -100,8 +100,8 @@ impl<D> ConvertToNative for Handle<Interface<D>>
 where
     D: DocReference,
 {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
-        let name = self.name.camel_case();
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
+        let name = self.name.camel_case(naming);
         let inner_transform = if let Some(cb) = self.get_functional_callback() {
             match self.mode {
                 InterfaceCategory::Synchronous | InterfaceCategory::Asynchronous => {
@@ -122,17 +122,17 @@ where
         Some(format!("new I{}NativeAdapter({})", name, inner_transform))
     }
 
-    fn cleanup_native(&self, _from: &str) -> Option<String> {
+    fn cleanup_native(&self, _from: &str, _naming: &Naming) -> Option<String> {
         None
     }
 }
 
 impl ConvertToNative for ClassDeclarationHandle {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, _naming: &Naming) -> Option<String> {
         Some(format!("{}.self", from))
     }
 
-    fn cleanup_native(&self, _: &str) -> Option<String> {
+    fn cleanup_native(&self, _: &str, _naming: &Naming) -> Option<String> {
         None
     }
 }
@@ -141,130 +141,130 @@ impl<D> ConvertToNative for Handle<Collection<D>>
 where
     D: DocReference,
 {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Helpers.ToNative({})",
-            self.collection_class.name.camel_case(),
+            self.collection_class.name.camel_case(naming),
             from
         ))
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Helpers.Cleanup({});",
-            self.collection_class.name.camel_case(),
+            self.collection_class.name.camel_case(naming),
             from
         ))
     }
 }
 
 impl ConvertToNative for FunctionArgStructField {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgStructField::Basic(x) => x.convert_to_native(from),
-            FunctionArgStructField::String(x) => x.convert_to_native(from),
-            FunctionArgStructField::Interface(x) => x.inner.convert_to_native(from),
-            FunctionArgStructField::Struct(x) => x.convert_to_native(from),
+            FunctionArgStructField::Basic(x) => x.convert_to_native(from, naming),
+            FunctionArgStructField::String(x) => x.convert_to_native(from, naming),
+            FunctionArgStructField::Interface(x) => x.inner.convert_to_native(from, naming),
+            FunctionArgStructField::Struct(x) => x.convert_to_native(from, naming),
         }
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgStructField::Basic(x) => x.cleanup_native(from),
-            FunctionArgStructField::String(x) => x.cleanup_native(from),
-            FunctionArgStructField::Interface(x) => x.inner.cleanup_native(from),
-            FunctionArgStructField::Struct(x) => x.cleanup_native(from),
+            FunctionArgStructField::Basic(x) => x.cleanup_native(from, naming),
+            FunctionArgStructField::String(x) => x.cleanup_native(from, naming),
+            FunctionArgStructField::Interface(x) => x.inner.cleanup_native(from, naming),
+            FunctionArgStructField::Struct(x) => x.cleanup_native(from, naming),
         }
     }
 }
 
 impl ConvertToNative for UniversalStructField {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalStructField::Basic(x) => x.convert_to_native(from),
-            UniversalStructField::Struct(x) => x.convert_to_native(from),
+            UniversalStructField::Basic(x) => x.convert_to_native(from, naming),
+            UniversalStructField::Struct(x) => x.convert_to_native(from, naming),
         }
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalStructField::Basic(x) => x.cleanup_native(from),
-            UniversalStructField::Struct(x) => x.cleanup_native(from),
+            UniversalStructField::Basic(x) => x.cleanup_native(from, naming),
+            UniversalStructField::Struct(x) => x.cleanup_native(from, naming),
         }
     }
 }
 
 impl ConvertToNative for FunctionArgument {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgument::Basic(x) => x.convert_to_native(from),
-            FunctionArgument::String(x) => x.convert_to_native(from),
-            FunctionArgument::Collection(x) => x.convert_to_native(from),
-            FunctionArgument::Struct(x) => x.convert_to_native(from),
-            FunctionArgument::StructRef(x) => x.inner.convert_to_native(from),
-            FunctionArgument::ClassRef(x) => x.convert_to_native(from),
-            FunctionArgument::Interface(x) => x.convert_to_native(from),
+            FunctionArgument::Basic(x) => x.convert_to_native(from, naming),
+            FunctionArgument::String(x) => x.convert_to_native(from, naming),
+            FunctionArgument::Collection(x) => x.convert_to_native(from, naming),
+            FunctionArgument::Struct(x) => x.convert_to_native(from, naming),
+            FunctionArgument::StructRef(x) => x.inner.convert_to_native(from, naming),
+            FunctionArgument::ClassRef(x) => x.convert_to_native(from, naming),
+            FunctionArgument::Interface(x) => x.convert_to_native(from, naming),
         }
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgument::Basic(x) => x.cleanup_native(from),
-            FunctionArgument::String(x) => x.cleanup_native(from),
-            FunctionArgument::Collection(x) => x.cleanup_native(from),
-            FunctionArgument::Struct(x) => x.cleanup_native(from),
-            FunctionArgument::StructRef(x) => x.inner.cleanup_native(from),
-            FunctionArgument::ClassRef(x) => x.cleanup_native(from),
-            FunctionArgument::Interface(x) => x.cleanup_native(from),
+            FunctionArgument::Basic(x) => x.cleanup_native(from, naming),
+            FunctionArgument::String(x) => x.cleanup_native(from, naming),
+            FunctionArgument::Collection(x) => x.cleanup_native(from, naming),
+            FunctionArgument::Struct(x) => x.cleanup_native(from, naming),
+            FunctionArgument::StructRef(x) => x.inner.cleanup_native(from, naming),
+            FunctionArgument::ClassRef(x) => x.cleanup_native(from, naming),
+            FunctionArgument::Interface(x) => x.cleanup_native(from, naming),
         }
     }
 }
 
 impl ConvertToNative for CallbackReturnValue {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Basic(x) => x.convert_to_native(from),
-            Self::Struct(x) => x.convert_to_native(from),
+            Self::Basic(x) => x.convert_to_native(from, naming),
+            Self::Struct(x) => x.convert_to_native(from, naming),
         }
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            Self::Basic(x) => x.cleanup_native(from),
-            Self::Struct(x) => x.cleanup_native(from),
+            Self::Basic(x) => x.cleanup_native(from, naming),
+            Self::Struct(x) => x.cleanup_native(from, naming),
         }
     }
 }
 
 impl ConvertToNative for UniversalOr<FunctionArgStructField> {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalOr::Specific(x) => x.convert_to_native(from),
-            UniversalOr::Universal(x) => x.convert_to_native(from),
+            UniversalOr::Specific(x) => x.convert_to_native(from, naming),
+            UniversalOr::Universal(x) => x.convert_to_native(from, naming),
         }
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalOr::Specific(x) => x.cleanup_native(from),
-            UniversalOr::Universal(x) => x.cleanup_native(from),
+            UniversalOr::Specific(x) => x.cleanup_native(from, naming),
+            UniversalOr::Universal(x) => x.cleanup_native(from, naming),
         }
     }
 }
 
 impl ConvertToNative for StructDeclarationHandle {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Native.ToNativeRef({})",
-            self.name.camel_case(),
+            self.name.camel_case(naming),
             from
         ))
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Native.NativeRefCleanup({});",
-            self.name.camel_case(),
+            self.name.camel_case(naming),
             from
         ))
     }
@@ -275,15 +275,15 @@ where
     D: DocReference,
     T: StructFieldType,
 {
-    fn convert_to_native(&self, from: &str) -> Option<String> {
+    fn convert_to_native(&self, from: &str, naming: &Naming) -> Option<String> {
         Some(format!(
             "{}Native.ToNative({})",
-            self.name().camel_case(),
+            self.name().camel_case(naming),
             from
         ))
     }
 
-    fn cleanup_native(&self, from: &str) -> Option<String> {
+    fn cleanup_native(&self, from: &str, _naming: &Naming) -> Option<String> {
         Some(format!("{}.Dispose();", from))
     }
 }
@@ -1,3 +1,4 @@
+use crate::backend::dotnet::TargetFramework;
 use crate::model::*;
 
 const INT_PTR_STRING: &str = "IntPtr";
@@ -5,23 +6,23 @@ const INT_PTR_STRING: &str = "IntPtr";
 /// provides information about a type
 pub(crate) trait TypeInfo {
     /// Returns the .NET natural type
-    fn get_dotnet_type(&self) -> String;
+    fn get_dotnet_type(&self, naming: &Naming) -> String;
     /// Return the .NET representation of the native C type
-    fn get_native_type(&self) -> String;
+    fn get_native_type(&self, naming: &Naming) -> String;
 }
 
 impl TypeInfo for DurationType {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, _naming: &Naming) -> String {
         "TimeSpan".to_string()
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         "ulong".to_string()
     }
 }
 
 impl TypeInfo for Primitive {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, _naming: &Naming) -> String {
         match self {
             Self::Bool => "bool".to_string(),
             Self::U8 => "byte".to_string(),
@@ -37,7 +38,7 @@ impl TypeInfo for Primitive {
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         match self {
             Self::Bool => "byte".to_string(),
             Self::U8 => "byte".to_string(),
@@ -58,39 +59,39 @@ impl<D> TypeInfo for Handle<Enum<D>>
 where
     D: DocReference,
 {
-    fn get_dotnet_type(&self) -> String {
-        self.name.camel_case()
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
     }
 
-    fn get_native_type(&self) -> String {
-        self.name.camel_case()
+    fn get_native_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
     }
 }
 
 impl TypeInfo for BasicType {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Primitive(x) => x.get_dotnet_type(),
-            Self::Duration(x) => x.get_dotnet_type(),
-            Self::Enum(x) => x.get_dotnet_type(),
+            Self::Primitive(x) => x.get_dotnet_type(naming),
+            Self::Duration(x) => x.get_dotnet_type(naming),
+            Self::Enum(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Primitive(x) => x.get_native_type(),
-            Self::Duration(x) => x.get_native_type(),
-            Self::Enum(x) => x.get_native_type(),
+            Self::Primitive(x) => x.get_native_type(naming),
+            Self::Duration(x) => x.get_native_type(naming),
+            Self::Enum(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for StringType {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, _naming: &Naming) -> String {
         "string".to_string()
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         INT_PTR_STRING.to_string()
     }
 }
@@ -106,16 +107,16 @@ where
     }
 }
 
-pub(crate) fn full_functor_type<D>(cb: &CallbackFunction<D>) -> String
+pub(crate) fn full_functor_type<D>(cb: &CallbackFunction<D>, naming: &Naming) -> String
 where
     D: DocReference,
 {
-    fn arg_types<D>(args: &[Arg<CallbackArgument, D>]) -> String
+    fn arg_types<D>(args: &[Arg<CallbackArgument, D>], naming: &Naming) -> String
     where
         D: DocReference,
     {
         args.iter()
-            .map(|x| x.arg_type.get_dotnet_type())
+            .map(|x| x.arg_type.get_dotnet_type(naming))
             .collect::<Vec<String>>()
             .join(", ")
     }
@@ -123,13 +124,17 @@ where
     match (&cb.return_type.get_value(), cb.arguments.as_slice()) {
         (None, []) => "Action".to_string(),
         (None, args) => {
-            format!("Action<{}>", arg_types(args))
+            format!("Action<{}>", arg_types(args, naming))
         }
         (Some(t), []) => {
-            format!("Func<{}>", t.get_dotnet_type())
+            format!("Func<{}>", t.get_dotnet_type(naming))
         }
         (Some(t), args) => {
-            format!("Func<{}, {}>", arg_types(args), t.get_dotnet_type())
+            format!(
+                "Func<{}, {}>",
+                arg_types(args, naming),
+                t.get_dotnet_type(naming)
+            )
         }
     }
 }
@@ -138,43 +143,88 @@ impl<D> TypeInfo for Handle<Interface<D>>
 where
     D: DocReference,
 {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         if let Some(cb) = self.get_functional_callback() {
             if cb.functional_transform.enabled() {
-                return full_functor_type(cb);
+                return full_functor_type(cb, naming);
             }
         }
 
-        format!("I{}", self.name.camel_case())
+        format!("I{}", self.name.camel_case(naming))
     }
 
-    fn get_native_type(&self) -> String {
-        format!("I{}NativeAdapter", self.name.camel_case())
+    fn get_native_type(&self, naming: &Naming) -> String {
+        format!("I{}NativeAdapter", self.name.camel_case(naming))
     }
 }
 
 impl TypeInfo for ClassDeclarationHandle {
-    fn get_dotnet_type(&self) -> String {
-        self.name.camel_case()
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         INT_PTR_STRING.to_string()
     }
 }
 
+/// true if the collection is taken as a `ReadOnlySpan<T>` instead of an `ICollection<T>`
+pub(crate) fn is_span<D>(collection: &Collection<D>, framework: TargetFramework) -> bool
+where
+    D: DocReference,
+{
+    matches!(
+        collection.item_type,
+        FunctionArgument::Basic(BasicType::Primitive(_))
+    ) && framework.uses_spans()
+}
+
+/// .NET type of a collection when it is taken as a parameter
+pub(crate) fn collection_parameter_type<D>(
+    collection: &Collection<D>,
+    framework: TargetFramework,
+    naming: &Naming,
+) -> String
+where
+    D: DocReference,
+{
+    if is_span(collection, framework) {
+        format!(
+            "System.ReadOnlySpan<{}>",
+            collection.item_type.get_dotnet_type(naming)
+        )
+    } else {
+        format!(
+            "System.Collections.Generic.ICollection<{}>",
+            collection.item_type.get_dotnet_type(naming)
+        )
+    }
+}
+
+/// .NET type of a function argument when it is taken as a parameter
+pub(crate) fn parameter_type(
+    arg: &FunctionArgument,
+    framework: TargetFramework,
+    naming: &Naming,
+) -> String {
+    match arg {
+        FunctionArgument::Collection(x) => collection_parameter_type(x, framework, naming),
+        _ => arg.get_dotnet_type(naming),
+    }
+}
+
 impl<D> TypeInfo for Handle<Collection<D>>
 where
     D: DocReference,
 {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         format!(
             "System.Collections.Generic.ICollection<{}>",
-            self.item_type.get_dotnet_type()
+            self.item_type.get_dotnet_type(naming)
         )
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         INT_PTR_STRING.to_string()
     }
 }
@@ -183,24 +233,24 @@ impl<D> TypeInfo for Handle<AbstractIterator<D>>
 where
     D: DocReference,
 {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match &self.item_type {
             IteratorItemType::Primitive(x) => {
                 format!(
                     "System.Collections.Generic.ICollection<{}>",
-                    x.get_dotnet_type()
+                    x.get_dotnet_type(naming)
                 )
             }
             IteratorItemType::Struct(x) => {
                 format!(
                     "System.Collections.Generic.ICollection<{}>",
-                    x.name().camel_case()
+                    x.name().camel_case(naming)
                 )
             }
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         INT_PTR_STRING.to_string()
     }
 }
@@ -209,199 +259,199 @@ impl<T> TypeInfo for UniversalOr<T>
 where
     T: StructFieldType,
 {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            UniversalOr::Specific(x) => x.get_dotnet_type(),
-            UniversalOr::Universal(x) => x.get_dotnet_type(),
+            UniversalOr::Specific(x) => x.get_dotnet_type(naming),
+            UniversalOr::Universal(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            UniversalOr::Specific(x) => x.get_native_type(),
-            UniversalOr::Universal(x) => x.get_native_type(),
+            UniversalOr::Specific(x) => x.get_native_type(naming),
+            UniversalOr::Universal(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for FunctionArgStructField {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgStructField::Basic(x) => x.get_dotnet_type(),
-            FunctionArgStructField::String(x) => x.get_dotnet_type(),
-            FunctionArgStructField::Interface(x) => x.inner.get_dotnet_type(),
-            FunctionArgStructField::Struct(x) => x.get_dotnet_type(),
+            FunctionArgStructField::Basic(x) => x.get_dotnet_type(naming),
+            FunctionArgStructField::String(x) => x.get_dotnet_type(naming),
+            FunctionArgStructField::Interface(x) => x.inner.get_dotnet_type(naming),
+            FunctionArgStructField::Struct(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgStructField::Basic(x) => x.get_native_type(),
-            FunctionArgStructField::String(x) => x.get_native_type(),
-            FunctionArgStructField::Interface(x) => x.inner.get_native_type(),
-            FunctionArgStructField::Struct(x) => x.get_native_type(),
+            FunctionArgStructField::Basic(x) => x.get_native_type(naming),
+            FunctionArgStructField::String(x) => x.get_native_type(naming),
+            FunctionArgStructField::Interface(x) => x.inner.get_native_type(naming),
+            FunctionArgStructField::Struct(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for FunctionReturnStructField {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_dotnet_type(),
-            Self::ClassRef(x) => x.get_dotnet_type(),
-            Self::Struct(x) => x.get_dotnet_type(),
-            Self::Iterator(x) => x.get_dotnet_type(),
+            Self::Basic(x) => x.get_dotnet_type(naming),
+            Self::ClassRef(x) => x.get_dotnet_type(naming),
+            Self::Struct(x) => x.get_dotnet_type(naming),
+            Self::Iterator(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_native_type(),
-            Self::ClassRef(x) => x.get_native_type(),
-            Self::Struct(x) => x.get_native_type(),
-            Self::Iterator(x) => x.get_native_type(),
+            Self::Basic(x) => x.get_native_type(naming),
+            Self::ClassRef(x) => x.get_native_type(naming),
+            Self::Struct(x) => x.get_native_type(naming),
+            Self::Iterator(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for CallbackArgStructField {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            CallbackArgStructField::Basic(x) => x.get_dotnet_type(),
-            CallbackArgStructField::Iterator(x) => x.get_dotnet_type(),
-            CallbackArgStructField::Struct(x) => x.get_dotnet_type(),
+            CallbackArgStructField::Basic(x) => x.get_dotnet_type(naming),
+            CallbackArgStructField::Iterator(x) => x.get_dotnet_type(naming),
+            CallbackArgStructField::Struct(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            CallbackArgStructField::Basic(x) => x.get_native_type(),
-            CallbackArgStructField::Iterator(x) => x.get_native_type(),
-            CallbackArgStructField::Struct(x) => x.get_native_type(),
+            CallbackArgStructField::Basic(x) => x.get_native_type(naming),
+            CallbackArgStructField::Iterator(x) => x.get_native_type(naming),
+            CallbackArgStructField::Struct(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for UniversalStructField {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            UniversalStructField::Basic(x) => x.get_dotnet_type(),
-            UniversalStructField::Struct(x) => x.get_dotnet_type(),
+            UniversalStructField::Basic(x) => x.get_dotnet_type(naming),
+            UniversalStructField::Struct(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            UniversalStructField::Basic(x) => x.get_native_type(),
-            UniversalStructField::Struct(x) => x.get_native_type(),
+            UniversalStructField::Basic(x) => x.get_native_type(naming),
+            UniversalStructField::Struct(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for FunctionArgument {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgument::Basic(x) => x.get_dotnet_type(),
-            FunctionArgument::String(x) => x.get_dotnet_type(),
-            FunctionArgument::Collection(x) => x.get_dotnet_type(),
-            FunctionArgument::Struct(x) => x.get_dotnet_type(),
-            FunctionArgument::StructRef(x) => x.inner.get_dotnet_type(),
-            FunctionArgument::ClassRef(x) => x.get_dotnet_type(),
-            FunctionArgument::Interface(x) => x.get_dotnet_type(),
+            FunctionArgument::Basic(x) => x.get_dotnet_type(naming),
+            FunctionArgument::String(x) => x.get_dotnet_type(naming),
+            FunctionArgument::Collection(x) => x.get_dotnet_type(naming),
+            FunctionArgument::Struct(x) => x.get_dotnet_type(naming),
+            FunctionArgument::StructRef(x) => x.inner.get_dotnet_type(naming),
+            FunctionArgument::ClassRef(x) => x.get_dotnet_type(naming),
+            FunctionArgument::Interface(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgument::Basic(x) => x.get_native_type(),
-            FunctionArgument::String(x) => x.get_native_type(),
-            FunctionArgument::Collection(x) => x.get_native_type(),
-            FunctionArgument::Struct(x) => x.get_native_type(),
-            FunctionArgument::StructRef(x) => x.inner.get_native_type(),
-            FunctionArgument::ClassRef(x) => x.get_native_type(),
-            FunctionArgument::Interface(x) => x.get_native_type(),
+            FunctionArgument::Basic(x) => x.get_native_type(naming),
+            FunctionArgument::String(x) => x.get_native_type(naming),
+            FunctionArgument::Collection(x) => x.get_native_type(naming),
+            FunctionArgument::Struct(x) => x.get_native_type(naming),
+            FunctionArgument::StructRef(x) => x.inner.get_native_type(naming),
+            FunctionArgument::ClassRef(x) => x.get_native_type(naming),
+            FunctionArgument::Interface(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for CallbackArgument {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_dotnet_type(),
-            Self::String(x) => x.get_dotnet_type(),
-            Self::Iterator(x) => x.get_dotnet_type(),
-            Self::Struct(x) => x.get_dotnet_type(),
-            Self::Class(x) => x.get_dotnet_type(),
+            Self::Basic(x) => x.get_dotnet_type(naming),
+            Self::String(x) => x.get_dotnet_type(naming),
+            Self::Iterator(x) => x.get_dotnet_type(naming),
+            Self::Struct(x) => x.get_dotnet_type(naming),
+            Self::Class(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_native_type(),
-            Self::String(x) => x.get_native_type(),
-            Self::Iterator(x) => x.get_native_type(),
-            Self::Struct(x) => x.get_native_type(),
-            Self::Class(x) => x.get_native_type(),
+            Self::Basic(x) => x.get_native_type(naming),
+            Self::String(x) => x.get_native_type(naming),
+            Self::Iterator(x) => x.get_native_type(naming),
+            Self::Struct(x) => x.get_native_type(naming),
+            Self::Class(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for CallbackReturnValue {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_dotnet_type(),
-            Self::Struct(x) => x.get_dotnet_type(),
+            Self::Basic(x) => x.get_dotnet_type(naming),
+            Self::Struct(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_native_type(),
-            Self::Struct(x) => x.get_native_type(),
+            Self::Basic(x) => x.get_native_type(naming),
+            Self::Struct(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for PrimitiveRef {
-    fn get_dotnet_type(&self) -> String {
-        self.inner.get_dotnet_type()
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
+        self.inner.get_dotnet_type(naming)
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         INT_PTR_STRING.to_string()
     }
 }
 
 impl TypeInfo for FunctionReturnValue {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_dotnet_type(),
-            Self::String(x) => x.get_dotnet_type(),
-            Self::ClassRef(x) => x.get_dotnet_type(),
-            Self::Struct(x) => x.get_dotnet_type(),
-            Self::StructRef(x) => x.untyped().get_dotnet_type(),
-            Self::PrimitiveRef(x) => x.get_dotnet_type(),
+            Self::Basic(x) => x.get_dotnet_type(naming),
+            Self::String(x) => x.get_dotnet_type(naming),
+            Self::ClassRef(x) => x.get_dotnet_type(naming),
+            Self::Struct(x) => x.get_dotnet_type(naming),
+            Self::StructRef(x) => x.untyped().get_dotnet_type(naming),
+            Self::PrimitiveRef(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.get_native_type(),
-            Self::String(x) => x.get_native_type(),
-            Self::ClassRef(x) => x.get_native_type(),
-            Self::Struct(x) => x.get_native_type(),
-            Self::StructRef(x) => x.untyped().get_native_type(),
-            Self::PrimitiveRef(x) => x.get_native_type(),
+            Self::Basic(x) => x.get_native_type(naming),
+            Self::String(x) => x.get_native_type(naming),
+            Self::ClassRef(x) => x.get_native_type(naming),
+            Self::Struct(x) => x.get_native_type(naming),
+            Self::StructRef(x) => x.untyped().get_native_type(naming),
+            Self::PrimitiveRef(x) => x.get_native_type(naming),
         }
     }
 }
 
 impl TypeInfo for StructDeclarationHandle {
-    fn get_dotnet_type(&self) -> String {
-        self.name.camel_case()
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, _naming: &Naming) -> String {
         INT_PTR_STRING.to_string()
     }
 }
@@ -411,12 +461,12 @@ where
     D: DocReference,
     T: StructFieldType,
 {
-    fn get_dotnet_type(&self) -> String {
-        self.name().camel_case()
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
+        self.name().camel_case(naming)
     }
 
-    fn get_native_type(&self) -> String {
-        format!("{}Native", self.name().camel_case())
+    fn get_native_type(&self, naming: &Naming) -> String {
+        format!("{}Native", self.name().camel_case(naming))
     }
 }
 
@@ -427,17 +477,17 @@ where
     D: DocReference,
     T: Clone + TypeInfo,
 {
-    fn get_dotnet_type(&self) -> String {
+    fn get_dotnet_type(&self, naming: &Naming) -> String {
         match self.get_value() {
             None => VOID.to_string(),
-            Some(x) => x.get_dotnet_type(),
+            Some(x) => x.get_dotnet_type(naming),
         }
     }
 
-    fn get_native_type(&self) -> String {
+    fn get_native_type(&self, naming: &Naming) -> String {
         match self.get_value() {
             None => VOID.to_string(),
-            Some(x) => x.get_native_type(),
+            Some(x) => x.get_native_type(naming),
         }
     }
 }
@@ -2,15 +2,21 @@ use crate::backend::*;
 use crate::model::*;
 
 use crate::backend::dotnet::conversion::TypeInfo;
+use crate::backend::dotnet::formatting::string_literal;
 
 pub(crate) fn xmldoc_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
     f.writeln("<summary>")?;
     docstring_print(f, &doc.brief)?;
     f.write("</summary>")?;
 
-    if !doc.details.is_empty() {
+    let remarks: Vec<_> = doc
+        .details
+        .iter()
+        .filter(|x| !matches!(x, DocParagraph::Example(_)))
+        .collect();
+    if !remarks.is_empty() || doc.deprecated.is_some() || doc.since.is_some() {
         f.writeln("<remarks>")?;
-        for detail in &doc.details {
+        for detail in remarks {
             match detail {
                 DocParagraph::Details(docstring) => {
                     f.writeln("<para>")?;
@@ -22,14 +28,57 @@ pub(crate) fn xmldoc_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> Formatt
                     docstring_print(f, docstring)?;
                     f.write("</para>")?;
                 }
+                DocParagraph::Example(_) => (),
             }
         }
+        if let Some(deprecated) = &doc.deprecated {
+            f.writeln(&format!(
+                "<para><b>Deprecated</b> since {}: ",
+                deprecated.since
+            ))?;
+            docstring_print(f, &deprecated.note)?;
+            f.write("</para>")?;
+        }
+        if let Some(since) = &doc.since {
+            f.writeln(&format!("<para>Since {}</para>", since))?;
+        }
         f.writeln("</remarks>")?;
     }
 
+    for example in doc.examples() {
+        if let Some(code) = example.get(ExampleLanguage::DotNet) {
+            f.writeln("<example>")?;
+            f.writeln("<code>")?;
+            for line in code.lines() {
+                f.writeln(&xml_escape(line))?;
+            }
+            f.writeln("</code>")?;
+            f.writeln("</example>")?;
+        }
+    }
+
     Ok(())
 }
 
+/// Attribute of a deprecated item so that the compiler warns the users
+pub(crate) fn obsolete_attribute(
+    f: &mut dyn Printer,
+    doc: &Doc<Validated>,
+) -> FormattingResult<()> {
+    if let Some(deprecated) = &doc.deprecated {
+        let naming = f.naming();
+        let message = deprecated.message(&|reference| reference_name(reference, naming));
+        f.writeln(&format!("[Obsolete({})]", string_literal(&message)))?;
+    }
+    Ok(())
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 pub(crate) fn docstring_print(
     f: &mut dyn Printer,
     docstring: &DocString<Validated>,
@@ -40,6 +89,20 @@ pub(crate) fn docstring_print(
             DocStringElement::Null => f.write("<c>null</c>")?,
             DocStringElement::Iterator => f.write("collection")?,
             DocStringElement::Reference(reference) => reference_print(f, reference)?,
+            DocStringElement::Code(code) => f.write(&format!("<c>{}</c>", xml_escape(code)))?,
+            DocStringElement::Bold(text) => f.write(&format!("<b>{}</b>", text))?,
+            DocStringElement::Link { text, url } => {
+                f.write(&format!("<see href=\"{}\">{}</see>", xml_escape(url), text))?
+            }
+            DocStringElement::List(items) => {
+                f.writeln("<list type=\"bullet\">")?;
+                for item in items {
+                    f.writeln("<item><description>")?;
+                    docstring_print(f, item)?;
+                    f.write("</description></item>")?;
+                }
+                f.writeln("</list>")?;
+            }
         }
     }
 
@@ -47,80 +110,61 @@ pub(crate) fn docstring_print(
 }
 
 fn reference_print(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
+    let name = reference_name(reference, f.naming());
     match reference {
-        Validated::Argument(param_name) => {
-            f.write(&format!("<c>{}</c>", param_name.mixed_case()))?
-        }
-        Validated::Class(class) => {
-            f.write(&format!("<see cref=\"{}\" />", class.name.camel_case()))?;
-        }
-        Validated::ClassMethod(class, method_name, _) => f.write(&format!(
-            "<see cref=\"{}.{}\" />",
-            class.name().camel_case(),
-            method_name.camel_case()
-        ))?,
+        Validated::Argument(_) => f.write(&format!("<c>{}</c>", name)),
+        _ => f.write(&format!("<see cref=\"{}\" />", name)),
+    }
+}
+
+/// Name of a referenced item in the .NET API, as written in a `cref`
+fn reference_name(reference: &Validated, naming: &Naming) -> String {
+    match reference {
+        Validated::Argument(param_name) => param_name.unescaped_mixed_case(naming),
+        Validated::Class(class) => class.name.camel_case(naming),
+        Validated::ClassMethod(class, method_name, _) => format!(
+            "{}.{}",
+            class.name().camel_case(naming),
+            method_name.camel_case(naming)
+        ),
         Validated::ClassConstructor(class, constructor) => {
             let params = constructor
                 .function
                 .arguments
                 .iter()
-                .map(|param| param.arg_type.get_dotnet_type())
+                .map(|param| param.arg_type.get_dotnet_type(naming))
                 .collect::<Vec<_>>()
                 .join(", ");
 
-            let class_name = class.name().camel_case();
-            f.write(&format!(
-                "<see cref=\"{}.{}({})\" />",
-                class_name, class_name, params
-            ))?;
+            let class_name = class.name().camel_case(naming);
+            format!("{}.{}({})", class_name, class_name, params)
         }
         Validated::ClassDestructor(class, _) => {
             let method_name = if let DestructionMode::Custom(name) = &class.destruction_mode {
-                name.camel_case()
+                name.camel_case(naming)
             } else {
                 "Dispose".to_string()
             };
 
-            f.write(&format!(
-                "<see cref=\"{}.{}()\" />",
-                class.name().camel_case(),
-                method_name,
-            ))?;
-        }
-        Validated::Struct(st) => {
-            f.write(&format!("<see cref=\"{}\" />", st.name().camel_case()))?;
-        }
-        Validated::StructField(st, field_name) => {
-            f.write(&format!(
-                "<see cref=\"{}.{}\" />",
-                st.name().camel_case(),
-                field_name.camel_case()
-            ))?;
-        }
-        Validated::Enum(handle) => {
-            f.write(&format!("<see cref=\"{}\" />", handle.name.camel_case()))?;
-        }
-        Validated::EnumVariant(handle, variant) => {
-            f.write(&format!(
-                "<see cref=\"{}.{}\" />",
-                handle.name.camel_case(),
-                variant.camel_case()
-            ))?;
-        }
-        Validated::Interface(interface) => {
-            f.write(&format!(
-                "<see cref=\"I{}\" />",
-                interface.name.camel_case()
-            ))?;
-        }
-        Validated::InterfaceMethod(interface, callback_name) => {
-            f.write(&format!(
-                "<see cref=\"I{}.{}\" />",
-                interface.name.camel_case(),
-                callback_name.camel_case()
-            ))?;
+            format!("{}.{}()", class.name().camel_case(naming), method_name)
         }
+        Validated::Struct(st) => st.name().camel_case(naming),
+        Validated::StructField(st, field_name) => format!(
+            "{}.{}",
+            st.name().camel_case(naming),
+            field_name.camel_case(naming)
+        ),
+        Validated::Enum(handle) => handle.name.camel_case(naming),
+        Validated::EnumVariant(handle, variant) => format!(
+            "{}.{}",
+            handle.name.camel_case(naming),
+            variant.camel_case(naming)
+        ),
+        Validated::Interface(interface) => format!("I{}", interface.name.camel_case(naming)),
+        Validated::InterfaceMethod(interface, callback_name) => format!(
+            "I{}.{}",
+            interface.name.camel_case(naming),
+            callback_name.camel_case(naming)
+        ),
     }
-
-    Ok(())
 }
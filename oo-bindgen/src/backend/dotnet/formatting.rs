@@ -1,4 +1,5 @@
 use crate::backend::*;
+use crate::model::Naming;
 
 struct DocumentationPrinter<'a> {
     inner: &'a mut dyn Printer,
@@ -19,6 +20,10 @@ impl<'a> Printer for DocumentationPrinter<'a> {
         self.inner.newline()?;
         self.inner.write("/// ")
     }
+
+    fn naming(&self) -> &Naming {
+        self.inner.naming()
+    }
 }
 
 pub(crate) fn documentation<F, T>(f: &mut dyn Printer, cb: F) -> FormattingResult<T>
@@ -36,3 +41,25 @@ where
     f.writeln(&format!("namespace {}", namespace))?;
     blocked(f, |f| cb(f))
 }
+
+/// C# string literal with the UTF-16 code units outside of ASCII escaped
+pub(crate) fn string_literal(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            ' '..='~' => literal.push(c),
+            _ => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    literal.push_str(&format!("\\u{:04x}", unit));
+                }
+            }
+        }
+    }
+    literal.push('"');
+    literal
+}
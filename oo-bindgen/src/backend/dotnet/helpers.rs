@@ -1,38 +1,48 @@
+use crate::backend::dotnet::constraint::write_constraint_check;
 use crate::backend::dotnet::formatting::namespaced;
+use crate::backend::dotnet::structure::default_value_expression;
 use crate::backend::dotnet::*;
 
 pub(crate) fn generate_collection_helpers(
     f: &mut dyn Printer,
     coll: &Handle<Collection<Validated>>,
     lib: &Library,
+    framework: TargetFramework,
 ) -> FormattingResult<()> {
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
-    namespaced(f, &lib.settings.name, |f| {
+    let namespace = dotnet_namespace(lib, &coll.collection_class.module, f.naming());
+    namespaced(f, &namespace, |f| {
         f.writeln(&format!(
             "internal static class {}Helpers",
-            coll.name().camel_case()
+            coll.name().camel_case(f.naming())
         ))?;
         blocked(f, |f| {
             // ToNative function
             f.writeln(&format!(
-                "internal static IntPtr ToNative(System.Collections.Generic.ICollection<{}> value)",
-                coll.item_type.get_dotnet_type()
+                "internal static IntPtr ToNative({} value)",
+                collection_parameter_type(coll, framework, f.naming())
             ))?;
             blocked(f, |f| {
                 if coll.has_reserve {
+                    let count = if is_span(coll, framework) {
+                        "Length"
+                    } else {
+                        "Count"
+                    };
                     f.writeln(&format!(
-                        "var builder = {}.{}((uint)value.Count);",
+                        "var builder = {}.{}((uint)value.{});",
                         NATIVE_FUNCTIONS_CLASSNAME,
-                        coll.create_func.name.camel_case()
+                        coll.create_func.name.camel_case(f.naming()),
+                        count
                     ))?;
                 } else {
                     f.writeln(&format!(
                         "var builder = {}.{}();",
                         NATIVE_FUNCTIONS_CLASSNAME,
-                        coll.create_func.name.camel_case()
+                        coll.create_func.name.camel_case(f.naming())
                     ))?;
                 }
 
@@ -40,17 +50,18 @@ pub(crate) fn generate_collection_helpers(
                 blocked(f, |f| {
                     let conversion = coll
                         .item_type
-                        .convert_to_native("el")
+                        .convert_to_native("el", f.naming())
                         .unwrap_or_else(|| "el".to_string());
                     f.writeln(&format!("var convertedEl = {};", conversion))?;
 
                     f.writeln(&format!(
                         "{}.{}(builder, convertedEl);",
                         NATIVE_FUNCTIONS_CLASSNAME,
-                        coll.add_func.name.camel_case()
+                        coll.add_func.name.camel_case(f.naming())
                     ))?;
 
-                    if let Some(cleanup) = &coll.item_type.cleanup_native("convertedEl") {
+                    if let Some(cleanup) = &coll.item_type.cleanup_native("convertedEl", f.naming())
+                    {
                         f.writeln(cleanup)?;
                     }
 
@@ -66,7 +77,7 @@ pub(crate) fn generate_collection_helpers(
                 f.writeln(&format!(
                     "{}.{}(value);",
                     NATIVE_FUNCTIONS_CLASSNAME,
-                    coll.delete_func.name.camel_case()
+                    coll.delete_func.name.camel_case(f.naming())
                 ))
             })?;
 
@@ -81,18 +92,19 @@ pub(crate) fn generate_iterator_helpers(
     lib: &Library,
 ) -> FormattingResult<()> {
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
-    namespaced(f, &lib.settings.name, |f| {
+    let namespace = dotnet_namespace(lib, &iter.iter_class.module, f.naming());
+    namespaced(f, &namespace, |f| {
         f.writeln(&format!(
             "internal static class {}Helpers",
-            iter.name().camel_case()
+            iter.name().camel_case(f.naming())
         ))?;
         blocked(f, |f| {
             let value_type = match &iter.item_type {
-                IteratorItemType::Primitive(x) => x.get_dotnet_type(),
-                IteratorItemType::Struct(x) => x.get_dotnet_type(),
+                IteratorItemType::Primitive(x) => x.get_dotnet_type(f.naming()),
+                IteratorItemType::Struct(x) => x.get_dotnet_type(f.naming()),
             };
 
             // ToNative function
@@ -101,7 +113,7 @@ pub(crate) fn generate_iterator_helpers(
                 let next_call = format!(
                     "{}.{}(value)",
                     NATIVE_FUNCTIONS_CLASSNAME,
-                    iter.next_function.name.camel_case()
+                    iter.next_function.name.camel_case(f.naming())
                 );
 
                 f.writeln(&format!(
@@ -118,13 +130,13 @@ pub(crate) fn generate_iterator_helpers(
                         IteratorItemType::Primitive(x) => f.writeln(&format!(
                             "builder.Add({});",
                             PrimitiveRef::new(*x)
-                                .convert_to_dotnet("itRawValue")
+                                .convert_to_dotnet("itRawValue", f.naming())
                                 .unwrap()
                         )),
                         IteratorItemType::Struct(x) => {
                             let conversion = x
                                 .declaration()
-                                .convert_to_dotnet("itRawValue")
+                                .convert_to_dotnet("itRawValue", f.naming())
                                 .unwrap_or_else(|| "itRawValue".to_string());
                             f.writeln(&format!("builder.Add({});", conversion))
                         }
@@ -136,6 +148,48 @@ pub(crate) fn generate_iterator_helpers(
     })
 }
 
+/// Durations and structs can't be compile-time constants, so they default to null
+fn has_constant_default(default: &ValidatedDefaultValue) -> bool {
+    !matches!(
+        default,
+        ValidatedDefaultValue::Duration(_, _) | ValidatedDefaultValue::DefaultStruct(_, _, _)
+    )
+}
+
+/// Declaration of a parameter, including the optional value if it has a default
+pub(crate) fn dotnet_parameter(
+    param: &Arg<FunctionArgument, Validated>,
+    framework: TargetFramework,
+    naming: &Naming,
+) -> String {
+    let dotnet_type = parameter_type(&param.arg_type, framework, naming);
+    match &param.default {
+        None => format!("{} {}", dotnet_type, param.name.mixed_case(naming)),
+        Some(x) if has_constant_default(x) => format!(
+            "{} {} = {}",
+            dotnet_type,
+            param.name.mixed_case(naming),
+            default_value_expression(x, naming)
+        ),
+        Some(ValidatedDefaultValue::Duration(_, _)) => {
+            format!("{}? {} = null", dotnet_type, param.name.mixed_case(naming))
+        }
+        Some(_) => format!("{} {} = null", dotnet_type, param.name.mixed_case(naming)),
+    }
+}
+
+/// Expression for the value of a parameter, substituting the default for null
+fn argument_value(param: &Arg<FunctionArgument, Validated>, naming: &Naming) -> String {
+    match &param.default {
+        Some(x) if !has_constant_default(x) => format!(
+            "({} ?? {})",
+            param.name.mixed_case(naming),
+            default_value_expression(x, naming)
+        ),
+        _ => param.name.mixed_case(naming),
+    }
+}
+
 pub(crate) fn call_native_function(
     f: &mut dyn Printer,
     method: &Function<Validated>,
@@ -143,9 +197,23 @@ pub(crate) fn call_native_function(
     first_param_is_self: Option<String>,
     is_constructor: bool,
 ) -> FormattingResult<()> {
+    // Check the constraints before anything is converted
+    for param in method.arguments.iter().filter(|x| x.has_constraints()) {
+        let param_name = param.name.unescaped_mixed_case(f.naming());
+        let value = argument_value(param, f.naming());
+        if let Some(target) = param.arg_type.constrained_type() {
+            for constraint in &param.constraints {
+                write_constraint_check(f, constraint, target, &value, &param_name)?;
+            }
+        }
+        if param.arg_type.has_nested_constraints() {
+            f.writeln(&format!("{}._AssertFieldsValid();", value))?;
+        }
+    }
+
     // Write the type conversions
     for (idx, param) in method.arguments.iter().enumerate() {
-        let mut param_name = param.name.mixed_case();
+        let mut param_name = argument_value(param, f.naming());
         if idx == 0 {
             if let Some(first_param) = first_param_is_self.clone() {
                 param_name = first_param;
@@ -154,11 +222,11 @@ pub(crate) fn call_native_function(
 
         let conversion = param
             .arg_type
-            .convert_to_native(&param_name)
+            .convert_to_native(&param_name, f.naming())
             .unwrap_or(param_name);
         f.writeln(&format!(
             "var _{} = {};",
-            param.name.mixed_case(),
+            param.name.unescaped_mixed_case(f.naming()),
             conversion
         ))?;
     }
@@ -170,13 +238,13 @@ pub(crate) fn call_native_function(
             f.write(&format!(
                 "var _result = {}.{}(",
                 NATIVE_FUNCTIONS_CLASSNAME,
-                method.name.camel_case()
+                method.name.camel_case(f.naming())
             ))?;
         } else {
             f.write(&format!(
                 "{}.{}(",
                 NATIVE_FUNCTIONS_CLASSNAME,
-                method.name.camel_case()
+                method.name.camel_case(f.naming())
             ))?;
         }
 
@@ -184,7 +252,7 @@ pub(crate) fn call_native_function(
             &method
                 .arguments
                 .iter()
-                .map(|param| format!("_{}", param.name.mixed_case()))
+                .map(|param| format!("_{}", param.name.unescaped_mixed_case(f.naming())))
                 .collect::<Vec<String>>()
                 .join(", "),
         )?;
@@ -193,7 +261,7 @@ pub(crate) fn call_native_function(
         // Convert the result (if required)
         let return_name = if let Some(return_type) = &method.return_type.get_value() {
             let mut return_name = "_result";
-            if let Some(conversion) = return_type.convert_to_dotnet("_result") {
+            if let Some(conversion) = return_type.convert_to_dotnet("_result", f.naming()) {
                 if !is_constructor {
                     f.writeln(&format!("var __result = {};", conversion))?;
                     return_name = "__result";
@@ -216,7 +284,7 @@ pub(crate) fn call_native_function(
     let has_cleanup = method
         .arguments
         .iter()
-        .any(|param| param.arg_type.cleanup_native("temp").is_some());
+        .any(|param| param.arg_type.cleanup_native("temp", f.naming()).is_some());
 
     if has_cleanup {
         f.writeln("try")?;
@@ -225,10 +293,10 @@ pub(crate) fn call_native_function(
         blocked(f, |f| {
             // Cleanup type conversions
             for param in method.arguments.iter() {
-                if let Some(cleanup) = param
-                    .arg_type
-                    .cleanup_native(&format!("_{}", param.name.mixed_case()))
-                {
+                if let Some(cleanup) = param.arg_type.cleanup_native(
+                    &format!("_{}", param.name.unescaped_mixed_case(f.naming())),
+                    f.naming(),
+                ) {
                     f.writeln(&cleanup)?;
                 }
             }
@@ -250,16 +318,23 @@ pub(crate) fn call_dotnet_function(
     for arg in method.arguments.iter() {
         let conversion = arg
             .arg_type
-            .convert_to_dotnet(&arg.name.mixed_case())
-            .unwrap_or_else(|| arg.name.mixed_case());
-        f.writeln(&format!("var _{} = {};", arg.name.mixed_case(), conversion))?;
+            .convert_to_dotnet(&arg.name.mixed_case(f.naming()), f.naming())
+            .unwrap_or_else(|| arg.name.mixed_case(f.naming()));
+        f.writeln(&format!(
+            "var _{} = {};",
+            arg.name.unescaped_mixed_case(f.naming()),
+            conversion
+        ))?;
     }
 
     // Call the .NET function
     f.newline()?;
-    let method_name = method.name.camel_case();
+    let method_name = method.name.camel_case(f.naming());
     if let Some(return_type) = &method.return_type.get_value() {
-        if return_type.convert_to_native("_result").is_some() {
+        if return_type
+            .convert_to_native("_result", f.naming())
+            .is_some()
+        {
             f.write(&format!("var _result = _impl.{}(", method_name))?;
         } else {
             f.write(&format!("{}_impl.{}(", return_destination, method_name))?;
@@ -272,7 +347,7 @@ pub(crate) fn call_dotnet_function(
         &method
             .arguments
             .iter()
-            .map(|arg| format!("_{}", arg.name.mixed_case()))
+            .map(|arg| format!("_{}", arg.name.unescaped_mixed_case(f.naming())))
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -280,7 +355,7 @@ pub(crate) fn call_dotnet_function(
 
     // Convert the result (if required)
     if let Some(return_type) = &method.return_type.get_value() {
-        if let Some(conversion) = return_type.convert_to_native("_result") {
+        if let Some(conversion) = return_type.convert_to_native("_result", f.naming()) {
             f.writeln(&format!("{}{};", return_destination, conversion))?;
         }
     }
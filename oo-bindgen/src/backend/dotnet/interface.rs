@@ -2,24 +2,24 @@ use crate::backend::dotnet::helpers::*;
 use crate::backend::dotnet::*;
 
 trait ConstantReturnValue {
-    fn get_constant_return_value(&self) -> String;
+    fn get_constant_return_value(&self, naming: &Naming) -> String;
 }
 
 trait MaybeConstantReturnValue {
-    fn try_get_constant_return_value(&self) -> Option<String>;
+    fn try_get_constant_return_value(&self, naming: &Naming) -> Option<String>;
 }
 
 impl<T> MaybeConstantReturnValue for T
 where
     T: ConstantReturnValue,
 {
-    fn try_get_constant_return_value(&self) -> Option<String> {
-        Some(self.get_constant_return_value())
+    fn try_get_constant_return_value(&self, naming: &Naming) -> Option<String> {
+        Some(self.get_constant_return_value(naming))
     }
 }
 
 impl ConstantReturnValue for PrimitiveValue {
-    fn get_constant_return_value(&self) -> String {
+    fn get_constant_return_value(&self, _naming: &Naming) -> String {
         match self {
             PrimitiveValue::Bool(x) => x.to_string(),
             PrimitiveValue::U8(x) => x.to_string(),
@@ -37,17 +37,17 @@ impl ConstantReturnValue for PrimitiveValue {
 }
 
 impl ConstantReturnValue for EnumValue {
-    fn get_constant_return_value(&self) -> String {
+    fn get_constant_return_value(&self, naming: &Naming) -> String {
         format!(
             "{}.{}",
-            self.handle.name.camel_case(),
-            self.variant.name.camel_case()
+            self.handle.name.camel_case(naming),
+            self.variant.name.camel_case(naming)
         )
     }
 }
 
 impl ConstantReturnValue for DurationValue {
-    fn get_constant_return_value(&self) -> String {
+    fn get_constant_return_value(&self, _naming: &Naming) -> String {
         match self {
             DurationValue::Milliseconds(x) => format!("TimeSpan.FromMilliseconds({})", x),
             DurationValue::Seconds(x) => format!("TimeSpan.FromSeconds({})", x),
@@ -56,34 +56,36 @@ impl ConstantReturnValue for DurationValue {
 }
 
 impl ConstantReturnValue for BasicValue {
-    fn get_constant_return_value(&self) -> String {
+    fn get_constant_return_value(&self, naming: &Naming) -> String {
         match self {
-            BasicValue::Primitive(x) => x.get_constant_return_value(),
-            BasicValue::Duration(x) => x.get_constant_return_value(),
-            BasicValue::Enum(x) => x.get_constant_return_value(),
+            BasicValue::Primitive(x) => x.get_constant_return_value(naming),
+            BasicValue::Duration(x) => x.get_constant_return_value(naming),
+            BasicValue::Enum(x) => x.get_constant_return_value(naming),
         }
     }
 }
 
 impl ConstantReturnValue for ZeroParameterStructInitializer {
-    fn get_constant_return_value(&self) -> String {
+    fn get_constant_return_value(&self, naming: &Naming) -> String {
         match self.initializer.initializer_type {
-            InitializerType::Normal => format!("new {}()", self.handle.name().camel_case()),
+            InitializerType::Normal => format!("new {}()", self.handle.name().camel_case(naming)),
             InitializerType::Static => format!(
                 "{}.{}()",
-                self.handle.name().camel_case(),
-                self.initializer.name.camel_case()
+                self.handle.name().camel_case(naming),
+                self.initializer.name.camel_case(naming)
             ),
         }
     }
 }
 
 impl MaybeConstantReturnValue for DefaultCallbackReturnValue {
-    fn try_get_constant_return_value(&self) -> Option<String> {
+    fn try_get_constant_return_value(&self, naming: &Naming) -> Option<String> {
         match self {
             DefaultCallbackReturnValue::Void => None,
-            DefaultCallbackReturnValue::Basic(x) => x.try_get_constant_return_value(),
-            DefaultCallbackReturnValue::InitializedStruct(x) => x.try_get_constant_return_value(),
+            DefaultCallbackReturnValue::Basic(x) => x.try_get_constant_return_value(naming),
+            DefaultCallbackReturnValue::InitializedStruct(x) => {
+                x.try_get_constant_return_value(naming)
+            }
         }
     }
 }
@@ -94,13 +96,14 @@ pub(crate) fn generate(
     lib: &Library,
     framework: TargetFramework,
 ) -> FormattingResult<()> {
-    let interface_name = format!("I{}", interface.name().camel_case());
+    let interface_name = format!("I{}", interface.name().camel_case(f.naming()));
 
     let destroy_func_name = lib.settings.interface.destroy_func_name.clone();
     let ctx_variable_name = lib.settings.interface.context_variable_name.clone();
 
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_obsolete_pragma(f, lib)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
     let is_private = interface
@@ -110,7 +113,8 @@ pub(crate) fn generate(
         .unwrap_or(false);
     let visibility = if is_private { "internal" } else { "public" };
 
-    namespaced(f, &lib.settings.name, |f| {
+    let namespace = dotnet_namespace(lib, &interface.untyped().module, f.naming());
+    namespaced(f, &namespace, |f| {
         documentation(f, |f| {
             // Print top-level documentation
             xmldoc_print(f, interface.doc())
@@ -128,7 +132,7 @@ pub(crate) fn generate(
 
                     // Print each parameter value
                     for arg in &func.arguments {
-                        f.writeln(&format!("<param name=\"{}\">", arg.name.mixed_case()))?;
+                        f.writeln(&format!("<param name=\"{}\">", arg.name.unescaped_mixed_case(f.naming())))?;
                         docstring_print(f, &arg.doc)?;
                         f.write("</param>")?;
                     }
@@ -142,12 +146,13 @@ pub(crate) fn generate(
 
                     Ok(())
                 })?;
+                obsolete_attribute(f, &func.doc)?;
 
                 // Callback signature
                 f.writeln(&format!(
                     "{} {}(",
-                    func.return_type.get_dotnet_type(),
-                    func.name.camel_case()
+                    func.return_type.get_dotnet_type(f.naming()),
+                    func.name.camel_case(f.naming())
                 ))?;
                 f.write(
                     &func
@@ -156,8 +161,8 @@ pub(crate) fn generate(
                         .map(|arg| {
                             format!(
                                 "{} {}",
-                                arg.arg_type.get_dotnet_type(),
-                                arg.name.mixed_case()
+                                arg.arg_type.get_dotnet_type(f.naming()),
+                                arg.name.mixed_case(f.naming())
                             )
                         })
                         .collect::<Vec<String>>()
@@ -169,7 +174,7 @@ pub(crate) fn generate(
                     }
                     Some(di) => {
                         if framework.supports_default_interface_methods() {
-                            match di.try_get_constant_return_value() {
+                            match di.try_get_constant_return_value(f.naming()) {
                                 None => {
                                     f.write(") {}")
                                 }
@@ -182,7 +187,7 @@ pub(crate) fn generate(
                                 }
                             }
                         } else {
-                            tracing::warn!("Method {}::{} has a default implementation defined, but it cannot be supported in C# 7.3", interface.name().camel_case(), func.name.camel_case());
+                            tracing::warn!("Method {}::{} has a default implementation defined, but it cannot be supported in C# 7.3", interface.name().camel_case(f.naming()), func.name.camel_case(f.naming()));
                             f.write(");")
                         }
                     }
@@ -202,14 +207,14 @@ pub(crate) fn generate(
 
         // write a Task-based implementation if it's a future interface
         if let InterfaceType::Future(fi) = interface {
-            let class_name = fi.interface.name.camel_case();
-            let value_type = fi.value_type.get_dotnet_type();
+            let class_name = fi.interface.name.camel_case(f.naming());
+            let value_type = fi.value_type.get_dotnet_type(f.naming());
             let success_method_name = fi
                 .interface
                 .settings
                 .future
                 .success_callback_method_name
-                .camel_case();
+                .camel_case(f.naming());
 
             f.writeln(&format!(
                 "internal class {}: {}",
@@ -240,17 +245,17 @@ pub(crate) fn generate(
                         .settings
                         .future
                         .failure_callback_method_name
-                        .camel_case();
+                        .camel_case(f.naming());
                     f.writeln(&format!(
                         "void {}.{}({} err)",
                         interface_name,
                         error_method_name,
-                        err.inner.get_dotnet_type()
+                        err.inner.get_dotnet_type(f.naming())
                     ))?;
                     blocked(f, |f| {
                         f.writeln(&format!(
                             "Task.Run(() => tcs.SetException(new {}(err)));",
-                            err.exception_name.camel_case()
+                            err.exception_name.camel_case(f.naming())
                         ))
                     })?;
                 }
@@ -260,6 +265,10 @@ pub(crate) fn generate(
             f.newline()?;
         }
 
+        if framework.uses_source_generated_interop() {
+            return generate_unmanaged_adapter(f, interface.untyped(), lib);
+        }
+
         // Create the native adapter
         f.writeln("[StructLayout(LayoutKind.Sequential)]")?;
         f.writeln(&format!("internal struct {}NativeAdapter", interface_name))?;
@@ -269,7 +278,7 @@ pub(crate) fn generate(
                 f.writeln("[UnmanagedFunctionPointer(CallingConvention.Cdecl)]")?; // C calling convetion
                 f.writeln(&format!(
                     "private delegate {} {}_delegate(",
-                    cb.return_type.get_native_type(),
+                    cb.return_type.get_native_type(f.naming()),
                     cb.name
                 ))?;
                 f.write(
@@ -278,8 +287,8 @@ pub(crate) fn generate(
                         .map(|arg| {
                             format!(
                                 "{} {}",
-                                arg.arg_type.get_native_type(),
-                                arg.name.mixed_case()
+                                arg.arg_type.get_native_type(f.naming()),
+                                arg.name.mixed_case(f.naming())
                             )
                         })
                         .chain(std::iter::once(format!(
@@ -356,7 +365,7 @@ pub(crate) fn generate(
             for cb in &interface.untyped().callbacks {
                 f.writeln(&format!(
                     "internal static {} {}_cb(",
-                    cb.return_type.get_native_type(),
+                    cb.return_type.get_native_type(f.naming()),
                     cb.name
                 ))?;
                 f.write(
@@ -365,8 +374,8 @@ pub(crate) fn generate(
                         .map(|arg| {
                             format!(
                                 "{} {}",
-                                arg.arg_type.get_native_type(),
-                                arg.name.mixed_case()
+                                arg.arg_type.get_native_type(f.naming()),
+                                arg.name.mixed_case(f.naming())
                             )
                         })
                         .chain(std::iter::once(format!("IntPtr {}", ctx_variable_name)))
@@ -420,16 +429,154 @@ pub(crate) fn generate(
     })
 }
 
+/// Native adapter made of unmanaged function pointers to `[UnmanagedCallersOnly]` methods
+///
+/// Unlike delegates, this requires no runtime marshalling and is compatible with NativeAOT
+fn generate_unmanaged_adapter(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+    lib: &Library,
+) -> FormattingResult<()> {
+    let interface_name = format!("I{}", interface.name.camel_case(f.naming()));
+    let destroy_func_name = &lib.settings.interface.destroy_func_name;
+    let ctx_variable_name = &lib.settings.interface.context_variable_name;
+    let unmanaged_callers_only = "[UnmanagedCallersOnly(CallConvs = new[] { typeof(System.Runtime.CompilerServices.CallConvCdecl) })]";
+
+    f.writeln("[StructLayout(LayoutKind.Sequential)]")?;
+    f.writeln(&format!(
+        "internal unsafe struct {}NativeAdapter",
+        interface_name
+    ))?;
+    blocked(f, |f| {
+        // Define each structure element that will be passed to native code
+        for cb in &interface.callbacks {
+            let types = cb
+                .arguments
+                .iter()
+                .map(|arg| arg.arg_type.get_native_type(f.naming()))
+                .chain(std::iter::once("IntPtr".to_string()))
+                .chain(std::iter::once(cb.return_type.get_native_type(f.naming())))
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!(
+                "private delegate* unmanaged[Cdecl]<{}> {};",
+                types, cb.name
+            ))?;
+        }
+
+        f.writeln(&format!(
+            "private delegate* unmanaged[Cdecl]<IntPtr, void> {};",
+            destroy_func_name
+        ))?;
+        f.writeln(&format!("public IntPtr {};", ctx_variable_name))?;
+
+        f.newline()?;
+
+        // Define the constructor
+        f.writeln(&format!(
+            "internal {}NativeAdapter({} impl)",
+            interface_name, interface_name
+        ))?;
+        blocked(f, |f| {
+            f.writeln("var _handle = GCHandle.Alloc(impl);")?;
+            f.newline()?;
+
+            for cb in &interface.callbacks {
+                f.writeln(&format!(
+                    "this.{} = &{}NativeAdapter.{}_cb;",
+                    cb.name, interface_name, cb.name
+                ))?;
+            }
+
+            f.writeln(&format!(
+                "this.{} = &{}NativeAdapter.{}_cb;",
+                destroy_func_name, interface_name, destroy_func_name
+            ))?;
+
+            f.writeln(&format!(
+                "this.{} = GCHandle.ToIntPtr(_handle);",
+                ctx_variable_name
+            ))
+        })?;
+
+        f.newline()?;
+
+        // Define each callback function
+        for cb in &interface.callbacks {
+            f.writeln(unmanaged_callers_only)?;
+            f.writeln(&format!(
+                "internal static {} {}_cb(",
+                cb.return_type.get_native_type(f.naming()),
+                cb.name
+            ))?;
+            f.write(
+                &cb.arguments
+                    .iter()
+                    .map(|arg| {
+                        format!(
+                            "{} {}",
+                            arg.arg_type.get_native_type(f.naming()),
+                            arg.name.mixed_case(f.naming())
+                        )
+                    })
+                    .chain(std::iter::once(format!("IntPtr {}", ctx_variable_name)))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            )?;
+            f.write(")")?;
+
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "var _handle = GCHandle.FromIntPtr({});",
+                    ctx_variable_name
+                ))?;
+                f.writeln(&format!("var _impl = ({})_handle.Target;", interface_name))?;
+                call_dotnet_function(f, cb, "return ")
+            })?;
+
+            f.newline()?;
+        }
+
+        // destroy callback
+        f.writeln(unmanaged_callers_only)?;
+        f.writeln(&format!(
+            "internal static void {}_cb(IntPtr arg)",
+            destroy_func_name
+        ))?;
+        blocked(f, |f| {
+            f.writeln("var _handle = GCHandle.FromIntPtr(arg);")?;
+            f.writeln("_handle.Free();")
+        })?;
+
+        f.newline()?;
+
+        // Write the conversion routine
+        f.writeln(&format!(
+            "internal static {} FromNative(IntPtr self)",
+            interface_name
+        ))?;
+        blocked(f, |f| {
+            f.writeln("if (self != IntPtr.Zero)")?;
+            blocked(f, |f| {
+                f.writeln("var handle = GCHandle.FromIntPtr(self);")?;
+                f.writeln(&format!("return handle.Target as {};", interface_name))
+            })?;
+            f.writeln("else")?;
+            blocked(f, |f| f.writeln("return null;"))
+        })
+    })
+}
+
 pub(crate) fn generate_interface_implementation(
     f: &mut dyn Printer,
     interface: &Handle<Interface<Validated>>,
     cb: &CallbackFunction<Validated>,
 ) -> FormattingResult<()> {
-    let functor_type = full_functor_type(cb);
+    let functor_type = full_functor_type(cb, f.naming());
 
     f.writeln(&format!(
         "internal class Implementation: I{}",
-        interface.name.camel_case()
+        interface.name.camel_case(f.naming())
     ))?;
     blocked(f, |f| {
         f.writeln(&format!("private readonly {} action;", functor_type))?;
@@ -443,8 +590,8 @@ pub(crate) fn generate_interface_implementation(
 
         f.writeln(&format!(
             "public {} {}(",
-            cb.return_type.get_dotnet_type(),
-            cb.name.camel_case()
+            cb.return_type.get_dotnet_type(f.naming()),
+            cb.name.camel_case(f.naming())
         ))?;
         f.write(
             &cb.arguments
@@ -452,8 +599,8 @@ pub(crate) fn generate_interface_implementation(
                 .map(|param| {
                     format!(
                         "{} {}",
-                        param.arg_type.get_dotnet_type(),
-                        param.name.mixed_case()
+                        param.arg_type.get_dotnet_type(f.naming()),
+                        param.name.mixed_case(f.naming())
                     )
                 })
                 .collect::<Vec<_>>()
@@ -470,7 +617,7 @@ pub(crate) fn generate_interface_implementation(
             let params = cb
                 .arguments
                 .iter()
-                .map(|param| param.name.mixed_case())
+                .map(|param| param.name.mixed_case(f.naming()))
                 .collect::<Vec<_>>()
                 .join(", ");
 
@@ -484,9 +631,9 @@ pub(crate) fn generate_functional_helpers(
     interface: &Handle<Interface<Validated>>,
     cb: &CallbackFunction<Validated>,
 ) -> FormattingResult<()> {
-    let interface_name = format!("I{}", interface.name.camel_case());
-    let class_name = interface.name.camel_case();
-    let functor_type = full_functor_type(cb);
+    let interface_name = format!("I{}", interface.name.camel_case(f.naming()));
+    let class_name = interface.name.camel_case(f.naming());
+    let functor_type = full_functor_type(cb, f.naming());
 
     let visibility = if cb.functional_transform.enabled() {
         "internal"
@@ -0,0 +1,194 @@
+use std::path::Path;
+
+use heck::CamelCase;
+
+use crate::backend::rust::mock::{code, ControlFunction, ControlType, CONTROL_API};
+use crate::backend::*;
+use crate::model::*;
+
+/// Write the `Mock` class controlling the mock implementation loaded in place of the library
+///
+/// The class is self-contained so that it can be compiled along with the tests.
+pub(crate) fn generate(lib: &Library, ffi_name: &str, path: &Path) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::DotNet))?;
+
+    f.writeln(&format!(
+        "// Control API of the mock implementation of the {} library generated by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.writeln("using System;")?;
+    f.writeln("using System.Runtime.InteropServices;")?;
+    f.writeln("using System.Text;")?;
+    f.newline()?;
+    f.writeln(&format!("namespace {}.Tests", lib.settings.name))?;
+    blocked(&mut f, |f| {
+        f.writeln("/// <summary>")?;
+        f.writeln(
+            "/// Control API of the mock implementation, loaded in place of the native library",
+        )?;
+        f.writeln("/// </summary>")?;
+        f.writeln("public static class Mock")?;
+        blocked(f, |f| {
+            for function in CONTROL_API.iter() {
+                write_method(f, function)?;
+                f.newline()?;
+            }
+            write_conversions(f)?;
+            f.newline()?;
+            f.writeln("private static class PInvoke")?;
+            blocked(f, |f| {
+                for (index, function) in CONTROL_API.iter().enumerate() {
+                    if index > 0 {
+                        f.newline()?;
+                    }
+                    write_import(f, ffi_name, &lib.settings.c_ffi_prefix, function)?;
+                }
+                Ok(())
+            })
+        })
+    })
+}
+
+fn write_method(f: &mut dyn Printer, function: &ControlFunction) -> FormattingResult<()> {
+    f.writeln(&format!(
+        "/// <summary>{}</summary>",
+        code(function.doc, "<c>", "</c>")
+    ))?;
+    for (name, _, doc) in function.params {
+        f.writeln(&format!("/// <param name=\"{}\">{}</param>", name, doc))?;
+    }
+    if let Some((_, doc)) = function.returns {
+        f.writeln(&format!("/// <returns>{}</returns>", doc))?;
+    }
+
+    let params: Vec<String> = function
+        .params
+        .iter()
+        .map(|(name, param_type, _)| match param_type {
+            ControlType::U32 => format!("uint {}", name),
+            ControlType::String => format!("string {}", name),
+            ControlType::Strings => format!("params string[] {}", name),
+        })
+        .collect();
+    f.writeln(&format!(
+        "public static {} {}({})",
+        return_type(function),
+        function.name.to_camel_case(),
+        params.join(", ")
+    ))?;
+    blocked(f, |f| {
+        let arrays: Vec<&str> = function
+            .params
+            .iter()
+            .filter(|(_, param_type, _)| *param_type == ControlType::Strings)
+            .map(|(name, _, _)| *name)
+            .collect();
+        for name in &arrays {
+            f.writeln(&format!(
+                "var _{} = Array.ConvertAll({}, ToUnmanaged);",
+                name, name
+            ))?;
+        }
+
+        let args: Vec<String> = function
+            .params
+            .iter()
+            .map(|(name, param_type, _)| match param_type {
+                ControlType::U32 => name.to_string(),
+                ControlType::String => format!("ToNative({})", name),
+                ControlType::Strings => format!("_{}, (uint)_{}.Length", name, name),
+            })
+            .collect();
+        let call = format!(
+            "PInvoke.{}({})",
+            function.name.to_camel_case(),
+            args.join(", ")
+        );
+        let statement = match function.returns {
+            None => format!("{};", call),
+            Some((ControlType::U32, _)) => format!("return {};", call),
+            Some(_) => format!("return FromNative({});", call),
+        };
+
+        if arrays.is_empty() {
+            return f.writeln(&statement);
+        }
+        f.writeln("try")?;
+        blocked(f, |f| f.writeln(&statement))?;
+        f.writeln("finally")?;
+        blocked(f, |f| {
+            for name in &arrays {
+                f.writeln(&format!("Array.ForEach(_{}, Marshal.FreeHGlobal);", name))?;
+            }
+            Ok(())
+        })
+    })
+}
+
+fn write_conversions(f: &mut dyn Printer) -> FormattingResult<()> {
+    f.writeln("private static byte[] ToNative(string value)")?;
+    blocked(f, |f| {
+        f.writeln("return Encoding.UTF8.GetBytes(value + \"\\0\");")
+    })?;
+    f.newline()?;
+    f.writeln("private static IntPtr ToUnmanaged(string value)")?;
+    blocked(f, |f| {
+        f.writeln("var bytes = ToNative(value);")?;
+        f.writeln("var result = Marshal.AllocHGlobal(bytes.Length);")?;
+        f.writeln("Marshal.Copy(bytes, 0, result, bytes.Length);")?;
+        f.writeln("return result;")
+    })?;
+    f.newline()?;
+    f.writeln("private static string FromNative(IntPtr value)")?;
+    blocked(f, |f| {
+        f.writeln("if (value == IntPtr.Zero)")?;
+        blocked(f, |f| f.writeln("return null;"))?;
+        f.writeln("var length = 0;")?;
+        f.writeln("while (Marshal.ReadByte(value, length) != 0)")?;
+        blocked(f, |f| f.writeln("++length;"))?;
+        f.writeln("var bytes = new byte[length];")?;
+        f.writeln("Marshal.Copy(value, bytes, 0, length);")?;
+        f.writeln("return Encoding.UTF8.GetString(bytes);")
+    })
+}
+
+fn write_import(
+    f: &mut dyn Printer,
+    ffi_name: &str,
+    prefix: &Name,
+    function: &ControlFunction,
+) -> FormattingResult<()> {
+    let params: Vec<String> = function
+        .params
+        .iter()
+        .map(|(name, param_type, _)| match param_type {
+            ControlType::U32 => format!("uint {}", name),
+            ControlType::String => format!("byte[] {}", name),
+            ControlType::Strings => format!("IntPtr[] {}, uint count", name),
+        })
+        .collect();
+    let return_type = match function.returns {
+        None => "void",
+        Some((ControlType::U32, _)) => "uint",
+        Some(_) => "IntPtr",
+    };
+    // the mock replaces the native library, so it is imported under the same name
+    f.writeln(&format!(
+        "[DllImport(\"{}\", CallingConvention = CallingConvention.Cdecl, EntryPoint = \"{}_mock_{}\")]",
+        ffi_name, prefix, function.name
+    ))?;
+    f.writeln(&format!(
+        "internal static extern {} {}({});",
+        return_type,
+        function.name.to_camel_case(),
+        params.join(", ")
+    ))
+}
+
+fn return_type(function: &ControlFunction) -> &'static str {
+    match function.returns {
+        None => "void",
+        Some((ControlType::U32, _)) => "uint",
+        Some(_) => "string",
+    }
+}
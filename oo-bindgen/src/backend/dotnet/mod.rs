@@ -13,11 +13,14 @@ use doc::*;
 use formatting::*;
 
 mod class;
-mod conversion;
+pub(crate) mod conformance;
+mod constraint;
+pub(crate) mod conversion;
 mod doc;
 mod formatting;
-mod helpers;
+pub(crate) mod helpers;
 mod interface;
+pub(crate) mod mock;
 mod structure;
 mod wrappers;
 
@@ -62,6 +65,10 @@ pub(crate) enum TargetFramework {
     /// .NET Standard 2.1 - NOT compatible with any .NET Framework
     /// Defaults to C# 8.0
     NetStandard2_1,
+    /// .NET 8.0 - Uses source generated `[LibraryImport]` marshalling and unmanaged function
+    /// pointers so that the library is compatible with trimming and NativeAOT
+    /// Defaults to C# 12.0
+    Net8_0,
 }
 
 impl std::fmt::Display for TargetFramework {
@@ -75,6 +82,7 @@ impl TargetFramework {
         match self {
             TargetFramework::NetStandard2_0 => "netstandard2.0",
             TargetFramework::NetStandard2_1 => "netstandard2.1",
+            TargetFramework::Net8_0 => "net8.0",
         }
     }
 
@@ -82,6 +90,27 @@ impl TargetFramework {
         match self {
             TargetFramework::NetStandard2_0 => false,
             TargetFramework::NetStandard2_1 => true,
+            TargetFramework::Net8_0 => true,
+        }
+    }
+
+    /// Native calls are generated by the `[LibraryImport]` source generator and all
+    /// native types are blittable, i.e. no runtime marshalling is required
+    pub(crate) fn uses_source_generated_interop(&self) -> bool {
+        match self {
+            TargetFramework::NetStandard2_0 => false,
+            TargetFramework::NetStandard2_1 => false,
+            TargetFramework::Net8_0 => true,
+        }
+    }
+
+    /// Collections of primitives are taken as `ReadOnlySpan<T>` so that arrays, stack allocated
+    /// buffers and slices of them are passed without copying them into a managed collection first
+    pub(crate) fn uses_spans(&self) -> bool {
+        match self {
+            TargetFramework::NetStandard2_0 => false,
+            TargetFramework::NetStandard2_1 => false,
+            TargetFramework::Net8_0 => true,
         }
     }
 }
@@ -99,19 +128,20 @@ pub(crate) fn generate_dotnet_bindings(
     lib: &Library,
     config: &DotnetBindgenConfig,
 ) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::DotNet);
     logged::create_dir_all(&config.output_dir)?;
 
     generate_csproj(lib, config)?;
     generate_targets_scripts(lib, config)?;
     generate_native_functions(lib, config)?;
-    generate_constants(lib, config)?;
-    generate_structs(lib, config)?;
-    generate_enums(lib, config)?;
-    generate_exceptions(lib, config)?;
-    generate_classes(lib, config)?;
-    generate_interfaces(lib, config)?;
-    generate_collection_helpers(lib, config)?;
-    generate_iterator_helpers(lib, config)?;
+    generate_constants(lib, config, naming)?;
+    generate_structs(lib, config, naming)?;
+    generate_enums(lib, config, naming)?;
+    generate_exceptions(lib, config, naming)?;
+    generate_classes(lib, config, naming)?;
+    generate_interfaces(lib, config, naming)?;
+    generate_collection_helpers(lib, config, naming)?;
+    generate_iterator_helpers(lib, config, naming)?;
 
     // generate the helper classes
     generate_helpers(lib, config)?;
@@ -127,10 +157,14 @@ fn generate_helpers(lib: &Library, config: &DotnetBindgenConfig) -> FormattingRe
     let mut filename = config.output_dir.clone();
     filename.push("Helpers");
     filename.set_extension("cs");
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
     print_license(&mut f, &lib.info.license_description)?;
-    f.writeln(include_str!("../../../static/dotnet/Helpers.cs"))
+    if config.target_framework.uses_source_generated_interop() {
+        f.writeln(include_str!("../../../static/dotnet/HelpersNet8.cs"))
+    } else {
+        f.writeln(include_str!("../../../static/dotnet/Helpers.cs"))
+    }
 }
 
 fn generate_csproj(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
@@ -138,7 +172,7 @@ fn generate_csproj(lib: &Library, config: &DotnetBindgenConfig) -> FormattingRes
     let mut filename = config.output_dir.clone();
     filename.push(lib.settings.name.to_string());
     filename.set_extension("csproj");
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
     f.writeln("<Project Sdk=\"Microsoft.NET.Sdk\">")?;
     f.writeln("  <PropertyGroup>")?;
@@ -146,6 +180,10 @@ fn generate_csproj(lib: &Library, config: &DotnetBindgenConfig) -> FormattingRes
         "    <TargetFramework>{}</TargetFramework>",
         config.target_framework.get_target_framework_str()
     ))?;
+    if config.target_framework.uses_source_generated_interop() {
+        f.writeln("    <AllowUnsafeBlocks>true</AllowUnsafeBlocks>")?;
+        f.writeln("    <IsAotCompatible>true</IsAotCompatible>")?;
+    }
     f.writeln("    <GenerateDocumentationFile>true</GenerateDocumentationFile>")?;
     f.writeln("    <IncludeSymbols>true</IncludeSymbols>")?; // Include symbols
     f.writeln("    <SymbolPackageFormat>snupkg</SymbolPackageFormat>")?; // Use new file format
@@ -193,9 +231,12 @@ fn generate_csproj(lib: &Library, config: &DotnetBindgenConfig) -> FormattingRes
 
     // Dependencies and files to include
     f.writeln("  <ItemGroup>")?;
-    f.writeln(
-        "    <PackageReference Include=\"System.Collections.Immutable\" Version=\"1.7.1\" />",
-    )?;
+    // System.Collections.Immutable is part of the shared framework since .NET 5
+    if !config.target_framework.uses_source_generated_interop() {
+        f.writeln(
+            "    <PackageReference Include=\"System.Collections.Immutable\" Version=\"1.7.1\" />",
+        )?;
+    }
     f.writeln(&format!(
         "    <None Include=\"{}\" Pack=\"true\" PackagePath=\"\" />",
         dunce::canonicalize(&lib.info.license_path)?.to_string_lossy()
@@ -229,7 +270,7 @@ fn generate_targets_scripts(lib: &Library, config: &DotnetBindgenConfig) -> Form
 
         filename.push(lib.settings.name.to_string());
         filename.set_extension("targets");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
         f.writeln("<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
         f.writeln("<Project ToolsVersion=\"4.0\" xmlns=\"http://schemas.microsoft.com/developer/msbuild/2003\">")?;
@@ -260,7 +301,7 @@ fn generate_targets_scripts(lib: &Library, config: &DotnetBindgenConfig) -> Form
 
         filename.push(lib.settings.name.to_string());
         filename.set_extension("targets");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
         f.writeln("<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
         f.writeln("<Project ToolsVersion=\"4.0\" xmlns=\"http://schemas.microsoft.com/developer/msbuild/2003\">")?;
@@ -278,62 +319,78 @@ fn generate_native_functions(lib: &Library, config: &DotnetBindgenConfig) -> For
     let mut filename = config.output_dir.clone();
     filename.push(NATIVE_FUNCTIONS_CLASSNAME);
     filename.set_extension("cs");
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
     wrappers::generate_native_functions_class(&mut f, lib, config)
 }
 
-fn generate_constants(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+fn generate_constants(
+    lib: &Library,
+    config: &DotnetBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for constants in lib.constants() {
         // Open file
         let mut filename = config.output_dir.clone();
         filename.push(constants.name.to_string());
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
-        generate_constant_set(&mut f, constants, lib)?;
+        generate_constant_set(&mut f, constants, lib, naming)?;
     }
 
     Ok(())
 }
 
-fn generate_structs(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+fn generate_structs(
+    lib: &Library,
+    config: &DotnetBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for st in lib.structs() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(st.name().camel_case());
+        filename.push(st.name().camel_case(naming));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
-        structure::generate(&mut f, lib, st)?;
+        structure::generate(&mut f, lib, st, config.target_framework)?;
     }
 
     Ok(())
 }
 
-fn generate_enums(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+fn generate_enums(
+    lib: &Library,
+    config: &DotnetBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for native_enum in lib.enums() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(native_enum.name.camel_case());
+        filename.push(native_enum.name.camel_case(naming));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
-        generate_enum(&mut f, native_enum, lib)?;
+        generate_enum(&mut f, native_enum, lib, naming)?;
     }
 
     Ok(())
 }
 
-fn generate_exceptions(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+fn generate_exceptions(
+    lib: &Library,
+    config: &DotnetBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for err in lib.error_types() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(err.exception_name.camel_case());
+        filename.push(err.exception_name.camel_case(naming));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
-        generate_exception(&mut f, err, lib)?;
+        generate_exception(&mut f, err, lib, naming)?;
     }
 
     Ok(())
@@ -343,6 +400,7 @@ fn generate_constant_set(
     f: &mut impl Printer,
     set: &Handle<ConstantSet<Validated>>,
     lib: &Library,
+    naming: &Naming,
 ) -> FormattingResult<()> {
     fn get_type_as_string(value: &ConstantValue) -> &'static str {
         match value {
@@ -357,23 +415,26 @@ fn generate_constant_set(
     }
 
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
-    namespaced(f, &lib.settings.name, |f| {
+    namespaced(f, &dotnet_namespace(lib, &set.module, naming), |f| {
         documentation(f, |f| {
             // Print top-level documentation
             xmldoc_print(f, &set.doc)
         })?;
 
-        f.writeln(&format!("public static class {}", set.name.camel_case()))?;
+        f.writeln(&format!(
+            "public static class {}",
+            set.name.camel_case(f.naming())
+        ))?;
         blocked(f, |f| {
             for value in &set.values {
                 documentation(f, |f| xmldoc_print(f, &value.doc))?;
                 f.writeln(&format!(
                     "public const {} {} = {};",
                     get_type_as_string(&value.value),
-                    value.name.camel_case(),
+                    value.name.camel_case(f.naming()),
                     get_value_as_string(&value.value),
                 ))?;
             }
@@ -386,49 +447,59 @@ fn generate_enum(
     f: &mut impl Printer,
     native_enum: &Handle<Enum<Validated>>,
     lib: &Library,
+    naming: &Naming,
 ) -> FormattingResult<()> {
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
-    namespaced(f, &lib.settings.name, |f| {
-        documentation(f, |f| {
-            // Print top-level documentation
-            xmldoc_print(f, &native_enum.doc)
-        })?;
+    namespaced(
+        f,
+        &dotnet_namespace(lib, &native_enum.module, naming),
+        |f| {
+            documentation(f, |f| {
+                // Print top-level documentation
+                xmldoc_print(f, &native_enum.doc)
+            })?;
 
-        f.writeln(&format!("public enum {}", native_enum.name.camel_case()))?;
-        blocked(f, |f| {
-            for variant in &native_enum.variants {
-                documentation(f, |f| xmldoc_print(f, &variant.doc))?;
-                f.writeln(&format!(
-                    "{} =  {},",
-                    variant.name.camel_case(),
-                    variant.value
-                ))?;
-            }
-            Ok(())
-        })
-    })
+            f.writeln(&format!(
+                "public enum {}",
+                native_enum.name.camel_case(naming)
+            ))?;
+            blocked(f, |f| {
+                for variant in &native_enum.variants {
+                    documentation(f, |f| xmldoc_print(f, &variant.doc))?;
+                    obsolete_attribute(f, &variant.doc)?;
+                    f.writeln(&format!(
+                        "{} =  {},",
+                        variant.name.camel_case(naming),
+                        variant.value
+                    ))?;
+                }
+                Ok(())
+            })
+        },
+    )
 }
 
 fn generate_exception(
     f: &mut impl Printer,
     err: &ErrorType<Validated>,
     lib: &Library,
+    naming: &Naming,
 ) -> FormattingResult<()> {
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
-    namespaced(f, &lib.settings.name, |f| {
+    namespaced(f, &dotnet_namespace(lib, &err.inner.module, naming), |f| {
         documentation(f, |f| {
             // Print top-level documentation
             xmldoc_print(f, &err.inner.doc)
         })?;
 
-        let error_name = err.inner.name.camel_case();
-        let exception_name = err.exception_name.camel_case();
+        let error_name = err.inner.name.camel_case(naming);
+        let exception_name = err.exception_name.camel_case(naming);
 
         f.writeln(&format!("public class {}: Exception", exception_name))?;
         blocked(f, |f| {
@@ -448,37 +519,45 @@ fn generate_exception(
     })
 }
 
-fn generate_classes(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+fn generate_classes(
+    lib: &Library,
+    config: &DotnetBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for class in lib.classes() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(class.name().camel_case());
+        filename.push(class.name().camel_case(naming));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
-        class::generate(&mut f, class, lib)?;
+        class::generate(&mut f, class, lib, config.target_framework)?;
     }
 
     for class in lib.static_classes() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(class.name.camel_case());
+        filename.push(class.name.camel_case(naming));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
-        class::generate_static(&mut f, class, lib)?;
+        class::generate_static(&mut f, class, lib, config.target_framework)?;
     }
 
     Ok(())
 }
 
-fn generate_interfaces(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+fn generate_interfaces(
+    lib: &Library,
+    config: &DotnetBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for interface in lib.interfaces() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(&format!("I{}", interface.name().camel_case()));
+        filename.push(&format!("I{}", interface.name().camel_case(naming)));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
         interface::generate(&mut f, interface, lib, config.target_framework)?;
     }
@@ -486,13 +565,17 @@ fn generate_interfaces(lib: &Library, config: &DotnetBindgenConfig) -> Formattin
     Ok(())
 }
 
-fn generate_iterator_helpers(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+fn generate_iterator_helpers(
+    lib: &Library,
+    config: &DotnetBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for iter in lib.iterators() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(&format!("{}Helpers", iter.name().camel_case()));
+        filename.push(&format!("{}Helpers", iter.name().camel_case(naming)));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
         helpers::generate_iterator_helpers(&mut f, iter, lib)?;
     }
@@ -503,15 +586,16 @@ fn generate_iterator_helpers(lib: &Library, config: &DotnetBindgenConfig) -> For
 fn generate_collection_helpers(
     lib: &Library,
     config: &DotnetBindgenConfig,
+    naming: &Naming,
 ) -> FormattingResult<()> {
     for coll in lib.collections() {
         // Open file
         let mut filename = config.output_dir.clone();
-        filename.push(&format!("{}Helpers", coll.name().camel_case()));
+        filename.push(&format!("{}Helpers", coll.name().camel_case(naming)));
         filename.set_extension("cs");
-        let mut f = FilePrinter::new(filename)?;
+        let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::DotNet))?;
 
-        helpers::generate_collection_helpers(&mut f, coll, lib)?;
+        helpers::generate_collection_helpers(&mut f, coll, lib, config.target_framework)?;
     }
 
     Ok(())
@@ -526,11 +610,43 @@ fn print_license(f: &mut dyn Printer, license: &[String]) -> FormattingResult<()
     })
 }
 
-fn print_imports(f: &mut dyn Printer) -> FormattingResult<()> {
+/// The bindings use their own obsolete members, which is not something to warn about
+fn print_obsolete_pragma(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    if lib.has_deprecated_items() {
+        f.writeln("#pragma warning disable 618")?;
+    }
+    Ok(())
+}
+
+fn print_imports(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
     f.writeln("using System;")?;
     f.writeln("using System.Runtime.InteropServices;")?;
     f.writeln("using System.Threading.Tasks;")?;
-    f.writeln("using System.Collections.Immutable;")
+    f.writeln("using System.Collections.Immutable;")?;
+    // the namespaces of the modules are only visible from their own ones
+    for namespace in module_namespaces(lib, f.naming()) {
+        f.writeln(&format!("using {};", namespace))?;
+    }
+    Ok(())
+}
+
+/// C# namespace of a module, nested in the one of the library
+pub(crate) fn dotnet_namespace(lib: &Library, module: &Module, naming: &Naming) -> String {
+    let mut namespace = lib.settings.name.to_string();
+    for name in module.path() {
+        namespace.push('.');
+        namespace.push_str(&name.snake_case(naming));
+    }
+    namespace
+}
+
+/// C# namespaces of the modules with types, other than the namespace of the library
+pub(crate) fn module_namespaces(lib: &Library, naming: &Naming) -> Vec<String> {
+    lib.type_modules()
+        .into_iter()
+        .filter(|x| !x.is_root())
+        .map(|x| dotnet_namespace(lib, x, naming))
+        .collect()
 }
 
 fn generate_doxygen(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
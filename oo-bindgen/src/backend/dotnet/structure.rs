@@ -1,3 +1,4 @@
+use crate::backend::dotnet::constraint::write_constraint_check;
 use crate::backend::dotnet::conversion::*;
 use crate::backend::dotnet::doc::*;
 use crate::backend::dotnet::formatting::*;
@@ -7,20 +8,21 @@ pub(crate) fn generate(
     f: &mut dyn Printer,
     lib: &Library,
     st: &StructType<Validated>,
+    framework: TargetFramework,
 ) -> FormattingResult<()> {
     match st {
-        StructType::FunctionArg(x) => {
-            generate_skeleton(f, x, lib, true, &|f| generate_to_native_conversions(f, x))
-        }
-        StructType::FunctionReturn(x) => {
-            generate_skeleton(f, x, lib, false, &|f| generate_to_dotnet_conversions(f, x))
-        }
-        StructType::CallbackArg(x) => {
-            generate_skeleton(f, x, lib, false, &|f| generate_to_dotnet_conversions(f, x))
-        }
+        StructType::FunctionArg(x) => generate_skeleton(f, x, lib, true, &|f| {
+            generate_to_native_conversions(f, x, framework)
+        }),
+        StructType::FunctionReturn(x) => generate_skeleton(f, x, lib, false, &|f| {
+            generate_to_dotnet_conversions(f, x, framework)
+        }),
+        StructType::CallbackArg(x) => generate_skeleton(f, x, lib, false, &|f| {
+            generate_to_dotnet_conversions(f, x, framework)
+        }),
         StructType::Universal(x) => generate_skeleton(f, x, lib, true, &|f| {
-            generate_to_native_conversions(f, x)?;
-            generate_to_dotnet_conversions(f, x)
+            generate_to_native_conversions(f, x, framework)?;
+            generate_to_dotnet_conversions(f, x, framework)
         }),
     }
 }
@@ -41,42 +43,52 @@ impl DotNetVisibility for Visibility {
 fn get_field_value<T>(
     field: &StructField<T, Validated>,
     constructor: &Initializer<Validated>,
+    naming: &Naming,
 ) -> String
 where
     T: StructFieldType,
 {
     match constructor.values.iter().find(|x| x.name == field.name) {
-        Some(x) => match &x.value {
-            ValidatedDefaultValue::Bool(x) => x.to_string(),
-            ValidatedDefaultValue::Number(x) => match x {
-                NumberValue::U8(x) => x.to_string(),
-                NumberValue::S8(x) => x.to_string(),
-                NumberValue::U16(x) => x.to_string(),
-                NumberValue::S16(x) => x.to_string(),
-                NumberValue::U32(x) => x.to_string(),
-                NumberValue::S32(x) => x.to_string(),
-                NumberValue::U64(x) => x.to_string(),
-                NumberValue::S64(x) => x.to_string(),
-                NumberValue::Float(x) => format!("{}F", x),
-                NumberValue::Double(x) => x.to_string(),
-            },
-            ValidatedDefaultValue::Duration(t, x) => match t {
-                DurationType::Milliseconds => {
-                    format!("TimeSpan.FromMilliseconds({})", t.get_value_string(*x))
-                }
-                DurationType::Seconds => {
-                    format!("TimeSpan.FromSeconds({})", t.get_value_string(*x))
-                }
-            },
-            ValidatedDefaultValue::Enum(x, variant) => {
-                format!("{}.{}", x.name.camel_case(), variant.camel_case())
+        Some(x) => default_value_expression(&x.value, naming),
+        None => field.name.mixed_case(naming),
+    }
+}
+
+/// C# expression that evaluates to a default value
+pub(crate) fn default_value_expression(value: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => match x {
+            NumberValue::U8(x) => x.to_string(),
+            NumberValue::S8(x) => x.to_string(),
+            NumberValue::U16(x) => x.to_string(),
+            NumberValue::S16(x) => x.to_string(),
+            NumberValue::U32(x) => x.to_string(),
+            NumberValue::S32(x) => x.to_string(),
+            NumberValue::U64(x) => x.to_string(),
+            NumberValue::S64(x) => x.to_string(),
+            NumberValue::Float(x) => format!("{}F", x),
+            NumberValue::Double(x) => x.to_string(),
+        },
+        ValidatedDefaultValue::Duration(t, x) => match t {
+            DurationType::Milliseconds => {
+                format!("TimeSpan.FromMilliseconds({})", t.get_value_string(*x))
             }
-            ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
-            ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
-                format!("new {}()", handle.name().camel_case())
+            DurationType::Seconds => {
+                format!("TimeSpan.FromSeconds({})", t.get_value_string(*x))
             }
         },
-        None => field.name.mixed_case(),
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!(
+                "{}.{}",
+                x.name.camel_case(naming),
+                variant.camel_case(naming)
+            )
+        }
+        ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
+        ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
+            format!("new {}()", handle.name().camel_case(naming))
+        }
     }
 }
 
@@ -92,27 +104,27 @@ where
 
     let invocation_args = handle
         .fields()
-        .map(|sf| get_field_value(sf, constructor))
+        .map(|sf| get_field_value(sf, constructor, f.naming()))
         .collect::<Vec<String>>()
         .join(", ");
 
     f.writeln(&format!(
         "public static {} {}({})",
-        handle.name().camel_case(),
-        constructor.name.camel_case(),
-        constructor_parameters(handle, constructor)
+        handle.name().camel_case(f.naming()),
+        constructor.name.camel_case(f.naming()),
+        constructor_parameters(handle, constructor, f.naming())
     ))?;
 
     blocked(f, |f| {
         f.writeln(&format!(
             "return new {}({});",
-            handle.declaration.name().camel_case(),
+            handle.declaration.name().camel_case(f.naming()),
             invocation_args
         ))
     })
 }
 
-fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
+fn get_default_value_doc(x: &ValidatedDefaultValue, naming: &Naming) -> String {
     match x {
         ValidatedDefaultValue::Bool(x) => x.to_string(),
         ValidatedDefaultValue::Number(x) => x.to_string(),
@@ -122,12 +134,12 @@ fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
         ValidatedDefaultValue::Duration(DurationType::Seconds, x) => format!("{}s", x.as_secs()),
         ValidatedDefaultValue::Enum(handle, variant) => format!(
             "<see cref=\"{}.{}\" />",
-            handle.name.camel_case(),
-            variant.camel_case()
+            handle.name.camel_case(naming),
+            variant.camel_case(naming)
         ),
         ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
         ValidatedDefaultValue::DefaultStruct(x, _, _) => {
-            format!("Default <see cref=\"{}\" />", x.name().camel_case())
+            format!("Default <see cref=\"{}\" />", x.name().camel_case(naming))
         }
     }
 }
@@ -152,9 +164,9 @@ where
             for init_value in constructor.values.iter() {
                 f.writeln(&format!(
                     "<item><description><see cref=\"{}.{}\" />: {}</description></item>",
-                    handle.name().camel_case(),
-                    init_value.name.camel_case(),
-                    get_default_value_doc(&init_value.value)
+                    handle.name().camel_case(f.naming()),
+                    init_value.name.camel_case(f.naming()),
+                    get_default_value_doc(&init_value.value, f.naming())
                 ))?;
             }
             f.writeln("</list>")?;
@@ -164,7 +176,10 @@ where
         f.newline()?;
 
         for arg in handle.initializer_args(constructor.clone()) {
-            f.writeln(&format!("<param name=\"{}\">", arg.name.mixed_case()))?;
+            f.writeln(&format!(
+                "<param name=\"{}\">",
+                arg.name.unescaped_mixed_case(f.naming())
+            ))?;
             docstring_print(f, &arg.doc.brief)?;
             f.write("</param>")?;
         }
@@ -172,7 +187,7 @@ where
         if write_return_info {
             f.writeln(&format!(
                 "<returns>Initialized <see cref=\"{}\" /> instance </returns>",
-                handle.name().camel_case()
+                handle.name().camel_case(f.naming())
             ))?;
         }
 
@@ -183,6 +198,7 @@ where
 fn constructor_parameters<T>(
     handle: &Struct<T, Validated>,
     constructor: &Handle<Initializer<Validated>>,
+    naming: &Naming,
 ) -> String
 where
     T: StructFieldType + TypeInfo,
@@ -192,8 +208,8 @@ where
         .map(|sf| {
             format!(
                 "{} {}",
-                sf.field_type.get_dotnet_type(),
-                sf.name.mixed_case()
+                sf.field_type.get_dotnet_type(naming),
+                sf.name.mixed_case(naming)
             )
         })
         .collect::<Vec<String>>()
@@ -221,16 +237,16 @@ where
     f.writeln(&format!(
         "{} {}({})",
         visibility.to_str(),
-        handle.name().camel_case(),
-        constructor_parameters(handle, constructor)
+        handle.name().camel_case(f.naming()),
+        constructor_parameters(handle, constructor, f.naming())
     ))?;
     blocked(f, |f| {
         for field in &handle.fields {
             indented(f, |f| {
                 f.writeln(&format!(
                     "this.{} = {};",
-                    field.name.camel_case(),
-                    get_field_value(field, constructor)
+                    field.name.camel_case(f.naming()),
+                    get_field_value(field, constructor, f.naming())
                 ))
             })?;
         }
@@ -242,11 +258,12 @@ where
 fn generate_to_native_conversions<T>(
     f: &mut dyn Printer,
     handle: &Struct<T, Validated>,
+    framework: TargetFramework,
 ) -> FormattingResult<()>
 where
     T: StructFieldType + ConvertToNative,
 {
-    let struct_name = handle.name().camel_case();
+    let struct_name = handle.name().camel_case(f.naming());
     let struct_native_name = format!("{}Native", struct_name);
 
     f.newline()?;
@@ -259,11 +276,11 @@ where
     blocked(f, |f| {
         f.writeln(&format!("{} result;", struct_native_name))?;
         for el in handle.fields() {
-            let el_name = el.name.camel_case();
+            let el_name = el.name.camel_case(f.naming());
 
             let conversion = el
                 .field_type
-                .convert_to_native(&format!("self.{}", el_name))
+                .convert_to_native(&format!("self.{}", el_name), f.naming())
                 .unwrap_or(format!("self.{}", el_name));
             f.writeln(&format!("result.{} = {};", el_name, conversion))?;
         }
@@ -282,8 +299,19 @@ where
         f.writeln("if (self != null)")?;
         blocked(f, |f| {
             f.writeln("var nativeStruct = ToNative(self);")?;
-            f.writeln("handle = Marshal.AllocHGlobal(Marshal.SizeOf(nativeStruct));")?;
-            f.writeln("Marshal.StructureToPtr(nativeStruct, handle, false);")?;
+            if framework.uses_source_generated_interop() {
+                f.writeln("unsafe")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "handle = Marshal.AllocHGlobal(sizeof({}));",
+                        struct_native_name
+                    ))?;
+                    f.writeln(&format!("*({}*)handle = nativeStruct;", struct_native_name))
+                })?;
+            } else {
+                f.writeln("handle = Marshal.AllocHGlobal(Marshal.SizeOf(nativeStruct));")?;
+                f.writeln("Marshal.StructureToPtr(nativeStruct, handle, false);")?;
+            }
             f.writeln("nativeStruct.Dispose();")
         })?;
         f.writeln("return handle;")
@@ -295,9 +323,12 @@ where
     f.writeln("internal void Dispose()")?;
     blocked(f, |f| {
         for el in handle.fields() {
-            let el_name = el.name.camel_case();
+            let el_name = el.name.camel_case(f.naming());
 
-            if let Some(cleanup) = el.field_type.cleanup_native(&format!("this.{}", el_name)) {
+            if let Some(cleanup) = el
+                .field_type
+                .cleanup_native(&format!("this.{}", el_name), f.naming())
+            {
                 f.writeln(&cleanup)?;
             }
         }
@@ -308,11 +339,12 @@ where
 fn generate_to_dotnet_conversions<T>(
     f: &mut dyn Printer,
     handle: &Struct<T, Validated>,
+    framework: TargetFramework,
 ) -> FormattingResult<()>
 where
     T: StructFieldType + ConvertToDotNet,
 {
-    let struct_name = handle.name().camel_case();
+    let struct_name = handle.name().camel_case(f.naming());
     let struct_native_name = format!("{}Native", struct_name);
 
     f.newline()?;
@@ -323,21 +355,41 @@ where
         struct_name, struct_native_name
     ))?;
     blocked(f, |f| {
-        f.writeln(&format!("return new {}", struct_name))?;
-        f.writeln("{")?;
-        indented(f, |f| {
-            for el in handle.fields() {
-                let el_name = el.name.camel_case();
-
-                let conversion = el
-                    .field_type
-                    .convert_to_dotnet(&format!("native.{}", el_name))
-                    .unwrap_or(format!("native.{}", el_name));
-                f.writeln(&format!("{} = {},", el_name, conversion))?;
-            }
-            Ok(())
-        })?;
-        f.writeln("};")
+        let naming = f.naming().clone();
+        let conversions = handle.fields().map(|el| {
+            let el_name = el.name.camel_case(&naming);
+            let conversion = el
+                .field_type
+                .convert_to_dotnet(&format!("native.{}", el_name), &naming)
+                .unwrap_or(format!("native.{}", el_name));
+            (el_name, conversion)
+        });
+
+        if handle.is_immutable() {
+            // readonly fields can only be assigned by the full constructor
+            f.writeln(&format!("return new {}(", struct_name))?;
+            indented(f, |f| {
+                for ((_, conversion), last) in conversions.with_last() {
+                    if last {
+                        f.writeln(&conversion)?;
+                    } else {
+                        f.writeln(&format!("{},", conversion))?;
+                    }
+                }
+                Ok(())
+            })?;
+            f.writeln(");")
+        } else {
+            f.writeln(&format!("return new {}", struct_name))?;
+            f.writeln("{")?;
+            indented(f, |f| {
+                for (el_name, conversion) in conversions {
+                    f.writeln(&format!("{} = {},", el_name, conversion))?;
+                }
+                Ok(())
+            })?;
+            f.writeln("};")
+        }
     })?;
 
     f.newline()?;
@@ -351,16 +403,167 @@ where
         f.writeln(&format!("{} handle = null;", struct_name))?;
         f.writeln("if (native != IntPtr.Zero)")?;
         blocked(f, |f| {
-            f.writeln(&format!(
-                "var nativeStruct = Marshal.PtrToStructure<{}>(native);",
-                struct_native_name
-            ))?;
-            f.writeln("handle = FromNative(nativeStruct);")
+            if framework.uses_source_generated_interop() {
+                f.writeln("unsafe")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "handle = FromNative(*({}*)native);",
+                        struct_native_name
+                    ))
+                })?;
+            } else {
+                f.writeln(&format!(
+                    "var nativeStruct = Marshal.PtrToStructure<{}>(native);",
+                    struct_native_name
+                ))?;
+                f.writeln("handle = FromNative(nativeStruct);")?;
+            }
+            Ok(())
         })?;
         f.writeln("return handle;")
     })
 }
 
+fn write_value_semantics<T>(
+    f: &mut dyn Printer,
+    handle: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + TypeInfo,
+{
+    let struct_name = handle.name().camel_case(f.naming());
+
+    documentation(f, |f| {
+        f.writeln(
+            "<summary>Determines whether the specified object is equal to this instance</summary>",
+        )
+    })?;
+    f.writeln("public override bool Equals(object obj)")?;
+    blocked(f, |f| {
+        f.writeln(&format!("return Equals(obj as {});", struct_name))
+    })?;
+
+    f.newline()?;
+
+    documentation(f, |f| {
+        f.writeln(&format!(
+            "<summary>Determines whether the specified <see cref=\"{}\" /> has the same field values as this instance</summary>",
+            struct_name
+        ))
+    })?;
+    f.writeln(&format!("public bool Equals({} other)", struct_name))?;
+    blocked(f, |f| {
+        f.writeln("if (ReferenceEquals(other, null)) return false;")?;
+        f.writeln("if (ReferenceEquals(this, other)) return true;")?;
+        if handle.fields.is_empty() {
+            return f.writeln("return true;");
+        }
+        f.writeln("return")?;
+        indented(f, |f| {
+            for (field, last) in handle.fields.iter().with_last() {
+                let terminator = if last { ";" } else { " &&" };
+                f.writeln(&format!(
+                    "System.Collections.Generic.EqualityComparer<{}>.Default.Equals(this.{}, other.{}){}",
+                    field.field_type.get_dotnet_type(f.naming()),
+                    field.name.camel_case(f.naming()),
+                    field.name.camel_case(f.naming()),
+                    terminator
+                ))?;
+            }
+            Ok(())
+        })
+    })?;
+
+    f.newline()?;
+
+    documentation(f, |f| {
+        f.writeln("<summary>Computes a hash code from the field values</summary>")
+    })?;
+    f.writeln("public override int GetHashCode()")?;
+    blocked(f, |f| {
+        f.writeln("unchecked")?;
+        blocked(f, |f| {
+            f.writeln("int hash = 17;")?;
+            for field in handle.fields() {
+                f.writeln(&format!(
+                    "hash = hash * 31 + System.Collections.Generic.EqualityComparer<{}>.Default.GetHashCode(this.{});",
+                    field.field_type.get_dotnet_type(f.naming()),
+                    field.name.camel_case(f.naming())
+                ))?;
+            }
+            f.writeln("return hash;")
+        })
+    })?;
+
+    f.newline()?;
+
+    documentation(f, |f| {
+        f.writeln("<summary>Returns a string representation of the field values</summary>")
+    })?;
+    f.writeln("public override string ToString()")?;
+    blocked(f, |f| {
+        let values = handle
+            .fields()
+            .map(|x| {
+                format!(
+                    "{} = \" + this.{} + \"",
+                    x.name.camel_case(f.naming()),
+                    x.name.camel_case(f.naming())
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        f.writeln(&format!("return \"{} {{ {} }}\";", struct_name, values))
+    })?;
+
+    f.newline()?;
+
+    documentation(f, |f| {
+        f.writeln("<summary>Compares two instances by value</summary>")
+    })?;
+    f.writeln(&format!(
+        "public static bool operator ==({} left, {} right)",
+        struct_name, struct_name
+    ))?;
+    blocked(f, |f| f.writeln("return object.Equals(left, right);"))?;
+
+    f.newline()?;
+
+    documentation(f, |f| {
+        f.writeln("<summary>Compares two instances by value</summary>")
+    })?;
+    f.writeln(&format!(
+        "public static bool operator !=({} left, {} right)",
+        struct_name, struct_name
+    ))?;
+    blocked(f, |f| f.writeln("return !object.Equals(left, right);"))
+}
+
+fn write_constraint_checker<T>(
+    f: &mut dyn Printer,
+    handle: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType,
+{
+    f.writeln("internal void _AssertFieldsValid()")?;
+    blocked(f, |f| {
+        for field in handle.fields.iter().filter(|x| x.has_constraints()) {
+            let value = format!("this.{}", field.name.camel_case(f.naming()));
+            if let Some(target) = field.field_type.constrained_type() {
+                let name = field.name.unescaped_mixed_case(f.naming());
+                for constraint in &field.constraints {
+                    write_constraint_check(f, constraint, target, &value, &name)?;
+                }
+            }
+            if field.field_type.has_nested_constraints() {
+                f.writeln(&format!("{}._AssertFieldsValid();", value))?;
+            }
+        }
+        Ok(())
+    })
+}
+
 fn generate_skeleton<T>(
     f: &mut dyn Printer,
     handle: &Struct<T, Validated>,
@@ -371,11 +574,12 @@ fn generate_skeleton<T>(
 where
     T: StructFieldType + TypeInfo,
 {
-    let struct_name = handle.name().camel_case();
+    let struct_name = handle.name().camel_case(f.naming());
     let struct_native_name = format!("{}Native", struct_name);
 
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_obsolete_pragma(f, lib)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
     let doc = match handle.visibility {
@@ -386,13 +590,21 @@ where
             .warning("This class is an opaque handle and cannot be constructed by user code"),
     };
 
-    namespaced(f, &lib.settings.name, |f| {
+    let namespace = dotnet_namespace(lib, handle.module(), f.naming());
+    namespaced(f, &namespace, |f| {
         documentation(f, |f| {
             // Print top-level documentation
             xmldoc_print(f, &doc)
         })?;
 
-        f.writeln(&format!("public class {}", struct_name))?;
+        if handle.has_value_semantics() {
+            f.writeln(&format!(
+                "public class {} : IEquatable<{}>",
+                struct_name, struct_name
+            ))?;
+        } else {
+            f.writeln(&format!("public class {}", struct_name))?;
+        }
         blocked(f, |f| {
             // Write .NET structure elements
             for field in handle.fields() {
@@ -401,12 +613,20 @@ where
                     xmldoc_print(f, &field.doc)?;
                     Ok(())
                 })?;
+                obsolete_attribute(f, &field.doc)?;
+
+                let modifier = if handle.is_immutable() {
+                    " readonly"
+                } else {
+                    ""
+                };
 
                 f.writeln(&format!(
-                    "{} {} {};",
+                    "{}{} {} {};",
                     handle.visibility.to_str(),
-                    field.field_type.get_dotnet_type(),
-                    field.name.camel_case()
+                    modifier,
+                    field.field_type.get_dotnet_type(f.naming()),
+                    field.name.camel_case(f.naming())
                 ))?;
             }
 
@@ -420,16 +640,36 @@ where
                         xmldoc_print(f, &field.doc)?;
                         Ok(())
                     })?;
+                    obsolete_attribute(f, &field.doc)?;
 
                     f.writeln(&format!(
                         "public {} With{}({} value)",
                         struct_name,
-                        field.name.camel_case(),
-                        field.field_type.get_dotnet_type(),
+                        field.name.camel_case(f.naming()),
+                        field.field_type.get_dotnet_type(f.naming()),
                     ))?;
                     blocked(f, |f| {
-                        f.writeln(&format!("this.{} = value;", field.name.camel_case(),))?;
-                        f.writeln("return this;")
+                        if handle.is_immutable() {
+                            // immutable structs return a modified copy
+                            let args = handle
+                                .fields()
+                                .map(|x| {
+                                    if x.name == field.name {
+                                        "value".to_string()
+                                    } else {
+                                        format!("this.{}", x.name.camel_case(f.naming()))
+                                    }
+                                })
+                                .collect::<Vec<String>>()
+                                .join(", ");
+                            f.writeln(&format!("return new {}({});", struct_name, args))
+                        } else {
+                            f.writeln(&format!(
+                                "this.{} = value;",
+                                field.name.camel_case(f.naming()),
+                            ))?;
+                            f.writeln("return this;")
+                        }
                     })?;
                 }
             }
@@ -458,7 +698,20 @@ where
             if !handle.has_default_initializer() {
                 // Internal parameter-less constructor
                 f.newline()?;
-                f.writeln(&format!("internal {}() {{ }}", handle.name().camel_case()))?;
+                f.writeln(&format!(
+                    "internal {}() {{ }}",
+                    handle.name().camel_case(f.naming())
+                ))?;
+            }
+
+            if handle.has_value_semantics() {
+                f.newline()?;
+                write_value_semantics(f, handle)?;
+            }
+
+            if handle.has_constraints() {
+                f.newline()?;
+                write_constraint_checker(f, handle)?;
             }
 
             Ok(())
@@ -474,8 +727,8 @@ where
             for el in handle.fields() {
                 f.writeln(&format!(
                     "{} {};",
-                    el.field_type.get_native_type(),
-                    el.name.camel_case()
+                    el.field_type.get_native_type(f.naming()),
+                    el.name.camel_case(f.naming())
                 ))?;
             }
 
@@ -12,7 +12,8 @@ pub(crate) fn generate_native_functions_class(
     config: &DotnetBindgenConfig,
 ) -> FormattingResult<()> {
     print_license(f, &lib.info.license_description)?;
-    print_imports(f)?;
+    print_obsolete_pragma(f, lib)?;
+    print_imports(f, lib)?;
     f.newline()?;
 
     doxygen(f, |f| {
@@ -36,6 +37,12 @@ pub(crate) fn generate_native_functions_class(
     })?;
     f.newline()?;
 
+    if config.target_framework.uses_source_generated_interop() {
+        // every native type is blittable, so no marshalling stubs are ever required
+        f.writeln("[assembly: System.Runtime.CompilerServices.DisableRuntimeMarshalling]")?;
+        f.newline()?;
+    }
+
     namespaced(f, &lib.settings.name, |f| {
         f.writeln(&format!("internal class {}", NATIVE_FUNCTIONS_CLASSNAME))?;
         blocked(f, |f| {
@@ -73,7 +80,11 @@ pub(crate) fn generate_native_functions_class(
 
         f.newline()?;
 
-        f.writeln("internal class PInvoke")?;
+        if config.target_framework.uses_source_generated_interop() {
+            f.writeln("internal static partial class PInvoke")?;
+        } else {
+            f.writeln("internal class PInvoke")?;
+        }
         blocked(f, |f| {
             for func in lib.functions() {
                 write_pinvoke_signature(f, func, &lib.settings.c_ffi_prefix, config)?;
@@ -93,18 +104,18 @@ fn write_exception_and_return_blocks(
         Some(ret) => {
             f.writeln(&format!(
                 "var _error_result = PInvoke.{}({}, out {} _return_value);",
-                func.name.camel_case(),
+                func.name.camel_case(f.naming()),
                 params,
-                ret.value.get_native_type()
+                ret.value.get_native_type(f.naming())
             ))?;
             f.writeln(&format!(
                 "if(_error_result != {}.Ok)",
-                err.inner.name.camel_case()
+                err.inner.name.camel_case(f.naming())
             ))?;
             blocked(f, |f| {
                 f.writeln(&format!(
                     "throw new {}(_error_result);",
-                    err.exception_name.camel_case()
+                    err.exception_name.camel_case(f.naming())
                 ))
             })?;
             f.writeln("return _return_value;")
@@ -112,14 +123,17 @@ fn write_exception_and_return_blocks(
         None => {
             f.writeln(&format!(
                 "var error = PInvoke.{}({});",
-                func.name.camel_case(),
+                func.name.camel_case(f.naming()),
                 params
             ))?;
-            f.writeln(&format!("if(error != {}.Ok)", err.inner.name.camel_case()))?;
+            f.writeln(&format!(
+                "if(error != {}.Ok)",
+                err.inner.name.camel_case(f.naming())
+            ))?;
             blocked(f, |f| {
                 f.writeln(&format!(
                     "throw new {}(error);",
-                    err.exception_name.camel_case()
+                    err.exception_name.camel_case(f.naming())
                 ))
             })
         }
@@ -132,15 +146,21 @@ fn write_conversion_wrapper(
 ) -> FormattingResult<()> {
     f.write(&format!(
         "internal static {} {}(",
-        func.return_type.get_native_type(),
-        func.name.camel_case()
+        func.return_type.get_native_type(f.naming()),
+        func.name.camel_case(f.naming())
     ))?;
 
     f.write(
         &func
             .arguments
             .iter()
-            .map(|param| format!("{} {}", param.arg_type.get_native_type(), param.name))
+            .map(|param| {
+                format!(
+                    "{} {}",
+                    param.arg_type.get_native_type(f.naming()),
+                    param.name
+                )
+            })
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -168,7 +188,7 @@ fn write_conversion_wrapper(
         f.write(&format!(
             "{}.{}({});",
             target,
-            func.name.camel_case(),
+            func.name.camel_case(f.naming()),
             params
         ))
     })
@@ -181,15 +201,21 @@ fn write_exception_wrapper(
 ) -> FormattingResult<()> {
     f.write(&format!(
         "internal static {} {}(",
-        func.return_type.get_native_type(),
-        func.name.camel_case(),
+        func.return_type.get_native_type(f.naming()),
+        func.name.camel_case(f.naming()),
     ))?;
 
     f.write(
         &func
             .arguments
             .iter()
-            .map(|param| format!("{} {}", param.arg_type.get_native_type(), param.name))
+            .map(|param| {
+                format!(
+                    "{} {}",
+                    param.arg_type.get_native_type(f.naming()),
+                    param.name
+                )
+            })
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -214,23 +240,35 @@ fn write_pinvoke_signature(
     prefix: &str,
     config: &DotnetBindgenConfig,
 ) -> FormattingResult<()> {
-    f.writeln(&format!(
-        "[DllImport(\"{}\", CallingConvention = CallingConvention.Cdecl, EntryPoint = \"{}_{}\")]",
-        config.ffi_name, prefix, handle.name
-    ))?;
+    let modifier = if config.target_framework.uses_source_generated_interop() {
+        f.writeln(&format!(
+            "[LibraryImport(\"{}\", EntryPoint = \"{}_{}\")]",
+            config.ffi_name, prefix, handle.name
+        ))?;
+        f.writeln("[UnmanagedCallConv(CallConvs = new[] { typeof(System.Runtime.CompilerServices.CallConvCdecl) })]")?;
+        "partial"
+    } else {
+        f.writeln(&format!(
+            "[DllImport(\"{}\", CallingConvention = CallingConvention.Cdecl, EntryPoint = \"{}_{}\")]",
+            config.ffi_name, prefix, handle.name
+        ))?;
+        "extern"
+    };
     f.newline()?;
 
     if let Some(err) = handle.error_type.get() {
         f.write(&format!(
-            "internal static extern {} {}(",
-            err.inner.get_native_type(),
-            handle.name.camel_case(),
+            "internal static {} {} {}(",
+            modifier,
+            err.inner.get_native_type(f.naming()),
+            handle.name.camel_case(f.naming()),
         ))?;
     } else {
         f.write(&format!(
-            "internal static extern {} {}(",
-            handle.return_type.get_native_type(),
-            handle.name.camel_case()
+            "internal static {} {} {}(",
+            modifier,
+            handle.return_type.get_native_type(f.naming()),
+            handle.name.camel_case(f.naming())
         ))?;
     }
 
@@ -238,7 +276,13 @@ fn write_pinvoke_signature(
         &handle
             .arguments
             .iter()
-            .map(|param| format!("{} {}", param.arg_type.get_native_type(), param.name))
+            .map(|param| {
+                format!(
+                    "{} {}",
+                    param.arg_type.get_native_type(f.naming()),
+                    param.name
+                )
+            })
             .collect::<Vec<String>>()
             .join(", "),
     )?;
@@ -247,7 +291,7 @@ fn write_pinvoke_signature(
         if !handle.arguments.is_empty() {
             f.write(", ")?;
         }
-        f.write(&format!("out {} @out", ret.get_native_type()))?
+        f.write(&format!("out {} @out", ret.get_native_type(f.naming())))?
     }
 
     f.write(");")
@@ -0,0 +1,173 @@
+use std::path::Path;
+
+use heck::CamelCase;
+
+use crate::backend::dotnet::module_namespaces;
+use crate::backend::java::api::library_packages;
+use crate::backend::*;
+use crate::model::*;
+
+/// Write the code examples of the documentation to `c/doc_examples.c`, `cpp/doc_examples.cpp`,
+/// `java/DocExamples.java` and `dotnet/DocExamples.cs`, each snippet in its own function
+/// so that the examples are checked by the compiler of every language
+pub(crate) fn extract(lib: &Library, group_id: &str, output_dir: &Path) -> FormattingResult<()> {
+    let examples = lib.examples();
+
+    let c_dir = output_dir.join("c");
+    let cpp_dir = output_dir.join("cpp");
+    let java_dir = output_dir.join("java");
+    let dotnet_dir = output_dir.join("dotnet");
+    logged::create_dir_all(&c_dir)?;
+    logged::create_dir_all(&cpp_dir)?;
+    logged::create_dir_all(&java_dir)?;
+    logged::create_dir_all(&dotnet_dir)?;
+
+    write_c(lib, &examples, &c_dir.join("doc_examples.c"))?;
+    write_cpp(lib, &examples, &cpp_dir.join("doc_examples.cpp"))?;
+    write_java(lib, &examples, group_id, &java_dir.join("DocExamples.java"))?;
+    write_dotnet(lib, &examples, &dotnet_dir.join("DocExamples.cs"))
+}
+
+fn write_c(lib: &Library, examples: &[(String, &Example)], path: &Path) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::C))?;
+
+    f.writeln(&format!(
+        "// Documentation examples of the {} C bindings extracted by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.newline()?;
+    f.writeln("#include <stdbool.h>")?;
+    f.writeln("#include <stddef.h>")?;
+    f.writeln("#include <stdint.h>")?;
+    f.writeln("#include <stdio.h>")?;
+    f.writeln("#include <string.h>")?;
+    f.newline()?;
+    f.writeln(&format!("#include \"{}.h\"", lib.settings.name))?;
+
+    for (name, example) in examples {
+        if let Some(code) = example.get(ExampleLanguage::C) {
+            f.newline()?;
+            f.writeln(&format!("void example_{}()", name))?;
+            blocked(&mut f, |f| write_lines(f, code))?;
+        }
+    }
+    Ok(())
+}
+
+fn write_cpp(lib: &Library, examples: &[(String, &Example)], path: &Path) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::Cpp))?;
+
+    f.writeln(&format!(
+        "// Documentation examples of the {} C++ bindings extracted by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.newline()?;
+    f.writeln("#include <chrono>")?;
+    f.writeln("#include <iostream>")?;
+    f.writeln("#include <memory>")?;
+    f.writeln("#include <string>")?;
+    f.writeln("#include <vector>")?;
+    f.newline()?;
+    f.writeln(&format!("#include \"{}.hpp\"", lib.settings.name))?;
+
+    for (name, example) in examples {
+        if let Some(code) = example.get(ExampleLanguage::Cpp) {
+            f.newline()?;
+            f.writeln(&format!("void example_{}()", name))?;
+            blocked(&mut f, |f| write_lines(f, code))?;
+        }
+    }
+    Ok(())
+}
+
+fn write_java(
+    lib: &Library,
+    examples: &[(String, &Example)],
+    group_id: &str,
+    path: &Path,
+) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::Java);
+    let mut f = FilePrinter::new(path, naming.clone())?;
+
+    f.writeln(&format!(
+        "// Documentation examples of the {} Java bindings extracted by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.writeln(&format!("package {}.{}_test;", group_id, lib.settings.name))?;
+    f.newline()?;
+    for package in library_packages(group_id, lib, naming) {
+        f.writeln(&format!("import {}.*;", package))?;
+    }
+    f.writeln("import org.joou.*;")?;
+    f.newline()?;
+    f.writeln("import static org.joou.Unsigned.*;")?;
+    f.newline()?;
+    f.writeln("class DocExamples")?;
+    blocked(&mut f, |f| {
+        let mut first = true;
+        for (name, example) in examples {
+            if let Some(code) = example.get(ExampleLanguage::Java) {
+                if !first {
+                    f.newline()?;
+                }
+                first = false;
+                // the snippet may call methods throwing checked exceptions
+                f.writeln(&format!(
+                    "static void example{}() throws Exception",
+                    name.to_camel_case()
+                ))?;
+                blocked(f, |f| write_lines(f, code))?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn write_dotnet(
+    lib: &Library,
+    examples: &[(String, &Example)],
+    path: &Path,
+) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::DotNet);
+    let mut f = FilePrinter::new(path, naming.clone())?;
+
+    f.writeln(&format!(
+        "// Documentation examples of the {} .NET bindings extracted by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.writeln("using System;")?;
+    f.writeln("using System.Collections.Generic;")?;
+    f.writeln(&format!("using {};", lib.settings.name))?;
+    for namespace in module_namespaces(lib, naming) {
+        f.writeln(&format!("using {};", namespace))?;
+    }
+    f.newline()?;
+    f.writeln(&format!("namespace {}.Tests", lib.settings.name))?;
+    blocked(&mut f, |f| {
+        f.writeln("public static class DocExamples")?;
+        blocked(f, |f| {
+            let mut first = true;
+            for (name, example) in examples {
+                if let Some(code) = example.get(ExampleLanguage::DotNet) {
+                    if !first {
+                        f.newline()?;
+                    }
+                    first = false;
+                    f.writeln(&format!(
+                        "public static void Example{}()",
+                        name.to_camel_case()
+                    ))?;
+                    blocked(f, |f| write_lines(f, code))?;
+                }
+            }
+            Ok(())
+        })
+    })
+}
+
+fn write_lines(f: &mut dyn Printer, code: &str) -> FormattingResult<()> {
+    for line in code.lines() {
+        f.writeln(line)?;
+    }
+    Ok(())
+}
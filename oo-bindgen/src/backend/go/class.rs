@@ -0,0 +1,437 @@
+use crate::backend::c::ctype::CType;
+
+use super::conversion::*;
+use super::doc::*;
+use super::*;
+
+/// What to do with an error returned by a native function
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum NativeError {
+    /// return it alongside the zero value of the result
+    Return,
+    /// fail the promise of an asynchronous method
+    FailPromise,
+}
+
+fn go_params<'a, I>(args: I, naming: &Naming) -> String
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    args.map(|arg| {
+        format!(
+            "{} {}",
+            arg.name.mixed_case(naming),
+            arg.arg_type.go_type(naming)
+        )
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+fn returns(function: &Function<Validated>, naming: &Naming) -> String {
+    match function.get_signature_type() {
+        SignatureType::NoErrorNoReturn => String::new(),
+        SignatureType::NoErrorWithReturn(value, _) => format!(" {}", value.go_type(naming)),
+        SignatureType::ErrorNoReturn(_) => " error".to_string(),
+        SignatureType::ErrorWithReturn(_, value, _) => {
+            format!(" ({}, error)", value.go_type(naming))
+        }
+    }
+}
+
+fn error_check(error_type: &ErrorType<Validated>) -> String {
+    let ok = &error_type.inner.variants[0].name;
+    format!(
+        "if _error != C.{}_{}_{} {{",
+        error_type.inner.settings.c_ffi_prefix.capital_snake_case(),
+        error_type.inner.name.capital_snake_case(),
+        ok.capital_snake_case()
+    )
+}
+
+/// Write the statements that convert the arguments, call the native function and convert its result
+///
+/// `instance` replaces the first argument of methods, `native_arg` names an argument that is already a C value
+fn write_native_call(
+    f: &mut dyn Printer,
+    function: &Handle<Function<Validated>>,
+    instance: Option<&str>,
+    native_arg: Option<&Name>,
+    native_error: NativeError,
+) -> FormattingResult<()> {
+    let skip = if instance.is_some() { 1 } else { 0 };
+    let converted = || {
+        function
+            .arguments
+            .iter()
+            .skip(skip)
+            .filter(move |arg| Some(&arg.name) != native_arg)
+    };
+
+    if converted().any(|arg| arg.arg_type.requires_scope()) {
+        f.writeln(&format!("{} := newNativeScope()", SCOPE))?;
+        f.writeln(&format!("defer {}.release()", SCOPE))?;
+    }
+
+    // the finalizer of a class must not run while its handle is in use
+    if instance.is_some() {
+        f.writeln("defer runtime.KeepAlive(instance)")?;
+    }
+    for arg in converted() {
+        if let FunctionArgument::ClassRef(_) = arg.arg_type {
+            f.writeln(&format!(
+                "defer runtime.KeepAlive({})",
+                arg.name.mixed_case(f.naming())
+            ))?;
+        }
+    }
+
+    let args: Vec<String> = instance
+        .map(|x| format!("{}.handle", x))
+        .into_iter()
+        .chain(function.arguments.iter().skip(skip).map(|arg| {
+            let name = arg.name.mixed_case(f.naming());
+            if Some(&arg.name) == native_arg {
+                name
+            } else {
+                arg.arg_type.convert_to_native(&name, f.naming())
+            }
+        }))
+        .collect();
+
+    match function.get_signature_type() {
+        SignatureType::NoErrorNoReturn => {
+            f.writeln(&format!("C.{}({})", function.to_c_type(), args.join(", ")))
+        }
+        SignatureType::NoErrorWithReturn(value, _) => {
+            f.writeln(&format!(
+                "_result := C.{}({})",
+                function.to_c_type(),
+                args.join(", ")
+            ))?;
+            f.writeln(&format!(
+                "return {}",
+                value.convert_to_go("_result", f.naming())
+            ))
+        }
+        SignatureType::ErrorNoReturn(error_type) => {
+            f.writeln(&format!(
+                "_error := C.{}({})",
+                function.to_c_type(),
+                args.join(", ")
+            ))?;
+            let error = format!("{}(_error)", error_type.inner.name.camel_case(f.naming()));
+            f.writeln(&error_check(&error_type))?;
+            match native_error {
+                NativeError::Return => {
+                    indented(f, |f| f.writeln(&format!("return {}", error)))?;
+                    f.writeln("}")?;
+                    f.writeln("return nil")
+                }
+                NativeError::FailPromise => {
+                    indented(f, |f| f.writeln(&format!("_promise.fail({})", error)))?;
+                    f.writeln("}")
+                }
+            }
+        }
+        SignatureType::ErrorWithReturn(error_type, value, _) => {
+            let args = args
+                .into_iter()
+                .chain(std::iter::once("&_out".to_string()))
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!("var _out {}", cgo_type(&value.to_c_type())))?;
+            f.writeln(&format!("_error := C.{}({})", function.to_c_type(), args))?;
+            f.writeln(&error_check(&error_type))?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "return *new({}), {}(_error)",
+                    value.go_type(f.naming()),
+                    error_type.inner.name.camel_case(f.naming())
+                ))
+            })?;
+            f.writeln("}")?;
+            f.writeln(&format!(
+                "return {}, nil",
+                value.convert_to_go("_out", f.naming())
+            ))
+        }
+    }
+}
+
+fn write_function_docs<'a, I>(
+    f: &mut dyn Printer,
+    function: &Function<Validated>,
+    args: I,
+    return_doc: Option<&DocString<Validated>>,
+) -> FormattingResult<()>
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    commented(f, |f| {
+        go_doc_print(f, &function.doc)?;
+
+        for arg in args {
+            f.newline()?;
+            f.writeln(&format!("`{}`: ", arg.name.mixed_case(f.naming())))?;
+            docstring_print(f, &arg.doc)?;
+        }
+
+        if let Some(doc) = return_doc {
+            f.newline()?;
+            f.writeln("Returns: ")?;
+            docstring_print(f, doc)?;
+        }
+
+        if let Some(error) = function.error_type.get() {
+            f.newline()?;
+            f.writeln(&format!(
+                "Fails with a [{}] error",
+                error.inner.name.camel_case(f.naming())
+            ))?;
+        }
+
+        Ok(())
+    })
+}
+
+fn write_constructor(
+    f: &mut dyn Printer,
+    class: &Class<Validated>,
+    constructor: &ClassConstructor<Validated>,
+) -> FormattingResult<()> {
+    let function = &constructor.function;
+    write_function_docs(f, function, function.arguments.iter(), None)?;
+    f.writeln(&format!(
+        "func {}({}){} {{",
+        constructor_name(class.name(), f.naming()),
+        go_params(function.arguments.iter(), f.naming()),
+        returns(function, f.naming())
+    ))?;
+    indented(f, |f| {
+        write_native_call(f, function, None, None, NativeError::Return)
+    })?;
+    f.writeln("}")
+}
+
+fn write_destructor(
+    f: &mut dyn Printer,
+    class: &Class<Validated>,
+    destructor: &ClassDestructor<Validated>,
+) -> FormattingResult<()> {
+    commented(f, |f| {
+        go_doc_print(f, &destructor.function.doc)?;
+        f.newline()?;
+        f.writeln("It is safe to call more than once and the instance can't be used afterwards.")?;
+        if class.destruction_mode == DestructionMode::Automatic {
+            f.writeln(
+                "It is also called by the garbage collector when the instance is unreachable.",
+            )?;
+        } else {
+            f.writeln("The garbage collector calls it if the instance becomes unreachable, but it should be called explicitly.")?;
+        }
+        Ok(())
+    })?;
+    f.writeln(&format!(
+        "func (instance *{}) {}() {{",
+        class.name().camel_case(f.naming()),
+        destructor_name(class, f.naming())
+    ))?;
+    indented(f, |f| {
+        f.writeln("if instance.handle == nil {")?;
+        indented(f, |f| f.writeln("return"))?;
+        f.writeln("}")?;
+        f.writeln(&format!(
+            "C.{}(instance.handle)",
+            destructor.function.to_c_type()
+        ))?;
+        f.writeln("instance.handle = nil")?;
+        f.writeln("runtime.SetFinalizer(instance, nil)")
+    })?;
+    f.writeln("}")
+}
+
+fn write_method(
+    f: &mut dyn Printer,
+    class: &Class<Validated>,
+    method: &Method<Validated>,
+) -> FormattingResult<()> {
+    let function = &method.native_function;
+    write_function_docs(
+        f,
+        function,
+        function.arguments.iter().skip(1),
+        function.return_type.get_doc(),
+    )?;
+    f.writeln(&format!(
+        "func (instance *{}) {}({}){} {{",
+        class.name().camel_case(f.naming()),
+        method_name(class, &method.name, &function.name, f.naming()),
+        go_params(function.arguments.iter().skip(1), f.naming()),
+        returns(function, f.naming())
+    ))?;
+    indented(f, |f| {
+        write_native_call(f, function, Some("instance"), None, NativeError::Return)
+    })?;
+    f.writeln("}")
+}
+
+fn write_static_method(
+    f: &mut dyn Printer,
+    name: &str,
+    method: &StaticMethod<Validated>,
+) -> FormattingResult<()> {
+    let function = &method.native_function;
+    write_function_docs(
+        f,
+        function,
+        function.arguments.iter(),
+        function.return_type.get_doc(),
+    )?;
+    f.writeln(&format!(
+        "func {}({}){} {{",
+        name,
+        go_params(function.arguments.iter(), f.naming()),
+        returns(function, f.naming())
+    ))?;
+    indented(f, |f| {
+        write_native_call(f, function, None, None, NativeError::Return)
+    })?;
+    f.writeln("}")
+}
+
+fn write_future_method(
+    f: &mut dyn Printer,
+    class: &Class<Validated>,
+    method: &FutureMethod<Validated>,
+) -> FormattingResult<()> {
+    let function = &method.native_function;
+    let value_type = method.future.value_type.go_type(f.naming());
+
+    let callback_arg = method
+        .arguments()
+        .find(|arg| match &arg.arg_type {
+            FunctionArgument::Interface(x) => x.name == method.future.interface.name,
+            _ => false,
+        })
+        .map(|arg| arg.name.clone())
+        .expect("future method without a callback argument");
+
+    write_function_docs(
+        f,
+        function,
+        method.arguments_without_callback(),
+        Some(&method.future.value_type_doc),
+    )?;
+    commented(f, |f| {
+        f.newline()?;
+        f.writeln("The channel receives a single [Result] when the operation completes")
+    })?;
+    f.writeln(&format!(
+        "func (instance *{}) {}({}) <-chan Result[{}] {{",
+        class.name().camel_case(f.naming()),
+        method_name(class, &method.name, &function.name, f.naming()),
+        go_params(method.arguments_without_callback(), f.naming()),
+        value_type
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!("_promise := newPromise[{}]()", value_type))?;
+        f.writeln("defer _promise.release()")?;
+        f.writeln(&format!(
+            "{} := {}(_promise)",
+            callback_arg.mixed_case(f.naming()),
+            to_native_func(&method.future.interface.name, f.naming())
+        ))?;
+        write_native_call(
+            f,
+            function,
+            Some("instance"),
+            Some(&callback_arg),
+            NativeError::FailPromise,
+        )?;
+        f.writeln("return _promise.results")
+    })?;
+    f.writeln("}")
+}
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    class: &Handle<Class<Validated>>,
+) -> FormattingResult<()> {
+    let class_name = class.name().camel_case(f.naming());
+    let c_type = cgo_type(&class.declaration().to_c_type());
+
+    commented(f, |f| go_doc_print(f, &class.doc))?;
+    f.writeln(&format!("type {} struct {{", class_name))?;
+    indented(f, |f| f.writeln(&format!("handle *{}", c_type)))?;
+    f.writeln("}")?;
+    f.newline()?;
+
+    // the garbage collector destroys instances that were not closed
+    f.writeln(&format!(
+        "func new{}(handle *{}) *{} {{",
+        class_name, c_type, class_name
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!("instance := &{}{{handle: handle}}", class_name))?;
+        if class.destructor.is_some() {
+            f.writeln(&format!(
+                "runtime.SetFinalizer(instance, (*{}).{})",
+                class_name,
+                destructor_name(class, f.naming())
+            ))?;
+        }
+        f.writeln("return instance")
+    })?;
+    f.writeln("}")?;
+
+    if let Some(constructor) = &class.constructor {
+        f.newline()?;
+        write_constructor(f, class, constructor)?;
+    }
+
+    if let Some(destructor) = &class.destructor {
+        f.newline()?;
+        write_destructor(f, class, destructor)?;
+    }
+
+    for method in &class.methods {
+        f.newline()?;
+        write_method(f, class, method)?;
+    }
+
+    for method in &class.future_methods {
+        f.newline()?;
+        write_future_method(f, class, method)?;
+    }
+
+    for method in &class.static_methods {
+        f.newline()?;
+        let name = static_method_name(class, method, f.naming());
+        write_static_method(f, &name, method)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn generate_static(
+    f: &mut dyn Printer,
+    class: &Handle<StaticClass<Validated>>,
+) -> FormattingResult<()> {
+    // Go has no static classes, the methods are package level functions named after the class
+    commented(f, |f| {
+        f.writeln(&format!("{} functions", class.name.camel_case(f.naming())))?;
+        go_doc_print(f, &class.doc)
+    })?;
+    f.newline()?;
+
+    for (i, method) in class.static_methods.iter().enumerate() {
+        if i > 0 {
+            f.newline()?;
+        }
+        let name = static_class_method_name(class, method, f.naming());
+        write_static_method(f, &name, method)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,516 @@
+use crate::backend::c::ctype::CType;
+use crate::model::*;
+
+use super::{from_native_func, to_native_func};
+
+/// Name of the `nativeScope` that owns the temporary allocations of a native call
+pub(crate) const SCOPE: &str = "_scope";
+
+/// cgo spelling of a type declared in the C header
+pub(crate) fn cgo_type(c_type: &str) -> String {
+    let c_type = c_type.trim().trim_start_matches("const ").trim();
+    let base = c_type.trim_end_matches('*').trim();
+    let pointers = c_type.len() - c_type.trim_end_matches('*').len();
+
+    if base == "void" && pointers > 0 {
+        format!("{}unsafe.Pointer", "*".repeat(pointers - 1))
+    } else {
+        format!("{}C.{}", "*".repeat(pointers), base)
+    }
+}
+
+/// Go spelling of a type in the public API
+pub(crate) trait GoType {
+    fn go_type(&self, naming: &Naming) -> String;
+}
+
+/// Conversion of a Go value into the value expected by the C API
+pub(crate) trait ConvertToNative {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String;
+    /// true if the conversion allocates memory owned by the `nativeScope`
+    fn requires_scope(&self) -> bool;
+}
+
+/// Conversion of a value returned by the C API into its Go representation
+pub(crate) trait ConvertToGo {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String;
+}
+
+impl GoType for Primitive {
+    fn go_type(&self, _naming: &Naming) -> String {
+        match self {
+            Self::Bool => "bool".to_string(),
+            Self::U8 => "uint8".to_string(),
+            Self::S8 => "int8".to_string(),
+            Self::U16 => "uint16".to_string(),
+            Self::S16 => "int16".to_string(),
+            Self::U32 => "uint32".to_string(),
+            Self::S32 => "int32".to_string(),
+            Self::U64 => "uint64".to_string(),
+            Self::S64 => "int64".to_string(),
+            Self::Float => "float32".to_string(),
+            Self::Double => "float64".to_string(),
+        }
+    }
+}
+
+impl ConvertToNative for Primitive {
+    fn convert_to_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("{}({})", cgo_type(&self.to_c_type()), expr)
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl ConvertToGo for Primitive {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        format!("{}({})", self.go_type(naming), expr)
+    }
+}
+
+impl GoType for BasicType {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.go_type(naming),
+            Self::Duration(_) => "time.Duration".to_string(),
+            Self::Enum(x) => x.name.camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for BasicType {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_to_native(expr, naming),
+            Self::Duration(DurationType::Milliseconds) => {
+                format!("C.uint64_t({}.Milliseconds())", expr)
+            }
+            Self::Duration(DurationType::Seconds) => {
+                format!("C.uint64_t({} / time.Second)", expr)
+            }
+            Self::Enum(x) => format!("{}({})", cgo_type(&x.to_c_type()), expr),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl ConvertToGo for BasicType {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_to_go(expr, naming),
+            Self::Duration(DurationType::Milliseconds) => {
+                format!("time.Duration({}) * time.Millisecond", expr)
+            }
+            Self::Duration(DurationType::Seconds) => {
+                format!("time.Duration({}) * time.Second", expr)
+            }
+            Self::Enum(x) => format!("{}({})", x.name.camel_case(naming), expr),
+        }
+    }
+}
+
+impl ConvertToNative for StringType {
+    fn convert_to_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("{}.cString({})", SCOPE, expr)
+    }
+
+    fn requires_scope(&self) -> bool {
+        true
+    }
+}
+
+impl ConvertToGo for StringType {
+    fn convert_to_go(&self, expr: &str, _naming: &Naming) -> String {
+        format!("C.GoString({})", expr)
+    }
+}
+
+impl GoType for ClassDeclarationHandle {
+    fn go_type(&self, naming: &Naming) -> String {
+        format!("*{}", self.name.camel_case(naming))
+    }
+}
+
+impl ConvertToGo for ClassDeclarationHandle {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        format!("new{}({})", self.name.camel_case(naming), expr)
+    }
+}
+
+impl GoType for IteratorItemType {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.go_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl<D> GoType for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn go_type(&self, naming: &Naming) -> String {
+        format!("[]{}", self.item_type.go_type(naming))
+    }
+}
+
+impl<D> ConvertToGo for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        // next() returns a pointer to the item for both primitives and structs
+        let (item, convert) = match &self.item_type {
+            IteratorItemType::Primitive(x) => {
+                (cgo_type(&x.to_c_type()), x.convert_to_go("_item", naming))
+            }
+            IteratorItemType::Struct(x) => (
+                cgo_type(&x.to_c_type()),
+                format!("{}(_item)", from_native_func(x.name(), naming)),
+            ),
+        };
+        format!(
+            "collectIterator(func() *{} {{ return C.{}({}) }}, func(_item {}) {} {{ return {} }})",
+            item,
+            self.next_function.to_c_type(),
+            expr,
+            item,
+            self.item_type.go_type(naming),
+            convert
+        )
+    }
+}
+
+impl<D> GoType for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn go_type(&self, naming: &Naming) -> String {
+        format!("[]{}", self.item_type.go_type(naming))
+    }
+}
+
+impl<D> ConvertToNative for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        let collection = format!("*{}", cgo_type(&self.to_c_type()));
+        let reserve = if self.has_reserve {
+            format!("C.uint32_t(len({}))", expr)
+        } else {
+            String::new()
+        };
+        format!(
+            "nativeCollection({}, {}, func() {} {{ return C.{}({}) }}, func(_collection {}, _item {}) {{ C.{}(_collection, {}) }}, func(_collection {}) {{ C.{}(_collection) }})",
+            SCOPE,
+            expr,
+            collection,
+            self.create_func.to_c_type(),
+            reserve,
+            collection,
+            self.item_type.go_type(naming),
+            self.add_func.to_c_type(),
+            self.item_type.convert_to_native("_item", naming),
+            collection,
+            self.delete_func.to_c_type()
+        )
+    }
+
+    fn requires_scope(&self) -> bool {
+        true
+    }
+}
+
+impl<D> GoType for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn go_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
+    }
+}
+
+impl<D> ConvertToNative for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        format!("{}({})", to_native_func(&self.name, naming), expr)
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl<T> GoType for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn go_type(&self, naming: &Naming) -> String {
+        self.name().camel_case(naming)
+    }
+}
+
+impl<T> ConvertToNative for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_to_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("{}.toNative({})", expr, SCOPE)
+    }
+
+    fn requires_scope(&self) -> bool {
+        true
+    }
+}
+
+impl<T> ConvertToGo for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        format!("{}({})", from_native_func(self.name(), naming), expr)
+    }
+}
+
+impl GoType for FunctionArgStructField {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::String(_) => "string".to_string(),
+            Self::Interface(x) => x.inner.go_type(naming),
+            Self::Struct(x) => x.go_type(naming),
+        }
+    }
+}
+
+impl ConvertToNative for FunctionArgStructField {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            Self::String(x) => x.convert_to_native(expr, naming),
+            Self::Interface(x) => x.inner.convert_to_native(expr, naming),
+            Self::Struct(x) => x.convert_to_native(expr, naming),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.requires_scope(),
+            Self::String(x) => x.requires_scope(),
+            Self::Interface(x) => x.inner.requires_scope(),
+            Self::Struct(x) => x.requires_scope(),
+        }
+    }
+}
+
+impl GoType for FunctionReturnStructField {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::ClassRef(x) => x.go_type(naming),
+            Self::Iterator(x) => x.go_type(naming),
+            Self::Struct(x) => x.go_type(naming),
+        }
+    }
+}
+
+impl ConvertToGo for FunctionReturnStructField {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_go(expr, naming),
+            Self::ClassRef(x) => x.convert_to_go(expr, naming),
+            Self::Iterator(x) => x.convert_to_go(expr, naming),
+            Self::Struct(x) => x.convert_to_go(expr, naming),
+        }
+    }
+}
+
+impl GoType for CallbackArgStructField {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::Iterator(x) => x.go_type(naming),
+            Self::Struct(x) => x.go_type(naming),
+        }
+    }
+}
+
+impl ConvertToGo for CallbackArgStructField {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_go(expr, naming),
+            Self::Iterator(x) => x.convert_to_go(expr, naming),
+            Self::Struct(x) => x.convert_to_go(expr, naming),
+        }
+    }
+}
+
+impl GoType for UniversalStructField {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for UniversalStructField {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            Self::Struct(_) => format!("{}.toNative({})", expr, SCOPE),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        matches!(self, Self::Struct(_))
+    }
+}
+
+impl ConvertToGo for UniversalStructField {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_go(expr, naming),
+            Self::Struct(x) => format!("{}({})", from_native_func(x.name(), naming), expr),
+        }
+    }
+}
+
+impl GoType for FunctionArgument {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::String(_) => "string".to_string(),
+            Self::Collection(x) => x.go_type(naming),
+            Self::Struct(x) => x.go_type(naming),
+            Self::StructRef(x) => format!("*{}", x.inner.name.camel_case(naming)),
+            Self::ClassRef(x) => x.go_type(naming),
+            Self::Interface(x) => x.go_type(naming),
+        }
+    }
+}
+
+impl ConvertToNative for FunctionArgument {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            Self::String(x) => x.convert_to_native(expr, naming),
+            Self::Collection(x) => x.convert_to_native(expr, naming),
+            Self::Struct(x) => x.convert_to_native(expr, naming),
+            Self::StructRef(x) => format!(
+                "func() *{} {{ if {} == nil {{ return nil }}; _native := {}.toNative({}); return &_native }}()",
+                cgo_type(&x.inner.to_c_type()),
+                expr,
+                expr,
+                SCOPE
+            ),
+            Self::ClassRef(_) => format!("{}.handle", expr),
+            Self::Interface(x) => x.convert_to_native(expr, naming),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.requires_scope(),
+            Self::String(x) => x.requires_scope(),
+            Self::Collection(x) => x.requires_scope(),
+            Self::Struct(x) => x.requires_scope(),
+            Self::StructRef(_) => true,
+            Self::ClassRef(_) => false,
+            Self::Interface(x) => x.requires_scope(),
+        }
+    }
+}
+
+impl GoType for CallbackArgument {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::String(_) => "string".to_string(),
+            Self::Iterator(x) => x.go_type(naming),
+            Self::Class(x) => x.go_type(naming),
+            Self::Struct(x) => x.go_type(naming),
+        }
+    }
+}
+
+impl ConvertToGo for CallbackArgument {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_go(expr, naming),
+            Self::String(x) => x.convert_to_go(expr, naming),
+            Self::Iterator(x) => x.convert_to_go(expr, naming),
+            Self::Class(x) => x.convert_to_go(expr, naming),
+            Self::Struct(x) => x.convert_to_go(expr, naming),
+        }
+    }
+}
+
+impl GoType for CallbackReturnValue {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for CallbackReturnValue {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            // universal structs never allocate
+            Self::Struct(_) => format!("{}.toNative(nil)", expr),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl GoType for FunctionReturnValue {
+    fn go_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.go_type(naming),
+            Self::PrimitiveRef(x) => format!("*{}", x.inner.go_type(naming)),
+            Self::String(_) => "string".to_string(),
+            Self::ClassRef(x) => x.go_type(naming),
+            Self::Struct(x) => x.go_type(naming),
+            Self::StructRef(x) => format!("*{}", x.untyped().name.camel_case(naming)),
+        }
+    }
+}
+
+impl ConvertToGo for FunctionReturnValue {
+    fn convert_to_go(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_go(expr, naming),
+            Self::PrimitiveRef(x) => format!(
+                "func() *{} {{ if {} == nil {{ return nil }}; _value := {}; return &_value }}()",
+                x.inner.go_type(naming),
+                expr,
+                x.inner.convert_to_go(&format!("*{}", expr), naming)
+            ),
+            Self::String(x) => x.convert_to_go(expr, naming),
+            Self::ClassRef(x) => x.convert_to_go(expr, naming),
+            Self::Struct(x) => x.convert_to_go(expr, naming),
+            Self::StructRef(x) => format!(
+                "func() *{} {{ if {} == nil {{ return nil }}; _value := {}(*{}); return &_value }}()",
+                x.untyped().name.camel_case(naming),
+                expr,
+                from_native_func(&x.untyped().name, naming),
+                expr
+            ),
+        }
+    }
+}
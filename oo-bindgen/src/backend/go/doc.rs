@@ -0,0 +1,135 @@
+use crate::backend::*;
+use crate::model::*;
+
+use super::{class_method_name, constructor_name, destructor_name};
+
+/// Go doc comment, printed inside a `//` comment
+pub(crate) fn go_doc_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    docstring_print(f, &doc.brief)?;
+
+    // there are no examples in this language
+    for detail in doc.details.iter().filter(|x| x.is_printed_in(None)) {
+        f.newline()?;
+        f.newline()?;
+
+        match detail {
+            DocParagraph::Details(docstring) => {
+                docstring_print(f, docstring)?;
+            }
+            DocParagraph::Warning(docstring) => {
+                f.write("Warning: ")?;
+                docstring_print(f, docstring)?;
+            }
+            DocParagraph::Example(_) => (),
+        }
+    }
+
+    // recognized by the Go tools when it starts a paragraph
+    if let Some(deprecated) = &doc.deprecated {
+        f.newline()?;
+        f.newline()?;
+        f.write(&format!("Deprecated: since {}, ", deprecated.since))?;
+        docstring_print(f, &deprecated.note)?;
+    }
+
+    if let Some(since) = &doc.since {
+        f.newline()?;
+        f.newline()?;
+        f.write(&format!("Since {}.", since))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn docstring_print(
+    f: &mut dyn Printer,
+    docstring: &DocString<Validated>,
+) -> FormattingResult<()> {
+    let mut elements = docstring.elements().peekable();
+    while let Some(el) = elements.next() {
+        match el {
+            DocStringElement::Text(text) => f.write(text)?,
+            DocStringElement::Null => f.write("nil")?,
+            DocStringElement::Iterator => f.write("slice")?,
+            DocStringElement::Reference(reference) => reference_print(f, reference)?,
+            // Go doc comments have no inline markup, URLs are linked automatically
+            DocStringElement::Code(code) => f.write(&format!("`{}`", code))?,
+            DocStringElement::Bold(text) => f.write(text)?,
+            DocStringElement::Link { text, url } => f.write(&format!("{} ({})", text, url))?,
+            DocStringElement::List(items) => {
+                // lists are indented and surrounded by empty lines
+                f.newline()?;
+                for item in items {
+                    f.writeln("  - ")?;
+                    docstring_print(f, item)?;
+                }
+                if elements.peek().is_some() {
+                    f.newline()?;
+                    f.newline()?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn reference_print(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
+    match reference {
+        Validated::Argument(param_name) => {
+            f.write(&format!("`{}`", param_name.mixed_case(f.naming())))?
+        }
+        Validated::Class(class) => {
+            f.write(&format!("[{}]", class.name.camel_case(f.naming())))?;
+        }
+        Validated::ClassMethod(class, method_name, _) => {
+            f.write(&format!(
+                "[{}]",
+                class_method_name(class, method_name, f.naming())
+            ))?;
+        }
+        Validated::ClassConstructor(class, _) => {
+            f.write(&format!("[{}]", constructor_name(class.name(), f.naming())))?;
+        }
+        Validated::ClassDestructor(class, _) => {
+            f.write(&format!(
+                "[{}.{}]",
+                class.name().camel_case(f.naming()),
+                destructor_name(class, f.naming())
+            ))?;
+        }
+        Validated::Struct(st) => {
+            f.write(&format!("[{}]", st.name().camel_case(f.naming())))?;
+        }
+        Validated::StructField(st, field_name) => {
+            f.write(&format!(
+                "[{}.{}]",
+                st.name().camel_case(f.naming()),
+                field_name.camel_case(f.naming())
+            ))?;
+        }
+        Validated::Enum(handle) => {
+            f.write(&format!("[{}]", handle.name.camel_case(f.naming())))?;
+        }
+        Validated::EnumVariant(handle, variant_name) => {
+            f.write(&format!(
+                "[{}{}]",
+                handle.name.camel_case(f.naming()),
+                variant_name.camel_case(f.naming())
+            ))?;
+        }
+        Validated::Interface(interface) => {
+            f.write(&format!("[{}]", interface.name.camel_case(f.naming())))?;
+        }
+        Validated::InterfaceMethod(interface, callback_name) => {
+            f.write(&format!(
+                "[{}.{}]",
+                interface.name.camel_case(f.naming()),
+                callback_name.camel_case(f.naming())
+            ))?;
+        }
+    }
+
+    Ok(())
+}
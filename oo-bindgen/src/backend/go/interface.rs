@@ -0,0 +1,349 @@
+use crate::backend::c::ctype::CType;
+use crate::backend::c::header::callback_parameters;
+
+use super::conversion::*;
+use super::doc::*;
+use super::structure::initializer_name;
+use super::*;
+
+/// Go literal of the value returned by a default implementation
+fn default_return_value(value: &DefaultCallbackReturnValue, naming: &Naming) -> Option<String> {
+    match value {
+        DefaultCallbackReturnValue::Void => None,
+        DefaultCallbackReturnValue::Basic(BasicValue::Primitive(x)) => Some(primitive_literal(x)),
+        DefaultCallbackReturnValue::Basic(BasicValue::Duration(x)) => Some(match x {
+            DurationValue::Milliseconds(x) => format!("{} * time.Millisecond", x),
+            DurationValue::Seconds(x) => format!("{} * time.Second", x),
+        }),
+        DefaultCallbackReturnValue::Basic(BasicValue::Enum(x)) => Some(format!(
+            "{}{}",
+            x.handle.name.camel_case(naming),
+            x.variant.name.camel_case(naming)
+        )),
+        DefaultCallbackReturnValue::InitializedStruct(x) => Some(format!(
+            "{}()",
+            initializer_name(
+                x.handle.name(),
+                x.handle.visibility,
+                &x.initializer.initializer_type,
+                &x.initializer.name,
+                naming,
+            )
+        )),
+    }
+}
+
+fn primitive_literal(value: &PrimitiveValue) -> String {
+    match value {
+        PrimitiveValue::Bool(x) => x.to_string(),
+        PrimitiveValue::U8(x) => x.to_string(),
+        PrimitiveValue::S8(x) => x.to_string(),
+        PrimitiveValue::U16(x) => x.to_string(),
+        PrimitiveValue::S16(x) => x.to_string(),
+        PrimitiveValue::U32(x) => x.to_string(),
+        PrimitiveValue::S32(x) => x.to_string(),
+        PrimitiveValue::U64(x) => x.to_string(),
+        PrimitiveValue::S64(x) => x.to_string(),
+        PrimitiveValue::Float(x) => format!("{:?}", x),
+        PrimitiveValue::Double(x) => format!("{:?}", x),
+    }
+}
+
+fn callback_signature(cb: &CallbackFunction<Validated>, naming: &Naming) -> String {
+    let params = cb
+        .arguments
+        .iter()
+        .map(|arg| {
+            format!(
+                "{} {}",
+                arg.name.mixed_case(naming),
+                arg.arg_type.go_type(naming)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    match cb.return_type.get_value() {
+        None => format!("{}({})", cb.name.camel_case(naming), params),
+        Some(x) => format!(
+            "{}({}) {}",
+            cb.name.camel_case(naming),
+            params,
+            x.go_type(naming)
+        ),
+    }
+}
+
+/// Name of the exported Go function called by a C function pointer
+fn trampoline_name(interface: &Interface<Validated>, callback: &Name) -> String {
+    format!(
+        "go_{}_{}_{}",
+        interface.settings.c_ffi_prefix, interface.name, callback
+    )
+}
+
+/// C declarations of the exported trampolines, added to the preamble of the file that references them
+pub(crate) fn trampoline_declarations(interface: &Handle<Interface<Validated>>) -> Vec<String> {
+    interface
+        .callbacks
+        .iter()
+        .map(|cb| {
+            format!(
+                "extern {} {}({});",
+                cb.return_type.to_c_type(),
+                trampoline_name(interface, &cb.name),
+                callback_parameters(cb)
+            )
+        })
+        .chain(std::iter::once(format!(
+            "extern void {}(void*);",
+            trampoline_name(interface, &interface.settings.interface.destroy_func_name)
+        )))
+        .collect()
+}
+
+/// Write the function that builds the C struct of an interface around a registered Go value
+fn write_to_native(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+    param_type: &str,
+) -> FormattingResult<()> {
+    let settings = &interface.settings.interface;
+    let c_type = cgo_type(&interface.to_c_type());
+
+    f.writeln(&format!(
+        "func {}(value {}) {} {{",
+        to_native_func(&interface.name, f.naming()),
+        param_type,
+        c_type
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!("return {}{{", c_type))?;
+        indented(f, |f| {
+            for cb in &interface.callbacks {
+                f.writeln(&format!(
+                    "{}: (*[0]byte)(C.{}),",
+                    c_field(&cb.name, f.naming()),
+                    trampoline_name(interface, &cb.name)
+                ))?;
+            }
+            f.writeln(&format!(
+                "{}: (*[0]byte)(C.{}),",
+                c_field(&settings.destroy_func_name, f.naming()),
+                trampoline_name(interface, &settings.destroy_func_name)
+            ))?;
+            f.writeln(&format!(
+                "{}: registerHandle(value),",
+                c_field(&settings.context_variable_name, f.naming())
+            ))
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
+
+/// Write the signature of a trampoline, the context pointer always comes last
+fn write_trampoline_signature(
+    f: &mut dyn Printer,
+    interface: &Interface<Validated>,
+    cb: &CallbackFunction<Validated>,
+) -> FormattingResult<()> {
+    let params = cb
+        .arguments
+        .iter()
+        .map(|arg| {
+            format!(
+                "{} {}",
+                arg.name.mixed_case(f.naming()),
+                cgo_type(&arg.arg_type.to_c_type())
+            )
+        })
+        .chain(std::iter::once(format!("{} unsafe.Pointer", CONTEXT)))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let returns = match cb.return_type.get_value() {
+        None => String::new(),
+        Some(x) => format!(" {}", cgo_type(&x.to_c_type())),
+    };
+
+    let name = trampoline_name(interface, &cb.name);
+    f.writeln(&format!("//export {}", name))?;
+    f.writeln(&format!("func {}({}){} {{", name, params, returns))
+}
+
+fn write_destroy_trampoline(
+    f: &mut dyn Printer,
+    interface: &Interface<Validated>,
+    body: &str,
+) -> FormattingResult<()> {
+    let name = trampoline_name(interface, &interface.settings.interface.destroy_func_name);
+    f.writeln(&format!("//export {}", name))?;
+    f.writeln(&format!("func {}({} unsafe.Pointer) {{", name, CONTEXT))?;
+    indented(f, |f| f.writeln(body))?;
+    f.writeln("}")
+}
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+) -> FormattingResult<()> {
+    let interface_name = interface.name.camel_case(f.naming());
+
+    commented(f, |f| go_doc_print(f, &interface.doc))?;
+    f.writeln(&format!("type {} interface {{", interface_name))?;
+    indented(f, |f| {
+        for cb in &interface.callbacks {
+            commented(f, |f| {
+                go_doc_print(f, &cb.doc)?;
+
+                for arg in &cb.arguments {
+                    f.newline()?;
+                    f.writeln(&format!("`{}`: ", arg.name.mixed_case(f.naming())))?;
+                    docstring_print(f, &arg.doc)?;
+                }
+
+                if let Some(doc) = cb.return_type.get_doc() {
+                    f.newline()?;
+                    f.writeln("Returns: ")?;
+                    docstring_print(f, doc)?;
+                }
+
+                Ok(())
+            })?;
+            f.writeln(&callback_signature(cb, f.naming()))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")?;
+
+    // Go interfaces can't have default methods, they are provided by a struct meant to be embedded
+    if interface
+        .callbacks
+        .iter()
+        .any(|cb| cb.default_implementation.is_some())
+    {
+        let defaults_name = format!("{}Defaults", interface_name);
+
+        f.newline()?;
+        f.writeln(&format!(
+            "// {} provides the default implementation of some methods of [{}] when embedded in an implementation",
+            defaults_name, interface_name
+        ))?;
+        f.writeln(&format!("type {} struct{{}}", defaults_name))?;
+
+        for cb in &interface.callbacks {
+            if let Some(default) = &cb.default_implementation {
+                f.newline()?;
+                f.writeln(&format!(
+                    "// {} is the default implementation of [{}.{}]",
+                    cb.name.camel_case(f.naming()),
+                    interface_name,
+                    cb.name.camel_case(f.naming())
+                ))?;
+                f.writeln(&format!(
+                    "func ({}) {} {{",
+                    defaults_name,
+                    callback_signature(cb, f.naming())
+                ))?;
+                if let Some(value) = default_return_value(default, f.naming()) {
+                    indented(f, |f| f.writeln(&format!("return {}", value)))?;
+                }
+                f.writeln("}")?;
+            }
+        }
+    }
+
+    // the implementation is kept in the registry until the native side destroys the interface
+    f.newline()?;
+    write_to_native(f, interface, &interface_name)
+}
+
+pub(crate) fn generate_future(
+    f: &mut dyn Printer,
+    future: &FutureInterface<Validated>,
+) -> FormattingResult<()> {
+    // futures are exposed as channels and have no public interface
+    let native_type = format!("*promise[{}]", future.value_type.go_type(f.naming()));
+    write_to_native(f, &future.interface, &native_type)
+}
+
+pub(crate) fn generate_trampolines(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+) -> FormattingResult<()> {
+    let interface_name = interface.name.camel_case(f.naming());
+
+    for cb in &interface.callbacks {
+        write_trampoline_signature(f, interface, cb)?;
+        indented(f, |f| {
+            let args = cb
+                .arguments
+                .iter()
+                .map(|arg| {
+                    arg.arg_type
+                        .convert_to_go(&arg.name.mixed_case(f.naming()), f.naming())
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            let call = format!(
+                "lookupHandle({}).({}).{}({})",
+                CONTEXT,
+                interface_name,
+                cb.name.camel_case(f.naming()),
+                args
+            );
+            match cb.return_type.get_value() {
+                None => f.writeln(&call),
+                Some(x) => {
+                    f.writeln(&format!("_result := {}", call))?;
+                    f.writeln(&format!(
+                        "return {}",
+                        x.convert_to_native("_result", f.naming())
+                    ))
+                }
+            }
+        })?;
+        f.writeln("}")?;
+        f.newline()?;
+    }
+
+    write_destroy_trampoline(f, interface, &format!("releaseHandle({})", CONTEXT))
+}
+
+pub(crate) fn generate_future_trampolines(
+    f: &mut dyn Printer,
+    future: &FutureInterface<Validated>,
+) -> FormattingResult<()> {
+    let interface = &future.interface;
+    let settings = &interface.settings.future;
+    let promise_type = format!("*promise[{}]", future.value_type.go_type(f.naming()));
+
+    for cb in &interface.callbacks {
+        let arg = &cb.arguments[0];
+        let method = if cb.name == settings.success_callback_method_name {
+            "complete"
+        } else {
+            "fail"
+        };
+
+        write_trampoline_signature(f, interface, cb)?;
+        indented(f, |f| {
+            f.writeln(&format!(
+                "lookupHandle({}).({}).{}({})",
+                CONTEXT,
+                promise_type,
+                method,
+                arg.arg_type
+                    .convert_to_go(&arg.name.mixed_case(f.naming()), f.naming())
+            ))
+        })?;
+        f.writeln("}")?;
+        f.newline()?;
+    }
+
+    write_destroy_trampoline(
+        f,
+        interface,
+        &format!("releaseHandle({}).({}).release()", CONTEXT, promise_type),
+    )
+}
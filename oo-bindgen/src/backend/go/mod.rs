@@ -0,0 +1,603 @@
+use std::path::PathBuf;
+
+use crate::backend::*;
+use crate::model::*;
+
+use doc::*;
+
+mod class;
+mod conversion;
+mod doc;
+mod interface;
+mod structure;
+
+/// Name of the context pointer parameter of the exported callback trampolines
+const CONTEXT: &str = "_ctx";
+
+/// Field of a C struct as cgo names it, with an underscore prefix if it is a Go keyword
+pub(crate) fn c_field(name: &Name, naming: &Naming) -> String {
+    let name = name.snake_case(&naming.switch(Some(TargetLanguage::C)));
+    if GO_KEYWORDS.contains(&name.as_str()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// Exported or unexported spelling of an identifier
+pub(crate) fn identifier(name: &Name, exported: bool, naming: &Naming) -> String {
+    if exported {
+        name.camel_case(naming)
+    } else {
+        name.mixed_case(naming)
+    }
+}
+
+pub(crate) fn constructor_name(class: &Name, naming: &Naming) -> String {
+    format!("New{}", class.camel_case(naming))
+}
+
+/// Method that destroys an instance, it's also registered as the finalizer
+pub(crate) fn destructor_name<D>(class: &Class<D>, naming: &Naming) -> String
+where
+    D: DocReference,
+{
+    match &class.destruction_mode {
+        DestructionMode::Custom(name) => name.camel_case(naming),
+        DestructionMode::Automatic | DestructionMode::Dispose => "Close".to_string(),
+    }
+}
+
+pub(crate) fn to_native_func(name: &Name, naming: &Naming) -> String {
+    format!("{}ToNative", name.mixed_case(naming))
+}
+
+pub(crate) fn from_native_func(name: &Name, naming: &Naming) -> String {
+    format!("{}FromNative", name.mixed_case(naming))
+}
+
+/// Go has no overloading, so members that share a name are told apart by their native function
+fn member_name<'a, I>(
+    parent: &Name,
+    names: I,
+    name: &Name,
+    function: &Name,
+    naming: &Naming,
+) -> String
+where
+    I: Iterator<Item = &'a Name>,
+{
+    if names.filter(|x| *x == name).count() < 2 {
+        return name.camel_case(naming);
+    }
+
+    let prefix = format!("{}_", parent.as_ref());
+    function
+        .strip_prefix(&prefix)
+        .and_then(|x| Name::create(x).ok())
+        .unwrap_or_else(|| function.clone())
+        .camel_case(naming)
+}
+
+fn class_member_name<D>(class: &Class<D>, name: &Name, function: &Name, naming: &Naming) -> String
+where
+    D: DocReference,
+{
+    let names = class
+        .methods
+        .iter()
+        .map(|x| &x.name)
+        .chain(class.static_methods.iter().map(|x| &x.name))
+        .chain(class.future_methods.iter().map(|x| &x.name));
+    member_name(class.name(), names, name, function, naming)
+}
+
+/// Package level function that implements a static method
+pub(crate) fn static_method_name<D>(
+    class: &Class<D>,
+    method: &StaticMethod<D>,
+    naming: &Naming,
+) -> String
+where
+    D: DocReference,
+{
+    format!(
+        "{}{}",
+        class.name().camel_case(naming),
+        class_member_name(class, &method.name, &method.native_function.name, naming)
+    )
+}
+
+pub(crate) fn method_name<D>(
+    class: &Class<D>,
+    name: &Name,
+    function: &Name,
+    naming: &Naming,
+) -> String
+where
+    D: DocReference,
+{
+    class_member_name(class, name, function, naming)
+}
+
+pub(crate) fn static_class_method_name<D>(
+    class: &StaticClass<D>,
+    method: &StaticMethod<D>,
+    naming: &Naming,
+) -> String
+where
+    D: DocReference,
+{
+    let names = class.static_methods.iter().map(|x| &x.name);
+    format!(
+        "{}{}",
+        class.name.camel_case(naming),
+        member_name(
+            &class.name,
+            names,
+            &method.name,
+            &method.native_function.name,
+            naming,
+        )
+    )
+}
+
+/// Documentation link to a method, static methods are package level functions
+pub(crate) fn class_method_name<D>(class: &Class<D>, name: &Name, naming: &Naming) -> String
+where
+    D: DocReference,
+{
+    if let Some(method) = class.static_methods.iter().find(|x| &x.name == name) {
+        return static_method_name(class, method, naming);
+    }
+
+    let function = class
+        .methods
+        .iter()
+        .find(|x| &x.name == name)
+        .map(|x| &x.native_function.name)
+        .or_else(|| {
+            class
+                .future_methods
+                .iter()
+                .find(|x| &x.name == name)
+                .map(|x| &x.native_function.name)
+        });
+
+    match function {
+        Some(function) => format!(
+            "{}.{}",
+            class.name().camel_case(naming),
+            method_name(class, name, function, naming)
+        ),
+        None => format!(
+            "{}.{}",
+            class.name().camel_case(naming),
+            name.camel_case(naming)
+        ),
+    }
+}
+
+/// cgo build constraint that matches a Rust platform
+///
+/// Platforms not in this map can't be linked from the package
+fn go_build_constraint(platform: &Platform) -> Option<String> {
+    let os = match platform.target_os {
+        OS::Linux => "linux",
+        OS::MacOS => "darwin",
+        OS::Windows => "windows",
+        _ => return None,
+    };
+    let arch = match platform.target_arch {
+        Arch::X86_64 => "amd64",
+        Arch::X86 => "386",
+        Arch::AArch64 => "arm64",
+        Arch::Arm => "arm",
+        _ => return None,
+    };
+    Some(format!("{},{}", os, arch))
+}
+
+pub(crate) struct GoBindgenConfig {
+    pub(crate) output_dir: PathBuf,
+    pub(crate) ffi_name: &'static str,
+    pub(crate) extra_files: Vec<PathBuf>,
+    pub(crate) platform_locations: PlatformLocations,
+}
+
+fn package_name(lib: &Library) -> String {
+    lib.settings.name.to_string().replace('_', "")
+}
+
+pub(crate) fn generate_go_package(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::Go);
+    logged::create_dir_all(&config.output_dir)?;
+
+    c::header::generate_c_header(lib, &config.output_dir.join("include"))?;
+    generate_module(lib, config)?;
+    generate_cgo_directives(lib, config)?;
+    generate_native_support(lib, config)?;
+    generate_constants(lib, config)?;
+    generate_enums(lib, config, naming)?;
+    generate_structs(lib, config)?;
+    generate_interfaces(lib, config)?;
+    generate_classes(lib, config)?;
+    generate_trampolines(lib, config)?;
+
+    // for each platform location, copy the library
+    for pl in config.platform_locations.iter() {
+        let span = tracing::info_span!("libs", platform = pl.platform.target_triple);
+        let _entered = span.enter();
+
+        let lib_path = config
+            .output_dir
+            .join("lib")
+            .join(pl.platform.target_triple);
+        logged::create_dir_all(&lib_path)?;
+
+        let lib_filename = pl.platform.dyn_lib_filename(config.ffi_name);
+        logged::copy(
+            pl.location.join(&lib_filename),
+            lib_path.join(&lib_filename),
+        )?;
+
+        let bin_filename = pl.platform.bin_filename(config.ffi_name);
+        logged::copy(
+            pl.location.join(&bin_filename),
+            lib_path.join(&bin_filename),
+        )?;
+    }
+
+    // Copy extra files
+    logged::copy(
+        &lib.info.license_path,
+        config
+            .output_dir
+            .join(lib.info.license_path.file_name().unwrap()),
+    )?;
+    for path in &config.extra_files {
+        logged::copy(path, config.output_dir.join(path.file_name().unwrap()))?;
+    }
+
+    Ok(())
+}
+
+fn generate_module(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(
+        config.output_dir.join("go.mod"),
+        lib.naming(TargetLanguage::Go),
+    )?;
+    f.writeln(&format!("module {}", package_name(lib)))?;
+    f.newline()?;
+    f.writeln("go 1.18")
+}
+
+fn generate_cgo_directives(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(
+        config.output_dir.join("cgo.go"),
+        lib.naming(TargetLanguage::Go),
+    )?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+
+    commented(&mut f, |f| {
+        f.writeln(&format!(
+            "Package {} {}",
+            package_name(lib),
+            lib.info.description
+        ))
+    })?;
+    f.writeln(&format!("package {}", package_name(lib)))?;
+    f.newline()?;
+
+    // the native library is linked from the directory of the platform doing the build
+    f.writeln("/*")?;
+    f.writeln("#cgo CFLAGS: -I${SRCDIR}/include")?;
+    // the wrappers call the deprecated C functions, Go users are warned by the doc comments
+    if lib.has_deprecated_items() {
+        f.writeln("#cgo CFLAGS: -Wno-deprecated-declarations")?;
+    }
+    let mut constraints: Vec<String> = Vec::new();
+    for pl in config.platform_locations.iter() {
+        let constraint = match go_build_constraint(&pl.platform) {
+            Some(x) => x,
+            None => continue,
+        };
+        // e.g. GNU and MUSL libraries can't be told apart by a build constraint
+        if constraints.contains(&constraint) {
+            continue;
+        }
+
+        let lib_dir = format!("${{SRCDIR}}/lib/{}", pl.platform.target_triple);
+        let rpath = if pl.platform.target_os == OS::Windows {
+            String::new()
+        } else {
+            format!(" -Wl,-rpath,{}", lib_dir)
+        };
+        f.writeln(&format!(
+            "#cgo {} LDFLAGS: -L{} -l{}{}",
+            constraint, lib_dir, config.ffi_name, rpath
+        ))?;
+        constraints.push(constraint);
+    }
+    f.writeln("*/")?;
+    f.writeln("import \"C\"")
+}
+
+/// Suffixes of file names that the Go toolchain interprets as test files or build constraints
+const FILE_SUFFIXES: &[&str] = &[
+    "test",
+    "aix",
+    "android",
+    "darwin",
+    "dragonfly",
+    "freebsd",
+    "illumos",
+    "ios",
+    "js",
+    "linux",
+    "netbsd",
+    "openbsd",
+    "plan9",
+    "solaris",
+    "windows",
+    "386",
+    "amd64",
+    "arm",
+    "arm64",
+    "wasm",
+];
+
+fn file_name(name: &Name) -> String {
+    let suffix = name.rsplit('_').next().unwrap_or_default();
+    if FILE_SUFFIXES.contains(&suffix) {
+        format!("{}_gen.go", name)
+    } else {
+        format!("{}.go", name)
+    }
+}
+
+/// Open a source file of the package
+///
+/// `preamble` contains C declarations added after the include of the header
+fn create_source_file(
+    lib: &Library,
+    config: &GoBindgenConfig,
+    name: &Name,
+    preamble: &[String],
+) -> FormattingResult<FilePrinter> {
+    let mut f = FilePrinter::new(
+        config.output_dir.join(file_name(name)),
+        lib.naming(TargetLanguage::Go),
+    )?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+    f.writeln(&format!("package {}", package_name(lib)))?;
+    f.newline()?;
+
+    f.writeln("/*")?;
+    f.writeln(&format!("#include \"{}.h\"", lib.settings.name))?;
+    for line in preamble {
+        f.writeln(line)?;
+    }
+    f.writeln("*/")?;
+    f.writeln("import \"C\"")?;
+    f.newline()?;
+    f.writeln("import (")?;
+    indented(&mut f, |f| {
+        f.writeln("\"runtime\"")?;
+        f.writeln("\"time\"")?;
+        f.writeln("\"unsafe\"")
+    })?;
+    f.writeln(")")?;
+    f.newline()?;
+
+    // reference the imports to suppress errors if they are not otherwise used
+    f.writeln("var _ = runtime.SetFinalizer")?;
+    f.writeln("var _ = time.Second")?;
+    f.writeln("var _ unsafe.Pointer")?;
+    f.newline()?;
+
+    Ok(f)
+}
+
+fn generate_native_support(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(
+        config.output_dir.join("native_support.go"),
+        lib.naming(TargetLanguage::Go),
+    )?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+    f.writeln(&format!("package {}", package_name(lib)))?;
+    f.newline()?;
+    f.writeln(include_str!("../../../static/go/native_support.go"))
+}
+
+fn generate_constants(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    fn get_value_as_string(value: &ConstantValue) -> String {
+        match value {
+            ConstantValue::U8(x, Representation::Hex) => format!("0x{:02X?}", x),
+        }
+    }
+
+    for set in lib.constants() {
+        let mut f = create_source_file(lib, config, &set.name, &[])?;
+
+        commented(&mut f, |f| go_doc_print(f, &set.doc))?;
+        f.writeln("const (")?;
+        indented(&mut f, |f| {
+            for value in &set.values {
+                commented(f, |f| go_doc_print(f, &value.doc))?;
+                f.writeln(&format!(
+                    "{}{} uint8 = {}",
+                    set.name.camel_case(f.naming()),
+                    value.name.camel_case(f.naming()),
+                    get_value_as_string(&value.value)
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln(")")?;
+    }
+
+    Ok(())
+}
+
+fn generate_enums(
+    lib: &Library,
+    config: &GoBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    for handle in lib.enums() {
+        let enum_name = handle.name.camel_case(naming);
+        let mut f = create_source_file(lib, config, &handle.name, &[])?;
+
+        commented(&mut f, |f| go_doc_print(f, &handle.doc))?;
+        f.writeln(&format!("type {} int32", enum_name))?;
+        f.newline()?;
+
+        f.writeln("const (")?;
+        indented(&mut f, |f| {
+            for variant in &handle.variants {
+                commented(f, |f| go_doc_print(f, &variant.doc))?;
+                f.writeln(&format!(
+                    "{}{} {} = {}",
+                    enum_name,
+                    variant.name.camel_case(naming),
+                    enum_name,
+                    variant.value
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln(")")?;
+        f.newline()?;
+
+        f.writeln("// String returns the name of the variant")?;
+        f.writeln(&format!("func (value {}) String() string {{", enum_name))?;
+        indented(&mut f, |f| {
+            f.writeln("switch value {")?;
+            for variant in &handle.variants {
+                f.writeln(&format!(
+                    "case {}{}:",
+                    enum_name,
+                    variant.name.camel_case(naming)
+                ))?;
+                indented(f, |f| f.writeln(&format!("return \"{}\"", variant.name)))?;
+            }
+            f.writeln("}")?;
+            f.writeln("return \"unknown\"")
+        })?;
+        f.writeln("}")?;
+
+        // error enums are returned as errors
+        if lib.error_types().any(|err| err.inner.name == handle.name) {
+            f.newline()?;
+            f.writeln("// Error implements the error interface")?;
+            f.writeln(&format!("func (value {}) Error() string {{", enum_name))?;
+            indented(&mut f, |f| f.writeln("return value.String()"))?;
+            f.writeln("}")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_structs(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    for st in lib.structs() {
+        let mut f = create_source_file(lib, config, st.name(), &[])?;
+
+        match st {
+            StructType::FunctionArg(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                structure::generate_to_native(&mut f, x)?;
+            }
+            StructType::FunctionReturn(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                structure::generate_from_native(&mut f, x)?;
+            }
+            StructType::CallbackArg(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                structure::generate_from_native(&mut f, x)?;
+            }
+            StructType::Universal(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                structure::generate_to_native(&mut f, x)?;
+                f.newline()?;
+                structure::generate_from_native(&mut f, x)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_interfaces(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    for interface in lib.interfaces() {
+        let handle = interface.untyped();
+        let mut f = create_source_file(
+            lib,
+            config,
+            &handle.name,
+            &interface::trampoline_declarations(handle),
+        )?;
+
+        match interface {
+            InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) => {
+                interface::generate(&mut f, x)?
+            }
+            InterfaceType::Future(x) => interface::generate_future(&mut f, x)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_classes(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    for class in lib.classes() {
+        let mut f = create_source_file(lib, config, class.name(), &[])?;
+        class::generate(&mut f, class)?;
+    }
+
+    for class in lib.static_classes() {
+        let mut f = create_source_file(lib, config, &class.name, &[])?;
+        class::generate_static(&mut f, class)?;
+    }
+
+    Ok(())
+}
+
+/// Exported Go functions called by the C function pointers of the interfaces
+///
+/// cgo doesn't allow C definitions in the preamble of a file that exports functions,
+/// so they live in their own file and are declared where the interfaces are converted
+fn generate_trampolines(lib: &Library, config: &GoBindgenConfig) -> FormattingResult<()> {
+    let mut f = create_source_file(lib, config, &Name::create("trampolines").unwrap(), &[])?;
+
+    for (i, interface) in lib.interfaces().enumerate() {
+        if i > 0 {
+            f.newline()?;
+        }
+        match interface {
+            InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) => {
+                interface::generate_trampolines(&mut f, x)?
+            }
+            InterfaceType::Future(x) => interface::generate_future_trampolines(&mut f, x)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn print_license(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    commented(f, |f| {
+        for line in lib.info.license_description.iter() {
+            f.writeln(line)?;
+        }
+        Ok(())
+    })
+}
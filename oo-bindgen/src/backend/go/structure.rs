@@ -0,0 +1,277 @@
+use crate::backend::c::ctype::CType;
+
+use super::conversion::*;
+use super::doc::*;
+use super::*;
+
+/// Go expression that evaluates to a default value
+pub(crate) fn default_value_expression(value: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => x.to_string(),
+        ValidatedDefaultValue::Duration(DurationType::Milliseconds, x) => {
+            format!("{} * time.Millisecond", x.as_millis())
+        }
+        ValidatedDefaultValue::Duration(DurationType::Seconds, x) => {
+            format!("{} * time.Second", x.as_secs())
+        }
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!(
+                "{}{}",
+                x.name.camel_case(naming),
+                variant.camel_case(naming)
+            )
+        }
+        ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
+        ValidatedDefaultValue::DefaultStruct(handle, initializer_type, name) => format!(
+            "{}()",
+            initializer_name(
+                handle.name(),
+                visibility(handle),
+                initializer_type,
+                name,
+                naming
+            )
+        ),
+    }
+}
+
+fn visibility<D>(st: &StructType<D>) -> Visibility
+where
+    D: DocReference,
+{
+    match st {
+        StructType::FunctionArg(x) => x.visibility,
+        StructType::FunctionReturn(x) => x.visibility,
+        StructType::CallbackArg(x) => x.visibility,
+        StructType::Universal(x) => x.visibility,
+    }
+}
+
+/// Initializers are package level functions, normal ones are named like constructors
+pub(crate) fn initializer_name(
+    struct_name: &Name,
+    visibility: Visibility,
+    initializer_type: &InitializerType,
+    name: &Name,
+    naming: &Naming,
+) -> String {
+    let exported = visibility == Visibility::Public;
+    match initializer_type {
+        InitializerType::Normal if exported => constructor_name(struct_name, naming),
+        InitializerType::Normal => format!("new{}", struct_name.camel_case(naming)),
+        InitializerType::Static => {
+            format!(
+                "{}{}",
+                identifier(struct_name, exported, naming),
+                name.camel_case(naming)
+            )
+        }
+    }
+}
+
+fn get_field_value<T>(
+    field: &StructField<T, Validated>,
+    initializer: &Initializer<Validated>,
+    naming: &Naming,
+) -> String
+where
+    T: StructFieldType,
+{
+    initializer
+        .values
+        .iter()
+        .find(|x| x.name == field.name)
+        .map(|x| default_value_expression(&x.value, naming))
+        .unwrap_or_else(|| field.name.mixed_case(naming))
+}
+
+fn write_initializer<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+    initializer: &Handle<Initializer<Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + GoType,
+{
+    let exported = st.visibility == Visibility::Public;
+    let struct_name = st.name().camel_case(f.naming());
+
+    if exported {
+        commented(f, |f| {
+            go_doc_print(f, &initializer.doc)?;
+
+            if !initializer.values.is_empty() {
+                f.newline()?;
+                f.writeln("Values are initialized to:")?;
+                for value in initializer.values.iter() {
+                    f.writeln(&format!(
+                        "  - {}: {}",
+                        value.name.camel_case(f.naming()),
+                        default_value_expression(&value.value, f.naming())
+                    ))?;
+                }
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let params = st
+        .initializer_args(initializer.clone())
+        .map(|field| {
+            format!(
+                "{} {}",
+                field.name.mixed_case(f.naming()),
+                field.field_type.go_type(f.naming())
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    f.writeln(&format!(
+        "func {}({}) {} {{",
+        initializer_name(
+            st.name(),
+            st.visibility,
+            &initializer.initializer_type,
+            &initializer.name,
+            f.naming(),
+        ),
+        params,
+        struct_name
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!("return {}{{", struct_name))?;
+        indented(f, |f| {
+            for field in st.fields() {
+                f.writeln(&format!(
+                    "{}: {},",
+                    identifier(&field.name, exported, f.naming()),
+                    get_field_value(field, initializer, f.naming())
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
+
+/// Write the public definition of the struct and its initializers
+pub(crate) fn generate<T>(
+    f: &mut dyn Printer,
+    st: &Handle<Struct<T, Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + GoType,
+{
+    let exported = st.visibility == Visibility::Public;
+
+    let doc = match st.visibility {
+        Visibility::Public => st.doc.clone(),
+        Visibility::Private => st
+            .doc
+            .clone()
+            .warning("This struct is an opaque handle and cannot be constructed by user code"),
+    };
+
+    commented(f, |f| go_doc_print(f, &doc))?;
+    f.writeln(&format!(
+        "type {} struct {{",
+        st.name().camel_case(f.naming())
+    ))?;
+    indented(f, |f| {
+        for field in st.fields() {
+            commented(f, |f| go_doc_print(f, &field.doc))?;
+            f.writeln(&format!(
+                "{} {}",
+                identifier(&field.name, exported, f.naming()),
+                field.field_type.go_type(f.naming())
+            ))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")?;
+
+    for initializer in &st.initializers {
+        f.newline()?;
+        write_initializer(f, st, initializer)?;
+    }
+
+    Ok(())
+}
+
+/// Write the conversion of a struct passed to the C API
+pub(crate) fn generate_to_native<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertToNative,
+{
+    let exported = st.visibility == Visibility::Public;
+    let c_type = cgo_type(&st.to_c_type());
+
+    f.writeln(&format!(
+        "func (instance {}) toNative({} *nativeScope) {} {{",
+        st.name().camel_case(f.naming()),
+        SCOPE,
+        c_type
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!("return {}{{", c_type))?;
+        indented(f, |f| {
+            for field in st.fields() {
+                f.writeln(&format!(
+                    "{}: {},",
+                    c_field(&field.name, f.naming()),
+                    field.field_type.convert_to_native(
+                        &format!("instance.{}", identifier(&field.name, exported, f.naming())),
+                        f.naming()
+                    )
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
+
+/// Write the conversion of a struct returned by the C API
+pub(crate) fn generate_from_native<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertToGo,
+{
+    let exported = st.visibility == Visibility::Public;
+    let struct_name = st.name().camel_case(f.naming());
+
+    f.writeln(&format!(
+        "func {}(native {}) {} {{",
+        from_native_func(st.name(), f.naming()),
+        cgo_type(&st.to_c_type()),
+        struct_name
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!("return {}{{", struct_name))?;
+        indented(f, |f| {
+            for field in st.fields() {
+                f.writeln(&format!(
+                    "{}: {},",
+                    identifier(&field.name, exported, f.naming()),
+                    field.field_type.convert_to_go(
+                        &format!("native.{}", c_field(&field.name, f.naming())),
+                        f.naming()
+                    )
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
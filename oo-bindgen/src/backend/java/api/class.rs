@@ -1,11 +1,13 @@
 use super::doc::*;
+use super::structure::{default_value_expression, get_default_value_doc};
 use super::*;
 
 pub(crate) fn generate(
     f: &mut dyn Printer,
     class: &Handle<Class<Validated>>,
+    interop: JavaInterop,
 ) -> FormattingResult<()> {
-    let classname = class.name().camel_case();
+    let classname = class.name().camel_case(f.naming());
 
     // Documentation
     documentation(f, |f| javadoc_print(f, &class.doc))?;
@@ -17,14 +19,21 @@ pub(crate) fn generate(
     }
 
     blocked(f, |f| {
-        f.writeln("final private long self;")?;
+        f.writeln(&format!(
+            "final {}long self;",
+            interop.internal_visibility(&class.declaration.module)
+        ))?;
         if class.destructor.is_some() {
             f.writeln("private java.util.concurrent.atomic.AtomicBoolean disposed = new java.util.concurrent.atomic.AtomicBoolean(false);")?;
         }
 
         f.newline()?;
 
-        f.writeln(&format!("private {}(long self)", classname))?;
+        f.writeln(&format!(
+            "{}{}(long self)",
+            interop.internal_visibility(&class.declaration.module),
+            classname
+        ))?;
         blocked(f, |f| f.writeln("this.self = self;"))?;
 
         f.newline()?;
@@ -62,7 +71,7 @@ pub(crate) fn generate_static(
     f: &mut dyn Printer,
     class: &Handle<StaticClass<Validated>>,
 ) -> FormattingResult<()> {
-    let classname = class.name.camel_case();
+    let classname = class.name.camel_case(f.naming());
 
     // Documentation
     documentation(f, |f| javadoc_print(f, &class.doc))?;
@@ -96,7 +105,7 @@ fn generate_constructor(
 
         // Print each parameter value
         for param in constructor.function.arguments.iter() {
-            f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+            f.writeln(&format!("@param {} ", param.name.mixed_case(f.naming())))?;
             docstring_print(f, &param.doc)?;
         }
 
@@ -104,13 +113,14 @@ fn generate_constructor(
         if let Some(error) = &constructor.function.error_type.get() {
             f.writeln(&format!(
                 "@throws {} {}",
-                error.exception_name.camel_case(),
-                error.inner.name.camel_case()
+                error.exception_name.camel_case(f.naming()),
+                error.inner.name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    deprecated_annotation(f, &constructor.function.doc)?;
 
     f.writeln(&format!("public {}(", classname))?;
     f.write(
@@ -121,8 +131,8 @@ fn generate_constructor(
             .map(|param| {
                 format!(
                     "{} {}",
-                    param.arg_type.as_java_primitive(),
-                    param.name.mixed_case()
+                    param.arg_type.as_java_primitive(f.naming()),
+                    param.name.mixed_case(f.naming())
                 )
             })
             .collect::<Vec<String>>()
@@ -139,7 +149,15 @@ fn generate_constructor(
         )?;
         f.writeln("this.self = object.self;")?;
         f.writeln("object.disposed.set(true);")
-    })
+    })?;
+
+    generate_default_overloads(
+        f,
+        &constructor.function,
+        0,
+        &format!("public {}", classname),
+        &|args| format!("this({});", args),
+    )
 }
 
 fn generate_destructor(
@@ -155,7 +173,7 @@ fn generate_destructor(
 
             // Print each parameter value
             for param in destructor.function.arguments.iter().skip(1) {
-                f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+                f.writeln(&format!("@param {} ", param.name.mixed_case(f.naming())))?;
                 docstring_print(f, &param.doc)?;
             }
 
@@ -168,7 +186,7 @@ fn generate_destructor(
             f.writeln("private void close()")?;
         }
         DestructionMode::Custom(name) => {
-            f.writeln(&format!("public void {}()", name.mixed_case()))?;
+            f.writeln(&format!("public void {}()", name.mixed_case(f.naming())))?;
         }
         DestructionMode::Dispose => {
             // AutoCloseable implementation
@@ -196,7 +214,7 @@ fn generate_destructor(
     f.writeln("public void finalize()")?;
     blocked(f, |f| {
         if let DestructionMode::Custom(name) = destruction_mode {
-            f.writeln(&format!("this.{}();", name.mixed_case()))
+            f.writeln(&format!("this.{}();", name.mixed_case(f.naming())))
         } else {
             f.writeln("this.close();")
         }
@@ -211,7 +229,7 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
 
         // Print each parameter value
         for param in method.native_function.arguments.iter().skip(1) {
-            f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+            f.writeln(&format!("@param {} ", param.name.mixed_case(f.naming())))?;
             docstring_print(f, &param.doc)?;
         }
 
@@ -225,18 +243,22 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
         if let Some(error) = &method.native_function.error_type.get() {
             f.writeln(&format!(
                 "@throws {} {}",
-                error.exception_name.camel_case(),
-                error.inner.name.camel_case()
+                error.exception_name.camel_case(f.naming()),
+                error.inner.name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    deprecated_annotation(f, &method.native_function.doc)?;
 
     f.writeln(&format!(
         "public {} {}(",
-        method.native_function.return_type.as_java_primitive(),
-        method.name.mixed_case()
+        method
+            .native_function
+            .return_type
+            .as_java_primitive(f.naming()),
+        method.name.mixed_case(f.naming())
     ))?;
     f.write(
         &method
@@ -247,8 +269,8 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
             .map(|param| {
                 format!(
                     "{} {}",
-                    param.arg_type.as_java_primitive(),
-                    param.name.mixed_case()
+                    param.arg_type.as_java_primitive(f.naming()),
+                    param.name.mixed_case(f.naming())
                 )
             })
             .collect::<Vec<String>>()
@@ -258,13 +280,32 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
 
     if let Some(error) = method.native_function.error_type.get() {
         if error.exception_type == ExceptionType::CheckedException {
-            f.write(&format!(" throws {}", error.exception_name.camel_case()))?;
+            f.write(&format!(
+                " throws {}",
+                error.exception_name.camel_case(f.naming())
+            ))?;
         }
     }
 
     blocked(f, |f| {
         call_native_function(f, &method.native_function, "return ", true)
-    })
+    })?;
+
+    let naming = f.naming().clone();
+    generate_default_overloads(
+        f,
+        &method.native_function,
+        1,
+        &format!(
+            "public {} {}",
+            method
+                .native_function
+                .return_type
+                .as_java_primitive(&naming),
+            method.name.mixed_case(&naming)
+        ),
+        &|args| invoke_full_overload(&method.native_function, &method.name, args, &naming),
+    )
 }
 
 fn generate_static_method(
@@ -278,7 +319,7 @@ fn generate_static_method(
 
         // Print each parameter value
         for param in method.native_function.arguments.iter() {
-            f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+            f.writeln(&format!("@param {} ", param.name.mixed_case(f.naming())))?;
             docstring_print(f, &param.doc)?;
         }
 
@@ -292,18 +333,22 @@ fn generate_static_method(
         if let Some(error) = &method.native_function.error_type.get() {
             f.writeln(&format!(
                 "@throws {} {}",
-                error.exception_name.camel_case(),
-                error.inner.name.camel_case()
+                error.exception_name.camel_case(f.naming()),
+                error.inner.name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    deprecated_annotation(f, &method.native_function.doc)?;
 
     f.writeln(&format!(
         "public static {} {}(",
-        method.native_function.return_type.as_java_primitive(),
-        method.name.mixed_case()
+        method
+            .native_function
+            .return_type
+            .as_java_primitive(f.naming()),
+        method.name.mixed_case(f.naming())
     ))?;
     f.write(
         &method
@@ -313,8 +358,8 @@ fn generate_static_method(
             .map(|param| {
                 format!(
                     "{} {}",
-                    param.arg_type.as_java_primitive(),
-                    param.name.mixed_case()
+                    param.arg_type.as_java_primitive(f.naming()),
+                    param.name.mixed_case(f.naming())
                 )
             })
             .collect::<Vec<String>>()
@@ -324,26 +369,157 @@ fn generate_static_method(
 
     if let Some(error) = method.native_function.error_type.get() {
         if error.exception_type == ExceptionType::CheckedException {
-            f.write(&format!(" throws {}", error.exception_name.camel_case()))?;
+            f.write(&format!(
+                " throws {}",
+                error.exception_name.camel_case(f.naming())
+            ))?;
         }
     }
 
     blocked(f, |f| {
         call_native_function(f, &method.native_function, "return ", false)
-    })
+    })?;
+
+    let naming = f.naming().clone();
+    generate_default_overloads(
+        f,
+        &method.native_function,
+        0,
+        &format!(
+            "public static {} {}",
+            method
+                .native_function
+                .return_type
+                .as_java_primitive(&naming),
+            method.name.mixed_case(&naming)
+        ),
+        &|args| invoke_full_overload(&method.native_function, &method.name, args, &naming),
+    )
+}
+
+fn invoke_full_overload(
+    function: &Function<Validated>,
+    name: &Name,
+    args: String,
+    naming: &Naming,
+) -> String {
+    if function.return_type.is_none() {
+        format!("{}({});", name.mixed_case(naming), args)
+    } else {
+        format!("return {}({});", name.mixed_case(naming), args)
+    }
+}
+
+/// Java has no default arguments, so write an overload for each parameter that may be omitted
+fn generate_default_overloads(
+    f: &mut dyn Printer,
+    function: &Function<Validated>,
+    skip: usize,
+    declaration: &str,
+    invocation: &dyn Fn(String) -> String,
+) -> FormattingResult<()> {
+    let params: Vec<&Arg<FunctionArgument, Validated>> =
+        function.arguments.iter().skip(skip).collect();
+    let num_defaults = params.iter().filter(|x| x.default.is_some()).count();
+
+    for num_omitted in 1..=num_defaults {
+        let (provided, omitted) = params.split_at(params.len() - num_omitted);
+
+        f.newline()?;
+        documentation(f, |f| {
+            // Print top-level documentation
+            javadoc_print(f, &function.doc)?;
+            f.newline()?;
+
+            for param in omitted {
+                if let Some(default) = &param.default {
+                    f.writeln(&format!(
+                        "<p>{{@code {}}} defaults to {}</p>",
+                        param.name.mixed_case(f.naming()),
+                        get_default_value_doc(default, f.naming())
+                    ))?;
+                }
+            }
+
+            // Print each parameter value
+            for param in provided {
+                f.writeln(&format!("@param {} ", param.name.mixed_case(f.naming())))?;
+                docstring_print(f, &param.doc)?;
+            }
+
+            // Print return value
+            if let Some(doc) = &function.return_type.get_doc() {
+                f.writeln("@return ")?;
+                docstring_print(f, doc)?;
+            }
+
+            // Print exception
+            if let Some(error) = &function.error_type.get() {
+                f.writeln(&format!(
+                    "@throws {} {}",
+                    error.exception_name.camel_case(f.naming()),
+                    error.inner.name.camel_case(f.naming())
+                ))?;
+            }
+
+            Ok(())
+        })?;
+        deprecated_annotation(f, &function.doc)?;
+
+        f.writeln(&format!("{}(", declaration))?;
+        f.write(
+            &provided
+                .iter()
+                .map(|param| {
+                    format!(
+                        "{} {}",
+                        param.arg_type.as_java_primitive(f.naming()),
+                        param.name.mixed_case(f.naming())
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+        )?;
+        f.write(")")?;
+
+        if let Some(error) = function.error_type.get() {
+            if error.exception_type == ExceptionType::CheckedException {
+                f.write(&format!(
+                    " throws {}",
+                    error.exception_name.camel_case(f.naming())
+                ))?;
+            }
+        }
+
+        let args = provided
+            .iter()
+            .map(|param| param.name.mixed_case(f.naming()))
+            .chain(omitted.iter().filter_map(|param| {
+                param
+                    .default
+                    .as_ref()
+                    .map(|x| default_value_expression(x, f.naming()))
+            }))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        blocked(f, |f| f.writeln(&invocation(args)))?;
+    }
+
+    Ok(())
 }
 
 fn generate_async_method(
     f: &mut dyn Printer,
     method: &FutureMethod<Validated>,
 ) -> FormattingResult<()> {
-    let value_type = method.future.value_type.as_java_object();
+    let value_type = method.future.value_type.as_java_object(f.naming());
     let settings = method.future.interface.settings.clone();
-    let interface_name = method.future.interface.name.camel_case();
+    let interface_name = method.future.interface.name.camel_case(f.naming());
     let callback_param_name = settings
         .future
         .async_method_callback_parameter_name
-        .mixed_case();
+        .mixed_case(f.naming());
 
     let future_type = format!("java.util.concurrent.CompletableFuture<{}>", value_type);
 
@@ -355,7 +531,7 @@ fn generate_async_method(
 
         // Print each parameter value
         for param in method.arguments_without_callback() {
-            f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+            f.writeln(&format!("@param {} ", param.name.mixed_case(f.naming())))?;
             docstring_print(f, &param.doc)?;
         }
 
@@ -367,18 +543,19 @@ fn generate_async_method(
         if let Some(error) = &method.native_function.error_type.get() {
             f.writeln(&format!(
                 "@throws {} {}",
-                error.exception_name.camel_case(),
-                error.inner.name.camel_case()
+                error.exception_name.camel_case(f.naming()),
+                error.inner.name.camel_case(f.naming())
             ))?;
         }
 
         Ok(())
     })?;
+    deprecated_annotation(f, &method.native_function.doc)?;
 
     f.writeln(&format!(
         "public java.util.concurrent.CompletionStage<{}> {}(",
         value_type,
-        method.name.mixed_case()
+        method.name.mixed_case(f.naming())
     ))?;
     f.write(
         &method
@@ -386,8 +563,8 @@ fn generate_async_method(
             .map(|param| {
                 format!(
                     "{} {}",
-                    param.arg_type.as_java_primitive(),
-                    param.name.mixed_case()
+                    param.arg_type.as_java_primitive(f.naming()),
+                    param.name.mixed_case(f.naming())
                 )
             })
             .collect::<Vec<String>>()
@@ -397,7 +574,10 @@ fn generate_async_method(
 
     if let Some(error) = method.native_function.error_type.get() {
         if error.exception_type == ExceptionType::CheckedException {
-            f.write(&format!(" throws {}", error.exception_name.camel_case()))?;
+            f.write(&format!(
+                " throws {}",
+                error.exception_name.camel_case(f.naming())
+            ))?;
         }
     }
 
@@ -411,7 +591,10 @@ fn generate_async_method(
         indented(f, |f| {
             f.writeln(&format!(
                 "public void {}({} value)",
-                settings.future.success_callback_method_name.mixed_case(),
+                settings
+                    .future
+                    .success_callback_method_name
+                    .mixed_case(f.naming()),
                 value_type
             ))?;
             blocked(f, |f| f.writeln("_future.complete(value);"))?;
@@ -420,13 +603,16 @@ fn generate_async_method(
                 f.newline()?;
                 f.writeln(&format!(
                     "public void {}({} error)",
-                    settings.future.failure_callback_method_name.mixed_case(),
-                    err.inner.name.camel_case()
+                    settings
+                        .future
+                        .failure_callback_method_name
+                        .mixed_case(f.naming()),
+                    err.inner.name.camel_case(f.naming())
                 ))?;
                 blocked(f, |f| {
                     f.writeln(&format!(
                         "_future.completeExceptionally(new {}(error));",
-                        err.exception_name.camel_case()
+                        err.exception_name.camel_case(f.naming())
                     ))
                 })?;
             }
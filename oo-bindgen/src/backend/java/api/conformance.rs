@@ -0,0 +1,314 @@
+use std::path::Path;
+
+use crate::backend::java::api::library_packages;
+use crate::backend::*;
+use crate::conformance::{echoed_struct, leaf_fields, values, Case, Method, Suite, Value};
+use crate::model::*;
+
+/// Write the conformance tests of the Java bindings as the `ConformanceTest` JUnit class
+pub(crate) fn generate(
+    lib: &Library,
+    suite: &Suite,
+    group_id: &str,
+    path: &Path,
+) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::Java);
+    let mut f = FilePrinter::new(path, naming.clone())?;
+    let class = suite.class.name.camel_case(naming);
+
+    f.writeln(&format!(
+        "// Conformance tests of the {} Java bindings generated by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.writeln(&format!("package {}.{}_test;", group_id, lib.settings.name))?;
+    f.newline()?;
+    for package in library_packages(group_id, lib, naming) {
+        f.writeln(&format!("import {}.*;", package))?;
+    }
+    f.writeln("import org.joou.*;")?;
+    f.writeln("import org.junit.jupiter.api.Test;")?;
+    f.newline()?;
+    f.writeln("import static org.assertj.core.api.Assertions.assertThat;")?;
+    f.writeln("import static org.assertj.core.api.Assertions.assertThatThrownBy;")?;
+    f.writeln("import static org.joou.Unsigned.*;")?;
+    f.newline()?;
+    f.writeln("public class ConformanceTest")?;
+    blocked(&mut f, |f| {
+        for (index, case) in suite.cases.iter().enumerate() {
+            if index > 0 {
+                f.newline()?;
+            }
+            f.writeln("@Test")?;
+            match case {
+                Case::Echo { method, value_type } => {
+                    f.writeln(&format!("public void {}()", method.name.camel_case(naming)))?;
+                    blocked(f, |f| write_echo(f, &class, &method.name, value_type))?;
+                }
+                Case::Raise { method, error } => {
+                    // the exception may be checked
+                    f.writeln(&format!(
+                        "public void {}() throws Exception",
+                        method.name.camel_case(naming)
+                    ))?;
+                    blocked(f, |f| write_raise(f, &class, method, error))?;
+                }
+                Case::List {
+                    collection,
+                    size,
+                    get,
+                } => {
+                    f.writeln(&format!(
+                        "public void {}()",
+                        collection.name().camel_case(naming)
+                    ))?;
+                    blocked(f, |f| {
+                        write_list(f, &class, &collection.item_type, size, get)
+                    })?;
+                }
+                Case::Iterate {
+                    method, collection, ..
+                } => {
+                    f.writeln(&format!("public void {}()", method.name.camel_case(naming)))?;
+                    blocked(f, |f| {
+                        write_iterate(f, &class, &method.name, &collection.item_type)
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+fn write_echo(
+    f: &mut dyn Printer,
+    class: &str,
+    method: &Name,
+    value_type: &FunctionArgument,
+) -> FormattingResult<()> {
+    if let Some(handle) = echoed_struct(value_type) {
+        let java_type = handle.name().camel_case(f.naming());
+        f.writeln(&format!("{} value = new {}();", java_type, java_type))?;
+        f.writeln(&format!(
+            "{} result = {}.{}(value);",
+            java_type,
+            class,
+            method.mixed_case(f.naming())
+        ))?;
+        for path in leaf_fields(handle) {
+            let field = path
+                .iter()
+                .map(|x| x.mixed_case(f.naming()))
+                .collect::<Vec<_>>()
+                .join(".");
+            f.writeln(&format!(
+                "assertThat(result.{}).isEqualTo(value.{});",
+                field, field
+            ))?;
+        }
+        return Ok(());
+    }
+
+    for value in values(value_type) {
+        let value = render(value_type, &value, f.naming())?;
+        f.writeln(&format!(
+            "assertThat({}.{}({})).isEqualTo({});",
+            class,
+            method.mixed_case(f.naming()),
+            value,
+            value
+        ))?;
+    }
+    Ok(())
+}
+
+fn write_raise(
+    f: &mut dyn Printer,
+    class: &str,
+    method: &Method,
+    error: &ErrorType<Validated>,
+) -> FormattingResult<()> {
+    let mut variants = error.inner.variants.iter();
+    // the first variant is the absence of error
+    if let Some(ok) = variants.next() {
+        f.writeln(&format!(
+            "{}.{}(uint({}));",
+            class,
+            method.name.mixed_case(f.naming()),
+            ok.value
+        ))?;
+    }
+    for variant in variants {
+        f.writeln(&format!(
+            "assertThatThrownBy(() -> {}.{}(uint({})))",
+            class,
+            method.name.mixed_case(f.naming()),
+            variant.value
+        ))?;
+        indented(f, |f| {
+            f.writeln(&format!(
+                ".isInstanceOf({}.class)",
+                error.exception_name.camel_case(f.naming())
+            ))?;
+            f.writeln(&format!(
+                ".hasFieldOrPropertyWithValue(\"error\", {}.{});",
+                error.inner.name.camel_case(f.naming()),
+                variant.name.capital_snake_case()
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+fn write_list(
+    f: &mut dyn Printer,
+    class: &str,
+    item_type: &FunctionArgument,
+    size: &Method,
+    get: &Method,
+) -> FormattingResult<()> {
+    let values = write_values(f, item_type)?;
+    f.writeln(&format!(
+        "assertThat({}.{}(list)).isEqualTo(uint({}));",
+        class,
+        size.name.mixed_case(f.naming()),
+        values.len()
+    ))?;
+    for (index, value) in values.iter().enumerate() {
+        f.writeln(&format!(
+            "assertThat({}.{}(list, uint({}))).isEqualTo({});",
+            class,
+            get.name.mixed_case(f.naming()),
+            index,
+            value
+        ))?;
+    }
+    Ok(())
+}
+
+fn write_iterate(
+    f: &mut dyn Printer,
+    class: &str,
+    method: &Name,
+    item_type: &FunctionArgument,
+) -> FormattingResult<()> {
+    write_values(f, item_type)?;
+    f.writeln(&format!(
+        "java.util.List<{}> received = new java.util.ArrayList<>();",
+        java_type(item_type)
+    ))?;
+    f.writeln(&format!(
+        "{}.{}(list, values -> received.addAll(values));",
+        class,
+        method.mixed_case(f.naming())
+    ))?;
+    f.writeln("assertThat(received).isEqualTo(list);")
+}
+
+/// Write a `list` holding the values exercising the edges of the item type
+fn write_values(
+    f: &mut dyn Printer,
+    item_type: &FunctionArgument,
+) -> FormattingResult<Vec<String>> {
+    let values = values(item_type)
+        .iter()
+        .map(|x| render(item_type, x, f.naming()))
+        .collect::<FormattingResult<Vec<_>>>()?;
+
+    f.writeln(&format!(
+        "java.util.List<{}> list = java.util.Arrays.asList({});",
+        java_type(item_type),
+        values.join(", ")
+    ))?;
+    Ok(values)
+}
+
+fn java_type(item_type: &FunctionArgument) -> &'static str {
+    match item_type {
+        FunctionArgument::Basic(BasicType::Primitive(x)) => boxed_type(*x),
+        _ => "String",
+    }
+}
+
+fn boxed_type(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "Boolean",
+        Primitive::U8 => "UByte",
+        Primitive::S8 => "Byte",
+        Primitive::U16 => "UShort",
+        Primitive::S16 => "Short",
+        Primitive::U32 => "UInteger",
+        Primitive::S32 => "Integer",
+        Primitive::U64 => "ULong",
+        Primitive::S64 => "Long",
+        Primitive::Float => "Float",
+        Primitive::Double => "Double",
+    }
+}
+
+fn render(
+    value_type: &FunctionArgument,
+    value: &Value,
+    naming: &Naming,
+) -> FormattingResult<String> {
+    let rendered = match (value_type, value) {
+        (_, Value::Bool(x)) => x.to_string(),
+        (FunctionArgument::Basic(BasicType::Primitive(x)), value) => primitive(*x, value),
+        (FunctionArgument::Basic(BasicType::Duration(x)), Value::Duration(value)) => match x {
+            DurationType::Milliseconds => format!("java.time.Duration.ofMillis({}L)", value),
+            DurationType::Seconds => format!("java.time.Duration.ofSeconds({}L)", value),
+        },
+        (_, Value::Text(x)) => string_literal(x),
+        (FunctionArgument::Basic(BasicType::Enum(x)), Value::Variant(variant)) => {
+            format!(
+                "{}.{}",
+                x.name.camel_case(naming),
+                variant.capital_snake_case()
+            )
+        }
+        _ => return Err("conformance value does not match its type".into()),
+    };
+    Ok(rendered)
+}
+
+fn primitive(primitive: Primitive, value: &Value) -> String {
+    let (min, zero, max) = match primitive {
+        Primitive::Bool => ("false", "false", "true"),
+        Primitive::U8 => ("UByte.MIN", "UByte.MIN", "UByte.MAX"),
+        Primitive::S8 => ("Byte.MIN_VALUE", "(byte) 0", "Byte.MAX_VALUE"),
+        Primitive::U16 => ("UShort.MIN", "UShort.MIN", "UShort.MAX"),
+        Primitive::S16 => ("Short.MIN_VALUE", "(short) 0", "Short.MAX_VALUE"),
+        Primitive::U32 => ("UInteger.MIN", "UInteger.MIN", "UInteger.MAX"),
+        Primitive::S32 => ("Integer.MIN_VALUE", "0", "Integer.MAX_VALUE"),
+        Primitive::U64 => ("ULong.MIN", "ULong.MIN", "ULong.MAX"),
+        Primitive::S64 => ("Long.MIN_VALUE", "0L", "Long.MAX_VALUE"),
+        Primitive::Float => ("-Float.MAX_VALUE", "0.0f", "Float.MAX_VALUE"),
+        Primitive::Double => ("-Double.MAX_VALUE", "0.0", "Double.MAX_VALUE"),
+    };
+    match value {
+        Value::Min => min.to_string(),
+        Value::Max => max.to_string(),
+        _ => zero.to_string(),
+    }
+}
+
+/// Java string literal with the UTF-16 code units outside of ASCII escaped
+fn string_literal(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            ' '..='~' => literal.push(c),
+            _ => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    literal.push_str(&format!("\\u{:04x}", unit));
+                }
+            }
+        }
+    }
+    literal.push('"');
+    literal
+}
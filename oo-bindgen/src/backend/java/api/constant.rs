@@ -17,7 +17,7 @@ pub(crate) fn generate(
         }
     }
 
-    let set_name = set.name.camel_case();
+    let set_name = set.name.camel_case(f.naming());
 
     // Documentation
     documentation(f, |f| javadoc_print(f, &set.doc))?;
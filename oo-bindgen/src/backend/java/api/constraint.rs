@@ -0,0 +1,124 @@
+use crate::backend::*;
+use crate::model::*;
+
+use super::structure::number_literal;
+
+fn is_unsigned(target: ConstrainedType) -> bool {
+    matches!(
+        target,
+        ConstrainedType::Primitive(Primitive::U8)
+            | ConstrainedType::Primitive(Primitive::U16)
+            | ConstrainedType::Primitive(Primitive::U32)
+            | ConstrainedType::Primitive(Primitive::U64)
+    )
+}
+
+fn less_than(target: ConstrainedType, value: &str, bound: &NumberValue) -> String {
+    if is_unsigned(target) {
+        // the unsigned wrappers don't support the relational operators
+        format!("{}.compareTo({}) < 0", value, number_literal(bound))
+    } else {
+        format!("{} < {}", value, number_literal(bound))
+    }
+}
+
+fn greater_than(target: ConstrainedType, value: &str, bound: &NumberValue) -> String {
+    if is_unsigned(target) {
+        format!("{}.compareTo({}) > 0", value, number_literal(bound))
+    } else {
+        format!("{} > {}", value, number_literal(bound))
+    }
+}
+
+/// Distinct regular expressions of the constraints, in order of appearance
+pub(crate) fn regex_patterns<'a>(
+    constraints: impl Iterator<Item = &'a Constraint>,
+) -> Vec<&'a str> {
+    let mut patterns: Vec<&str> = Vec::new();
+    for constraint in constraints {
+        if let Constraint::Regex(x) = constraint {
+            if !patterns.contains(&x.as_str()) {
+                patterns.push(x);
+            }
+        }
+    }
+    patterns
+}
+
+fn pattern_field(index: usize) -> String {
+    format!("PATTERN_{}", index)
+}
+
+/// Write a `static final Pattern` for each of the patterns, compiled once when the class is loaded
+pub(crate) fn write_regex_patterns(f: &mut dyn Printer, patterns: &[&str]) -> FormattingResult<()> {
+    for (index, pattern) in patterns.iter().enumerate() {
+        f.writeln(&format!(
+            "private static final java.util.regex.Pattern {} = java.util.regex.Pattern.compile(\"{}\");",
+            pattern_field(index),
+            escape_string_literal(pattern)
+        ))?;
+    }
+    Ok(())
+}
+
+/// Write a check that throws `IllegalArgumentException` if `value` violates the constraint
+///
+/// `patterns` are the ones written by [write_regex_patterns] in the enclosing class
+pub(crate) fn write_constraint_check(
+    f: &mut dyn Printer,
+    constraint: &Constraint,
+    target: ConstrainedType,
+    value: &str,
+    patterns: &[&str],
+) -> FormattingResult<()> {
+    let condition = match constraint {
+        Constraint::Range { .. } => {
+            let conditions: Vec<String> = constraint
+                .lower_bound()
+                .map(|min| less_than(target, value, &min))
+                .into_iter()
+                .chain(
+                    constraint
+                        .upper_bound()
+                        .map(|max| greater_than(target, value, &max)),
+                )
+                .collect();
+            if conditions.is_empty() {
+                // the range covers every value of the type
+                return Ok(());
+            }
+            conditions.join(" || ")
+        }
+        Constraint::NonZero => match target {
+            // check the value that actually crosses the FFI boundary
+            ConstrainedType::Duration(DurationType::Milliseconds) => {
+                format!("{}.toMillis() == 0", value)
+            }
+            ConstrainedType::Duration(DurationType::Seconds) => {
+                format!("{}.getSeconds() == 0", value)
+            }
+            _ if is_unsigned(target) => format!("{}.longValue() == 0", value),
+            _ => format!("{} == 0", value),
+        },
+        Constraint::NonEmpty => format!("{}.isEmpty()", value),
+        Constraint::MaxLength(x) => format!(
+            "{}.getBytes(java.nio.charset.StandardCharsets.UTF_8).length > {}",
+            value, x
+        ),
+        Constraint::Regex(x) => {
+            let index = patterns
+                .iter()
+                .position(|p| p == x)
+                .expect("pattern not written in the enclosing class");
+            format!("!{}.matcher({}).matches()", pattern_field(index), value)
+        }
+    };
+
+    f.writeln(&format!("if({})", condition))?;
+    blocked(f, |f| {
+        f.writeln(&format!(
+            "throw new IllegalArgumentException(\"{}\");",
+            constraint.violation_message(value)
+        ))
+    })
+}
@@ -4,12 +4,12 @@ use crate::model::*;
 use crate::backend::java::api::NATIVE_FUNCTIONS_CLASSNAME;
 
 pub(crate) trait JavaType {
-    fn as_java_primitive(&self) -> String;
-    fn as_java_object(&self) -> String;
+    fn as_java_primitive(&self, naming: &Naming) -> String;
+    fn as_java_object(&self, naming: &Naming) -> String;
 }
 
 impl JavaType for Primitive {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, _naming: &Naming) -> String {
         match self {
             Self::Bool => "boolean".to_string(),
             Self::U8 => "UByte".to_string(),
@@ -25,7 +25,7 @@ impl JavaType for Primitive {
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, _naming: &Naming) -> String {
         match self {
             Self::Bool => "Boolean".to_string(),
             Self::U8 => "UByte".to_string(),
@@ -43,29 +43,29 @@ impl JavaType for Primitive {
 }
 
 impl JavaType for BasicType {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            Self::Primitive(x) => x.as_java_primitive(),
+            Self::Primitive(x) => x.as_java_primitive(naming),
             Self::Duration(_) => "java.time.Duration".to_string(),
-            Self::Enum(handle) => handle.name.camel_case(),
+            Self::Enum(handle) => handle.name.camel_case(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            Self::Primitive(x) => x.as_java_object(),
+            Self::Primitive(x) => x.as_java_object(naming),
             Self::Duration(_) => "java.time.Duration".to_string(),
-            Self::Enum(handle) => handle.name.camel_case(),
+            Self::Enum(handle) => handle.name.camel_case(naming),
         }
     }
 }
 
 impl JavaType for StringType {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, _naming: &Naming) -> String {
         "String".to_string()
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, _naming: &Naming) -> String {
         "String".to_string()
     }
 }
@@ -74,12 +74,12 @@ impl<D> JavaType for Handle<Interface<D>>
 where
     D: DocReference,
 {
-    fn as_java_primitive(&self) -> String {
-        self.as_java_object()
+    fn as_java_primitive(&self, naming: &Naming) -> String {
+        self.as_java_object(naming)
     }
 
-    fn as_java_object(&self) -> String {
-        self.name.camel_case()
+    fn as_java_object(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
     }
 }
 
@@ -87,47 +87,47 @@ impl<D> JavaType for Handle<Collection<D>>
 where
     D: DocReference,
 {
-    fn as_java_primitive(&self) -> String {
-        self.as_java_object()
+    fn as_java_primitive(&self, naming: &Naming) -> String {
+        self.as_java_object(naming)
     }
 
-    fn as_java_object(&self) -> String {
-        format!("java.util.List<{}>", self.item_type.as_java_object())
+    fn as_java_object(&self, naming: &Naming) -> String {
+        format!("java.util.List<{}>", self.item_type.as_java_object(naming))
     }
 }
 
 impl JavaType for ClassDeclarationHandle {
-    fn as_java_primitive(&self) -> String {
-        self.as_java_object()
+    fn as_java_primitive(&self, naming: &Naming) -> String {
+        self.as_java_object(naming)
     }
 
-    fn as_java_object(&self) -> String {
-        self.name.camel_case()
+    fn as_java_object(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
     }
 }
 
 impl JavaType for StructDeclarationHandle {
-    fn as_java_primitive(&self) -> String {
-        self.as_java_object()
+    fn as_java_primitive(&self, naming: &Naming) -> String {
+        self.as_java_object(naming)
     }
 
-    fn as_java_object(&self) -> String {
-        self.name.camel_case()
+    fn as_java_object(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
     }
 }
 
 impl JavaType for IteratorItemType {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            IteratorItemType::Struct(x) => x.as_java_primitive(),
-            IteratorItemType::Primitive(x) => x.as_java_primitive(),
+            IteratorItemType::Struct(x) => x.as_java_primitive(naming),
+            IteratorItemType::Primitive(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            IteratorItemType::Struct(x) => x.as_java_object(),
-            IteratorItemType::Primitive(x) => x.as_java_object(),
+            IteratorItemType::Struct(x) => x.as_java_object(naming),
+            IteratorItemType::Primitive(x) => x.as_java_object(naming),
         }
     }
 }
@@ -136,12 +136,12 @@ impl<D> JavaType for Handle<AbstractIterator<D>>
 where
     D: DocReference,
 {
-    fn as_java_primitive(&self) -> String {
-        self.as_java_object()
+    fn as_java_primitive(&self, naming: &Naming) -> String {
+        self.as_java_object(naming)
     }
 
-    fn as_java_object(&self) -> String {
-        format!("java.util.List<{}>", self.item_type.as_java_object())
+    fn as_java_object(&self, naming: &Naming) -> String {
+        format!("java.util.List<{}>", self.item_type.as_java_object(naming))
     }
 }
 
@@ -150,12 +150,12 @@ where
     D: DocReference,
     T: StructFieldType,
 {
-    fn as_java_primitive(&self) -> String {
-        self.as_java_object()
+    fn as_java_primitive(&self, naming: &Naming) -> String {
+        self.as_java_object(naming)
     }
 
-    fn as_java_object(&self) -> String {
-        self.name().camel_case()
+    fn as_java_object(&self, naming: &Naming) -> String {
+        self.name().camel_case(naming)
     }
 }
 
@@ -163,190 +163,190 @@ impl<T> JavaType for UniversalOr<T>
 where
     T: StructFieldType,
 {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            UniversalOr::Specific(x) => x.as_java_primitive(),
-            UniversalOr::Universal(x) => x.as_java_primitive(),
+            UniversalOr::Specific(x) => x.as_java_primitive(naming),
+            UniversalOr::Universal(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            UniversalOr::Specific(x) => x.as_java_object(),
-            UniversalOr::Universal(x) => x.as_java_object(),
+            UniversalOr::Specific(x) => x.as_java_object(naming),
+            UniversalOr::Universal(x) => x.as_java_object(naming),
         }
     }
 }
 
 impl JavaType for FunctionArgStructField {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgStructField::Basic(x) => x.as_java_primitive(),
-            FunctionArgStructField::String(x) => x.as_java_primitive(),
-            FunctionArgStructField::Interface(x) => x.inner.as_java_primitive(),
-            FunctionArgStructField::Struct(x) => x.as_java_primitive(),
+            FunctionArgStructField::Basic(x) => x.as_java_primitive(naming),
+            FunctionArgStructField::String(x) => x.as_java_primitive(naming),
+            FunctionArgStructField::Interface(x) => x.inner.as_java_primitive(naming),
+            FunctionArgStructField::Struct(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            FunctionArgStructField::Basic(x) => x.as_java_object(),
-            FunctionArgStructField::String(x) => x.as_java_object(),
-            FunctionArgStructField::Interface(x) => x.inner.as_java_object(),
-            FunctionArgStructField::Struct(x) => x.as_java_object(),
+            FunctionArgStructField::Basic(x) => x.as_java_object(naming),
+            FunctionArgStructField::String(x) => x.as_java_object(naming),
+            FunctionArgStructField::Interface(x) => x.inner.as_java_object(naming),
+            FunctionArgStructField::Struct(x) => x.as_java_object(naming),
         }
     }
 }
 
 impl JavaType for FunctionReturnStructField {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            FunctionReturnStructField::Basic(x) => x.as_java_primitive(),
-            FunctionReturnStructField::ClassRef(x) => x.as_java_primitive(),
-            FunctionReturnStructField::Struct(x) => x.as_java_primitive(),
-            FunctionReturnStructField::Iterator(x) => x.as_java_primitive(),
+            FunctionReturnStructField::Basic(x) => x.as_java_primitive(naming),
+            FunctionReturnStructField::ClassRef(x) => x.as_java_primitive(naming),
+            FunctionReturnStructField::Struct(x) => x.as_java_primitive(naming),
+            FunctionReturnStructField::Iterator(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            FunctionReturnStructField::Basic(x) => x.as_java_object(),
-            FunctionReturnStructField::ClassRef(x) => x.as_java_object(),
-            FunctionReturnStructField::Struct(x) => x.as_java_object(),
-            FunctionReturnStructField::Iterator(x) => x.as_java_object(),
+            FunctionReturnStructField::Basic(x) => x.as_java_object(naming),
+            FunctionReturnStructField::ClassRef(x) => x.as_java_object(naming),
+            FunctionReturnStructField::Struct(x) => x.as_java_object(naming),
+            FunctionReturnStructField::Iterator(x) => x.as_java_object(naming),
         }
     }
 }
 
 impl JavaType for CallbackArgStructField {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            CallbackArgStructField::Basic(x) => x.as_java_primitive(),
-            CallbackArgStructField::Iterator(x) => x.as_java_primitive(),
-            CallbackArgStructField::Struct(x) => x.as_java_primitive(),
+            CallbackArgStructField::Basic(x) => x.as_java_primitive(naming),
+            CallbackArgStructField::Iterator(x) => x.as_java_primitive(naming),
+            CallbackArgStructField::Struct(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            CallbackArgStructField::Basic(x) => x.as_java_object(),
-            CallbackArgStructField::Iterator(x) => x.as_java_object(),
-            CallbackArgStructField::Struct(x) => x.as_java_object(),
+            CallbackArgStructField::Basic(x) => x.as_java_object(naming),
+            CallbackArgStructField::Iterator(x) => x.as_java_object(naming),
+            CallbackArgStructField::Struct(x) => x.as_java_object(naming),
         }
     }
 }
 
 impl JavaType for UniversalStructField {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            UniversalStructField::Basic(x) => x.as_java_primitive(),
-            UniversalStructField::Struct(x) => x.as_java_primitive(),
+            UniversalStructField::Basic(x) => x.as_java_primitive(naming),
+            UniversalStructField::Struct(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            UniversalStructField::Basic(x) => x.as_java_object(),
-            UniversalStructField::Struct(x) => x.as_java_object(),
+            UniversalStructField::Basic(x) => x.as_java_object(naming),
+            UniversalStructField::Struct(x) => x.as_java_object(naming),
         }
     }
 }
 
 impl JavaType for FunctionArgument {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_primitive(),
-            Self::String(x) => x.as_java_primitive(),
-            Self::Collection(x) => x.as_java_primitive(),
-            Self::Struct(x) => x.as_java_primitive(),
-            Self::StructRef(x) => x.inner.as_java_primitive(),
-            Self::ClassRef(x) => x.as_java_primitive(),
-            Self::Interface(x) => x.as_java_primitive(),
+            Self::Basic(x) => x.as_java_primitive(naming),
+            Self::String(x) => x.as_java_primitive(naming),
+            Self::Collection(x) => x.as_java_primitive(naming),
+            Self::Struct(x) => x.as_java_primitive(naming),
+            Self::StructRef(x) => x.inner.as_java_primitive(naming),
+            Self::ClassRef(x) => x.as_java_primitive(naming),
+            Self::Interface(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_object(),
-            Self::String(x) => x.as_java_object(),
-            Self::Collection(x) => x.as_java_object(),
-            Self::Struct(x) => x.as_java_object(),
-            Self::StructRef(x) => x.inner.as_java_object(),
-            Self::ClassRef(x) => x.as_java_object(),
-            Self::Interface(x) => x.as_java_object(),
+            Self::Basic(x) => x.as_java_object(naming),
+            Self::String(x) => x.as_java_object(naming),
+            Self::Collection(x) => x.as_java_object(naming),
+            Self::Struct(x) => x.as_java_object(naming),
+            Self::StructRef(x) => x.inner.as_java_object(naming),
+            Self::ClassRef(x) => x.as_java_object(naming),
+            Self::Interface(x) => x.as_java_object(naming),
         }
     }
 }
 
 impl JavaType for CallbackArgument {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_primitive(),
-            Self::String(x) => x.as_java_primitive(),
-            Self::Iterator(x) => x.as_java_primitive(),
-            Self::Struct(x) => x.as_java_primitive(),
-            Self::Class(x) => x.as_java_primitive(),
+            Self::Basic(x) => x.as_java_primitive(naming),
+            Self::String(x) => x.as_java_primitive(naming),
+            Self::Iterator(x) => x.as_java_primitive(naming),
+            Self::Struct(x) => x.as_java_primitive(naming),
+            Self::Class(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_object(),
-            Self::String(x) => x.as_java_object(),
-            Self::Iterator(x) => x.as_java_object(),
-            Self::Struct(x) => x.as_java_object(),
-            Self::Class(x) => x.as_java_object(),
+            Self::Basic(x) => x.as_java_object(naming),
+            Self::String(x) => x.as_java_object(naming),
+            Self::Iterator(x) => x.as_java_object(naming),
+            Self::Struct(x) => x.as_java_object(naming),
+            Self::Class(x) => x.as_java_object(naming),
         }
     }
 }
 
 impl JavaType for CallbackReturnValue {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_primitive(),
-            Self::Struct(x) => x.as_java_primitive(),
+            Self::Basic(x) => x.as_java_primitive(naming),
+            Self::Struct(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_object(),
-            Self::Struct(x) => x.as_java_object(),
+            Self::Basic(x) => x.as_java_object(naming),
+            Self::Struct(x) => x.as_java_object(naming),
         }
     }
 }
 
 /// we always use the Boxed form since it's an optional (reference) of a primitive
 impl JavaType for PrimitiveRef {
-    fn as_java_primitive(&self) -> String {
-        self.inner.as_java_object()
+    fn as_java_primitive(&self, naming: &Naming) -> String {
+        self.inner.as_java_object(naming)
     }
 
-    fn as_java_object(&self) -> String {
-        self.inner.as_java_object()
+    fn as_java_object(&self, naming: &Naming) -> String {
+        self.inner.as_java_object(naming)
     }
 }
 
 impl JavaType for FunctionReturnValue {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_primitive(),
-            Self::String(x) => x.as_java_primitive(),
-            Self::ClassRef(x) => x.as_java_primitive(),
-            Self::Struct(x) => x.as_java_primitive(),
-            Self::StructRef(x) => x.untyped().as_java_primitive(),
-            Self::PrimitiveRef(x) => x.as_java_primitive(),
+            Self::Basic(x) => x.as_java_primitive(naming),
+            Self::String(x) => x.as_java_primitive(naming),
+            Self::ClassRef(x) => x.as_java_primitive(naming),
+            Self::Struct(x) => x.as_java_primitive(naming),
+            Self::StructRef(x) => x.untyped().as_java_primitive(naming),
+            Self::PrimitiveRef(x) => x.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self {
-            Self::Basic(x) => x.as_java_object(),
-            Self::String(x) => x.as_java_object(),
-            Self::ClassRef(x) => x.as_java_object(),
-            Self::Struct(x) => x.as_java_object(),
-            Self::StructRef(x) => x.untyped().as_java_object(),
-            Self::PrimitiveRef(x) => x.as_java_object(),
+            Self::Basic(x) => x.as_java_object(naming),
+            Self::String(x) => x.as_java_object(naming),
+            Self::ClassRef(x) => x.as_java_object(naming),
+            Self::Struct(x) => x.as_java_object(naming),
+            Self::StructRef(x) => x.untyped().as_java_object(naming),
+            Self::PrimitiveRef(x) => x.as_java_object(naming),
         }
     }
 }
@@ -357,17 +357,17 @@ impl<T> JavaType for OptionalReturnType<T, Validated>
 where
     T: Clone + JavaType,
 {
-    fn as_java_primitive(&self) -> String {
+    fn as_java_primitive(&self, naming: &Naming) -> String {
         match self.get_value() {
             None => VOID.to_string(),
-            Some(v) => v.as_java_primitive(),
+            Some(v) => v.as_java_primitive(naming),
         }
     }
 
-    fn as_java_object(&self) -> String {
+    fn as_java_object(&self, naming: &Naming) -> String {
         match self.get_value() {
             None => VOID.to_string(),
-            Some(v) => v.as_java_object(),
+            Some(v) => v.as_java_object(naming),
         }
     }
 }
@@ -386,7 +386,7 @@ pub(crate) fn call_native_function(
             if first_param_is_this && idx == 0 {
                 "this".to_string()
             } else {
-                param.name.mixed_case()
+                param.name.mixed_case(f.naming())
             }
         })
         .collect::<Vec<String>>()
@@ -7,26 +7,78 @@ pub(crate) fn javadoc_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> Format
     f.newline()?;
     docstring_print(f, &doc.brief)?;
 
-    for detail in &doc.details {
+    for detail in doc
+        .details
+        .iter()
+        .filter(|x| x.is_printed_in(Some(ExampleLanguage::Java)))
+    {
         f.newline()?;
 
         match detail {
             DocParagraph::Details(docstring) => {
                 f.writeln("<p>")?;
                 docstring_print(f, docstring)?;
-                f.write("</p>")?;
+                end_paragraph(f, docstring)?;
             }
             DocParagraph::Warning(docstring) => {
                 f.writeln("<p><b>Warning:</b> ")?;
                 docstring_print(f, docstring)?;
-                f.write("</p>")?;
+                end_paragraph(f, docstring)?;
+            }
+            DocParagraph::Example(example) => {
+                let code = example.get(ExampleLanguage::Java).unwrap_or_default();
+                f.writeln("<pre>{@code")?;
+                for line in code.lines() {
+                    f.writeln(line)?;
+                }
+                f.writeln("}</pre>")?;
             }
         }
     }
 
+    if let Some(deprecated) = &doc.deprecated {
+        f.newline()?;
+        f.writeln(&format!("@deprecated Since {}: ", deprecated.since))?;
+        docstring_print(f, &deprecated.note)?;
+    }
+
+    if let Some(since) = &doc.since {
+        f.newline()?;
+        f.writeln(&format!("@since {}", since))?;
+    }
+
+    Ok(())
+}
+
+/// A list implicitly closes the paragraph, doclint rejects the end tag after it
+fn end_paragraph(f: &mut dyn Printer, docstring: &DocString<Validated>) -> FormattingResult<()> {
+    if !docstring
+        .elements()
+        .any(|x| matches!(x, DocStringElement::List(_)))
+    {
+        f.write("</p>")?;
+    }
     Ok(())
 }
 
+/// Annotation matching the `@deprecated` tag printed by [javadoc_print] so that the compiler warns the users
+pub(crate) fn deprecated_annotation(
+    f: &mut dyn Printer,
+    doc: &Doc<Validated>,
+) -> FormattingResult<()> {
+    if doc.deprecated.is_some() {
+        f.writeln("@Deprecated")?;
+    }
+    Ok(())
+}
+
+/// Escape the characters of a URL that would end a Javadoc HTML attribute or start an entity
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
 pub(crate) fn docstring_print(
     f: &mut dyn Printer,
     docstring: &DocString<Validated>,
@@ -37,6 +89,20 @@ pub(crate) fn docstring_print(
             DocStringElement::Null => f.write("{@code null}")?,
             DocStringElement::Iterator => f.write("collection")?,
             DocStringElement::Reference(reference) => reference_print(f, reference)?,
+            DocStringElement::Code(code) => f.write(&format!("{{@code {}}}", code))?,
+            DocStringElement::Bold(text) => f.write(&format!("<b>{}</b>", text))?,
+            DocStringElement::Link { text, url } => {
+                f.write(&format!("<a href=\"{}\">{}</a>", html_escape(url), text))?
+            }
+            DocStringElement::List(items) => {
+                f.writeln("<ul>")?;
+                for item in items {
+                    f.writeln("<li>")?;
+                    docstring_print(f, item)?;
+                    f.write("</li>")?;
+                }
+                f.writeln("</ul>")?;
+            }
         }
     }
 
@@ -46,16 +112,16 @@ pub(crate) fn docstring_print(
 fn reference_print(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
     match reference {
         Validated::Argument(param_name) => {
-            f.write(&format!("{{@code {}}}", param_name.mixed_case()))?
+            f.write(&format!("{{@code {}}}", param_name.mixed_case(f.naming())))?
         }
         Validated::Class(class) => {
-            f.write(&format!("{{@link {}}}", class.name.camel_case()))?;
+            f.write(&format!("{{@link {}}}", class.name.camel_case(f.naming())))?;
         }
         Validated::ClassMethod(class, method_name, _) => {
             f.write(&format!(
                 "{{@link {}#{}}}",
-                class.name().camel_case(),
-                method_name.mixed_case()
+                class.name().camel_case(f.naming()),
+                method_name.mixed_case(f.naming())
             ))?;
         }
         Validated::ClassConstructor(class, constructor) => {
@@ -63,11 +129,11 @@ fn reference_print(f: &mut dyn Printer, reference: &Validated) -> FormattingResu
                 .function
                 .arguments
                 .iter()
-                .map(|param| param.arg_type.as_java_primitive())
+                .map(|param| param.arg_type.as_java_primitive(f.naming()))
                 .collect::<Vec<_>>()
                 .join(", ");
 
-            let class_name = class.name().camel_case();
+            let class_name = class.name().camel_case(f.naming());
             f.write(&format!(
                 "{{@link {}#{}({})}}",
                 class_name, class_name, params
@@ -75,45 +141,48 @@ fn reference_print(f: &mut dyn Printer, reference: &Validated) -> FormattingResu
         }
         Validated::ClassDestructor(class, _) => {
             let method_name = if let DestructionMode::Custom(name) = &class.destruction_mode {
-                name.mixed_case()
+                name.mixed_case(f.naming())
             } else {
                 "close".to_string()
             };
 
             f.write(&format!(
                 "{{@link {}#{}}}",
-                class.name().camel_case(),
+                class.name().camel_case(f.naming()),
                 method_name
             ))?;
         }
         Validated::Struct(st) => {
-            f.write(&format!("{{@link {}}}", st.name().camel_case()))?;
+            f.write(&format!("{{@link {}}}", st.name().camel_case(f.naming())))?;
         }
         Validated::StructField(st, field_name) => {
             f.write(&format!(
                 "{{@link {}#{}}}",
-                st.name().camel_case(),
-                field_name.mixed_case()
+                st.name().camel_case(f.naming()),
+                field_name.mixed_case(f.naming())
             ))?;
         }
         Validated::Enum(handle) => {
-            f.write(&format!("{{@link {}}}", handle.name.camel_case()))?;
+            f.write(&format!("{{@link {}}}", handle.name.camel_case(f.naming())))?;
         }
         Validated::EnumVariant(handle, variant_name) => {
             f.write(&format!(
                 "{{@link {}#{}}}",
-                handle.name.camel_case(),
+                handle.name.camel_case(f.naming()),
                 variant_name.capital_snake_case()
             ))?;
         }
         Validated::Interface(interface) => {
-            f.write(&format!("{{@link {}}}", interface.name.camel_case()))?;
+            f.write(&format!(
+                "{{@link {}}}",
+                interface.name.camel_case(f.naming())
+            ))?;
         }
         Validated::InterfaceMethod(interface, callback_name) => {
             f.write(&format!(
                 "{{@link {}#{}}}",
-                interface.name.camel_case(),
-                callback_name.mixed_case()
+                interface.name.camel_case(f.naming()),
+                callback_name.mixed_case(f.naming())
             ))?;
         }
     }
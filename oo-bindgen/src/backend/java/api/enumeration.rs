@@ -5,7 +5,7 @@ pub(crate) fn generate(
     f: &mut impl Printer,
     native_enum: &Handle<Enum<Validated>>,
 ) -> FormattingResult<()> {
-    let enum_name = native_enum.name.camel_case();
+    let enum_name = native_enum.name.camel_case(f.naming());
 
     // Documentation
     documentation(f, |f| javadoc_print(f, &native_enum.doc))?;
@@ -16,6 +16,7 @@ pub(crate) fn generate(
         // Write the variants
         for variant in &native_enum.variants {
             documentation(f, |f| javadoc_print(f, &variant.doc))?;
+            deprecated_annotation(f, &variant.doc)?;
             f.writeln(&format!(
                 "{}({}),",
                 variant.name.capital_snake_case(),
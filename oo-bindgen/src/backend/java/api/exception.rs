@@ -5,8 +5,8 @@ pub(crate) fn generate(f: &mut impl Printer, error: &ErrorType<Validated>) -> Fo
     // Documentation
     documentation(f, |f| javadoc_print(f, &error.inner.doc))?;
 
-    let exception_name = error.exception_name.camel_case();
-    let error_name = error.inner.name.camel_case();
+    let exception_name = error.exception_name.camel_case(f.naming());
+    let error_name = error.inner.name.camel_case(f.naming());
 
     let exception_type = match error.exception_type {
         ExceptionType::CheckedException => "Exception",
@@ -22,7 +22,12 @@ pub(crate) fn generate(f: &mut impl Printer, error: &ErrorType<Validated>) -> Fo
         documentation(f, |f| f.writeln("Error detail"))?;
         f.writeln(&format!("public final {} error;", error_name))?;
         f.newline()?;
-        f.writeln(&format!("{}({} error)", exception_name, error_name))?;
+        f.writeln(&format!(
+            "{}{}({} error)",
+            package_visibility(&error.inner.module),
+            exception_name,
+            error_name
+        ))?;
         blocked(f, |f| {
             f.writeln("super(error.toString());")?;
             f.writeln("this.error = error;")
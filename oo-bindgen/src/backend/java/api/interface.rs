@@ -2,24 +2,24 @@ use super::doc::*;
 use super::*;
 
 trait ToConstantValue {
-    fn get_constant_value(&self) -> String;
+    fn get_constant_value(&self, naming: &Naming) -> String;
 }
 
-trait PossiblyVoidReturnValue {
-    fn get_optional_constant_value(&self) -> Option<String>;
+pub(crate) trait PossiblyVoidReturnValue {
+    fn get_optional_constant_value(&self, naming: &Naming) -> Option<String>;
 }
 
 impl<T> PossiblyVoidReturnValue for T
 where
     T: ToConstantValue,
 {
-    fn get_optional_constant_value(&self) -> Option<String> {
-        Some(self.get_constant_value())
+    fn get_optional_constant_value(&self, naming: &Naming) -> Option<String> {
+        Some(self.get_constant_value(naming))
     }
 }
 
 impl ToConstantValue for PrimitiveValue {
-    fn get_constant_value(&self) -> String {
+    fn get_constant_value(&self, _naming: &Naming) -> String {
         match self {
             PrimitiveValue::Bool(x) => x.to_string(),
             PrimitiveValue::U8(x) => format!("UByte.valueOf({})", x),
@@ -37,7 +37,7 @@ impl ToConstantValue for PrimitiveValue {
 }
 
 impl ToConstantValue for DurationValue {
-    fn get_constant_value(&self) -> String {
+    fn get_constant_value(&self, _naming: &Naming) -> String {
         match self {
             DurationValue::Milliseconds(x) => format!("java.time.Duration.ofMillis({})", x),
             DurationValue::Seconds(x) => format!("java.time.Duration.ofSeconds({})", x),
@@ -46,14 +46,14 @@ impl ToConstantValue for DurationValue {
 }
 
 impl ToConstantValue for BasicValue {
-    fn get_constant_value(&self) -> String {
+    fn get_constant_value(&self, naming: &Naming) -> String {
         match self {
-            BasicValue::Primitive(x) => x.get_constant_value(),
-            BasicValue::Duration(x) => x.get_constant_value(),
+            BasicValue::Primitive(x) => x.get_constant_value(naming),
+            BasicValue::Duration(x) => x.get_constant_value(naming),
             BasicValue::Enum(x) => {
                 format!(
                     "{}.{}",
-                    x.handle.name.camel_case(),
+                    x.handle.name.camel_case(naming),
                     x.variant.name.capital_snake_case()
                 )
             }
@@ -62,24 +62,26 @@ impl ToConstantValue for BasicValue {
 }
 
 impl ToConstantValue for ZeroParameterStructInitializer {
-    fn get_constant_value(&self) -> String {
+    fn get_constant_value(&self, naming: &Naming) -> String {
         match self.initializer.initializer_type {
-            InitializerType::Normal => format!("new {}()", self.handle.name().camel_case()),
+            InitializerType::Normal => format!("new {}()", self.handle.name().camel_case(naming)),
             InitializerType::Static => format!(
                 "{}.{}()",
-                self.handle.name().camel_case(),
-                self.initializer.name.mixed_case()
+                self.handle.name().camel_case(naming),
+                self.initializer.name.mixed_case(naming)
             ),
         }
     }
 }
 
 impl PossiblyVoidReturnValue for DefaultCallbackReturnValue {
-    fn get_optional_constant_value(&self) -> Option<String> {
+    fn get_optional_constant_value(&self, naming: &Naming) -> Option<String> {
         match self {
             DefaultCallbackReturnValue::Void => None,
-            DefaultCallbackReturnValue::Basic(x) => x.get_optional_constant_value(),
-            DefaultCallbackReturnValue::InitializedStruct(x) => x.get_optional_constant_value(),
+            DefaultCallbackReturnValue::Basic(x) => x.get_optional_constant_value(naming),
+            DefaultCallbackReturnValue::InitializedStruct(x) => {
+                x.get_optional_constant_value(naming)
+            }
         }
     }
 }
@@ -88,7 +90,7 @@ pub(crate) fn generate(
     f: &mut dyn Printer,
     interface: &Handle<Interface<Validated>>,
 ) -> FormattingResult<()> {
-    let interface_name = interface.name.camel_case();
+    let interface_name = interface.name.camel_case(f.naming());
 
     documentation(f, |f| {
         // Print top-level documentation
@@ -105,7 +107,7 @@ pub(crate) fn generate(
             let constant_return_value = func
                 .default_implementation
                 .as_ref()
-                .map(|x| x.get_optional_constant_value());
+                .map(|x| x.get_optional_constant_value(f.naming()));
 
             // Documentation
             documentation(f, |f| {
@@ -133,7 +135,7 @@ pub(crate) fn generate(
 
                 // Print each argument value
                 for arg in &func.arguments {
-                    f.writeln(&format!("@param {} ", arg.name.mixed_case()))?;
+                    f.writeln(&format!("@param {} ", arg.name.mixed_case(f.naming())))?;
                     docstring_print(f, &arg.doc)?;
                 }
 
@@ -145,6 +147,7 @@ pub(crate) fn generate(
 
                 Ok(())
             })?;
+            deprecated_annotation(f, &func.doc)?;
 
             let modifier = if func.default_implementation.is_some() {
                 "default "
@@ -156,8 +159,8 @@ pub(crate) fn generate(
             f.writeln(&format!(
                 "{}{} {}(",
                 modifier,
-                func.return_type.as_java_primitive(),
-                func.name.mixed_case()
+                func.return_type.as_java_primitive(f.naming()),
+                func.name.mixed_case(f.naming())
             ))?;
             f.write(
                 &func
@@ -166,8 +169,8 @@ pub(crate) fn generate(
                     .map(|arg| {
                         format!(
                             "{} {}",
-                            arg.arg_type.as_java_primitive(),
-                            arg.name.mixed_case()
+                            arg.arg_type.as_java_primitive(f.naming()),
+                            arg.name.mixed_case(f.naming())
                         )
                     })
                     .collect::<Vec<String>>()
@@ -0,0 +1,164 @@
+use crate::model::*;
+
+/// Kotlin spelling of the types in the Java API
+///
+/// The unsigned wrappers are fully qualified so they don't collide with Kotlin's own unsigned types
+pub(crate) trait KotlinType {
+    fn as_kotlin_type(&self, naming: &Naming) -> String;
+}
+
+impl KotlinType for Primitive {
+    fn as_kotlin_type(&self, _naming: &Naming) -> String {
+        match self {
+            Self::Bool => "Boolean".to_string(),
+            Self::U8 => "org.joou.UByte".to_string(),
+            Self::S8 => "Byte".to_string(),
+            Self::U16 => "org.joou.UShort".to_string(),
+            Self::S16 => "Short".to_string(),
+            Self::U32 => "org.joou.UInteger".to_string(),
+            Self::S32 => "Int".to_string(),
+            Self::U64 => "org.joou.ULong".to_string(),
+            Self::S64 => "Long".to_string(),
+            Self::Float => "Float".to_string(),
+            Self::Double => "Double".to_string(),
+        }
+    }
+}
+
+impl KotlinType for BasicType {
+    fn as_kotlin_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.as_kotlin_type(naming),
+            Self::Duration(_) => "java.time.Duration".to_string(),
+            Self::Enum(x) => x.name.camel_case(naming),
+        }
+    }
+}
+
+impl KotlinType for IteratorItemType {
+    fn as_kotlin_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Struct(x) => x.name().camel_case(naming),
+            Self::Primitive(x) => x.as_kotlin_type(naming),
+        }
+    }
+}
+
+impl KotlinType for FunctionArgument {
+    fn as_kotlin_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_kotlin_type(naming),
+            Self::String(_) => "String".to_string(),
+            Self::Collection(x) => format!("List<{}>", x.item_type.as_kotlin_type(naming)),
+            Self::Struct(x) => x.name().camel_case(naming),
+            Self::StructRef(x) => format!("{}?", x.inner.name.camel_case(naming)),
+            Self::ClassRef(x) => x.name.camel_case(naming),
+            Self::Interface(x) => x.name.camel_case(naming),
+        }
+    }
+}
+
+impl KotlinType for CallbackArgument {
+    fn as_kotlin_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_kotlin_type(naming),
+            Self::String(_) => "String".to_string(),
+            Self::Iterator(x) => format!("List<{}>", x.item_type.as_kotlin_type(naming)),
+            Self::Struct(x) => x.name().camel_case(naming),
+            Self::Class(x) => x.name.camel_case(naming),
+        }
+    }
+}
+
+impl KotlinType for CallbackReturnValue {
+    fn as_kotlin_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_kotlin_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl<T> KotlinType for OptionalReturnType<T, Validated>
+where
+    T: Clone + KotlinType,
+{
+    fn as_kotlin_type(&self, naming: &Naming) -> String {
+        match self.get_value() {
+            None => "Unit".to_string(),
+            Some(x) => x.as_kotlin_type(naming),
+        }
+    }
+}
+
+/// Type and conversions of a field in the data class mirror of a universal struct
+pub(crate) trait DataClassField {
+    /// Kotlin type of the field in the data class
+    fn as_data_type(&self, naming: &Naming) -> String;
+    /// Convert the value of the Java field into the data class representation
+    fn to_data(&self, expr: &str) -> String;
+    /// Convert the data class representation back into the value of the Java field
+    fn to_java(&self, expr: &str) -> String;
+}
+
+impl DataClassField for Primitive {
+    fn as_data_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::U8 => "UByte".to_string(),
+            Self::U16 => "UShort".to_string(),
+            Self::U32 => "UInt".to_string(),
+            Self::U64 => "ULong".to_string(),
+            _ => self.as_kotlin_type(naming),
+        }
+    }
+
+    fn to_data(&self, expr: &str) -> String {
+        match self {
+            Self::U8 => format!("{}.toInt().toUByte()", expr),
+            Self::U16 => format!("{}.toInt().toUShort()", expr),
+            Self::U32 => format!("{}.toLong().toUInt()", expr),
+            Self::U64 => format!("{}.toLong().toULong()", expr),
+            _ => expr.to_string(),
+        }
+    }
+
+    fn to_java(&self, expr: &str) -> String {
+        match self {
+            Self::U8 => format!("org.joou.UByte.valueOf({}.toInt())", expr),
+            Self::U16 => format!("org.joou.UShort.valueOf({}.toInt())", expr),
+            Self::U32 => format!("org.joou.UInteger.valueOf({}.toLong())", expr),
+            Self::U64 => format!("org.joou.ULong.valueOf({}.toLong())", expr),
+            _ => expr.to_string(),
+        }
+    }
+}
+
+impl DataClassField for UniversalStructField {
+    fn as_data_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(BasicType::Primitive(x)) => x.as_data_type(naming),
+            Self::Basic(x) => x.as_kotlin_type(naming),
+            Self::Struct(x) => data_class_name(x.name(), naming),
+        }
+    }
+
+    fn to_data(&self, expr: &str) -> String {
+        match self {
+            Self::Basic(BasicType::Primitive(x)) => x.to_data(expr),
+            Self::Basic(_) => expr.to_string(),
+            Self::Struct(_) => format!("{}.toData()", expr),
+        }
+    }
+
+    fn to_java(&self, expr: &str) -> String {
+        match self {
+            Self::Basic(BasicType::Primitive(x)) => x.to_java(expr),
+            Self::Basic(_) => expr.to_string(),
+            Self::Struct(_) => format!("{}.toJava()", expr),
+        }
+    }
+}
+
+pub(crate) fn data_class_name(name: &Name, naming: &Naming) -> String {
+    format!("{}Data", name.camel_case(naming))
+}
@@ -0,0 +1,580 @@
+use super::*;
+
+use self::conversion::*;
+
+mod conversion;
+
+const KOTLIN_VERSION: &str = "2.0.0";
+const COROUTINES_VERSION: &str = "1.8.1";
+
+/// Package of the Java API with the segments that are Kotlin keywords escaped
+fn kotlin_package(package: &str) -> String {
+    package
+        .split('.')
+        .map(|x| TargetLanguage::Kotlin.escape(x.to_string()))
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+pub(crate) fn generate(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::Kotlin);
+    logged::create_dir_all(config.kotlin_source_dir(lib))?;
+
+    generate_coroutines(lib, config, naming)?;
+    generate_functional(lib, config, naming)?;
+    generate_resources(lib, config, naming)?;
+    generate_data_classes(lib, config, naming)?;
+    generate_errors(lib, config, naming)?;
+
+    Ok(())
+}
+
+/// Kotlin properties added to the pom when the Kotlin layer is enabled
+pub(crate) fn write_pom_properties(
+    f: &mut dyn Printer,
+    config: &JavaBindgenConfig,
+) -> FormattingResult<()> {
+    f.writeln(&format!(
+        "    <kotlin.version>{}</kotlin.version>",
+        KOTLIN_VERSION
+    ))?;
+    f.writeln(&format!(
+        "    <kotlin.compiler.jvmTarget>{}</kotlin.compiler.jvmTarget>",
+        config.interop.java_version()
+    ))
+}
+
+/// Kotlin dependencies added to the pom when the Kotlin layer is enabled
+pub(crate) fn write_pom_dependencies(f: &mut dyn Printer) -> FormattingResult<()> {
+    f.writeln("    <dependency>")?;
+    f.writeln("        <groupId>org.jetbrains.kotlin</groupId>")?;
+    f.writeln("        <artifactId>kotlin-stdlib</artifactId>")?;
+    f.writeln("        <version>${kotlin.version}</version>")?;
+    f.writeln("    </dependency>")?;
+    f.writeln("    <dependency>")?;
+    f.writeln("        <groupId>org.jetbrains.kotlinx</groupId>")?;
+    f.writeln("        <artifactId>kotlinx-coroutines-core</artifactId>")?;
+    f.writeln(&format!(
+        "        <version>{}</version>",
+        COROUTINES_VERSION
+    ))?;
+    f.writeln("    </dependency>")
+}
+
+/// Kotlin compiler plugin, the Java sources are passed along so that the Kotlin code can reference them
+pub(crate) fn write_pom_plugin(f: &mut dyn Printer) -> FormattingResult<()> {
+    f.writeln("    <plugin>")?;
+    f.writeln("      <groupId>org.jetbrains.kotlin</groupId>")?;
+    f.writeln("      <artifactId>kotlin-maven-plugin</artifactId>")?;
+    f.writeln("      <version>${kotlin.version}</version>")?;
+    f.writeln("      <executions>")?;
+    f.writeln("        <execution>")?;
+    f.writeln("          <id>compile</id>")?;
+    f.writeln("          <goals>")?;
+    f.writeln("            <goal>compile</goal>")?;
+    f.writeln("          </goals>")?;
+    f.writeln("          <configuration>")?;
+    f.writeln("            <sourceDirs>")?;
+    f.writeln("              <sourceDir>${project.basedir}/src/main/kotlin</sourceDir>")?;
+    f.writeln("              <sourceDir>${project.basedir}/src/main/java</sourceDir>")?;
+    f.writeln("            </sourceDirs>")?;
+    f.writeln("          </configuration>")?;
+    f.writeln("        </execution>")?;
+    f.writeln("      </executions>")?;
+    f.writeln("    </plugin>")
+}
+
+fn create_file(
+    name: &str,
+    config: &JavaBindgenConfig,
+    lib: &Library,
+    naming: &Naming,
+) -> FormattingResult<FilePrinter> {
+    let mut filename = config.kotlin_source_dir(lib);
+    filename.push(name);
+    filename.set_extension("kt");
+    let mut f = FilePrinter::new(filename, naming.clone())?;
+
+    print_license(&mut f, &lib.info.license_description)?;
+    f.writeln(&format!(
+        "package {}",
+        kotlin_package(&java_package(
+            &config.group_id,
+            lib,
+            &Module::root(),
+            naming
+        ))
+    ))?;
+    f.newline()?;
+    if lib.has_modules() {
+        for package in library_packages(&config.group_id, lib, naming)
+            .iter()
+            .skip(1)
+        {
+            f.writeln(&format!("import {}.*", kotlin_package(package)))?;
+        }
+        f.newline()?;
+    }
+
+    Ok(f)
+}
+
+/// Kotlin block with the opening brace on the same line, a newline before it would change the meaning
+fn kotlin_block<F>(f: &mut dyn Printer, header: &str, cb: F) -> FormattingResult<()>
+where
+    F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+{
+    blocked_open_close(f, &format!("{} {{", header), "}", cb)
+}
+
+fn kotlin_params<'a, I>(args: I, naming: &Naming) -> String
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    args.map(|arg| {
+        format!(
+            "{}: {}",
+            arg.name.mixed_case(naming),
+            arg.arg_type.as_kotlin_type(naming)
+        )
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+fn generate_coroutines(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let methods: Vec<(&Handle<Class<Validated>>, &FutureMethod<Validated>)> = lib
+        .classes()
+        .flat_map(|class| class.future_methods.iter().map(move |m| (class, m)))
+        .collect();
+
+    if methods.is_empty() {
+        return Ok(());
+    }
+
+    let mut f = create_file("Coroutines", config, lib, naming)?;
+    f.writeln("import kotlinx.coroutines.future.await")?;
+
+    for (class, method) in methods {
+        let class_name = class.name().camel_case(naming);
+        let method_name = method.name.mixed_case(naming);
+
+        f.newline()?;
+        documentation(&mut f, |f| {
+            f.writeln(&format!(
+                "Suspending version of [{}.{}] that resumes once the operation completes",
+                class_name, method_name
+            ))?;
+            for err in [
+                method.native_function.error_type.get(),
+                method.future.error_type.get(),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                f.newline()?;
+                f.writeln(&format!(
+                    "@throws {} if the operation fails",
+                    err.exception_name.camel_case(naming)
+                ))?;
+            }
+            Ok(())
+        })?;
+
+        let args = method
+            .arguments_without_callback()
+            .map(|arg| arg.name.mixed_case(naming))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        f.writeln(&format!(
+            "suspend fun {}.{}Await({}): {} = {}({}).await()",
+            class_name,
+            method.name.unescaped_mixed_case(naming),
+            kotlin_params(method.arguments_without_callback(), naming),
+            method.future.value_type.as_kotlin_type(naming),
+            method_name,
+            args
+        ))?;
+    }
+
+    Ok(())
+}
+
+fn generate_functional(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let interfaces: Vec<(&Handle<Interface<Validated>>, &CallbackFunction<Validated>)> = lib
+        .untyped_interfaces()
+        .filter_map(|x| x.get_functional_callback().map(|cb| (x, cb)))
+        .filter(|(_, cb)| cb.functional_transform.enabled() && cb.default_implementation.is_none())
+        .collect();
+
+    if interfaces.is_empty() {
+        return Ok(());
+    }
+
+    let mut f = create_file("Functional", config, lib, naming)?;
+
+    for (interface, cb) in interfaces {
+        let interface_name = interface.name.camel_case(naming);
+        let alias = format!("{}Function", interface_name);
+
+        let params = cb
+            .arguments
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    arg.name.mixed_case(naming),
+                    arg.arg_type.as_kotlin_type(naming)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        documentation(&mut f, |f| {
+            f.writeln(&format!(
+                "Kotlin function type with the same signature as [{}.{}]",
+                interface_name,
+                cb.name.mixed_case(naming)
+            ))
+        })?;
+        f.writeln(&format!(
+            "typealias {} = ({}) -> {}",
+            alias,
+            params,
+            cb.return_type.as_kotlin_type(naming)
+        ))?;
+
+        f.newline()?;
+
+        documentation(&mut f, |f| {
+            f.writeln(&format!(
+                "Adapt a Kotlin function to the [{}] interface",
+                interface_name
+            ))
+        })?;
+        f.writeln(&format!(
+            "fun {}.to{}(): {} = {}(this)",
+            alias, interface_name, interface_name, interface_name
+        ))?;
+
+        f.newline()?;
+    }
+
+    Ok(())
+}
+
+fn generate_resources(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    // classes that use Dispose are AutoCloseable and already work with the standard library's use()
+    let classes: Vec<(&Handle<Class<Validated>>, &Name)> = lib
+        .classes()
+        .filter(|class| class.destructor.is_some())
+        .filter_map(|class| match &class.destruction_mode {
+            DestructionMode::Custom(name) => Some((class, name)),
+            _ => None,
+        })
+        .collect();
+
+    if classes.is_empty() {
+        return Ok(());
+    }
+
+    let mut f = create_file("Resources", config, lib, naming)?;
+
+    for (class, destroy) in classes {
+        let class_name = class.name().camel_case(naming);
+
+        documentation(&mut f, |f| {
+            f.writeln(&format!(
+                "Execute [block] with this instance and then call [{}.{}], even if the block throws",
+                class_name,
+                destroy.mixed_case(naming)
+            ))
+        })?;
+        kotlin_block(
+            &mut f,
+            &format!(
+                "inline fun <R> {}.use(block: ({}) -> R): R",
+                class_name, class_name
+            ),
+            |f| {
+                f.writeln("try {")?;
+                indented(f, |f| f.writeln("return block(this)"))?;
+                f.writeln("} finally {")?;
+                indented(f, |f| {
+                    f.writeln(&format!("{}()", destroy.mixed_case(naming)))
+                })?;
+                f.writeln("}")
+            },
+        )?;
+
+        f.newline()?;
+    }
+
+    Ok(())
+}
+
+fn generate_data_classes(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let structs: Vec<&Handle<Struct<UniversalStructField, Validated>>> = lib
+        .structs()
+        .filter_map(|x| match x {
+            StructType::Universal(x) if x.visibility == Visibility::Public => Some(x),
+            _ => None,
+        })
+        .collect();
+
+    if structs.is_empty() {
+        return Ok(());
+    }
+
+    let mut f = create_file("Data", config, lib, naming)?;
+
+    for st in structs {
+        let struct_name = st.name().camel_case(naming);
+        let data_name = data_class_name(st.name(), naming);
+
+        documentation(&mut f, |f| {
+            f.writeln(&format!("Immutable Kotlin mirror of [{}]", struct_name))?;
+            f.newline()?;
+            for field in st.fields() {
+                f.writeln(&format!(
+                    "@property {} see [{}.{}]",
+                    field.name.mixed_case(naming),
+                    struct_name,
+                    field.name.mixed_case(naming)
+                ))?;
+            }
+            Ok(())
+        })?;
+        blocked_open_close(&mut f, &format!("data class {}(", data_name), ")", |f| {
+            for field in st.fields() {
+                f.writeln(&format!(
+                    "val {}: {},",
+                    field.name.mixed_case(naming),
+                    field.field_type.as_data_type(naming)
+                ))?;
+            }
+            Ok(())
+        })?;
+
+        f.newline()?;
+
+        documentation(&mut f, |f| {
+            f.writeln(&format!(
+                "Copy the values of this [{}] into a [{}]",
+                struct_name, data_name
+            ))
+        })?;
+        blocked_open_close(
+            &mut f,
+            &format!(
+                "fun {}.toData(): {} = {}(",
+                struct_name, data_name, data_name
+            ),
+            ")",
+            |f| {
+                for field in st.fields() {
+                    f.writeln(&format!(
+                        "{},",
+                        field.field_type.to_data(&field.name.mixed_case(naming))
+                    ))?;
+                }
+                Ok(())
+            },
+        )?;
+
+        if let Some((construction, modifiers)) = to_java_expression(st, naming) {
+            f.newline()?;
+            documentation(&mut f, |f| {
+                f.writeln(&format!(
+                    "Create a [{}] with the values of this [{}]",
+                    struct_name, data_name
+                ))
+            })?;
+            f.writeln(&format!(
+                "fun {}.toJava(): {} = {}",
+                data_name, struct_name, construction
+            ))?;
+            indented(&mut f, |f| {
+                for modifier in modifiers {
+                    f.writeln(&modifier)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        f.newline()?;
+    }
+
+    Ok(())
+}
+
+/// Expression that builds the Java struct from the data class, split into the initializer call and the `with` calls
+///
+/// The Java struct is built with one of its initializers and the fields the initializer sets
+/// to a default are then overwritten with the `with` methods. Structs without an initializer
+/// can't be created by user code, so they are only ever converted to the data class.
+fn to_java_expression(
+    st: &Handle<Struct<UniversalStructField, Validated>>,
+    naming: &Naming,
+) -> Option<(String, Vec<String>)> {
+    let initializer = st
+        .initializers
+        .iter()
+        .find(|x| x.initializer_type == InitializerType::Normal)
+        .or_else(|| st.initializers.first())?;
+
+    let args = st
+        .initializer_args(initializer.clone())
+        .map(|field| field.field_type.to_java(&field.name.mixed_case(naming)))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let construction = match initializer.initializer_type {
+        InitializerType::Normal => format!("{}({})", st.name().camel_case(naming), args),
+        InitializerType::Static => format!(
+            "{}.{}({})",
+            st.name().camel_case(naming),
+            initializer.name.mixed_case(naming),
+            args
+        ),
+    };
+
+    let modifiers = st
+        .fields()
+        .filter(|field| initializer.values.iter().any(|x| x.name == field.name))
+        .map(|field| {
+            format!(
+                ".with{}({})",
+                field.name.camel_case(naming),
+                field.field_type.to_java(&field.name.mixed_case(naming))
+            )
+        })
+        .collect();
+
+    Some((construction, modifiers))
+}
+
+fn generate_errors(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let errors: Vec<&ErrorType<Validated>> = lib.error_types().collect();
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let mut f = create_file("Errors", config, lib, naming)?;
+
+    for error in errors {
+        let enum_name = error.inner.name.camel_case(naming);
+        let exception_name = error.exception_name.camel_case(naming);
+        let sealed_name = format!("{}Failure", enum_name);
+        // the first variant is always the success value
+        let (ok, failures) = error.inner.variants.split_first().unwrap();
+
+        documentation(&mut f, |f| {
+            f.writeln(&format!(
+                "Sealed hierarchy of the failures reported by [{}]",
+                exception_name
+            ))?;
+            f.newline()?;
+            f.writeln(&format!(
+                "Allows exhaustive `when` expressions over the values of [{}]",
+                enum_name
+            ))?;
+            f.newline()?;
+            f.writeln(&format!("@property error underlying [{}] value", enum_name))
+        })?;
+        kotlin_block(
+            &mut f,
+            &format!("sealed class {}(val error: {})", sealed_name, enum_name),
+            |f| {
+                for variant in failures {
+                    documentation(f, |f| {
+                        f.writeln(&format!(
+                            "Failure for [{}.{}]",
+                            enum_name,
+                            variant.name.capital_snake_case()
+                        ))
+                    })?;
+                    f.writeln(&format!(
+                        "data object {} : {}({}.{})",
+                        variant.name.camel_case(naming),
+                        sealed_name,
+                        enum_name,
+                        variant.name.capital_snake_case()
+                    ))?;
+                    f.newline()?;
+                }
+
+                kotlin_block(f, "companion object", |f| {
+                    documentation(f, |f| {
+                        f.writeln(&format!(
+                            "Map a [{}] to its failure, throws [IllegalArgumentException] for [{}.{}]",
+                            enum_name,
+                            enum_name,
+                            ok.name.capital_snake_case()
+                        ))
+                    })?;
+                    kotlin_block(
+                        f,
+                        &format!(
+                            "fun of(error: {}): {} = when (error)",
+                            enum_name, sealed_name
+                        ),
+                        |f| {
+                            f.writeln(&format!(
+                                "{}.{} -> throw IllegalArgumentException(\"{} is not a failure\")",
+                                enum_name,
+                                ok.name.capital_snake_case(),
+                                ok.name.capital_snake_case()
+                            ))?;
+                            for variant in failures {
+                                f.writeln(&format!(
+                                    "{}.{} -> {}",
+                                    enum_name,
+                                    variant.name.capital_snake_case(),
+                                    variant.name.camel_case(naming)
+                                ))?;
+                            }
+                            Ok(())
+                        },
+                    )
+                })
+            },
+        )?;
+
+        f.newline()?;
+
+        documentation(&mut f, |f| {
+            f.writeln(&format!("[{}] carried by this exception", sealed_name))
+        })?;
+        f.writeln(&format!("val {}.failure: {}", exception_name, sealed_name))?;
+        indented(&mut f, |f| {
+            f.writeln(&format!("get() = {}.of(error)", sealed_name))
+        })?;
+
+        f.newline()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use std::path::Path;
+
+use heck::MixedCase;
+
+use crate::backend::rust::mock::{code, ControlFunction, ControlType, CONTROL_API};
+use crate::backend::*;
+use crate::model::*;
+
+/// Write the `Mock` class controlling the mock implementation loaded in place of the library
+///
+/// The class calls the mock through the Foreign Function and Memory API. It loads the library
+/// from the path the Panama bindings read, so it only applies to these bindings.
+pub(crate) fn generate(lib: &Library, group_id: &str, path: &Path) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::Java))?;
+    let location = format!(
+        "{}_NATIVE_LIB_LOCATION",
+        lib.settings.name.capital_snake_case()
+    );
+
+    f.writeln(&format!(
+        "// Control API of the mock implementation of the {} library generated by oo-bindgen",
+        lib.settings.name
+    ))?;
+    f.writeln(&format!("package {}.{}_test;", group_id, lib.settings.name))?;
+    f.newline()?;
+    f.writeln("import org.joou.*;")?;
+    f.newline()?;
+    f.writeln("import java.lang.foreign.*;")?;
+    f.writeln("import java.lang.invoke.MethodHandle;")?;
+    f.newline()?;
+    f.writeln("/**")?;
+    f.writeln(" * Control API of the mock implementation, loaded in place of the native library")?;
+    f.writeln(" *")?;
+    f.writeln(&format!(
+        " * <p>The mock is found at the path in {{@code {}}}, like the Panama bindings do.",
+        location
+    ))?;
+    f.writeln(" */")?;
+    f.writeln("public class Mock")?;
+    blocked(&mut f, |f| {
+        f.writeln(&format!("private static final SymbolLookup LIBRARY = SymbolLookup.libraryLookup(java.nio.file.Path.of(System.getenv(\"{}\")), Arena.global());", location))?;
+        f.newline()?;
+        for function in CONTROL_API.iter() {
+            f.writeln(&format!(
+                "private static final MethodHandle {} = downcall(\"{}_mock_{}\", {});",
+                function.name,
+                lib.settings.c_ffi_prefix,
+                function.name,
+                descriptor(function)
+            ))?;
+        }
+        f.newline()?;
+        f.writeln("private Mock() {}")?;
+        for function in CONTROL_API.iter() {
+            f.newline()?;
+            write_method(f, function)?;
+        }
+        f.newline()?;
+        f.writeln(
+            "private static MethodHandle downcall(String name, FunctionDescriptor descriptor)",
+        )?;
+        blocked(f, |f| {
+            f.writeln("MemorySegment symbol = LIBRARY.find(name).orElseThrow(() -> new UnsatisfiedLinkError(\"Unable to find native symbol: \" + name));")?;
+            f.writeln("return Linker.nativeLinker().downcallHandle(symbol, descriptor);")
+        })?;
+        f.newline()?;
+        f.writeln("private static String fromNative(MemorySegment value)")?;
+        blocked(f, |f| {
+            f.writeln("return value.equals(MemorySegment.NULL) ? null : value.reinterpret(Long.MAX_VALUE).getString(0);")
+        })
+    })
+}
+
+fn descriptor(function: &ControlFunction) -> String {
+    let mut params = Vec::new();
+    for (_, param_type, _) in function.params {
+        match param_type {
+            ControlType::U32 => params.push("ValueLayout.JAVA_INT"),
+            ControlType::String => params.push("ValueLayout.ADDRESS"),
+            ControlType::Strings => {
+                params.push("ValueLayout.ADDRESS");
+                params.push("ValueLayout.JAVA_INT");
+            }
+        }
+    }
+    match function.returns {
+        None => format!("FunctionDescriptor.ofVoid({})", params.join(", ")),
+        Some((ControlType::U32, _)) => format!(
+            "FunctionDescriptor.of(ValueLayout.JAVA_INT{})",
+            params
+                .iter()
+                .map(|x| format!(", {}", x))
+                .collect::<String>()
+        ),
+        Some(_) => format!(
+            "FunctionDescriptor.of(ValueLayout.ADDRESS{})",
+            params
+                .iter()
+                .map(|x| format!(", {}", x))
+                .collect::<String>()
+        ),
+    }
+}
+
+fn write_method(f: &mut dyn Printer, function: &ControlFunction) -> FormattingResult<()> {
+    f.writeln("/**")?;
+    f.writeln(&format!(" * {}", code(function.doc, "{@code ", "}")))?;
+    if !function.params.is_empty() || function.returns.is_some() {
+        f.writeln(" *")?;
+    }
+    for (name, _, doc) in function.params {
+        f.writeln(&format!(" * @param {} {}", name, doc))?;
+    }
+    if let Some((_, doc)) = function.returns {
+        f.writeln(&format!(" * @return {}", doc))?;
+    }
+    f.writeln(" */")?;
+
+    let params: Vec<String> = function
+        .params
+        .iter()
+        .map(|(name, param_type, _)| match param_type {
+            ControlType::U32 => format!("UInteger {}", name),
+            ControlType::String => format!("String {}", name),
+            ControlType::Strings => format!("String... {}", name),
+        })
+        .collect();
+    let return_type = match function.returns {
+        None => "void",
+        Some((ControlType::U32, _)) => "UInteger",
+        Some(_) => "String",
+    };
+    f.writeln(&format!(
+        "public static {} {}({})",
+        return_type,
+        function.name.to_mixed_case(),
+        params.join(", ")
+    ))?;
+    blocked(f, |f| {
+        f.writeln("try (Arena _arena = Arena.ofConfined())")?;
+        blocked(f, |f| {
+            let mut args = Vec::new();
+            for (name, param_type, _) in function.params {
+                match param_type {
+                    ControlType::U32 => args.push(format!("{}.intValue()", name)),
+                    ControlType::String => args.push(format!("_arena.allocateFrom({})", name)),
+                    ControlType::Strings => {
+                        f.writeln(&format!(
+                            "MemorySegment _{} = _arena.allocate(ValueLayout.ADDRESS, Math.max({}.length, 1));",
+                            name, name
+                        ))?;
+                        f.writeln(&format!("for (int i = 0; i < {}.length; ++i)", name))?;
+                        blocked(f, |f| {
+                            f.writeln(&format!(
+                                "_{}.setAtIndex(ValueLayout.ADDRESS, i, _arena.allocateFrom({}[i]));",
+                                name, name
+                            ))
+                        })?;
+                        args.push(format!("_{}", name));
+                        args.push(format!("{}.length", name));
+                    }
+                }
+            }
+            let call = format!("{}.invoke({})", function.name, args.join(", "));
+            match function.returns {
+                None => f.writeln(&format!("{};", call)),
+                Some((ControlType::U32, _)) => f.writeln(&format!(
+                    "return UInteger.valueOf(Integer.toUnsignedLong((int) {}));",
+                    call
+                )),
+                Some(_) => f.writeln(&format!("return fromNative((MemorySegment) {});", call)),
+            }
+        })?;
+        f.writeln("catch (Throwable _t)")?;
+        blocked(f, |f| {
+            f.writeln("throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);")
+        })
+    })
+}
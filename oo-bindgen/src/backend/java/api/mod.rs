@@ -4,22 +4,71 @@ use std::path::PathBuf;
 
 use crate::backend::java::api::nullable::{IsStruct, Nullable};
 
+use self::constraint::*;
 use self::conversion::*;
 use self::formatting::*;
 
 mod class;
+pub(crate) mod conformance;
 mod constant;
-mod conversion;
+mod constraint;
+pub(crate) mod conversion;
 mod doc;
 mod enumeration;
 mod exception;
 mod formatting;
 mod interface;
+mod kotlin;
+pub(crate) mod mock;
 mod nullable;
+mod panama;
 mod structure;
 
 const NATIVE_FUNCTIONS_CLASSNAME: &str = "NativeFunctions";
 
+/// Mechanism used by the generated Java code to call the native library
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum JavaInterop {
+    /// JNI - Requires a companion JNI shared library, compatible with Java 8
+    Jni,
+    /// Foreign Function & Memory API - Pure Java bindings to the C library, requires JDK 22+
+    Panama,
+}
+
+impl std::fmt::Display for JavaInterop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl JavaInterop {
+    /// Java visibility of the members that the native glue needs to read or invoke
+    ///
+    /// JNI bypasses access checks, but the Panama glue is plain Java code in the root package
+    pub(crate) fn internal_visibility(&self, module: &Module) -> &'static str {
+        match self {
+            JavaInterop::Jni => "private ",
+            JavaInterop::Panama => package_visibility(module),
+        }
+    }
+
+    /// Minimum Java version required by the generated code
+    fn java_version(&self) -> &'static str {
+        match self {
+            JavaInterop::Jni => "1.8",
+            JavaInterop::Panama => "22",
+        }
+    }
+
+    /// Name of the shared library bundled in the jar
+    fn native_library_name(&self, ffi_name: &str) -> String {
+        match self {
+            JavaInterop::Jni => format!("{}_java", ffi_name),
+            JavaInterop::Panama => ffi_name.to_string(),
+        }
+    }
+}
+
 pub(crate) struct JavaBindgenConfig {
     /// Path to output the generated Java code
     pub(crate) java_output_dir: PathBuf,
@@ -31,16 +80,33 @@ pub(crate) struct JavaBindgenConfig {
     pub(crate) extra_files: Vec<PathBuf>,
     /// Platforms to include
     pub(crate) platforms: PlatformLocations,
+    /// How the generated code calls the native library
+    pub(crate) interop: JavaInterop,
+    /// Generate the Kotlin extensions layered on top of the Java API
+    pub(crate) kotlin: bool,
 }
 
 impl JavaBindgenConfig {
-    fn java_source_dir(&self, lib: &Library) -> PathBuf {
+    fn java_source_dir(&self, lib: &Library, module: &Module, naming: &Naming) -> PathBuf {
         let mut result = self.java_output_dir.clone();
         result.extend(&["src", "main", "java"]);
         for dir in self.group_id.split('.') {
             result.push(dir);
         }
         result.push(&lib.settings.name.kebab_case());
+        for dir in module.path() {
+            result.push(dir.snake_case(naming));
+        }
+        result
+    }
+
+    fn kotlin_source_dir(&self, lib: &Library) -> PathBuf {
+        let mut result = self.java_output_dir.clone();
+        result.extend(["src", "main", "kotlin"]);
+        for dir in self.group_id.split('.') {
+            result.push(dir);
+        }
+        result.push(lib.settings.name.kebab_case());
         result
     }
 
@@ -55,6 +121,7 @@ pub(crate) fn generate_java_bindings(
     lib: &Library,
     config: &JavaBindgenConfig,
 ) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::Java);
     logged::create_dir_all(&config.java_output_dir)?;
 
     // Create the pom.xml
@@ -62,8 +129,7 @@ pub(crate) fn generate_java_bindings(
 
     // Copy the compiled libraries to the resource folder
     logged::create_dir_all(config.java_resource_dir())?;
-    let mut ffi_name = config.ffi_name.to_string();
-    ffi_name.push_str("_java");
+    let ffi_name = config.interop.native_library_name(config.ffi_name);
 
     for p in config.platforms.iter() {
         let target_dir = config.java_resource_dir().join(p.platform.target_triple);
@@ -86,19 +152,29 @@ pub(crate) fn generate_java_bindings(
         logged::copy(path, dest)?;
     }
 
-    // Create the source directory
-    logged::create_dir_all(&config.java_source_dir(lib))?;
+    // Create the source directories
+    logged::create_dir_all(&config.java_source_dir(lib, &Module::root(), naming))?;
+    for module in lib.type_modules() {
+        logged::create_dir_all(config.java_source_dir(lib, module, naming))?;
+    }
 
     // Create all the direct mappings
-    generate_native_func_class(lib, config)?;
+    match config.interop {
+        JavaInterop::Jni => generate_native_func_class(lib, config, naming)?,
+        JavaInterop::Panama => panama::generate(lib, config, naming)?,
+    }
 
     // Generate the user-facing stuff
-    generate_constants(lib, config)?;
-    generate_exceptions(lib, config)?;
-    generate_structs(lib, config)?;
-    generate_enums(lib, config)?;
-    generate_classes(lib, config)?;
-    generate_interfaces(lib, config)?;
+    generate_constants(lib, config, naming)?;
+    generate_exceptions(lib, config, naming)?;
+    generate_structs(lib, config, naming)?;
+    generate_enums(lib, config, naming)?;
+    generate_classes(lib, config, naming)?;
+    generate_interfaces(lib, config, naming)?;
+
+    if config.kotlin {
+        kotlin::generate(lib, config)?;
+    }
 
     Ok(())
 }
@@ -108,7 +184,7 @@ fn generate_pom(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<(
     let mut filename = config.java_output_dir.clone();
     filename.push("pom");
     filename.set_extension("xml");
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::Java))?;
 
     f.writeln("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
     f.writeln("<project xmlns=\"http://maven.apache.org/POM/4.0.0\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"http://maven.apache.org/POM/4.0.0 http://maven.apache.org/xsd/maven-4.0.0.xsd\">")?;
@@ -180,10 +256,23 @@ fn generate_pom(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<(
 
         // General properties
         f.writeln("<properties>")?;
-        f.writeln("    <project.java.version>1.8</project.java.version>")?;
+        let java_version = config.interop.java_version();
+        f.writeln(&format!(
+            "    <project.java.version>{}</project.java.version>",
+            java_version
+        ))?;
         f.writeln("    <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>")?;
-        f.writeln("    <maven.compiler.target>1.8</maven.compiler.target>")?;
-        f.writeln("    <maven.compiler.source>1.8</maven.compiler.source>")?;
+        f.writeln(&format!(
+            "    <maven.compiler.target>{}</maven.compiler.target>",
+            java_version
+        ))?;
+        f.writeln(&format!(
+            "    <maven.compiler.source>{}</maven.compiler.source>",
+            java_version
+        ))?;
+        if config.kotlin {
+            kotlin::write_pom_properties(f, config)?;
+        }
         f.writeln("</properties>")?;
 
         f.newline()?;
@@ -195,6 +284,9 @@ fn generate_pom(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<(
         f.writeln("        <artifactId>joou-java-6</artifactId>")?;
         f.writeln("        <version>0.9.4</version>")?;
         f.writeln("    </dependency>")?;
+        if config.kotlin {
+            kotlin::write_pom_dependencies(f)?;
+        }
         f.writeln("</dependencies>")?;
 
         f.newline()?;
@@ -228,6 +320,9 @@ fn generate_pom(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<(
         f.writeln("        </execution>")?;
         f.writeln("      </executions>")?;
         f.writeln("    </plugin>")?;
+        if config.kotlin {
+            kotlin::write_pom_plugin(f)?;
+        }
         f.writeln("  </plugins>")?;
         f.writeln("</build>")?;
 
@@ -236,23 +331,24 @@ fn generate_pom(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<(
     f.writeln("</project>")
 }
 
-fn write_null_checks(
+fn write_argument_checks(
     f: &mut dyn Printer,
     args: &[Arg<FunctionArgument, Validated>],
+    patterns: &[&str],
 ) -> FormattingResult<()> {
     for arg in args.iter().filter(|a| a.arg_type.is_nullable()) {
-        let arg_name = arg.name.mixed_case();
+        let arg_name = arg.name.mixed_case(f.naming());
         f.writeln(&format!(
             "java.util.Objects.requireNonNull({}, \"{} cannot be null\");",
             arg_name, arg_name
         ))?;
         if arg.arg_type.is_struct() {
-            f.writeln(&format!("{}._assertFieldsNotNull();", arg_name))?;
+            f.writeln(&format!("{}._assertFieldsValid();", arg_name))?;
         }
         if let FunctionArgument::Collection(x) = &arg.arg_type {
             f.writeln(&format!(
                 "for({} _item: {})",
-                x.item_type.as_java_object(),
+                x.item_type.as_java_object(f.naming()),
                 arg_name
             ))?;
             blocked(f, |f| {
@@ -260,15 +356,37 @@ fn write_null_checks(
             })?;
         }
     }
+    for arg in args {
+        if let Some(target) = arg.arg_type.constrained_type() {
+            let name = arg.name.mixed_case(f.naming());
+            for constraint in &arg.constraints {
+                write_constraint_check(f, constraint, target, &name, patterns)?;
+            }
+        }
+    }
     Ok(())
 }
 
-fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
-    let mut f = create_file(NATIVE_FUNCTIONS_CLASSNAME, config, lib)?;
+fn generate_native_func_class(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let mut f = create_file(
+        NATIVE_FUNCTIONS_CLASSNAME,
+        &Module::root(),
+        config,
+        lib,
+        naming,
+    )?;
 
     f.newline()?;
 
-    f.writeln(&format!("class {}", NATIVE_FUNCTIONS_CLASSNAME))?;
+    f.writeln(&format!(
+        "{}class {}",
+        glue_visibility(lib),
+        NATIVE_FUNCTIONS_CLASSNAME
+    ))?;
     blocked(&mut f, |f| {
         f.writeln(&format!(
             "static final String VERSION = \"{}\";",
@@ -296,38 +414,16 @@ fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> Form
                 f.writeln("else")?;
                 blocked(f, |f| {
                     f.writeln("boolean loaded = false;")?;
-                    let libname = format!("{}_java", config.ffi_name);
+                    let libname = config.interop.native_library_name(config.ffi_name);
                     for platform in config.platforms.iter() {
-                        match platform.platform.target_os {
-                            OS::Windows => {
-                                f.writeln("if(!loaded)")?;
-                                blocked(f, |f| {
-                                    f.writeln(&format!(
-                                        "loaded = loadLibrary(\"{}\", \"{}\", \"dll\");",
-                                        platform.platform.target_triple, libname
-                                    ))
-                                })?;
-                            }
-                            OS::Linux => {
-                                f.writeln("if(!loaded)")?;
-                                blocked(f, |f| {
-                                    f.writeln(&format!(
-                                        "loaded = loadLibrary(\"{}\", \"lib{}\", \"so\");",
-                                        platform.platform.target_triple, libname
-                                    ))
-                                })?;
-                            }
-                            OS::MacOS => {
-                                f.writeln("if(!loaded)")?;
-                                blocked(f, |f| {
-                                    f.writeln(&format!(
-                                        "loaded = loadLibrary(\"{}\", \"lib{}\", \"dylib\");",
-                                        platform.platform.target_triple, libname
-                                    ))
-                                })?;
-                            }
-                            _ => unimplemented!(),
-                        }
+                        let (name, extension) = shared_library_file(&platform.platform, &libname);
+                        f.writeln("if(!loaded)")?;
+                        blocked(f, |f| {
+                            f.writeln(&format!(
+                                "loaded = loadLibrary(\"{}\", \"{}\", \"{}\");",
+                                platform.platform.target_triple, name, extension
+                            ))
+                        })?;
                     }
 
                     f.writeln("if(!loaded)")?;
@@ -372,21 +468,10 @@ fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> Form
 
         f.newline()?;
 
-        fn skip(c: FunctionCategory) -> bool {
-            match c {
-                FunctionCategory::Native => false,
-                // we don't generate any of these
-                FunctionCategory::CollectionCreate => true,
-                FunctionCategory::CollectionDestroy => true,
-                FunctionCategory::CollectionAdd => true,
-                FunctionCategory::IteratorNext => true,
-            }
-        }
-
         for handle in lib.functions().filter(|func| !skip(func.category)) {
             f.writeln(&format!(
                 "private static native {} {}(",
-                handle.return_type.as_java_primitive(),
+                handle.return_type.as_java_primitive(naming),
                 handle.name
             ))?;
 
@@ -396,8 +481,8 @@ fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> Form
                 .map(|param| {
                     format!(
                         "{} {}",
-                        param.arg_type.as_java_primitive(),
-                        param.name.mixed_case()
+                        param.arg_type.as_java_primitive(naming),
+                        param.name.mixed_case(naming)
                     )
                 })
                 .collect::<Vec<String>>()
@@ -408,112 +493,216 @@ fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> Form
             f.newline()?;
         }
 
-        f.writeln("// wrappers around the native functions that do null checking")?;
-        f.writeln("static class Wrapped")?;
-        blocked(f, |f| {
-            for handle in lib.functions().filter(|func| !skip(func.category)) {
-                f.writeln(&format!(
-                    "static {} {}(",
-                    handle.return_type.as_java_primitive(),
-                    handle.name
-                ))?;
+        write_wrapped_class(f, lib)
+    })
+}
 
-                let args = handle
+fn skip(c: FunctionCategory) -> bool {
+    match c {
+        FunctionCategory::Native => false,
+        // we don't generate any of these
+        FunctionCategory::CollectionCreate => true,
+        FunctionCategory::CollectionDestroy => true,
+        FunctionCategory::CollectionAdd => true,
+        FunctionCategory::IteratorNext => true,
+    }
+}
+
+fn write_wrapped_class(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("// wrappers around the native functions that do null and constraint checking")?;
+    f.writeln(&format!("{}static class Wrapped", glue_visibility(lib)))?;
+    let patterns = regex_patterns(
+        lib.functions()
+            .filter(|func| !skip(func.category))
+            .flat_map(|func| func.arguments.iter())
+            .flat_map(|arg| arg.constraints.iter()),
+    );
+    blocked(f, |f| {
+        write_regex_patterns(f, &patterns)?;
+        for handle in lib.functions().filter(|func| !skip(func.category)) {
+            f.writeln(&format!(
+                "{}static {} {}(",
+                glue_visibility(lib),
+                handle.return_type.as_java_primitive(f.naming()),
+                handle.name
+            ))?;
+
+            let args = handle
+                .arguments
+                .iter()
+                .map(|param| {
+                    format!(
+                        "{} {}",
+                        param.arg_type.as_java_primitive(f.naming()),
+                        param.name.mixed_case(f.naming())
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            f.write(&args)?;
+            f.write(")")?;
+            blocked(f, |f| {
+                write_argument_checks(f, &handle.arguments, &patterns)?;
+                let arg_names = handle
                     .arguments
                     .iter()
-                    .map(|param| {
-                        format!(
-                            "{} {}",
-                            param.arg_type.as_java_primitive(),
-                            param.name.mixed_case()
-                        )
-                    })
+                    .map(|x| x.name.mixed_case(f.naming()))
                     .collect::<Vec<String>>()
                     .join(", ");
-
-                f.write(&args)?;
-                f.write(")")?;
-                blocked(f, |f| {
-                    write_null_checks(f, &handle.arguments)?;
-                    let arg_names = handle
-                        .arguments
-                        .iter()
-                        .map(|x| x.name.mixed_case())
-                        .collect::<Vec<String>>()
-                        .join(", ");
-                    let invocation = format!("NativeFunctions.{}({});", handle.name, arg_names);
-                    if handle.return_type.is_some() {
-                        f.writeln(&format!("return {}", invocation))
-                    } else {
-                        f.writeln(&invocation)
-                    }
-                })?;
-            }
-            Ok(())
-        })?;
-
+                let invocation = format!("NativeFunctions.{}({});", handle.name, arg_names);
+                if handle.return_type.is_some() {
+                    f.writeln(&format!("return {}", invocation))
+                } else {
+                    f.writeln(&invocation)
+                }
+            })?;
+        }
         Ok(())
     })
 }
 
-fn generate_constants(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+/// File name (without the extension) and extension of a shared library on a platform
+fn shared_library_file(platform: &Platform, libname: &str) -> (String, &'static str) {
+    match platform.target_os {
+        OS::Windows => (libname.to_string(), "dll"),
+        OS::Linux => (format!("lib{}", libname), "so"),
+        OS::MacOS => (format!("lib{}", libname), "dylib"),
+        _ => unimplemented!(),
+    }
+}
+
+fn generate_constants(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for set in lib.constants() {
-        let mut f = create_file(&set.name.camel_case(), config, lib)?;
+        let mut f = create_file(
+            &set.name.camel_case(naming),
+            &set.module,
+            config,
+            lib,
+            naming,
+        )?;
         constant::generate(&mut f, set)?;
     }
 
     Ok(())
 }
 
-fn generate_exceptions(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+fn generate_exceptions(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for error in lib.error_types() {
-        let mut f = create_file(&error.exception_name.camel_case(), config, lib)?;
+        let mut f = create_file(
+            &error.exception_name.camel_case(naming),
+            &error.inner.module,
+            config,
+            lib,
+            naming,
+        )?;
         exception::generate(&mut f, error)?;
     }
 
     Ok(())
 }
 
-fn generate_structs(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+fn generate_structs(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for st in lib.structs() {
-        let mut f = create_file(&st.name().camel_case(), config, lib)?;
+        let mut f = create_file(
+            &st.name().camel_case(naming),
+            st.module(),
+            config,
+            lib,
+            naming,
+        )?;
+        let glue = glue_visibility(lib);
         match st {
-            StructType::FunctionArg(x) => structure::generate(&mut f, x, true)?,
-            StructType::FunctionReturn(x) => structure::generate(&mut f, x, false)?,
-            StructType::CallbackArg(x) => structure::generate(&mut f, x, false)?,
-            StructType::Universal(x) => structure::generate(&mut f, x, true)?,
+            StructType::FunctionArg(x) => {
+                structure::generate(&mut f, x, true, config.interop, glue)?
+            }
+            StructType::FunctionReturn(x) => {
+                structure::generate(&mut f, x, false, config.interop, glue)?
+            }
+            StructType::CallbackArg(x) => {
+                structure::generate(&mut f, x, false, config.interop, glue)?
+            }
+            StructType::Universal(x) => structure::generate(&mut f, x, true, config.interop, glue)?,
         }
     }
 
     Ok(())
 }
 
-fn generate_enums(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+fn generate_enums(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for native_enum in lib.enums() {
-        let mut f = create_file(&native_enum.name.camel_case(), config, lib)?;
+        let mut f = create_file(
+            &native_enum.name.camel_case(naming),
+            &native_enum.module,
+            config,
+            lib,
+            naming,
+        )?;
         enumeration::generate(&mut f, native_enum)?;
     }
 
     Ok(())
 }
 
-fn generate_classes(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+fn generate_classes(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for class in lib.classes() {
-        let mut f = create_file(&class.name().camel_case(), config, lib)?;
-        class::generate(&mut f, class)?;
+        let mut f = create_file(
+            &class.name().camel_case(naming),
+            &class.declaration.module,
+            config,
+            lib,
+            naming,
+        )?;
+        class::generate(&mut f, class, config.interop)?;
     }
 
     for class in lib.static_classes() {
-        let mut f = create_file(&class.name.camel_case(), config, lib)?;
+        let mut f = create_file(
+            &class.name.camel_case(naming),
+            &class.module,
+            config,
+            lib,
+            naming,
+        )?;
         class::generate_static(&mut f, class)?;
     }
 
     Ok(())
 }
 
-fn generate_interfaces(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+fn generate_interfaces(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
     for interface in lib.untyped_interfaces() {
-        let mut f = create_file(&interface.name.camel_case(), config, lib)?;
+        let mut f = create_file(
+            &interface.name.camel_case(naming),
+            &interface.module,
+            config,
+            lib,
+            naming,
+        )?;
         interface::generate(&mut f, interface)?;
     }
 
@@ -522,17 +711,19 @@ fn generate_interfaces(lib: &Library, config: &JavaBindgenConfig) -> FormattingR
 
 fn create_file(
     name: &str,
+    module: &Module,
     config: &JavaBindgenConfig,
     lib: &Library,
+    naming: &Naming,
 ) -> FormattingResult<FilePrinter> {
     // Open file
-    let mut filename = config.java_source_dir(lib);
+    let mut filename = config.java_source_dir(lib, module, naming);
     filename.push(name);
     filename.set_extension("java");
-    let mut f = FilePrinter::new(filename)?;
+    let mut f = FilePrinter::new(filename, lib.naming(TargetLanguage::Java))?;
 
     print_license(&mut f, &lib.info.license_description)?;
-    print_package(&mut f, config, lib)?;
+    print_package(&mut f, module, config, lib)?;
     f.newline()?;
 
     Ok(f)
@@ -549,14 +740,69 @@ fn print_license(f: &mut dyn Printer, license: &[String]) -> FormattingResult<()
 
 fn print_package(
     f: &mut dyn Printer,
+    module: &Module,
     config: &JavaBindgenConfig,
     lib: &Library,
 ) -> FormattingResult<()> {
     f.writeln(&format!(
-        "package {}.{};",
-        config.group_id,
-        lib.settings.name.kebab_case()
+        "package {};",
+        java_package(&config.group_id, lib, module, f.naming())
     ))?;
     f.newline()?;
-    f.writeln("import org.joou.*;")
+    f.writeln("import org.joou.*;")?;
+    if lib.has_modules() {
+        // the types of the other modules are in other packages
+        for package in library_packages(&config.group_id, lib, f.naming()) {
+            f.writeln(&format!("import {}.*;", package))?;
+        }
+    }
+    Ok(())
+}
+
+/// Java package of a module, nested in the one of the library
+pub(crate) fn java_package(
+    group_id: &str,
+    lib: &Library,
+    module: &Module,
+    naming: &Naming,
+) -> String {
+    let mut package = format!("{}.{}", group_id, lib.settings.name.kebab_case());
+    for name in module.path() {
+        package.push('.');
+        package.push_str(&name.snake_case(naming));
+    }
+    package
+}
+
+/// Packages of the library, i.e. the root one with the native glue and the ones of the modules with types
+pub(crate) fn library_packages(group_id: &str, lib: &Library, naming: &Naming) -> Vec<String> {
+    std::iter::once(java_package(group_id, lib, &Module::root(), naming))
+        .chain(
+            lib.type_modules()
+                .into_iter()
+                .filter(|x| !x.is_root())
+                .map(|x| java_package(group_id, lib, x, naming)),
+        )
+        .collect()
+}
+
+/// Java visibility of a member that is package-private when its type is in the root package
+///
+/// The native glue is in the root package, so it can only reach the types of the other modules
+/// through their public members.
+pub(crate) fn package_visibility(module: &Module) -> &'static str {
+    if module.is_root() {
+        ""
+    } else {
+        "public "
+    }
+}
+
+/// Java visibility of the glue invoked by the types of every module, e.g. the wrapped functions
+pub(crate) fn glue_visibility(lib: &Library) -> &'static str {
+    if lib.has_modules() {
+        "public "
+    } else {
+        ""
+    }
 }
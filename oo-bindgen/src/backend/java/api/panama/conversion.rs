@@ -0,0 +1,559 @@
+use crate::model::*;
+
+/// Memory layout of a C type as seen from the Foreign Function & Memory API
+pub(crate) enum Layout {
+    /// scalar or pointer value with the name of the layout constant and the Java carrier type
+    Value(&'static str, &'static str),
+    /// struct passed by value, carried as a MemorySegment
+    Group(String),
+}
+
+impl Layout {
+    const ADDRESS: Layout = Layout::Value("ValueLayout.ADDRESS", "MemorySegment");
+
+    /// expression that evaluates to the layout
+    pub(crate) fn expr(&self) -> String {
+        match self {
+            Layout::Value(x, _) => x.to_string(),
+            Layout::Group(x) => x.clone(),
+        }
+    }
+
+    /// Java type used for the value in method handle signatures
+    pub(crate) fn carrier(&self) -> &'static str {
+        match self {
+            Layout::Value(_, x) => x,
+            Layout::Group(_) => "MemorySegment",
+        }
+    }
+
+    /// expression of the zeroed value
+    pub(crate) fn zero(&self) -> String {
+        match self {
+            Layout::Value(_, "boolean") => "false".to_string(),
+            Layout::Value(_, "byte") => "(byte) 0".to_string(),
+            Layout::Value(_, "short") => "(short) 0".to_string(),
+            Layout::Value(_, "long") => "0L".to_string(),
+            Layout::Value(_, "float") => "0.0f".to_string(),
+            Layout::Value(_, "double") => "0.0".to_string(),
+            Layout::Value(_, "MemorySegment") => "MemorySegment.NULL".to_string(),
+            Layout::Value(_, _) => "0".to_string(),
+            Layout::Group(x) => format!("Arena.ofAuto().allocate({})", x),
+        }
+    }
+
+    /// expression that reads the value from a segment that holds it
+    pub(crate) fn read(&self, segment: &str) -> String {
+        match self {
+            Layout::Value(x, _) => format!("{}.get({}, 0)", segment, x),
+            Layout::Group(_) => segment.to_string(),
+        }
+    }
+
+    /// statement that writes a carrier value into a segment sized to hold it
+    pub(crate) fn write(&self, segment: &str, value: &str) -> String {
+        match self {
+            Layout::Value(x, _) => format!("{}.set({}, 0, {});", segment, x, value),
+            Layout::Group(_) => format!("{}.copyFrom({});", segment, value),
+        }
+    }
+}
+
+/// Types with a C representation
+pub(crate) trait NativeLayout {
+    fn layout(&self) -> Layout;
+}
+
+/// Conversion of a Java value to its C representation
+///
+/// Conversions may allocate from a `SegmentAllocator` named `_arena` that must be in scope
+pub(crate) trait ToNative: NativeLayout {
+    fn convert_to_native(&self, expr: &str) -> String;
+}
+
+/// Conversion of a C value to its Java representation
+pub(crate) trait FromNative: NativeLayout {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String;
+}
+
+pub(crate) fn struct_layout(name: &Name) -> String {
+    format!("Structs.{}_layout", name)
+}
+
+pub(crate) fn interface_layout(name: &Name) -> String {
+    format!("Interfaces.{}_layout", name)
+}
+
+impl NativeLayout for Primitive {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Bool => Layout::Value("ValueLayout.JAVA_BOOLEAN", "boolean"),
+            Self::U8 | Self::S8 => Layout::Value("ValueLayout.JAVA_BYTE", "byte"),
+            Self::U16 | Self::S16 => Layout::Value("ValueLayout.JAVA_SHORT", "short"),
+            Self::U32 | Self::S32 => Layout::Value("ValueLayout.JAVA_INT", "int"),
+            Self::U64 | Self::S64 => Layout::Value("ValueLayout.JAVA_LONG", "long"),
+            Self::Float => Layout::Value("ValueLayout.JAVA_FLOAT", "float"),
+            Self::Double => Layout::Value("ValueLayout.JAVA_DOUBLE", "double"),
+        }
+    }
+}
+
+impl ToNative for Primitive {
+    fn convert_to_native(&self, expr: &str) -> String {
+        match self {
+            Self::U8 => format!("{}.byteValue()", expr),
+            Self::U16 => format!("{}.shortValue()", expr),
+            Self::U32 => format!("{}.intValue()", expr),
+            Self::U64 => format!("{}.longValue()", expr),
+            _ => expr.to_string(),
+        }
+    }
+}
+
+impl FromNative for Primitive {
+    fn convert_from_native(&self, expr: &str, _naming: &Naming) -> String {
+        match self {
+            Self::U8 => format!("UByte.valueOf(Byte.toUnsignedInt({}))", expr),
+            Self::U16 => format!("UShort.valueOf(Short.toUnsignedInt({}))", expr),
+            Self::U32 => format!("UInteger.valueOf(Integer.toUnsignedLong({}))", expr),
+            Self::U64 => format!("ULong.valueOf({})", expr),
+            _ => expr.to_string(),
+        }
+    }
+}
+
+impl NativeLayout for DurationType {
+    fn layout(&self) -> Layout {
+        Layout::Value("ValueLayout.JAVA_LONG", "long")
+    }
+}
+
+impl ToNative for DurationType {
+    fn convert_to_native(&self, expr: &str) -> String {
+        match self {
+            DurationType::Milliseconds => format!("{}.toMillis()", expr),
+            DurationType::Seconds => format!("{}.getSeconds()", expr),
+        }
+    }
+}
+
+impl FromNative for DurationType {
+    fn convert_from_native(&self, expr: &str, _naming: &Naming) -> String {
+        match self {
+            DurationType::Milliseconds => format!("java.time.Duration.ofMillis({})", expr),
+            DurationType::Seconds => format!("java.time.Duration.ofSeconds({})", expr),
+        }
+    }
+}
+
+impl<D> NativeLayout for Handle<Enum<D>>
+where
+    D: DocReference,
+{
+    fn layout(&self) -> Layout {
+        Layout::Value("ValueLayout.JAVA_INT", "int")
+    }
+}
+
+impl<D> ToNative for Handle<Enum<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str) -> String {
+        format!("Enums.{}_to_native({})", self.name, expr)
+    }
+}
+
+impl<D> FromNative for Handle<Enum<D>>
+where
+    D: DocReference,
+{
+    fn convert_from_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("Enums.{}_from_native({})", self.name, expr)
+    }
+}
+
+impl NativeLayout for BasicType {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Primitive(x) => x.layout(),
+            Self::Duration(x) => x.layout(),
+            Self::Enum(x) => x.layout(),
+        }
+    }
+}
+
+impl ToNative for BasicType {
+    fn convert_to_native(&self, expr: &str) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_to_native(expr),
+            Self::Duration(x) => x.convert_to_native(expr),
+            Self::Enum(x) => x.convert_to_native(expr),
+        }
+    }
+}
+
+impl FromNative for BasicType {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_from_native(expr, naming),
+            Self::Duration(x) => x.convert_from_native(expr, naming),
+            Self::Enum(x) => x.convert_from_native(expr, naming),
+        }
+    }
+}
+
+impl NativeLayout for StringType {
+    fn layout(&self) -> Layout {
+        Layout::ADDRESS
+    }
+}
+
+impl ToNative for StringType {
+    fn convert_to_native(&self, expr: &str) -> String {
+        format!("_arena.allocateFrom({})", expr)
+    }
+}
+
+impl FromNative for StringType {
+    fn convert_from_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("NativeMemory.toJavaString({})", expr)
+    }
+}
+
+impl NativeLayout for ClassDeclarationHandle {
+    fn layout(&self) -> Layout {
+        Layout::ADDRESS
+    }
+}
+
+impl ToNative for ClassDeclarationHandle {
+    fn convert_to_native(&self, expr: &str) -> String {
+        format!("MemorySegment.ofAddress({}.self)", expr)
+    }
+}
+
+impl FromNative for ClassDeclarationHandle {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        format!("new {}({}.address())", self.name.camel_case(naming), expr)
+    }
+}
+
+impl<D> NativeLayout for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn layout(&self) -> Layout {
+        Layout::ADDRESS
+    }
+}
+
+impl<D> ToNative for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str) -> String {
+        format!("Collections.{}_to_native(_arena, {})", self.name(), expr)
+    }
+}
+
+impl<D> NativeLayout for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn layout(&self) -> Layout {
+        Layout::Group(interface_layout(&self.name))
+    }
+}
+
+impl<D> ToNative for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str) -> String {
+        format!("Interfaces.{}_to_native(_arena, {})", self.name, expr)
+    }
+}
+
+impl<D> NativeLayout for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn layout(&self) -> Layout {
+        Layout::ADDRESS
+    }
+}
+
+impl<D> FromNative for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn convert_from_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("Iterators.{}_to_list({})", self.iter_class.name, expr)
+    }
+}
+
+impl<T> NativeLayout for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn layout(&self) -> Layout {
+        Layout::Group(struct_layout(self.name()))
+    }
+}
+
+impl<T> ToNative for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_to_native(&self, expr: &str) -> String {
+        format!("Structs.{}_to_native(_arena, {})", self.name(), expr)
+    }
+}
+
+impl<T> FromNative for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_from_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("Structs.{}_from_native({})", self.name(), expr)
+    }
+}
+
+impl<T, D> NativeLayout for Handle<Struct<T, D>>
+where
+    T: StructFieldType,
+    D: DocReference,
+{
+    fn layout(&self) -> Layout {
+        Layout::Group(struct_layout(self.name()))
+    }
+}
+
+impl<T, D> ToNative for Handle<Struct<T, D>>
+where
+    T: StructFieldType,
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str) -> String {
+        format!("Structs.{}_to_native(_arena, {})", self.name(), expr)
+    }
+}
+
+impl<T, D> FromNative for Handle<Struct<T, D>>
+where
+    T: StructFieldType,
+    D: DocReference,
+{
+    fn convert_from_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("Structs.{}_from_native({})", self.name(), expr)
+    }
+}
+
+impl NativeLayout for PrimitiveRef {
+    fn layout(&self) -> Layout {
+        Layout::ADDRESS
+    }
+}
+
+impl FromNative for PrimitiveRef {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        let layout = self.inner.layout().expr();
+        let value = format!(
+            "{}.reinterpret({}.byteSize()).get({}, 0)",
+            expr, layout, layout
+        );
+        format!(
+            "({}.address() == 0 ? null : {})",
+            expr,
+            self.inner.convert_from_native(&value, naming)
+        )
+    }
+}
+
+impl NativeLayout for FunctionArgument {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::String(x) => x.layout(),
+            Self::Collection(x) => x.layout(),
+            Self::Struct(x) => x.layout(),
+            Self::StructRef(_) => Layout::ADDRESS,
+            Self::ClassRef(x) => x.layout(),
+            Self::Interface(x) => x.layout(),
+        }
+    }
+}
+
+impl ToNative for FunctionArgument {
+    fn convert_to_native(&self, expr: &str) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr),
+            Self::String(x) => x.convert_to_native(expr),
+            Self::Collection(x) => x.convert_to_native(expr),
+            Self::Struct(x) => x.convert_to_native(expr),
+            Self::StructRef(x) => format!("Structs.{}_to_native(_arena, {})", x.name(), expr),
+            Self::ClassRef(x) => x.convert_to_native(expr),
+            Self::Interface(x) => x.convert_to_native(expr),
+        }
+    }
+}
+
+impl NativeLayout for FunctionReturnValue {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::PrimitiveRef(x) => x.layout(),
+            Self::String(x) => x.layout(),
+            Self::ClassRef(x) => x.layout(),
+            Self::Struct(x) => x.layout(),
+            Self::StructRef(_) => Layout::ADDRESS,
+        }
+    }
+}
+
+impl FromNative for FunctionReturnValue {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_native(expr, naming),
+            Self::PrimitiveRef(x) => x.convert_from_native(expr, naming),
+            Self::String(x) => x.convert_from_native(expr, naming),
+            Self::ClassRef(x) => x.convert_from_native(expr, naming),
+            Self::Struct(x) => x.convert_from_native(expr, naming),
+            Self::StructRef(x) => format!("Structs.{}_from_pointer({})", x.untyped().name, expr),
+        }
+    }
+}
+
+impl NativeLayout for CallbackArgument {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::String(x) => x.layout(),
+            Self::Iterator(x) => x.layout(),
+            Self::Class(x) => x.layout(),
+            Self::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl FromNative for CallbackArgument {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_native(expr, naming),
+            Self::String(x) => x.convert_from_native(expr, naming),
+            Self::Iterator(x) => x.convert_from_native(expr, naming),
+            Self::Class(x) => x.convert_from_native(expr, naming),
+            Self::Struct(x) => x.convert_from_native(expr, naming),
+        }
+    }
+}
+
+impl NativeLayout for CallbackReturnValue {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl ToNative for CallbackReturnValue {
+    fn convert_to_native(&self, expr: &str) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr),
+            Self::Struct(x) => x.convert_to_native(expr),
+        }
+    }
+}
+
+impl NativeLayout for FunctionArgStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::String(x) => x.layout(),
+            Self::Interface(x) => x.inner.layout(),
+            Self::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl ToNative for FunctionArgStructField {
+    fn convert_to_native(&self, expr: &str) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr),
+            Self::String(x) => x.convert_to_native(expr),
+            Self::Interface(x) => x.inner.convert_to_native(expr),
+            Self::Struct(x) => x.convert_to_native(expr),
+        }
+    }
+}
+
+impl NativeLayout for FunctionReturnStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::ClassRef(x) => x.layout(),
+            Self::Struct(x) => x.layout(),
+            Self::Iterator(x) => x.layout(),
+        }
+    }
+}
+
+impl FromNative for FunctionReturnStructField {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_native(expr, naming),
+            Self::ClassRef(x) => x.convert_from_native(expr, naming),
+            Self::Struct(x) => x.convert_from_native(expr, naming),
+            Self::Iterator(x) => x.convert_from_native(expr, naming),
+        }
+    }
+}
+
+impl NativeLayout for CallbackArgStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::Iterator(x) => x.layout(),
+            Self::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl FromNative for CallbackArgStructField {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_native(expr, naming),
+            Self::Iterator(x) => x.convert_from_native(expr, naming),
+            Self::Struct(x) => x.convert_from_native(expr, naming),
+        }
+    }
+}
+
+impl NativeLayout for UniversalStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Basic(x) => x.layout(),
+            Self::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl ToNative for UniversalStructField {
+    fn convert_to_native(&self, expr: &str) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr),
+            Self::Struct(x) => x.convert_to_native(expr),
+        }
+    }
+}
+
+impl FromNative for UniversalStructField {
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_native(expr, naming),
+            Self::Struct(x) => x.convert_from_native(expr, naming),
+        }
+    }
+}
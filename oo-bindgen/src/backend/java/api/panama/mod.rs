@@ -0,0 +1,870 @@
+use super::*;
+use crate::backend::c::ctype::CType;
+
+use self::conversion::*;
+use super::interface::PossiblyVoidReturnValue;
+
+mod conversion;
+
+/// Generate the pure Java glue that calls the C API using the Foreign Function & Memory API
+pub(crate) fn generate(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let mut f = create_file("NativeMemory", &Module::root(), config, lib, naming)?;
+    f.writeln(include_str!("../../../../../static/java/NativeMemory.java"))?;
+
+    generate_native_functions(lib, config, naming)
+}
+
+fn generate_native_functions(
+    lib: &Library,
+    config: &JavaBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let mut f = create_file(
+        NATIVE_FUNCTIONS_CLASSNAME,
+        &Module::root(),
+        config,
+        lib,
+        naming,
+    )?;
+    f.writeln("import java.lang.foreign.*;")?;
+    f.writeln("import java.lang.invoke.MethodHandle;")?;
+
+    f.newline()?;
+
+    f.writeln(&format!(
+        "{}class {}",
+        glue_visibility(lib),
+        NATIVE_FUNCTIONS_CLASSNAME
+    ))?;
+    blocked(&mut f, |f| {
+        f.writeln(&format!(
+            "static final String VERSION = \"{}\";",
+            lib.version
+        ))?;
+
+        f.newline()?;
+
+        f.writeln("static final SymbolLookup LIBRARY = loadLibrary();")?;
+
+        f.newline()?;
+
+        // Check the loaded binary version
+        f.writeln("static")?;
+        blocked(f, |f| {
+            f.writeln("String loadedVersion = version();")?;
+            f.writeln("if (!loadedVersion.equals(VERSION))")?;
+            blocked(f, |f| {
+                f.writeln(&format!("System.err.println(\"Native code library failed to load: {} module version mismatch. Expected \" + VERSION + \" but loaded \" + loadedVersion);", lib.settings.name))?;
+                f.writeln("System.exit(1);")
+            })
+        })?;
+
+        f.newline()?;
+
+        write_library_loader(f, lib, config)?;
+
+        f.newline()?;
+
+        write_handles(f, lib)?;
+
+        for func in lib.functions().filter(|func| !skip(func.category)) {
+            f.newline()?;
+            write_function(f, func)?;
+        }
+
+        f.newline()?;
+
+        write_wrapped_class(f, lib)?;
+
+        f.newline()?;
+
+        write_enums(f, lib)?;
+
+        f.newline()?;
+
+        write_structs(f, lib)?;
+
+        f.newline()?;
+
+        write_interfaces(f, lib)?;
+
+        f.newline()?;
+
+        write_collections(f, lib)?;
+
+        f.newline()?;
+
+        write_iterators(f, lib)
+    })
+}
+
+fn write_library_loader(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &JavaBindgenConfig,
+) -> FormattingResult<()> {
+    f.writeln("private static SymbolLookup loadLibrary()")?;
+    blocked(f, |f| {
+        f.writeln("try")?;
+        blocked(f, |f| {
+            let env_variable_name = format!(
+                "{}_NATIVE_LIB_LOCATION",
+                lib.settings.name.capital_snake_case()
+            );
+            f.writeln(&format!(
+                "String nativeLibLocation = System.getenv(\"{}\");",
+                env_variable_name
+            ))?;
+            f.writeln("if(nativeLibLocation != null)")?;
+            blocked(f, |f| {
+                f.writeln("return SymbolLookup.libraryLookup(java.nio.file.Path.of(nativeLibLocation), Arena.global());")
+            })?;
+
+            f.newline()?;
+
+            f.writeln("SymbolLookup lookup = null;")?;
+            let libname = config.interop.native_library_name(config.ffi_name);
+            for platform in config.platforms.iter() {
+                let (name, extension) = shared_library_file(&platform.platform, &libname);
+                f.writeln("if(lookup == null)")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "lookup = loadLibrary(\"{}\", \"{}\", \"{}\");",
+                        platform.platform.target_triple, name, extension
+                    ))
+                })?;
+            }
+
+            f.writeln("if(lookup == null)")?;
+            blocked(f, |f| {
+                f.writeln(
+                    "throw new Exception(\"Unable to load any of the included native library\");",
+                )
+            })?;
+            f.writeln("return lookup;")
+        })?;
+        f.writeln("catch(Exception e)")?;
+        blocked(f, |f| {
+            f.writeln("System.err.println(\"Native code library failed to load: \" + e);")?;
+            f.writeln("System.exit(1);")?;
+            f.writeln("return null;")
+        })
+    })?;
+
+    f.newline()?;
+
+    f.writeln(
+        "private static SymbolLookup loadLibrary(String directory, String name, String extension)",
+    )?;
+    blocked(f, |f| {
+        f.writeln("try")?;
+        blocked(f, |f| {
+            f.writeln("java.io.InputStream stream = NativeFunctions.class.getResourceAsStream(\"/\" + directory + \"/\" + name + \".\" + extension);")?;
+            f.writeln("java.nio.file.Path tempFilePath = java.nio.file.Files.createTempFile(name, \".\" + extension);")?;
+            f.writeln("tempFilePath.toFile().deleteOnExit();")?;
+            f.writeln("java.nio.file.Files.copy(stream, tempFilePath, java.nio.file.StandardCopyOption.REPLACE_EXISTING);")?;
+            f.writeln("return SymbolLookup.libraryLookup(tempFilePath, Arena.global());")
+        })?;
+        f.writeln("catch(Throwable e)")?;
+        blocked(f, |f| f.writeln("return null;"))
+    })
+}
+
+fn descriptor(return_layout: Option<String>, args: Vec<String>) -> String {
+    match return_layout {
+        None => format!("FunctionDescriptor.ofVoid({})", args.join(", ")),
+        Some(x) => format!(
+            "FunctionDescriptor.of({})",
+            std::iter::once(x)
+                .chain(args)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn function_descriptor(func: &Function<Validated>) -> String {
+    let mut args: Vec<String> = func
+        .arguments
+        .iter()
+        .map(|arg| arg.arg_type.layout().expr())
+        .collect();
+
+    let return_layout = match func.get_signature_type() {
+        SignatureType::NoErrorNoReturn => None,
+        SignatureType::NoErrorWithReturn(rt, _) => Some(rt.layout().expr()),
+        SignatureType::ErrorNoReturn(_) => Some("ValueLayout.JAVA_INT".to_string()),
+        SignatureType::ErrorWithReturn(_, _, _) => {
+            args.push("ValueLayout.ADDRESS".to_string());
+            Some("ValueLayout.JAVA_INT".to_string())
+        }
+    };
+
+    descriptor(return_layout, args)
+}
+
+fn write_handles(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("// downcall handles for every function in the C API")?;
+    f.writeln("static final class Handles")?;
+    blocked(f, |f| {
+        for func in lib.functions() {
+            f.writeln(&format!(
+                "static final MethodHandle {} = NativeMemory.downcall(LIBRARY, \"{}\", {});",
+                func.name,
+                func.to_c_type(),
+                function_descriptor(func)
+            ))?;
+        }
+        Ok(())
+    })
+}
+
+fn write_function(f: &mut dyn Printer, func: &Handle<Function<Validated>>) -> FormattingResult<()> {
+    let params = func
+        .arguments
+        .iter()
+        .map(|arg| {
+            format!(
+                "{} {}",
+                arg.arg_type.as_java_primitive(f.naming()),
+                arg.name.mixed_case(f.naming())
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    f.writeln(&format!(
+        "private static {} {}({})",
+        func.return_type.as_java_primitive(f.naming()),
+        func.name,
+        params
+    ))?;
+    blocked(f, |f| {
+        f.writeln("try (Arena _arena = Arena.ofConfined())")?;
+        blocked(f, |f| {
+            let collections: Vec<&Arg<FunctionArgument, Validated>> = func
+                .arguments
+                .iter()
+                .filter(|arg| matches!(arg.arg_type, FunctionArgument::Collection(_)))
+                .collect();
+
+            if collections.is_empty() {
+                return write_invocation(f, func);
+            }
+
+            // native collections are destroyed once the call completes
+            for arg in collections.iter() {
+                f.writeln(&format!(
+                    "MemorySegment _{} = MemorySegment.NULL;",
+                    arg.name.mixed_case(f.naming())
+                ))?;
+            }
+            f.writeln("try")?;
+            blocked(f, |f| {
+                for arg in collections.iter() {
+                    let name = arg.name.mixed_case(f.naming());
+                    f.writeln(&format!(
+                        "_{} = {};",
+                        name,
+                        arg.arg_type.convert_to_native(&name)
+                    ))?;
+                }
+                write_invocation(f, func)
+            })?;
+            f.writeln("finally")?;
+            blocked(f, |f| {
+                for arg in collections.iter() {
+                    if let FunctionArgument::Collection(x) = &arg.arg_type {
+                        let name = arg.name.mixed_case(f.naming());
+                        f.writeln(&format!("if (_{}.address() != 0)", name))?;
+                        blocked(f, |f| {
+                            f.writeln(&format!("Collections.{}_destroy(_{});", x.name(), name))
+                        })?;
+                    }
+                }
+                Ok(())
+            })
+        })?;
+        f.writeln("catch (Throwable _t)")?;
+        blocked(f, |f| f.writeln("throw NativeMemory.rethrow(_t);"))
+    })
+}
+
+fn write_invocation(
+    f: &mut dyn Printer,
+    func: &Handle<Function<Validated>>,
+) -> FormattingResult<()> {
+    let signature = func.get_signature_type();
+
+    let mut args: Vec<String> = Vec::new();
+    // struct return values are allocated by the caller
+    if let SignatureType::NoErrorWithReturn(FunctionReturnValue::Struct(_), _) = &signature {
+        args.push("_arena".to_string());
+    }
+    for arg in func.arguments.iter() {
+        let name = arg.name.mixed_case(f.naming());
+        match &arg.arg_type {
+            FunctionArgument::Collection(_) => args.push(format!("_{}", name)),
+            other => args.push(other.convert_to_native(&name)),
+        }
+    }
+    if let SignatureType::ErrorWithReturn(_, rt, _) = &signature {
+        f.writeln(&format!(
+            "MemorySegment _out = _arena.allocate({});",
+            rt.layout().expr()
+        ))?;
+        args.push("_out".to_string());
+    }
+
+    let invocation = format!("Handles.{}.invoke({})", func.name, args.join(", "));
+
+    match signature {
+        SignatureType::NoErrorNoReturn => f.writeln(&format!("{};", invocation)),
+        SignatureType::NoErrorWithReturn(rt, _) => {
+            let carrier = rt.layout().carrier();
+            f.writeln(&format!(
+                "{} _result = ({}) {};",
+                carrier, carrier, invocation
+            ))?;
+            f.writeln(&format!(
+                "return {};",
+                rt.convert_from_native("_result", f.naming())
+            ))
+        }
+        SignatureType::ErrorNoReturn(err) => {
+            f.writeln(&format!("int _error = (int) {};", invocation))?;
+            write_error_check(f, &err)
+        }
+        SignatureType::ErrorWithReturn(err, rt, _) => {
+            f.writeln(&format!("int _error = (int) {};", invocation))?;
+            write_error_check(f, &err)?;
+            f.writeln(&format!(
+                "return {};",
+                rt.convert_from_native(&rt.layout().read("_out"), f.naming())
+            ))
+        }
+    }
+}
+
+fn write_error_check(f: &mut dyn Printer, err: &ErrorType<Validated>) -> FormattingResult<()> {
+    f.writeln("if (_error != 0)")?;
+    blocked(f, |f| {
+        f.writeln(&format!(
+            "throw new {}({});",
+            err.exception_name.camel_case(f.naming()),
+            err.inner.convert_from_native("_error", f.naming())
+        ))
+    })
+}
+
+fn write_enums(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("// conversions between Java enums and C integers")?;
+    f.writeln("static final class Enums")?;
+    blocked(f, |f| {
+        for (handle, last) in lib.enums().with_last() {
+            let enum_name = handle.name.camel_case(f.naming());
+
+            f.writeln(&format!(
+                "static int {}_to_native({} value)",
+                handle.name, enum_name
+            ))?;
+            blocked(f, |f| {
+                f.writeln("switch (value)")?;
+                blocked(f, |f| {
+                    for variant in &handle.variants {
+                        f.writeln(&format!(
+                            "case {}: return {};",
+                            variant.name.capital_snake_case(),
+                            variant.value
+                        ))?;
+                    }
+                    f.writeln(&format!(
+                        "default: throw new IllegalArgumentException(\"Invalid {} value: \" + value);",
+                        enum_name
+                    ))
+                })
+            })?;
+
+            f.newline()?;
+
+            f.writeln(&format!(
+                "static {} {}_from_native(int value)",
+                enum_name, handle.name
+            ))?;
+            blocked(f, |f| {
+                f.writeln("switch (value)")?;
+                blocked(f, |f| {
+                    for variant in &handle.variants {
+                        f.writeln(&format!(
+                            "case {}: return {}.{};",
+                            variant.value,
+                            enum_name,
+                            variant.name.capital_snake_case()
+                        ))?;
+                    }
+                    f.writeln(&format!(
+                        "default: throw new IllegalArgumentException(\"Invalid {} value: \" + value);",
+                        enum_name
+                    ))
+                })
+            })?;
+
+            if !last {
+                f.newline()?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn field_segment(layout: &str, name: &Name) -> String {
+    format!("NativeMemory.field(_native, {}, \"{}\")", layout, name)
+}
+
+fn write_struct_layout<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + NativeLayout,
+{
+    let members = st
+        .fields()
+        .map(|field| {
+            format!(
+                "{}.withName(\"{}\")",
+                field.field_type.layout().expr(),
+                field.name
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    f.writeln(&format!(
+        "static final StructLayout {}_layout = NativeMemory.struct({});",
+        st.name(),
+        members
+    ))
+}
+
+fn write_struct_to_native<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + ToNative,
+{
+    let layout = format!("{}_layout", st.name());
+
+    f.newline()?;
+    f.writeln(&format!(
+        "static MemorySegment {}_to_native(SegmentAllocator _arena, {} value) throws Throwable",
+        st.name(),
+        st.name().camel_case(f.naming())
+    ))?;
+    blocked(f, |f| {
+        f.writeln(&format!(
+            "MemorySegment _native = _arena.allocate({});",
+            layout
+        ))?;
+        for field in st.fields() {
+            let value = field
+                .field_type
+                .convert_to_native(&format!("value.{}", field.name.mixed_case(f.naming())));
+            f.writeln(
+                &field
+                    .field_type
+                    .layout()
+                    .write(&field_segment(&layout, &field.name), &value),
+            )?;
+        }
+        f.writeln("return _native;")
+    })
+}
+
+fn write_struct_from_native<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + FromNative,
+{
+    let struct_name = st.name().camel_case(f.naming());
+    let layout = format!("{}_layout", st.name());
+
+    f.newline()?;
+    f.writeln(&format!(
+        "static {} {}_from_native(MemorySegment _native) throws Throwable",
+        struct_name,
+        st.name()
+    ))?;
+    blocked(f, |f| {
+        for field in st.fields() {
+            let value = field
+                .field_type
+                .layout()
+                .read(&field_segment(&layout, &field.name));
+            f.writeln(&format!(
+                "var {} = {};",
+                field.name.mixed_case(f.naming()),
+                field.field_type.convert_from_native(&value, f.naming())
+            ))?;
+        }
+
+        // construct the instance using the initializer that takes every field
+        let constructor = match st.get_full_initializer() {
+            Some(x) if matches!(x.initializer_type, InitializerType::Static) => {
+                format!("{}.{}", struct_name, x.name.mixed_case(f.naming()))
+            }
+            _ => format!("new {}", struct_name),
+        };
+        let args = st
+            .fields()
+            .map(|field| field.name.mixed_case(f.naming()))
+            .collect::<Vec<String>>()
+            .join(", ");
+        f.writeln(&format!("return {}({});", constructor, args))
+    })?;
+
+    f.newline()?;
+    f.writeln(&format!(
+        "static {} {}_from_pointer(MemorySegment _pointer) throws Throwable",
+        struct_name,
+        st.name()
+    ))?;
+    blocked(f, |f| {
+        f.writeln("if (_pointer.address() == 0)")?;
+        blocked(f, |f| f.writeln("return null;"))?;
+        f.writeln(&format!(
+            "return {}_from_native(_pointer.reinterpret({}.byteSize()));",
+            st.name(),
+            layout
+        ))
+    })
+}
+
+fn write_structs(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("// layouts and conversions of the C structs")?;
+    f.writeln("static final class Structs")?;
+    blocked(f, |f| {
+        for (st, last) in lib.structs().with_last() {
+            match st {
+                StructType::FunctionArg(x) => {
+                    write_struct_layout(f, x)?;
+                    write_struct_to_native(f, x)?;
+                }
+                StructType::FunctionReturn(x) => {
+                    write_struct_layout(f, x)?;
+                    write_struct_from_native(f, x)?;
+                }
+                StructType::CallbackArg(x) => {
+                    write_struct_layout(f, x)?;
+                    write_struct_from_native(f, x)?;
+                }
+                StructType::Universal(x) => {
+                    write_struct_layout(f, x)?;
+                    write_struct_to_native(f, x)?;
+                    write_struct_from_native(f, x)?;
+                }
+            }
+
+            if !last {
+                f.newline()?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn callback_descriptor(cb: &CallbackFunction<Validated>) -> String {
+    let args = cb
+        .arguments
+        .iter()
+        .map(|arg| arg.arg_type.layout().expr())
+        .chain(std::iter::once("ValueLayout.ADDRESS".to_string()))
+        .collect();
+
+    descriptor(cb.return_type.get_value().map(|x| x.layout().expr()), args)
+}
+
+fn write_interfaces(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    let ctx_name = &lib.settings.interface.context_variable_name;
+    let destroy_name = &lib.settings.interface.destroy_func_name;
+
+    f.writeln(
+        "// layouts of the C interfaces and the upcalls that invoke the Java implementations",
+    )?;
+    f.writeln("static final class Interfaces")?;
+    blocked(f, |f| {
+        for (interface, last) in lib.untyped_interfaces().with_last() {
+            let interface_name = interface.name.camel_case(f.naming());
+            let layout = format!("{}_layout", interface.name);
+
+            let members = interface
+                .callbacks
+                .iter()
+                .map(|cb| &cb.name)
+                .chain([destroy_name, ctx_name])
+                .map(|name| format!("ValueLayout.ADDRESS.withName(\"{}\")", name))
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!(
+                "static final StructLayout {} = NativeMemory.struct({});",
+                layout, members
+            ))?;
+
+            for cb in &interface.callbacks {
+                f.writeln(&format!(
+                    "static final MemorySegment {}_{}_stub = NativeMemory.upcall(Interfaces.class, \"{}_{}\", {});",
+                    interface.name,
+                    cb.name,
+                    interface.name,
+                    cb.name,
+                    callback_descriptor(cb)
+                ))?;
+            }
+
+            f.newline()?;
+
+            f.writeln(&format!(
+                "static MemorySegment {}_to_native(SegmentAllocator _arena, {} value)",
+                interface.name, interface_name
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "MemorySegment _native = _arena.allocate({});",
+                    layout
+                ))?;
+                for cb in &interface.callbacks {
+                    f.writeln(&format!(
+                        "{}.set(ValueLayout.ADDRESS, 0, {}_{}_stub);",
+                        field_segment(&layout, &cb.name),
+                        interface.name,
+                        cb.name
+                    ))?;
+                }
+                f.writeln(&format!(
+                    "{}.set(ValueLayout.ADDRESS, 0, NativeMemory.RELEASE);",
+                    field_segment(&layout, destroy_name)
+                ))?;
+                f.writeln(&format!(
+                    "{}.set(ValueLayout.ADDRESS, 0, MemorySegment.ofAddress(NativeMemory.register(value)));",
+                    field_segment(&layout, ctx_name)
+                ))?;
+                f.writeln("return _native;")
+            })?;
+
+            for cb in &interface.callbacks {
+                f.newline()?;
+                write_upcall(f, &interface.name, cb)?;
+            }
+
+            if !last {
+                f.newline()?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn write_upcall(
+    f: &mut dyn Printer,
+    interface_name: &Name,
+    cb: &CallbackFunction<Validated>,
+) -> FormattingResult<()> {
+    let params = cb
+        .arguments
+        .iter()
+        .map(|arg| {
+            format!(
+                "{} {}",
+                arg.arg_type.layout().carrier(),
+                arg.name.mixed_case(f.naming())
+            )
+        })
+        .chain(std::iter::once("MemorySegment _ctx".to_string()))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let return_carrier = match cb.return_type.get_value() {
+        None => "void",
+        Some(x) => x.layout().carrier(),
+    };
+
+    f.writeln(&format!(
+        "static {} {}_{}({})",
+        return_carrier, interface_name, cb.name, params
+    ))?;
+    blocked(f, |f| {
+        f.writeln("try")?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "{} _impl = ({}) NativeMemory.lookup(_ctx);",
+                interface_name.camel_case(f.naming()),
+                interface_name.camel_case(f.naming())
+            ))?;
+
+            let args = cb
+                .arguments
+                .iter()
+                .map(|arg| {
+                    arg.arg_type
+                        .convert_from_native(&arg.name.mixed_case(f.naming()), f.naming())
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            let invocation = format!("_impl.{}({})", cb.name.mixed_case(f.naming()), args);
+
+            match cb.return_type.get_value() {
+                None => f.writeln(&format!("{};", invocation)),
+                Some(rt) => {
+                    if let CallbackReturnValue::Struct(_) = rt {
+                        // the returned struct is copied by the upcall stub
+                        f.writeln("Arena _arena = Arena.ofAuto();")?;
+                    }
+                    f.writeln(&format!(
+                        "{} _result = {};",
+                        rt.as_java_primitive(f.naming()),
+                        invocation
+                    ))?;
+                    f.writeln(&format!("return {};", rt.convert_to_native("_result")))
+                }
+            }
+        })?;
+        // an exception that escapes the upcall stub terminates the JVM
+        f.writeln("catch (Throwable _ex)")?;
+        blocked(f, |f| {
+            f.writeln("Thread _thread = Thread.currentThread();")?;
+            f.writeln("_thread.getUncaughtExceptionHandler().uncaughtException(_thread, _ex);")?;
+            match cb.return_type.get_value() {
+                None => Ok(()),
+                Some(rt) => {
+                    let default = cb
+                        .default_implementation
+                        .as_ref()
+                        .and_then(|x| x.get_optional_constant_value(f.naming()));
+                    match default {
+                        Some(value) => {
+                            if let CallbackReturnValue::Struct(_) = rt {
+                                f.writeln("Arena _arena = Arena.ofAuto();")?;
+                            }
+                            f.writeln(&format!("return {};", rt.convert_to_native(&value)))
+                        }
+                        None => f.writeln(&format!("return {};", rt.layout().zero())),
+                    }
+                }
+            }
+        })
+    })
+}
+
+fn write_collections(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("// conversions from Java lists to C collections")?;
+    f.writeln("static final class Collections")?;
+    blocked(f, |f| {
+        for (col, last) in lib.collections().with_last() {
+            let size = if col.has_reserve { "value.size()" } else { "" };
+
+            f.writeln(&format!(
+                "static MemorySegment {}_to_native(SegmentAllocator _arena, {} value) throws Throwable",
+                col.name(),
+                col.as_java_primitive(f.naming())
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "MemorySegment _collection = (MemorySegment) Handles.{}.invoke({});",
+                    col.create_func.name, size
+                ))?;
+                f.writeln("try")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "for ({} _item : value)",
+                        col.item_type.as_java_object(f.naming())
+                    ))?;
+                    blocked(f, |f| {
+                        f.writeln(&format!(
+                            "Handles.{}.invoke(_collection, {});",
+                            col.add_func.name,
+                            col.item_type.convert_to_native("_item")
+                        ))
+                    })
+                })?;
+                f.writeln("catch (Throwable _t)")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "Handles.{}.invoke(_collection);",
+                        col.delete_func.name
+                    ))?;
+                    f.writeln("throw _t;")
+                })?;
+                f.writeln("return _collection;")
+            })?;
+
+            f.newline()?;
+
+            f.writeln(&format!(
+                "static void {}_destroy(MemorySegment _collection) throws Throwable",
+                col.name()
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "Handles.{}.invoke(_collection);",
+                    col.delete_func.name
+                ))
+            })?;
+
+            if !last {
+                f.newline()?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn write_iterators(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("// conversions from C iterators to Java lists")?;
+    f.writeln("static final class Iterators")?;
+    blocked(f, |f| {
+        for (iter, last) in lib.iterators().with_last() {
+            f.writeln(&format!(
+                "static {} {}_to_list(MemorySegment _iter) throws Throwable",
+                iter.as_java_primitive(f.naming()),
+                iter.iter_class.name
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "{} _list = new java.util.ArrayList<>();",
+                    iter.as_java_primitive(f.naming())
+                ))?;
+                f.writeln("while (true)")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "MemorySegment _next = (MemorySegment) Handles.{}.invoke(_iter);",
+                        iter.next_function.name
+                    ))?;
+                    f.writeln("if (_next.address() == 0)")?;
+                    blocked(f, |f| f.writeln("return _list;"))?;
+                    let item = match &iter.item_type {
+                        IteratorItemType::Primitive(x) => {
+                            let layout = x.layout().expr();
+                            x.convert_from_native(
+                                &format!(
+                                    "_next.reinterpret({}.byteSize()).get({}, 0)",
+                                    layout, layout
+                                ),
+                                f.naming(),
+                            )
+                        }
+                        IteratorItemType::Struct(x) => {
+                            format!("Structs.{}_from_pointer(_next)", x.name())
+                        }
+                    };
+                    f.writeln(&format!("_list.add({});", item))
+                })
+            })?;
+
+            if !last {
+                f.newline()?;
+            }
+        }
+        Ok(())
+    })
+}
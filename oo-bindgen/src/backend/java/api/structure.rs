@@ -3,63 +3,98 @@ use super::*;
 
 use crate::backend::java::api::nullable::IsStruct;
 
-fn constructor_visibility(struct_type: Visibility) -> &'static str {
+fn constructor_visibility(
+    struct_type: Visibility,
+    module: &Module,
+    interop: JavaInterop,
+) -> &'static str {
     match struct_type {
-        Visibility::Public => "public",
-        Visibility::Private => "private",
+        Visibility::Public => "public ",
+        Visibility::Private => interop.internal_visibility(module),
     }
 }
 
-fn field_visibility(struct_type: Visibility) -> &'static str {
-    match struct_type {
-        Visibility::Public => "public",
-        Visibility::Private => "private final",
+fn field_visibility<T>(st: &Struct<T, Validated>, interop: JavaInterop) -> String
+where
+    T: StructFieldType,
+{
+    match st.visibility {
+        Visibility::Public if st.is_immutable() => "public final".to_string(),
+        Visibility::Public => "public".to_string(),
+        Visibility::Private => format!("{}final", interop.internal_visibility(st.module())),
     }
 }
 
 fn get_field_value<T>(
     field: &StructField<T, Validated>,
     constructor: &Initializer<Validated>,
+    naming: &Naming,
 ) -> String
 where
     T: StructFieldType,
 {
-    match constructor.values.iter().find(|x| x.name == field.name) {
-        Some(x) => match &x.value {
-            ValidatedDefaultValue::Bool(x) => x.to_string(),
-            ValidatedDefaultValue::Number(x) => match x {
-                NumberValue::U8(x) => format!("UByte.valueOf({})", x),
-                NumberValue::S8(x) => format!("(byte) {}", x),
-                NumberValue::U16(x) => format!("UShort.valueOf({})", x),
-                NumberValue::S16(x) => format!("(short) {}", x),
-                NumberValue::U32(x) => format!("UInteger.valueOf({}L)", x),
-                NumberValue::S32(x) => x.to_string(),
-                NumberValue::U64(x) => format!("ULong.valueOf({}L)", x),
-                NumberValue::S64(x) => x.to_string(),
-                NumberValue::Float(x) => format!("{}F", x),
-                NumberValue::Double(x) => x.to_string(),
-            },
-            ValidatedDefaultValue::Duration(t, x) => match t {
-                DurationType::Milliseconds => {
-                    format!("java.time.Duration.ofMillis({})", t.get_value_string(*x))
-                }
-                DurationType::Seconds => {
-                    format!("java.time.Duration.ofSeconds({})", t.get_value_string(*x))
-                }
-            },
-            ValidatedDefaultValue::Enum(x, variant) => {
-                format!("{}.{}", x.name.camel_case(), variant.capital_snake_case())
+    get_initialized_value(field, constructor, naming)
+        .unwrap_or_else(|| field.name.mixed_case(naming))
+}
+
+fn get_initialized_value<T>(
+    field: &StructField<T, Validated>,
+    constructor: &Initializer<Validated>,
+    naming: &Naming,
+) -> Option<String>
+where
+    T: StructFieldType,
+{
+    constructor
+        .values
+        .iter()
+        .find(|x| x.name == field.name)
+        .map(|x| default_value_expression(&x.value, naming))
+}
+
+/// Java expression that evaluates to a default value
+pub(crate) fn default_value_expression(value: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => number_literal(x),
+        ValidatedDefaultValue::Duration(t, x) => match t {
+            DurationType::Milliseconds => {
+                format!("java.time.Duration.ofMillis({})", t.get_value_string(*x))
             }
-            ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
-            ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
-                format!("new {}()", handle.name().camel_case(),)
+            DurationType::Seconds => {
+                format!("java.time.Duration.ofSeconds({})", t.get_value_string(*x))
             }
         },
-        None => field.name.mixed_case(),
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!(
+                "{}.{}",
+                x.name.camel_case(naming),
+                variant.capital_snake_case()
+            )
+        }
+        ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
+        ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
+            format!("new {}()", handle.name().camel_case(naming),)
+        }
     }
 }
 
-fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
+pub(crate) fn number_literal(value: &NumberValue) -> String {
+    match value {
+        NumberValue::U8(x) => format!("UByte.valueOf({})", x),
+        NumberValue::S8(x) => format!("(byte) {}", x),
+        NumberValue::U16(x) => format!("UShort.valueOf({})", x),
+        NumberValue::S16(x) => format!("(short) {}", x),
+        NumberValue::U32(x) => format!("UInteger.valueOf({}L)", x),
+        NumberValue::S32(x) => x.to_string(),
+        NumberValue::U64(x) => format!("ULong.valueOf({}L)", x),
+        NumberValue::S64(x) => format!("{}L", x),
+        NumberValue::Float(x) => format!("{}F", x),
+        NumberValue::Double(x) => x.to_string(),
+    }
+}
+
+pub(crate) fn get_default_value_doc(x: &ValidatedDefaultValue, naming: &Naming) -> String {
     match x {
         ValidatedDefaultValue::Bool(x) => x.to_string(),
         ValidatedDefaultValue::Number(x) => x.to_string(),
@@ -69,12 +104,12 @@ fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
         ValidatedDefaultValue::Duration(DurationType::Seconds, x) => format!("{}s", x.as_secs()),
         ValidatedDefaultValue::Enum(x, variant) => format!(
             "{{@link {}#{}}}",
-            x.name.camel_case(),
+            x.name.camel_case(naming),
             variant.capital_snake_case()
         ),
         ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
         ValidatedDefaultValue::DefaultStruct(x, _, _) => {
-            format!("Default {{@link {}}}", x.name().camel_case())
+            format!("Default {{@link {}}}", x.name().camel_case(naming))
         }
     }
 }
@@ -98,9 +133,9 @@ where
             for value in constructor.values.iter() {
                 f.writeln(&format!(
                     "<li> {{@link {}#{}}} : {}</li>",
-                    handle.name().camel_case(),
-                    value.name.mixed_case(),
-                    get_default_value_doc(&value.value)
+                    handle.name().camel_case(f.naming()),
+                    value.name.mixed_case(f.naming()),
+                    get_default_value_doc(&value.value, f.naming())
                 ))?;
             }
             f.writeln("</ul><p>")?;
@@ -109,14 +144,14 @@ where
         f.newline()?;
 
         for field in handle.initializer_args(constructor.clone()) {
-            f.writeln(&format!("@param {} ", field.name.mixed_case()))?;
+            f.writeln(&format!("@param {} ", field.name.mixed_case(f.naming())))?;
             docstring_print(f, &field.doc.brief)?;
         }
 
         if write_return_info {
             f.writeln(&format!(
                 "@return Initialized {{@link {}}} instance",
-                handle.name().camel_case()
+                handle.name().camel_case(f.naming())
             ))?;
         }
 
@@ -136,22 +171,22 @@ where
 
     let invocation_args = handle
         .fields()
-        .map(|sf| get_field_value(sf, constructor))
+        .map(|sf| get_field_value(sf, constructor, f.naming()))
         .collect::<Vec<String>>()
         .join(", ");
 
     f.writeln(&format!(
         "public static {} {}({})",
-        handle.name().camel_case(),
-        constructor.name.mixed_case(),
-        constructor_args(handle, constructor)
+        handle.name().camel_case(f.naming()),
+        constructor.name.mixed_case(f.naming()),
+        constructor_args(handle, constructor, f.naming())
     ))?;
 
     blocked(f, |f| {
         indented(f, |f| {
             f.writeln(&format!(
                 "return new {}({});",
-                handle.name().camel_case(),
+                handle.name().camel_case(f.naming()),
                 invocation_args
             ))
         })
@@ -161,6 +196,7 @@ where
 fn constructor_args<T>(
     handle: &Struct<T, Validated>,
     constructor: &Handle<Initializer<Validated>>,
+    naming: &Naming,
 ) -> String
 where
     T: StructFieldType + JavaType,
@@ -170,8 +206,8 @@ where
         .map(|sf| {
             format!(
                 "{} {}",
-                sf.field_type.as_java_primitive(),
-                sf.name.mixed_case()
+                sf.field_type.as_java_primitive(naming),
+                sf.name.mixed_case(naming)
             )
         })
         .collect::<Vec<String>>()
@@ -183,6 +219,7 @@ fn write_constructor<T>(
     visibility: Visibility,
     handle: &Struct<T, Validated>,
     constructor: &Handle<Initializer<Validated>>,
+    interop: JavaInterop,
 ) -> FormattingResult<()>
 where
     T: StructFieldType + JavaType,
@@ -192,23 +229,25 @@ where
     }
 
     let visibility = match visibility {
-        Visibility::Public => constructor_visibility(handle.visibility),
-        Visibility::Private => constructor_visibility(Visibility::Private),
+        Visibility::Public => constructor_visibility(handle.visibility, handle.module(), interop),
+        Visibility::Private => {
+            constructor_visibility(Visibility::Private, handle.module(), interop)
+        }
     };
 
     f.writeln(&format!(
-        "{} {}({})",
+        "{}{}({})",
         visibility,
-        handle.name().camel_case(),
-        constructor_args(handle, constructor)
+        handle.name().camel_case(f.naming()),
+        constructor_args(handle, constructor, f.naming())
     ))?;
     blocked(f, |f| {
         for field in &handle.fields {
             indented(f, |f| {
                 f.writeln(&format!(
                     "this.{} = {};",
-                    field.name.mixed_case(),
-                    get_field_value(field, constructor)
+                    field.name.mixed_case(f.naming()),
+                    get_field_value(field, constructor, f.naming())
                 ))
             })?;
         }
@@ -218,21 +257,31 @@ where
     Ok(())
 }
 
-fn write_null_checker<T>(f: &mut dyn Printer, handle: &Struct<T, Validated>) -> FormattingResult<()>
+fn write_field_checker<T>(
+    f: &mut dyn Printer,
+    handle: &Struct<T, Validated>,
+    glue_visibility: &str,
+    patterns: &[&str],
+) -> FormattingResult<()>
 where
     T: StructFieldType + Nullable + IsStruct,
 {
-    f.writeln("void _assertFieldsNotNull()")?;
+    f.writeln(&format!("{}void _assertFieldsValid()", glue_visibility))?;
     blocked(f, |f| {
         for field in handle.fields.iter() {
+            let field_name = field.name.mixed_case(f.naming());
             if field.field_type.is_nullable() {
-                let field_name = field.name.mixed_case();
                 f.writeln(&format!(
                     "java.util.Objects.requireNonNull({}, \"{} cannot be null\");",
                     field_name, field_name
                 ))?;
                 if field.field_type.is_struct() {
-                    f.writeln(&format!("{}._assertFieldsNotNull();", field_name))?;
+                    f.writeln(&format!("{}._assertFieldsValid();", field_name))?;
+                }
+            }
+            if let Some(target) = field.field_type.constrained_type() {
+                for constraint in &field.constraints {
+                    write_constraint_check(f, constraint, target, &field_name, patterns)?;
                 }
             }
         }
@@ -240,15 +289,178 @@ where
     })
 }
 
+fn write_builder<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + JavaType,
+{
+    let struct_name = st.name().camel_case(f.naming());
+    let defaults = st.get_default_initializer();
+
+    documentation(f, |f| {
+        f.writeln(&format!(
+            "Create a {{@link Builder}} for {{@link {}}}",
+            struct_name
+        ))?;
+        if defaults.is_some() {
+            f.newline()?;
+            f.writeln("<p>Values start out with the same defaults as the default constructor</p>")?;
+        }
+        f.newline()?;
+        f.writeln("@return new builder instance")
+    })?;
+    f.writeln("public static Builder builder()")?;
+    blocked(f, |f| f.writeln("return new Builder();"))?;
+
+    f.newline()?;
+
+    documentation(f, |f| {
+        f.writeln(&format!(
+            "Builder used to construct immutable instances of {{@link {}}}",
+            struct_name
+        ))
+    })?;
+    f.writeln("public static final class Builder")?;
+    blocked(f, |f| {
+        for field in st.fields() {
+            let value = defaults
+                .and_then(|x| get_initialized_value(field, x, f.naming()))
+                .unwrap_or_else(|| "null".to_string());
+            f.writeln(&format!(
+                "private {} {} = {};",
+                field.field_type.as_java_object(f.naming()),
+                field.name.mixed_case(f.naming()),
+                value
+            ))?;
+        }
+
+        f.newline()?;
+        f.writeln("private Builder() {}")?;
+
+        for field in st.fields() {
+            f.newline()?;
+            documentation(f, |f| {
+                javadoc_print(f, &field.doc)?;
+                Ok(())
+            })?;
+            deprecated_annotation(f, &field.doc)?;
+            f.writeln(&format!(
+                "public Builder with{}({} value)",
+                field.name.camel_case(f.naming()),
+                field.field_type.as_java_primitive(f.naming()),
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "this.{} = value;",
+                    field.name.mixed_case(f.naming())
+                ))?;
+                f.writeln("return this;")
+            })?;
+        }
+
+        f.newline()?;
+        documentation(f, |f| {
+            f.writeln(&format!(
+                "Construct an instance of {{@link {}}} from the values of the builder",
+                struct_name
+            ))?;
+            f.newline()?;
+            f.writeln(&format!("@return new {{@link {}}} instance", struct_name))
+        })?;
+        f.writeln(&format!("public {} build()", struct_name))?;
+        blocked(f, |f| {
+            for field in st.fields() {
+                if defaults.is_none() {
+                    let field_name = field.name.mixed_case(f.naming());
+                    f.writeln(&format!(
+                        "java.util.Objects.requireNonNull({}, \"{} must be set\");",
+                        field_name, field_name
+                    ))?;
+                }
+            }
+            let args = st
+                .fields()
+                .map(|x| x.name.mixed_case(f.naming()))
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!("return new {}({});", struct_name, args))
+        })
+    })
+}
+
+fn write_value_semantics<T>(
+    f: &mut dyn Printer,
+    handle: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType,
+{
+    let struct_name = handle.name().camel_case(f.naming());
+
+    f.writeln("@Override")?;
+    f.writeln("public boolean equals(Object other)")?;
+    blocked(f, |f| {
+        f.writeln("if (this == other) return true;")?;
+        f.writeln("if (other == null || getClass() != other.getClass()) return false;")?;
+        if handle.fields.is_empty() {
+            return f.writeln("return true;");
+        }
+        f.writeln(&format!("{} that = ({}) other;", struct_name, struct_name))?;
+        f.writeln("return")?;
+        indented(f, |f| {
+            for (field, last) in handle.fields.iter().with_last() {
+                let field_name = field.name.mixed_case(f.naming());
+                let terminator = if last { ";" } else { " &&" };
+                f.writeln(&format!(
+                    "java.util.Objects.equals(this.{}, that.{}){}",
+                    field_name, field_name, terminator
+                ))?;
+            }
+            Ok(())
+        })
+    })?;
+
+    f.newline()?;
+
+    let field_names = handle
+        .fields
+        .iter()
+        .map(|x| x.name.mixed_case(f.naming()))
+        .collect::<Vec<String>>();
+
+    f.writeln("@Override")?;
+    f.writeln("public int hashCode()")?;
+    blocked(f, |f| {
+        f.writeln(&format!(
+            "return java.util.Objects.hash({});",
+            field_names.join(", ")
+        ))
+    })?;
+
+    f.newline()?;
+
+    f.writeln("@Override")?;
+    f.writeln("public String toString()")?;
+    blocked(f, |f| {
+        let values = field_names
+            .iter()
+            .map(|name| format!("{}=\" + {} + \"", name, name))
+            .collect::<Vec<String>>()
+            .join(", ");
+        f.writeln(&format!("return \"{}{{{}}}\";", struct_name, values))
+    })
+}
+
 pub(crate) fn generate<T>(
     f: &mut dyn Printer,
     st: &Struct<T, Validated>,
     generate_builder_methods: bool,
+    interop: JavaInterop,
+    glue_visibility: &str,
 ) -> FormattingResult<()>
 where
     T: StructFieldType + JavaType + Nullable + IsStruct,
 {
-    let struct_name = st.name().camel_case();
+    let struct_name = st.name().camel_case(f.naming());
 
     let doc = match st.visibility {
         Visibility::Public => st.doc.clone(),
@@ -270,12 +482,13 @@ where
                 javadoc_print(f, &field.doc)?;
                 Ok(())
             })?;
+            deprecated_annotation(f, &field.doc)?;
 
             f.writeln(&format!(
                 "{} {} {};",
-                field_visibility(st.visibility),
-                field.field_type.as_java_primitive(),
-                field.name.mixed_case()
+                field_visibility(st, interop),
+                field.field_type.as_java_primitive(f.naming()),
+                field.name.mixed_case(f.naming())
             ))?;
         }
 
@@ -286,16 +499,36 @@ where
                     javadoc_print(f, &field.doc)?;
                     Ok(())
                 })?;
+                deprecated_annotation(f, &field.doc)?;
 
                 f.writeln(&format!(
                     "public {} with{}({} value)",
                     struct_name,
-                    field.name.camel_case(),
-                    field.field_type.as_java_primitive(),
+                    field.name.camel_case(f.naming()),
+                    field.field_type.as_java_primitive(f.naming()),
                 ))?;
                 blocked(f, |f| {
-                    f.writeln(&format!("this.{} = value;", field.name.mixed_case(),))?;
-                    f.writeln("return this;")
+                    if st.is_immutable() {
+                        // immutable structs return a modified copy
+                        let args = st
+                            .fields()
+                            .map(|x| {
+                                if x.name == field.name {
+                                    "value".to_string()
+                                } else {
+                                    format!("this.{}", x.name.mixed_case(f.naming()))
+                                }
+                            })
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        f.writeln(&format!("return new {}({});", struct_name, args))
+                    } else {
+                        f.writeln(&format!(
+                            "this.{} = value;",
+                            field.name.mixed_case(f.naming()),
+                        ))?;
+                        f.writeln("return this;")
+                    }
                 })?;
             }
         }
@@ -304,7 +537,7 @@ where
             f.newline()?;
             match &c.initializer_type {
                 InitializerType::Normal => {
-                    write_constructor(f, Visibility::Public, st, c)?;
+                    write_constructor(f, Visibility::Public, st, c, interop)?;
                 }
                 InitializerType::Static => {
                     write_static_method_constructor(f, st, c)?;
@@ -319,10 +552,28 @@ where
             ));
 
             f.newline()?;
-            write_constructor(f, Visibility::Private, st, &constructor)?;
+            write_constructor(f, Visibility::Private, st, &constructor, interop)?;
+        }
+
+        if st.visibility == Visibility::Public && st.is_immutable() && generate_builder_methods {
+            f.newline()?;
+            write_builder(f, st)?;
+        }
+
+        let patterns = regex_patterns(st.fields().flat_map(|x| x.constraints.iter()));
+        if !patterns.is_empty() {
+            f.newline()?;
+            write_regex_patterns(f, &patterns)?;
         }
 
         f.newline()?;
-        write_null_checker(f, st)
+        write_field_checker(f, st, glue_visibility, &patterns)?;
+
+        if st.has_value_semantics() {
+            f.newline()?;
+            write_value_semantics(f, st)?;
+        }
+
+        Ok(())
     })
 }
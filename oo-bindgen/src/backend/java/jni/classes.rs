@@ -1,6 +1,6 @@
 use crate::model::Library;
 
-use crate::backend::java::jni::JniBindgenConfig;
+use crate::backend::java::jni::{class_path, JniBindgenConfig};
 use crate::backend::*;
 
 pub(crate) fn generate_classes_cache(
@@ -19,7 +19,7 @@ pub(crate) fn generate_classes_cache(
     f.writeln("}")?;
 
     for class in lib.classes() {
-        let class_name = class.name().camel_case();
+        let class_name = class.name().camel_case(f.naming());
         f.newline()?;
         f.writeln(&format!("pub(crate) struct {} {{", class_name))?;
         indented(f, |f| f.writeln("info: ClassInfo"))?;
@@ -29,10 +29,16 @@ pub(crate) fn generate_classes_cache(
         indented(f, |f| {
             f.writeln("fn init(env: &jni::JNIEnv) -> Self {")?;
             indented(f, |f| {
-                let class_name = class.name().camel_case();
+                let class_name = class.name().camel_case(f.naming());
                 f.writeln(&format!(
-                    "let class = env.find_class(\"L{}/{};\").expect(\"Unable to find class {}\");",
-                    lib_path, class_name, class_name
+                    "let class = env.find_class(\"L{};\").expect(\"Unable to find class {}\");",
+                    class_path(
+                        &lib_path,
+                        &class.declaration.module,
+                        class.name(),
+                        f.naming()
+                    ),
+                    class_name
                 ))?;
                 f.writeln(&format!("let constructor = env.get_method_id(class, \"<init>\", \"(J)V\").map(|mid| mid.into_inner().into()).expect(\"Unable to find constructor of {}\");", class_name))?;
                 f.writeln(&format!("let self_field = env.get_field_id(class, \"self\", \"J\").map(|mid| mid.into_inner().into()).expect(\"Unable to find self field of {}\");", class_name))?;
@@ -46,7 +52,7 @@ pub(crate) fn generate_classes_cache(
 
             f.newline()?;
 
-            f.writeln(&format!("pub(crate) fn to_rust(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> *mut {}::{} {{", config.ffi_name, class_name))?;
+            f.writeln(&format!("pub(crate) fn to_rust(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> *mut {}::{} {{", config.ffi_name, class.name().ffi_camel_case()))?;
             indented(f, |f| {
                 f.writeln("env.get_field_unchecked(obj, self.info.self_field, jni::signature::JavaType::Primitive(jni::signature::Primitive::Long)).unwrap().j().unwrap() as *mut _")
             })?;
@@ -54,7 +60,7 @@ pub(crate) fn generate_classes_cache(
 
             f.newline()?;
 
-            f.writeln(&format!("pub(crate) fn to_jni(&self, env: &jni::JNIEnv, value: *mut {}::{}) -> jni::sys::jobject {{", config.ffi_name, class_name))?;
+            f.writeln(&format!("pub(crate) fn to_jni(&self, env: &jni::JNIEnv, value: *mut {}::{}) -> jni::sys::jobject {{", config.ffi_name, class.name().ffi_camel_case()))?;
             indented(f, |f| {
                 f.writeln("env.new_object_unchecked(&self.info.class, self.info.constructor, &[jni::objects::JValue::Long(value as i64)]).unwrap().into_inner()")
             })?;
@@ -71,7 +77,7 @@ pub(crate) fn generate_classes_cache(
             f.writeln(&format!(
                 "pub(crate) {}: {},",
                 class.name(),
-                class.name().camel_case()
+                class.name().camel_case(f.naming())
             ))?;
         }
 
@@ -91,7 +97,7 @@ pub(crate) fn generate_classes_cache(
                     f.writeln(&format!(
                         "{}: {}::init(env),",
                         class.name(),
-                        class.name().camel_case()
+                        class.name().camel_case(f.naming())
                     ))?;
                 }
                 Ok(())
@@ -21,12 +21,12 @@ pub(crate) trait ConvertibleToRust {
     /// This usually takes the form of a shadowed variable
     ///
     /// **note:** This should only be used in contexts where primitives are unboxed.
-    fn to_rust(&self, expr: &str) -> Option<String>;
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String>;
 
     /// Same as `to_rust` but used in contexts where primitives are always boxed, e.g.
     /// when converting values in a Java list to a native collection type
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
-        self.to_rust(expr)
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
+        self.to_rust(expr, naming)
     }
 
     /// Optional, convert an expression to another type at the function call site
@@ -34,7 +34,7 @@ pub(crate) trait ConvertibleToRust {
 }
 
 impl ConvertibleToRust for StringType {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         Some(format!("_env.get_string({}.into()).unwrap()", expr))
     }
 
@@ -44,7 +44,7 @@ impl ConvertibleToRust for StringType {
 }
 
 impl ConvertibleToRust for Primitive {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         match self {
             Primitive::Bool => Some(format!("{} != 0", expr)),
             Primitive::U8 => Some(format!("_cache.unsigned.byte.to_rust(&_env, {})", expr)),
@@ -60,16 +60,16 @@ impl ConvertibleToRust for Primitive {
         }
     }
 
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
             Primitive::Bool => Some(format!("_cache.primitives.boolean.value(&_env, {})", expr)),
-            Primitive::U8 => self.to_rust(expr),
+            Primitive::U8 => self.to_rust(expr, naming),
             Primitive::S8 => Some(format!("_cache.primitives.byte.value(&_env, {})", expr)),
-            Primitive::U16 => self.to_rust(expr),
+            Primitive::U16 => self.to_rust(expr, naming),
             Primitive::S16 => Some(format!("_cache.primitives.short.value(&_env, {})", expr)),
-            Primitive::U32 => self.to_rust(expr),
+            Primitive::U32 => self.to_rust(expr, naming),
             Primitive::S32 => Some(format!("_cache.primitives.integer.value(&_env, {})", expr)),
-            Primitive::U64 => self.to_rust(expr),
+            Primitive::U64 => self.to_rust(expr, naming),
             Primitive::S64 => Some(format!("_cache.primitives.long.value(&_env, {})", expr)),
             Primitive::Float => Some(format!("_cache.primitives.float.value(&_env, {})", expr)),
             Primitive::Double => Some(format!("_cache.primitives.double.value(&_env, {})", expr)),
@@ -94,7 +94,7 @@ impl ConvertibleToRust for Primitive {
 }
 
 impl ConvertibleToRust for DurationType {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         Some(match self {
             DurationType::Milliseconds => {
                 format!("_cache.duration.to_rust_millis(&_env, {})", expr)
@@ -111,7 +111,7 @@ impl ConvertibleToRust for DurationType {
 }
 
 impl ConvertibleToRust for Handle<Enum<Unvalidated>> {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         Some(format!(
             "_cache.enums.{}.to_rust(&_env, {})",
             self.name, expr
@@ -124,19 +124,19 @@ impl ConvertibleToRust for Handle<Enum<Unvalidated>> {
 }
 
 impl ConvertibleToRust for BasicType {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            BasicType::Primitive(x) => x.to_rust(expr),
-            BasicType::Duration(x) => x.to_rust(expr),
-            BasicType::Enum(x) => x.to_rust(expr),
+            BasicType::Primitive(x) => x.to_rust(expr, naming),
+            BasicType::Duration(x) => x.to_rust(expr, naming),
+            BasicType::Enum(x) => x.to_rust(expr, naming),
         }
     }
 
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            BasicType::Primitive(x) => x.to_rust_from_object(expr),
-            BasicType::Duration(x) => x.to_rust_from_object(expr),
-            BasicType::Enum(x) => x.to_rust_from_object(expr),
+            BasicType::Primitive(x) => x.to_rust_from_object(expr, naming),
+            BasicType::Duration(x) => x.to_rust_from_object(expr, naming),
+            BasicType::Enum(x) => x.to_rust_from_object(expr, naming),
         }
     }
 
@@ -150,11 +150,11 @@ impl ConvertibleToRust for BasicType {
 }
 
 impl ConvertibleToRust for CollectionHandle {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
         // create the helper guard object that allocates and fills the native collection from the list
         Some(format!(
             "collections::{}::new(_env, {}).unwrap()",
-            self.collection_class.name.camel_case(),
+            self.collection_class.name.camel_case(naming),
             expr
         ))
     }
@@ -166,8 +166,8 @@ impl ConvertibleToRust for CollectionHandle {
 }
 
 impl ConvertibleToRust for AsynchronousInterface {
-    fn to_rust(&self, expr: &str) -> Option<String> {
-        self.inner.to_rust(expr)
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
+        self.inner.to_rust(expr, naming)
     }
 
     fn call_site(&self, expr: &str) -> Option<String> {
@@ -176,7 +176,7 @@ impl ConvertibleToRust for AsynchronousInterface {
 }
 
 impl ConvertibleToRust for InterfaceHandle {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         Some(format!(
             "_cache.interfaces.{}.to_rust(&_env, {})",
             self.name, expr
@@ -189,7 +189,7 @@ impl ConvertibleToRust for InterfaceHandle {
 }
 
 impl ConvertibleToRust for FunctionArgStructDeclaration {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         Some(format!(
             "_cache.structs.{}.to_rust(_cache, &_env, {})",
             self.inner.name, expr
@@ -206,7 +206,7 @@ impl<T> ConvertibleToRust for Handle<Struct<T, Unvalidated>>
 where
     T: StructFieldType,
 {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         Some(format!(
             "_cache.structs.{}.to_rust(_cache, &_env, {})",
             self.name(),
@@ -220,17 +220,17 @@ where
 }
 
 impl ConvertibleToRust for UniversalOr<FunctionArgStructField> {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalOr::Specific(x) => x.to_rust(expr),
-            UniversalOr::Universal(x) => x.to_rust(expr),
+            UniversalOr::Specific(x) => x.to_rust(expr, naming),
+            UniversalOr::Universal(x) => x.to_rust(expr, naming),
         }
     }
 
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalOr::Specific(x) => x.to_rust_from_object(expr),
-            UniversalOr::Universal(x) => x.to_rust_from_object(expr),
+            UniversalOr::Specific(x) => x.to_rust_from_object(expr, naming),
+            UniversalOr::Universal(x) => x.to_rust_from_object(expr, naming),
         }
     }
 
@@ -243,7 +243,7 @@ impl ConvertibleToRust for UniversalOr<FunctionArgStructField> {
 }
 
 impl ConvertibleToRust for ClassDeclarationHandle {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, _naming: &Naming) -> Option<String> {
         Some(format!(
             "_cache.classes.{}.to_rust(&_env, {})",
             self.name, expr
@@ -256,27 +256,27 @@ impl ConvertibleToRust for ClassDeclarationHandle {
 }
 
 impl ConvertibleToRust for FunctionArgument {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgument::Basic(x) => x.to_rust(expr),
-            FunctionArgument::String(x) => x.to_rust(expr),
-            FunctionArgument::Collection(x) => x.to_rust(expr),
-            FunctionArgument::Struct(x) => x.to_rust(expr),
-            FunctionArgument::StructRef(x) => x.to_rust(expr),
-            FunctionArgument::ClassRef(x) => x.to_rust(expr),
-            FunctionArgument::Interface(x) => x.to_rust(expr),
+            FunctionArgument::Basic(x) => x.to_rust(expr, naming),
+            FunctionArgument::String(x) => x.to_rust(expr, naming),
+            FunctionArgument::Collection(x) => x.to_rust(expr, naming),
+            FunctionArgument::Struct(x) => x.to_rust(expr, naming),
+            FunctionArgument::StructRef(x) => x.to_rust(expr, naming),
+            FunctionArgument::ClassRef(x) => x.to_rust(expr, naming),
+            FunctionArgument::Interface(x) => x.to_rust(expr, naming),
         }
     }
 
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgument::Basic(x) => x.to_rust_from_object(expr),
-            FunctionArgument::String(x) => x.to_rust_from_object(expr),
-            FunctionArgument::Collection(x) => x.to_rust_from_object(expr),
-            FunctionArgument::Struct(x) => x.to_rust_from_object(expr),
-            FunctionArgument::StructRef(x) => x.to_rust_from_object(expr),
-            FunctionArgument::ClassRef(x) => x.to_rust_from_object(expr),
-            FunctionArgument::Interface(x) => x.to_rust_from_object(expr),
+            FunctionArgument::Basic(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgument::String(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgument::Collection(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgument::Struct(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgument::StructRef(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgument::ClassRef(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgument::Interface(x) => x.to_rust_from_object(expr, naming),
         }
     }
 
@@ -294,17 +294,17 @@ impl ConvertibleToRust for FunctionArgument {
 }
 
 impl ConvertibleToRust for CallbackReturnValue {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            CallbackReturnValue::Basic(x) => x.to_rust(expr),
-            CallbackReturnValue::Struct(x) => x.to_rust(expr),
+            CallbackReturnValue::Basic(x) => x.to_rust(expr, naming),
+            CallbackReturnValue::Struct(x) => x.to_rust(expr, naming),
         }
     }
 
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            CallbackReturnValue::Basic(x) => x.to_rust_from_object(expr),
-            CallbackReturnValue::Struct(x) => x.to_rust_from_object(expr),
+            CallbackReturnValue::Basic(x) => x.to_rust_from_object(expr, naming),
+            CallbackReturnValue::Struct(x) => x.to_rust_from_object(expr, naming),
         }
     }
 
@@ -317,21 +317,21 @@ impl ConvertibleToRust for CallbackReturnValue {
 }
 
 impl ConvertibleToRust for FunctionArgStructField {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgStructField::Basic(x) => x.to_rust(expr),
-            FunctionArgStructField::String(x) => x.to_rust(expr),
-            FunctionArgStructField::Interface(x) => x.to_rust(expr),
-            FunctionArgStructField::Struct(x) => x.to_rust(expr),
+            FunctionArgStructField::Basic(x) => x.to_rust(expr, naming),
+            FunctionArgStructField::String(x) => x.to_rust(expr, naming),
+            FunctionArgStructField::Interface(x) => x.to_rust(expr, naming),
+            FunctionArgStructField::Struct(x) => x.to_rust(expr, naming),
         }
     }
 
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgStructField::Basic(x) => x.to_rust_from_object(expr),
-            FunctionArgStructField::String(x) => x.to_rust_from_object(expr),
-            FunctionArgStructField::Interface(x) => x.to_rust_from_object(expr),
-            FunctionArgStructField::Struct(x) => x.to_rust_from_object(expr),
+            FunctionArgStructField::Basic(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgStructField::String(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgStructField::Interface(x) => x.to_rust_from_object(expr, naming),
+            FunctionArgStructField::Struct(x) => x.to_rust_from_object(expr, naming),
         }
     }
 
@@ -346,17 +346,17 @@ impl ConvertibleToRust for FunctionArgStructField {
 }
 
 impl ConvertibleToRust for UniversalStructField {
-    fn to_rust(&self, expr: &str) -> Option<String> {
+    fn to_rust(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalStructField::Basic(x) => x.to_rust(expr),
-            UniversalStructField::Struct(x) => x.to_rust(expr),
+            UniversalStructField::Basic(x) => x.to_rust(expr, naming),
+            UniversalStructField::Struct(x) => x.to_rust(expr, naming),
         }
     }
 
-    fn to_rust_from_object(&self, expr: &str) -> Option<String> {
+    fn to_rust_from_object(&self, expr: &str, naming: &Naming) -> Option<String> {
         match self {
-            UniversalStructField::Basic(x) => x.to_rust_from_object(expr),
-            UniversalStructField::Struct(x) => x.to_rust_from_object(expr),
+            UniversalStructField::Basic(x) => x.to_rust_from_object(expr, naming),
+            UniversalStructField::Struct(x) => x.to_rust_from_object(expr, naming),
         }
     }
 
@@ -1,12 +1,12 @@
 use crate::model::*;
 
 pub(crate) trait GuardType {
-    fn guard_type(&self) -> Option<String>;
+    fn guard_type(&self, naming: &Naming) -> Option<String>;
     fn guard_transform(&self, expr: &str) -> Option<String>;
 }
 
 impl GuardType for BasicType {
-    fn guard_type(&self) -> Option<String> {
+    fn guard_type(&self, _naming: &Naming) -> Option<String> {
         None
     }
 
@@ -16,7 +16,7 @@ impl GuardType for BasicType {
 }
 
 impl GuardType for StringType {
-    fn guard_type(&self) -> Option<String> {
+    fn guard_type(&self, _naming: &Naming) -> Option<String> {
         Some("jni::strings::JavaStr<'a, 'a>".to_string())
     }
 
@@ -26,7 +26,7 @@ impl GuardType for StringType {
 }
 
 impl GuardType for AsynchronousInterface {
-    fn guard_type(&self) -> Option<String> {
+    fn guard_type(&self, _naming: &Naming) -> Option<String> {
         None
     }
 
@@ -39,8 +39,8 @@ impl<T> GuardType for Handle<Struct<T, Unvalidated>>
 where
     T: StructFieldType,
 {
-    fn guard_type(&self) -> Option<String> {
-        Some(format!("{}Guard<'a>", self.name().camel_case()))
+    fn guard_type(&self, naming: &Naming) -> Option<String> {
+        Some(format!("{}Guard<'a>", self.name().camel_case(naming)))
     }
 
     fn guard_transform(&self, expr: &str) -> Option<String> {
@@ -49,10 +49,10 @@ where
 }
 
 impl GuardType for UniversalOr<FunctionArgStructField> {
-    fn guard_type(&self) -> Option<String> {
+    fn guard_type(&self, naming: &Naming) -> Option<String> {
         match self {
-            UniversalOr::Specific(x) => x.guard_type(),
-            UniversalOr::Universal(x) => x.guard_type(),
+            UniversalOr::Specific(x) => x.guard_type(naming),
+            UniversalOr::Universal(x) => x.guard_type(naming),
         }
     }
 
@@ -65,12 +65,12 @@ impl GuardType for UniversalOr<FunctionArgStructField> {
 }
 
 impl GuardType for FunctionArgStructField {
-    fn guard_type(&self) -> Option<String> {
+    fn guard_type(&self, naming: &Naming) -> Option<String> {
         match self {
-            FunctionArgStructField::Basic(x) => x.guard_type(),
-            FunctionArgStructField::String(x) => x.guard_type(),
-            FunctionArgStructField::Interface(x) => x.guard_type(),
-            FunctionArgStructField::Struct(x) => x.guard_type(),
+            FunctionArgStructField::Basic(x) => x.guard_type(naming),
+            FunctionArgStructField::String(x) => x.guard_type(naming),
+            FunctionArgStructField::Interface(x) => x.guard_type(naming),
+            FunctionArgStructField::Struct(x) => x.guard_type(naming),
         }
     }
 
@@ -85,10 +85,10 @@ impl GuardType for FunctionArgStructField {
 }
 
 impl GuardType for UniversalStructField {
-    fn guard_type(&self) -> Option<String> {
+    fn guard_type(&self, naming: &Naming) -> Option<String> {
         match self {
-            UniversalStructField::Basic(x) => x.guard_type(),
-            UniversalStructField::Struct(x) => x.guard_type(),
+            UniversalStructField::Basic(x) => x.guard_type(naming),
+            UniversalStructField::Struct(x) => x.guard_type(naming),
         }
     }
 
@@ -1,3 +1,4 @@
+use crate::backend::java::jni::class_path;
 use crate::model::*;
 
 pub(crate) trait JniTypeId {
@@ -8,15 +9,15 @@ pub(crate) trait JniTypeId {
 /// Identifier which may be a fixed value or generated from type name in the library
 pub(crate) enum TypeId {
     Fixed(&'static str),
-    LibraryType(Name),
+    LibraryType(Module, Name),
 }
 
 impl TypeId {
-    pub(crate) fn as_string(&self, lib_path: &str) -> String {
+    pub(crate) fn as_string(&self, lib_path: &str, naming: &Naming) -> String {
         match self {
             TypeId::Fixed(x) => x.to_string(),
-            TypeId::LibraryType(name) => {
-                format!("L{}/{};", lib_path, name.camel_case())
+            TypeId::LibraryType(module, name) => {
+                format!("L{};", class_path(lib_path, module, name, naming))
             }
         }
     }
@@ -54,7 +55,7 @@ impl JniTypeId for DurationType {
 
 impl JniTypeId for EnumHandle {
     fn jni_type_id(&self) -> TypeId {
-        TypeId::LibraryType(self.name.clone())
+        TypeId::LibraryType(self.module.clone(), self.name.clone())
     }
 }
 
@@ -76,7 +77,7 @@ impl JniTypeId for AbstractIteratorHandle {
 
 impl JniTypeId for ClassDeclarationHandle {
     fn jni_type_id(&self) -> TypeId {
-        TypeId::LibraryType(self.name.clone())
+        TypeId::LibraryType(self.module.clone(), self.name.clone())
     }
 }
 
@@ -85,13 +86,13 @@ where
     T: StructFieldType,
 {
     fn jni_type_id(&self) -> TypeId {
-        TypeId::LibraryType(self.name().clone())
+        TypeId::LibraryType(self.module().clone(), self.name().clone())
     }
 }
 
 impl JniTypeId for UniversalStructHandle {
     fn jni_type_id(&self) -> TypeId {
-        TypeId::LibraryType(self.name().clone())
+        TypeId::LibraryType(self.module().clone(), self.name().clone())
     }
 }
 
@@ -127,7 +128,7 @@ impl JniTypeId for OptionalReturnType<CallbackReturnValue, Validated> {
 
 impl JniTypeId for InterfaceHandle {
     fn jni_type_id(&self) -> TypeId {
-        TypeId::LibraryType(self.name.clone())
+        TypeId::LibraryType(self.module.clone(), self.name.clone())
     }
 }
 
@@ -44,25 +44,25 @@ impl RustType for StringType {
 
 impl RustType for AbstractIteratorHandle {
     fn get_rust_type(&self, ffi_name: &str) -> String {
-        format!("*mut {}::{}", ffi_name, self.name().camel_case())
+        format!("*mut {}::{}", ffi_name, self.name().ffi_camel_case())
     }
 }
 
 impl RustType for ClassDeclarationHandle {
     fn get_rust_type(&self, ffi_name: &str) -> String {
-        format!("*mut {}::{}", ffi_name, self.name.camel_case())
+        format!("*mut {}::{}", ffi_name, self.name.ffi_camel_case())
     }
 }
 
 impl RustType for UniversalOr<CallbackArgStructField> {
     fn get_rust_type(&self, ffi_name: &str) -> String {
-        format!("{}::ffi::{}", ffi_name, self.name().camel_case())
+        format!("{}::ffi::{}", ffi_name, self.name().ffi_camel_case())
     }
 }
 
 impl RustType for UniversalStructHandle {
     fn get_rust_type(&self, ffi_name: &str) -> String {
-        format!("{}::ffi::{}", ffi_name, self.name().camel_case())
+        format!("{}::ffi::{}", ffi_name, self.name().ffi_camel_case())
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::backend::java::jni::JniBindgenConfig;
+use crate::backend::java::jni::{class_path, JniBindgenConfig};
 use crate::backend::*;
 use crate::model::Library;
 
@@ -16,7 +16,7 @@ pub(crate) fn generate_enums_cache(
             f.writeln(&format!(
                 "pub {}: {},",
                 enumeration.name,
-                enumeration.name.camel_case()
+                enumeration.name.camel_case(f.naming())
             ))?;
         }
 
@@ -35,7 +35,7 @@ pub(crate) fn generate_enums_cache(
                     f.writeln(&format!(
                         "{}: {}::init(env),",
                         enumeration.name,
-                        enumeration.name.camel_case()
+                        enumeration.name.camel_case(f.naming())
                     ))?;
                 }
                 Ok(())
@@ -45,8 +45,16 @@ pub(crate) fn generate_enums_cache(
 
     // Each enum implementation
     for enumeration in lib.enums() {
-        let enum_name = enumeration.name.camel_case();
-        let enum_sig = format!("\"L{}/{};\"", lib_path, enum_name);
+        let enum_name = enumeration.name.camel_case(f.naming());
+        let enum_sig = format!(
+            "\"L{};\"",
+            class_path(
+                &lib_path,
+                &enumeration.module,
+                &enumeration.name,
+                f.naming()
+            )
+        );
 
         f.writeln(&format!("pub struct {}", enum_name))?;
         blocked(f, |f| {
@@ -1,7 +1,7 @@
 use crate::backend::*;
 use crate::model::Library;
 
-use crate::backend::java::jni::JniBindgenConfig;
+use crate::backend::java::jni::{class_path, JniBindgenConfig};
 
 pub(crate) fn generate_exceptions_cache(
     f: &mut dyn Printer,
@@ -29,16 +29,30 @@ pub(crate) fn generate_exceptions_cache(
         })?;
         f.writeln("}")?;
         for error in lib.error_types() {
-            let camel_name = error.exception_name.camel_case();
-            let enum_name = error.inner.name.camel_case();
+            let camel_name = error.exception_name.camel_case(f.naming());
             f.newline()?;
             f.writeln(&format!(
                 "fn init_{}(env: &jni::JNIEnv) -> Self {{",
                 error.exception_name
             ))?;
             indented(f, |f| {
-                f.writeln(&format!("let class = env.find_class(\"L{}/{};\").expect(\"Unable to find exception {}\");", lib_path, camel_name, camel_name))?;
-                f.writeln(&format!("let constructor = env.get_method_id(class, \"<init>\", \"(L{}/{};)V\").map(|mid| mid.into_inner().into()).expect(\"Unable to find constructor of {}\");", lib_path, enum_name, camel_name))?;
+                let exception_path = class_path(
+                    &lib_path,
+                    &error.inner.module,
+                    &error.exception_name,
+                    f.naming(),
+                );
+                let enum_path = class_path(
+                    &lib_path,
+                    &error.inner.module,
+                    &error.inner.name,
+                    f.naming(),
+                );
+                f.writeln(&format!(
+                    "let class = env.find_class(\"L{};\").expect(\"Unable to find exception {}\");",
+                    exception_path, camel_name
+                ))?;
+                f.writeln(&format!("let constructor = env.get_method_id(class, \"<init>\", \"(L{};)V\").map(|mid| mid.into_inner().into()).expect(\"Unable to find constructor of {}\");", enum_path, camel_name))?;
                 f.writeln("Self { class : env.new_global_ref(class).unwrap(), constructor }")
             })?;
             f.writeln("}")?;
@@ -2,7 +2,7 @@ use crate::backend::*;
 use crate::model::*;
 
 use crate::backend::java::jni::conversion::*;
-use crate::backend::java::jni::JniBindgenConfig;
+use crate::backend::java::jni::{class_path, JniBindgenConfig};
 
 pub(crate) fn generate_interfaces_cache(
     f: &mut dyn Printer,
@@ -18,7 +18,7 @@ pub(crate) fn generate_interfaces_cache(
             f.writeln(&format!(
                 "pub {}: {},",
                 interface.name,
-                interface.name.camel_case()
+                interface.name.camel_case(f.naming())
             ))?;
         }
 
@@ -37,7 +37,7 @@ pub(crate) fn generate_interfaces_cache(
                     f.writeln(&format!(
                         "{}: {}::init(env),",
                         interface.name,
-                        interface.name.camel_case()
+                        interface.name.camel_case(f.naming())
                     ))?;
                 }
 
@@ -51,7 +51,7 @@ pub(crate) fn generate_interfaces_cache(
 
     // Each interface implementation
     for interface in lib.untyped_interfaces() {
-        let interface_name = interface.name.camel_case();
+        let interface_name = interface.name.camel_case(f.naming());
 
         f.writeln(&format!("pub struct {}", interface_name))?;
         blocked(f, |f| {
@@ -70,12 +70,23 @@ pub(crate) fn generate_interfaces_cache(
 
         f.writeln(&format!("impl {}", interface_name))?;
         blocked(f, |f| {
-            write_interface_init(f, &interface_name, &lib_path, &interface.callbacks)?;
+            let interface_path =
+                class_path(&lib_path, &interface.module, &interface.name, f.naming());
+            write_interface_init(
+                f,
+                &interface_name,
+                &interface_path,
+                &lib_path,
+                &interface.callbacks,
+            )?;
 
             f.newline()?;
 
-            let rust_struct_name =
-                format!("{}::ffi::{}", config.ffi_name, interface.name.camel_case());
+            let rust_struct_name = format!(
+                "{}::ffi::{}",
+                config.ffi_name,
+                interface.name.ffi_camel_case()
+            );
             f.writeln(&format!(
                 "pub(crate) fn to_rust(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> {}",
                 rust_struct_name
@@ -146,7 +157,7 @@ pub(crate) fn generate_interfaces_cache(
                 if let Some(return_type) = &cb.return_type.get_value() {
                     let unwrapped = format!("_result.{}", return_type.unwrap_value());
 
-                    if let Some(converted) = return_type.to_rust(&unwrapped) {
+                    if let Some(converted) = return_type.to_rust(&unwrapped, f.naming()) {
                         let ret = return_type.call_site(&converted).unwrap_or(converted);
                         f.writeln(&format!("return {};", ret))?;
                     } else {
@@ -176,14 +187,15 @@ pub(crate) fn generate_interfaces_cache(
 fn write_interface_init(
     f: &mut dyn Printer,
     interface_name: &str,
+    interface_path: &str,
     lib_path: &str,
     callbacks: &[CallbackFunction<Validated>],
 ) -> FormattingResult<()> {
     f.writeln("pub fn init(env: &jni::JNIEnv) -> Self")?;
     blocked(f, |f| {
         f.writeln(&format!(
-            "let class = env.find_class(\"L{}/{};\").expect(\"Unable to find {}\");",
-            lib_path, interface_name, interface_name
+            "let class = env.find_class(\"L{};\").expect(\"Unable to find {}\");",
+            interface_path, interface_name
         ))?;
         for callback in callbacks {
             let method_sig = format!(
@@ -191,12 +203,15 @@ fn write_interface_init(
                 callback
                     .arguments
                     .iter()
-                    .map(|arg| { arg.arg_type.jni_type_id().as_string(lib_path) })
+                    .map(|arg| { arg.arg_type.jni_type_id().as_string(lib_path, f.naming()) })
                     .collect::<Vec<_>>()
                     .join(""),
-                callback.return_type.jni_type_id().as_string(lib_path)
+                callback
+                    .return_type
+                    .jni_type_id()
+                    .as_string(lib_path, f.naming())
             );
-            f.writeln(&format!("let {} = env.get_method_id(class, \"{method_mixed}\", \"{method_sig}\").map(|mid| mid.into_inner().into()).expect(\"Unable to find method {method_mixed}\");", callback.name, method_mixed=callback.name.mixed_case(), method_sig=method_sig))?;
+            f.writeln(&format!("let {} = env.get_method_id(class, \"{method_mixed}\", \"{method_sig}\").map(|mid| mid.into_inner().into()).expect(\"Unable to find method {method_mixed}\");", callback.name, method_mixed=callback.name.mixed_case(f.naming()), method_sig=method_sig))?;
         }
         f.writeln("Self")?;
         blocked(f, |f| {
@@ -233,8 +248,9 @@ fn call_java_callback(
     f.writeln("// convert the arguments")?;
     // Perform the conversion of the parameters
     for param in args {
-        if let Some(conversion) = param.arg_type.maybe_convert(&param.name) {
-            f.writeln(&format!("let {} = {};", param.name, conversion))?;
+        let name = param.name.to_string();
+        if let Some(conversion) = param.arg_type.maybe_convert(&name) {
+            f.writeln(&format!("let {} = {};", name, conversion))?;
         }
     }
 
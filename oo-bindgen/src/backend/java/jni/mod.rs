@@ -29,6 +29,18 @@ impl<'a> JniBindgenConfig<'a> {
     }
 }
 
+/// JNI path of a type of the library, i.e. the path of the library followed by the module ones
+pub(crate) fn class_path(lib_path: &str, module: &Module, name: &Name, naming: &Naming) -> String {
+    let mut result = lib_path.to_string();
+    for dir in module.path() {
+        result.push('/');
+        result.push_str(&dir.snake_case(naming));
+    }
+    result.push('/');
+    result.push_str(&name.camel_case(naming));
+    result
+}
+
 fn module_string(name: &str, f: &mut dyn Printer, content: &str) -> FormattingResult<()> {
     module(name, f, |f| {
         for line in content.lines() {
@@ -54,7 +66,7 @@ where
 /// This function is typically called from a build.rs script in a target that builds
 /// the JNI shared library
 pub fn generate_jni(path: &Path, lib: &Library, config: &JniBindgenConfig) -> FormattingResult<()> {
-    let mut f = FilePrinter::new(path)?;
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::Java))?;
 
     generate_cache(&mut f)?;
     write_functions(&mut f, lib, config)?;
@@ -249,7 +261,7 @@ fn write_collection_guard(
     config: &JniBindgenConfig,
     col: &Handle<Collection<Validated>>,
 ) -> FormattingResult<()> {
-    let collection_name = col.collection_class.name.camel_case();
+    let collection_name = col.collection_class.name.camel_case(f.naming());
     let c_ffi_prefix = col.collection_class.settings.c_ffi_prefix.clone();
 
     f.writeln("/// Guard that builds the C collection type from a Java list")?;
@@ -257,7 +269,8 @@ fn write_collection_guard(
     indented(f, |f| {
         f.writeln(&format!(
             "inner: *mut {}::{}",
-            config.ffi_name, collection_name
+            config.ffi_name,
+            col.collection_class.name.ffi_camel_case()
         ))
     })?;
     f.writeln("}")?;
@@ -268,7 +281,8 @@ fn write_collection_guard(
     indented(f, |f| {
         f.writeln(&format!(
             "type Target = *mut {}::{};",
-            config.ffi_name, collection_name
+            config.ffi_name,
+            col.collection_class.name.ffi_camel_case()
         ))?;
         f.newline()?;
         f.writeln("fn deref(&self) -> &Self::Target {")?;
@@ -304,7 +318,7 @@ fn write_collection_guard(
                 )?;
                 if let Some(converted) = col
                     .item_type
-                    .to_rust_from_object("next.as_obj().into_inner()")
+                    .to_rust_from_object("next.as_obj().into_inner()", f.naming())
                 {
                     // perform  primary conversion that shadows the variable
                     f.writeln(&format!("let next = {};", converted))?;
@@ -388,6 +402,10 @@ fn write_function_signature(
         }
     };
 
+    // users are warned by the deprecated Java method
+    if handle.doc.deprecated.is_some() {
+        f.writeln("#[allow(deprecated)]")?;
+    }
     f.writeln("#[no_mangle]")?;
     f.writeln(
         &format!(
@@ -416,8 +434,9 @@ fn write_function(
 
         // Perform the primary conversion of the parameters if required
         for param in &handle.arguments {
-            if let Some(converted) = param.arg_type.to_rust(&param.name) {
-                let conversion = format!("let {} = {};", param.name, converted);
+            let name = param.name.to_string();
+            if let Some(converted) = param.arg_type.to_rust(&name, f.naming()) {
+                let conversion = format!("let {} = {};", name, converted);
                 f.writeln(&conversion)?;
             }
         }
@@ -436,10 +455,8 @@ fn write_function(
             .arguments
             .iter()
             .map(|param| {
-                param
-                    .arg_type
-                    .call_site(&param.name)
-                    .unwrap_or_else(|| param.name.to_string())
+                let name = param.name.to_string();
+                param.arg_type.call_site(&name).unwrap_or(name)
             })
             .chain(extra_param.into_iter())
             .collect::<Vec<String>>()
@@ -489,12 +506,12 @@ fn write_function(
             }
             SignatureType::ErrorWithReturn(error_type, return_type, _) => {
                 f.writeln("let _result = if _result == 0")?;
-                blocked(f, |f| {
-                    f.writeln("let _result = unsafe { _out.assume_init() };")?;
-                    if let Some(conversion) = return_type.maybe_convert("_result") {
-                        f.writeln(&conversion)?;
+                blocked(f, |f| match return_type.maybe_convert("_result") {
+                    Some(conversion) => {
+                        f.writeln("let _result = unsafe { _out.assume_init() };")?;
+                        f.writeln(&conversion)
                     }
-                    Ok(())
+                    None => f.writeln("unsafe { _out.assume_init() }"),
                 })?;
                 f.writeln("else")?;
                 blocked(f, |f| {
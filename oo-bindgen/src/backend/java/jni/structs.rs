@@ -1,5 +1,5 @@
 use crate::backend::java::jni::conversion::*;
-use crate::backend::java::jni::JniBindgenConfig;
+use crate::backend::java::jni::{class_path, JniBindgenConfig};
 use crate::backend::*;
 use crate::model::*;
 
@@ -27,7 +27,7 @@ fn generate_top_level_cache(f: &mut dyn Printer, lib: &Library) -> FormattingRes
             f.writeln(&format!(
                 "pub {}: instances::{},",
                 structure.name(),
-                structure.name().camel_case()
+                structure.name().camel_case(f.naming())
             ))?;
         }
 
@@ -46,7 +46,7 @@ fn generate_top_level_cache(f: &mut dyn Printer, lib: &Library) -> FormattingRes
                     f.writeln(&format!(
                         "{}: instances::{}::init(env),",
                         structure.name(),
-                        structure.name().camel_case()
+                        structure.name().camel_case(f.naming())
                     ))?;
                 }
                 Ok(())
@@ -98,8 +98,12 @@ fn generate_conversion_to_rust<T>(
 where
     T: StructFieldType + UnwrapValue + ConvertibleToRust + GuardType + JniJavaType,
 {
-    let struct_name = structure.name().camel_case();
-    let ffi_struct_name = format!("{}::ffi::{}", config.ffi_name, struct_name);
+    let struct_name = structure.name().camel_case(f.naming());
+    let ffi_struct_name = format!(
+        "{}::ffi::{}",
+        config.ffi_name,
+        structure.name().ffi_camel_case()
+    );
     let guard_struct_name = format!("{}Guard", struct_name);
 
     f.newline()?;
@@ -113,14 +117,14 @@ where
         if !structure
             .fields
             .iter()
-            .any(|x| x.field_type.guard_type().is_some())
+            .any(|x| x.field_type.guard_type(f.naming()).is_some())
         {
             f.writeln("/// empty guard objects require this field to make use of the lifetime")?;
             f.writeln("_phantom: std::marker::PhantomData<&'a usize>,")?;
         }
 
         for x in structure.fields.iter() {
-            if let Some(guard_type) = x.field_type.guard_type() {
+            if let Some(guard_type) = x.field_type.guard_type(f.naming()) {
                 f.writeln(&format!("/// guard for the {} field", x.name))?;
                 f.writeln(&format!("{}: {},", x.name, guard_type))?;
             }
@@ -150,8 +154,9 @@ where
 
             // transform the fields and shadow the variables
             for field in structure.fields() {
-                if let Some(converted) = field.field_type.to_rust(&field.name) {
-                    f.writeln(&format!("let {} = {};", field.name, converted))?;
+                let name = field.name.to_string();
+                if let Some(converted) = field.field_type.to_rust(&name, f.naming()) {
+                    f.writeln(&format!("let {} = {};", name, converted))?;
                 }
             }
 
@@ -159,8 +164,9 @@ where
             f.writeln(&format!("let _ffi_struct = {} {{", ffi_struct_name))?;
             indented(f, |f| {
                 for field in structure.fields() {
-                    if let Some(converted) = field.field_type.call_site(&field.name) {
-                        f.writeln(&format!("{}: {},", field.name, converted))?;
+                    let name = field.name.to_string();
+                    if let Some(converted) = field.field_type.call_site(&name) {
+                        f.writeln(&format!("{}: {},", name, converted))?;
                     } else {
                         f.writeln(&format!("{},", field.name))?;
                     }
@@ -174,18 +180,20 @@ where
 
             f.writeln(&format!("let _guard = {} {{", guard_struct_name))?;
             indented(f, |f| {
+                let naming = f.naming().clone();
                 if structure
                     .fields
                     .iter()
-                    .any(|f| f.field_type.guard_type().is_some())
+                    .any(|x| x.field_type.guard_type(&naming).is_some())
                 {
                     for field in structure
                         .fields
                         .iter()
-                        .filter(|x| x.field_type.guard_type().is_some())
+                        .filter(|x| x.field_type.guard_type(&naming).is_some())
                     {
-                        if let Some(transform) = field.field_type.guard_transform(&field.name) {
-                            f.writeln(&format!("{} : {},", field.name, transform))?;
+                        let name = field.name.to_string();
+                        if let Some(transform) = field.field_type.guard_transform(&name) {
+                            f.writeln(&format!("{} : {},", name, transform))?;
                         } else {
                             f.writeln(&format!("{},", field.name))?;
                         }
@@ -212,8 +220,12 @@ fn generate_conversion_to_jni<T>(
 where
     T: StructFieldType + MaybeConvertibleToJni,
 {
-    let struct_name = structure.name().camel_case();
-    let ffi_struct_name = format!("{}::ffi::{}", config.ffi_name, struct_name);
+    let struct_name = structure.name().camel_case(f.naming());
+    let ffi_struct_name = format!(
+        "{}::ffi::{}",
+        config.ffi_name,
+        structure.name().ffi_camel_case()
+    );
 
     f.newline()?;
     f.writeln(&format!("impl {}", struct_name))?;
@@ -254,7 +266,7 @@ fn generate_struct_fields<T>(
 where
     T: StructFieldType,
 {
-    let struct_name = structure.name().camel_case();
+    let struct_name = structure.name().camel_case(f.naming());
 
     f.newline()?;
     f.writeln(&format!("pub struct {}", struct_name))?;
@@ -276,8 +288,11 @@ where
     T: StructFieldType + JniTypeId,
 {
     let lib_path = config.java_signature_path(&structure.declaration.inner.settings.name);
-    let struct_name = structure.name().camel_case();
-    let struct_sig = format!("\"L{}/{};\"", lib_path, struct_name);
+    let struct_name = structure.name().camel_case(f.naming());
+    let struct_sig = format!(
+        "\"L{};\"",
+        class_path(&lib_path, structure.module(), structure.name(), f.naming())
+    );
 
     f.newline()?;
     f.writeln(&format!("impl {}", struct_name))?;
@@ -289,7 +304,7 @@ where
                 struct_sig, struct_name
             ))?;
             for field in structure.fields() {
-                f.writeln(&format!("let {field_snake} = env.get_field_id(class, \"{field_mixed}\", \"{field_sig}\").map(|mid| mid.into_inner().into()).expect(\"Unable to find field {field_mixed}\");", field_snake=field.name, field_mixed=field.name.mixed_case(), field_sig=field.field_type.jni_type_id().as_string(&lib_path)))?;
+                f.writeln(&format!("let {field_snake} = env.get_field_id(class, \"{field_mixed}\", \"{field_sig}\").map(|mid| mid.into_inner().into()).expect(\"Unable to find field {field_mixed}\");", field_snake=field.name, field_mixed=field.name.mixed_case(f.naming()), field_sig=field.field_type.jni_type_id().as_string(&lib_path, f.naming())))?;
             }
             f.writeln("Self")?;
             blocked(f, |f| {
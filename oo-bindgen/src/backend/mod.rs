@@ -1,9 +1,16 @@
 pub(crate) mod c;
 pub(crate) mod dotnet;
+pub(crate) mod examples;
+pub(crate) mod go;
 /// generation routines for Java bindings
 pub mod java;
+pub(crate) mod node;
 /// generation routines for the Rust FFI
 pub mod rust;
+pub(crate) mod site;
+pub(crate) mod swift;
+/// generation routines for WebAssembly bindings
+pub mod wasm;
 
 mod common;
 pub(crate) use common::*;
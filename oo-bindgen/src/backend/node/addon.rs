@@ -0,0 +1,1014 @@
+use crate::backend::c::ctype::CType;
+use crate::backend::c::formatting::allow_deprecated;
+
+use super::conversion::*;
+use super::*;
+
+/// Declare the scope that owns the memory borrowed by the converted values
+fn write_scope(f: &mut dyn Printer) -> FormattingResult<()> {
+    f.writeln("napi_support_scope_t _scope = {NULL};")?;
+    f.writeln("napi_support_scope_t* scope = &_scope;")
+}
+
+/// C literal of the value returned by a default implementation
+fn default_return_value(
+    value: &DefaultCallbackReturnValue,
+    return_type: Option<&CallbackReturnValue>,
+) -> Option<String> {
+    match value {
+        DefaultCallbackReturnValue::Void => None,
+        DefaultCallbackReturnValue::Basic(BasicValue::Primitive(x)) => Some(match x {
+            PrimitiveValue::Bool(x) => x.to_string(),
+            PrimitiveValue::U8(x) => x.to_string(),
+            PrimitiveValue::S8(x) => x.to_string(),
+            PrimitiveValue::U16(x) => x.to_string(),
+            PrimitiveValue::S16(x) => x.to_string(),
+            PrimitiveValue::U32(x) => format!("{}u", x),
+            PrimitiveValue::S32(x) => x.to_string(),
+            PrimitiveValue::U64(x) => format!("{}ull", x),
+            PrimitiveValue::S64(x) => format!("{}ll", x),
+            PrimitiveValue::Float(x) => format!("{:?}f", x),
+            PrimitiveValue::Double(x) => format!("{:?}", x),
+        }),
+        DefaultCallbackReturnValue::Basic(BasicValue::Duration(x)) => {
+            // durations are passed to C in the unit of the return type
+            let millis = match x {
+                DurationValue::Milliseconds(x) => *x,
+                DurationValue::Seconds(x) => *x * 1000,
+            };
+            Some(match return_type {
+                Some(CallbackReturnValue::Basic(BasicType::Duration(DurationType::Seconds))) => {
+                    format!("{}ull", millis / 1000)
+                }
+                _ => format!("{}ull", millis),
+            })
+        }
+        DefaultCallbackReturnValue::Basic(BasicValue::Enum(x)) => Some(format!(
+            "{}_{}_{}",
+            x.handle.settings.c_ffi_prefix.capital_snake_case(),
+            x.handle.name.capital_snake_case(),
+            x.variant.name.capital_snake_case()
+        )),
+        DefaultCallbackReturnValue::InitializedStruct(x) => {
+            let initializer = x.initializer.name.to_string();
+            Some(format!(
+                "{}()",
+                converter(&x.handle.to_c_type(), &initializer)
+            ))
+        }
+    }
+}
+
+fn ok_variant(error_type: &ErrorType<Validated>) -> String {
+    format!(
+        "{}_{}_{}",
+        error_type.inner.settings.c_ffi_prefix.capital_snake_case(),
+        error_type.inner.name.capital_snake_case(),
+        error_type.inner.variants[0].name.capital_snake_case()
+    )
+}
+
+fn write_enum_from_js(
+    f: &mut dyn Printer,
+    handle: &Handle<Enum<Validated>>,
+) -> FormattingResult<()> {
+    let c_type = handle.to_c_type();
+    f.writeln(&format!(
+        "static bool {}(napi_env env, napi_value value, {}* out)",
+        converter(&c_type, "from_js"),
+        c_type
+    ))?;
+    blocked(f, |f| {
+        f.writeln("int32_t number;")?;
+        f.writeln("if (!napi_support_get_s32(env, value, &number))")?;
+        blocked(f, |f| f.writeln("return false;"))?;
+        // the native library can't receive values that aren't variants
+        f.writeln("switch (number)")?;
+        blocked(f, |f| {
+            for variant in &handle.variants {
+                f.writeln(&format!("case {}:", variant.value))?;
+            }
+            indented(f, |f| {
+                f.writeln(&format!("*out = ({})number;", c_type))?;
+                f.writeln("return true;")
+            })?;
+            f.writeln("default:")?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "napi_throw_range_error(env, NULL, \"not a variant of {}\");",
+                    handle.name.camel_case(f.naming())
+                ))?;
+                f.writeln("return false;")
+            })
+        })
+    })
+}
+
+fn struct_from_js_signature<T>(st: &Struct<T, Validated>) -> String
+where
+    T: StructFieldType,
+{
+    let c_type = st.to_c_type();
+    format!(
+        "static bool {}(napi_env env, napi_support_scope_t* scope, napi_value value, {}* out)",
+        converter(&c_type, "from_js"),
+        c_type
+    )
+}
+
+fn struct_ref_from_js_signature<T>(st: &Struct<T, Validated>) -> String
+where
+    T: StructFieldType,
+{
+    let c_type = st.to_c_type();
+    format!(
+        "static bool {}(napi_env env, napi_support_scope_t* scope, napi_value value, {}** out)",
+        converter(&c_type, "ref_from_js"),
+        c_type
+    )
+}
+
+fn struct_to_js_signature<T>(st: &Struct<T, Validated>) -> String
+where
+    T: StructFieldType,
+{
+    let c_type = st.to_c_type();
+    format!(
+        "static napi_value {}(napi_env env, const {}* value)",
+        converter(&c_type, "to_js"),
+        c_type
+    )
+}
+
+fn write_struct_from_js<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertToNative,
+{
+    let c_type = st.to_c_type();
+
+    f.writeln(&struct_from_js_signature(st))?;
+    blocked(f, |f| {
+        f.writeln("napi_value field;")?;
+        for field in st.fields() {
+            f.writeln(&format!(
+                "if (!napi_support_get_property(env, value, \"{}\", &field) || !{})",
+                field.name.mixed_case(f.naming()),
+                field.field_type.convert_to_native(
+                    "field",
+                    &format!("&out->{}", c_name(&field.name, f.naming())),
+                    f.naming()
+                )
+            ))?;
+            blocked(f, |f| f.writeln("return false;"))?;
+        }
+        f.writeln("return true;")
+    })?;
+
+    // optional structs are allocated in the scope
+    f.newline()?;
+    f.writeln(&struct_ref_from_js_signature(st))?;
+    blocked(f, |f| {
+        f.writeln("if (napi_support_is_nullish(env, value))")?;
+        blocked(f, |f| {
+            f.writeln("*out = NULL;")?;
+            f.writeln("return true;")
+        })?;
+        f.writeln(&format!(
+            "*out = napi_support_alloc(env, scope, sizeof({}));",
+            c_type
+        ))?;
+        f.writeln(&format!(
+            "return *out != NULL && {}(env, scope, value, *out);",
+            converter(&c_type, "from_js")
+        ))
+    })
+}
+
+fn write_struct_to_js<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertToJs,
+{
+    f.writeln(&struct_to_js_signature(st))?;
+    blocked(f, |f| {
+        f.writeln("napi_value object;")?;
+        f.writeln("if (napi_create_object(env, &object) != napi_ok)")?;
+        blocked(f, |f| f.writeln("return NULL;"))?;
+        for field in st.fields() {
+            f.writeln(&format!(
+                "if (!napi_support_set_property(env, object, \"{}\", {}))",
+                field.name.mixed_case(f.naming()),
+                field.field_type.convert_to_js(
+                    &format!("value->{}", c_name(&field.name, f.naming())),
+                    f.naming()
+                )
+            ))?;
+            blocked(f, |f| f.writeln("return NULL;"))?;
+        }
+        f.writeln("return object;")
+    })
+}
+
+fn iterator_to_js_signature(iter: &Handle<AbstractIterator<Validated>>) -> String {
+    let c_type = iter.iter_class.to_c_type();
+    format!(
+        "static napi_value {}(napi_env env, {}* iter)",
+        converter(&c_type, "to_js"),
+        c_type
+    )
+}
+
+fn write_iterator_to_js(
+    f: &mut dyn Printer,
+    iter: &Handle<AbstractIterator<Validated>>,
+) -> FormattingResult<()> {
+    let item = match &iter.item_type {
+        IteratorItemType::Primitive(x) => x.convert_to_js("*item", f.naming()),
+        IteratorItemType::Struct(x) => {
+            format!("{}(env, item)", converter(&x.to_c_type(), "to_js"))
+        }
+    };
+
+    f.writeln(&iterator_to_js_signature(iter))?;
+    blocked(f, |f| {
+        f.writeln("napi_value array;")?;
+        f.writeln("uint32_t index = 0;")?;
+        f.writeln(&format!(
+            "{} item;",
+            iter.next_function.return_type.to_c_type()
+        ))?;
+        f.writeln("if (napi_create_array(env, &array) != napi_ok)")?;
+        blocked(f, |f| f.writeln("return NULL;"))?;
+        f.writeln(&format!(
+            "while ((item = {}(iter)) != NULL)",
+            iter.next_function.to_c_type()
+        ))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "if (napi_set_element(env, array, index++, {}) != napi_ok)",
+                item
+            ))?;
+            blocked(f, |f| f.writeln("return NULL;"))
+        })?;
+        f.writeln("return array;")
+    })
+}
+
+fn collection_from_js_signature(collection: &Handle<Collection<Validated>>) -> String {
+    let c_type = collection.to_c_type();
+    format!(
+        "static bool {}(napi_env env, napi_support_scope_t* scope, napi_value value, {}** out)",
+        converter(&c_type, "from_js"),
+        c_type
+    )
+}
+
+fn write_collection_from_js(
+    f: &mut dyn Printer,
+    collection: &Handle<Collection<Validated>>,
+) -> FormattingResult<()> {
+    let c_type = collection.to_c_type();
+    let release = converter(&c_type, "release");
+
+    f.writeln(&format!("static void {}(void* value)", release))?;
+    blocked(f, |f| {
+        f.writeln(&format!(
+            "{}(({}*)value);",
+            collection.delete_func.to_c_type(),
+            c_type
+        ))
+    })?;
+    f.newline()?;
+
+    f.writeln(&collection_from_js_signature(collection))?;
+    blocked(f, |f| {
+        f.writeln("uint32_t length;")?;
+        f.writeln("uint32_t index;")?;
+        f.writeln("if (!napi_support_get_array_length(env, value, &length))")?;
+        blocked(f, |f| f.writeln("return false;"))?;
+        let reserve = if collection.has_reserve { "length" } else { "" };
+        f.writeln(&format!(
+            "*out = {}({});",
+            collection.create_func.to_c_type(),
+            reserve
+        ))?;
+        f.writeln(&format!(
+            "if (!napi_support_on_release(scope, {}, *out))",
+            release
+        ))?;
+        blocked(f, |f| {
+            f.writeln(&format!("{}(*out);", release))?;
+            f.writeln("napi_throw_error(env, NULL, \"out of memory\");")?;
+            f.writeln("return false;")
+        })?;
+        f.writeln("for (index = 0; index < length; index++)")?;
+        blocked(f, |f| {
+            f.writeln("napi_value element;")?;
+            f.writeln(&format!("{} item;", collection.item_type.to_c_type()))?;
+            f.writeln(&format!(
+                "if (napi_get_element(env, value, index, &element) != napi_ok || !{})",
+                collection
+                    .item_type
+                    .convert_to_native("element", "&item", f.naming())
+            ))?;
+            blocked(f, |f| f.writeln("return false;"))?;
+            f.writeln(&format!("{}(*out, item);", collection.add_func.to_c_type()))
+        })?;
+        f.writeln("return true;")
+    })
+}
+
+fn trampoline_prefix(interface: &Interface<Validated>, cb: &Name) -> String {
+    format!(
+        "{}_{}_{}",
+        interface.settings.c_ffi_prefix, interface.name, cb
+    )
+}
+
+/// Values of a callback passed from the calling thread to the JavaScript thread
+///
+/// Returns the name of the struct, or `None` if the callback has no arguments and no return value
+fn write_call_struct(
+    f: &mut dyn Printer,
+    interface: &Interface<Validated>,
+    cb: &CallbackFunction<Validated>,
+) -> FormattingResult<Option<String>> {
+    if cb.arguments.is_empty() && cb.return_type.get_value().is_none() {
+        return Ok(None);
+    }
+
+    let name = format!("{}_call_t", trampoline_prefix(interface, &cb.name));
+    f.writeln(&format!("typedef struct {}", name))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        for arg in &cb.arguments {
+            f.writeln(&format!(
+                "{} {};",
+                arg.arg_type.to_c_type(),
+                c_name(&arg.name, f.naming())
+            ))?;
+        }
+        if let Some(value) = cb.return_type.get_value() {
+            f.writeln(&format!("{} result;", value.to_c_type()))?;
+        }
+        Ok(())
+    })?;
+    f.writeln(&format!("}} {};", name))?;
+    f.newline()?;
+
+    Ok(Some(name))
+}
+
+/// Callbacks without a return value that only receive copyable values don't wait for the JavaScript thread
+///
+/// Other callbacks block the calling thread, they return a value or lend native memory for the duration of the call
+fn is_posted(cb: &CallbackFunction<Validated>) -> bool {
+    cb.return_type.get_value().is_none()
+        && cb.arguments.iter().all(|arg| {
+            matches!(
+                arg.arg_type,
+                CallbackArgument::Basic(_) | CallbackArgument::String(_)
+            )
+        })
+}
+
+/// Free the copy of the arguments of a posted callback
+///
+/// Returns the name of the function, or `None` if there is nothing to free
+fn write_release(
+    f: &mut dyn Printer,
+    interface: &Interface<Validated>,
+    cb: &CallbackFunction<Validated>,
+    call_struct: Option<&str>,
+) -> FormattingResult<Option<String>> {
+    let name = match call_struct {
+        None => return Ok(None),
+        Some(name) => name,
+    };
+    let strings: Vec<&Name> = cb
+        .arguments
+        .iter()
+        .filter(|arg| matches!(arg.arg_type, CallbackArgument::String(_)))
+        .map(|arg| &arg.name)
+        .collect();
+    if strings.is_empty() {
+        return Ok(Some("free".to_string()));
+    }
+
+    let release = format!("{}_release", trampoline_prefix(interface, &cb.name));
+    f.writeln(&format!("static void {}(void* data)", release))?;
+    blocked(f, |f| {
+        f.writeln(&format!("{}* call = data;", name))?;
+        for arg in strings {
+            f.writeln(&format!("free((void*)call->{});", c_name(arg, f.naming())))?;
+        }
+        f.writeln("free(call);")
+    })?;
+    f.newline()?;
+
+    Ok(Some(release))
+}
+
+/// Write the function called by the native library, it forwards the call to the JavaScript thread
+fn write_native_trampoline(
+    f: &mut dyn Printer,
+    interface: &Interface<Validated>,
+    cb: &CallbackFunction<Validated>,
+    call_struct: Option<&str>,
+    default: Option<String>,
+) -> FormattingResult<()> {
+    let prefix = trampoline_prefix(interface, &cb.name);
+    let release = if is_posted(cb) {
+        Some(write_release(f, interface, cb, call_struct)?)
+    } else {
+        None
+    };
+    let params = cb
+        .arguments
+        .iter()
+        .map(|arg| {
+            format!(
+                "{} {}",
+                arg.arg_type.to_c_type(),
+                c_name(&arg.name, f.naming())
+            )
+        })
+        .chain(std::iter::once("void* _ctx".to_string()))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    f.writeln(&format!(
+        "static {} {}_native({})",
+        cb.return_type.to_c_type(),
+        prefix,
+        params
+    ))?;
+    blocked(f, |f| match (call_struct, release) {
+        (None, None) => f.writeln(&format!("napi_support_invoke(_ctx, {}_js, NULL);", prefix)),
+        (None, Some(_)) => f.writeln(&format!(
+            "napi_support_post(_ctx, {}_js, NULL, NULL);",
+            prefix
+        )),
+        (Some(name), Some(release)) => {
+            // the arguments are copied, the native library may free them before the callback runs
+            f.writeln(&format!("{}* _call = malloc(sizeof({}));", name, name))?;
+            f.writeln("if (_call == NULL)")?;
+            blocked(f, |f| f.writeln("return;"))?;
+            for arg in &cb.arguments {
+                let name = c_name(&arg.name, f.naming());
+                match arg.arg_type {
+                    CallbackArgument::String(_) => f.writeln(&format!(
+                        "_call->{} = napi_support_copy_string({});",
+                        name, name
+                    ))?,
+                    _ => f.writeln(&format!("_call->{} = {};", name, name))?,
+                }
+            }
+            f.writeln(&format!(
+                "napi_support_post(_ctx, {}_js, _call, {});",
+                prefix,
+                release.as_deref().unwrap_or("NULL")
+            ))
+        }
+        (Some(name), None) => {
+            f.writeln(&format!("{} _call;", name))?;
+            f.writeln("memset(&_call, 0, sizeof(_call));")?;
+            for arg in &cb.arguments {
+                let name = c_name(&arg.name, f.naming());
+                f.writeln(&format!("_call.{} = {};", name, name))?;
+            }
+            if let Some(value) = default {
+                f.writeln(&format!("_call.result = {};", value))?;
+            }
+            f.writeln(&format!(
+                "napi_support_invoke(_ctx, {}_js, &_call);",
+                prefix
+            ))?;
+            if cb.return_type.get_value().is_some() {
+                f.writeln("return _call.result;")?;
+            }
+            Ok(())
+        }
+    })
+}
+
+fn write_js_signature(
+    f: &mut dyn Printer,
+    interface: &Interface<Validated>,
+    cb: &CallbackFunction<Validated>,
+    call_struct: Option<&str>,
+) -> FormattingResult<()> {
+    f.writeln(&format!(
+        "static void {}_js(napi_env env, napi_support_ctx_t* ctx, void* data)",
+        trampoline_prefix(interface, &cb.name)
+    ))?;
+    f.writeln("{")?;
+    if let Some(name) = call_struct {
+        indented(f, |f| f.writeln(&format!("{}* call = data;", name)))?;
+    }
+    Ok(())
+}
+
+fn interface_from_js_signature(interface: &Handle<Interface<Validated>>) -> String {
+    let c_type = interface.to_c_type();
+    format!(
+        "static bool {}(napi_env env, napi_support_scope_t* scope, napi_value value, {}* out)",
+        converter(&c_type, "from_js"),
+        c_type
+    )
+}
+
+fn write_interface(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+) -> FormattingResult<()> {
+    for cb in &interface.callbacks {
+        let call_struct = write_call_struct(f, interface, cb)?;
+        let return_type = cb.return_type.get_value();
+
+        // runs on the JavaScript thread
+        write_js_signature(f, interface, cb, call_struct.as_deref())?;
+        indented(f, |f| {
+            if return_type.is_some() {
+                write_scope(f)?;
+            }
+            if !cb.arguments.is_empty() {
+                f.writeln(&format!("napi_value argv[{}];", cb.arguments.len()))?;
+            }
+            f.writeln("napi_value result;")?;
+            for (i, arg) in cb.arguments.iter().enumerate() {
+                f.writeln(&format!(
+                    "argv[{}] = {};",
+                    i,
+                    arg.arg_type.convert_to_js(
+                        &format!("call->{}", c_name(&arg.name, f.naming())),
+                        f.naming()
+                    )
+                ))?;
+            }
+            let call = format!(
+                "napi_support_call_method(env, ctx, \"{}\", {}, {}, {}, &result)",
+                cb.name.mixed_case(f.naming()),
+                cb.default_implementation.is_some(),
+                cb.arguments.len(),
+                if cb.arguments.is_empty() {
+                    "NULL"
+                } else {
+                    "argv"
+                }
+            );
+            match return_type {
+                None => f.writeln(&format!("{};", call)),
+                Some(value) => {
+                    // the default or zero value is returned if the method fails
+                    f.writeln(&format!("if ({} == NAPI_SUPPORT_CALLED)", call))?;
+                    blocked(f, |f| {
+                        f.writeln(&format!(
+                            "{};",
+                            value.convert_to_native("result", "&call->result", f.naming())
+                        ))
+                    })?;
+                    f.writeln("napi_support_scope_release(scope);")
+                }
+            }
+        })?;
+        f.writeln("}")?;
+        f.newline()?;
+
+        let default = cb
+            .default_implementation
+            .as_ref()
+            .and_then(|x| default_return_value(x, return_type));
+        write_native_trampoline(f, interface, cb, call_struct.as_deref(), default)?;
+        f.newline()?;
+    }
+
+    let settings = &interface.settings.interface;
+    let functional = match interface.get_functional_callback() {
+        Some(cb) => cb.functional_transform.enabled(),
+        None => false,
+    };
+
+    f.writeln(&interface_from_js_signature(interface))?;
+    blocked(f, |f| {
+        f.writeln("napi_support_ctx_t* ctx;")?;
+        f.writeln(&format!(
+            "if (!napi_support_check_implementation(env, value, {}))",
+            functional
+        ))?;
+        blocked(f, |f| f.writeln("return false;"))?;
+        f.writeln("ctx = napi_support_ctx_new(env, value, NULL);")?;
+        f.writeln("if (ctx == NULL)")?;
+        blocked(f, |f| f.writeln("return false;"))?;
+        for cb in &interface.callbacks {
+            f.writeln(&format!(
+                "out->{} = {}_native;",
+                c_name(&cb.name, f.naming()),
+                trampoline_prefix(interface, &cb.name)
+            ))?;
+        }
+        f.writeln(&format!(
+            "out->{} = napi_support_ctx_release;",
+            c_name(&settings.destroy_func_name, f.naming())
+        ))?;
+        f.writeln(&format!(
+            "out->{} = ctx;",
+            c_name(&settings.context_variable_name, f.naming())
+        ))?;
+        f.writeln("return true;")
+    })
+}
+
+fn future_create_signature(future: &FutureInterface<Validated>) -> String {
+    let c_type = future.interface.to_c_type();
+    format!(
+        "static napi_value {}(napi_env env, {}* out)",
+        converter(&c_type, "create"),
+        c_type
+    )
+}
+
+/// Futures complete a promise instead of calling a JavaScript object
+fn write_future(f: &mut dyn Printer, future: &FutureInterface<Validated>) -> FormattingResult<()> {
+    let interface = &future.interface;
+    let settings = &interface.settings;
+
+    for cb in &interface.callbacks {
+        let arg = &cb.arguments[0];
+        let call_struct = write_call_struct(f, interface, cb)?;
+        let value = format!("call->{}", c_name(&arg.name, f.naming()));
+
+        write_js_signature(f, interface, cb, call_struct.as_deref())?;
+        indented(f, |f| {
+            if cb.name == settings.future.success_callback_method_name {
+                f.writeln(&format!(
+                    "napi_support_settle(env, ctx, true, {});",
+                    arg.arg_type.convert_to_js(&value, f.naming())
+                ))
+            } else {
+                let error = match future.error_type.get() {
+                    Some(error) => format!(
+                        "napi_support_exception(env, \"{}\", (int32_t){})",
+                        error.exception_name.camel_case(f.naming()),
+                        value
+                    ),
+                    None => arg.arg_type.convert_to_js(&value, f.naming()),
+                };
+                f.writeln(&format!("napi_support_settle(env, ctx, false, {});", error))
+            }
+        })?;
+        f.writeln("}")?;
+        f.newline()?;
+
+        write_native_trampoline(f, interface, cb, call_struct.as_deref(), None)?;
+        f.newline()?;
+    }
+
+    // the context is released when the native library drops the future
+    f.writeln(&future_create_signature(future))?;
+    blocked(f, |f| {
+        f.writeln("napi_deferred deferred;")?;
+        f.writeln("napi_value promise;")?;
+        f.writeln("napi_support_ctx_t* ctx;")?;
+        f.writeln("if (napi_create_promise(env, &deferred, &promise) != napi_ok)")?;
+        blocked(f, |f| f.writeln("return NULL;"))?;
+        f.writeln("ctx = napi_support_ctx_new(env, NULL, deferred);")?;
+        f.writeln("if (ctx == NULL)")?;
+        blocked(f, |f| f.writeln("return NULL;"))?;
+        for cb in &interface.callbacks {
+            f.writeln(&format!(
+                "out->{} = {}_native;",
+                c_name(&cb.name, f.naming()),
+                trampoline_prefix(interface, &cb.name)
+            ))?;
+        }
+        f.writeln(&format!(
+            "out->{} = napi_support_ctx_release;",
+            c_name(&settings.interface.destroy_func_name, f.naming())
+        ))?;
+        f.writeln(&format!(
+            "out->{} = ctx;",
+            c_name(&settings.interface.context_variable_name, f.naming())
+        ))?;
+        f.writeln("return promise;")
+    })
+}
+
+/// Destructors that run on the thread pool
+fn off_thread_destructors(lib: &Library) -> Vec<&Name> {
+    lib.classes()
+        .filter(|class| is_destroyed_off_thread(class))
+        .filter_map(|class| class.destructor.as_ref().map(|x| &x.function.name))
+        .collect()
+}
+
+/// Local variable that holds a converted argument, prefixed to avoid clashing with the parameters of the wrapper
+/// Field, parameter or callback as the C headers spell it
+fn c_name(name: &Name, naming: &Naming) -> String {
+    name.snake_case(&naming.switch(Some(TargetLanguage::C)))
+}
+
+fn local(name: &Name) -> String {
+    format!("arg_{}", name)
+}
+
+fn write_function(
+    f: &mut dyn Printer,
+    function: &Handle<Function<Validated>>,
+    off_thread: bool,
+) -> FormattingResult<()> {
+    if off_thread {
+        f.writeln(&format!(
+            "static void {}_work(void* instance)",
+            function.name
+        ))?;
+        blocked(f, |f| {
+            f.writeln(&format!("{}(instance);", function.to_c_type()))
+        })?;
+        f.newline()?;
+    }
+
+    let future_arg = function
+        .arguments
+        .iter()
+        .find(|arg| is_future_callback(arg));
+    let converted: Vec<&Arg<FunctionArgument, Validated>> = function
+        .arguments
+        .iter()
+        .filter(|arg| !is_future_callback(arg))
+        .collect();
+
+    f.writeln(&format!(
+        "static napi_value {}_js(napi_env env, napi_callback_info info)",
+        function.name
+    ))?;
+    blocked(f, |f| {
+        if !converted.is_empty() {
+            f.writeln(&format!("size_t argc = {};", converted.len()))?;
+            f.writeln(&format!("napi_value argv[{}];", converted.len()))?;
+        }
+        write_scope(f)?;
+        f.writeln("napi_value _return = NULL;")?;
+        for arg in &function.arguments {
+            f.writeln(&format!(
+                "{} {};",
+                arg.arg_type.to_c_type(),
+                local(&arg.name)
+            ))?;
+        }
+        if future_arg.is_some() {
+            f.writeln("napi_value _promise;")?;
+        }
+        if function.error_type.get().is_some() {
+            f.writeln(&format!(
+                "{} _error;",
+                function.error_type.get().unwrap().inner.to_c_type()
+            ))?;
+        }
+        if let Some(value) = function.return_type.get_value() {
+            f.writeln(&format!("{} _result;", value.to_c_type()))?;
+        }
+        f.newline()?;
+
+        // missing arguments are undefined and fail to convert
+        let mut conditions: Vec<String> = Vec::new();
+        if !converted.is_empty() {
+            conditions.push(
+                "napi_get_cb_info(env, info, &argc, argv, NULL, NULL) == napi_ok".to_string(),
+            );
+        }
+        for (i, arg) in converted.iter().enumerate() {
+            conditions.push(arg.arg_type.convert_to_native(
+                &format!("argv[{}]", i),
+                &format!("&{}", local(&arg.name)),
+                f.naming(),
+            ));
+        }
+        if let Some(arg) = future_arg {
+            if let FunctionArgument::Interface(x) = &arg.arg_type {
+                conditions.push(format!(
+                    "(_promise = {}(env, &{})) != NULL",
+                    converter(&x.to_c_type(), "create"),
+                    local(&arg.name)
+                ));
+            }
+        }
+
+        let args = function
+            .arguments
+            .iter()
+            .map(|arg| local(&arg.name))
+            .collect::<Vec<String>>();
+
+        let write_call = |f: &mut dyn Printer| -> FormattingResult<()> {
+            let success = match (future_arg, function.return_type.get_value()) {
+                (Some(_), _) => "_promise".to_string(),
+                (None, None) => "napi_support_undefined(env)".to_string(),
+                (None, Some(value)) => value.convert_to_js("_result", f.naming()),
+            };
+
+            match function.error_type.get() {
+                None if off_thread => {
+                    f.writeln(&format!(
+                        "if (napi_support_queue_work(env, {}_work, {}))",
+                        function.name,
+                        args.join(", ")
+                    ))?;
+                    blocked(f, |f| f.writeln("_return = napi_support_undefined(env);"))
+                }
+                None => {
+                    match function.return_type.get_value() {
+                        None => {
+                            f.writeln(&format!("{}({});", function.to_c_type(), args.join(", ")))?
+                        }
+                        Some(_) => f.writeln(&format!(
+                            "_result = {}({});",
+                            function.to_c_type(),
+                            args.join(", ")
+                        ))?,
+                    }
+                    f.writeln(&format!("_return = {};", success))
+                }
+                Some(error) => {
+                    let args = match function.return_type.get_value() {
+                        None => args.join(", "),
+                        Some(_) => args
+                            .iter()
+                            .cloned()
+                            .chain(std::iter::once("&_result".to_string()))
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                    };
+                    f.writeln(&format!("_error = {}({});", function.to_c_type(), args))?;
+                    f.writeln(&format!("if (_error == {})", ok_variant(error)))?;
+                    blocked(f, |f| f.writeln(&format!("_return = {};", success)))?;
+                    f.writeln("else")?;
+                    blocked(f, |f| {
+                        let exception = format!(
+                            "\"{}\", (int32_t)_error",
+                            error.exception_name.camel_case(f.naming())
+                        );
+                        match future_arg {
+                            // asynchronous methods reject the promise instead of throwing
+                            Some(arg) => {
+                                f.writeln(&format!(
+                                    "napi_support_settle(env, {}.{}, false, napi_support_exception(env, {}));",
+                                    local(&arg.name),
+                                    c_name(
+                                        &function.settings.interface.context_variable_name,
+                                        f.naming()
+                                    ),
+                                    exception
+                                ))?;
+                                f.writeln("_return = _promise;")
+                            }
+                            None => f.writeln(&format!("napi_support_throw(env, {});", exception)),
+                        }
+                    })
+                }
+            }
+        };
+
+        if conditions.is_empty() {
+            write_call(f)?;
+        } else {
+            f.writeln(&format!("if ({})", conditions.join("\n    && ")))?;
+            blocked(f, write_call)?;
+        }
+
+        f.newline()?;
+        f.writeln("napi_support_scope_release(scope);")?;
+        f.writeln("return _return;")
+    })
+}
+
+/// Functions called by the JavaScript layer
+fn exported_functions(lib: &Library) -> impl Iterator<Item = &Handle<Function<Validated>>> {
+    lib.functions()
+        .filter(|x| x.category == FunctionCategory::Native)
+}
+
+pub(crate) fn generate(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("#include \"napi_support.h\"")?;
+    f.newline()?;
+    f.writeln(&format!("#include \"{}.h\"", lib.settings.name))?;
+    f.newline()?;
+
+    // the addon has to call the deprecated functions of the C API
+    allow_deprecated(f, lib.has_deprecated_items(), |f| write_definitions(f, lib))
+}
+
+fn write_definitions(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    // conversions reference each other, so they are all declared first
+    for handle in lib.enums() {
+        let c_type = handle.to_c_type();
+        f.writeln(&format!(
+            "static bool {}(napi_env env, napi_value value, {}* out);",
+            converter(&c_type, "from_js"),
+            c_type
+        ))?;
+    }
+    for st in lib.structs() {
+        match st {
+            StructType::FunctionArg(x) => {
+                f.writeln(&format!("{};", struct_from_js_signature(x)))?;
+                f.writeln(&format!("{};", struct_ref_from_js_signature(x)))?;
+            }
+            StructType::FunctionReturn(x) => {
+                f.writeln(&format!("{};", struct_to_js_signature(x)))?;
+            }
+            StructType::CallbackArg(x) => {
+                f.writeln(&format!("{};", struct_to_js_signature(x)))?;
+            }
+            StructType::Universal(x) => {
+                f.writeln(&format!("{};", struct_from_js_signature(x)))?;
+                f.writeln(&format!("{};", struct_ref_from_js_signature(x)))?;
+                f.writeln(&format!("{};", struct_to_js_signature(x)))?;
+            }
+        }
+    }
+    for iter in lib.iterators() {
+        f.writeln(&format!("{};", iterator_to_js_signature(iter)))?;
+    }
+    for collection in lib.collections() {
+        f.writeln(&format!("{};", collection_from_js_signature(collection)))?;
+    }
+    for interface in lib.interfaces() {
+        match interface {
+            InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) => {
+                f.writeln(&format!("{};", interface_from_js_signature(x)))?;
+            }
+            InterfaceType::Future(x) => {
+                f.writeln(&format!("{};", future_create_signature(x)))?;
+            }
+        }
+    }
+
+    for handle in lib.enums() {
+        f.newline()?;
+        write_enum_from_js(f, handle)?;
+    }
+
+    for st in lib.structs() {
+        f.newline()?;
+        match st {
+            StructType::FunctionArg(x) => write_struct_from_js(f, x)?,
+            StructType::FunctionReturn(x) => write_struct_to_js(f, x)?,
+            StructType::CallbackArg(x) => write_struct_to_js(f, x)?,
+            StructType::Universal(x) => {
+                write_struct_from_js(f, x)?;
+                f.newline()?;
+                write_struct_to_js(f, x)?;
+            }
+        }
+    }
+
+    for iter in lib.iterators() {
+        f.newline()?;
+        write_iterator_to_js(f, iter)?;
+    }
+
+    for collection in lib.collections() {
+        f.newline()?;
+        write_collection_from_js(f, collection)?;
+    }
+
+    for interface in lib.interfaces() {
+        f.newline()?;
+        match interface {
+            InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) => {
+                write_interface(f, x)?
+            }
+            InterfaceType::Future(x) => write_future(f, x)?,
+        }
+    }
+
+    let off_thread = off_thread_destructors(lib);
+    for function in exported_functions(lib) {
+        f.newline()?;
+        write_function(f, function, off_thread.contains(&&function.name))?;
+    }
+
+    f.newline()?;
+    f.writeln("NAPI_MODULE_INIT()")?;
+    blocked(f, |f| {
+        f.writeln("static const napi_property_descriptor properties[] = {")?;
+        indented(f, |f| {
+            f.writeln(
+                "{\"__init\", NULL, napi_support_init, NULL, NULL, NULL, napi_default, NULL},",
+            )?;
+            for function in exported_functions(lib) {
+                f.writeln(&format!(
+                    "{{\"{}\", NULL, {}_js, NULL, NULL, NULL, napi_default, NULL}},",
+                    export_name(function),
+                    function.name
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("};")?;
+        f.newline()?;
+        f.writeln("if (napi_define_properties(env, exports, sizeof(properties) / sizeof(properties[0]), properties) != napi_ok)")?;
+        blocked(f, |f| f.writeln("return NULL;"))?;
+        f.writeln("return exports;")
+    })
+}
@@ -0,0 +1,503 @@
+use crate::backend::c::ctype::CType;
+use crate::model::*;
+
+/// Name of a generated C conversion function, e.g. `foo_numbers_from_js` for `foo_numbers_t`
+pub(crate) fn converter(c_type: &str, direction: &str) -> String {
+    format!(
+        "{}_{}",
+        c_type.strip_suffix("_t").unwrap_or(c_type),
+        direction
+    )
+}
+
+/// TypeScript spelling of a type in the public API
+pub(crate) trait TypeScriptType {
+    fn ts_type(&self, naming: &Naming) -> String;
+}
+
+/// C expression that converts a JavaScript value into a native value
+///
+/// `out` is a pointer to the destination, the expression is false if an exception is pending
+pub(crate) trait ConvertToNative {
+    fn convert_to_native(&self, value: &str, out: &str, naming: &Naming) -> String;
+}
+
+/// C expression that converts a native value into a `napi_value`
+pub(crate) trait ConvertToJs {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String;
+}
+
+fn primitive_getter(primitive: &Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::U8 => "u8",
+        Primitive::S8 => "s8",
+        Primitive::U16 => "u16",
+        Primitive::S16 => "s16",
+        Primitive::U32 => "u32",
+        Primitive::S32 => "s32",
+        Primitive::U64 => "u64",
+        Primitive::S64 => "s64",
+        Primitive::Float => "float",
+        Primitive::Double => "double",
+    }
+}
+
+impl TypeScriptType for Primitive {
+    fn ts_type(&self, _naming: &Naming) -> String {
+        match self {
+            Self::Bool => "boolean".to_string(),
+            // 64-bit integers don't fit in a number without losing precision
+            Self::U64 | Self::S64 => "bigint".to_string(),
+            _ => "number".to_string(),
+        }
+    }
+}
+
+impl ConvertToNative for Primitive {
+    fn convert_to_native(&self, value: &str, out: &str, _naming: &Naming) -> String {
+        format!(
+            "napi_support_get_{}(env, {}, {})",
+            primitive_getter(self),
+            value,
+            out
+        )
+    }
+}
+
+impl ConvertToJs for Primitive {
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        let function = match self {
+            Self::Bool => "bool",
+            Self::U8 | Self::U16 | Self::U32 => "u32",
+            Self::S8 | Self::S16 | Self::S32 => "s32",
+            Self::U64 => "u64",
+            Self::S64 => "s64",
+            Self::Float | Self::Double => "double",
+        };
+        format!("napi_support_{}(env, {})", function, expr)
+    }
+}
+
+fn duration_suffix(duration_type: DurationType) -> &'static str {
+    match duration_type {
+        DurationType::Milliseconds => "ms",
+        DurationType::Seconds => "s",
+    }
+}
+
+impl TypeScriptType for BasicType {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.ts_type(naming),
+            // durations are a number of milliseconds
+            Self::Duration(_) => "number".to_string(),
+            Self::Enum(x) => x.name.camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for BasicType {
+    fn convert_to_native(&self, value: &str, out: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_to_native(value, out, naming),
+            Self::Duration(x) => format!(
+                "napi_support_get_duration_{}(env, {}, {})",
+                duration_suffix(*x),
+                value,
+                out
+            ),
+            Self::Enum(x) => format!(
+                "{}(env, {}, {})",
+                converter(&x.to_c_type(), "from_js"),
+                value,
+                out
+            ),
+        }
+    }
+}
+
+impl ConvertToJs for BasicType {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_to_js(expr, naming),
+            Self::Duration(x) => {
+                format!(
+                    "napi_support_duration_{}(env, {})",
+                    duration_suffix(*x),
+                    expr
+                )
+            }
+            Self::Enum(_) => format!("napi_support_s32(env, (int32_t){})", expr),
+        }
+    }
+}
+
+impl TypeScriptType for StringType {
+    fn ts_type(&self, _naming: &Naming) -> String {
+        "string".to_string()
+    }
+}
+
+impl ConvertToNative for StringType {
+    fn convert_to_native(&self, value: &str, out: &str, _naming: &Naming) -> String {
+        format!("napi_support_get_string(env, scope, {}, {})", value, out)
+    }
+}
+
+impl ConvertToJs for StringType {
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        format!("napi_support_string(env, {})", expr)
+    }
+}
+
+impl TypeScriptType for ClassDeclarationHandle {
+    fn ts_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
+    }
+}
+
+impl ConvertToNative for ClassDeclarationHandle {
+    fn convert_to_native(&self, value: &str, out: &str, _naming: &Naming) -> String {
+        format!("napi_support_get_handle(env, {}, (void**){})", value, out)
+    }
+}
+
+impl ConvertToJs for ClassDeclarationHandle {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        format!(
+            "napi_support_wrap(env, \"{}\", {})",
+            self.name.camel_case(naming),
+            expr
+        )
+    }
+}
+
+impl TypeScriptType for IteratorItemType {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.ts_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl<D> TypeScriptType for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn ts_type(&self, naming: &Naming) -> String {
+        format!("{}[]", self.item_type.ts_type(naming))
+    }
+}
+
+impl<D> ConvertToJs for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        format!(
+            "{}(env, {})",
+            converter(&self.iter_class.to_c_type(), "to_js"),
+            expr
+        )
+    }
+}
+
+impl<D> TypeScriptType for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn ts_type(&self, naming: &Naming) -> String {
+        format!("{}[]", self.item_type.ts_type(naming))
+    }
+}
+
+impl<D> ConvertToNative for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, value: &str, out: &str, _naming: &Naming) -> String {
+        format!(
+            "{}(env, scope, {}, {})",
+            converter(&self.to_c_type(), "from_js"),
+            value,
+            out
+        )
+    }
+}
+
+impl<D> TypeScriptType for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn ts_type(&self, naming: &Naming) -> String {
+        // functional interfaces may also be implemented by a function
+        match self.get_functional_callback() {
+            Some(cb) if cb.functional_transform.enabled() => format!(
+                "{} | NonNullable<{}[\"{}\"]>",
+                self.name.camel_case(naming),
+                self.name.camel_case(naming),
+                cb.name.mixed_case(naming)
+            ),
+            _ => self.name.camel_case(naming),
+        }
+    }
+}
+
+impl<D> ConvertToNative for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, value: &str, out: &str, _naming: &Naming) -> String {
+        format!(
+            "{}(env, scope, {}, {})",
+            converter(&self.to_c_type(), "from_js"),
+            value,
+            out
+        )
+    }
+}
+
+impl<T> TypeScriptType for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn ts_type(&self, naming: &Naming) -> String {
+        self.name().camel_case(naming)
+    }
+}
+
+impl<T> ConvertToNative for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_to_native(&self, value: &str, out: &str, _naming: &Naming) -> String {
+        format!(
+            "{}(env, scope, {}, {})",
+            converter(&self.to_c_type(), "from_js"),
+            value,
+            out
+        )
+    }
+}
+
+impl<T> ConvertToJs for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        format!("{}(env, &{})", converter(&self.to_c_type(), "to_js"), expr)
+    }
+}
+
+impl TypeScriptType for FunctionArgStructField {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::String(x) => x.ts_type(naming),
+            Self::Interface(x) => x.inner.ts_type(naming),
+            Self::Struct(x) => x.ts_type(naming),
+        }
+    }
+}
+
+impl ConvertToNative for FunctionArgStructField {
+    fn convert_to_native(&self, value: &str, out: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(value, out, naming),
+            Self::String(x) => x.convert_to_native(value, out, naming),
+            Self::Interface(x) => x.inner.convert_to_native(value, out, naming),
+            Self::Struct(x) => x.convert_to_native(value, out, naming),
+        }
+    }
+}
+
+impl TypeScriptType for FunctionReturnStructField {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::ClassRef(x) => x.ts_type(naming),
+            Self::Iterator(x) => x.ts_type(naming),
+            Self::Struct(x) => x.ts_type(naming),
+        }
+    }
+}
+
+impl ConvertToJs for FunctionReturnStructField {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::ClassRef(x) => x.convert_to_js(expr, naming),
+            Self::Iterator(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+        }
+    }
+}
+
+impl TypeScriptType for CallbackArgStructField {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::Iterator(x) => x.ts_type(naming),
+            Self::Struct(x) => x.ts_type(naming),
+        }
+    }
+}
+
+impl ConvertToJs for CallbackArgStructField {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::Iterator(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+        }
+    }
+}
+
+impl TypeScriptType for UniversalStructField {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for UniversalStructField {
+    fn convert_to_native(&self, value: &str, out: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(value, out, naming),
+            Self::Struct(x) => format!(
+                "{}(env, scope, {}, {})",
+                converter(&x.to_c_type(), "from_js"),
+                value,
+                out
+            ),
+        }
+    }
+}
+
+impl ConvertToJs for UniversalStructField {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => format!("{}(env, &{})", converter(&x.to_c_type(), "to_js"), expr),
+        }
+    }
+}
+
+impl TypeScriptType for FunctionArgument {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::String(x) => x.ts_type(naming),
+            Self::Collection(x) => x.ts_type(naming),
+            Self::Struct(x) => x.ts_type(naming),
+            Self::StructRef(x) => format!("{} | null", x.inner.name.camel_case(naming)),
+            Self::ClassRef(x) => x.ts_type(naming),
+            Self::Interface(x) => x.ts_type(naming),
+        }
+    }
+}
+
+impl ConvertToNative for FunctionArgument {
+    fn convert_to_native(&self, value: &str, out: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(value, out, naming),
+            Self::String(x) => x.convert_to_native(value, out, naming),
+            Self::Collection(x) => x.convert_to_native(value, out, naming),
+            Self::Struct(x) => x.convert_to_native(value, out, naming),
+            Self::StructRef(x) => format!(
+                "{}(env, scope, {}, {})",
+                converter(&x.inner.to_c_type(), "ref_from_js"),
+                value,
+                out
+            ),
+            Self::ClassRef(x) => x.convert_to_native(value, out, naming),
+            Self::Interface(x) => x.convert_to_native(value, out, naming),
+        }
+    }
+}
+
+impl TypeScriptType for CallbackArgument {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::String(x) => x.ts_type(naming),
+            Self::Iterator(x) => x.ts_type(naming),
+            Self::Class(x) => x.ts_type(naming),
+            Self::Struct(x) => x.ts_type(naming),
+        }
+    }
+}
+
+impl ConvertToJs for CallbackArgument {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::String(x) => x.convert_to_js(expr, naming),
+            Self::Iterator(x) => x.convert_to_js(expr, naming),
+            Self::Class(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+        }
+    }
+}
+
+impl TypeScriptType for CallbackReturnValue {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for CallbackReturnValue {
+    fn convert_to_native(&self, value: &str, out: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(value, out, naming),
+            Self::Struct(x) => format!(
+                "{}(env, scope, {}, {})",
+                converter(&x.to_c_type(), "from_js"),
+                value,
+                out
+            ),
+        }
+    }
+}
+
+impl TypeScriptType for FunctionReturnValue {
+    fn ts_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.ts_type(naming),
+            Self::PrimitiveRef(x) => format!("{} | null", x.inner.ts_type(naming)),
+            Self::String(x) => x.ts_type(naming),
+            Self::ClassRef(x) => x.ts_type(naming),
+            Self::Struct(x) => x.ts_type(naming),
+            Self::StructRef(x) => format!("{} | null", x.untyped().name.camel_case(naming)),
+        }
+    }
+}
+
+impl ConvertToJs for FunctionReturnValue {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::PrimitiveRef(x) => format!(
+                "({} == NULL ? napi_support_null(env) : {})",
+                expr,
+                x.inner.convert_to_js(&format!("*{}", expr), naming)
+            ),
+            Self::String(x) => x.convert_to_js(expr, naming),
+            Self::ClassRef(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+            Self::StructRef(x) => format!(
+                "({} == NULL ? napi_support_null(env) : {}(env, {}))",
+                expr,
+                converter(&x.untyped().to_c_type(), "to_js"),
+                expr
+            ),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::backend::*;
+use crate::model::*;
+
+use super::{class_method_name, destructor_name};
+
+/// Print a JSDoc block
+pub(crate) fn jsdoc<F, T>(f: &mut dyn Printer, cb: F) -> FormattingResult<T>
+where
+    F: FnOnce(&mut dyn Printer) -> FormattingResult<T>,
+{
+    f.writeln("/**")?;
+    let mut printer = PrefixPrinter::new(f, " * ");
+    let result = cb(&mut printer)?;
+    f.writeln(" */")?;
+
+    Ok(result)
+}
+
+pub(crate) fn jsdoc_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    docstring_print(f, &doc.brief)?;
+
+    // there are no examples in this language
+    for detail in doc.details.iter().filter(|x| x.is_printed_in(None)) {
+        f.newline()?;
+
+        match detail {
+            DocParagraph::Details(docstring) => {
+                f.newline()?;
+                docstring_print(f, docstring)?;
+            }
+            DocParagraph::Warning(docstring) => {
+                f.writeln("@remarks Warning: ")?;
+                docstring_print(f, docstring)?;
+            }
+            DocParagraph::Example(_) => (),
+        }
+    }
+
+    if let Some(deprecated) = &doc.deprecated {
+        f.writeln(&format!("@deprecated Since {}: ", deprecated.since))?;
+        docstring_print(f, &deprecated.note)?;
+    }
+
+    if let Some(since) = &doc.since {
+        f.writeln(&format!("@since {}", since))?;
+    }
+
+    Ok(())
+}
+
+/// Print the tags that document the parameters, the return value and the exception of a function
+pub(crate) fn jsdoc_print_function<'a, I>(
+    f: &mut dyn Printer,
+    function: &Function<Validated>,
+    args: I,
+    return_doc: Option<&DocString<Validated>>,
+) -> FormattingResult<()>
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    jsdoc_print(f, &function.doc)?;
+
+    for arg in args {
+        f.writeln(&format!("@param {} ", arg.name.mixed_case(f.naming())))?;
+        docstring_print(f, &arg.doc)?;
+    }
+
+    if let Some(doc) = return_doc {
+        f.writeln("@returns ")?;
+        docstring_print(f, doc)?;
+    }
+
+    if let Some(error) = function.error_type.get() {
+        f.writeln(&format!(
+            "@throws {{{}}} ",
+            error.exception_name.camel_case(f.naming())
+        ))?;
+        f.write("If the native function returns an error")?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn docstring_print(
+    f: &mut dyn Printer,
+    docstring: &DocString<Validated>,
+) -> FormattingResult<()> {
+    let mut elements = docstring.elements().peekable();
+    while let Some(el) = elements.next() {
+        match el {
+            DocStringElement::Text(text) => f.write(text)?,
+            DocStringElement::Null => f.write("`null`")?,
+            DocStringElement::Iterator => f.write("array")?,
+            DocStringElement::Reference(reference) => reference_print(f, reference)?,
+            DocStringElement::Code(code) => f.write(&format!("`{}`", code))?,
+            DocStringElement::Bold(text) => f.write(&format!("**{}**", text))?,
+            DocStringElement::Link { text, url } => f.write(&format!("[{}]({})", text, url))?,
+            DocStringElement::List(items) => {
+                for item in items {
+                    f.writeln("- ")?;
+                    docstring_print(f, item)?;
+                }
+                // an empty line ends the list
+                if elements.peek().is_some() {
+                    f.newline()?;
+                    f.newline()?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn reference_print(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
+    match reference {
+        Validated::Argument(param_name) => {
+            f.write(&format!("`{}`", param_name.mixed_case(f.naming())))?
+        }
+        Validated::Class(class) => {
+            f.write(&format!("{{@link {}}}", class.name.camel_case(f.naming())))?;
+        }
+        Validated::ClassMethod(class, method_name, _) => {
+            f.write(&format!(
+                "{{@link {}}}",
+                class_method_name(class, method_name, f.naming())
+            ))?;
+        }
+        Validated::ClassConstructor(class, _) => {
+            f.write(&format!(
+                "{{@link {}.constructor}}",
+                class.name().camel_case(f.naming())
+            ))?;
+        }
+        Validated::ClassDestructor(class, _) => {
+            f.write(&format!(
+                "{{@link {}.{}}}",
+                class.name().camel_case(f.naming()),
+                destructor_name(class, f.naming())
+            ))?;
+        }
+        Validated::Struct(st) => {
+            f.write(&format!("{{@link {}}}", st.name().camel_case(f.naming())))?;
+        }
+        Validated::StructField(st, field_name) => {
+            f.write(&format!(
+                "{{@link {}.{}}}",
+                st.name().camel_case(f.naming()),
+                field_name.mixed_case(f.naming())
+            ))?;
+        }
+        Validated::Enum(handle) => {
+            f.write(&format!("{{@link {}}}", handle.name.camel_case(f.naming())))?;
+        }
+        Validated::EnumVariant(handle, variant_name) => {
+            f.write(&format!(
+                "{{@link {}.{}}}",
+                handle.name.camel_case(f.naming()),
+                variant_name.camel_case(f.naming())
+            ))?;
+        }
+        Validated::Interface(interface) => {
+            f.write(&format!(
+                "{{@link {}}}",
+                interface.name.camel_case(f.naming())
+            ))?;
+        }
+        Validated::InterfaceMethod(interface, callback_name) => {
+            f.write(&format!(
+                "{{@link {}.{}}}",
+                interface.name.camel_case(f.naming()),
+                callback_name.mixed_case(f.naming())
+            ))?;
+        }
+    }
+
+    Ok(())
+}
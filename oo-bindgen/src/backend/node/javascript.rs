@@ -0,0 +1,393 @@
+use super::*;
+
+/// JavaScript expression of a default value
+pub(crate) fn default_value_expression(value: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        // 64-bit integers are BigInt
+        ValidatedDefaultValue::Number(NumberValue::U64(x)) => format!("{}n", x),
+        ValidatedDefaultValue::Number(NumberValue::S64(x)) => format!("{}n", x),
+        ValidatedDefaultValue::Number(x) => x.to_string(),
+        ValidatedDefaultValue::Duration(_, x) => x.as_millis().to_string(),
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!(
+                "{}.{}",
+                x.name.camel_case(naming),
+                variant.camel_case(naming)
+            )
+        }
+        ValidatedDefaultValue::String(x) => format!("{:?}", x),
+        ValidatedDefaultValue::DefaultStruct(handle, _, name) => {
+            format!(
+                "{}.{}()",
+                handle.name().camel_case(naming),
+                name.mixed_case(naming)
+            )
+        }
+    }
+}
+
+/// Parameters of a function, with their default value if they have one
+fn params<'a, I>(args: I, naming: &Naming) -> String
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    args.map(|arg| match &arg.default {
+        None => arg.name.mixed_case(naming),
+        Some(x) => format!(
+            "{} = {}",
+            arg.name.mixed_case(naming),
+            default_value_expression(x, naming)
+        ),
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+/// Call of an exported function, `instance` replaces the first argument of methods
+fn native_call(function: &Function<Validated>, instance: Option<&str>, naming: &Naming) -> String {
+    let skip = if instance.is_some() { 1 } else { 0 };
+    let args = instance
+        .map(|x| x.to_string())
+        .into_iter()
+        .chain(
+            function
+                .arguments
+                .iter()
+                .skip(skip)
+                .filter(|arg| !is_future_callback(arg))
+                .map(|arg| arg.name.mixed_case(naming)),
+        )
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!("native.{}({})", export_name(function), args)
+}
+
+fn write_method(
+    f: &mut dyn Printer,
+    name: &str,
+    function: &Function<Validated>,
+    is_static: bool,
+) -> FormattingResult<()> {
+    let (prefix, skip, instance) = if is_static {
+        ("static ", 0, None)
+    } else {
+        ("", 1, Some("this"))
+    };
+    let args = function
+        .arguments
+        .iter()
+        .skip(skip)
+        .filter(|arg| !is_future_callback(arg));
+
+    f.writeln(&format!(
+        "{}{}({}) {{",
+        prefix,
+        name,
+        params(args, f.naming())
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!(
+            "return {};",
+            native_call(function, instance, f.naming())
+        ))
+    })?;
+    f.writeln("}")
+}
+
+fn write_class(f: &mut dyn Printer, class: &Handle<Class<Validated>>) -> FormattingResult<()> {
+    let class_name = class.name().camel_case(f.naming());
+
+    f.writeln(&format!("class {} {{", class_name))?;
+    indented(f, |f| {
+        // the native function returns the wrapped instance
+        match &class.constructor {
+            Some(constructor) => {
+                let function = &constructor.function;
+                f.writeln(&format!(
+                    "constructor({}) {{",
+                    params(function.arguments.iter(), f.naming())
+                ))?;
+                indented(f, |f| {
+                    f.writeln(&format!(
+                        "return {};",
+                        native_call(function, None, f.naming())
+                    ))
+                })?;
+                f.writeln("}")?;
+            }
+            None => {
+                f.writeln("constructor() {")?;
+                indented(f, |f| {
+                    f.writeln(&format!(
+                        "throw new TypeError(\"{} can't be constructed\");",
+                        class_name
+                    ))
+                })?;
+                f.writeln("}")?;
+            }
+        }
+
+        if let Some(destructor) = &class.destructor {
+            f.newline()?;
+            f.writeln(&format!("{}() {{", destructor_name(class, f.naming())))?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "dispose(this, native.{});",
+                    export_name(&destructor.function)
+                ))
+            })?;
+            f.writeln("}")?;
+            f.newline()?;
+            f.writeln("[disposeSymbol]() {")?;
+            indented(f, |f| {
+                f.writeln(&format!("this.{}();", destructor_name(class, f.naming())))
+            })?;
+            f.writeln("}")?;
+        }
+
+        for method in &class.methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_method(f, &name, &method.native_function, false)?;
+        }
+
+        for method in &class.future_methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_method(f, &name, &method.native_function, false)?;
+        }
+
+        for method in &class.static_methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_method(f, &name, &method.native_function, true)?;
+        }
+
+        Ok(())
+    })?;
+    f.writeln("}")?;
+
+    match &class.destructor {
+        Some(destructor) => f.writeln(&format!(
+            "registerClass({}, native.{});",
+            class_name,
+            export_name(&destructor.function)
+        )),
+        None => f.writeln(&format!("registerClass({});", class_name)),
+    }
+}
+
+fn write_static_class(
+    f: &mut dyn Printer,
+    class: &Handle<StaticClass<Validated>>,
+) -> FormattingResult<()> {
+    f.writeln(&format!("class {} {{", class.name.camel_case(f.naming())))?;
+    indented(f, |f| {
+        for (i, method) in class.static_methods.iter().enumerate() {
+            if i > 0 {
+                f.newline()?;
+            }
+            let name = static_class_method_name(class, method, f.naming());
+            write_method(f, &name, &method.native_function, true)?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn write_struct_initializers<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType,
+{
+    f.writeln(&format!(
+        "const {} = Object.freeze({{",
+        st.name().camel_case(f.naming())
+    ))?;
+    indented(f, |f| {
+        for (i, initializer) in st.initializers.iter().enumerate() {
+            if i > 0 {
+                f.newline()?;
+            }
+            let args = st
+                .initializer_args(initializer.clone())
+                .map(|field| field.name.mixed_case(f.naming()))
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!(
+                "{}({}) {{",
+                initializer.name.mixed_case(f.naming()),
+                args
+            ))?;
+            indented(f, |f| {
+                f.writeln("return {")?;
+                indented(f, |f| {
+                    for field in st.fields() {
+                        let value = initializer
+                            .values
+                            .iter()
+                            .find(|x| x.name == field.name)
+                            .map(|x| default_value_expression(&x.value, f.naming()))
+                            .unwrap_or_else(|| field.name.mixed_case(f.naming()));
+                        f.writeln(&format!(
+                            "{}: {},",
+                            field.name.mixed_case(f.naming()),
+                            value
+                        ))?;
+                    }
+                    Ok(())
+                })?;
+                f.writeln("};")
+            })?;
+            f.writeln("},")?;
+        }
+        Ok(())
+    })?;
+    f.writeln("});")
+}
+
+pub(crate) fn generate(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    print_license(f, lib)?;
+    f.newline()?;
+    f.writeln("\"use strict\";")?;
+    f.newline()?;
+    f.writeln(&format!(
+        "const native = require(\"./build/Release/{}.node\");",
+        lib.settings.name
+    ))?;
+    f.newline()?;
+    f.writeln(include_str!("../../../static/node/runtime.js"))?;
+
+    let mut exports: Vec<String> = Vec::new();
+
+    for set in lib.constants() {
+        f.newline()?;
+        f.writeln(&format!(
+            "const {} = Object.freeze({{",
+            set.name.camel_case(f.naming())
+        ))?;
+        indented(f, |f| {
+            for value in &set.values {
+                let ConstantValue::U8(x, _) = value.value;
+                f.writeln(&format!(
+                    "{}: 0x{:02X},",
+                    value.name.camel_case(f.naming()),
+                    x
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("});")?;
+        exports.push(set.name.camel_case(f.naming()));
+    }
+
+    for handle in lib.enums() {
+        // same shape as a TypeScript enum, including the reverse mapping
+        f.newline()?;
+        f.writeln(&format!(
+            "const {} = Object.freeze({{",
+            handle.name.camel_case(f.naming())
+        ))?;
+        indented(f, |f| {
+            for variant in &handle.variants {
+                f.writeln(&format!(
+                    "{}: {},",
+                    variant.name.camel_case(f.naming()),
+                    variant.value
+                ))?;
+            }
+            for variant in &handle.variants {
+                f.writeln(&format!(
+                    "{}: \"{}\",",
+                    variant.value,
+                    variant.name.camel_case(f.naming())
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("});")?;
+        exports.push(handle.name.camel_case(f.naming()));
+    }
+
+    for error in lib.error_types() {
+        let name = error.exception_name.camel_case(f.naming());
+        f.newline()?;
+        f.writeln(&format!("class {} extends Error {{", name))?;
+        indented(f, |f| {
+            f.writeln("constructor(error) {")?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "super({}[error] ?? `unknown error ${{error}}`);",
+                    error.inner.name.camel_case(f.naming())
+                ))?;
+                f.writeln(&format!("this.name = \"{}\";", name))?;
+                f.writeln("this.error = error;")
+            })?;
+            f.writeln("}")
+        })?;
+        f.writeln("}")?;
+        f.writeln(&format!("registerException({});", name))?;
+        exports.push(name);
+    }
+
+    for st in lib.structs() {
+        let initializers = match st {
+            StructType::FunctionArg(x) => !x.initializers.is_empty(),
+            StructType::FunctionReturn(x) => !x.initializers.is_empty(),
+            StructType::CallbackArg(x) => !x.initializers.is_empty(),
+            StructType::Universal(x) => !x.initializers.is_empty(),
+        };
+        if !initializers {
+            continue;
+        }
+
+        f.newline()?;
+        match st {
+            StructType::FunctionArg(x) => write_struct_initializers(f, x)?,
+            StructType::FunctionReturn(x) => write_struct_initializers(f, x)?,
+            StructType::CallbackArg(x) => write_struct_initializers(f, x)?,
+            StructType::Universal(x) => write_struct_initializers(f, x)?,
+        }
+        exports.push(st.name().camel_case(f.naming()));
+    }
+
+    for class in lib.classes() {
+        f.newline()?;
+        write_class(f, class)?;
+        exports.push(class.name().camel_case(f.naming()));
+    }
+
+    for class in lib.static_classes() {
+        f.newline()?;
+        write_static_class(f, class)?;
+        exports.push(class.name.camel_case(f.naming()));
+    }
+
+    f.newline()?;
+    f.writeln("module.exports = {")?;
+    indented(f, |f| {
+        for name in &exports {
+            f.writeln(&format!("{},", name))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("};")
+}
@@ -0,0 +1,372 @@
+use std::path::PathBuf;
+
+use crate::backend::*;
+use crate::model::*;
+
+mod addon;
+pub(crate) mod conversion;
+pub(crate) mod doc;
+pub(crate) mod javascript;
+pub(crate) mod typescript;
+
+/// Name of the function exported by the addon, the native function without the C prefix
+pub(crate) fn export_name<D>(function: &Function<D>) -> String
+where
+    D: DocReference,
+{
+    function.name.to_string()
+}
+
+/// Method that destroys an instance
+pub(crate) fn destructor_name<D>(class: &Class<D>, naming: &Naming) -> String
+where
+    D: DocReference,
+{
+    match &class.destruction_mode {
+        DestructionMode::Custom(name) => name.mixed_case(naming),
+        DestructionMode::Automatic | DestructionMode::Dispose => "dispose".to_string(),
+    }
+}
+
+/// Destructors of classes that receive asynchronous interfaces may join threads that call back into JavaScript,
+/// so they run on the thread pool instead of blocking the event loop
+pub(crate) fn is_destroyed_off_thread(class: &Class<Validated>) -> bool {
+    let is_asynchronous = |function: &Handle<Function<Validated>>| {
+        function.arguments.iter().any(|arg| match &arg.arg_type {
+            FunctionArgument::Interface(x) => x.mode == InterfaceCategory::Asynchronous,
+            _ => false,
+        })
+    };
+
+    class
+        .constructor
+        .iter()
+        .map(|x| &x.function)
+        .chain(class.methods.iter().map(|x| &x.native_function))
+        .any(is_asynchronous)
+}
+
+/// JavaScript has no overloading, so members that share a name are told apart by their native function
+fn member_name<'a, I>(
+    parent: &Name,
+    names: I,
+    name: &Name,
+    function: &Name,
+    naming: &Naming,
+) -> String
+where
+    I: Iterator<Item = &'a Name>,
+{
+    if names.filter(|x| *x == name).count() < 2 {
+        return name.mixed_case(naming);
+    }
+
+    let prefix = format!("{}_", parent.as_ref());
+    function
+        .strip_prefix(&prefix)
+        .and_then(|x| Name::create(x).ok())
+        .unwrap_or_else(|| function.clone())
+        .mixed_case(naming)
+}
+
+pub(crate) fn method_name<D>(
+    class: &Class<D>,
+    name: &Name,
+    function: &Name,
+    naming: &Naming,
+) -> String
+where
+    D: DocReference,
+{
+    let names = class
+        .methods
+        .iter()
+        .map(|x| &x.name)
+        .chain(class.static_methods.iter().map(|x| &x.name))
+        .chain(class.future_methods.iter().map(|x| &x.name));
+    member_name(class.name(), names, name, function, naming)
+}
+
+pub(crate) fn static_class_method_name<D>(
+    class: &StaticClass<D>,
+    method: &StaticMethod<D>,
+    naming: &Naming,
+) -> String
+where
+    D: DocReference,
+{
+    let names = class.static_methods.iter().map(|x| &x.name);
+    member_name(
+        &class.name,
+        names,
+        &method.name,
+        &method.native_function.name,
+        naming,
+    )
+}
+
+/// Documentation link to a method
+pub(crate) fn class_method_name<D>(class: &Class<D>, name: &Name, naming: &Naming) -> String
+where
+    D: DocReference,
+{
+    let function = class
+        .methods
+        .iter()
+        .find(|x| &x.name == name)
+        .map(|x| &x.native_function.name)
+        .or_else(|| {
+            class
+                .static_methods
+                .iter()
+                .find(|x| &x.name == name)
+                .map(|x| &x.native_function.name)
+        })
+        .or_else(|| {
+            class
+                .future_methods
+                .iter()
+                .find(|x| &x.name == name)
+                .map(|x| &x.native_function.name)
+        });
+
+    match function {
+        Some(function) => format!(
+            "{}.{}",
+            class.name().camel_case(naming),
+            method_name(class, name, function, naming)
+        ),
+        None => format!(
+            "{}.{}",
+            class.name().camel_case(naming),
+            name.mixed_case(naming)
+        ),
+    }
+}
+
+/// Callback argument of a future method, it's replaced by the returned promise
+pub(crate) fn is_future_callback(arg: &Arg<FunctionArgument, Validated>) -> bool {
+    match &arg.arg_type {
+        FunctionArgument::Interface(x) => x.mode == InterfaceCategory::Future,
+        _ => false,
+    }
+}
+
+/// node-gyp condition that matches a Rust platform
+///
+/// Platforms not in this map can't be linked from the addon
+fn gyp_condition(platform: &Platform) -> Option<String> {
+    let os = match platform.target_os {
+        OS::Linux => "linux",
+        OS::MacOS => "mac",
+        OS::Windows => "win",
+        _ => return None,
+    };
+    let arch = match platform.target_arch {
+        Arch::X86_64 => "x64",
+        Arch::X86 => "ia32",
+        Arch::AArch64 => "arm64",
+        Arch::Arm => "arm",
+        _ => return None,
+    };
+    Some(format!(
+        "OS==\\\"{}\\\" and target_arch==\\\"{}\\\"",
+        os, arch
+    ))
+}
+
+pub(crate) struct NodeBindgenConfig {
+    pub(crate) output_dir: PathBuf,
+    pub(crate) ffi_name: &'static str,
+    pub(crate) extra_files: Vec<PathBuf>,
+    pub(crate) platform_locations: PlatformLocations,
+}
+
+pub(crate) fn generate_node_package(
+    lib: &Library,
+    config: &NodeBindgenConfig,
+) -> FormattingResult<()> {
+    logged::create_dir_all(&config.output_dir)?;
+
+    c::header::generate_c_header(lib, &config.output_dir.join("include"))?;
+    generate_package_json(lib, config)?;
+    generate_binding_gyp(lib, config)?;
+    generate_addon(lib, config)?;
+    javascript::generate(
+        &mut FilePrinter::new(
+            config.output_dir.join("index.js"),
+            lib.naming(TargetLanguage::Node),
+        )?,
+        lib,
+    )?;
+    typescript::generate(
+        &mut FilePrinter::new(
+            config.output_dir.join("index.d.ts"),
+            lib.naming(TargetLanguage::Node),
+        )?,
+        lib,
+    )?;
+
+    // for each platform location, copy the library
+    for pl in config.platform_locations.iter() {
+        let span = tracing::info_span!("libs", platform = pl.platform.target_triple);
+        let _entered = span.enter();
+
+        let lib_path = config
+            .output_dir
+            .join("lib")
+            .join(pl.platform.target_triple);
+        logged::create_dir_all(&lib_path)?;
+
+        let lib_filename = pl.platform.dyn_lib_filename(config.ffi_name);
+        logged::copy(
+            pl.location.join(&lib_filename),
+            lib_path.join(&lib_filename),
+        )?;
+
+        let bin_filename = pl.platform.bin_filename(config.ffi_name);
+        logged::copy(
+            pl.location.join(&bin_filename),
+            lib_path.join(&bin_filename),
+        )?;
+    }
+
+    // Copy extra files
+    logged::copy(
+        &lib.info.license_path,
+        config
+            .output_dir
+            .join(lib.info.license_path.file_name().unwrap()),
+    )?;
+    for path in &config.extra_files {
+        logged::copy(path, config.output_dir.join(path.file_name().unwrap()))?;
+    }
+
+    Ok(())
+}
+
+fn generate_package_json(lib: &Library, config: &NodeBindgenConfig) -> FormattingResult<()> {
+    let license = lib
+        .info
+        .license_path
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string();
+
+    let package = serde_json::json!({
+        "name": lib.settings.name.kebab_case(),
+        "version": lib.version.to_string(),
+        "description": lib.info.description,
+        "homepage": lib.info.project_url,
+        "repository": {
+            "type": "git",
+            "url": lib.info.repository,
+        },
+        "license": format!("SEE LICENSE IN {}", license),
+        "main": "index.js",
+        "types": "index.d.ts",
+        "gypfile": true,
+        "scripts": {
+            "install": "node-gyp rebuild",
+        },
+    });
+
+    let mut f = FilePrinter::new(
+        config.output_dir.join("package.json"),
+        lib.naming(TargetLanguage::Node),
+    )?;
+    f.writeln(&serde_json::to_string_pretty(&package).unwrap())
+}
+
+fn generate_binding_gyp(lib: &Library, config: &NodeBindgenConfig) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(
+        config.output_dir.join("binding.gyp"),
+        lib.naming(TargetLanguage::Node),
+    )?;
+
+    f.writeln("{")?;
+    indented(&mut f, |f| {
+        f.writeln("\"targets\": [{")?;
+        indented(f, |f| {
+            f.writeln(&format!("\"target_name\": \"{}\",", lib.settings.name))?;
+            f.writeln("\"sources\": [\"src/addon.c\"],")?;
+            f.writeln("\"include_dirs\": [\"include\"],")?;
+            // the support code and the addon define more conversions than a given library uses
+            f.writeln("\"cflags\": [\"-Wno-unused-function\"],")?;
+            f.writeln("\"xcode_settings\": {\"WARNING_CFLAGS\": [\"-Wno-unused-function\"]},")?;
+            f.writeln("\"conditions\": [")?;
+            indented(f, |f| {
+                let mut conditions: Vec<String> = Vec::new();
+                for pl in config.platform_locations.iter() {
+                    let condition = match gyp_condition(&pl.platform) {
+                        Some(x) => x,
+                        None => continue,
+                    };
+                    // e.g. GNU and MUSL libraries can't be told apart by a condition
+                    if conditions.contains(&condition) {
+                        continue;
+                    }
+
+                    let lib_dir = format!("<(module_root_dir)/lib/{}", pl.platform.target_triple);
+                    f.writeln(&format!("[\"{}\", {{", condition))?;
+                    indented(f, |f| {
+                        if pl.platform.target_os == OS::Windows {
+                            // the import library is linked and the DLL is copied next to the addon
+                            f.writeln(&format!(
+                                "\"libraries\": [\"{}/{}\"],",
+                                lib_dir,
+                                pl.platform.dyn_lib_filename(config.ffi_name)
+                            ))?;
+                            f.writeln("\"copies\": [{")?;
+                            indented(f, |f| {
+                                f.writeln("\"destination\": \"<(PRODUCT_DIR)\",")?;
+                                f.writeln(&format!(
+                                    "\"files\": [\"{}/{}\"]",
+                                    lib_dir,
+                                    pl.platform.bin_filename(config.ffi_name)
+                                ))
+                            })?;
+                            f.writeln("}]")
+                        } else {
+                            f.writeln(&format!(
+                                "\"libraries\": [\"-L{}\", \"-l{}\", \"-Wl,-rpath,{}\"]",
+                                lib_dir, config.ffi_name, lib_dir
+                            ))
+                        }
+                    })?;
+                    f.writeln("}],")?;
+                    conditions.push(condition);
+                }
+                Ok(())
+            })?;
+            f.writeln("]")
+        })?;
+        f.writeln("}]")
+    })?;
+    f.writeln("}")
+}
+
+fn generate_addon(lib: &Library, config: &NodeBindgenConfig) -> FormattingResult<()> {
+    let src = config.output_dir.join("src");
+    logged::create_dir_all(&src)?;
+
+    let mut f = FilePrinter::new(src.join("napi_support.h"), lib.naming(TargetLanguage::Node))?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+    f.writeln(include_str!("../../../static/node/napi_support.h"))?;
+
+    let mut f = FilePrinter::new(src.join("addon.c"), lib.naming(TargetLanguage::Node))?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+    addon::generate(&mut f, lib)
+}
+
+fn print_license(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    commented(f, |f| {
+        for line in lib.info.license_description.iter() {
+            f.writeln(line)?;
+        }
+        Ok(())
+    })
+}
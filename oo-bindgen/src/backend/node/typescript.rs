@@ -0,0 +1,448 @@
+use super::conversion::*;
+use super::doc::*;
+use super::*;
+
+fn params<'a, I>(args: I, naming: &Naming) -> String
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    args.map(|arg| {
+        let optional = if arg.default.is_some() { "?" } else { "" };
+        format!(
+            "{}{}: {}",
+            arg.name.mixed_case(naming),
+            optional,
+            arg.arg_type.ts_type(naming)
+        )
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+fn return_type(function: &Function<Validated>, naming: &Naming) -> String {
+    match function.return_type.get_value() {
+        None => "void".to_string(),
+        Some(x) => x.ts_type(naming),
+    }
+}
+
+fn write_method(
+    f: &mut dyn Printer,
+    name: &str,
+    function: &Function<Validated>,
+    is_static: bool,
+) -> FormattingResult<()> {
+    let skip = if is_static { 0 } else { 1 };
+    jsdoc(f, |f| {
+        jsdoc_print_function(
+            f,
+            function,
+            function.arguments.iter().skip(skip),
+            function.return_type.get_doc(),
+        )
+    })?;
+    f.writeln(&format!(
+        "{}{}({}): {};",
+        if is_static { "static " } else { "" },
+        name,
+        params(function.arguments.iter().skip(skip), f.naming()),
+        return_type(function, f.naming())
+    ))
+}
+
+fn write_future_method(
+    f: &mut dyn Printer,
+    class: &Class<Validated>,
+    method: &FutureMethod<Validated>,
+) -> FormattingResult<()> {
+    let function = &method.native_function;
+    jsdoc(f, |f| {
+        jsdoc_print_function(
+            f,
+            function,
+            method.arguments_without_callback(),
+            Some(&method.future.value_type_doc),
+        )?;
+        f.newline()?;
+        f.writeln("The promise is rejected if the operation fails")
+    })?;
+    f.writeln(&format!(
+        "{}({}): Promise<{}>;",
+        method_name(class, &method.name, &function.name, f.naming()),
+        params(method.arguments_without_callback(), f.naming()),
+        method.future.value_type.ts_type(f.naming())
+    ))
+}
+
+fn write_class(f: &mut dyn Printer, class: &Handle<Class<Validated>>) -> FormattingResult<()> {
+    jsdoc(f, |f| jsdoc_print(f, &class.doc))?;
+    f.writeln(&format!(
+        "export declare class {} {{",
+        class.name().camel_case(f.naming())
+    ))?;
+    indented(f, |f| {
+        match &class.constructor {
+            Some(constructor) => {
+                let function = &constructor.function;
+                jsdoc(f, |f| {
+                    jsdoc_print_function(f, function, function.arguments.iter(), None)
+                })?;
+                f.writeln(&format!(
+                    "constructor({});",
+                    params(function.arguments.iter(), f.naming())
+                ))?;
+            }
+            None => f.writeln("private constructor();")?,
+        }
+
+        if let Some(destructor) = &class.destructor {
+            f.newline()?;
+            jsdoc(f, |f| {
+                jsdoc_print(f, &destructor.function.doc)?;
+                f.newline()?;
+                f.writeln(
+                    "It is safe to call more than once and the instance can't be used afterwards.",
+                )?;
+                if class.destruction_mode == DestructionMode::Automatic {
+                    f.writeln("It is also called by the garbage collector when the instance is unreachable.")?;
+                } else {
+                    f.writeln("The garbage collector calls it if the instance becomes unreachable, but it should be called explicitly.")?;
+                }
+                if is_destroyed_off_thread(class) {
+                    f.writeln("The native instance is destroyed in the background, so the callbacks it has already queued may still run.")?;
+                }
+                Ok(())
+            })?;
+            f.writeln(&format!("{}(): void;", destructor_name(class, f.naming())))?;
+            f.newline()?;
+            jsdoc(f, |f| {
+                f.writeln(&format!(
+                    "Calls {{@link {}.{}}} at the end of a `using` block",
+                    class.name().camel_case(f.naming()),
+                    destructor_name(class, f.naming())
+                ))
+            })?;
+            f.writeln("[Symbol.dispose](): void;")?;
+        }
+
+        for method in &class.methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_method(f, &name, &method.native_function, false)?;
+        }
+
+        for method in &class.future_methods {
+            f.newline()?;
+            write_future_method(f, class, method)?;
+        }
+
+        for method in &class.static_methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_method(f, &name, &method.native_function, true)?;
+        }
+
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn write_static_class(
+    f: &mut dyn Printer,
+    class: &Handle<StaticClass<Validated>>,
+) -> FormattingResult<()> {
+    jsdoc(f, |f| jsdoc_print(f, &class.doc))?;
+    f.writeln(&format!(
+        "export declare class {} {{",
+        class.name.camel_case(f.naming())
+    ))?;
+    indented(f, |f| {
+        f.writeln("private constructor();")?;
+        for method in &class.static_methods {
+            f.newline()?;
+            let name = static_class_method_name(class, method, f.naming());
+            write_method(f, &name, &method.native_function, true)?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+/// Structs are plain objects described by an interface
+pub(crate) fn write_struct_declaration<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + TypeScriptType,
+{
+    let doc = match st.visibility {
+        Visibility::Public => st.doc.clone(),
+        Visibility::Private => st
+            .doc
+            .clone()
+            .warning("This struct is an opaque handle and cannot be constructed by user code"),
+    };
+
+    jsdoc(f, |f| jsdoc_print(f, &doc))?;
+    f.writeln(&format!(
+        "export interface {} {{",
+        st.name().camel_case(f.naming())
+    ))?;
+    indented(f, |f| {
+        for field in st.fields() {
+            jsdoc(f, |f| jsdoc_print(f, &field.doc))?;
+            f.writeln(&format!(
+                "{}: {};",
+                field.name.mixed_case(f.naming()),
+                field.field_type.ts_type(f.naming())
+            ))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn write_struct<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + TypeScriptType,
+{
+    let struct_name = st.name().camel_case(f.naming());
+
+    write_struct_declaration(f, st)?;
+
+    if st.initializers.is_empty() {
+        return Ok(());
+    }
+
+    f.newline()?;
+    jsdoc(f, |f| {
+        f.newline()?;
+        f.write(&format!("Initializers of {{@link {}}}", struct_name))
+    })?;
+    f.writeln(&format!("export declare const {}: {{", struct_name))?;
+    indented(f, |f| {
+        for initializer in &st.initializers {
+            jsdoc(f, |f| {
+                jsdoc_print(f, &initializer.doc)?;
+                if !initializer.values.is_empty() {
+                    f.newline()?;
+                    f.writeln("Values are initialized to:")?;
+                    for value in initializer.values.iter() {
+                        f.writeln(&format!(
+                            "- {}: `{}`",
+                            value.name.mixed_case(f.naming()),
+                            javascript::default_value_expression(&value.value, f.naming())
+                        ))?;
+                    }
+                }
+                Ok(())
+            })?;
+            let args = st
+                .initializer_args(initializer.clone())
+                .map(|field| {
+                    format!(
+                        "{}: {}",
+                        field.name.mixed_case(f.naming()),
+                        field.field_type.ts_type(f.naming())
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!(
+                "{}({}): {};",
+                initializer.name.mixed_case(f.naming()),
+                args,
+                struct_name
+            ))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("};")
+}
+
+fn write_interface(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+) -> FormattingResult<()> {
+    jsdoc(f, |f| {
+        jsdoc_print(f, &interface.doc)?;
+        if interface.mode == InterfaceCategory::Asynchronous {
+            f.newline()?;
+            f.writeln("Methods may be invoked from another thread, the calling thread then waits until the event loop has run them")?;
+        }
+        Ok(())
+    })?;
+    f.writeln(&format!(
+        "export interface {} {{",
+        interface.name.camel_case(f.naming())
+    ))?;
+    indented(f, |f| write_interface_callbacks(f, interface))?;
+    f.writeln("}")
+}
+
+/// Methods of the objects that implement an interface
+pub(crate) fn write_interface_callbacks(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+) -> FormattingResult<()> {
+    for cb in &interface.callbacks {
+        jsdoc(f, |f| {
+            jsdoc_print(f, &cb.doc)?;
+            for arg in &cb.arguments {
+                f.writeln(&format!("@param {} ", arg.name.mixed_case(f.naming())))?;
+                docstring_print(f, &arg.doc)?;
+            }
+            if let Some(doc) = cb.return_type.get_doc() {
+                f.writeln("@returns ")?;
+                docstring_print(f, doc)?;
+            }
+            Ok(())
+        })?;
+
+        let args = cb
+            .arguments
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    arg.name.mixed_case(f.naming()),
+                    arg.arg_type.ts_type(f.naming())
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let returns = match cb.return_type.get_value() {
+            None => "void".to_string(),
+            Some(x) => x.ts_type(f.naming()),
+        };
+        // methods with a default implementation may be omitted
+        let optional = if cb.default_implementation.is_some() {
+            "?"
+        } else {
+            ""
+        };
+        f.writeln(&format!(
+            "{}{}({}): {};",
+            cb.name.mixed_case(f.naming()),
+            optional,
+            args,
+            returns
+        ))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn generate(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    print_license(f, lib)?;
+
+    for set in lib.constants() {
+        f.newline()?;
+        jsdoc(f, |f| jsdoc_print(f, &set.doc))?;
+        f.writeln(&format!(
+            "export declare const {}: {{",
+            set.name.camel_case(f.naming())
+        ))?;
+        indented(f, |f| {
+            for value in &set.values {
+                jsdoc(f, |f| jsdoc_print(f, &value.doc))?;
+                f.writeln(&format!(
+                    "readonly {}: number;",
+                    value.name.camel_case(f.naming())
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("};")?;
+    }
+
+    for handle in lib.enums() {
+        f.newline()?;
+        jsdoc(f, |f| jsdoc_print(f, &handle.doc))?;
+        f.writeln(&format!(
+            "export declare enum {} {{",
+            handle.name.camel_case(f.naming())
+        ))?;
+        indented(f, |f| {
+            for variant in &handle.variants {
+                jsdoc(f, |f| jsdoc_print(f, &variant.doc))?;
+                f.writeln(&format!(
+                    "{} = {},",
+                    variant.name.camel_case(f.naming()),
+                    variant.value
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("}")?;
+    }
+
+    for error in lib.error_types() {
+        f.newline()?;
+        jsdoc(f, |f| {
+            f.newline()?;
+            f.write(&format!(
+                "Thrown when a native function fails with a {{@link {}}}",
+                error.inner.name.camel_case(f.naming())
+            ))
+        })?;
+        f.writeln(&format!(
+            "export declare class {} extends Error {{",
+            error.exception_name.camel_case(f.naming())
+        ))?;
+        indented(f, |f| {
+            f.writeln(&format!(
+                "constructor(error: {});",
+                error.inner.name.camel_case(f.naming())
+            ))?;
+            f.writeln(&format!(
+                "readonly error: {};",
+                error.inner.name.camel_case(f.naming())
+            ))
+        })?;
+        f.writeln("}")?;
+    }
+
+    for st in lib.structs() {
+        f.newline()?;
+        match st {
+            StructType::FunctionArg(x) => write_struct(f, x)?,
+            StructType::FunctionReturn(x) => write_struct(f, x)?,
+            StructType::CallbackArg(x) => write_struct(f, x)?,
+            StructType::Universal(x) => write_struct(f, x)?,
+        }
+    }
+
+    // futures are exposed as promises
+    for interface in lib.interfaces() {
+        match interface {
+            InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) => {
+                f.newline()?;
+                write_interface(f, x)?;
+            }
+            InterfaceType::Future(_) => {}
+        }
+    }
+
+    for class in lib.classes() {
+        f.newline()?;
+        write_class(f, class)?;
+    }
+
+    for class in lib.static_classes() {
+        f.newline()?;
+        write_static_class(f, class)?;
+    }
+
+    Ok(())
+}
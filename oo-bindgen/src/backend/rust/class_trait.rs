@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use heck::CamelCase;
+
+use crate::backend::rust::rust_type::{LifetimeInfo, RustType};
+use crate::backend::rust::type_converter::TypeConverter;
+use crate::backend::*;
+use crate::model::*;
+
+/// How an FFI shim reaches the implementation of a class function through its trait
+pub(crate) struct ClassDispatch {
+    class: String,
+    kind: DispatchKind,
+}
+
+enum DispatchKind {
+    Constructor(String),
+    Destructor(String),
+    Method(String),
+}
+
+impl ClassDispatch {
+    fn new(class: &Name, kind: DispatchKind) -> Self {
+        Self {
+            class: class.to_camel_case(),
+            kind,
+        }
+    }
+
+    /// Expression invoking the trait implementation with the (already converted) arguments
+    pub(crate) fn invocation(&self, handle: &Function<Validated>) -> String {
+        let target = format!("<crate::{0} as {0}Api>", self.class);
+        let args = |skip| {
+            handle
+                .arguments
+                .iter()
+                .skip(skip)
+                .map(|param| param.name.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        match &self.kind {
+            DispatchKind::Constructor(name) => {
+                let create = format!("{}::{}({})", target, name, args(0).join(", "));
+                if handle.error_type.get().is_some() {
+                    format!("{}.map(|x| Box::into_raw(Box::new(x)))", create)
+                } else {
+                    format!("Box::into_raw(Box::new({}))", create)
+                }
+            }
+            DispatchKind::Destructor(name) => {
+                let instance = &handle.arguments[0].name;
+                format!(
+                    "if !{instance}.is_null() {{ {}::{}(Box::from_raw({instance})) }}",
+                    target,
+                    name,
+                    instance = instance
+                )
+            }
+            DispatchKind::Method(name) => {
+                let args: Vec<String> = args(0);
+                format!("{}::{}({})", target, name, args.join(", "))
+            }
+        }
+    }
+
+    /// Write the conversion of the instance pointer of a method to a reference, returning early
+    /// when it is null
+    ///
+    /// A method invoked on a null instance does nothing. It returns the invalid argument error of
+    /// its error type, or the first error if there is none, and a zeroed value otherwise.
+    pub(crate) fn write_instance_check(
+        &self,
+        f: &mut dyn Printer,
+        handle: &Function<Validated>,
+    ) -> FormattingResult<()> {
+        if !matches!(self.kind, DispatchKind::Method(_)) {
+            return Ok(());
+        }
+
+        let instance = &handle.arguments[0].name;
+        f.writeln(&format!("let {} = match {}.as_ref()", instance, instance))?;
+        blocked(f, |f| {
+            f.writeln("Some(x) => x,")?;
+            f.writeln("None =>")?;
+            blocked(f, |f| match handle.error_type.get() {
+                None if handle.return_type.is_none() => f.writeln("return;"),
+                None => f.writeln("return std::mem::zeroed();"),
+                Some(err) => {
+                    let variant = err
+                        .invalid_argument_variant()
+                        .or_else(|| err.inner.variants.get(1).map(|x| &x.name));
+                    match variant {
+                        Some(variant) => {
+                            let converter = TypeConverter::ValidatedEnum(err.inner.clone());
+                            let value = format!(
+                                "{}::{}",
+                                err.inner.name.to_camel_case(),
+                                variant.to_camel_case()
+                            );
+                            converter.convert_to_c(f, &value, "return ")?;
+                            f.write(";")
+                        }
+                        None => f.writeln("return std::mem::zeroed();"),
+                    }
+                }
+            })
+        })?;
+        f.write(";")
+    }
+}
+
+/// Name of the trait function backing a class function, i.e. the native name without the class prefix
+fn trait_function_name(class: &Name, function: &Function<Validated>) -> String {
+    let prefix = format!("{}_", class.as_ref());
+    match function.name.strip_prefix(prefix.as_str()) {
+        Some(name) => name.to_string(),
+        None => function.name.to_string(),
+    }
+}
+
+/// Map the native functions of every instance of the classes with a trait (constructor, destructor,
+/// methods and future methods) to their trait dispatch. Static methods remain free functions.
+pub(crate) fn dispatch_table(
+    lib: &Library,
+    has_trait: &dyn Fn(&Name) -> bool,
+) -> HashMap<Name, ClassDispatch> {
+    let mut table = HashMap::new();
+
+    for class in lib.classes().filter(|x| has_trait(x.name())) {
+        let name = class.name();
+        if let Some(x) = &class.constructor {
+            let kind = DispatchKind::Constructor(trait_function_name(name, &x.function));
+            table.insert(x.function.name.clone(), ClassDispatch::new(name, kind));
+        }
+        if let Some(x) = &class.destructor {
+            let kind = DispatchKind::Destructor(trait_function_name(name, &x.function));
+            table.insert(x.function.name.clone(), ClassDispatch::new(name, kind));
+        }
+        let methods = class
+            .methods
+            .iter()
+            .map(|x| &x.native_function)
+            .chain(class.future_methods.iter().map(|x| &x.native_function));
+        for function in methods {
+            let kind = DispatchKind::Method(trait_function_name(name, function));
+            table.insert(function.name.clone(), ClassDispatch::new(name, kind));
+        }
+    }
+
+    table
+}
+
+enum Receiver {
+    None,
+    Instance,
+    Boxed,
+}
+
+/// Write the `{Class}Api` trait that the implementation of a class must provide
+pub(crate) fn write_class_trait(
+    f: &mut dyn Printer,
+    handle: &Handle<Class<Validated>>,
+) -> FormattingResult<()> {
+    let name = handle.name();
+
+    f.writeln(&format!("pub trait {}Api: Sized", name.to_camel_case()))?;
+    blocked(f, |f| {
+        if let Some(x) = &handle.constructor {
+            write_trait_function(f, name, &x.function, Receiver::None, true)?;
+        }
+        for method in &handle.methods {
+            write_trait_function(f, name, &method.native_function, Receiver::Instance, false)?;
+        }
+        for method in &handle.future_methods {
+            write_trait_function(f, name, &method.native_function, Receiver::Instance, false)?;
+        }
+        if let Some(x) = &handle.destructor {
+            write_trait_function(f, name, &x.function, Receiver::Boxed, false)?;
+        }
+        Ok(())
+    })
+}
+
+fn write_trait_function(
+    f: &mut dyn Printer,
+    class: &Name,
+    handle: &Function<Validated>,
+    receiver: Receiver,
+    constructor: bool,
+) -> FormattingResult<()> {
+    let arguments = match receiver {
+        Receiver::None => &handle.arguments[..],
+        Receiver::Instance | Receiver::Boxed => &handle.arguments[1..],
+    };
+
+    let return_type = |t: &FunctionReturnValue| {
+        if constructor {
+            "Self".to_string()
+        } else {
+            t.as_rust_type()
+        }
+    };
+    let (return_type, return_lifetime) = match handle.get_signature_type() {
+        SignatureType::NoErrorNoReturn => (None, false),
+        SignatureType::NoErrorWithReturn(t, _) => {
+            (Some(return_type(&t)), t.rust_requires_lifetime())
+        }
+        SignatureType::ErrorNoReturn(err) => (
+            Some(format!("Result<(), {}>", err.inner.name.to_camel_case())),
+            false,
+        ),
+        SignatureType::ErrorWithReturn(err, t, _) => (
+            Some(format!(
+                "Result<{}, {}>",
+                return_type(&t),
+                err.inner.name.to_camel_case()
+            )),
+            t.rust_requires_lifetime(),
+        ),
+    };
+
+    let lifetime = return_lifetime
+        || arguments
+            .iter()
+            .any(|arg| arg.arg_type.rust_requires_lifetime());
+
+    let destructor = matches!(receiver, Receiver::Boxed);
+    let receiver = match receiver {
+        Receiver::None => None,
+        Receiver::Instance if return_lifetime => Some("&'a self".to_string()),
+        Receiver::Instance => Some("&self".to_string()),
+        Receiver::Boxed => Some("self: Box<Self>".to_string()),
+    };
+
+    let parameters = receiver
+        .into_iter()
+        .chain(
+            arguments
+                .iter()
+                .map(|arg| format!("{}: {}", arg.name, arg.arg_type.as_rust_type())),
+        )
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    if lifetime {
+        f.writeln("#[allow(clippy::needless_lifetimes)]")?;
+    }
+    f.writeln(&format!(
+        "fn {}{}({})",
+        trait_function_name(class, handle),
+        if lifetime { "<'a>" } else { "" },
+        parameters
+    ))?;
+    if let Some(return_type) = return_type {
+        f.write(&format!(" -> {}", return_type))?;
+    }
+
+    // the instance is simply dropped unless the implementation needs to do more
+    if destructor {
+        f.write(" {}")
+    } else {
+        f.write(";")
+    }
+}
@@ -0,0 +1,195 @@
+use heck::CamelCase;
+
+use crate::backend::rust::mock::write_signature;
+use crate::backend::rust::rust_type::RustType;
+use crate::backend::*;
+use crate::conformance::{Case, Suite};
+use crate::model::*;
+
+/// Write the implementation of the conformance functions
+pub(crate) fn generate(f: &mut dyn Printer, suite: &Suite) -> FormattingResult<()> {
+    f.writeln("use crate::ffi::*;")?;
+
+    for case in &suite.cases {
+        f.newline()?;
+        match case {
+            Case::Echo { method, .. } => {
+                write_signature(f, &method.function)?;
+                blocked(f, |f| f.writeln("value"))?;
+            }
+            Case::Raise { method, error } => {
+                write_signature(f, &method.function)?;
+                blocked(f, |f| write_raise(f, error))?;
+            }
+            Case::List {
+                collection,
+                size,
+                get,
+            } => write_list(f, collection, &size.function, &get.function)?,
+            Case::Iterate {
+                method,
+                receiver,
+                iterator,
+                ..
+            } => write_iterate(f, &method.function, receiver, iterator)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_raise(f: &mut dyn Printer, error: &ErrorType<Validated>) -> FormattingResult<()> {
+    let name = error.inner.name.to_camel_case();
+    f.writeln("match code")?;
+    blocked(f, |f| {
+        // the first variant is the absence of error
+        for variant in error.inner.variants.iter().skip(1) {
+            f.writeln(&format!(
+                "{} => Err({}::{}),",
+                variant.value,
+                name,
+                variant.name.to_camel_case()
+            ))?;
+        }
+        f.writeln("_ => Ok(()),")
+    })
+}
+
+fn write_list(
+    f: &mut dyn Printer,
+    collection: &Collection<Validated>,
+    size: &Function<Validated>,
+    get: &Function<Validated>,
+) -> FormattingResult<()> {
+    let (item_type, store, read) = match &collection.item_type {
+        FunctionArgument::String(_) => (
+            "std::ffi::CString".to_string(),
+            "value.to_owned()",
+            ".map(|x| x.as_c_str())",
+        ),
+        FunctionArgument::Basic(BasicType::Primitive(x)) => (
+            BasicType::Primitive(*x).as_rust_type(),
+            "value",
+            ".copied()",
+        ),
+        _ => unimplemented!("conformance lists only hold strings and primitives"),
+    };
+
+    f.writeln("#[derive(Default)]")?;
+    f.writeln(&format!("pub struct {}", collection.name().to_camel_case()))?;
+    blocked(f, |f| f.writeln(&format!("values: Vec<{}>,", item_type)))?;
+
+    f.newline()?;
+    write_signature(f, &collection.create_func)?;
+    blocked(f, |f| f.writeln("Box::into_raw(Box::default())"))?;
+
+    f.newline()?;
+    write_signature(f, &collection.delete_func)?;
+    blocked(f, |f| {
+        let instance = &collection.delete_func.arguments[0].name;
+        f.writeln(&format!("if !{}.is_null()", instance))?;
+        blocked(f, |f| {
+            f.writeln(&format!("drop(unsafe {{ Box::from_raw({}) }});", instance))
+        })
+    })?;
+
+    f.newline()?;
+    write_signature(f, &collection.add_func)?;
+    blocked(f, |f| {
+        let instance = &collection.add_func.arguments[0].name;
+        f.writeln(&format!(
+            "if let Some(list) = unsafe {{ {}.as_mut() }}",
+            instance
+        ))?;
+        blocked(f, |f| f.writeln(&format!("list.values.push({});", store)))
+    })?;
+
+    f.newline()?;
+    write_signature(f, size)?;
+    blocked(f, |f| {
+        f.writeln("unsafe { list.as_ref() }")?;
+        indented(f, |f| {
+            f.writeln(".map(|x| x.values.len() as u32)")?;
+            f.writeln(".unwrap_or_default()")
+        })
+    })?;
+
+    f.newline()?;
+    write_signature(f, get)?;
+    blocked(f, |f| {
+        f.writeln("unsafe { list.as_ref() }")?;
+        indented(f, |f| {
+            f.writeln(".and_then(|x| x.values.get(index as usize))")?;
+            f.writeln(read)?;
+            f.writeln(".unwrap_or_default()")
+        })
+    })
+}
+
+fn write_iterate(
+    f: &mut dyn Printer,
+    function: &Function<Validated>,
+    receiver: &InterfaceHandle,
+    iterator: &AbstractIterator<Validated>,
+) -> FormattingResult<()> {
+    let item_type = match &iterator.item_type {
+        IteratorItemType::Primitive(x) => BasicType::Primitive(*x).as_rust_type(),
+        IteratorItemType::Struct(_) => unimplemented!("conformance iterators only hold primitives"),
+    };
+    let name = iterator.iter_class.name.to_camel_case();
+    let callback = receiver
+        .callbacks
+        .first()
+        .ok_or("conformance receivers have a callback")?;
+
+    f.writeln(&format!("pub struct {}", name))?;
+    blocked(f, |f| {
+        f.writeln(&format!("values: std::vec::IntoIter<{}>,", item_type))?;
+        f.writeln(&format!("current: {},", item_type))
+    })?;
+
+    f.newline()?;
+    write_signature(f, &iterator.next_function)?;
+    blocked(f, |f| {
+        let instance = &iterator.next_function.arguments[0].name;
+        f.writeln(&format!(
+            "let it = match unsafe {{ {}.as_mut() }}",
+            instance
+        ))?;
+        blocked_open_close(f, "{", "};", |f| {
+            f.writeln("Some(x) => x,")?;
+            f.writeln("None => return std::ptr::null(),")
+        })?;
+        f.writeln("match it.values.next()")?;
+        blocked(f, |f| {
+            f.writeln("Some(x) =>")?;
+            blocked(f, |f| {
+                f.writeln("it.current = x;")?;
+                f.writeln("&it.current")
+            })?;
+            f.writeln("None => std::ptr::null(),")
+        })
+    })?;
+
+    f.newline()?;
+    write_signature(f, function)?;
+    blocked(f, |f| {
+        f.writeln(&format!(
+            "let values = unsafe {{ {}.as_ref() }}",
+            function.arguments[0].name
+        ))?;
+        indented(f, |f| {
+            f.writeln(".map(|x| x.values.clone())")?;
+            f.writeln(".unwrap_or_default();")
+        })?;
+        f.writeln(&format!("let mut it = {}", name))?;
+        blocked_open_close(f, "{", "};", |f| {
+            f.writeln("values: values.into_iter(),")?;
+            f.writeln("current: Default::default(),")
+        })?;
+        f.writeln(&format!(
+            "{}.{}(&mut it);",
+            function.arguments[1].name, callback.name
+        ))
+    })
+}
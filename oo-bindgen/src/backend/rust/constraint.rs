@@ -0,0 +1,71 @@
+use crate::backend::*;
+use crate::model::*;
+
+fn number_literal(value: NumberValue) -> String {
+    match value {
+        NumberValue::Float(x) => format!("{:?}", x),
+        NumberValue::Double(x) => format!("{:?}", x),
+        x => x.to_string(),
+    }
+}
+
+fn zero_literal(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Float | Primitive::Double => "0.0",
+        _ => "0",
+    }
+}
+
+/// Write a check that invokes `on_violation` if `value` violates the constraint
+///
+/// `value` is an expression of the Rust type of the argument or field,
+/// i.e. strings are `&CStr` and durations are `std::time::Duration`
+pub(crate) fn write_constraint_check(
+    f: &mut dyn Printer,
+    constraint: &Constraint,
+    target: ConstrainedType,
+    value: &str,
+    on_violation: &dyn Fn(&mut dyn Printer) -> FormattingResult<()>,
+) -> FormattingResult<()> {
+    let condition = match constraint {
+        Constraint::Range { .. } => {
+            match (constraint.lower_bound(), constraint.upper_bound()) {
+                (Some(min), Some(max)) => format!(
+                    "!({}..={}).contains(&{})",
+                    number_literal(min),
+                    number_literal(max),
+                    value
+                ),
+                (Some(min), None) => format!("{} < {}", value, number_literal(min)),
+                (None, Some(max)) => format!("{} > {}", value, number_literal(max)),
+                // the range covers every value of the type
+                (None, None) => return Ok(()),
+            }
+        }
+        Constraint::NonZero => match target {
+            ConstrainedType::Primitive(x) => format!("{} == {}", value, zero_literal(x)),
+            _ => format!("{}.is_zero()", value),
+        },
+        Constraint::NonEmpty => format!("{}.to_bytes().is_empty()", value),
+        Constraint::MaxLength(x) => format!("{}.to_bytes().len() > {}", value, x),
+        Constraint::Regex(_) => {
+            // compile the pattern once and reuse it for every call
+            let pattern = constraint.anchored_regex().unwrap();
+            return blocked(f, |f| {
+                f.writeln("static PATTERN: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();")?;
+                f.writeln(&format!(
+                    "let pattern = PATTERN.get_or_init(|| regex::Regex::new({:?}).unwrap());",
+                    pattern
+                ))?;
+                f.writeln(&format!(
+                    "if !matches!({}.to_str(), Ok(x) if pattern.is_match(x))",
+                    value
+                ))?;
+                blocked(f, on_violation)
+            });
+        }
+    };
+
+    f.writeln(&format!("if {}", condition))?;
+    blocked(f, on_violation)
+}
@@ -0,0 +1,250 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::sync::Mutex;
+
+/// Text representation of a value recorded in the call log
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+/// Value that can be configured from its text representation
+pub trait Parse: Sized {
+    fn parse(value: &str) -> Option<Self>;
+}
+
+macro_rules! text_value {
+    ($($t:ty),*) => {
+        $(
+            impl Describe for $t {
+                fn describe(&self) -> String {
+                    self.to_string()
+                }
+            }
+
+            impl Parse for $t {
+                fn parse(value: &str) -> Option<Self> {
+                    value.trim().parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+text_value!(bool, u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl Describe for () {
+    fn describe(&self) -> String {
+        String::new()
+    }
+}
+
+impl Describe for CStr {
+    fn describe(&self) -> String {
+        self.to_string_lossy().into_owned()
+    }
+}
+
+impl Describe for std::time::Duration {
+    fn describe(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl<T: Describe + ?Sized> Describe for &T {
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+}
+
+impl<T: Describe> Describe for Option<T> {
+    fn describe(&self) -> String {
+        match self {
+            Some(x) => x.describe(),
+            None => "null".to_string(),
+        }
+    }
+}
+
+impl<T> Describe for *mut T {
+    fn describe(&self) -> String {
+        if self.is_null() { "null" } else { "instance" }.to_string()
+    }
+}
+
+impl<T> Describe for *const T {
+    fn describe(&self) -> String {
+        if self.is_null() { "null" } else { "instance" }.to_string()
+    }
+}
+
+/// Function invocation recorded by the mock
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    /// Native name of the function
+    pub function: String,
+    /// Text representation of each argument
+    pub arguments: Vec<String>,
+}
+
+struct Interfaces(Vec<Box<dyn Any>>);
+
+// only the interfaces of the FFI layer are stored and all of them are Send
+unsafe impl Send for Interfaces {}
+
+#[derive(Default)]
+struct State {
+    calls: Vec<Call>,
+    returns: HashMap<String, CString>,
+    errors: HashMap<String, String>,
+    interfaces: HashMap<String, Interfaces>,
+    text: CString,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+fn with<R>(action: impl FnOnce(&mut State) -> R) -> R {
+    let mut state = STATE.lock().unwrap_or_else(|err| err.into_inner());
+    action(state.get_or_insert_with(Default::default))
+}
+
+/// Forget every call, configured value and interface
+pub fn reset() {
+    // interfaces notify the host language when they are dropped, so drop them outside the lock
+    let _previous = with(std::mem::take);
+}
+
+/// All the calls recorded since the last reset
+pub fn calls() -> Vec<Call> {
+    with(|state| state.calls.clone())
+}
+
+/// Number of times a function was called since the last reset
+pub fn call_count(function: &str) -> usize {
+    with(|state| {
+        state
+            .calls
+            .iter()
+            .filter(|call| call.function == function)
+            .count()
+    })
+}
+
+/// Arguments of the most recent call to a function
+pub fn last_arguments(function: &str) -> Option<Vec<String>> {
+    with(|state| {
+        state
+            .calls
+            .iter()
+            .rev()
+            .find(|call| call.function == function)
+            .map(|call| call.arguments.clone())
+    })
+}
+
+/// Configure the value returned by a function, e.g. `42`, `true`, `enum_variant` or any string
+pub fn set_return(function: &str, value: &str) {
+    let value = CString::new(value).unwrap_or_default();
+    with(|state| state.returns.insert(function.to_string(), value));
+}
+
+/// Configure the error returned by a function, e.g. `bad_password`
+pub fn set_error(function: &str, error: &str) {
+    with(|state| state.errors.insert(function.to_string(), error.to_string()));
+}
+
+/// Clear the configured return value and error of a function
+pub fn clear(function: &str) {
+    with(|state| {
+        state.returns.remove(function);
+        state.errors.remove(function);
+    });
+}
+
+/// Keep a string alive until the next call to this function and return a pointer to it
+pub fn text(value: &str) -> *const std::os::raw::c_char {
+    let value = CString::new(value).unwrap_or_default();
+    with(|state| {
+        state.text = value;
+        state.text.as_ptr()
+    })
+}
+
+pub(crate) fn record(function: &str, arguments: Vec<String>) {
+    let call = Call {
+        function: function.to_string(),
+        arguments,
+    };
+    with(|state| state.calls.push(call));
+}
+
+pub(crate) fn configured<T: Parse>(function: &str) -> Option<T> {
+    with(|state| {
+        state
+            .returns
+            .get(function)
+            .and_then(|value| T::parse(value.to_str().ok()?))
+    })
+}
+
+/// The string remains valid until the function is configured again or the mock is reset
+pub(crate) fn configured_string(function: &str) -> Option<&'static CStr> {
+    with(|state| {
+        state
+            .returns
+            .get(function)
+            .map(|value| unsafe { &*(value.as_c_str() as *const CStr) })
+    })
+}
+
+pub(crate) fn error<T: Parse>(function: &str) -> Option<T> {
+    with(|state| state.errors.get(function).and_then(|err| T::parse(err)))
+}
+
+pub(crate) fn argument<T: Parse>(arguments: &[&str], index: usize) -> Option<T> {
+    T::parse(arguments.get(index)?)
+}
+
+/// Keep the interfaces passed to a function so that their callbacks can be invoked
+pub(crate) fn store_interfaces(function: &str, interfaces: Vec<Box<dyn Any>>) {
+    let _previous = with(|state| {
+        state
+            .interfaces
+            .insert(function.to_string(), Interfaces(interfaces))
+    });
+}
+
+/// Run an action on the first interface of type `T` passed to a function
+pub(crate) fn with_interface<T: 'static, R>(
+    function: &str,
+    action: impl FnOnce(&T) -> Option<R>,
+) -> Option<R> {
+    // the callback may call back into the library, so invoke it outside the lock
+    let interfaces = with(|state| state.interfaces.remove(function))?;
+    let result = interfaces
+        .0
+        .iter()
+        .find_map(|x| x.downcast_ref::<T>())
+        .and_then(action);
+    let _replaced = with(|state| {
+        if state.interfaces.contains_key(function) {
+            Some(interfaces)
+        } else {
+            state.interfaces.insert(function.to_string(), interfaces)
+        }
+    });
+    result
+}
+
+/// Text of a C string, empty if it is null or not valid UTF-8
+///
+/// # Safety
+///
+/// `value` must be null or point to a nul-terminated string
+pub unsafe fn c_str<'a>(value: *const std::os::raw::c_char) -> &'a str {
+    if value.is_null() {
+        ""
+    } else {
+        CStr::from_ptr(value).to_str().unwrap_or_default()
+    }
+}
@@ -0,0 +1,680 @@
+use std::collections::HashSet;
+
+use heck::CamelCase;
+
+use crate::backend::rust::rust_struct::RustStruct;
+use crate::backend::rust::rust_type::{LifetimeInfo, RustType};
+use crate::backend::*;
+use crate::model::*;
+
+/// Write a mock implementation of every native function referenced by the FFI layer
+pub(crate) fn generate(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("use crate::ffi::*;")?;
+    f.newline()?;
+
+    write_runtime(f, lib)?;
+
+    for statement in lib.statements() {
+        match statement {
+            Statement::ClassDeclaration(handle) => write_class(f, lib, handle)?,
+            Statement::EnumDefinition(handle) => write_enum(f, handle)?,
+            Statement::StructDefinition(s) => match s {
+                StructType::FunctionArg(s) => write_describe(f, s)?,
+                StructType::CallbackArg(s) => write_default(f, s, &callback_arg_field_default)?,
+                StructType::FunctionReturn(s) => {
+                    write_default(f, s, &function_return_field_default)?
+                }
+                StructType::Universal(s) => {
+                    write_describe(f, s)?;
+                    write_default(f, s, &universal_field_default)?
+                }
+            },
+            Statement::InterfaceDefinition(handle) => write_interface(f, handle.untyped())?,
+            _ => (),
+        }
+    }
+
+    let destructors: HashSet<Name> = lib
+        .classes()
+        .filter_map(|x| x.destructor.as_ref())
+        .map(|x| x.function.name.clone())
+        .collect();
+
+    for handle in lib.functions() {
+        f.newline()?;
+        write_function(f, lib, handle, destructors.contains(&handle.name))?;
+    }
+
+    write_control(f, &lib.settings.c_ffi_prefix)
+}
+
+fn write_runtime(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("/// Control API of the mock implementation")?;
+    f.writeln("pub mod mock")?;
+    blocked(f, |f| {
+        for line in include_str!("copy/mock.rs").lines() {
+            f.writeln(line)?;
+        }
+        f.newline()?;
+        write_invoke(f, lib)
+    })
+}
+
+fn write_invoke(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("/// Invoke a callback of an interface passed to a function, the arguments and the returned")?;
+    f.writeln("/// value are in their text representation. Returns `None` if the callback was not invoked.")?;
+    let has_deprecated_callbacks = lib
+        .untyped_interfaces()
+        .flat_map(|x| x.callbacks.iter())
+        .any(|x| x.doc.deprecated.is_some());
+    if has_deprecated_callbacks {
+        f.writeln("#[allow(deprecated)]")?;
+    }
+    f.writeln(
+        "pub fn invoke(function: &str, callback: &str, arguments: &[&str]) -> Option<String>",
+    )?;
+    blocked(f, |f| {
+        let mut any = false;
+        for interface in lib.untyped_interfaces() {
+            if interface.callbacks.is_empty() {
+                continue;
+            }
+            any = true;
+            f.writeln(&format!(
+                "let result = with_interface(function, |interface: &crate::ffi::{}| match callback",
+                interface.name.to_camel_case()
+            ))?;
+            blocked(f, |f| {
+                for cb in &interface.callbacks {
+                    write_callback_invocation(f, cb)?;
+                }
+                f.writeln("_ => None,")
+            })?;
+            f.write(");")?;
+            f.writeln("if result.is_some()")?;
+            blocked(f, |f| f.writeln("return result;"))?;
+        }
+        if !any {
+            f.writeln("let _ = (function, callback, arguments);")?;
+        }
+        f.writeln("None")
+    })
+}
+
+fn write_callback_invocation(
+    f: &mut dyn Printer,
+    cb: &CallbackFunction<Validated>,
+) -> FormattingResult<()> {
+    let values: Vec<String> = cb
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| match &arg.arg_type {
+            CallbackArgument::Basic(x) => {
+                parse_basic(x, &|t| format!("argument::<{}>(arguments, {})?", t, index))
+            }
+            CallbackArgument::String(_) => {
+                format!("&std::ffi::CString::new(*arguments.get({})?).ok()?", index)
+            }
+            // values without a text representation are null or default
+            CallbackArgument::Iterator(_) | CallbackArgument::Class(_) => {
+                "std::ptr::null_mut()".to_string()
+            }
+            CallbackArgument::Struct(_) => "Default::default()".to_string(),
+        })
+        .collect();
+
+    f.writeln(&format!("\"{}\" =>", cb.name))?;
+    blocked(f, |f| {
+        for (index, value) in values.iter().enumerate() {
+            f.writeln(&format!("let arg{} = {};", index, value))?;
+        }
+        let invocation = format!(
+            "interface.{}({})",
+            cb.name,
+            (0..values.len())
+                .map(|index| format!("arg{}", index))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        if cb.return_type.is_none() {
+            f.writeln(&format!("{};", invocation))?;
+            f.writeln("Some(String::new())")
+        } else {
+            f.writeln(&format!("Some({}.describe())", invocation))
+        }
+    })
+}
+
+/// Expression converting a value parsed by `parse` to the rust representation of a basic type
+fn parse_basic(basic: &BasicType, parse: &dyn Fn(&str) -> String) -> String {
+    match basic {
+        BasicType::Primitive(x) => parse(&x.as_rust_type()),
+        BasicType::Duration(DurationType::Milliseconds) => {
+            format!("std::time::Duration::from_millis({})", parse("u64"))
+        }
+        BasicType::Duration(DurationType::Seconds) => {
+            format!("std::time::Duration::from_secs({})", parse("u64"))
+        }
+        BasicType::Enum(x) => parse(&format!("crate::ffi::{}", x.name.to_camel_case())),
+    }
+}
+
+fn write_class(
+    f: &mut dyn Printer,
+    lib: &Library,
+    handle: &ClassDeclarationHandle,
+) -> FormattingResult<()> {
+    let lifetime = lib
+        .iterators()
+        .any(|x| x.iter_class == *handle && x.has_lifetime_annotation);
+
+    f.newline()?;
+    f.writeln("#[derive(Default)]")?;
+    if lifetime {
+        f.writeln(&format!(
+            "pub struct {}<'a>(std::marker::PhantomData<&'a ()>);",
+            handle.name.to_camel_case()
+        ))
+    } else {
+        f.writeln(&format!("pub struct {};", handle.name.to_camel_case()))
+    }
+}
+
+fn write_enum(f: &mut dyn Printer, handle: &Handle<Enum<Validated>>) -> FormattingResult<()> {
+    let name = handle.name.to_camel_case();
+    let has_deprecated_variants = handle.variants.iter().any(|x| x.doc.deprecated.is_some());
+
+    f.newline()?;
+    if has_deprecated_variants {
+        f.writeln("#[allow(deprecated)]")?;
+    }
+    f.writeln(&format!("impl mock::Describe for {}", name))?;
+    blocked(f, |f| {
+        f.writeln("fn describe(&self) -> String")?;
+        blocked(f, |f| {
+            f.writeln("match self")?;
+            blocked(f, |f| {
+                for variant in &handle.variants {
+                    f.writeln(&format!(
+                        "{}::{} => \"{}\",",
+                        name,
+                        variant.name.to_camel_case(),
+                        variant.name
+                    ))?;
+                }
+                Ok(())
+            })?;
+            f.write(".to_string()")
+        })
+    })?;
+
+    f.newline()?;
+    if has_deprecated_variants {
+        f.writeln("#[allow(deprecated)]")?;
+    }
+    f.writeln(&format!("impl mock::Parse for {}", name))?;
+    blocked(f, |f| {
+        f.writeln("fn parse(value: &str) -> Option<Self>")?;
+        blocked(f, |f| {
+            f.writeln("match value.trim()")?;
+            blocked(f, |f| {
+                for variant in &handle.variants {
+                    f.writeln(&format!(
+                        "\"{}\" => Some({}::{}),",
+                        variant.name,
+                        name,
+                        variant.name.to_camel_case()
+                    ))?;
+                }
+                f.writeln("_ => None,")
+            })
+        })
+    })
+}
+
+/// Text representation of the structs that are passed to functions or returned by callbacks
+fn write_describe<T>(
+    f: &mut dyn Printer,
+    handle: &Handle<Struct<T, Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + RustType,
+{
+    f.newline()?;
+    // the accessors of deprecated fields are deprecated
+    if handle.fields.iter().any(|x| x.doc.deprecated.is_some()) {
+        f.writeln("#[allow(deprecated)]")?;
+    }
+    f.writeln(&format!(
+        "impl mock::Describe for {}",
+        handle.name().to_camel_case()
+    ))?;
+    blocked(f, |f| {
+        f.writeln("fn describe(&self) -> String")?;
+        blocked(f, |f| {
+            let fields = handle
+                .fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "format!(\"{}: {{}}\", mock::Describe::describe(&self.{}()))",
+                        field.name, field.name
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!(
+                "format!(\"{{{{{{}}}}}}\", [{}].join(\", \"))",
+                fields
+            ))
+        })
+    })
+}
+
+/// Default value of the structs returned by functions or passed to callbacks
+fn write_default<T>(
+    f: &mut dyn Printer,
+    handle: &Handle<Struct<T, Validated>>,
+    field_default: &dyn Fn(&T) -> String,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + RustType,
+{
+    let name = handle.name().to_camel_case();
+    let lifetime = if handle.annotate_c_with_lifetime() {
+        "<'a>"
+    } else {
+        ""
+    };
+
+    f.newline()?;
+    f.writeln("#[allow(clippy::derivable_impls)]")?;
+    f.writeln(&format!(
+        "impl{lifetime} Default for {}{lifetime}",
+        name,
+        lifetime = lifetime
+    ))?;
+    blocked(f, |f| {
+        f.writeln("fn default() -> Self")?;
+        blocked(f, |f| {
+            f.writeln(&name)?;
+            blocked(f, |f| {
+                for field in &handle.fields {
+                    f.writeln(&format!(
+                        "{}: {},",
+                        field.name,
+                        field_default(&field.field_type)
+                    ))?;
+                }
+                Ok(())
+            })
+        })
+    })
+}
+
+/// Default value of a basic type in its C representation
+fn basic_c_default(basic: &BasicType) -> String {
+    match basic {
+        BasicType::Primitive(_) | BasicType::Duration(_) => "Default::default()".to_string(),
+        BasicType::Enum(x) => format!(
+            "{}::{}.into()",
+            x.name.to_camel_case(),
+            x.variants[0].name.to_camel_case()
+        ),
+    }
+}
+
+fn function_return_field_default(field: &FunctionReturnStructField) -> String {
+    match field {
+        FunctionReturnStructField::Basic(x) => basic_c_default(x),
+        FunctionReturnStructField::ClassRef(_) | FunctionReturnStructField::Iterator(_) => {
+            "std::ptr::null_mut()".to_string()
+        }
+        FunctionReturnStructField::Struct(_) => "Default::default()".to_string(),
+    }
+}
+
+fn callback_arg_field_default(field: &CallbackArgStructField) -> String {
+    match field {
+        CallbackArgStructField::Basic(x) => basic_c_default(x),
+        CallbackArgStructField::Iterator(_) => "std::ptr::null_mut()".to_string(),
+        CallbackArgStructField::Struct(_) => "Default::default()".to_string(),
+    }
+}
+
+fn universal_field_default(field: &UniversalStructField) -> String {
+    match field {
+        UniversalStructField::Basic(x) => basic_c_default(x),
+        UniversalStructField::Struct(_) => "Default::default()".to_string(),
+    }
+}
+
+fn write_interface(f: &mut dyn Printer, handle: &Interface<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    f.writeln(&format!(
+        "impl mock::Describe for {}",
+        handle.name.to_camel_case()
+    ))?;
+    blocked(f, |f| {
+        f.writeln("fn describe(&self) -> String")?;
+        blocked(f, |f| {
+            f.writeln(&format!("\"{}\".to_string()", handle.name))
+        })
+    })
+}
+
+fn write_function(
+    f: &mut dyn Printer,
+    lib: &Library,
+    handle: &Handle<Function<Validated>>,
+    destructor: bool,
+) -> FormattingResult<()> {
+    let name = &handle.name;
+
+    write_signature(f, handle)?;
+
+    blocked(f, |f| {
+        f.writeln(&format!(
+            "mock::record(\"{}\", vec![{}]);",
+            name,
+            handle
+                .arguments
+                .iter()
+                .map(|arg| format!("mock::Describe::describe(&{})", arg.name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ))?;
+
+        let interfaces: Vec<String> = handle
+            .arguments
+            .iter()
+            .filter(|arg| matches!(arg.arg_type, FunctionArgument::Interface(_)))
+            .map(|arg| format!("Box::new({})", arg.name))
+            .collect();
+        if !interfaces.is_empty() {
+            f.writeln(&format!(
+                "mock::store_interfaces(\"{}\", vec![{}]);",
+                name,
+                interfaces.join(", ")
+            ))?;
+        }
+
+        if destructor || handle.category == FunctionCategory::CollectionDestroy {
+            let instance = &handle.arguments[0].name;
+            f.writeln(&format!("if !{}.is_null()", instance))?;
+            blocked(f, |f| {
+                f.writeln(&format!("drop(unsafe {{ Box::from_raw({}) }});", instance))
+            })?;
+        }
+
+        if handle.error_type.get().is_some() {
+            f.writeln(&format!("if let Some(err) = mock::error(\"{}\")", name))?;
+            blocked(f, |f| f.writeln("return Err(err);"))?;
+        }
+
+        match handle.get_signature_type() {
+            SignatureType::NoErrorNoReturn => Ok(()),
+            SignatureType::NoErrorWithReturn(t, _) => f.writeln(&return_value(lib, handle, &t)),
+            SignatureType::ErrorNoReturn(_) => f.writeln("Ok(())"),
+            SignatureType::ErrorWithReturn(_, t, _) => {
+                f.writeln(&format!("Ok({})", return_value(lib, handle, &t)))
+            }
+        }
+    })
+}
+
+/// Write the signature of the function of the crate called by the FFI layer for a native function
+pub(crate) fn write_signature(
+    f: &mut dyn Printer,
+    handle: &Function<Validated>,
+) -> FormattingResult<()> {
+    // a returned reference has nothing to borrow from, so it is given a named lifetime
+    let struct_ref = matches!(
+        handle.return_type.get_value(),
+        Some(FunctionReturnValue::StructRef(_))
+    );
+    let lifetime = struct_ref
+        || handle
+            .arguments
+            .iter()
+            .any(|arg| arg.arg_type.rust_requires_lifetime())
+        || handle.return_type.rust_requires_lifetime();
+    let rust_type = |t: &FunctionReturnValue| match t {
+        FunctionReturnValue::StructRef(x) => {
+            format!("Option<&'a {}>", x.untyped().name.to_camel_case())
+        }
+        _ => t.as_rust_type(),
+    };
+
+    let return_type = match handle.get_signature_type() {
+        SignatureType::NoErrorNoReturn => None,
+        SignatureType::NoErrorWithReturn(t, _) => Some(rust_type(&t)),
+        SignatureType::ErrorNoReturn(err) => {
+            Some(format!("Result<(), {}>", err.inner.name.to_camel_case()))
+        }
+        SignatureType::ErrorWithReturn(err, t, _) => Some(format!(
+            "Result<{}, {}>",
+            rust_type(&t),
+            err.inner.name.to_camel_case()
+        )),
+    };
+
+    f.writeln("#[allow(clippy::needless_lifetimes, clippy::extra_unused_lifetimes)]")?;
+    f.writeln(&format!(
+        "pub(crate) fn {}{}({})",
+        handle.name,
+        if lifetime { "<'a>" } else { "" },
+        handle
+            .arguments
+            .iter()
+            .map(|arg| format!("{}: {}", arg.name, arg.arg_type.as_rust_type()))
+            .collect::<Vec<String>>()
+            .join(", ")
+    ))?;
+    if let Some(return_type) = return_type {
+        f.write(&format!(" -> {}", return_type))?;
+    }
+
+    Ok(())
+}
+
+/// Configured value of a function or, if none was configured, a default value
+fn return_value(
+    lib: &Library,
+    handle: &Function<Validated>,
+    return_type: &FunctionReturnValue,
+) -> String {
+    let name = &handle.name;
+    let configured = |t: &str| format!("mock::configured::<{}>(\"{}\")", t, name);
+    match return_type {
+        FunctionReturnValue::Basic(BasicType::Enum(x)) => format!(
+            "{}.unwrap_or({}::{})",
+            configured(&x.name.to_camel_case()),
+            x.name.to_camel_case(),
+            x.variants[0].name.to_camel_case()
+        ),
+        FunctionReturnValue::Basic(x) => {
+            parse_basic(x, &|t| format!("{}.unwrap_or_default()", configured(t)))
+        }
+        FunctionReturnValue::PrimitiveRef(_)
+            if handle.category == FunctionCategory::IteratorNext =>
+        {
+            "std::ptr::null()".to_string()
+        }
+        FunctionReturnValue::PrimitiveRef(x) => format!(
+            "Box::leak(Box::new({}))",
+            parse_basic(&BasicType::Primitive(x.inner), &|t| {
+                format!("{}.unwrap_or_default()", configured(t))
+            })
+        ),
+        // the native version check of the bindings expects the version of the library
+        FunctionReturnValue::String(_) if name.as_ref() == "version" => format!(
+            "mock::configured_string(\"{}\").unwrap_or(c\"{}\")",
+            name, lib.version
+        ),
+        FunctionReturnValue::String(_) => {
+            format!("mock::configured_string(\"{}\").unwrap_or_default()", name)
+        }
+        FunctionReturnValue::ClassRef(_) => "Box::into_raw(Box::default())".to_string(),
+        FunctionReturnValue::Struct(_) => "Default::default()".to_string(),
+        FunctionReturnValue::StructRef(_) => "None".to_string(),
+    }
+}
+
+/// Type of a parameter or of the value returned by a function of the control API
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ControlType {
+    U32,
+    /// nul-terminated UTF-8 string, null when returned if there is none
+    String,
+    /// array of strings whose length is the next parameter
+    Strings,
+}
+
+/// Function of the control API exported to C as `{prefix}_mock_{name}`
+pub(crate) struct ControlFunction {
+    pub(crate) name: &'static str,
+    pub(crate) doc: &'static str,
+    pub(crate) params: &'static [(&'static str, ControlType, &'static str)],
+    pub(crate) returns: Option<(ControlType, &'static str)>,
+}
+
+const FUNCTION: (&str, ControlType, &str) = (
+    "function",
+    ControlType::String,
+    "Name of the native function without the prefix",
+);
+
+/// Replace the `code` spans of the documentation of the control API with the markup of a language
+pub(crate) fn code(doc: &str, open: &str, close: &str) -> String {
+    doc.split('`')
+        .enumerate()
+        .map(|(i, text)| {
+            if i % 2 == 1 {
+                format!("{}{}{}", open, text, close)
+            } else {
+                text.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Control API of the mock implementation, declared by the bindings of each language
+pub(crate) const CONTROL_API: [ControlFunction; 7] = [
+    ControlFunction {
+        name: "reset",
+        doc: "Forget every call, configured value and interface",
+        params: &[],
+        returns: None,
+    },
+    ControlFunction {
+        name: "call_count",
+        doc: "Number of times a function was called since the last reset",
+        params: &[FUNCTION],
+        returns: Some((ControlType::U32, "Number of calls")),
+    },
+    ControlFunction {
+        name: "last_arguments",
+        doc: "Arguments of the most recent call to a function, separated by commas",
+        params: &[FUNCTION],
+        returns: Some((
+            ControlType::String,
+            "Text of the arguments, valid until the next call returning a string",
+        )),
+    },
+    ControlFunction {
+        name: "set_return",
+        doc: "Configure the value returned by a function, e.g. `42`, `true` or `enum_variant`",
+        params: &[
+            FUNCTION,
+            ("value", ControlType::String, "Text of the value"),
+        ],
+        returns: None,
+    },
+    ControlFunction {
+        name: "set_error",
+        doc: "Configure the error returned by a function, e.g. `bad_password`",
+        params: &[
+            FUNCTION,
+            ("error", ControlType::String, "Name of the error variant"),
+        ],
+        returns: None,
+    },
+    ControlFunction {
+        name: "clear",
+        doc: "Clear the configured return value and error of a function",
+        params: &[FUNCTION],
+        returns: None,
+    },
+    ControlFunction {
+        name: "invoke",
+        doc: "Invoke a callback of an interface passed to a function",
+        params: &[
+            FUNCTION,
+            ("callback", ControlType::String, "Name of the callback"),
+            (
+                "arguments",
+                ControlType::Strings,
+                "Text of the arguments of the callback",
+            ),
+        ],
+        returns: Some((
+            ControlType::String,
+            "Text of the value returned by the callback, or null if it was not invoked",
+        )),
+    },
+];
+
+fn write_control(f: &mut dyn Printer, prefix: &str) -> FormattingResult<()> {
+    for function in CONTROL_API.iter() {
+        let params: Vec<String> = function
+            .params
+            .iter()
+            .map(|(name, param_type, _)| match param_type {
+                ControlType::U32 => format!("{}: u32", name),
+                ControlType::String => format!("{}: *const std::os::raw::c_char", name),
+                ControlType::Strings => {
+                    format!("{}: *const *const std::os::raw::c_char, count: u32", name)
+                }
+            })
+            .collect();
+        let return_type = match function.returns {
+            None => "".to_string(),
+            Some((ControlType::U32, _)) => " -> u32".to_string(),
+            Some(_) => " -> *const std::os::raw::c_char".to_string(),
+        };
+
+        f.newline()?;
+        f.writeln("#[allow(clippy::missing_safety_doc)]")?;
+        f.writeln("#[no_mangle]")?;
+        f.writeln(&format!(
+            "pub unsafe extern \"C\" fn {}_mock_{}({}){}",
+            prefix,
+            function.name,
+            params.join(", "),
+            return_type
+        ))?;
+        blocked(f, |f| write_control_body(f, function.name))?;
+    }
+    Ok(())
+}
+
+fn write_control_body(f: &mut dyn Printer, name: &str) -> FormattingResult<()> {
+    match name {
+        "reset" => f.writeln("mock::reset()"),
+        "call_count" => f.writeln("mock::call_count(mock::c_str(function)) as u32"),
+        "last_arguments" => f.writeln("mock::text(&mock::last_arguments(mock::c_str(function)).unwrap_or_default().join(\", \"))"),
+        "set_return" => f.writeln("mock::set_return(mock::c_str(function), mock::c_str(value))"),
+        "set_error" => f.writeln("mock::set_error(mock::c_str(function), mock::c_str(error))"),
+        "clear" => f.writeln("mock::clear(mock::c_str(function))"),
+        _ => {
+            f.writeln("let arguments: Vec<&str> = (0..count as usize).map(|i| mock::c_str(*arguments.add(i))).collect();")?;
+            f.writeln("match mock::invoke(mock::c_str(function), mock::c_str(callback), &arguments)")?;
+            blocked(f, |f| {
+                f.writeln("Some(result) => mock::text(&result),")?;
+                f.writeln("None => std::ptr::null(),")
+            })
+        }
+    }
+}
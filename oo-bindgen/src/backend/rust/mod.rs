@@ -1,3 +1,4 @@
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
@@ -11,8 +12,14 @@ use crate::backend::rust::rust_struct::RustStruct;
 use crate::backend::rust::rust_type::RustType;
 use crate::backend::rust::type_converter::TypeConverter;
 
+use crate::backend::rust::class_trait::{dispatch_table, write_class_trait, ClassDispatch};
+use crate::backend::rust::constraint::write_constraint_check;
 use crate::backend::rust::rust_type::LifetimeInfo;
 
+mod class_trait;
+mod conformance;
+mod constraint;
+pub(crate) mod mock;
 mod rust_struct;
 mod rust_type;
 mod type_converter;
@@ -25,24 +32,92 @@ mod type_converter;
 ///
 /// This function is typically called from a build.rs script
 pub fn generate_ffi(library: &Library) -> FormattingResult<()> {
-    RustCodegen::new(library).generate()
+    generate_ffi_with_config(library, &FfiBindgenConfig::default())
+}
+
+/// Generate the FFI (C ABI) interface for the library with the specified options
+pub fn generate_ffi_with_config(
+    library: &Library,
+    config: &FfiBindgenConfig,
+) -> FormattingResult<()> {
+    RustCodegen::new(library, config).generate()
+}
+
+/// Generate a mock implementation of the FFI interface in `$OUT_DIR/mock.rs`.
+///
+/// Every native function referenced by the output of [generate_ffi] is implemented by recording
+/// the call and returning the value or error configured through the `mock` module or the exported
+/// `{prefix}_mock_*` functions. The interfaces passed to a function are kept so that their callbacks
+/// can be invoked on demand. This lets the tests of the bindings check the marshalling of every
+/// type without implementing the library.
+///
+/// The file is meant to be included at the root of a crate whose `ffi` module includes the
+/// output of [generate_ffi].
+pub fn generate_mock(library: &Library) -> FormattingResult<()> {
+    let path = Path::new(&env::var_os("OUT_DIR").unwrap()).join("mock.rs");
+    let mut f = FilePrinter::new(path, Naming::default())?;
+    mock::generate(&mut f, library)
+}
+
+/// Generate the implementation of the conformance functions in `$OUT_DIR/conformance.rs`.
+///
+/// The functions must have been added to the library by [crate::conformance::define]. The file is
+/// meant to be included in a module whose items are re-exported at the root of the crate, with the
+/// output of [generate_ffi] in `crate::ffi`.
+pub fn generate_conformance(library: &Library) -> FormattingResult<()> {
+    let suite = crate::conformance::Suite::find(library)
+        .ok_or("the library does not define the conformance functions")?;
+    let path = Path::new(&env::var_os("OUT_DIR").unwrap()).join("conformance.rs");
+    let mut f = FilePrinter::new(path, Naming::default())?;
+    conformance::generate(&mut f, &suite)
+}
+
+/// Configuration for the FFI (Rust) generation
+#[derive(Debug, Clone, Default)]
+pub struct FfiBindgenConfig {
+    /// Generate a `{Class}Api` trait for every class and dispatch its constructor, destructor,
+    /// methods and future methods through `impl {Class}Api for {Class}` instead of free functions
+    /// named after the native functions.
+    pub class_traits: bool,
+    /// Classes that keep calling free functions when `class_traits` is set, e.g. because their
+    /// methods need exclusive access to the instance
+    pub free_function_classes: Vec<String>,
+}
+
+impl FfiBindgenConfig {
+    fn has_class_trait(&self, class: &Name) -> bool {
+        self.class_traits
+            && !self
+                .free_function_classes
+                .iter()
+                .any(|x| x == class.as_ref())
+    }
 }
 
 struct RustCodegen<'a> {
     library: &'a Library,
     dest_path: PathBuf,
+    class_dispatch: HashMap<Name, ClassDispatch>,
+    class_traits: HashSet<Name>,
 }
 
 impl<'a> RustCodegen<'a> {
-    fn new(lib: &'a Library) -> Self {
+    fn new(lib: &'a Library, config: &FfiBindgenConfig) -> Self {
         RustCodegen {
             library: lib,
             dest_path: Path::new(&env::var_os("OUT_DIR").unwrap()).join("ffi.rs"),
+            class_dispatch: dispatch_table(lib, &|x| config.has_class_trait(x)),
+            class_traits: lib
+                .classes()
+                .map(|x| x.name())
+                .filter(|x| config.has_class_trait(x))
+                .cloned()
+                .collect(),
         }
     }
 
     fn generate(self) -> FormattingResult<()> {
-        let mut f = FilePrinter::new(&self.dest_path)?;
+        let mut f = FilePrinter::new(&self.dest_path, Naming::default())?;
 
         for statement in self.library.statements() {
             match statement {
@@ -53,12 +128,18 @@ impl<'a> RustCodegen<'a> {
                     StructType::Universal(s) => self.write_struct_definition(&mut f, s)?,
                 },
                 Statement::EnumDefinition(handle) => self.write_enum_definition(&mut f, handle)?,
-                Statement::FunctionDefinition(handle) => {
-                    Self::write_function(&mut f, handle, &self.library.settings.c_ffi_prefix)?
-                }
+                Statement::FunctionDefinition(handle) => Self::write_function(
+                    &mut f,
+                    handle,
+                    &self.library.settings.c_ffi_prefix,
+                    self.class_dispatch.get(&handle.name),
+                )?,
                 Statement::InterfaceDefinition(t) => {
                     self.write_interface(&mut f, t.untyped(), t.mode())?
                 }
+                Statement::ClassDefinition(handle) if self.class_traits.contains(handle.name()) => {
+                    write_class_trait(&mut f, handle)?
+                }
                 _ => (),
             }
             f.newline()?;
@@ -133,6 +214,7 @@ impl<'a> RustCodegen<'a> {
                 };
 
                 // Accessor
+                write_deprecation(f, &field.doc)?;
                 f.writeln("#[allow(clippy::needless_lifetimes)]")?;
                 f.writeln(&format!(
                     "pub fn {name}{fn_lifetime}(&{lifetime}self) -> {ampersand}{return_type}",
@@ -172,6 +254,7 @@ impl<'a> RustCodegen<'a> {
                 f.newline()?;
 
                 // Mutator
+                write_deprecation(f, &field.doc)?;
                 f.writeln("#[allow(clippy::needless_lifetimes)]")?;
                 f.writeln(&format!(
                     "pub fn set_{name}{fn_lifetime}(&{lifetime}mut self, value: {element_type})",
@@ -191,6 +274,11 @@ impl<'a> RustCodegen<'a> {
 
                 f.newline()?;
             }
+
+            if handle.has_constraints() {
+                Self::write_struct_constraint_check(f, handle)?;
+            }
+
             Ok(())
         })?;
 
@@ -259,6 +347,7 @@ impl<'a> RustCodegen<'a> {
         f.writeln(&format!("pub enum {}", enum_name))?;
         blocked(f, |f| {
             for variant in &handle.variants {
+                write_deprecation(f, &variant.doc)?;
                 f.writeln(&format!(
                     "{} = {},",
                     variant.name.to_camel_case(),
@@ -269,6 +358,10 @@ impl<'a> RustCodegen<'a> {
         })?;
 
         // Conversion routines
+        let has_deprecated_variants = handle.variants.iter().any(|x| x.doc.deprecated.is_some());
+        if has_deprecated_variants {
+            f.writeln("#[allow(deprecated)]")?;
+        }
         f.writeln(&format!("impl From<{}> for std::os::raw::c_int", enum_name))?;
         blocked(f, |f| {
             f.writeln(&format!("fn from(value: {}) -> Self", enum_name))?;
@@ -288,6 +381,9 @@ impl<'a> RustCodegen<'a> {
             })
         })?;
 
+        if has_deprecated_variants {
+            f.writeln("#[allow(deprecated)]")?;
+        }
         f.writeln(&format!("impl From<std::os::raw::c_int> for {}", enum_name))?;
         blocked(f, |f| {
             f.writeln("fn from(value: std::os::raw::c_int) -> Self")?;
@@ -311,11 +407,77 @@ impl<'a> RustCodegen<'a> {
         })
     }
 
+    fn write_struct_constraint_check<T>(
+        f: &mut dyn Printer,
+        handle: &Handle<Struct<T, Validated>>,
+    ) -> FormattingResult<()>
+    where
+        T: StructFieldType,
+    {
+        if handle.fields.iter().any(|x| x.doc.deprecated.is_some()) {
+            f.writeln("#[allow(deprecated)]")?;
+        }
+        f.writeln("pub fn satisfies_constraints(&self) -> bool")?;
+        blocked(f, |f| {
+            for field in handle.fields.iter().filter(|x| x.has_constraints()) {
+                let value = format!("self.{}()", field.name);
+                if let Some(target) = field.field_type.constrained_type() {
+                    for constraint in &field.constraints {
+                        write_constraint_check(f, constraint, target, &value, &|f| {
+                            f.writeln("return false;")
+                        })?;
+                    }
+                }
+                if field.field_type.has_nested_constraints() {
+                    f.writeln(&format!("if !{}.satisfies_constraints()", value))?;
+                    blocked(f, |f| f.writeln("return false;"))?;
+                }
+            }
+            f.writeln("true")
+        })
+    }
+
+    fn write_argument_constraint_checks(
+        f: &mut dyn Printer,
+        handle: &Handle<Function<Validated>>,
+    ) -> FormattingResult<()> {
+        let err = match handle.error_type.get() {
+            Some(err) => err,
+            None => return Ok(()),
+        };
+        let invalid_argument = match err.invalid_argument_variant() {
+            Some(x) => format!("{}::{}", err.inner.name.to_camel_case(), x.to_camel_case()),
+            None => return Ok(()),
+        };
+        let converter = TypeConverter::ValidatedEnum(err.inner.clone());
+        let on_violation = |f: &mut dyn Printer| -> FormattingResult<()> {
+            converter.convert_to_c(f, &invalid_argument, "return ")?;
+            f.write(";")
+        };
+
+        for param in handle.arguments.iter().filter(|x| x.has_constraints()) {
+            if let Some(target) = param.arg_type.constrained_type() {
+                let value = param.name.to_string();
+                for constraint in &param.constraints {
+                    write_constraint_check(f, constraint, target, &value, &on_violation)?;
+                }
+            }
+            if param.arg_type.has_nested_constraints() {
+                f.writeln(&format!("if !{}.satisfies_constraints()", param.name))?;
+                blocked(f, on_violation)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn write_function(
         f: &mut dyn Printer,
         handle: &Handle<Function<Validated>>,
         prefix: &str,
+        dispatch: Option<&ClassDispatch>,
     ) -> FormattingResult<()> {
+        write_deprecation(f, &handle.doc)?;
         f.writeln("#[allow(clippy::missing_safety_doc)]")?;
         f.writeln("#[no_mangle]")?;
         f.writeln(&format!(
@@ -361,44 +523,46 @@ impl<'a> RustCodegen<'a> {
         }
 
         blocked(f, |f| {
+            if let Some(dispatch) = dispatch {
+                dispatch.write_instance_check(f, handle)?;
+            }
+
             for param in &handle.arguments {
                 if let Some(converter) = param.arg_type.conversion() {
-                    converter.convert_from_c(f, &param.name, &format!("let {} = ", param.name))?;
+                    let name = param.name.to_string();
+                    converter.convert_from_c(f, &name, &format!("let {} = ", name))?;
                     f.write(";")?;
                 }
             }
 
-            fn basic_invocation(f: &mut dyn Printer, name: &str) -> FormattingResult<()> {
-                f.writeln(&format!("crate::{}(", name))
-            }
+            Self::write_argument_constraint_checks(f, handle)?;
 
             // invoke the inner function
+            let invocation = match dispatch {
+                Some(dispatch) => dispatch.invocation(handle),
+                None => format!(
+                    "crate::{}({})",
+                    handle.name,
+                    handle
+                        .arguments
+                        .iter()
+                        .map(|param| param.name.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            };
             match handle.get_signature_type() {
-                SignatureType::NoErrorNoReturn => {
-                    basic_invocation(f, &handle.name)?;
+                SignatureType::NoErrorWithReturn(ret, _) if ret.has_conversion() => {
+                    f.writeln(&format!("let _result = {}", invocation))?;
                 }
-                SignatureType::NoErrorWithReturn(ret, _) => {
-                    if ret.has_conversion() {
-                        f.writeln(&format!("let _result = crate::{}(", handle.name))?;
-                    } else {
-                        basic_invocation(f, &handle.name)?;
-                    }
+                SignatureType::NoErrorNoReturn | SignatureType::NoErrorWithReturn(_, _) => {
+                    f.writeln(&invocation)?;
                 }
                 SignatureType::ErrorWithReturn(_, _, _) | SignatureType::ErrorNoReturn(_) => {
-                    f.writeln(&format!("match crate::{}(", &handle.name))?;
+                    f.writeln(&format!("match {}", invocation))?;
                 }
             }
 
-            f.write(
-                &handle
-                    .arguments
-                    .iter()
-                    .map(|param| param.name.to_string())
-                    .collect::<Vec<String>>()
-                    .join(", "),
-            )?;
-            f.write(")")?;
-
             match handle.get_signature_type() {
                 SignatureType::NoErrorNoReturn => {}
                 SignatureType::NoErrorWithReturn(ret, _) => {
@@ -565,6 +729,7 @@ impl<'a> RustCodegen<'a> {
                 };
 
                 // Function signature
+                write_deprecation(f, &callback.doc)?;
                 f.writeln("#[allow(clippy::needless_lifetimes)]")?;
                 f.writeln(&format!(
                     "pub(crate) fn {name}{lifetime}(&self, ",
@@ -589,11 +754,8 @@ impl<'a> RustCodegen<'a> {
                 blocked(f, |f| {
                     for arg in &callback.arguments {
                         if let Some(converter) = arg.arg_type.conversion() {
-                            converter.convert_to_c(
-                                f,
-                                &arg.name,
-                                &format!("let {} = ", arg.name),
-                            )?;
+                            let name = arg.name.to_string();
+                            converter.convert_to_c(f, &name, &format!("let {} = ", name))?;
                             f.write(";")?;
                         }
                     }
@@ -637,3 +799,15 @@ impl<'a> RustCodegen<'a> {
         })
     }
 }
+
+/// Rust attribute of a deprecated item
+fn write_deprecation(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
+    if let Some(deprecated) = &doc.deprecated {
+        f.writeln(&format!(
+            "#[deprecated(since = \"{}\", note = {:?})]",
+            deprecated.since,
+            deprecated.plain_note()
+        ))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use crate::backend::c::ctype::CType;
+use crate::backend::c::header::{callback_parameters, function_signature};
+use crate::backend::site::{Item, Language};
+use crate::model::*;
+
+pub(super) struct C;
+
+impl Language for C {
+    fn id(&self) -> &'static str {
+        "c"
+    }
+
+    fn name(&self) -> &'static str {
+        "C"
+    }
+
+    fn target(&self) -> Option<TargetLanguage> {
+        Some(TargetLanguage::C)
+    }
+
+    fn example_language(&self) -> ExampleLanguage {
+        ExampleLanguage::C
+    }
+
+    fn null(&self) -> &'static str {
+        "NULL"
+    }
+
+    fn iterator(&self) -> &'static str {
+        "iterator"
+    }
+
+    fn reference(&self, reference: &Validated, naming: &Naming) -> String {
+        match reference {
+            Validated::Argument(name) => name.snake_case(naming),
+            Validated::Class(class) => class.to_c_type(),
+            Validated::ClassMethod(class, _, function) => {
+                format!("{}_{}", class.settings.c_ffi_prefix, function.name)
+            }
+            Validated::ClassConstructor(class, constructor) => format!(
+                "{}_{}",
+                class.settings.c_ffi_prefix, constructor.function.name
+            ),
+            Validated::ClassDestructor(class, destructor) => format!(
+                "{}_{}",
+                class.settings.c_ffi_prefix, destructor.function.name
+            ),
+            Validated::Struct(st) => st.to_c_type(),
+            Validated::StructField(st, field) => {
+                format!("{}.{}", st.to_c_type(), field.snake_case(naming))
+            }
+            Validated::Enum(handle) => handle.to_c_type(),
+            Validated::EnumVariant(handle, variant) => format!(
+                "{}_{}_{}",
+                handle.settings.c_ffi_prefix.capital_snake_case(),
+                handle.name.capital_snake_case(),
+                variant.capital_snake_case()
+            ),
+            Validated::Interface(handle) => handle.to_c_type(),
+            Validated::InterfaceMethod(handle, callback) => {
+                format!("{}.{}", handle.to_c_type(), callback.snake_case(naming))
+            }
+        }
+    }
+
+    fn error(&self, error: &ErrorType<Validated>, _naming: &Naming) -> String {
+        error.inner.to_c_type()
+    }
+
+    fn item(&self, item: &Item, _naming: &Naming) -> Option<String> {
+        match item {
+            Item::Class(x) => Some(format!(
+                "typedef struct {} {};",
+                x.declaration.to_c_type(),
+                x.declaration.to_c_type()
+            )),
+            Item::Interface(x) => Some(format!(
+                "typedef struct {} {};",
+                x.to_c_type(),
+                x.to_c_type()
+            )),
+            Item::Struct(x) => Some(format!(
+                "typedef struct {} {};",
+                x.to_c_type(),
+                x.to_c_type()
+            )),
+            Item::Enum(x) => Some(format!("typedef enum {} {};", x.to_c_type(), x.to_c_type())),
+            // the functions and the constants are not grouped in C
+            Item::StaticClass(_) | Item::Constants(_) => None,
+        }
+    }
+
+    fn constant(
+        &self,
+        set: &Handle<ConstantSet<Validated>>,
+        constant: &Constant<Validated>,
+        _naming: &Naming,
+    ) -> String {
+        let ConstantValue::U8(value, Representation::Hex) = constant.value;
+        format!(
+            "#define {}_{}_{} 0x{:02X?}",
+            set.settings.c_ffi_prefix.capital_snake_case(),
+            set.name.capital_snake_case(),
+            constant.name.capital_snake_case(),
+            value
+        )
+    }
+
+    fn variant(
+        &self,
+        handle: &Handle<Enum<Validated>>,
+        variant: &EnumVariant<Validated>,
+        _naming: &Naming,
+    ) -> String {
+        format!(
+            "{}_{}_{} = {}",
+            handle.settings.c_ffi_prefix.capital_snake_case(),
+            handle.name.capital_snake_case(),
+            variant.name.capital_snake_case(),
+            variant.value
+        )
+    }
+
+    fn fields(&self, st: &StructType<Validated>, naming: &Naming) -> Vec<String> {
+        match st {
+            StructType::FunctionArg(x) => fields(x, naming),
+            StructType::FunctionReturn(x) => fields(x, naming),
+            StructType::CallbackArg(x) => fields(x, naming),
+            StructType::Universal(x) => fields(x, naming),
+        }
+    }
+
+    fn initializer(
+        &self,
+        st: &StructType<Validated>,
+        initializer: &Handle<Initializer<Validated>>,
+        naming: &Naming,
+    ) -> String {
+        match st {
+            StructType::FunctionArg(x) => initializer_signature(x, initializer, naming),
+            StructType::FunctionReturn(x) => initializer_signature(x, initializer, naming),
+            StructType::CallbackArg(x) => initializer_signature(x, initializer, naming),
+            StructType::Universal(x) => initializer_signature(x, initializer, naming),
+        }
+    }
+
+    fn callback(
+        &self,
+        _handle: &Handle<Interface<Validated>>,
+        callback: &CallbackFunction<Validated>,
+        naming: &Naming,
+    ) -> String {
+        format!(
+            "{} (*{})({});",
+            callback.return_type.to_c_type(),
+            callback.name.snake_case(naming),
+            callback_parameters(callback)
+        )
+    }
+
+    fn constructor(
+        &self,
+        _class: &Handle<Class<Validated>>,
+        constructor: &ClassConstructor<Validated>,
+        naming: &Naming,
+    ) -> String {
+        format!("{};", function_signature(&constructor.function, naming))
+    }
+
+    fn destructor(
+        &self,
+        _class: &Handle<Class<Validated>>,
+        destructor: &ClassDestructor<Validated>,
+        naming: &Naming,
+    ) -> Option<String> {
+        Some(format!(
+            "{};",
+            function_signature(&destructor.function, naming)
+        ))
+    }
+
+    fn method(&self, method: &Method<Validated>, naming: &Naming) -> String {
+        format!("{};", function_signature(&method.native_function, naming))
+    }
+
+    fn static_method(&self, method: &StaticMethod<Validated>, naming: &Naming) -> String {
+        format!("{};", function_signature(&method.native_function, naming))
+    }
+
+    fn future_method(&self, method: &FutureMethod<Validated>, naming: &Naming) -> String {
+        format!("{};", function_signature(&method.native_function, naming))
+    }
+}
+
+fn fields<T>(handle: &Handle<Struct<T, Validated>>, naming: &Naming) -> Vec<String>
+where
+    T: StructFieldType + CType,
+{
+    handle
+        .fields()
+        .map(|x| {
+            format!(
+                "{} {};",
+                x.field_type.to_c_type(),
+                x.name.snake_case(naming)
+            )
+        })
+        .collect()
+}
+
+fn initializer_signature<T>(
+    handle: &Handle<Struct<T, Validated>>,
+    initializer: &Handle<Initializer<Validated>>,
+    naming: &Naming,
+) -> String
+where
+    T: StructFieldType + CType,
+{
+    let params = handle
+        .initializer_args(initializer.clone())
+        .map(|x| format!("{} {}", x.field_type.to_c_type(), x.name.snake_case(naming)))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    format!(
+        "static {} {}_{}_{}({});",
+        handle.to_c_type(),
+        handle.settings().c_ffi_prefix,
+        handle.name().as_ref(),
+        initializer.name,
+        params
+    )
+}
@@ -0,0 +1,30 @@
+// Language switcher of the API reference generated by oo-bindgen
+(function () {
+    var key = "oo-bindgen-language";
+
+    function select(language) {
+        document.body.dataset.lang = language;
+        try {
+            localStorage.setItem(key, language);
+        } catch (e) {
+            // the selection is only kept for the current page
+        }
+    }
+
+    document.addEventListener("DOMContentLoaded", function () {
+        try {
+            var saved = localStorage.getItem(key);
+            if (saved) {
+                document.body.dataset.lang = saved;
+            }
+        } catch (e) {
+            // storage is disabled, keep the default language
+        }
+
+        document.querySelectorAll(".switcher button").forEach(function (button) {
+            button.addEventListener("click", function () {
+                select(button.dataset.lang);
+            });
+        });
+    });
+})();
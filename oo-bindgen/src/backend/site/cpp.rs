@@ -0,0 +1,273 @@
+use crate::backend::c::cpp::conversion::*;
+use crate::backend::c::cpp::header::cpp_arguments;
+use crate::backend::site::{Item, Language};
+use crate::model::*;
+
+pub(super) struct Cpp;
+
+impl Language for Cpp {
+    fn id(&self) -> &'static str {
+        "cpp"
+    }
+
+    fn name(&self) -> &'static str {
+        "C++"
+    }
+
+    fn target(&self) -> Option<TargetLanguage> {
+        Some(TargetLanguage::Cpp)
+    }
+
+    fn example_language(&self) -> ExampleLanguage {
+        ExampleLanguage::Cpp
+    }
+
+    fn null(&self) -> &'static str {
+        "nullptr"
+    }
+
+    fn iterator(&self) -> &'static str {
+        "iterator"
+    }
+
+    fn reference(&self, reference: &Validated, naming: &Naming) -> String {
+        match reference {
+            Validated::Argument(name) => name.snake_case(naming),
+            Validated::Class(class) => class.core_cpp_type(naming),
+            Validated::ClassMethod(class, method, _) => {
+                format!(
+                    "{}::{}()",
+                    class.core_cpp_type(naming),
+                    method.snake_case(naming)
+                )
+            }
+            Validated::ClassConstructor(class, _) => {
+                format!(
+                    "{}::{}()",
+                    class.core_cpp_type(naming),
+                    class.core_cpp_type(naming)
+                )
+            }
+            Validated::ClassDestructor(class, _) => {
+                format!(
+                    "{}::~{}()",
+                    class.core_cpp_type(naming),
+                    class.core_cpp_type(naming)
+                )
+            }
+            Validated::Struct(st) => st.core_cpp_type(naming),
+            Validated::StructField(st, field) => {
+                format!("{}::{}", st.core_cpp_type(naming), field.snake_case(naming))
+            }
+            Validated::Enum(handle) => handle.core_cpp_type(naming),
+            Validated::EnumVariant(handle, variant) => {
+                format!(
+                    "{}::{}",
+                    handle.core_cpp_type(naming),
+                    variant.snake_case(naming)
+                )
+            }
+            Validated::Interface(handle) => handle.core_cpp_type(naming),
+            Validated::InterfaceMethod(handle, callback) => {
+                format!(
+                    "{}::{}()",
+                    handle.core_cpp_type(naming),
+                    callback.snake_case(naming)
+                )
+            }
+        }
+    }
+
+    fn error(&self, error: &ErrorType<Validated>, naming: &Naming) -> String {
+        error.core_cpp_type(naming)
+    }
+
+    fn item(&self, item: &Item, naming: &Naming) -> Option<String> {
+        Some(match item {
+            Item::Class(x) => format!("class {}", x.core_cpp_type(naming)),
+            Item::StaticClass(x) => format!("class {}", x.core_cpp_type(naming)),
+            Item::Interface(x) => format!("class {}", x.core_cpp_type(naming)),
+            Item::Struct(x) => format!("struct {}", x.core_cpp_type(naming)),
+            Item::Enum(x) => format!("enum class {}", x.core_cpp_type(naming)),
+            Item::Constants(x) => format!("namespace {}", x.name.snake_case(naming)),
+        })
+    }
+
+    fn constant(
+        &self,
+        _set: &Handle<ConstantSet<Validated>>,
+        constant: &Constant<Validated>,
+        naming: &Naming,
+    ) -> String {
+        let ConstantValue::U8(value, Representation::Hex) = constant.value;
+        format!(
+            "constexpr uint8_t {} = 0x{:02X};",
+            constant.core_cpp_type(naming),
+            value
+        )
+    }
+
+    fn variant(
+        &self,
+        _handle: &Handle<Enum<Validated>>,
+        variant: &EnumVariant<Validated>,
+        naming: &Naming,
+    ) -> String {
+        format!("{} = {}", variant.core_cpp_type(naming), variant.value)
+    }
+
+    fn fields(&self, st: &StructType<Validated>, naming: &Naming) -> Vec<String> {
+        match st {
+            StructType::FunctionArg(x) => fields(x, naming),
+            StructType::FunctionReturn(x) => fields(x, naming),
+            StructType::CallbackArg(x) => fields(x, naming),
+            StructType::Universal(x) => fields(x, naming),
+        }
+    }
+
+    fn initializer(
+        &self,
+        st: &StructType<Validated>,
+        initializer: &Handle<Initializer<Validated>>,
+        naming: &Naming,
+    ) -> String {
+        match st {
+            StructType::FunctionArg(x) => initializer_signature(x, initializer, naming),
+            StructType::FunctionReturn(x) => initializer_signature(x, initializer, naming),
+            StructType::CallbackArg(x) => initializer_signature(x, initializer, naming),
+            StructType::Universal(x) => initializer_signature(x, initializer, naming),
+        }
+    }
+
+    fn callback(
+        &self,
+        _handle: &Handle<Interface<Validated>>,
+        callback: &CallbackFunction<Validated>,
+        naming: &Naming,
+    ) -> String {
+        let args = callback
+            .arguments
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{} {}",
+                    arg.arg_type.get_cpp_callback_arg_type(naming),
+                    arg.core_cpp_type(naming)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!(
+            "virtual {} {}({});",
+            callback.return_type.get_cpp_callback_return_type(naming),
+            callback.core_cpp_type(naming),
+            args
+        )
+    }
+
+    fn constructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        constructor: &ClassConstructor<Validated>,
+        naming: &Naming,
+    ) -> String {
+        format!(
+            "{}({});",
+            class.core_cpp_type(naming),
+            cpp_arguments(constructor.function.arguments.iter(), naming)
+        )
+    }
+
+    fn destructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        _destructor: &ClassDestructor<Validated>,
+        naming: &Naming,
+    ) -> Option<String> {
+        Some(format!("~{}();", class.core_cpp_type(naming)))
+    }
+
+    fn method(&self, method: &Method<Validated>, naming: &Naming) -> String {
+        format!(
+            "{} {}({});",
+            method
+                .native_function
+                .return_type
+                .get_cpp_function_return_type(naming),
+            method.name.snake_case(naming),
+            cpp_arguments(method.native_function.arguments.iter().skip(1), naming)
+        )
+    }
+
+    fn static_method(&self, method: &StaticMethod<Validated>, naming: &Naming) -> String {
+        format!(
+            "static {} {}({});",
+            method
+                .native_function
+                .return_type
+                .get_cpp_function_return_type(naming),
+            method.name.snake_case(naming),
+            cpp_arguments(method.native_function.arguments.iter(), naming)
+        )
+    }
+
+    fn future_method(&self, method: &FutureMethod<Validated>, naming: &Naming) -> String {
+        format!(
+            "{} {}({});",
+            method
+                .native_function
+                .return_type
+                .get_cpp_function_return_type(naming),
+            method.name.snake_case(naming),
+            cpp_arguments(method.native_function.arguments.iter().skip(1), naming)
+        )
+    }
+}
+
+fn fields<T>(handle: &Handle<Struct<T, Validated>>, naming: &Naming) -> Vec<String>
+where
+    T: StructFieldType + CppStructType,
+{
+    handle
+        .fields()
+        .map(|x| {
+            format!(
+                "{} {};",
+                x.field_type.struct_member_type(naming),
+                x.name.snake_case(naming)
+            )
+        })
+        .collect()
+}
+
+fn initializer_signature<T>(
+    handle: &Handle<Struct<T, Validated>>,
+    initializer: &Handle<Initializer<Validated>>,
+    naming: &Naming,
+) -> String
+where
+    T: StructFieldType + CppFunctionArgType,
+{
+    let args = handle
+        .initializer_args(initializer.clone())
+        .map(|x| {
+            format!(
+                "{} {}",
+                x.field_type.get_cpp_function_arg_type(naming),
+                x.name.snake_case(naming)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    match initializer.initializer_type {
+        InitializerType::Normal => format!("{}({});", handle.core_cpp_type(naming), args),
+        InitializerType::Static => format!(
+            "static {} {}({});",
+            handle.core_cpp_type(naming),
+            initializer.name.snake_case(naming),
+            args
+        ),
+    }
+}
@@ -0,0 +1,287 @@
+use crate::backend::dotnet::conversion::TypeInfo;
+use crate::backend::dotnet::helpers::dotnet_parameter;
+use crate::backend::dotnet::TargetFramework;
+use crate::backend::site::{Item, Language};
+use crate::model::*;
+
+pub(super) struct DotNet;
+
+impl Language for DotNet {
+    fn id(&self) -> &'static str {
+        "dotnet"
+    }
+
+    fn name(&self) -> &'static str {
+        "C#"
+    }
+
+    fn target(&self) -> Option<TargetLanguage> {
+        Some(TargetLanguage::DotNet)
+    }
+
+    fn example_language(&self) -> ExampleLanguage {
+        ExampleLanguage::DotNet
+    }
+
+    fn null(&self) -> &'static str {
+        "null"
+    }
+
+    fn iterator(&self) -> &'static str {
+        "collection"
+    }
+
+    fn reference(&self, reference: &Validated, naming: &Naming) -> String {
+        match reference {
+            Validated::Argument(name) => name.mixed_case(naming),
+            Validated::Class(class) => class.name.camel_case(naming),
+            Validated::ClassMethod(class, method, _) => {
+                format!(
+                    "{}.{}()",
+                    class.name().camel_case(naming),
+                    method.camel_case(naming)
+                )
+            }
+            Validated::ClassConstructor(class, _) => {
+                format!("new {}()", class.name().camel_case(naming))
+            }
+            Validated::ClassDestructor(class, _) => format!(
+                "{}.{}()",
+                class.name().camel_case(naming),
+                destructor_name(&class.destruction_mode, naming)
+            ),
+            Validated::Struct(st) => st.name().camel_case(naming),
+            Validated::StructField(st, field) => {
+                format!(
+                    "{}.{}",
+                    st.name().camel_case(naming),
+                    field.camel_case(naming)
+                )
+            }
+            Validated::Enum(handle) => handle.name.camel_case(naming),
+            Validated::EnumVariant(handle, variant) => {
+                format!(
+                    "{}.{}",
+                    handle.name.camel_case(naming),
+                    variant.camel_case(naming)
+                )
+            }
+            Validated::Interface(handle) => format!("I{}", handle.name.camel_case(naming)),
+            Validated::InterfaceMethod(handle, callback) => {
+                format!(
+                    "I{}.{}()",
+                    handle.name.camel_case(naming),
+                    callback.camel_case(naming)
+                )
+            }
+        }
+    }
+
+    fn error(&self, error: &ErrorType<Validated>, naming: &Naming) -> String {
+        error.exception_name.camel_case(naming)
+    }
+
+    fn item(&self, item: &Item, naming: &Naming) -> Option<String> {
+        Some(match item {
+            Item::Class(x) => format!("public sealed class {}", x.name().camel_case(naming)),
+            Item::StaticClass(x) => format!("public static class {}", x.name.camel_case(naming)),
+            Item::Interface(x) => format!("public interface I{}", x.name.camel_case(naming)),
+            Item::Struct(x) => format!("public class {}", x.name().camel_case(naming)),
+            Item::Enum(x) => format!("public enum {}", x.name.camel_case(naming)),
+            Item::Constants(x) => format!("public static class {}", x.name.camel_case(naming)),
+        })
+    }
+
+    fn constant(
+        &self,
+        _set: &Handle<ConstantSet<Validated>>,
+        constant: &Constant<Validated>,
+        naming: &Naming,
+    ) -> String {
+        let ConstantValue::U8(value, Representation::Hex) = constant.value;
+        format!(
+            "public const byte {} = 0x{:02X?};",
+            constant.name.camel_case(naming),
+            value
+        )
+    }
+
+    fn variant(
+        &self,
+        _handle: &Handle<Enum<Validated>>,
+        variant: &EnumVariant<Validated>,
+        naming: &Naming,
+    ) -> String {
+        format!("{} = {}", variant.name.camel_case(naming), variant.value)
+    }
+
+    fn fields(&self, st: &StructType<Validated>, naming: &Naming) -> Vec<String> {
+        match st {
+            StructType::FunctionArg(x) => fields(x, naming),
+            StructType::FunctionReturn(x) => fields(x, naming),
+            StructType::CallbackArg(x) => fields(x, naming),
+            StructType::Universal(x) => fields(x, naming),
+        }
+    }
+
+    fn initializer(
+        &self,
+        st: &StructType<Validated>,
+        initializer: &Handle<Initializer<Validated>>,
+        naming: &Naming,
+    ) -> String {
+        match st {
+            StructType::FunctionArg(x) => initializer_signature(x, initializer, naming),
+            StructType::FunctionReturn(x) => initializer_signature(x, initializer, naming),
+            StructType::CallbackArg(x) => initializer_signature(x, initializer, naming),
+            StructType::Universal(x) => initializer_signature(x, initializer, naming),
+        }
+    }
+
+    fn callback(
+        &self,
+        _handle: &Handle<Interface<Validated>>,
+        callback: &CallbackFunction<Validated>,
+        naming: &Naming,
+    ) -> String {
+        let args = callback
+            .arguments
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{} {}",
+                    arg.arg_type.get_dotnet_type(naming),
+                    arg.name.mixed_case(naming)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!(
+            "{} {}({});",
+            callback.return_type.get_dotnet_type(naming),
+            callback.name.camel_case(naming),
+            args
+        )
+    }
+
+    fn constructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        constructor: &ClassConstructor<Validated>,
+        naming: &Naming,
+    ) -> String {
+        format!(
+            "public {}({})",
+            class.name().camel_case(naming),
+            arguments(constructor.function.arguments.iter(), naming)
+        )
+    }
+
+    fn destructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        _destructor: &ClassDestructor<Validated>,
+        naming: &Naming,
+    ) -> Option<String> {
+        if class.destruction_mode.is_manual_destruction() {
+            Some(format!(
+                "public void {}()",
+                destructor_name(&class.destruction_mode, naming)
+            ))
+        } else {
+            // called by the finalizer
+            None
+        }
+    }
+
+    fn method(&self, method: &Method<Validated>, naming: &Naming) -> String {
+        format!(
+            "public {} {}({})",
+            method.native_function.return_type.get_dotnet_type(naming),
+            method.name.camel_case(naming),
+            arguments(method.native_function.arguments.iter().skip(1), naming)
+        )
+    }
+
+    fn static_method(&self, method: &StaticMethod<Validated>, naming: &Naming) -> String {
+        format!(
+            "public static {} {}({})",
+            method.native_function.return_type.get_dotnet_type(naming),
+            method.name.camel_case(naming),
+            arguments(method.native_function.arguments.iter(), naming)
+        )
+    }
+
+    fn future_method(&self, method: &FutureMethod<Validated>, naming: &Naming) -> String {
+        format!(
+            "public Task<{}> {}({})",
+            method.future.value_type.get_dotnet_type(naming),
+            method.name.camel_case(naming),
+            arguments(method.arguments_without_callback(), naming)
+        )
+    }
+}
+
+fn destructor_name(mode: &DestructionMode, naming: &Naming) -> String {
+    match mode {
+        DestructionMode::Custom(name) => name.camel_case(naming),
+        DestructionMode::Automatic | DestructionMode::Dispose => "Dispose".to_string(),
+    }
+}
+
+fn arguments<'a, T>(iter: T, naming: &Naming) -> String
+where
+    T: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    iter.map(|x| dotnet_parameter(x, TargetFramework::NetStandard2_0, naming))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn fields<T>(handle: &Handle<Struct<T, Validated>>, naming: &Naming) -> Vec<String>
+where
+    T: StructFieldType + TypeInfo,
+{
+    handle
+        .fields()
+        .map(|x| {
+            format!(
+                "{} {};",
+                x.field_type.get_dotnet_type(naming),
+                x.name.camel_case(naming)
+            )
+        })
+        .collect()
+}
+
+fn initializer_signature<T>(
+    handle: &Handle<Struct<T, Validated>>,
+    initializer: &Handle<Initializer<Validated>>,
+    naming: &Naming,
+) -> String
+where
+    T: StructFieldType + TypeInfo,
+{
+    let args = handle
+        .initializer_args(initializer.clone())
+        .map(|x| {
+            format!(
+                "{} {}",
+                x.field_type.get_dotnet_type(naming),
+                x.name.mixed_case(naming)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    match initializer.initializer_type {
+        InitializerType::Normal => format!("public {}({})", handle.name().camel_case(naming), args),
+        InitializerType::Static => format!(
+            "public static {} {}({})",
+            handle.name().camel_case(naming),
+            initializer.name.camel_case(naming),
+            args
+        ),
+    }
+}
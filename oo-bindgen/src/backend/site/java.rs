@@ -0,0 +1,292 @@
+use crate::backend::java::api::conversion::JavaType;
+use crate::backend::site::{Item, Language};
+use crate::model::*;
+
+pub(super) struct Java;
+
+impl Language for Java {
+    fn id(&self) -> &'static str {
+        "java"
+    }
+
+    fn name(&self) -> &'static str {
+        "Java"
+    }
+
+    fn target(&self) -> Option<TargetLanguage> {
+        Some(TargetLanguage::Java)
+    }
+
+    fn example_language(&self) -> ExampleLanguage {
+        ExampleLanguage::Java
+    }
+
+    fn null(&self) -> &'static str {
+        "null"
+    }
+
+    fn iterator(&self) -> &'static str {
+        "collection"
+    }
+
+    fn reference(&self, reference: &Validated, naming: &Naming) -> String {
+        match reference {
+            Validated::Argument(name) => name.mixed_case(naming),
+            Validated::Class(class) => class.name.camel_case(naming),
+            Validated::ClassMethod(class, method, _) => {
+                format!(
+                    "{}.{}()",
+                    class.name().camel_case(naming),
+                    method.mixed_case(naming)
+                )
+            }
+            Validated::ClassConstructor(class, _) => {
+                format!("new {}()", class.name().camel_case(naming))
+            }
+            Validated::ClassDestructor(class, _) => format!(
+                "{}.{}()",
+                class.name().camel_case(naming),
+                destructor_name(&class.destruction_mode, naming)
+            ),
+            Validated::Struct(st) => st.name().camel_case(naming),
+            Validated::StructField(st, field) => {
+                format!(
+                    "{}.{}",
+                    st.name().camel_case(naming),
+                    field.mixed_case(naming)
+                )
+            }
+            Validated::Enum(handle) => handle.name.camel_case(naming),
+            Validated::EnumVariant(handle, variant) => format!(
+                "{}.{}",
+                handle.name.camel_case(naming),
+                variant.capital_snake_case()
+            ),
+            Validated::Interface(handle) => handle.name.camel_case(naming),
+            Validated::InterfaceMethod(handle, callback) => {
+                format!(
+                    "{}.{}()",
+                    handle.name.camel_case(naming),
+                    callback.mixed_case(naming)
+                )
+            }
+        }
+    }
+
+    fn error(&self, error: &ErrorType<Validated>, naming: &Naming) -> String {
+        error.exception_name.camel_case(naming)
+    }
+
+    fn item(&self, item: &Item, naming: &Naming) -> Option<String> {
+        Some(match item {
+            Item::Class(x) => format!("public final class {}", x.name().camel_case(naming)),
+            Item::StaticClass(x) => format!("public final class {}", x.name.camel_case(naming)),
+            Item::Interface(x) => format!("public interface {}", x.name.camel_case(naming)),
+            Item::Struct(x) => format!("public final class {}", x.name().camel_case(naming)),
+            Item::Enum(x) => format!("public enum {}", x.name.camel_case(naming)),
+            Item::Constants(x) => format!("public final class {}", x.name.camel_case(naming)),
+        })
+    }
+
+    fn constant(
+        &self,
+        _set: &Handle<ConstantSet<Validated>>,
+        constant: &Constant<Validated>,
+        _naming: &Naming,
+    ) -> String {
+        let ConstantValue::U8(value, Representation::Hex) = constant.value;
+        format!(
+            "public static final UByte {} = UByte.valueOf(0x{:02X?});",
+            constant.name.capital_snake_case(),
+            value
+        )
+    }
+
+    fn variant(
+        &self,
+        _handle: &Handle<Enum<Validated>>,
+        variant: &EnumVariant<Validated>,
+        _naming: &Naming,
+    ) -> String {
+        format!("{}({})", variant.name.capital_snake_case(), variant.value)
+    }
+
+    fn fields(&self, st: &StructType<Validated>, naming: &Naming) -> Vec<String> {
+        match st {
+            StructType::FunctionArg(x) => fields(x, naming),
+            StructType::FunctionReturn(x) => fields(x, naming),
+            StructType::CallbackArg(x) => fields(x, naming),
+            StructType::Universal(x) => fields(x, naming),
+        }
+    }
+
+    fn initializer(
+        &self,
+        st: &StructType<Validated>,
+        initializer: &Handle<Initializer<Validated>>,
+        naming: &Naming,
+    ) -> String {
+        match st {
+            StructType::FunctionArg(x) => initializer_signature(x, initializer, naming),
+            StructType::FunctionReturn(x) => initializer_signature(x, initializer, naming),
+            StructType::CallbackArg(x) => initializer_signature(x, initializer, naming),
+            StructType::Universal(x) => initializer_signature(x, initializer, naming),
+        }
+    }
+
+    fn callback(
+        &self,
+        _handle: &Handle<Interface<Validated>>,
+        callback: &CallbackFunction<Validated>,
+        naming: &Naming,
+    ) -> String {
+        let modifier = if callback.default_implementation.is_some() {
+            "default "
+        } else {
+            ""
+        };
+
+        let args = callback
+            .arguments
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{} {}",
+                    arg.arg_type.as_java_primitive(naming),
+                    arg.name.mixed_case(naming)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!(
+            "{}{} {}({});",
+            modifier,
+            callback.return_type.as_java_primitive(naming),
+            callback.name.mixed_case(naming),
+            args
+        )
+    }
+
+    fn constructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        constructor: &ClassConstructor<Validated>,
+        naming: &Naming,
+    ) -> String {
+        format!(
+            "public {}({})",
+            class.name().camel_case(naming),
+            arguments(constructor.function.arguments.iter(), naming)
+        )
+    }
+
+    fn destructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        _destructor: &ClassDestructor<Validated>,
+        naming: &Naming,
+    ) -> Option<String> {
+        match &class.destruction_mode {
+            // called by the finalizer
+            DestructionMode::Automatic => None,
+            mode => Some(format!("public void {}()", destructor_name(mode, naming))),
+        }
+    }
+
+    fn method(&self, method: &Method<Validated>, naming: &Naming) -> String {
+        format!(
+            "public {} {}({})",
+            method.native_function.return_type.as_java_primitive(naming),
+            method.name.mixed_case(naming),
+            arguments(method.native_function.arguments.iter().skip(1), naming)
+        )
+    }
+
+    fn static_method(&self, method: &StaticMethod<Validated>, naming: &Naming) -> String {
+        format!(
+            "public static {} {}({})",
+            method.native_function.return_type.as_java_primitive(naming),
+            method.name.mixed_case(naming),
+            arguments(method.native_function.arguments.iter(), naming)
+        )
+    }
+
+    fn future_method(&self, method: &FutureMethod<Validated>, naming: &Naming) -> String {
+        format!(
+            "public java.util.concurrent.CompletionStage<{}> {}({})",
+            method.future.value_type.as_java_object(naming),
+            method.name.mixed_case(naming),
+            arguments(method.arguments_without_callback(), naming)
+        )
+    }
+}
+
+fn destructor_name(mode: &DestructionMode, naming: &Naming) -> String {
+    match mode {
+        DestructionMode::Custom(name) => name.mixed_case(naming),
+        DestructionMode::Automatic | DestructionMode::Dispose => "close".to_string(),
+    }
+}
+
+fn arguments<'a, T>(iter: T, naming: &Naming) -> String
+where
+    T: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    iter.map(|param| {
+        format!(
+            "{} {}",
+            param.arg_type.as_java_primitive(naming),
+            param.name.mixed_case(naming)
+        )
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+fn fields<T>(handle: &Handle<Struct<T, Validated>>, naming: &Naming) -> Vec<String>
+where
+    T: StructFieldType + JavaType,
+{
+    handle
+        .fields()
+        .map(|x| {
+            format!(
+                "{} {};",
+                x.field_type.as_java_primitive(naming),
+                x.name.mixed_case(naming)
+            )
+        })
+        .collect()
+}
+
+fn initializer_signature<T>(
+    handle: &Handle<Struct<T, Validated>>,
+    initializer: &Handle<Initializer<Validated>>,
+    naming: &Naming,
+) -> String
+where
+    T: StructFieldType + JavaType,
+{
+    let args = handle
+        .initializer_args(initializer.clone())
+        .map(|x| {
+            format!(
+                "{} {}",
+                x.field_type.as_java_primitive(naming),
+                x.name.mixed_case(naming)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    match initializer.initializer_type {
+        InitializerType::Normal => format!("public {}({})", handle.name().camel_case(naming), args),
+        InitializerType::Static => format!(
+            "public static {} {}({})",
+            handle.name().camel_case(naming),
+            initializer.name.mixed_case(naming),
+            args
+        ),
+    }
+}
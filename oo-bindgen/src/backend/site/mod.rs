@@ -0,0 +1,695 @@
+use std::path::Path;
+
+use crate::backend::*;
+use crate::model::*;
+
+mod c;
+mod cpp;
+mod dotnet;
+mod java;
+
+/// Languages documented by the site, the first one is selected by default
+const LANGUAGES: &[&dyn Language] = &[&c::C, &cpp::Cpp, &java::Java, &dotnet::DotNet];
+
+/// Names and declarations of the API elements in one of the documented languages
+trait Language {
+    /// Identifier of the language in the HTML classes and attributes
+    fn id(&self) -> &'static str;
+    /// Name displayed in the language switchers
+    fn name(&self) -> &'static str;
+    /// Language whose naming rules apply to the declarations, C uses the names of the FFI
+    fn target(&self) -> Option<TargetLanguage>;
+    /// Language of the documentation examples shown with the declarations
+    fn example_language(&self) -> ExampleLanguage;
+    /// How a null value is spelled
+    fn null(&self) -> &'static str;
+    /// How the doc strings call an iterator
+    fn iterator(&self) -> &'static str;
+    /// Name of a referenced element, e.g. `TestClass.getValue()` in Java
+    fn reference(&self, reference: &Validated, naming: &Naming) -> String;
+    /// Type reported when a function fails
+    fn error(&self, error: &ErrorType<Validated>, naming: &Naming) -> String;
+    /// Declaration of the element documented on a page, if the language has a type for it
+    fn item(&self, item: &Item, naming: &Naming) -> Option<String>;
+    fn constant(
+        &self,
+        set: &Handle<ConstantSet<Validated>>,
+        constant: &Constant<Validated>,
+        naming: &Naming,
+    ) -> String;
+    fn variant(
+        &self,
+        handle: &Handle<Enum<Validated>>,
+        variant: &EnumVariant<Validated>,
+        naming: &Naming,
+    ) -> String;
+    /// Declaration of each field, in the order of the fields of the struct
+    fn fields(&self, st: &StructType<Validated>, naming: &Naming) -> Vec<String>;
+    fn initializer(
+        &self,
+        st: &StructType<Validated>,
+        initializer: &Handle<Initializer<Validated>>,
+        naming: &Naming,
+    ) -> String;
+    fn callback(
+        &self,
+        handle: &Handle<Interface<Validated>>,
+        callback: &CallbackFunction<Validated>,
+        naming: &Naming,
+    ) -> String;
+    fn constructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        constructor: &ClassConstructor<Validated>,
+        naming: &Naming,
+    ) -> String;
+    /// Declaration of the destructor, if the language lets the user call it
+    fn destructor(
+        &self,
+        class: &Handle<Class<Validated>>,
+        destructor: &ClassDestructor<Validated>,
+        naming: &Naming,
+    ) -> Option<String>;
+    fn method(&self, method: &Method<Validated>, naming: &Naming) -> String;
+    fn static_method(&self, method: &StaticMethod<Validated>, naming: &Naming) -> String;
+    fn future_method(&self, method: &FutureMethod<Validated>, naming: &Naming) -> String;
+}
+
+/// Element of the API documented on its own page
+#[derive(Copy, Clone)]
+enum Item<'a> {
+    Class(&'a Handle<Class<Validated>>),
+    StaticClass(&'a Handle<StaticClass<Validated>>),
+    Interface(&'a Handle<Interface<Validated>>),
+    Struct(&'a StructType<Validated>),
+    Enum(&'a Handle<Enum<Validated>>),
+    Constants(&'a Handle<ConstantSet<Validated>>),
+}
+
+impl Item<'_> {
+    fn kind(&self) -> &'static str {
+        match self {
+            Item::Class(_) => "class",
+            Item::StaticClass(_) => "static_class",
+            Item::Interface(_) => "interface",
+            Item::Struct(_) => "struct",
+            Item::Enum(_) => "enum",
+            Item::Constants(_) => "constants",
+        }
+    }
+
+    fn name(&self) -> &Name {
+        match self {
+            Item::Class(x) => x.name(),
+            Item::StaticClass(x) => &x.name,
+            Item::Interface(x) => &x.name,
+            Item::Struct(x) => x.name(),
+            Item::Enum(x) => &x.name,
+            Item::Constants(x) => &x.name,
+        }
+    }
+
+    fn doc(&self) -> &Doc<Validated> {
+        match self {
+            Item::Class(x) => &x.doc,
+            Item::StaticClass(x) => &x.doc,
+            Item::Interface(x) => &x.doc,
+            Item::Struct(x) => x.doc(),
+            Item::Enum(x) => &x.doc,
+            Item::Constants(x) => &x.doc,
+        }
+    }
+
+    fn page(&self) -> String {
+        page(self.kind(), self.name())
+    }
+}
+
+/// Headings of the index, in the order the kinds of items are listed
+const SECTIONS: &[(&str, &str)] = &[
+    ("class", "Classes"),
+    ("static_class", "Static classes"),
+    ("interface", "Interfaces"),
+    ("struct", "Structs"),
+    ("enum", "Enums"),
+    ("constants", "Constants"),
+];
+
+/// Write a static HTML reference of the C, C++, Java and .NET bindings to `output_dir`
+///
+/// Every element is documented once with its declaration in each language, a switcher selects
+/// the language that is displayed. The site only needs a browser, nothing is generated by doxygen
+/// or javadoc.
+pub(crate) fn generate(lib: &Library, output_dir: &Path) -> FormattingResult<()> {
+    logged::create_dir_all(output_dir)?;
+
+    let items = items(lib);
+
+    write_index(lib, &items, &output_dir.join("index.html"))?;
+    for item in &items {
+        write_page(lib, item, &output_dir.join(item.page()))?;
+    }
+
+    let mut f = FilePrinter::new(output_dir.join("style.css"), Naming::default())?;
+    write_lines(&mut f, include_str!("copy/style.css"))?;
+    let mut f = FilePrinter::new(output_dir.join("site.js"), Naming::default())?;
+    write_lines(&mut f, include_str!("copy/site.js"))
+}
+
+fn items(lib: &Library) -> Vec<Item<'_>> {
+    let mut items = Vec::new();
+    items.extend(lib.classes().map(Item::Class));
+    items.extend(lib.static_classes().map(Item::StaticClass));
+    // future interfaces are implemented by the bindings, the user never sees them
+    items.extend(lib.interfaces().filter_map(|x| match x {
+        InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) => Some(Item::Interface(x)),
+        InterfaceType::Future(_) => None,
+    }));
+    items.extend(lib.structs().map(Item::Struct));
+    items.extend(lib.enums().map(Item::Enum));
+    items.extend(lib.constants().map(Item::Constants));
+    items
+}
+
+fn write_lines(f: &mut dyn Printer, content: &str) -> FormattingResult<()> {
+    for line in content.lines() {
+        f.writeln(line)?;
+    }
+    Ok(())
+}
+
+fn page(kind: &str, name: &Name) -> String {
+    format!("{}.{}.html", kind, name)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Page of the site with the header common to every page
+fn html_page<F>(lib: &Library, path: &Path, title: &str, body: F) -> FormattingResult<()>
+where
+    F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+{
+    // the names are spelled for each language by the declarations and references
+    let mut f = FilePrinter::new(path, Naming::new(None, lib.naming_rules.clone()))?;
+
+    f.writeln("<!DOCTYPE html>")?;
+    f.writeln("<html lang=\"en\">")?;
+    f.writeln("<head>")?;
+    f.writeln("<meta charset=\"utf-8\">")?;
+    f.writeln(&format!(
+        "<title>{} - {} {}</title>",
+        escape(title),
+        lib.settings.name,
+        lib.version
+    ))?;
+    f.writeln("<link rel=\"stylesheet\" href=\"style.css\">")?;
+    f.writeln("<script src=\"site.js\" defer></script>")?;
+    f.writeln("</head>")?;
+    f.writeln(&format!("<body data-lang=\"{}\">", LANGUAGES[0].id()))?;
+    f.writeln("<header>")?;
+    f.writeln(&format!(
+        "<a href=\"index.html\">{} {}</a>",
+        lib.settings.name, lib.version
+    ))?;
+    print_switcher(&mut f)?;
+    f.writeln("</header>")?;
+    f.writeln("<main>")?;
+    body(&mut f)?;
+    f.writeln("</main>")?;
+    f.writeln("</body>")?;
+    f.writeln("</html>")
+}
+
+fn print_switcher(f: &mut dyn Printer) -> FormattingResult<()> {
+    f.writeln("<nav class=\"switcher\">")?;
+    for language in LANGUAGES {
+        f.write(&format!(
+            "<button data-lang=\"{}\">{}</button>",
+            language.id(),
+            language.name()
+        ))?;
+    }
+    f.write("</nav>")
+}
+
+fn write_index(lib: &Library, items: &[Item], path: &Path) -> FormattingResult<()> {
+    html_page(lib, path, "API reference", |f| {
+        f.writeln(&format!("<h1>{} API reference</h1>", lib.settings.name))?;
+        f.writeln(&format!("<p>{}</p>", escape(&lib.info.description)))?;
+        for (kind, heading) in SECTIONS {
+            let items: Vec<&Item> = items.iter().filter(|x| x.kind() == *kind).collect();
+            if items.is_empty() {
+                continue;
+            }
+            f.writeln(&format!("<h2>{}</h2>", heading))?;
+            f.writeln("<dl class=\"index\">")?;
+            for item in items {
+                f.writeln(&format!(
+                    "<dt><a href=\"{}\">{}</a></dt>",
+                    item.page(),
+                    item.name()
+                ))?;
+                f.writeln("<dd>")?;
+                print_docstring(f, &item.doc().brief)?;
+                f.write("</dd>")?;
+            }
+            f.writeln("</dl>")?;
+        }
+        Ok(())
+    })
+}
+
+fn write_page(lib: &Library, item: &Item, path: &Path) -> FormattingResult<()> {
+    html_page(lib, path, item.name().as_ref(), |f| {
+        f.writeln(&format!("<h1>{}</h1>", item.name()))?;
+        let item_declarations = declarations(f.naming(), |x, naming| x.item(item, naming));
+        print_declarations(f, item_declarations)?;
+        print_doc(f, item.doc())?;
+
+        match item {
+            Item::Class(x) => print_class(f, x),
+            Item::StaticClass(x) => {
+                for method in &x.static_methods {
+                    let declarations = declarations(f.naming(), |x, naming| {
+                        Some(x.static_method(method, naming))
+                    });
+                    print_member(
+                        f,
+                        &format!("method.{}", method.name),
+                        &method.native_function.doc,
+                        declarations,
+                    )?;
+                    print_function_details(f, &method.native_function, 0)?;
+                    f.writeln("</section>")?;
+                }
+                Ok(())
+            }
+            Item::Interface(x) => {
+                for callback in &x.callbacks {
+                    let declarations = declarations(f.naming(), |l, naming| {
+                        Some(l.callback(x, callback, naming))
+                    });
+                    print_member(
+                        f,
+                        &format!("callback.{}", callback.name),
+                        &callback.doc,
+                        declarations,
+                    )?;
+                    print_arguments(f, callback.arguments.iter().map(|x| (&x.name, &x.doc)))?;
+                    if let Some(doc) = callback.return_type.get_doc() {
+                        f.writeln("<h4>Returns</h4>")?;
+                        f.writeln("<div>")?;
+                        print_docstring(f, doc)?;
+                        f.write("</div>")?;
+                    }
+                    f.writeln("</section>")?;
+                }
+                Ok(())
+            }
+            Item::Struct(x) => {
+                let fields: Vec<Vec<String>> = LANGUAGES
+                    .iter()
+                    .map(|l| l.fields(x, &f.naming().switch(l.target())))
+                    .collect();
+                for (index, (name, doc)) in x.field_docs().into_iter().enumerate() {
+                    let declarations = fields.iter().map(|x| Some(x[index].clone())).collect();
+                    print_member(f, &format!("field.{}", name), doc, declarations)?;
+                    f.writeln("</section>")?;
+                }
+                for initializer in x.initializers() {
+                    let declarations = declarations(f.naming(), |l, naming| {
+                        Some(l.initializer(x, initializer, naming))
+                    });
+                    print_member(
+                        f,
+                        &format!("initializer.{}", initializer.name),
+                        &initializer.doc,
+                        declarations,
+                    )?;
+                    f.writeln("</section>")?;
+                }
+                Ok(())
+            }
+            Item::Enum(x) => {
+                for variant in &x.variants {
+                    let declarations =
+                        declarations(f.naming(), |l, naming| Some(l.variant(x, variant, naming)));
+                    print_member(
+                        f,
+                        &format!("variant.{}", variant.name),
+                        &variant.doc,
+                        declarations,
+                    )?;
+                    f.writeln("</section>")?;
+                }
+                Ok(())
+            }
+            Item::Constants(x) => {
+                for constant in &x.values {
+                    let declarations = declarations(f.naming(), |l, naming| {
+                        Some(l.constant(x, constant, naming))
+                    });
+                    print_member(
+                        f,
+                        &format!("constant.{}", constant.name),
+                        &constant.doc,
+                        declarations,
+                    )?;
+                    f.writeln("</section>")?;
+                }
+                Ok(())
+            }
+        }
+    })
+}
+
+fn print_class(f: &mut dyn Printer, class: &Handle<Class<Validated>>) -> FormattingResult<()> {
+    if let Some(constructor) = &class.constructor {
+        let declarations = declarations(f.naming(), |l, naming| {
+            Some(l.constructor(class, constructor, naming))
+        });
+        print_member(f, "constructor", &constructor.function.doc, declarations)?;
+        print_function_details(f, &constructor.function, 0)?;
+        f.writeln("</section>")?;
+    }
+
+    if let Some(destructor) = &class.destructor {
+        let declarations = declarations(f.naming(), |l, naming| {
+            l.destructor(class, destructor, naming)
+        });
+        print_member(f, "destructor", &destructor.function.doc, declarations)?;
+        f.writeln("</section>")?;
+    }
+
+    for method in &class.methods {
+        let declarations = declarations(f.naming(), |l, naming| Some(l.method(method, naming)));
+        print_member(
+            f,
+            &format!("method.{}", method.name),
+            &method.native_function.doc,
+            declarations,
+        )?;
+        print_function_details(f, &method.native_function, 1)?;
+        f.writeln("</section>")?;
+    }
+
+    for method in &class.static_methods {
+        let declarations = declarations(f.naming(), |l, naming| {
+            Some(l.static_method(method, naming))
+        });
+        print_member(
+            f,
+            &format!("method.{}", method.name),
+            &method.native_function.doc,
+            declarations,
+        )?;
+        print_function_details(f, &method.native_function, 0)?;
+        f.writeln("</section>")?;
+    }
+
+    for method in &class.future_methods {
+        let declarations = declarations(f.naming(), |l, naming| {
+            Some(l.future_method(method, naming))
+        });
+        print_member(
+            f,
+            &format!("method.{}", method.name),
+            &method.native_function.doc,
+            declarations,
+        )?;
+        print_arguments(
+            f,
+            method
+                .arguments_without_callback()
+                .map(|x| (&x.name, &x.doc)),
+        )?;
+        f.writeln("<h4>Returns</h4>")?;
+        f.writeln("<div>")?;
+        f.write("A future completed with ")?;
+        print_docstring(f, &method.future.value_type_doc)?;
+        f.write("</div>")?;
+        if let Some(error) = method.future.error_type.get() {
+            print_error(f, error)?;
+        }
+        f.writeln("</section>")?;
+    }
+
+    Ok(())
+}
+
+/// Open the section of a member, the caller adds the details and closes it
+fn print_member(
+    f: &mut dyn Printer,
+    anchor: &str,
+    doc: &Doc<Validated>,
+    declarations: Vec<Option<String>>,
+) -> FormattingResult<()> {
+    let (_, name) = anchor.split_once('.').unwrap_or(("", anchor));
+
+    f.writeln(&format!("<section class=\"member\" id=\"{}\">", anchor))?;
+    f.writeln(&format!("<h3><a href=\"#{}\">{}</a>", anchor, name))?;
+    if doc.deprecated.is_some() {
+        f.write(" <span class=\"badge\">deprecated</span>")?;
+    }
+    f.write("</h3>")?;
+    print_switcher(f)?;
+    print_declarations(f, declarations)?;
+    print_doc(f, doc)
+}
+
+/// Declaration of an element in each language, with the names spelled for the language
+fn declarations<F>(naming: &Naming, declaration: F) -> Vec<Option<String>>
+where
+    F: Fn(&dyn Language, &Naming) -> Option<String>,
+{
+    LANGUAGES
+        .iter()
+        .map(|x| declaration(*x, &naming.switch(x.target())))
+        .collect()
+}
+
+fn print_declarations(
+    f: &mut dyn Printer,
+    declarations: Vec<Option<String>>,
+) -> FormattingResult<()> {
+    for (language, declaration) in LANGUAGES.iter().zip(declarations) {
+        match declaration {
+            Some(x) => f.writeln(&format!(
+                "<pre class=\"declaration lang-{}\"><code>{}</code></pre>",
+                language.id(),
+                escape(&x)
+            ))?,
+            None => f.writeln(&format!(
+                "<p class=\"declaration lang-{}\">Not declared in {}</p>",
+                language.id(),
+                language.name()
+            ))?,
+        }
+    }
+    Ok(())
+}
+
+/// Parameters, return value and error of a function, skipping the arguments that are implicit
+/// in the object oriented languages
+fn print_function_details(
+    f: &mut dyn Printer,
+    function: &Handle<Function<Validated>>,
+    skip: usize,
+) -> FormattingResult<()> {
+    print_arguments(
+        f,
+        function
+            .arguments
+            .iter()
+            .skip(skip)
+            .map(|x| (&x.name, &x.doc)),
+    )?;
+
+    if let Some(doc) = function.return_type.get_doc() {
+        f.writeln("<h4>Returns</h4>")?;
+        f.writeln("<div>")?;
+        print_docstring(f, doc)?;
+        f.write("</div>")?;
+    }
+
+    if let Some(error) = function.error_type.get() {
+        print_error(f, error)?;
+    }
+
+    Ok(())
+}
+
+fn print_arguments<'a, I>(f: &mut dyn Printer, arguments: I) -> FormattingResult<()>
+where
+    I: Iterator<Item = (&'a Name, &'a DocString<Validated>)>,
+{
+    let mut arguments = arguments.peekable();
+    if arguments.peek().is_none() {
+        return Ok(());
+    }
+
+    f.writeln("<h4>Parameters</h4>")?;
+    f.writeln("<dl class=\"parameters\">")?;
+    for (name, doc) in arguments {
+        let reference = Validated::Argument(name.clone());
+        let names = per_language(f.naming(), |x, naming| x.reference(&reference, naming));
+        f.writeln(&format!("<dt>{}</dt>", names))?;
+        f.writeln("<dd>")?;
+        print_docstring(f, doc)?;
+        f.write("</dd>")?;
+    }
+    f.writeln("</dl>")
+}
+
+fn print_error(f: &mut dyn Printer, error: &ErrorType<Validated>) -> FormattingResult<()> {
+    let names = per_language(f.naming(), |x, naming| x.error(error, naming));
+    f.writeln("<h4>Errors</h4>")?;
+    f.writeln(&format!(
+        "<div>Fails with <a href=\"{}\">{}</a></div>",
+        page("enum", &error.inner.name),
+        names
+    ))
+}
+
+fn print_doc(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
+    if let Some(deprecated) = &doc.deprecated {
+        f.writeln(&format!(
+            "<div class=\"deprecated\"><b>Deprecated since {}:</b> ",
+            deprecated.since
+        ))?;
+        print_docstring(f, &deprecated.note)?;
+        f.write("</div>")?;
+    }
+
+    f.writeln("<div class=\"paragraph\">")?;
+    print_docstring(f, &doc.brief)?;
+    f.write("</div>")?;
+
+    for paragraph in &doc.details {
+        match paragraph {
+            DocParagraph::Details(x) => {
+                f.writeln("<div class=\"paragraph\">")?;
+                print_docstring(f, x)?;
+                f.write("</div>")?;
+            }
+            DocParagraph::Warning(x) => {
+                f.writeln("<div class=\"warning\"><b>Warning:</b> ")?;
+                print_docstring(f, x)?;
+                f.write("</div>")?;
+            }
+            DocParagraph::Example(x) => {
+                for language in LANGUAGES {
+                    if let Some(code) = x.get(language.example_language()) {
+                        f.writeln(&format!(
+                            "<pre class=\"example lang-{}\"><code>{}</code></pre>",
+                            language.id(),
+                            escape(code)
+                        ))?;
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(since) = &doc.since {
+        f.writeln(&format!("<div class=\"since\">Since {}</div>", since))?;
+    }
+
+    Ok(())
+}
+
+fn print_docstring(f: &mut dyn Printer, docstring: &DocString<Validated>) -> FormattingResult<()> {
+    for element in docstring.elements() {
+        match element {
+            DocStringElement::Text(text) => f.write(&escape(text))?,
+            DocStringElement::Null => {
+                let names = per_language(f.naming(), |x, _| x.null().to_string());
+                f.write(&names)?
+            }
+            DocStringElement::Iterator => {
+                let names = per_language(f.naming(), |x, _| x.iterator().to_string());
+                f.write(&names)?
+            }
+            DocStringElement::Reference(reference) => {
+                let names = per_language(f.naming(), |x, naming| x.reference(reference, naming));
+                match link(reference) {
+                    Some(href) => f.write(&format!("<a href=\"{}\">{}</a>", href, names))?,
+                    None => f.write(&names)?,
+                }
+            }
+            DocStringElement::Code(code) => f.write(&format!("<code>{}</code>", escape(code)))?,
+            DocStringElement::Bold(text) => f.write(&format!("<b>{}</b>", escape(text)))?,
+            DocStringElement::Link { text, url } => {
+                f.write(&format!("<a href=\"{}\">{}</a>", escape(url), escape(text)))?
+            }
+            DocStringElement::List(items) => {
+                f.write("<ul>")?;
+                for item in items {
+                    f.write("<li>")?;
+                    print_docstring(f, item)?;
+                    f.write("</li>")?;
+                }
+                f.write("</ul>")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Spell a name in every language, only the one of the selected language is displayed
+fn per_language<F>(naming: &Naming, name: F) -> String
+where
+    F: Fn(&dyn Language, &Naming) -> String,
+{
+    LANGUAGES
+        .iter()
+        .map(|x| {
+            format!(
+                "<code class=\"lang-{}\">{}</code>",
+                x.id(),
+                escape(&name(*x, &naming.switch(x.target())))
+            )
+        })
+        .collect()
+}
+
+/// Page and anchor documenting a referenced element
+fn link(reference: &Validated) -> Option<String> {
+    match reference {
+        Validated::Argument(_) => None,
+        Validated::Class(x) => match x.class_type {
+            ClassType::Normal => Some(page("class", &x.name)),
+            // iterators and collections are converted to the types of each language
+            ClassType::Iterator | ClassType::Collection => None,
+        },
+        Validated::ClassMethod(class, method, _) => {
+            Some(format!("{}#method.{}", page("class", class.name()), method))
+        }
+        Validated::ClassConstructor(class, _) => {
+            Some(format!("{}#constructor", page("class", class.name())))
+        }
+        Validated::ClassDestructor(class, _) => {
+            Some(format!("{}#destructor", page("class", class.name())))
+        }
+        Validated::Struct(x) => Some(page("struct", x.name())),
+        Validated::StructField(x, field) => {
+            Some(format!("{}#field.{}", page("struct", x.name()), field))
+        }
+        Validated::Enum(x) => Some(page("enum", &x.name)),
+        Validated::EnumVariant(x, variant) => {
+            Some(format!("{}#variant.{}", page("enum", &x.name), variant))
+        }
+        Validated::Interface(x) => Some(page("interface", &x.name)),
+        Validated::InterfaceMethod(x, callback) => Some(format!(
+            "{}#callback.{}",
+            page("interface", &x.name),
+            callback
+        )),
+    }
+}
@@ -0,0 +1,444 @@
+use super::conversion::*;
+use super::doc::*;
+use super::interface::write_native_interface;
+use super::structure::default_value_expression;
+use super::*;
+
+/// What to do with the value returned by a native function
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum NativeReturn {
+    /// return the converted value
+    Value,
+    /// initialize the class with the returned handle
+    Constructor,
+}
+
+fn swift_params<'a, I>(args: I, naming: &Naming) -> String
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    args.map(|arg| {
+        let default = match &arg.default {
+            Some(x) => format!(" = {}", default_value_expression(x, naming)),
+            None => String::new(),
+        };
+        format!(
+            "{}: {}{}",
+            arg.name.mixed_case(naming),
+            arg.arg_type.as_swift_type(naming),
+            default
+        )
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+fn throws(function: &Function<Validated>) -> &'static str {
+    if function.error_type.is_some() {
+        " throws"
+    } else {
+        ""
+    }
+}
+
+fn returns(function: &Function<Validated>, naming: &Naming) -> String {
+    match function.return_type.get_value() {
+        Some(x) => format!(" -> {}", x.as_swift_type(naming)),
+        None => String::new(),
+    }
+}
+
+fn write_error_check(
+    f: &mut dyn Printer,
+    error_type: &ErrorType<Validated>,
+) -> FormattingResult<()> {
+    let ok = &error_type.inner.variants[0].name;
+    f.writeln(&format!(
+        "if _error != {}_{}_{} {{",
+        error_type.inner.settings.c_ffi_prefix.capital_snake_case(),
+        error_type.inner.name.capital_snake_case(),
+        ok.capital_snake_case()
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!(
+            "throw {}(rawValue: Int32(truncatingIfNeeded: _error.rawValue))!",
+            error_type.inner.name.camel_case(f.naming())
+        ))
+    })?;
+    f.writeln("}")
+}
+
+/// Write the statements that convert the arguments, call the native function and convert its result
+///
+/// `instance` replaces the first argument of methods, `native_arg` names an argument that is already a C value
+fn write_native_call(
+    f: &mut dyn Printer,
+    function: &Handle<Function<Validated>>,
+    instance: Option<&str>,
+    native_arg: Option<&Name>,
+    native_return: NativeReturn,
+) -> FormattingResult<()> {
+    let skip = if instance.is_some() { 1 } else { 0 };
+    let converted = || {
+        function
+            .arguments
+            .iter()
+            .skip(skip)
+            .filter(move |arg| Some(&arg.name) != native_arg)
+    };
+
+    if converted().any(|arg| arg.arg_type.requires_scope()) {
+        f.writeln(&format!("let {} = NativeScope()", SCOPE))?;
+        f.writeln(&format!("defer {{ {}.release() }}", SCOPE))?;
+    }
+
+    let args: Vec<String> = instance
+        .map(|x| x.to_string())
+        .into_iter()
+        .chain(function.arguments.iter().skip(skip).map(|arg| {
+            let name = arg.name.mixed_case(f.naming());
+            if Some(&arg.name) == native_arg {
+                name
+            } else {
+                arg.arg_type.convert_to_native(&name, f.naming())
+            }
+        }))
+        .collect();
+
+    let call = format!("{}({})", function.to_c_type(), args.join(", "));
+
+    let write_return = |f: &mut dyn Printer, value: &FunctionReturnValue| match native_return {
+        NativeReturn::Value => f.writeln(&format!(
+            "return {}",
+            value.convert_to_swift("_result", f.naming())
+        )),
+        NativeReturn::Constructor => f.writeln("self.init(handle: _result!)"),
+    };
+
+    match function.get_signature_type() {
+        SignatureType::NoErrorNoReturn => f.writeln(&call),
+        SignatureType::NoErrorWithReturn(value, _) => {
+            f.writeln(&format!("let _result = {}", call))?;
+            write_return(f, &value)
+        }
+        SignatureType::ErrorNoReturn(error_type) => {
+            f.writeln(&format!("let _error = {}", call))?;
+            write_error_check(f, &error_type)
+        }
+        SignatureType::ErrorWithReturn(error_type, value, _) => {
+            let args = args
+                .into_iter()
+                .chain(std::iter::once("_out".to_string()))
+                .collect::<Vec<String>>()
+                .join(", ");
+            f.writeln(&format!(
+                "let (_error, _result) = withOutParameter {{ _out in {}({}) }}",
+                function.to_c_type(),
+                args
+            ))?;
+            write_error_check(f, &error_type)?;
+            write_return(f, &value)
+        }
+    }
+}
+
+fn write_function_docs<'a, I>(
+    f: &mut dyn Printer,
+    function: &Function<Validated>,
+    args: I,
+    return_doc: Option<&DocString<Validated>>,
+) -> FormattingResult<()>
+where
+    I: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
+{
+    doxygen(f, |f| {
+        docc_print(f, &function.doc)?;
+
+        let mut args = args.peekable();
+        if args.peek().is_some() || return_doc.is_some() || function.error_type.is_some() {
+            f.newline()?;
+        }
+
+        for arg in args {
+            f.writeln(&format!(
+                "- Parameter {}: ",
+                arg.name.mixed_case(f.naming())
+            ))?;
+            docstring_print(f, &arg.doc)?;
+        }
+
+        if let Some(doc) = return_doc {
+            f.writeln("- Returns: ")?;
+            docstring_print(f, doc)?;
+        }
+
+        if let Some(error) = function.error_type.get() {
+            f.writeln(&format!(
+                "- Throws: ``{}``",
+                error.inner.name.camel_case(f.naming())
+            ))?;
+        }
+
+        Ok(())
+    })
+}
+
+fn write_constructor(
+    f: &mut dyn Printer,
+    constructor: &ClassConstructor<Validated>,
+) -> FormattingResult<()> {
+    let function = &constructor.function;
+    write_function_docs(f, function, function.arguments.iter(), None)?;
+    f.writeln(&format!(
+        "public convenience init({}){} {{",
+        swift_params(function.arguments.iter(), f.naming()),
+        throws(function)
+    ))?;
+    indented(f, |f| {
+        write_native_call(f, function, None, None, NativeReturn::Constructor)
+    })?;
+    f.writeln("}")
+}
+
+fn write_destructor(
+    f: &mut dyn Printer,
+    class: &Class<Validated>,
+    destructor: &ClassDestructor<Validated>,
+) -> FormattingResult<()> {
+    let destroy = format!("{}(handle)", destructor.function.to_c_type());
+
+    let method_name = match &class.destruction_mode {
+        DestructionMode::Automatic => {
+            f.writeln("deinit {")?;
+            indented(f, |f| f.writeln(&destroy))?;
+            return f.writeln("}");
+        }
+        DestructionMode::Custom(name) => name.mixed_case(f.naming()),
+        DestructionMode::Dispose => "close".to_string(),
+    };
+
+    // manual destruction can happen before the instance is released
+    f.writeln("deinit {")?;
+    indented(f, |f| f.writeln(&format!("{}()", method_name)))?;
+    f.writeln("}")?;
+    f.newline()?;
+
+    doxygen(f, |f| docc_print(f, &destructor.function.doc))?;
+    f.writeln(&format!("public func {}() {{", method_name))?;
+    indented(f, |f| {
+        f.writeln("if destroyed {")?;
+        indented(f, |f| f.writeln("return"))?;
+        f.writeln("}")?;
+        f.writeln("destroyed = true")?;
+        f.writeln(&destroy)
+    })?;
+    f.writeln("}")
+}
+
+fn write_method(f: &mut dyn Printer, method: &Method<Validated>) -> FormattingResult<()> {
+    let function = &method.native_function;
+    write_function_docs(
+        f,
+        function,
+        function.arguments.iter().skip(1),
+        function.return_type.get_doc(),
+    )?;
+    f.writeln(&format!(
+        "public func {}({}){}{} {{",
+        method.name.mixed_case(f.naming()),
+        swift_params(function.arguments.iter().skip(1), f.naming()),
+        throws(function),
+        returns(function, f.naming())
+    ))?;
+    indented(f, |f| {
+        write_native_call(f, function, Some("handle"), None, NativeReturn::Value)
+    })?;
+    f.writeln("}")
+}
+
+fn write_static_method(
+    f: &mut dyn Printer,
+    method: &StaticMethod<Validated>,
+) -> FormattingResult<()> {
+    let function = &method.native_function;
+    write_function_docs(
+        f,
+        function,
+        function.arguments.iter(),
+        function.return_type.get_doc(),
+    )?;
+    f.writeln(&format!(
+        "public static func {}({}){}{} {{",
+        method.name.mixed_case(f.naming()),
+        swift_params(function.arguments.iter(), f.naming()),
+        throws(function),
+        returns(function, f.naming())
+    ))?;
+    indented(f, |f| {
+        write_native_call(f, function, None, None, NativeReturn::Value)
+    })?;
+    f.writeln("}")
+}
+
+fn write_future_method(
+    f: &mut dyn Printer,
+    method: &FutureMethod<Validated>,
+) -> FormattingResult<()> {
+    let function = &method.native_function;
+    let settings = &function.settings.future;
+    let value_type = method.future.value_type.as_swift_type(f.naming());
+    let box_name = format!("FutureBox<{}>", value_type);
+
+    let callback_arg = method
+        .arguments()
+        .find(|arg| match &arg.arg_type {
+            FunctionArgument::Interface(x) => x.name == method.future.interface.name,
+            _ => false,
+        })
+        .map(|arg| arg.name.clone())
+        .expect("future method without a callback argument");
+
+    write_function_docs(
+        f,
+        function,
+        method.arguments_without_callback(),
+        Some(&method.future.value_type_doc),
+    )?;
+    f.writeln(&format!(
+        "public func {}({}) async throws -> {} {{",
+        method.name.mixed_case(f.naming()),
+        swift_params(method.arguments_without_callback(), f.naming()),
+        value_type
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!(
+            "try await withCheckedThrowingContinuation {{ (_continuation: CheckedContinuation<{}, Error>) in",
+            value_type
+        ))?;
+        indented(f, |f| {
+            f.writeln("let _future = FutureBox(_continuation)")?;
+            f.writeln(&format!("let {} = ", callback_arg.mixed_case(f.naming())))?;
+            write_native_interface(
+                f,
+                &method.future.interface,
+                &format!("{}.release({})", box_name, CONTEXT),
+                "_future.retain()",
+                |f, cb| {
+                    let arg = &cb.arguments[0];
+                    let name = arg.name.mixed_case(f.naming());
+                    let result = if cb.name == settings.success_callback_method_name {
+                        format!(
+                            ".success({})",
+                            arg.arg_type.convert_to_swift(&name, f.naming())
+                        )
+                    } else {
+                        format!(
+                            ".failure({})",
+                            arg.arg_type.convert_to_swift(&name, f.naming())
+                        )
+                    };
+                    f.writeln(&format!(
+                        "{}.get({}).resume(with: {})",
+                        box_name, CONTEXT, result
+                    ))
+                },
+            )?;
+
+            if function.error_type.is_some() {
+                f.writeln("do {")?;
+                indented(f, |f| {
+                    write_native_call(
+                        f,
+                        function,
+                        Some("handle"),
+                        Some(&callback_arg),
+                        NativeReturn::Value,
+                    )
+                })?;
+                f.writeln("} catch {")?;
+                indented(f, |f| f.writeln("_future.resume(with: .failure(error))"))?;
+                f.writeln("}")
+            } else {
+                write_native_call(
+                    f,
+                    function,
+                    Some("handle"),
+                    Some(&callback_arg),
+                    NativeReturn::Value,
+                )
+            }
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    class: &Handle<Class<Validated>>,
+) -> FormattingResult<()> {
+    let class_name = class.name().camel_case(f.naming());
+
+    doxygen(f, |f| docc_print(f, &class.doc))?;
+    f.writeln(&format!("public final class {} {{", class_name))?;
+    indented(f, |f| {
+        f.writeln("let handle: OpaquePointer")?;
+        if class.destructor.is_some() && class.destruction_mode.is_manual_destruction() {
+            f.writeln("private var destroyed = false")?;
+        }
+        f.newline()?;
+
+        f.writeln("init(handle: OpaquePointer) {")?;
+        indented(f, |f| f.writeln("self.handle = handle"))?;
+        f.writeln("}")?;
+
+        if let Some(constructor) = &class.constructor {
+            f.newline()?;
+            write_constructor(f, constructor)?;
+        }
+
+        if let Some(destructor) = &class.destructor {
+            f.newline()?;
+            write_destructor(f, class, destructor)?;
+        }
+
+        for method in &class.methods {
+            f.newline()?;
+            write_method(f, method)?;
+        }
+
+        for method in &class.future_methods {
+            f.newline()?;
+            write_future_method(f, method)?;
+        }
+
+        for method in &class.static_methods {
+            f.newline()?;
+            write_static_method(f, method)?;
+        }
+
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+pub(crate) fn generate_static(
+    f: &mut dyn Printer,
+    class: &Handle<StaticClass<Validated>>,
+) -> FormattingResult<()> {
+    doxygen(f, |f| docc_print(f, &class.doc))?;
+    f.writeln(&format!(
+        "public enum {} {{",
+        class.name.camel_case(f.naming())
+    ))?;
+    indented(f, |f| {
+        for (i, method) in class.static_methods.iter().enumerate() {
+            if i > 0 {
+                f.newline()?;
+            }
+            write_static_method(f, method)?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
@@ -0,0 +1,471 @@
+use crate::backend::c::ctype::CType;
+use crate::model::*;
+
+/// Name of the `NativeScope` that owns the temporary allocations of a native call
+pub(crate) const SCOPE: &str = "_scope";
+
+/// Swift spelling of a type in the public API
+pub(crate) trait SwiftType {
+    fn as_swift_type(&self, naming: &Naming) -> String;
+}
+
+/// Conversion of a Swift value into the value expected by the C API
+pub(crate) trait ConvertToNative {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String;
+    /// true if the conversion allocates memory owned by the `NativeScope`
+    fn requires_scope(&self) -> bool;
+}
+
+/// Conversion of a value returned by the C API into its Swift representation
+///
+/// Pointers are expected to be optionals, which is how Swift imports them into local variables and closure parameters
+pub(crate) trait ConvertToSwift {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String;
+}
+
+impl SwiftType for Primitive {
+    fn as_swift_type(&self, _naming: &Naming) -> String {
+        match self {
+            Self::Bool => "Bool".to_string(),
+            Self::U8 => "UInt8".to_string(),
+            Self::S8 => "Int8".to_string(),
+            Self::U16 => "UInt16".to_string(),
+            Self::S16 => "Int16".to_string(),
+            Self::U32 => "UInt32".to_string(),
+            Self::S32 => "Int32".to_string(),
+            Self::U64 => "UInt64".to_string(),
+            Self::S64 => "Int64".to_string(),
+            Self::Float => "Float".to_string(),
+            Self::Double => "Double".to_string(),
+        }
+    }
+}
+
+impl SwiftType for BasicType {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.as_swift_type(naming),
+            Self::Duration(_) => "TimeInterval".to_string(),
+            Self::Enum(x) => x.name.camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for BasicType {
+    fn convert_to_native(&self, expr: &str, _naming: &Naming) -> String {
+        match self {
+            Self::Primitive(_) => expr.to_string(),
+            Self::Duration(DurationType::Milliseconds) => format!("UInt64({} * 1000)", expr),
+            Self::Duration(DurationType::Seconds) => format!("UInt64({})", expr),
+            Self::Enum(x) => format!(
+                "{}(rawValue: .init(truncatingIfNeeded: {}.rawValue))",
+                x.to_c_type(),
+                expr
+            ),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl ConvertToSwift for BasicType {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(_) => expr.to_string(),
+            Self::Duration(DurationType::Milliseconds) => format!("TimeInterval({}) / 1000", expr),
+            Self::Duration(DurationType::Seconds) => format!("TimeInterval({})", expr),
+            Self::Enum(x) => format!(
+                "{}(rawValue: Int32(truncatingIfNeeded: {}.rawValue))!",
+                x.name.camel_case(naming),
+                expr
+            ),
+        }
+    }
+}
+
+impl ConvertToNative for StringType {
+    fn convert_to_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("{}.string({})", SCOPE, expr)
+    }
+
+    fn requires_scope(&self) -> bool {
+        true
+    }
+}
+
+impl ConvertToSwift for StringType {
+    fn convert_to_swift(&self, expr: &str, _naming: &Naming) -> String {
+        format!("String(cString: {}!)", expr)
+    }
+}
+
+impl SwiftType for ClassDeclarationHandle {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
+    }
+}
+
+impl ConvertToSwift for ClassDeclarationHandle {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        format!("{}(handle: {}!)", self.name.camel_case(naming), expr)
+    }
+}
+
+impl SwiftType for IteratorItemType {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl<D> SwiftType for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        format!("[{}]", self.item_type.as_swift_type(naming))
+    }
+}
+
+impl<D> ConvertToSwift for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        let convert = match &self.item_type {
+            IteratorItemType::Primitive(_) => "$0".to_string(),
+            IteratorItemType::Struct(x) => format!("{}(native: $0)", x.name().camel_case(naming)),
+        };
+        format!(
+            "collectIterator({}, next: {{ {}($0) }}, convert: {{ {} }})",
+            expr,
+            self.next_function.to_c_type(),
+            convert
+        )
+    }
+}
+
+impl<D> SwiftType for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        format!("[{}]", self.item_type.as_swift_type(naming))
+    }
+}
+
+impl<D> ConvertToNative for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        let reserve = if self.has_reserve {
+            format!("UInt32({}.count)", expr)
+        } else {
+            String::new()
+        };
+        format!(
+            "{}.collection({}, create: {{ {}({}) }}, add: {{ {}($0, {}) }}, destroy: {{ {}($0) }})",
+            SCOPE,
+            expr,
+            self.create_func.to_c_type(),
+            reserve,
+            self.add_func.to_c_type(),
+            self.item_type.convert_to_native("$1", naming),
+            self.delete_func.to_c_type()
+        )
+    }
+
+    fn requires_scope(&self) -> bool {
+        true
+    }
+}
+
+impl<D> SwiftType for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        self.name.camel_case(naming)
+    }
+}
+
+impl<D> ConvertToNative for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, expr: &str, _naming: &Naming) -> String {
+        format!("{}.toNative()", expr)
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl<T> SwiftType for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        self.name().camel_case(naming)
+    }
+}
+
+impl<T> ConvertToSwift for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        format!("{}(native: {})", self.name().camel_case(naming), expr)
+    }
+}
+
+impl ConvertToNative for UniversalOr<FunctionArgStructField> {
+    fn convert_to_native(&self, expr: &str, _naming: &Naming) -> String {
+        match self {
+            Self::Specific(_) => format!("{}.toNative({})", expr, SCOPE),
+            Self::Universal(_) => format!("{}.toNative()", expr),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        matches!(self, Self::Specific(_))
+    }
+}
+
+impl SwiftType for FunctionArgStructField {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::String(_) => "String".to_string(),
+            Self::Interface(x) => x.inner.as_swift_type(naming),
+            Self::Struct(x) => x.as_swift_type(naming),
+        }
+    }
+}
+
+impl ConvertToNative for FunctionArgStructField {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            Self::String(x) => x.convert_to_native(expr, naming),
+            Self::Interface(x) => x.inner.convert_to_native(expr, naming),
+            Self::Struct(x) => x.convert_to_native(expr, naming),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.requires_scope(),
+            Self::String(x) => x.requires_scope(),
+            Self::Interface(x) => x.inner.requires_scope(),
+            Self::Struct(x) => x.requires_scope(),
+        }
+    }
+}
+
+impl SwiftType for FunctionReturnStructField {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::ClassRef(x) => x.as_swift_type(naming),
+            Self::Iterator(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.as_swift_type(naming),
+        }
+    }
+}
+
+impl ConvertToSwift for FunctionReturnStructField {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_swift(expr, naming),
+            Self::ClassRef(x) => x.convert_to_swift(expr, naming),
+            Self::Iterator(x) => x.convert_to_swift(expr, naming),
+            Self::Struct(x) => x.convert_to_swift(expr, naming),
+        }
+    }
+}
+
+impl SwiftType for CallbackArgStructField {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::Iterator(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.as_swift_type(naming),
+        }
+    }
+}
+
+impl ConvertToSwift for CallbackArgStructField {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_swift(expr, naming),
+            Self::Iterator(x) => x.convert_to_swift(expr, naming),
+            Self::Struct(x) => x.convert_to_swift(expr, naming),
+        }
+    }
+}
+
+impl SwiftType for UniversalStructField {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for UniversalStructField {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            Self::Struct(_) => format!("{}.toNative()", expr),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl ConvertToSwift for UniversalStructField {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_swift(expr, naming),
+            Self::Struct(x) => format!("{}(native: {})", x.name().camel_case(naming), expr),
+        }
+    }
+}
+
+impl SwiftType for FunctionArgument {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::String(_) => "String".to_string(),
+            Self::Collection(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.as_swift_type(naming),
+            Self::StructRef(x) => format!("{}?", x.inner.name.camel_case(naming)),
+            Self::ClassRef(x) => x.as_swift_type(naming),
+            Self::Interface(x) => x.as_swift_type(naming),
+        }
+    }
+}
+
+impl ConvertToNative for FunctionArgument {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            Self::String(x) => x.convert_to_native(expr, naming),
+            Self::Collection(x) => x.convert_to_native(expr, naming),
+            Self::Struct(x) => x.convert_to_native(expr, naming),
+            Self::StructRef(_) => format!("{}.pointer({}?.toNative({}))", SCOPE, expr, SCOPE),
+            Self::ClassRef(_) => format!("{}.handle", expr),
+            Self::Interface(x) => x.convert_to_native(expr, naming),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.requires_scope(),
+            Self::String(x) => x.requires_scope(),
+            Self::Collection(x) => x.requires_scope(),
+            Self::Struct(x) => x.requires_scope(),
+            Self::StructRef(_) => true,
+            Self::ClassRef(_) => false,
+            Self::Interface(x) => x.requires_scope(),
+        }
+    }
+}
+
+impl SwiftType for CallbackArgument {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::String(_) => "String".to_string(),
+            Self::Iterator(x) => x.as_swift_type(naming),
+            Self::Class(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.as_swift_type(naming),
+        }
+    }
+}
+
+impl ConvertToSwift for CallbackArgument {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_swift(expr, naming),
+            Self::String(x) => x.convert_to_swift(expr, naming),
+            Self::Iterator(x) => x.convert_to_swift(expr, naming),
+            Self::Class(x) => x.convert_to_swift(expr, naming),
+            Self::Struct(x) => x.convert_to_swift(expr, naming),
+        }
+    }
+}
+
+impl SwiftType for CallbackReturnValue {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.name().camel_case(naming),
+        }
+    }
+}
+
+impl ConvertToNative for CallbackReturnValue {
+    fn convert_to_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_native(expr, naming),
+            Self::Struct(_) => format!("{}.toNative()", expr),
+        }
+    }
+
+    fn requires_scope(&self) -> bool {
+        false
+    }
+}
+
+impl SwiftType for FunctionReturnValue {
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.as_swift_type(naming),
+            Self::PrimitiveRef(x) => format!("{}?", x.inner.as_swift_type(naming)),
+            Self::String(_) => "String".to_string(),
+            Self::ClassRef(x) => x.as_swift_type(naming),
+            Self::Struct(x) => x.as_swift_type(naming),
+            Self::StructRef(x) => format!("{}?", x.untyped().name.camel_case(naming)),
+        }
+    }
+}
+
+impl ConvertToSwift for FunctionReturnValue {
+    fn convert_to_swift(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_swift(expr, naming),
+            Self::PrimitiveRef(_) => format!("{}?.pointee", expr),
+            Self::String(x) => x.convert_to_swift(expr, naming),
+            Self::ClassRef(x) => x.convert_to_swift(expr, naming),
+            Self::Struct(x) => x.convert_to_swift(expr, naming),
+            Self::StructRef(x) => format!(
+                "{}.map {{ {}(native: $0.pointee) }}",
+                expr,
+                x.untyped().name.camel_case(naming)
+            ),
+        }
+    }
+}
+
+impl<T> SwiftType for OptionalReturnType<T, Validated>
+where
+    T: Clone + SwiftType,
+{
+    fn as_swift_type(&self, naming: &Naming) -> String {
+        match self.get_value() {
+            None => "Void".to_string(),
+            Some(x) => x.as_swift_type(naming),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::backend::*;
+use crate::model::*;
+
+/// Markdown documentation as understood by DocC, printed inside a `///` comment
+pub(crate) fn docc_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    docstring_print(f, &doc.brief)?;
+
+    // there are no examples in this language
+    for detail in doc.details.iter().filter(|x| x.is_printed_in(None)) {
+        f.newline()?;
+        f.newline()?;
+
+        match detail {
+            DocParagraph::Details(docstring) => {
+                docstring_print(f, docstring)?;
+            }
+            DocParagraph::Warning(docstring) => {
+                f.write("- Warning: ")?;
+                docstring_print(f, docstring)?;
+            }
+            DocParagraph::Example(_) => (),
+        }
+    }
+
+    if let Some(deprecated) = &doc.deprecated {
+        f.newline()?;
+        f.newline()?;
+        f.write(&format!(
+            "- Important: Deprecated since {}, ",
+            deprecated.since
+        ))?;
+        docstring_print(f, &deprecated.note)?;
+    }
+
+    if let Some(since) = &doc.since {
+        f.newline()?;
+        f.newline()?;
+        f.write(&format!("- Since: {}", since))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn docstring_print(
+    f: &mut dyn Printer,
+    docstring: &DocString<Validated>,
+) -> FormattingResult<()> {
+    let mut elements = docstring.elements().peekable();
+    while let Some(el) = elements.next() {
+        match el {
+            DocStringElement::Text(text) => f.write(text)?,
+            DocStringElement::Null => f.write("`nil`")?,
+            DocStringElement::Iterator => f.write("array")?,
+            DocStringElement::Reference(reference) => reference_print(f, reference)?,
+            DocStringElement::Code(code) => f.write(&format!("`{}`", code))?,
+            DocStringElement::Bold(text) => f.write(&format!("**{}**", text))?,
+            DocStringElement::Link { text, url } => f.write(&format!("[{}]({})", text, url))?,
+            DocStringElement::List(items) => {
+                for item in items {
+                    f.writeln("- ")?;
+                    docstring_print(f, item)?;
+                }
+                // an empty line ends the list
+                if elements.peek().is_some() {
+                    f.newline()?;
+                    f.newline()?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn reference_print(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
+    match reference {
+        Validated::Argument(param_name) => {
+            f.write(&format!("`{}`", param_name.mixed_case(f.naming())))?
+        }
+        Validated::Class(class) => {
+            f.write(&format!("``{}``", class.name.camel_case(f.naming())))?;
+        }
+        Validated::ClassMethod(class, method_name, _) => {
+            f.write(&format!(
+                "``{}/{}``",
+                class.name().camel_case(f.naming()),
+                method_name.mixed_case(f.naming())
+            ))?;
+        }
+        Validated::ClassConstructor(class, _) => {
+            f.write(&format!("``{}/init``", class.name().camel_case(f.naming())))?;
+        }
+        Validated::ClassDestructor(class, _) => match &class.destruction_mode {
+            DestructionMode::Custom(name) => f.write(&format!(
+                "``{}/{}()``",
+                class.name().camel_case(f.naming()),
+                name.mixed_case(f.naming())
+            ))?,
+            DestructionMode::Dispose => f.write(&format!(
+                "``{}/close()``",
+                class.name().camel_case(f.naming())
+            ))?,
+            DestructionMode::Automatic => f.write(&format!(
+                "the deinitializer of ``{}``",
+                class.name().camel_case(f.naming())
+            ))?,
+        },
+        Validated::Struct(st) => {
+            f.write(&format!("``{}``", st.name().camel_case(f.naming())))?;
+        }
+        Validated::StructField(st, field_name) => {
+            f.write(&format!(
+                "``{}/{}``",
+                st.name().camel_case(f.naming()),
+                field_name.mixed_case(f.naming())
+            ))?;
+        }
+        Validated::Enum(handle) => {
+            f.write(&format!("``{}``", handle.name.camel_case(f.naming())))?;
+        }
+        Validated::EnumVariant(handle, variant_name) => {
+            f.write(&format!(
+                "``{}/{}``",
+                handle.name.camel_case(f.naming()),
+                variant_name.mixed_case(f.naming())
+            ))?;
+        }
+        Validated::Interface(interface) => {
+            f.write(&format!("``{}``", interface.name.camel_case(f.naming())))?;
+        }
+        Validated::InterfaceMethod(interface, callback_name) => {
+            f.write(&format!(
+                "``{}/{}``",
+                interface.name.camel_case(f.naming()),
+                callback_name.mixed_case(f.naming())
+            ))?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,243 @@
+use super::conversion::*;
+use super::doc::*;
+use super::*;
+
+/// Swift literal of the value returned by a default implementation
+fn default_return_value(value: &DefaultCallbackReturnValue, naming: &Naming) -> Option<String> {
+    match value {
+        DefaultCallbackReturnValue::Void => None,
+        DefaultCallbackReturnValue::Basic(BasicValue::Primitive(x)) => Some(primitive_literal(x)),
+        DefaultCallbackReturnValue::Basic(BasicValue::Duration(x)) => Some(match x {
+            DurationValue::Milliseconds(x) => format!("{:?}", *x as f64 / 1000.0),
+            DurationValue::Seconds(x) => format!("{:?}", *x as f64),
+        }),
+        DefaultCallbackReturnValue::Basic(BasicValue::Enum(x)) => Some(format!(
+            "{}.{}",
+            x.handle.name.camel_case(naming),
+            x.variant.name.mixed_case(naming)
+        )),
+        DefaultCallbackReturnValue::InitializedStruct(x) => {
+            Some(match x.initializer.initializer_type {
+                InitializerType::Normal => format!("{}()", x.handle.name().camel_case(naming)),
+                InitializerType::Static => format!(
+                    "{}.{}()",
+                    x.handle.name().camel_case(naming),
+                    x.initializer.name.mixed_case(naming)
+                ),
+            })
+        }
+    }
+}
+
+fn primitive_literal(value: &PrimitiveValue) -> String {
+    match value {
+        PrimitiveValue::Bool(x) => x.to_string(),
+        PrimitiveValue::U8(x) => x.to_string(),
+        PrimitiveValue::S8(x) => x.to_string(),
+        PrimitiveValue::U16(x) => x.to_string(),
+        PrimitiveValue::S16(x) => x.to_string(),
+        PrimitiveValue::U32(x) => x.to_string(),
+        PrimitiveValue::S32(x) => x.to_string(),
+        PrimitiveValue::U64(x) => x.to_string(),
+        PrimitiveValue::S64(x) => x.to_string(),
+        PrimitiveValue::Float(x) => format!("{:?}", x),
+        PrimitiveValue::Double(x) => format!("{:?}", x),
+    }
+}
+
+fn callback_signature(cb: &CallbackFunction<Validated>, naming: &Naming) -> String {
+    let params = cb
+        .arguments
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                arg.name.mixed_case(naming),
+                arg.arg_type.as_swift_type(naming)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    match cb.return_type.get_value() {
+        None => format!("func {}({})", cb.name.mixed_case(naming), params),
+        Some(x) => format!(
+            "func {}({}) -> {}",
+            cb.name.mixed_case(naming),
+            params,
+            x.as_swift_type(naming)
+        ),
+    }
+}
+
+/// Parameter list of a C function pointer closure, the context pointer always comes last
+pub(crate) fn closure_params(cb: &CallbackFunction<Validated>, naming: &Naming) -> String {
+    cb.arguments
+        .iter()
+        .map(|arg| arg.name.mixed_case(naming))
+        .chain(std::iter::once(CONTEXT.to_string()))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Write the C struct of an interface, `write_callback` writes the body of each C function pointer closure
+pub(crate) fn write_native_interface<F>(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+    on_destroy: &str,
+    ctx: &str,
+    mut write_callback: F,
+) -> FormattingResult<()>
+where
+    F: FnMut(&mut dyn Printer, &CallbackFunction<Validated>) -> FormattingResult<()>,
+{
+    let settings = &interface.settings.interface;
+
+    f.write(&format!("{}(", interface.to_c_type()))?;
+    indented(f, |f| {
+        for cb in &interface.callbacks {
+            f.writeln(&format!(
+                "{}: {{ {} in",
+                c_member(&cb.name, f.naming()),
+                closure_params(cb, f.naming())
+            ))?;
+            indented(f, |f| write_callback(f, cb))?;
+            f.writeln("},")?;
+        }
+        f.writeln(&format!(
+            "{}: {{ {} in {} }},",
+            c_member(&settings.destroy_func_name, f.naming()),
+            CONTEXT,
+            on_destroy
+        ))?;
+        f.writeln(&format!(
+            "{}: {}",
+            c_member(&settings.context_variable_name, f.naming()),
+            ctx
+        ))
+    })?;
+    f.writeln(")")
+}
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    interface: &Handle<Interface<Validated>>,
+) -> FormattingResult<()> {
+    let interface_name = interface.name.camel_case(f.naming());
+    let box_name = format!("InterfaceBox<{}>", interface_name);
+
+    doxygen(f, |f| docc_print(f, &interface.doc))?;
+    f.writeln(&format!("public protocol {} {{", interface_name))?;
+    indented(f, |f| {
+        for cb in &interface.callbacks {
+            doxygen(f, |f| {
+                docc_print(f, &cb.doc)?;
+
+                if !cb.arguments.is_empty() {
+                    f.newline()?;
+                }
+                for arg in &cb.arguments {
+                    f.writeln(&format!(
+                        "- Parameter {}: ",
+                        arg.name.mixed_case(f.naming())
+                    ))?;
+                    docstring_print(f, &arg.doc)?;
+                }
+
+                if let Some(doc) = cb.return_type.get_doc() {
+                    f.writeln("- Returns: ")?;
+                    docstring_print(f, doc)?;
+                }
+
+                Ok(())
+            })?;
+            f.writeln(&callback_signature(cb, f.naming()))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")?;
+
+    // default implementations
+    if interface
+        .callbacks
+        .iter()
+        .any(|cb| cb.default_implementation.is_some())
+    {
+        f.newline()?;
+        f.writeln(&format!("extension {} {{", interface_name))?;
+        indented(f, |f| {
+            for cb in &interface.callbacks {
+                if let Some(default) = &cb.default_implementation {
+                    f.writeln(&format!("public {} {{", callback_signature(cb, f.naming())))?;
+                    if let Some(value) = default_return_value(default, f.naming()) {
+                        indented(f, |f| f.writeln(&value))?;
+                    }
+                    f.writeln("}")?;
+                }
+            }
+            Ok(())
+        })?;
+        f.writeln("}")?;
+    }
+
+    // conversion to the C struct, the implementation is retained until the native side destroys the interface
+    f.newline()?;
+    f.writeln(&format!("extension {} {{", interface_name))?;
+    indented(f, |f| {
+        f.writeln(&format!("func toNative() -> {} {{", interface.to_c_type()))?;
+        indented(f, |f| f.writeln(&format!("native{}(self)", interface_name)))?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")?;
+
+    // C function pointers can't be formed in the generic context of a protocol extension
+    f.newline()?;
+    f.writeln(&format!(
+        "private func native{}(_ value: {}) -> {} {{",
+        interface_name,
+        interface_name,
+        interface.to_c_type()
+    ))?;
+    indented(f, |f| {
+        f.newline()?;
+        write_native_interface(
+            f,
+            interface,
+            &format!("{}.release({})", box_name, CONTEXT),
+            &format!("{}.retain(value)", box_name),
+            |f, cb| {
+                let args = cb
+                    .arguments
+                    .iter()
+                    .map(|arg| {
+                        let name = arg.name.mixed_case(f.naming());
+                        format!(
+                            "{}: {}",
+                            name,
+                            arg.arg_type.convert_to_swift(&name, f.naming())
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let call = format!(
+                    "{}.get({}).{}({})",
+                    box_name,
+                    CONTEXT,
+                    cb.name.mixed_case(f.naming()),
+                    args
+                );
+                match cb.return_type.get_value() {
+                    None => f.writeln(&call),
+                    Some(x) => {
+                        f.writeln(&format!("let _result = {}", call))?;
+                        f.writeln(&format!(
+                            "return {}",
+                            x.convert_to_native("_result", f.naming())
+                        ))
+                    }
+                }
+            },
+        )
+    })?;
+    f.writeln("}")
+}
@@ -0,0 +1,425 @@
+use std::path::PathBuf;
+
+use crate::backend::c::ctype::CType;
+use crate::backend::*;
+use crate::model::*;
+
+use doc::*;
+
+mod class;
+mod conversion;
+mod doc;
+mod interface;
+mod structure;
+
+/// Name of the context pointer parameter in C function pointer closures
+const CONTEXT: &str = "_ctx";
+
+/// Member of a C struct as it is imported from the C headers
+fn c_member(name: &Name, naming: &Naming) -> String {
+    name.snake_case(&naming.switch(Some(TargetLanguage::C)))
+}
+
+/// Swift compilation condition that matches a Rust platform
+///
+/// Platforms not in this map are not loadable from the package
+fn swift_platform_condition(platform: &Platform) -> Option<String> {
+    let os = match platform.target_os {
+        OS::Linux => "Linux",
+        OS::MacOS => "macOS",
+        OS::Windows => "Windows",
+        _ => return None,
+    };
+    let arch = match platform.target_arch {
+        Arch::X86_64 => "x86_64",
+        Arch::X86 => "i386",
+        Arch::AArch64 => "arm64",
+        Arch::Arm => "arm",
+        _ => return None,
+    };
+    Some(format!("os({}) && arch({})", os, arch))
+}
+
+pub(crate) struct SwiftBindgenConfig {
+    pub(crate) output_dir: PathBuf,
+    pub(crate) ffi_name: &'static str,
+    pub(crate) extra_files: Vec<PathBuf>,
+    pub(crate) platform_locations: PlatformLocations,
+}
+
+impl SwiftBindgenConfig {
+    fn c_module_name(&self, lib: &Library, naming: &Naming) -> String {
+        format!("C{}", lib.settings.name.camel_case(naming))
+    }
+
+    fn c_module_dir(&self, lib: &Library, naming: &Naming) -> PathBuf {
+        self.output_dir
+            .join("Sources")
+            .join(self.c_module_name(lib, naming))
+    }
+
+    fn source_dir(&self, lib: &Library, naming: &Naming) -> PathBuf {
+        self.output_dir
+            .join("Sources")
+            .join(lib.settings.name.camel_case(naming))
+    }
+}
+
+pub(crate) fn generate_swift_package(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+) -> FormattingResult<()> {
+    let naming = &lib.naming(TargetLanguage::Swift);
+    logged::create_dir_all(&config.output_dir)?;
+
+    generate_package_manifest(lib, config, naming)?;
+    generate_c_module(lib, config, naming)?;
+
+    logged::create_dir_all(config.source_dir(lib, naming))?;
+    generate_native_support(lib, config, naming)?;
+    generate_constants(lib, config, naming)?;
+    generate_enums(lib, config, naming)?;
+    generate_structs(lib, config, naming)?;
+    generate_interfaces(lib, config, naming)?;
+    generate_classes(lib, config, naming)?;
+
+    // for each platform location, copy the library
+    for pl in config.platform_locations.iter() {
+        let span = tracing::info_span!("libs", platform = pl.platform.target_triple);
+        let _entered = span.enter();
+
+        let lib_path = config
+            .output_dir
+            .join("lib")
+            .join(pl.platform.target_triple);
+        logged::create_dir_all(&lib_path)?;
+
+        let lib_filename = pl.platform.dyn_lib_filename(config.ffi_name);
+        logged::copy(
+            pl.location.join(&lib_filename),
+            lib_path.join(&lib_filename),
+        )?;
+
+        let bin_filename = pl.platform.bin_filename(config.ffi_name);
+        logged::copy(
+            pl.location.join(&bin_filename),
+            lib_path.join(&bin_filename),
+        )?;
+    }
+
+    // Copy extra files
+    logged::copy(
+        &lib.info.license_path,
+        config
+            .output_dir
+            .join(lib.info.license_path.file_name().unwrap()),
+    )?;
+    for path in &config.extra_files {
+        logged::copy(path, config.output_dir.join(path.file_name().unwrap()))?;
+    }
+
+    Ok(())
+}
+
+fn generate_package_manifest(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let name = lib.settings.name.camel_case(naming);
+    let c_module = config.c_module_name(lib, naming);
+    let mut f = FilePrinter::new(
+        config.output_dir.join("Package.swift"),
+        lib.naming(TargetLanguage::Swift),
+    )?;
+
+    f.writeln("// swift-tools-version:5.7")?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+    f.writeln("import PackageDescription")?;
+    f.newline()?;
+
+    // the native library is located relative to the manifest for the platform doing the build
+    f.writeln("let packageRoot = String(#filePath.dropLast(\"/Package.swift\".count))")?;
+    let mut first = true;
+    for pl in config.platform_locations.iter() {
+        if let Some(condition) = swift_platform_condition(&pl.platform) {
+            let keyword = if first { "#if" } else { "#elseif" };
+            f.writeln(&format!("{} {}", keyword, condition))?;
+            indented(&mut f, |f| {
+                f.writeln(&format!(
+                    "let libPath = packageRoot + \"/lib/{}\"",
+                    pl.platform.target_triple
+                ))
+            })?;
+            first = false;
+        }
+    }
+    if first {
+        f.writeln("let libPath = packageRoot + \"/lib\"")?;
+    } else {
+        f.writeln("#else")?;
+        indented(&mut f, |f| {
+            f.writeln("let libPath = packageRoot + \"/lib\"")
+        })?;
+        f.writeln("#endif")?;
+    }
+    f.newline()?;
+
+    f.writeln("let package = Package(")?;
+    indented(&mut f, |f| {
+        f.writeln(&format!("name: \"{}\",", name))?;
+        f.writeln("platforms: [.macOS(.v10_15), .iOS(.v13)],")?;
+        f.writeln("products: [")?;
+        indented(f, |f| {
+            f.writeln(&format!(
+                ".library(name: \"{}\", targets: [\"{}\"]),",
+                name, name
+            ))
+        })?;
+        f.writeln("],")?;
+        f.writeln("targets: [")?;
+        indented(f, |f| {
+            f.writeln(&format!(".systemLibrary(name: \"{}\"),", c_module))?;
+            f.writeln(".target(")?;
+            indented(f, |f| {
+                f.writeln(&format!("name: \"{}\",", name))?;
+                f.writeln(&format!("dependencies: [\"{}\"],", c_module))?;
+                f.writeln("linkerSettings: [")?;
+                indented(f, |f| {
+                    f.writeln(".unsafeFlags([\"-L\", libPath, \"-Xlinker\", \"-rpath\", \"-Xlinker\", libPath]),")
+                })?;
+                f.writeln("]")
+            })?;
+            f.writeln("),")
+        })?;
+        f.writeln("]")
+    })?;
+    f.writeln(")")
+}
+
+fn generate_c_module(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let dir = config.c_module_dir(lib, naming);
+    c::header::generate_c_header(lib, &dir)?;
+
+    let mut f = FilePrinter::new(
+        dir.join("module.modulemap"),
+        lib.naming(TargetLanguage::Swift),
+    )?;
+    f.writeln(&format!(
+        "module {} [system] {{",
+        config.c_module_name(lib, naming)
+    ))?;
+    indented(&mut f, |f| {
+        f.writeln(&format!("header \"{}.h\"", lib.settings.name))?;
+        f.writeln(&format!("link \"{}\"", config.ffi_name))?;
+        f.writeln("export *")
+    })?;
+    f.writeln("}")
+}
+
+fn create_source_file(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    name: &str,
+    naming: &Naming,
+) -> FormattingResult<FilePrinter> {
+    let mut f = FilePrinter::new(
+        config
+            .source_dir(lib, naming)
+            .join(format!("{}.swift", name)),
+        lib.naming(TargetLanguage::Swift),
+    )?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+    f.writeln("import Foundation")?;
+    f.writeln(&format!("import {}", config.c_module_name(lib, naming)))?;
+    f.newline()?;
+    Ok(f)
+}
+
+fn generate_native_support(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(
+        config.source_dir(lib, naming).join("NativeSupport.swift"),
+        lib.naming(TargetLanguage::Swift),
+    )?;
+    print_license(&mut f, lib)?;
+    f.newline()?;
+    f.writeln(include_str!("../../../static/swift/NativeSupport.swift"))
+}
+
+fn generate_constants(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    fn get_value_as_string(value: &ConstantValue) -> String {
+        match value {
+            ConstantValue::U8(x, Representation::Hex) => format!("0x{:02X?}", x),
+        }
+    }
+
+    for set in lib.constants() {
+        let mut f = create_source_file(lib, config, &set.name.camel_case(naming), naming)?;
+
+        doxygen(&mut f, |f| docc_print(f, &set.doc))?;
+        f.writeln(&format!(
+            "public enum {} {{",
+            set.name.camel_case(f.naming())
+        ))?;
+        indented(&mut f, |f| {
+            for value in &set.values {
+                doxygen(f, |f| docc_print(f, &value.doc))?;
+                f.writeln(&format!(
+                    "public static let {}: UInt8 = {}",
+                    value.name.mixed_case(f.naming()),
+                    get_value_as_string(&value.value)
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("}")?;
+    }
+
+    Ok(())
+}
+
+fn generate_enums(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    for handle in lib.enums() {
+        let mut f = create_source_file(lib, config, &handle.name.camel_case(naming), naming)?;
+
+        doxygen(&mut f, |f| docc_print(f, &handle.doc))?;
+        f.writeln(&format!(
+            "public enum {}: Int32 {{",
+            handle.name.camel_case(naming)
+        ))?;
+        indented(&mut f, |f| {
+            for variant in &handle.variants {
+                doxygen(f, |f| docc_print(f, &variant.doc))?;
+                f.writeln(&format!(
+                    "case {} = {}",
+                    variant.name.mixed_case(naming),
+                    variant.value
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("}")?;
+
+        // error enums are thrown directly
+        if lib.error_types().any(|err| err.inner.name == handle.name) {
+            f.newline()?;
+            f.writeln(&format!(
+                "extension {}: Error {{}}",
+                handle.name.camel_case(naming)
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_structs(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    for st in lib.structs() {
+        let mut f = create_source_file(lib, config, &st.name().camel_case(naming), naming)?;
+        let name = st.name().camel_case(naming);
+
+        match st {
+            StructType::FunctionArg(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                blocked_open_close(&mut f, &format!("extension {} {{", name), "}", |f| {
+                    structure::generate_to_native(f, x, true)
+                })?;
+            }
+            StructType::FunctionReturn(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                blocked_open_close(&mut f, &format!("extension {} {{", name), "}", |f| {
+                    structure::generate_from_native(f, x)
+                })?;
+            }
+            StructType::CallbackArg(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                blocked_open_close(&mut f, &format!("extension {} {{", name), "}", |f| {
+                    structure::generate_from_native(f, x)
+                })?;
+            }
+            StructType::Universal(x) => {
+                structure::generate(&mut f, x)?;
+                f.newline()?;
+                blocked_open_close(&mut f, &format!("extension {} {{", name), "}", |f| {
+                    structure::generate_to_native(f, x, false)?;
+                    f.newline()?;
+                    structure::generate_from_native(f, x)
+                })?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_interfaces(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    for interface in lib.interfaces() {
+        // futures are exposed as async methods and have no public protocol
+        let interface = match interface {
+            InterfaceType::Synchronous(x) => x,
+            InterfaceType::Asynchronous(x) => x,
+            InterfaceType::Future(_) => continue,
+        };
+
+        let mut f = create_source_file(lib, config, &interface.name.camel_case(naming), naming)?;
+        interface::generate(&mut f, interface)?;
+    }
+
+    Ok(())
+}
+
+fn generate_classes(
+    lib: &Library,
+    config: &SwiftBindgenConfig,
+    naming: &Naming,
+) -> FormattingResult<()> {
+    for class in lib.classes() {
+        let mut f = create_source_file(lib, config, &class.name().camel_case(naming), naming)?;
+        class::generate(&mut f, class)?;
+    }
+
+    for class in lib.static_classes() {
+        let mut f = create_source_file(lib, config, &class.name.camel_case(naming), naming)?;
+        class::generate_static(&mut f, class)?;
+    }
+
+    Ok(())
+}
+
+fn print_license(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    commented(f, |f| {
+        for line in lib.info.license_description.iter() {
+            f.writeln(line)?;
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,320 @@
+use super::conversion::*;
+use super::doc::*;
+use super::*;
+
+/// Swift expression that evaluates to a default value
+pub(crate) fn default_value_expression(value: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => x.to_string(),
+        ValidatedDefaultValue::Duration(_, x) => format!("{:?}", x.as_secs_f64()),
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!(
+                "{}.{}",
+                x.name.camel_case(naming),
+                variant.mixed_case(naming)
+            )
+        }
+        ValidatedDefaultValue::String(x) => format!("\"{}\"", x),
+        ValidatedDefaultValue::DefaultStruct(handle, InitializerType::Normal, _) => {
+            format!("{}()", handle.name().camel_case(naming))
+        }
+        ValidatedDefaultValue::DefaultStruct(handle, InitializerType::Static, name) => {
+            format!(
+                "{}.{}()",
+                handle.name().camel_case(naming),
+                name.mixed_case(naming)
+            )
+        }
+    }
+}
+
+fn get_field_value<T>(
+    field: &StructField<T, Validated>,
+    initializer: &Initializer<Validated>,
+    naming: &Naming,
+) -> String
+where
+    T: StructFieldType,
+{
+    initializer
+        .values
+        .iter()
+        .find(|x| x.name == field.name)
+        .map(|x| default_value_expression(&x.value, naming))
+        .unwrap_or_else(|| field.name.mixed_case(naming))
+}
+
+fn initializer_params<T>(
+    st: &Struct<T, Validated>,
+    initializer: &Handle<Initializer<Validated>>,
+    naming: &Naming,
+) -> String
+where
+    T: StructFieldType + SwiftType,
+{
+    st.initializer_args(initializer.clone())
+        .map(|field| {
+            format!(
+                "{}: {}",
+                field.name.mixed_case(naming),
+                field.field_type.as_swift_type(naming)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn write_initializer_docs<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+    initializer: &Handle<Initializer<Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType,
+{
+    doxygen(f, |f| {
+        docc_print(f, &initializer.doc)?;
+
+        if !initializer.values.is_empty() {
+            f.newline()?;
+            f.writeln("Values are initialized to:")?;
+            for value in initializer.values.iter() {
+                f.writeln(&format!(
+                    "- ``{}/{}``: `{}`",
+                    st.name().camel_case(f.naming()),
+                    value.name.mixed_case(f.naming()),
+                    default_value_expression(&value.value, f.naming())
+                ))?;
+            }
+        }
+
+        let mut args = st.initializer_args(initializer.clone()).peekable();
+        if args.peek().is_some() {
+            f.newline()?;
+        }
+        for field in args {
+            f.writeln(&format!(
+                "- Parameter {}: ",
+                field.name.mixed_case(f.naming())
+            ))?;
+            docstring_print(f, &field.doc.brief)?;
+        }
+
+        Ok(())
+    })
+}
+
+fn write_initializer<T>(
+    f: &mut dyn Printer,
+    visibility: &str,
+    st: &Struct<T, Validated>,
+    initializer: &Handle<Initializer<Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + SwiftType,
+{
+    f.writeln(&format!(
+        "{}init({}) {{",
+        visibility,
+        initializer_params(st, initializer, f.naming())
+    ))?;
+    indented(f, |f| {
+        for field in st.fields() {
+            f.writeln(&format!(
+                "self.{} = {}",
+                field.name.mixed_case(f.naming()),
+                get_field_value(field, initializer, f.naming())
+            ))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn write_static_initializer<T>(
+    f: &mut dyn Printer,
+    visibility: &str,
+    st: &Struct<T, Validated>,
+    initializer: &Handle<Initializer<Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + SwiftType,
+{
+    let struct_name = st.name().camel_case(f.naming());
+    let args = st
+        .fields()
+        .map(|field| {
+            format!(
+                "{}: {}",
+                field.name.mixed_case(f.naming()),
+                get_field_value(field, initializer, f.naming())
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    f.writeln(&format!(
+        "{}static func {}({}) -> {} {{",
+        visibility,
+        initializer.name.mixed_case(f.naming()),
+        initializer_params(st, initializer, f.naming()),
+        struct_name
+    ))?;
+    indented(f, |f| f.writeln(&format!("{}({})", struct_name, args)))?;
+    f.writeln("}")
+}
+
+fn has_normal_full_initializer<T>(st: &Struct<T, Validated>) -> bool
+where
+    T: StructFieldType,
+{
+    st.initializers
+        .iter()
+        .any(|x| x.initializer_type.is_normal() && x.values.is_empty())
+}
+
+/// Write the public definition of the struct
+pub(crate) fn generate<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + SwiftType,
+{
+    let struct_name = st.name().camel_case(f.naming());
+
+    let (field_declaration, visibility) = match st.visibility {
+        Visibility::Public if st.is_immutable() => ("public let", "public "),
+        Visibility::Public => ("public var", "public "),
+        Visibility::Private => ("let", ""),
+    };
+
+    let doc = match st.visibility {
+        Visibility::Public => st.doc.clone(),
+        Visibility::Private => st
+            .doc
+            .clone()
+            .warning("This struct is an opaque handle and cannot be constructed by user code"),
+    };
+
+    doxygen(f, |f| docc_print(f, &doc))?;
+    let conformance = if st.has_value_semantics() {
+        ": Hashable "
+    } else {
+        " "
+    };
+    f.writeln(&format!("public struct {}{}{{", struct_name, conformance))?;
+    indented(f, |f| {
+        for field in st.fields() {
+            doxygen(f, |f| docc_print(f, &field.doc))?;
+            f.writeln(&format!(
+                "{} {}: {}",
+                field_declaration,
+                field.name.mixed_case(f.naming()),
+                field.field_type.as_swift_type(f.naming())
+            ))?;
+        }
+
+        for initializer in &st.initializers {
+            f.newline()?;
+            if st.visibility == Visibility::Public {
+                write_initializer_docs(f, st, initializer)?;
+            }
+            match initializer.initializer_type {
+                InitializerType::Normal => write_initializer(f, visibility, st, initializer)?,
+                InitializerType::Static => {
+                    write_static_initializer(f, visibility, st, initializer)?
+                }
+            }
+        }
+
+        if !has_normal_full_initializer(st) {
+            let initializer = Handle::new(Initializer::full(
+                InitializerType::Normal,
+                brief("Initialize all values"),
+            ));
+            f.newline()?;
+            write_initializer(f, "", st, &initializer)?;
+        }
+
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+/// Write the conversion of a struct passed to the C API
+///
+/// Function argument structs take the scope that owns their strings, universal structs never allocate
+pub(crate) fn generate_to_native<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+    with_scope: bool,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertToNative,
+{
+    let scope = if with_scope {
+        format!("_ {}: NativeScope", SCOPE)
+    } else {
+        String::new()
+    };
+
+    blocked_open_close(
+        f,
+        &format!("func toNative({}) -> {} {{", scope, st.to_c_type()),
+        "}",
+        |f| {
+            f.writeln(&format!("{}(", st.to_c_type()))?;
+            indented(f, |f| {
+                let count = st.fields.len();
+                for (i, field) in st.fields().enumerate() {
+                    let separator = if i + 1 < count { "," } else { "" };
+                    f.writeln(&format!(
+                        "{}: {}{}",
+                        c_member(&field.name, f.naming()),
+                        field.field_type.convert_to_native(
+                            &format!("self.{}", field.name.mixed_case(f.naming())),
+                            f.naming()
+                        ),
+                        separator
+                    ))?;
+                }
+                Ok(())
+            })?;
+            f.writeln(")")
+        },
+    )
+}
+
+/// Write the conversion of a struct returned by the C API
+pub(crate) fn generate_from_native<T>(
+    f: &mut dyn Printer,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertToSwift,
+{
+    blocked_open_close(
+        f,
+        &format!("init(native: {}) {{", st.to_c_type()),
+        "}",
+        |f| {
+            f.writeln("self.init(")?;
+            indented(f, |f| {
+                let count = st.fields.len();
+                for (i, field) in st.fields().enumerate() {
+                    let separator = if i + 1 < count { "," } else { "" };
+                    f.writeln(&format!(
+                        "{}: {}{}",
+                        field.name.mixed_case(f.naming()),
+                        field.field_type.convert_to_swift(
+                            &format!("native.{}", c_member(&field.name, f.naming())),
+                            f.naming(),
+                        ),
+                        separator
+                    ))?;
+                }
+                Ok(())
+            })?;
+            f.writeln(")")
+        },
+    )
+}
@@ -0,0 +1,452 @@
+use crate::backend::node::conversion::TypeScriptType;
+use crate::backend::node::doc::{jsdoc_print, jsdoc_print_function};
+use crate::backend::node::{
+    destructor_name, is_future_callback, method_name, static_class_method_name,
+};
+
+use super::*;
+
+/// How an exported function is bound to its class
+enum Receiver<'a> {
+    /// the constructor returns the new instance
+    Constructor,
+    /// the first argument is the instance
+    Instance,
+    Static,
+    /// the callback argument is replaced by the returned promise
+    Future(&'a FutureMethod<Validated>),
+}
+
+pub(crate) fn generate_class(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+    class: &Handle<Class<Validated>>,
+) -> FormattingResult<()> {
+    let class_name = class.name().camel_case(f.naming());
+
+    documentation(f, |f| {
+        jsdoc_print(f, &class.doc)?;
+        if class.destructor.is_some() {
+            f.newline()?;
+            f.newline()?;
+            f.write(&format!("The native instance is destroyed by {{@link {}.{}}}, or when the instance is freed by wasm-bindgen", class_name, destructor_name(class, f.naming())))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("#[wasm_bindgen]")?;
+    f.writeln(&format!("pub struct {} {{", class_name))?;
+    indented(f, |f| {
+        f.writeln(&format!(
+            "inner: {},",
+            class.declaration.get_rust_type(config.ffi_name, f.naming())
+        ))?;
+        f.writeln("/// false for the instances borrowed from the native library")?;
+        f.writeln("owned: bool,")
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+
+    f.writeln(&format!("impl {} {{", class_name))?;
+    indented(f, |f| {
+        f.writeln(&format!(
+            "fn inner(&self) -> Result<{}, JsValue> {{",
+            class.declaration.get_rust_type(config.ffi_name, f.naming())
+        ))?;
+        indented(f, |f| {
+            f.writeln("if self.inner.is_null() {")?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "return Err(crate::runtime::type_error(\"the {} has been destroyed\"));",
+                    class_name
+                ))
+            })?;
+            f.writeln("}")?;
+            f.writeln("Ok(self.inner)")
+        })?;
+        f.writeln("}")?;
+
+        f.newline()?;
+
+        f.writeln("/// Wrap an instance that is owned by the native library")?;
+        f.writeln(&format!(
+            "pub(crate) fn wrap(inner: {}) -> JsValue {{",
+            class.declaration.get_rust_type(config.ffi_name, f.naming())
+        ))?;
+        indented(f, |f| {
+            f.writeln("if inner.is_null() {")?;
+            indented(f, |f| f.writeln("return JsValue::NULL;"))?;
+            f.writeln("}")?;
+            f.writeln("Self { inner, owned: false }.into()")
+        })?;
+        f.writeln("}")?;
+
+        if let Some(destructor) = &class.destructor {
+            f.newline()?;
+            f.writeln("fn release(&mut self) {")?;
+            indented(f, |f| {
+                f.writeln("if self.owned && !self.inner.is_null() {")?;
+                indented(f, |f| {
+                    f.writeln(&format!(
+                        "unsafe {{ {}(self.inner) }};",
+                        config.native_function(lib, &destructor.function.name)
+                    ))
+                })?;
+                f.writeln("}")?;
+                f.writeln("self.inner = std::ptr::null_mut();")
+            })?;
+            f.writeln("}")?;
+        }
+
+        Ok(())
+    })?;
+    f.writeln("}")?;
+
+    if class.destructor.is_some() {
+        f.newline()?;
+        f.writeln(&format!("impl Drop for {} {{", class_name))?;
+        indented(f, |f| {
+            f.writeln("fn drop(&mut self) {")?;
+            indented(f, |f| f.writeln("self.release();"))?;
+            f.writeln("}")
+        })?;
+        f.writeln("}")?;
+    }
+
+    f.newline()?;
+
+    f.writeln("#[wasm_bindgen]")?;
+    f.writeln(&format!("impl {} {{", class_name))?;
+    indented(f, |f| {
+        if let Some(constructor) = &class.constructor {
+            write_function(
+                f,
+                lib,
+                config,
+                &constructor.function,
+                "constructor",
+                Receiver::Constructor,
+            )?;
+            f.newline()?;
+        }
+
+        if let Some(destructor) = &class.destructor {
+            documentation(f, |f| {
+                jsdoc_print(f, &destructor.function.doc)?;
+                f.newline()?;
+                f.newline()?;
+                f.write(
+                    "It is safe to call more than once and the instance can't be used afterwards.",
+                )
+            })?;
+            f.writeln(&format!(
+                "#[wasm_bindgen(js_name = {})]",
+                destructor_name(class, f.naming())
+            ))?;
+            f.writeln(&format!(
+                "pub fn {}(&mut self) {{",
+                destructor.function.name
+            ))?;
+            indented(f, |f| f.writeln("self.release();"))?;
+            f.writeln("}")?;
+            f.newline()?;
+        }
+
+        // used to pass instances in arrays, structs and interfaces
+        f.writeln("#[wasm_bindgen(js_name = \"__handle\", skip_typescript)]")?;
+        f.writeln("pub fn __handle(&self) -> Result<usize, JsValue> {")?;
+        indented(f, |f| f.writeln("Ok(self.inner()? as usize)"))?;
+        f.writeln("}")?;
+
+        for method in &class.methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_function(
+                f,
+                lib,
+                config,
+                &method.native_function,
+                &name,
+                Receiver::Instance,
+            )?;
+        }
+
+        for method in &class.future_methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_function(
+                f,
+                lib,
+                config,
+                &method.native_function,
+                &name,
+                Receiver::Future(method),
+            )?;
+        }
+
+        for method in &class.static_methods {
+            f.newline()?;
+            let name = method_name(
+                class,
+                &method.name,
+                &method.native_function.name,
+                f.naming(),
+            );
+            write_function(
+                f,
+                lib,
+                config,
+                &method.native_function,
+                &name,
+                Receiver::Static,
+            )?;
+        }
+
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+pub(crate) fn generate_static_class(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+    class: &Handle<StaticClass<Validated>>,
+) -> FormattingResult<()> {
+    let class_name = class.name.camel_case(f.naming());
+
+    documentation(f, |f| jsdoc_print(f, &class.doc))?;
+    f.writeln("#[wasm_bindgen]")?;
+    f.writeln(&format!("pub struct {} {{", class_name))?;
+    indented(f, |f| f.writeln("_private: (),"))?;
+    f.writeln("}")?;
+
+    f.newline()?;
+
+    f.writeln("#[wasm_bindgen]")?;
+    f.writeln(&format!("impl {} {{", class_name))?;
+    indented(f, |f| {
+        for (i, method) in class.static_methods.iter().enumerate() {
+            if i > 0 {
+                f.newline()?;
+            }
+            let name = static_class_method_name(class, method, f.naming());
+            write_function(
+                f,
+                lib,
+                config,
+                &method.native_function,
+                &name,
+                Receiver::Static,
+            )?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn write_function(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+    function: &Handle<Function<Validated>>,
+    js_name: &str,
+    receiver: Receiver,
+) -> FormattingResult<()> {
+    let skip = match receiver {
+        Receiver::Instance | Receiver::Future(_) => 1,
+        Receiver::Constructor | Receiver::Static => 0,
+    };
+    let params: Vec<(&Arg<FunctionArgument, Validated>, Param)> = function
+        .arguments
+        .iter()
+        .skip(skip)
+        .filter(|arg| !is_future_callback(arg))
+        .map(|arg| (arg, Param::new(arg)))
+        .collect();
+
+    let return_value = function.return_type.get_value();
+
+    documentation(f, |f| match &receiver {
+        Receiver::Future(method) => {
+            jsdoc_print_function(
+                f,
+                function,
+                method.arguments_without_callback(),
+                Some(&method.future.value_type_doc),
+            )?;
+            f.newline()?;
+            f.write("The promise is rejected if the operation fails")
+        }
+        Receiver::Constructor => jsdoc_print_function(f, function, function.arguments.iter(), None),
+        _ => jsdoc_print_function(
+            f,
+            function,
+            function.arguments.iter().skip(skip),
+            function.return_type.get_doc(),
+        ),
+    })?;
+
+    let (returns, ts_return) = match &receiver {
+        Receiver::Constructor => ("Self".to_string(), None),
+        Receiver::Future(method) => (
+            "js_sys::Promise".to_string(),
+            Some(format!(
+                "Promise<{}>",
+                method.future.value_type.ts_type(f.naming())
+            )),
+        ),
+        Receiver::Instance | Receiver::Static => match return_value {
+            None => ("()".to_string(), None),
+            Some(x) => (x.wasm_type(f.naming()), x.ts_type_override(f.naming())),
+        },
+    };
+
+    // users are warned by the @deprecated tag of the TypeScript declarations
+    if function.doc.deprecated.is_some() {
+        f.writeln("#[allow(deprecated)]")?;
+    }
+    match (&receiver, ts_return) {
+        (Receiver::Constructor, _) => f.writeln("#[wasm_bindgen(constructor)]")?,
+        (_, None) => f.writeln(&format!("#[wasm_bindgen(js_name = {})]", js_name))?,
+        (_, Some(ts_return)) => f.writeln(&format!(
+            "#[wasm_bindgen(js_name = {}, unchecked_return_type = {:?})]",
+            js_name, ts_return
+        ))?,
+    }
+
+    let args = match receiver {
+        Receiver::Instance | Receiver::Future(_) => Some("&self".to_string()),
+        Receiver::Constructor | Receiver::Static => None,
+    }
+    .into_iter()
+    .chain(
+        params
+            .iter()
+            .map(|(arg, param)| match param.ts_type(f.naming()) {
+                Some(ts_type) => format!(
+                    "#[wasm_bindgen(unchecked_param_type = {:?})] {}: {}",
+                    ts_type,
+                    arg.name,
+                    param.wasm_type(f.naming())
+                ),
+                None => format!("{}: {}", arg.name, param.wasm_type(f.naming())),
+            }),
+    )
+    .collect::<Vec<String>>()
+    .join(", ");
+
+    f.writeln(&format!(
+        "pub fn {}({}) -> Result<{}, JsValue> {{",
+        function.name, args, returns
+    ))?;
+    indented(f, |f| {
+        if params.iter().any(|(_, param)| param.requires_scope()) {
+            f.writeln("let scope = &mut crate::runtime::Scope::default();")?;
+        }
+
+        if skip == 1 {
+            f.writeln(&format!(
+                "let {} = self.inner()?;",
+                function.arguments[0].name
+            ))?;
+        }
+
+        for (arg, param) in &params {
+            f.writeln(&format!(
+                "let {} = {};",
+                arg.name,
+                param.to_native(f.naming())
+            ))?;
+        }
+
+        if let Receiver::Future(method) = &receiver {
+            if let Some(arg) = function
+                .arguments
+                .iter()
+                .find(|arg| is_future_callback(arg))
+            {
+                f.writeln(&format!(
+                    "let (_promise, {}) = crate::interfaces::{}_create();",
+                    arg.name, method.future.interface.name
+                ))?;
+            }
+        }
+
+        let signature = function.get_signature_type();
+        let out = match signature {
+            SignatureType::ErrorWithReturn(_, _, _) => Some("_out.as_mut_ptr()".to_string()),
+            _ => None,
+        };
+        let args = function
+            .arguments
+            .iter()
+            .map(|arg| arg.name.to_string())
+            .chain(out)
+            .collect::<Vec<String>>()
+            .join(", ");
+        let invocation = format!(
+            "unsafe {{ {}({}) }}",
+            config.native_function(lib, &function.name),
+            args
+        );
+
+        match &signature {
+            SignatureType::NoErrorNoReturn => f.writeln(&format!("{};", invocation))?,
+            SignatureType::NoErrorWithReturn(_, _) => {
+                f.writeln(&format!("let _result = {};", invocation))?
+            }
+            SignatureType::ErrorNoReturn(error_type) => {
+                f.writeln(&format!("let _error = {};", invocation))?;
+                write_error_check(f, &receiver, error_type)?;
+            }
+            SignatureType::ErrorWithReturn(error_type, _, _) => {
+                f.writeln("let mut _out = std::mem::MaybeUninit::uninit();")?;
+                f.writeln(&format!("let _error = {};", invocation))?;
+                write_error_check(f, &receiver, error_type)?;
+                f.writeln("let _result = unsafe { _out.assume_init() };")?;
+            }
+        }
+
+        match (&receiver, return_value) {
+            (Receiver::Constructor, _) => f.writeln("Ok(Self { inner: _result, owned: true })"),
+            (Receiver::Future(_), _) => f.writeln("Ok(_promise)"),
+            (_, None) => f.writeln("Ok(())"),
+            (_, Some(x)) => f.writeln(&format!(
+                "Ok({})",
+                x.convert_from_native("_result", f.naming())
+            )),
+        }
+    })?;
+    f.writeln("}")
+}
+
+fn write_error_check(
+    f: &mut dyn Printer,
+    receiver: &Receiver,
+    error_type: &ErrorType<Validated>,
+) -> FormattingResult<()> {
+    f.writeln("if _error != 0 {")?;
+    indented(f, |f| {
+        // the native library has already dropped the callback and rejected the promise
+        if let Receiver::Future(_) = receiver {
+            f.writeln("crate::runtime::discard(&_promise);")?;
+        }
+        f.writeln(&format!(
+            "return Err(crate::exceptions::{}(_error));",
+            error_type.exception_name
+        ))
+    })?;
+    f.writeln("}")
+}
@@ -0,0 +1,551 @@
+use crate::backend::node::conversion::TypeScriptType;
+use crate::model::*;
+
+/// Rust type of a value in the FFI
+pub(crate) trait RustType {
+    fn get_rust_type(&self, ffi_name: &str, naming: &Naming) -> String;
+}
+
+/// Expression that converts a `&JsValue` into a native value
+///
+/// Errors are propagated with `?` and the memory borrowed by the native value is owned by `scope`
+pub(crate) trait ConvertFromJs {
+    fn convert_from_js(&self, value: &str, naming: &Naming) -> String;
+}
+
+/// Expression that converts a native value into a `JsValue`
+pub(crate) trait ConvertToJs {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String;
+}
+
+/// Rust literal of a number with the suffix of its type
+pub(crate) fn number_literal(value: &NumberValue) -> String {
+    match value {
+        NumberValue::U8(x) => format!("{}u8", x),
+        NumberValue::S8(x) => format!("{}i8", x),
+        NumberValue::U16(x) => format!("{}u16", x),
+        NumberValue::S16(x) => format!("{}i16", x),
+        NumberValue::U32(x) => format!("{}u32", x),
+        NumberValue::S32(x) => format!("{}i32", x),
+        NumberValue::U64(x) => format!("{}u64", x),
+        NumberValue::S64(x) => format!("{}i64", x),
+        NumberValue::Float(x) => format!("{:?}f32", x),
+        NumberValue::Double(x) => format!("{:?}f64", x),
+    }
+}
+
+/// Name of the generated function that builds a struct with one of its initializers
+pub(crate) fn initializer_name(struct_name: &Name, initializer: &Name) -> String {
+    format!("{}_{}", struct_name, initializer)
+}
+
+/// `JsValue` expression of a default value
+pub(crate) fn default_value_to_js(value: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => format!("JsValue::from({})", x),
+        ValidatedDefaultValue::Number(x) => format!("JsValue::from({})", number_literal(x)),
+        ValidatedDefaultValue::Duration(_, x) => format!("JsValue::from({}f64)", x.as_millis()),
+        ValidatedDefaultValue::Enum(x, variant) => format!(
+            "JsValue::from(crate::{}::{}.to_native())",
+            x.name.camel_case(naming),
+            variant.camel_case(naming)
+        ),
+        ValidatedDefaultValue::String(x) => format!("JsValue::from_str({:?})", x),
+        ValidatedDefaultValue::DefaultStruct(handle, _, name) => format!(
+            "crate::structs::{}()",
+            initializer_name(handle.name(), name)
+        ),
+    }
+}
+
+/// Rust expression of a default value in the native representation
+pub(crate) fn default_value_to_native(value: &ValidatedDefaultValue, naming: &Naming) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => number_literal(x),
+        ValidatedDefaultValue::Duration(DurationType::Milliseconds, x) => {
+            format!("{}u64", x.as_millis())
+        }
+        ValidatedDefaultValue::Duration(DurationType::Seconds, x) => {
+            format!("{}u64", x.as_secs())
+        }
+        ValidatedDefaultValue::Enum(x, variant) => format!(
+            "crate::{}::{}.to_native()",
+            x.name.camel_case(naming),
+            variant.camel_case(naming)
+        ),
+        ValidatedDefaultValue::String(x) => format!(
+            "concat!({:?}, \"\\0\").as_ptr() as *const std::os::raw::c_char",
+            x
+        ),
+        ValidatedDefaultValue::DefaultStruct(handle, _, name) => format!(
+            "crate::structs::{}_native()",
+            initializer_name(handle.name(), name)
+        ),
+    }
+}
+
+impl RustType for Primitive {
+    fn get_rust_type(&self, _ffi_name: &str, _naming: &Naming) -> String {
+        self.get_c_rust_type().to_string()
+    }
+}
+
+impl ConvertFromJs for Primitive {
+    fn convert_from_js(&self, value: &str, _naming: &Naming) -> String {
+        match self {
+            Self::Bool => format!("crate::runtime::boolean({})?", value),
+            Self::U8 | Self::S8 | Self::U16 | Self::S16 | Self::U32 | Self::S32 => {
+                format!("crate::runtime::integer({})?", value)
+            }
+            Self::U64 | Self::S64 => format!("crate::runtime::bigint({})?", value),
+            Self::Float => format!("(crate::runtime::number({})? as f32)", value),
+            Self::Double => format!("crate::runtime::number({})?", value),
+        }
+    }
+}
+
+impl ConvertToJs for Primitive {
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        // 64-bit integers become a BigInt
+        format!("JsValue::from({})", expr)
+    }
+}
+
+/// Conversion of a duration in milliseconds into the native unit
+pub(crate) fn duration_to_native(duration_type: DurationType, millis: &str) -> String {
+    match duration_type {
+        DurationType::Milliseconds => format!("crate::runtime::duration_ms({})?", millis),
+        DurationType::Seconds => format!("crate::runtime::duration_s({})?", millis),
+    }
+}
+
+/// Conversion of a native duration into a number of milliseconds
+pub(crate) fn duration_from_native(duration_type: DurationType, expr: &str) -> String {
+    match duration_type {
+        DurationType::Milliseconds => format!("{} as f64", expr),
+        DurationType::Seconds => format!("{} as f64 * 1000.0", expr),
+    }
+}
+
+impl RustType for BasicType {
+    fn get_rust_type(&self, ffi_name: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.get_rust_type(ffi_name, naming),
+            Self::Duration(_) => "u64".to_string(),
+            Self::Enum(_) => "std::os::raw::c_int".to_string(),
+        }
+    }
+}
+
+impl ConvertFromJs for BasicType {
+    fn convert_from_js(&self, value: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_from_js(value, naming),
+            Self::Duration(x) => {
+                duration_to_native(*x, &format!("crate::runtime::number({})?", value))
+            }
+            Self::Enum(x) => format!(
+                "crate::{}::from_js({})?.to_native()",
+                x.name.camel_case(naming),
+                value
+            ),
+        }
+    }
+}
+
+impl ConvertToJs for BasicType {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Primitive(x) => x.convert_to_js(expr, naming),
+            Self::Duration(x) => format!("JsValue::from({})", duration_from_native(*x, expr)),
+            Self::Enum(_) => format!("JsValue::from({})", expr),
+        }
+    }
+}
+
+impl RustType for StringType {
+    fn get_rust_type(&self, _ffi_name: &str, _naming: &Naming) -> String {
+        "*const std::os::raw::c_char".to_string()
+    }
+}
+
+impl ConvertFromJs for StringType {
+    fn convert_from_js(&self, value: &str, _naming: &Naming) -> String {
+        format!("scope.string(&crate::runtime::string({})?)?", value)
+    }
+}
+
+impl ConvertToJs for StringType {
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        format!(
+            "JsValue::from(unsafe {{ crate::runtime::string_from_native({}) }})",
+            expr
+        )
+    }
+}
+
+impl RustType for ClassDeclarationHandle {
+    fn get_rust_type(&self, ffi_name: &str, _naming: &Naming) -> String {
+        format!("*mut {}::{}", ffi_name, self.name.ffi_camel_case())
+    }
+}
+
+impl ConvertFromJs for ClassDeclarationHandle {
+    fn convert_from_js(&self, value: &str, _naming: &Naming) -> String {
+        format!("crate::runtime::handle({})?", value)
+    }
+}
+
+impl ConvertToJs for ClassDeclarationHandle {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        format!("crate::{}::wrap({})", self.name.camel_case(naming), expr)
+    }
+}
+
+impl<D> RustType for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn get_rust_type(&self, ffi_name: &str, naming: &Naming) -> String {
+        self.iter_class.get_rust_type(ffi_name, naming)
+    }
+}
+
+impl<D> ConvertToJs for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        format!("crate::iterators::{}_to_js({})", self.iter_class.name, expr)
+    }
+}
+
+impl<D> ConvertFromJs for Handle<Collection<D>>
+where
+    D: DocReference,
+{
+    fn convert_from_js(&self, value: &str, _naming: &Naming) -> String {
+        format!(
+            "crate::collections::{}_from_js({}, scope)?",
+            self.collection_class.name, value
+        )
+    }
+}
+
+impl<D> ConvertFromJs for Handle<Interface<D>>
+where
+    D: DocReference,
+{
+    fn convert_from_js(&self, value: &str, _naming: &Naming) -> String {
+        format!("crate::interfaces::{}_from_js({})?", self.name, value)
+    }
+}
+
+impl<T> RustType for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn get_rust_type(&self, ffi_name: &str, _naming: &Naming) -> String {
+        format!("{}::ffi::{}", ffi_name, self.name().ffi_camel_case())
+    }
+}
+
+impl<T> ConvertFromJs for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_from_js(&self, value: &str, _naming: &Naming) -> String {
+        format!("crate::structs::{}_from_js({}, scope)?", self.name(), value)
+    }
+}
+
+impl<T> ConvertToJs for UniversalOr<T>
+where
+    T: StructFieldType,
+{
+    fn convert_to_js(&self, expr: &str, _naming: &Naming) -> String {
+        format!("crate::structs::{}_to_js(&{})", self.name(), expr)
+    }
+}
+
+impl ConvertFromJs for FunctionArgStructField {
+    fn convert_from_js(&self, value: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_js(value, naming),
+            Self::String(x) => x.convert_from_js(value, naming),
+            Self::Interface(x) => x.inner.convert_from_js(value, naming),
+            Self::Struct(x) => x.convert_from_js(value, naming),
+        }
+    }
+}
+
+impl ConvertToJs for FunctionReturnStructField {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::ClassRef(x) => x.convert_to_js(expr, naming),
+            Self::Iterator(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+        }
+    }
+}
+
+impl ConvertToJs for CallbackArgStructField {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::Iterator(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+        }
+    }
+}
+
+impl ConvertFromJs for UniversalStructField {
+    fn convert_from_js(&self, value: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_js(value, naming),
+            Self::Struct(x) => format!("crate::structs::{}_from_js({}, scope)?", x.name(), value),
+        }
+    }
+}
+
+impl ConvertToJs for UniversalStructField {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => format!("crate::structs::{}_to_js(&{})", x.name(), expr),
+        }
+    }
+}
+
+impl ConvertFromJs for FunctionArgument {
+    fn convert_from_js(&self, value: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_js(value, naming),
+            Self::String(x) => x.convert_from_js(value, naming),
+            Self::Collection(x) => x.convert_from_js(value, naming),
+            Self::Struct(x) => x.convert_from_js(value, naming),
+            Self::StructRef(x) => format!(
+                "crate::structs::{}_ref_from_js({}, scope)?",
+                x.inner.name, value
+            ),
+            Self::ClassRef(x) => x.convert_from_js(value, naming),
+            Self::Interface(x) => x.convert_from_js(value, naming),
+        }
+    }
+}
+
+impl RustType for CallbackArgument {
+    fn get_rust_type(&self, ffi_name: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.get_rust_type(ffi_name, naming),
+            Self::String(x) => x.get_rust_type(ffi_name, naming),
+            Self::Iterator(x) => x.get_rust_type(ffi_name, naming),
+            Self::Class(x) => x.get_rust_type(ffi_name, naming),
+            Self::Struct(x) => x.get_rust_type(ffi_name, naming),
+        }
+    }
+}
+
+impl ConvertToJs for CallbackArgument {
+    fn convert_to_js(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_to_js(expr, naming),
+            Self::String(x) => x.convert_to_js(expr, naming),
+            Self::Iterator(x) => x.convert_to_js(expr, naming),
+            Self::Class(x) => x.convert_to_js(expr, naming),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+        }
+    }
+}
+
+impl RustType for CallbackReturnValue {
+    fn get_rust_type(&self, ffi_name: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.get_rust_type(ffi_name, naming),
+            Self::Struct(x) => format!("{}::ffi::{}", ffi_name, x.name().ffi_camel_case()),
+        }
+    }
+}
+
+impl ConvertFromJs for CallbackReturnValue {
+    fn convert_from_js(&self, value: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(x) => x.convert_from_js(value, naming),
+            Self::Struct(x) => format!("crate::structs::{}_from_js({}, scope)?", x.name(), value),
+        }
+    }
+}
+
+/// Parameter of an exported function
+///
+/// Types that wasm-bindgen converts are passed as is, the others are a `JsValue` converted by the generated code
+pub(crate) struct Param<'a> {
+    arg: &'a Arg<FunctionArgument, Validated>,
+}
+
+impl<'a> Param<'a> {
+    pub(crate) fn new(arg: &'a Arg<FunctionArgument, Validated>) -> Self {
+        Self { arg }
+    }
+
+    fn optional(&self, wasm_type: String) -> String {
+        match self.arg.default {
+            Some(_) => format!("Option<{}>", wasm_type),
+            None => wasm_type,
+        }
+    }
+
+    pub(crate) fn wasm_type(&self, naming: &Naming) -> String {
+        match &self.arg.arg_type {
+            FunctionArgument::Basic(BasicType::Primitive(x)) => {
+                self.optional(x.get_c_rust_type().to_string())
+            }
+            FunctionArgument::Basic(BasicType::Duration(_)) => self.optional("f64".to_string()),
+            FunctionArgument::Basic(BasicType::Enum(x)) => {
+                self.optional(format!("crate::{}", x.name.camel_case(naming)))
+            }
+            FunctionArgument::String(_) => match self.arg.default {
+                Some(_) => "Option<String>".to_string(),
+                None => "&str".to_string(),
+            },
+            FunctionArgument::ClassRef(x) => format!("&crate::{}", x.name.camel_case(naming)),
+            FunctionArgument::Collection(_)
+            | FunctionArgument::Struct(_)
+            | FunctionArgument::StructRef(_)
+            | FunctionArgument::Interface(_) => "JsValue".to_string(),
+        }
+    }
+
+    /// TypeScript type of the parameters that are a `JsValue`
+    pub(crate) fn ts_type(&self, naming: &Naming) -> Option<String> {
+        match &self.arg.arg_type {
+            FunctionArgument::Collection(_)
+            | FunctionArgument::Struct(_)
+            | FunctionArgument::StructRef(_)
+            | FunctionArgument::Interface(_) => {
+                let ts_type = self.arg.arg_type.ts_type(naming);
+                Some(match self.arg.default {
+                    Some(_) => format!("{} | undefined", ts_type),
+                    None => ts_type,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// true if the conversion allocates memory owned by the `scope`
+    pub(crate) fn requires_scope(&self) -> bool {
+        matches!(
+            self.arg.arg_type,
+            FunctionArgument::String(_)
+                | FunctionArgument::Collection(_)
+                | FunctionArgument::Struct(_)
+                | FunctionArgument::StructRef(_)
+        )
+    }
+
+    /// Expression that converts the parameter into the native value
+    pub(crate) fn to_native(&self, naming: &Naming) -> String {
+        let name = self.arg.name.to_string();
+        let default = self.arg.default.as_ref();
+        match &self.arg.arg_type {
+            FunctionArgument::Basic(BasicType::Primitive(_)) => match default {
+                Some(ValidatedDefaultValue::Number(x)) => {
+                    format!("{}.unwrap_or({})", name, number_literal(x))
+                }
+                Some(ValidatedDefaultValue::Bool(x)) => format!("{}.unwrap_or({})", name, x),
+                _ => name,
+            },
+            FunctionArgument::Basic(BasicType::Duration(x)) => match default {
+                Some(ValidatedDefaultValue::Duration(_, value)) => {
+                    duration_to_native(*x, &format!("{}.unwrap_or({}f64)", name, value.as_millis()))
+                }
+                _ => duration_to_native(*x, &name),
+            },
+            FunctionArgument::Basic(BasicType::Enum(_)) => match default {
+                Some(ValidatedDefaultValue::Enum(handle, variant)) => format!(
+                    "{}.unwrap_or(crate::{}::{}).to_native()",
+                    name,
+                    handle.name.camel_case(naming),
+                    variant.camel_case(naming)
+                ),
+                _ => format!("{}.to_native()", name),
+            },
+            FunctionArgument::String(_) => match default {
+                Some(ValidatedDefaultValue::String(x)) => {
+                    format!("scope.string({}.as_deref().unwrap_or({:?}))?", name, x)
+                }
+                _ => format!("scope.string({})?", name),
+            },
+            FunctionArgument::ClassRef(_) => format!("{}.inner()?", name),
+            _ => match default {
+                Some(value) => self.arg.arg_type.convert_from_js(
+                    &format!(
+                        "&(if {name}.is_undefined() {{ {} }} else {{ {name} }})",
+                        default_value_to_js(value, naming),
+                        name = name
+                    ),
+                    naming,
+                ),
+                None => self
+                    .arg
+                    .arg_type
+                    .convert_from_js(&format!("&{}", name), naming),
+            },
+        }
+    }
+}
+
+/// Return value of an exported function
+pub(crate) trait WasmReturnType {
+    fn wasm_type(&self, naming: &Naming) -> String;
+    /// TypeScript type of the return values that are a `JsValue`
+    fn ts_type_override(&self, naming: &Naming) -> Option<String>;
+    /// Expression that converts the native value into the returned value
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String;
+}
+
+impl WasmReturnType for FunctionReturnValue {
+    fn wasm_type(&self, naming: &Naming) -> String {
+        match self {
+            Self::Basic(BasicType::Primitive(x)) => x.get_c_rust_type().to_string(),
+            Self::Basic(BasicType::Duration(_)) => "f64".to_string(),
+            Self::Basic(BasicType::Enum(x)) => format!("crate::{}", x.name.camel_case(naming)),
+            Self::PrimitiveRef(x) => format!("Option<{}>", x.inner.get_c_rust_type()),
+            Self::String(_) => "String".to_string(),
+            Self::ClassRef(x) => format!("crate::{}", x.name.camel_case(naming)),
+            Self::Struct(_) | Self::StructRef(_) => "JsValue".to_string(),
+        }
+    }
+
+    fn ts_type_override(&self, naming: &Naming) -> Option<String> {
+        match self {
+            Self::Struct(_) | Self::StructRef(_) => Some(self.ts_type(naming)),
+            _ => None,
+        }
+    }
+
+    fn convert_from_native(&self, expr: &str, naming: &Naming) -> String {
+        match self {
+            Self::Basic(BasicType::Primitive(_)) => expr.to_string(),
+            Self::Basic(BasicType::Duration(x)) => duration_from_native(*x, expr),
+            Self::Basic(BasicType::Enum(x)) => {
+                format!("crate::{}::from_native({})?", x.name.camel_case(naming), expr)
+            }
+            Self::PrimitiveRef(_) => format!("unsafe {{ {}.as_ref() }}.copied()", expr),
+            Self::String(_) => format!(
+                "unsafe {{ crate::runtime::string_from_native({}) }}",
+                expr
+            ),
+            Self::ClassRef(x) => format!("crate::{} {{ inner: {} }}", x.name.camel_case(naming), expr),
+            Self::Struct(x) => x.convert_to_js(expr, naming),
+            Self::StructRef(x) => format!(
+                "match unsafe {{ {}.as_ref() }} {{ Some(x) => crate::structs::{}_to_js(x), None => JsValue::NULL }}",
+                expr,
+                x.untyped().name
+            ),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+
+/// Owns the native memory borrowed by the converted arguments of a call
+#[derive(Default)]
+pub(crate) struct Scope {
+    strings: Vec<CString>,
+    releases: Vec<Box<dyn FnOnce()>>,
+}
+
+impl Scope {
+    pub(crate) fn string(&mut self, value: &str) -> Result<*const c_char, JsValue> {
+        let value = CString::new(value).map_err(|_| type_error("strings can't contain a NUL character"))?;
+        let ptr = value.as_ptr();
+        self.strings.push(value);
+        Ok(ptr)
+    }
+
+    pub(crate) fn alloc<T: 'static>(&mut self, value: T) -> *mut T {
+        let ptr = Box::into_raw(Box::new(value));
+        self.on_release(move || drop(unsafe { Box::from_raw(ptr) }));
+        ptr
+    }
+
+    pub(crate) fn on_release<F: FnOnce() + 'static>(&mut self, release: F) {
+        self.releases.push(Box::new(release));
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        while let Some(release) = self.releases.pop() {
+            release();
+        }
+    }
+}
+
+pub(crate) fn type_error(message: &str) -> JsValue {
+    js_sys::TypeError::new(message).into()
+}
+
+pub(crate) fn range_error(message: &str) -> JsValue {
+    js_sys::RangeError::new(message).into()
+}
+
+/// Error thrown when a native function fails, `error` holds the value of the error enum
+pub(crate) fn exception(name: &str, message: &str, error: i32) -> JsValue {
+    let exception = js_sys::Error::new(message);
+    exception.set_name(name);
+    // only fails if the target is frozen
+    let _ = js_sys::Reflect::set(&exception, &JsValue::from_str("error"), &JsValue::from(error));
+    exception.into()
+}
+
+pub(crate) fn boolean(value: &JsValue) -> Result<bool, JsValue> {
+    value.as_bool().ok_or_else(|| type_error("expected a boolean"))
+}
+
+pub(crate) fn number(value: &JsValue) -> Result<f64, JsValue> {
+    value.as_f64().ok_or_else(|| type_error("expected a number"))
+}
+
+pub(crate) fn integer<T: TryFrom<i64>>(value: &JsValue) -> Result<T, JsValue> {
+    let value = number(value)?;
+    if value.fract() != 0.0 {
+        return Err(range_error("expected an integer"));
+    }
+    T::try_from(value as i64).map_err(|_| range_error("expected an integer in the range of the native type"))
+}
+
+pub(crate) fn bigint<T: TryFrom<JsValue>>(value: &JsValue) -> Result<T, JsValue> {
+    if !value.is_bigint() {
+        return Err(type_error("expected a bigint"));
+    }
+    T::try_from(value.clone()).map_err(|_| range_error("expected a bigint in the range of the native type"))
+}
+
+/// Durations are a number of milliseconds in JavaScript
+pub(crate) fn duration_ms(value: f64) -> Result<u64, JsValue> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(range_error("expected a positive duration"));
+    }
+    Ok(value as u64)
+}
+
+pub(crate) fn duration_s(value: f64) -> Result<u64, JsValue> {
+    duration_ms(value).map(|x| x / 1000)
+}
+
+pub(crate) fn string(value: &JsValue) -> Result<String, JsValue> {
+    value.as_string().ok_or_else(|| type_error("expected a string"))
+}
+
+/// # Safety
+///
+/// `value` must be a valid C string
+pub(crate) unsafe fn string_from_native(value: *const c_char) -> String {
+    CStr::from_ptr(value).to_string_lossy().into_owned()
+}
+
+pub(crate) fn array(value: &JsValue) -> Result<js_sys::Array, JsValue> {
+    if js_sys::Array::is_array(value) {
+        Ok(value.clone().unchecked_into())
+    } else {
+        Err(type_error("expected an array"))
+    }
+}
+
+pub(crate) fn get(object: &JsValue, name: &str) -> Result<JsValue, JsValue> {
+    if !object.is_object() {
+        return Err(type_error("expected an object"));
+    }
+    js_sys::Reflect::get(object, &JsValue::from_str(name))
+}
+
+pub(crate) fn set(object: &js_sys::Object, name: &str, value: JsValue) {
+    // only fails if the target is frozen
+    let _ = js_sys::Reflect::set(object, &JsValue::from_str(name), &value);
+}
+
+/// Native handle of an instance of a generated class
+pub(crate) fn handle<T>(value: &JsValue) -> Result<*mut T, JsValue> {
+    let method = get(value, "__handle")?;
+    let method = method
+        .dyn_ref::<js_sys::Function>()
+        .ok_or_else(|| type_error("expected an instance of a class"))?;
+    let handle = number(&method.call0(value)?)?;
+    Ok(handle as usize as *mut T)
+}
+
+/// Exceptions can't propagate through the native library, they are reported as unhandled rejections instead
+fn report(error: JsValue) {
+    let _ = js_sys::Promise::reject(&error);
+}
+
+/// Handle the rejection of a promise that is never returned to the caller
+pub(crate) fn discard(promise: &js_sys::Promise) {
+    let handler = Closure::<dyn FnMut(JsValue)>::new(|_: JsValue| {});
+    let _ = promise.catch(&handler);
+    handler.forget();
+}
+
+/// Check that a value implements the methods of an interface that don't have a default implementation
+pub(crate) fn check_implementation(value: &JsValue, methods: &[&str], functional: bool) -> Result<(), JsValue> {
+    if functional && value.is_function() {
+        return Ok(());
+    }
+    if !value.is_object() {
+        return Err(type_error("expected an object that implements the interface"));
+    }
+    for method in methods {
+        if !get(value, method)?.is_function() {
+            return Err(type_error(&format!("the implementation of the interface is missing the {} method", method)));
+        }
+    }
+    Ok(())
+}
+
+/// Context of an interface, the object that implements it
+pub(crate) fn new_target(value: &JsValue) -> *mut c_void {
+    Box::into_raw(Box::new(value.clone())) as *mut c_void
+}
+
+pub(crate) extern "C" fn release_target(ctx: *mut c_void) {
+    drop(unsafe { Box::from_raw(ctx as *mut JsValue) });
+}
+
+/// Invoke a method of the object that implements an interface
+///
+/// `default` provides the value returned to the native library if the method is missing or fails
+pub(crate) fn invoke<T, C, D>(ctx: *mut c_void, method: &str, args: &[JsValue], convert: C, default: D) -> T
+where
+    C: FnOnce(JsValue) -> Result<T, JsValue>,
+    D: FnOnce() -> T,
+{
+    let target = unsafe { &*(ctx as *const JsValue) };
+    let args: js_sys::Array = args.iter().collect();
+    // functional interfaces may be implemented by a function
+    let result = match target.dyn_ref::<js_sys::Function>() {
+        Some(function) => function.apply(&JsValue::UNDEFINED, &args),
+        None => match get(target, method) {
+            Ok(x) => match x.dyn_ref::<js_sys::Function>() {
+                Some(function) => function.apply(target, &args),
+                None => return default(),
+            },
+            Err(err) => Err(err),
+        },
+    };
+    match result.and_then(convert) {
+        Ok(x) => x,
+        Err(err) => {
+            report(err);
+            default()
+        }
+    }
+}
+
+/// Context of a future, the functions that settle the promise
+struct Deferred {
+    resolve: js_sys::Function,
+    reject: js_sys::Function,
+    settled: bool,
+}
+
+pub(crate) fn new_deferred() -> (js_sys::Promise, *mut c_void) {
+    let mut functions = None;
+    let promise = js_sys::Promise::new(&mut |resolve, reject| functions = Some((resolve, reject)));
+    // the executor runs before the constructor returns
+    let (resolve, reject) = functions.unwrap();
+    let deferred = Deferred {
+        resolve,
+        reject,
+        settled: false,
+    };
+    (promise, Box::into_raw(Box::new(deferred)) as *mut c_void)
+}
+
+pub(crate) fn settle(ctx: *mut c_void, resolve: bool, value: JsValue) {
+    let deferred = unsafe { &mut *(ctx as *mut Deferred) };
+    if deferred.settled {
+        return;
+    }
+    deferred.settled = true;
+    let function = if resolve { &deferred.resolve } else { &deferred.reject };
+    if let Err(err) = function.call1(&JsValue::UNDEFINED, &value) {
+        report(err);
+    }
+}
+
+pub(crate) extern "C" fn release_deferred(ctx: *mut c_void) {
+    settle(ctx, false, js_sys::Error::new("the operation was dropped before it completed").into());
+    drop(unsafe { Box::from_raw(ctx as *mut Deferred) });
+}
@@ -0,0 +1,317 @@
+use crate::backend::node::doc::{jsdoc, jsdoc_print};
+use crate::backend::node::typescript::write_interface_callbacks;
+
+use super::*;
+
+/// Rust expression of the value returned by a default implementation
+fn default_return_value(
+    value: &DefaultCallbackReturnValue,
+    return_type: Option<&CallbackReturnValue>,
+    naming: &Naming,
+) -> Option<String> {
+    match value {
+        DefaultCallbackReturnValue::Void => None,
+        DefaultCallbackReturnValue::Basic(BasicValue::Primitive(x)) => Some(match x {
+            PrimitiveValue::Bool(x) => x.to_string(),
+            PrimitiveValue::U8(x) => format!("{}u8", x),
+            PrimitiveValue::S8(x) => format!("{}i8", x),
+            PrimitiveValue::U16(x) => format!("{}u16", x),
+            PrimitiveValue::S16(x) => format!("{}i16", x),
+            PrimitiveValue::U32(x) => format!("{}u32", x),
+            PrimitiveValue::S32(x) => format!("{}i32", x),
+            PrimitiveValue::U64(x) => format!("{}u64", x),
+            PrimitiveValue::S64(x) => format!("{}i64", x),
+            PrimitiveValue::Float(x) => format!("{:?}f32", x),
+            PrimitiveValue::Double(x) => format!("{:?}f64", x),
+        }),
+        DefaultCallbackReturnValue::Basic(BasicValue::Duration(x)) => {
+            // durations are returned in the unit of the return type
+            let millis = match x {
+                DurationValue::Milliseconds(x) => *x,
+                DurationValue::Seconds(x) => *x * 1000,
+            };
+            Some(match return_type {
+                Some(CallbackReturnValue::Basic(BasicType::Duration(DurationType::Seconds))) => {
+                    format!("{}u64", millis / 1000)
+                }
+                _ => format!("{}u64", millis),
+            })
+        }
+        DefaultCallbackReturnValue::Basic(BasicValue::Enum(x)) => Some(format!(
+            "crate::{}::{}.to_native()",
+            x.handle.name.camel_case(naming),
+            x.variant.name.camel_case(naming)
+        )),
+        DefaultCallbackReturnValue::InitializedStruct(x) => Some(format!(
+            "crate::structs::{}_native()",
+            initializer_name(x.handle.name(), &x.initializer.name)
+        )),
+    }
+}
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+) -> FormattingResult<()> {
+    for interface in lib.interfaces() {
+        match interface {
+            InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) => {
+                write_interface(f, config, x)?
+            }
+            InterfaceType::Future(x) => write_future(f, config, x)?,
+        }
+    }
+
+    f.newline()?;
+    typescript_section(f, |f| {
+        // futures are exposed as promises
+        for interface in lib.interfaces() {
+            if let InterfaceType::Synchronous(x) | InterfaceType::Asynchronous(x) = interface {
+                f.newline()?;
+                jsdoc(f, |f| jsdoc_print(f, &x.doc))?;
+                f.writeln(&format!(
+                    "export interface {} {{",
+                    x.name.camel_case(f.naming())
+                ))?;
+                indented(f, |f| write_interface_callbacks(f, x))?;
+                f.writeln("}")?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn interface_type(config: &WasmBindgenConfig, interface: &Interface<Validated>) -> String {
+    format!(
+        "{}::ffi::{}",
+        config.ffi_name,
+        interface.name.ffi_camel_case()
+    )
+}
+
+fn trampoline_name(
+    interface: &Interface<Validated>,
+    callback: &CallbackFunction<Validated>,
+) -> String {
+    format!("{}_{}", interface.name, callback.name)
+}
+
+/// Signature of the function that the native library invokes
+fn write_trampoline_signature(
+    f: &mut dyn Printer,
+    config: &WasmBindgenConfig,
+    interface: &Interface<Validated>,
+    callback: &CallbackFunction<Validated>,
+) -> FormattingResult<()> {
+    let args = callback
+        .arguments
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                arg.name,
+                arg.arg_type.get_rust_type(config.ffi_name, f.naming())
+            )
+        })
+        .chain(std::iter::once(format!(
+            "{}: *mut std::os::raw::c_void",
+            interface.settings.interface.context_variable_name
+        )))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let returns = match callback.return_type.get_value() {
+        None => "".to_string(),
+        Some(x) => format!(" -> {}", x.get_rust_type(config.ffi_name, f.naming())),
+    };
+    f.writeln(&format!(
+        "extern \"C\" fn {}({}){} {{",
+        trampoline_name(interface, callback),
+        args,
+        returns
+    ))
+}
+
+fn write_interface(
+    f: &mut dyn Printer,
+    config: &WasmBindgenConfig,
+    interface: &Handle<Interface<Validated>>,
+) -> FormattingResult<()> {
+    let settings = &interface.settings.interface;
+
+    // methods with a default implementation may be omitted
+    let required = interface
+        .callbacks
+        .iter()
+        .filter(|cb| cb.default_implementation.is_none())
+        .map(|cb| format!("\"{}\"", cb.name.mixed_case(f.naming())))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let functional = interface
+        .get_functional_callback()
+        .map(|cb| cb.functional_transform.enabled())
+        .unwrap_or(false);
+
+    f.newline()?;
+    f.writeln(&format!(
+        "/// Implement {} with a JavaScript object",
+        interface.name.camel_case(f.naming())
+    ))?;
+    f.writeln(&format!(
+        "pub(crate) fn {}_from_js(value: &JsValue) -> Result<{}, JsValue> {{",
+        interface.name,
+        interface_type(config, interface)
+    ))?;
+    indented(f, |f| {
+        f.writeln(&format!(
+            "crate::runtime::check_implementation(value, &[{}], {})?;",
+            required, functional
+        ))?;
+        f.writeln(&format!("Ok({} {{", interface_type(config, interface)))?;
+        indented(f, |f| {
+            for cb in &interface.callbacks {
+                f.writeln(&format!(
+                    "{}: Some({}),",
+                    cb.name,
+                    trampoline_name(interface, cb)
+                ))?;
+            }
+            f.writeln(&format!(
+                "{}: Some(crate::runtime::release_target),",
+                settings.destroy_func_name
+            ))?;
+            f.writeln(&format!(
+                "{}: crate::runtime::new_target(value),",
+                settings.context_variable_name
+            ))
+        })?;
+        f.writeln("})")
+    })?;
+    f.writeln("}")?;
+
+    for cb in &interface.callbacks {
+        let return_type = cb.return_type.get_value();
+        let js_args = cb
+            .arguments
+            .iter()
+            .map(|arg| {
+                let name = arg.name.to_string();
+                arg.arg_type.convert_to_js(&name, f.naming())
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let default = cb
+            .default_implementation
+            .as_ref()
+            .and_then(|x| default_return_value(x, return_type, f.naming()));
+
+        f.newline()?;
+        write_trampoline_signature(f, config, interface, cb)?;
+        indented(f, |f| {
+            f.writeln("crate::runtime::invoke(")?;
+            indented(f, |f| {
+                f.writeln(&format!("{},", settings.context_variable_name))?;
+                f.writeln(&format!("\"{}\",", cb.name.mixed_case(f.naming())))?;
+                f.writeln(&format!("&[{}],", js_args))?;
+                match return_type {
+                    None => {
+                        f.writeln("|_| Ok(()),")?;
+                        f.writeln("|| (),")
+                    }
+                    Some(x) => {
+                        f.writeln("|value| {")?;
+                        indented(f, |f| {
+                            f.writeln("let scope = &mut crate::runtime::Scope::default();")?;
+                            f.writeln(&format!("Ok({})", x.convert_from_js("&value", f.naming())))
+                        })?;
+                        f.writeln("},")?;
+                        match default {
+                            Some(default) => f.writeln(&format!("|| {},", default)),
+                            // a failure of a required method has no meaningful value
+                            None => f.writeln("|| unsafe { std::mem::zeroed() },"),
+                        }
+                    }
+                }
+            })?;
+            f.writeln(")")
+        })?;
+        f.writeln("}")?;
+    }
+
+    Ok(())
+}
+
+fn write_future(
+    f: &mut dyn Printer,
+    config: &WasmBindgenConfig,
+    future: &FutureInterface<Validated>,
+) -> FormattingResult<()> {
+    let interface = &future.interface;
+    let settings = &interface.settings;
+
+    f.newline()?;
+    f.writeln(&format!(
+        "/// Create a {} that settles the returned promise",
+        interface.name.camel_case(f.naming())
+    ))?;
+    f.writeln(&format!(
+        "pub(crate) fn {}_create() -> (js_sys::Promise, {}) {{",
+        interface.name,
+        interface_type(config, interface)
+    ))?;
+    indented(f, |f| {
+        f.writeln("let (promise, ctx) = crate::runtime::new_deferred();")?;
+        f.writeln(&format!(
+            "let value = {} {{",
+            interface_type(config, interface)
+        ))?;
+        indented(f, |f| {
+            for cb in &interface.callbacks {
+                f.writeln(&format!(
+                    "{}: Some({}),",
+                    cb.name,
+                    trampoline_name(interface, cb)
+                ))?;
+            }
+            f.writeln(&format!(
+                "{}: Some(crate::runtime::release_deferred),",
+                settings.interface.destroy_func_name
+            ))?;
+            f.writeln(&format!(
+                "{}: ctx,",
+                settings.interface.context_variable_name
+            ))
+        })?;
+        f.writeln("};")?;
+        f.writeln("(promise, value)")
+    })?;
+    f.writeln("}")?;
+
+    for cb in &interface.callbacks {
+        let arg = &cb.arguments[0];
+        let arg_name = arg.name.to_string();
+
+        f.newline()?;
+        write_trampoline_signature(f, config, interface, cb)?;
+        indented(f, |f| {
+            let (resolve, value) = if cb.name == settings.future.success_callback_method_name {
+                (true, arg.arg_type.convert_to_js(&arg_name, f.naming()))
+            } else {
+                let value = match future.error_type.get() {
+                    Some(error) => {
+                        format!("crate::exceptions::{}({})", error.exception_name, arg.name)
+                    }
+                    None => arg.arg_type.convert_to_js(&arg_name, f.naming()),
+                };
+                (false, value)
+            };
+            f.writeln(&format!(
+                "crate::runtime::settle({}, {}, {});",
+                settings.interface.context_variable_name, resolve, value
+            ))
+        })?;
+        f.writeln("}")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,364 @@
+use crate::backend::node::doc::jsdoc_print;
+use crate::backend::*;
+use crate::model::*;
+
+use crate::backend::wasm::conversion::*;
+
+use std::path::Path;
+
+mod classes;
+mod conversion;
+mod interface;
+mod structs;
+
+/// Configuration for the wasm-bindgen (Rust) generation
+pub struct WasmBindgenConfig<'a> {
+    /// Name of the FFI target
+    pub ffi_name: &'a str,
+}
+
+impl<'a> WasmBindgenConfig<'a> {
+    /// Path of a native function of the FFI
+    fn native_function(&self, lib: &Library, name: &Name) -> String {
+        format!(
+            "{}::ffi::{}_{}",
+            self.ffi_name, lib.settings.c_ffi_prefix, name
+        )
+    }
+}
+
+fn module_string(name: &str, f: &mut dyn Printer, content: &str) -> FormattingResult<()> {
+    module(name, f, |f| {
+        for line in content.lines() {
+            f.writeln(line)?;
+        }
+        Ok(())
+    })
+}
+
+fn module<F>(name: &str, f: &mut dyn Printer, write: F) -> FormattingResult<()>
+where
+    F: Fn(&mut dyn Printer) -> FormattingResult<()>,
+{
+    f.newline()?;
+    f.writeln(&format!("pub(crate) mod {} {{", name))?;
+    indented(f, |f| {
+        f.writeln("use wasm_bindgen::prelude::*;")?;
+        write(f)
+    })?;
+    f.writeln("}")?;
+    Ok(())
+}
+
+/// Rust documentation, wasm-bindgen copies it into the TypeScript declarations
+fn documentation<F>(f: &mut dyn Printer, cb: F) -> FormattingResult<()>
+where
+    F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+{
+    cb(&mut PrefixPrinter::new(f, "/// "))
+}
+
+/// Declarations that wasm-bindgen can't infer from the exported items, e.g. plain objects
+fn typescript_section<F>(f: &mut dyn Printer, write: F) -> FormattingResult<()>
+where
+    F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+{
+    f.writeln("#[wasm_bindgen(typescript_custom_section)]")?;
+    f.writeln("const TYPESCRIPT: &'static str = r##\"")?;
+    write(f)?;
+    f.writeln("\"##;")
+}
+
+/// Generate the wasm-bindgen glue that exposes the C FFI to JavaScript
+///
+/// This function is typically called from a build.rs script in a target that builds
+/// the WebAssembly module with wasm-pack
+pub fn generate_wasm(
+    path: &Path,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, lib.naming(TargetLanguage::Node))?;
+
+    f.writeln("use wasm_bindgen::prelude::*;")?;
+
+    for set in lib.constants() {
+        f.newline()?;
+        write_constants(&mut f, set)?;
+    }
+
+    for handle in lib.enums() {
+        f.newline()?;
+        write_enum(&mut f, handle)?;
+    }
+
+    for class in lib.classes() {
+        f.newline()?;
+        classes::generate_class(&mut f, lib, config, class)?;
+    }
+
+    for class in lib.static_classes() {
+        f.newline()?;
+        classes::generate_static_class(&mut f, lib, config, class)?;
+    }
+
+    module("exceptions", &mut f, |f| write_exceptions(f, lib))?;
+    module("structs", &mut f, |f| structs::generate(f, lib, config))?;
+    module("interfaces", &mut f, |f| {
+        interface::generate(f, lib, config)
+    })?;
+    module("collections", &mut f, |f| {
+        write_collection_conversions(f, lib, config)
+    })?;
+    module("iterators", &mut f, |f| {
+        write_iterator_conversions(f, lib, config)
+    })?;
+
+    // Copy the module that never changes
+    module_string("runtime", &mut f, include_str!("copy/runtime.rs"))?;
+
+    Ok(())
+}
+
+fn write_constants(
+    f: &mut dyn Printer,
+    set: &Handle<ConstantSet<Validated>>,
+) -> FormattingResult<()> {
+    documentation(f, |f| jsdoc_print(f, &set.doc))?;
+    f.writeln("#[wasm_bindgen]")?;
+    f.writeln(&format!(
+        "pub struct {} {{",
+        set.name.camel_case(f.naming())
+    ))?;
+    indented(f, |f| f.writeln("_private: (),"))?;
+    f.writeln("}")?;
+    f.newline()?;
+    f.writeln("#[wasm_bindgen]")?;
+    f.writeln(&format!("impl {} {{", set.name.camel_case(f.naming())))?;
+    indented(f, |f| {
+        for value in &set.values {
+            let ConstantValue::U8(x, _) = value.value;
+            documentation(f, |f| jsdoc_print(f, &value.doc))?;
+            f.writeln(&format!(
+                "#[wasm_bindgen(js_name = {})]",
+                value.name.camel_case(f.naming())
+            ))?;
+            f.writeln(&format!("pub fn {}() -> u8 {{", value.name))?;
+            indented(f, |f| f.writeln(&format!("0x{:02X}", x)))?;
+            f.writeln("}")?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn write_enum(f: &mut dyn Printer, handle: &Handle<Enum<Validated>>) -> FormattingResult<()> {
+    let name = handle.name.camel_case(f.naming());
+
+    documentation(f, |f| jsdoc_print(f, &handle.doc))?;
+    f.writeln("#[wasm_bindgen]")?;
+    f.writeln("#[derive(Copy, Clone, Debug, PartialEq, Eq)]")?;
+    f.writeln(&format!("pub enum {} {{", name))?;
+    indented(f, |f| {
+        for variant in &handle.variants {
+            documentation(f, |f| jsdoc_print(f, &variant.doc))?;
+            f.writeln(&format!(
+                "{} = {},",
+                variant.name.camel_case(f.naming()),
+                variant.value
+            ))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+
+    f.writeln(&format!("impl {} {{", name))?;
+    indented(f, |f| {
+        f.writeln("pub(crate) fn to_native(self) -> std::os::raw::c_int {")?;
+        indented(f, |f| f.writeln("self as std::os::raw::c_int"))?;
+        f.writeln("}")?;
+        f.newline()?;
+        f.writeln(
+            "pub(crate) fn from_native(value: std::os::raw::c_int) -> Result<Self, JsValue> {",
+        )?;
+        indented(f, |f| {
+            f.writeln("match value {")?;
+            indented(f, |f| {
+                for variant in &handle.variants {
+                    f.writeln(&format!(
+                        "{} => Ok(Self::{}),",
+                        variant.value,
+                        variant.name.camel_case(f.naming())
+                    ))?;
+                }
+                f.writeln(&format!(
+                    "_ => Err(crate::runtime::range_error(\"not a variant of {}\")),",
+                    name
+                ))
+            })?;
+            f.writeln("}")
+        })?;
+        f.writeln("}")?;
+        f.newline()?;
+        f.writeln("pub(crate) fn from_js(value: &JsValue) -> Result<Self, JsValue> {")?;
+        indented(f, |f| {
+            f.writeln("Self::from_native(crate::runtime::integer(value)?)")
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
+
+fn write_exceptions(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    for error in lib.error_types() {
+        let name = error.exception_name.camel_case(f.naming());
+        f.newline()?;
+        f.writeln(&format!(
+            "/// Create a {} from the value of {}",
+            name,
+            error.inner.name.camel_case(f.naming())
+        ))?;
+        f.writeln(&format!(
+            "pub(crate) fn {}(error: std::os::raw::c_int) -> JsValue {{",
+            error.exception_name
+        ))?;
+        indented(f, |f| {
+            f.writeln("let message = match error {")?;
+            indented(f, |f| {
+                for variant in &error.inner.variants {
+                    f.writeln(&format!(
+                        "{} => \"{}\",",
+                        variant.value,
+                        variant.name.camel_case(f.naming())
+                    ))?;
+                }
+                f.writeln("_ => \"unknown error\",")
+            })?;
+            f.writeln("};")?;
+            f.writeln(&format!(
+                "crate::runtime::exception(\"{}\", message, error)",
+                name
+            ))
+        })?;
+        f.writeln("}")?;
+    }
+
+    if lib.error_types().next().is_none() {
+        return Ok(());
+    }
+
+    f.newline()?;
+    typescript_section(f, |f| {
+        for error in lib.error_types() {
+            f.newline()?;
+            f.writeln(&format!(
+                "/** Thrown when a native function fails with a {{@link {}}} */",
+                error.inner.name.camel_case(f.naming())
+            ))?;
+            f.writeln(&format!(
+                "export interface {} extends Error {{",
+                error.exception_name.camel_case(f.naming())
+            ))?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "readonly error: {};",
+                    error.inner.name.camel_case(f.naming())
+                ))
+            })?;
+            f.writeln("}")?;
+        }
+        Ok(())
+    })
+}
+
+fn write_collection_conversions(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+) -> FormattingResult<()> {
+    for col in lib.collections() {
+        let class = &col.collection_class;
+
+        f.newline()?;
+        f.writeln(&format!(
+            "/// Build a {} from an array, it is destroyed with the scope",
+            class.name.camel_case(f.naming())
+        ))?;
+        f.writeln(&format!("pub(crate) fn {}_from_js(value: &JsValue, scope: &mut crate::runtime::Scope) -> Result<{}, JsValue> {{", class.name, class.get_rust_type(config.ffi_name, f.naming())))?;
+        indented(f, |f| {
+            f.writeln("let array = crate::runtime::array(value)?;")?;
+            let size = if col.has_reserve {
+                "array.length()"
+            } else {
+                ""
+            };
+            f.writeln(&format!(
+                "let col = unsafe {{ {}({}) }};",
+                config.native_function(lib, &col.create_func.name),
+                size
+            ))?;
+            f.writeln(&format!(
+                "scope.on_release(move || unsafe {{ {}(col) }});",
+                config.native_function(lib, &col.delete_func.name)
+            ))?;
+            f.writeln("for item in array.iter() {")?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "let item = {};",
+                    col.item_type.convert_from_js("&item", f.naming())
+                ))?;
+                f.writeln(&format!(
+                    "unsafe {{ {}(col, item) }};",
+                    config.native_function(lib, &col.add_func.name)
+                ))
+            })?;
+            f.writeln("}")?;
+            f.writeln("Ok(col)")
+        })?;
+        f.writeln("}")?;
+    }
+    Ok(())
+}
+
+fn write_iterator_conversions(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+) -> FormattingResult<()> {
+    for iter in lib.iterators() {
+        let class = &iter.iter_class;
+
+        f.newline()?;
+        f.writeln(&format!(
+            "/// Consume a {} into an array",
+            class.name.camel_case(f.naming())
+        ))?;
+        f.writeln(&format!(
+            "pub(crate) fn {}_to_js(iter: {}) -> JsValue {{",
+            class.name,
+            class.get_rust_type(config.ffi_name, f.naming())
+        ))?;
+        indented(f, |f| {
+            f.writeln("let array = js_sys::Array::new();")?;
+            f.writeln(&format!(
+                "while let Some(next) = unsafe {{ {}(iter).as_ref() }} {{",
+                config.native_function(lib, &iter.next_function.name)
+            ))?;
+            indented(f, |f| {
+                let item = match &iter.item_type {
+                    IteratorItemType::Primitive(x) => x.convert_to_js("*next", f.naming()),
+                    IteratorItemType::Struct(x) => {
+                        format!("crate::structs::{}_to_js(next)", x.name())
+                    }
+                };
+                f.writeln(&format!("array.push(&{});", item))
+            })?;
+            f.writeln("}")?;
+            f.writeln("array.into()")
+        })?;
+        f.writeln("}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,236 @@
+use crate::backend::node::conversion::TypeScriptType;
+use crate::backend::node::doc::jsdoc_print;
+use crate::backend::node::javascript::default_value_expression;
+use crate::backend::node::typescript::write_struct_declaration;
+
+use super::*;
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &WasmBindgenConfig,
+) -> FormattingResult<()> {
+    for st in lib.structs() {
+        match st {
+            StructType::FunctionArg(x) => {
+                write_from_js(f, config, x)?;
+                write_initializers(f, x)?;
+            }
+            StructType::FunctionReturn(x) => {
+                write_to_js(f, config, x)?;
+                write_initializers(f, x)?;
+            }
+            StructType::CallbackArg(x) => {
+                write_to_js(f, config, x)?;
+                write_initializers(f, x)?;
+            }
+            StructType::Universal(x) => {
+                write_from_js(f, config, x)?;
+                write_to_js(f, config, x)?;
+                write_initializers(f, x)?;
+                write_native_initializers(f, config, x)?;
+            }
+        }
+    }
+
+    f.newline()?;
+    typescript_section(f, |f| {
+        for st in lib.structs() {
+            f.newline()?;
+            match st {
+                StructType::FunctionArg(x) => write_struct_declaration(f, x)?,
+                StructType::FunctionReturn(x) => write_struct_declaration(f, x)?,
+                StructType::CallbackArg(x) => write_struct_declaration(f, x)?,
+                StructType::Universal(x) => write_struct_declaration(f, x)?,
+            }
+        }
+        Ok(())
+    })
+}
+
+fn struct_type<T>(config: &WasmBindgenConfig, st: &Struct<T, Validated>) -> String
+where
+    T: StructFieldType,
+{
+    format!("{}::ffi::{}", config.ffi_name, st.name().ffi_camel_case())
+}
+
+fn write_from_js<T>(
+    f: &mut dyn Printer,
+    config: &WasmBindgenConfig,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertFromJs,
+{
+    let struct_type = struct_type(config, st);
+
+    f.newline()?;
+    f.writeln(&format!("pub(crate) fn {}_from_js(value: &JsValue, scope: &mut crate::runtime::Scope) -> Result<{}, JsValue> {{", st.name(), struct_type))?;
+    indented(f, |f| {
+        f.writeln(&format!("Ok({} {{", struct_type))?;
+        indented(f, |f| {
+            for field in st.fields() {
+                let value = format!(
+                    "&crate::runtime::get(value, \"{}\")?",
+                    field.name.mixed_case(f.naming())
+                );
+                f.writeln(&format!(
+                    "{}: {},",
+                    field.name,
+                    field.field_type.convert_from_js(&value, f.naming())
+                ))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("})")
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+    f.writeln("/// Struct passed by reference, `null` and `undefined` are a null pointer")?;
+    f.writeln(&format!("pub(crate) fn {}_ref_from_js(value: &JsValue, scope: &mut crate::runtime::Scope) -> Result<*const {}, JsValue> {{", st.name(), struct_type))?;
+    indented(f, |f| {
+        f.writeln("if value.is_null() || value.is_undefined() {")?;
+        indented(f, |f| f.writeln("return Ok(std::ptr::null());"))?;
+        f.writeln("}")?;
+        f.writeln(&format!(
+            "let value = {}_from_js(value, scope)?;",
+            st.name()
+        ))?;
+        f.writeln("Ok(scope.alloc(value) as *const _)")
+    })?;
+    f.writeln("}")
+}
+
+fn write_to_js<T>(
+    f: &mut dyn Printer,
+    config: &WasmBindgenConfig,
+    st: &Struct<T, Validated>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + ConvertToJs,
+{
+    f.newline()?;
+    f.writeln(&format!(
+        "pub(crate) fn {}_to_js(value: &{}) -> JsValue {{",
+        st.name(),
+        struct_type(config, st)
+    ))?;
+    indented(f, |f| {
+        f.writeln("let object = js_sys::Object::new();")?;
+        for field in st.fields() {
+            f.writeln(&format!(
+                "crate::runtime::set(&object, \"{}\", {});",
+                field.name.mixed_case(f.naming()),
+                field
+                    .field_type
+                    .convert_to_js(&format!("value.{}", field.name), f.naming())
+            ))?;
+        }
+        f.writeln("object.into()")
+    })?;
+    f.writeln("}")
+}
+
+/// Exported functions that build the plain object of a struct
+fn write_initializers<T>(f: &mut dyn Printer, st: &Struct<T, Validated>) -> FormattingResult<()>
+where
+    T: StructFieldType + TypeScriptType,
+{
+    for initializer in &st.initializers {
+        f.newline()?;
+        documentation(f, |f| {
+            jsdoc_print(f, &initializer.doc)?;
+            if !initializer.values.is_empty() {
+                f.newline()?;
+                f.writeln("Values are initialized to:")?;
+                for value in initializer.values.iter() {
+                    f.writeln(&format!(
+                        "- {}: `{}`",
+                        value.name.mixed_case(f.naming()),
+                        default_value_expression(&value.value, f.naming())
+                    ))?;
+                }
+            }
+            Ok(())
+        })?;
+        f.writeln(&format!(
+            "#[wasm_bindgen(js_name = {}{}, unchecked_return_type = {:?})]",
+            st.name().mixed_case(f.naming()),
+            initializer.name.camel_case(f.naming()),
+            st.name().camel_case(f.naming())
+        ))?;
+        let args = st
+            .initializer_args(initializer.clone())
+            .map(|field| {
+                format!(
+                    "#[wasm_bindgen(unchecked_param_type = {:?})] {}: JsValue",
+                    field.field_type.ts_type(f.naming()),
+                    field.name
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        f.writeln(&format!(
+            "pub fn {}({}) -> JsValue {{",
+            initializer_name(st.name(), &initializer.name),
+            args
+        ))?;
+        indented(f, |f| {
+            f.writeln("let object = js_sys::Object::new();")?;
+            for field in st.fields() {
+                let value = match initializer.values.iter().find(|x| x.name == field.name) {
+                    Some(x) => default_value_to_js(&x.value, f.naming()),
+                    None => field.name.to_string(),
+                };
+                f.writeln(&format!(
+                    "crate::runtime::set(&object, \"{}\", {});",
+                    field.name.mixed_case(f.naming()),
+                    value
+                ))?;
+            }
+            f.writeln("object.into()")
+        })?;
+        f.writeln("}")?;
+    }
+    Ok(())
+}
+
+/// Native values of the initializers without parameters, returned by the default implementation of callbacks
+fn write_native_initializers(
+    f: &mut dyn Printer,
+    config: &WasmBindgenConfig,
+    st: &Struct<UniversalStructField, Validated>,
+) -> FormattingResult<()> {
+    let struct_type = struct_type(config, st);
+
+    for initializer in &st.initializers {
+        if st.initializer_args(initializer.clone()).next().is_some() {
+            continue;
+        }
+
+        f.newline()?;
+        f.writeln(&format!(
+            "pub(crate) fn {}_native() -> {} {{",
+            initializer_name(st.name(), &initializer.name),
+            struct_type
+        ))?;
+        indented(f, |f| {
+            f.writeln(&format!("{} {{", struct_type))?;
+            indented(f, |f| {
+                for value in initializer.values.iter() {
+                    f.writeln(&format!(
+                        "{}: {},",
+                        value.name,
+                        default_value_to_native(&value.value, f.naming())
+                    ))?;
+                }
+                Ok(())
+            })?;
+            f.writeln("}")
+        })?;
+        f.writeln("}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use tracing::Level;
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(Level::INFO)
+        .with_target(false)
+        .init();
+
+    oo_bindgen::cli::run_schema();
+}
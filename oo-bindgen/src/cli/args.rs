@@ -1,21 +1,37 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use platforms::Platform;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
 impl Args {
     pub(crate) fn get() -> Self {
-        let mut args = crate::cli::Args::parse();
-        if !(args.build_c || args.build_dotnet || args.build_java) {
-            args.build_c = true;
-            args.build_dotnet = true;
-            args.build_java = true;
+        crate::cli::Args::parse().resolve()
+    }
+
+    /// Apply the implicit language selections
+    pub(crate) fn resolve(mut self) -> Self {
+        if self.build_kotlin {
+            // the Kotlin layer is compiled as part of the Java package
+            self.build_java = true;
+        }
+        if !(self.build_c
+            || self.build_dotnet
+            || self.build_java
+            || self.build_swift
+            || self.build_go
+            || self.build_node)
+        {
+            self.build_c = true;
+            self.build_dotnet = true;
+            self.build_java = true;
         }
-        args
+        self
     }
 }
 
 use crate::backend::dotnet::TargetFramework;
+use crate::backend::java::api::JavaInterop;
+use crate::model::Severity;
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -23,6 +39,12 @@ pub(crate) struct EnabledLanguages {
     pub(crate) cpp: bool,
     pub(crate) dotnet: bool,
     pub(crate) java: bool,
+    #[serde(default)]
+    pub(crate) swift: bool,
+    #[serde(default)]
+    pub(crate) go: bool,
+    #[serde(default)]
+    pub(crate) node: bool,
 }
 
 #[derive(Deserialize)]
@@ -52,6 +74,27 @@ impl PackageOptions {
             .map(|x| x.java)
             .unwrap_or(false)
     }
+
+    pub(crate) fn package_swift(&self, platform: &Platform) -> bool {
+        self.targets
+            .get(platform.target_triple)
+            .map(|x| x.swift)
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn package_go(&self, platform: &Platform) -> bool {
+        self.targets
+            .get(platform.target_triple)
+            .map(|x| x.go)
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn package_node(&self, platform: &Platform) -> bool {
+        self.targets
+            .get(platform.target_triple)
+            .map(|x| x.node)
+            .unwrap_or(false)
+    }
 }
 
 #[derive(Parser)]
@@ -66,6 +109,18 @@ pub(crate) struct Args {
     /// build the Java bindings
     #[arg(long = "java", default_value_t = false)]
     pub(crate) build_java: bool,
+    /// build the Kotlin extensions along with the Java bindings
+    #[arg(long = "kotlin", default_value_t = false)]
+    pub(crate) build_kotlin: bool,
+    /// build the Swift package
+    #[arg(long = "swift", default_value_t = false)]
+    pub(crate) build_swift: bool,
+    /// build the Go module
+    #[arg(long = "go", default_value_t = false)]
+    pub(crate) build_go: bool,
+    /// build the Node.js addon
+    #[arg(long = "node", default_value_t = false)]
+    pub(crate) build_node: bool,
     /// Path to where the compiled FFI/JNI shared libraries reside or a directory with multiple target triple dirs if packaging.
     /// If not specified, ./release/target is assumed
     #[arg(long = "artifact-dir", short = 'a')]
@@ -76,9 +131,15 @@ pub(crate) struct Args {
     /// Target .NET framework, which indirectly determines the C# language version
     #[arg(value_enum, short = 't', long = "target-dotnet-framework", default_value_t = TargetFramework::NetStandard2_0)]
     pub(crate) target_framework: TargetFramework,
+    /// Mechanism used by the Java bindings to call the native library
+    #[arg(value_enum, long = "java-interop", default_value_t = JavaInterop::Jni)]
+    pub(crate) java_interop: JavaInterop,
     /// generate doxygen documentation
     #[arg(long = "doxygen", default_value_t = false)]
     pub(crate) generate_doxygen: bool,
+    /// declare the C functions of each module in its own header, included by the main one
+    #[arg(long = "c-module-headers", default_value_t = false)]
+    pub(crate) c_module_headers: bool,
     /// do NOT run the unit tests
     #[arg(long = "no-tests", default_value_t = false)]
     pub(crate) no_tests: bool,
@@ -91,4 +152,48 @@ pub(crate) struct Args {
     /// Path(s) to extra files to include in the generated bindings
     #[arg(short = 'f', long = "extra-files")]
     pub(crate) extra_files: Vec<PathBuf>,
+    /// Write the library to a TOML schema file instead of generating the bindings
+    #[arg(long = "export-schema")]
+    pub(crate) export_schema: Option<PathBuf>,
+    /// Write the conformance tests of the C, C++, Java and .NET bindings to this directory instead of generating the bindings
+    #[arg(long = "conformance")]
+    pub(crate) conformance: Option<PathBuf>,
+    /// Write the declarations of the control API of the mock implementation for the C, Java and .NET bindings to this directory instead of generating the bindings
+    #[arg(long = "mock-api")]
+    pub(crate) mock_api: Option<PathBuf>,
+    /// Write the code examples of the documentation as C, C++, Java and .NET sources to this directory instead of generating the bindings
+    #[arg(long = "extract-examples")]
+    pub(crate) extract_examples: Option<PathBuf>,
+    /// Write an HTML API reference of the C, C++, Java and .NET bindings to this directory instead of generating the bindings
+    #[arg(long = "doc-site")]
+    pub(crate) doc_site: Option<PathBuf>,
+    /// Report the documentation lints of the library instead of generating the bindings
+    #[arg(long = "lint")]
+    pub(crate) lint: bool,
+    /// Least severe lint that makes the lint mode fail
+    #[arg(value_enum, long = "lint-fail-on", default_value_t = Severity::Error)]
+    pub(crate) lint_fail_on: Severity,
+    /// Also write the lints as JSON to this file
+    #[arg(long = "lint-report")]
+    pub(crate) lint_report: Option<PathBuf>,
+}
+
+/// Arguments of the standalone `oo-bindgen` binary
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+pub(crate) struct StandaloneArgs {
+    #[command(subcommand)]
+    pub(crate) command: Command,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Generate the bindings of a library described by a schema file
+    Generate {
+        /// Path to the JSON or TOML schema
+        #[arg(long = "schema", short = 's')]
+        schema: PathBuf,
+        #[command(flatten)]
+        args: Args,
+    },
 }
@@ -3,12 +3,14 @@ use crate::backend::PlatformLocations;
 use std::path::PathBuf;
 use std::process::Command;
 
-use crate::cli::{BindingBuilder, BindingBuilderSettings};
+use crate::cli::builders::{recreate_dir, run_command};
+use crate::cli::{BindingBuilder, BindingBuilderSettings, CliResult};
 
 pub(crate) struct CBindingBuilder {
     settings: BindingBuilderSettings,
     platforms: PlatformLocations,
     extra_files: Vec<PathBuf>,
+    module_headers: bool,
 }
 
 impl CBindingBuilder {
@@ -16,11 +18,13 @@ impl CBindingBuilder {
         settings: BindingBuilderSettings,
         platforms: PlatformLocations,
         extra_files: &[PathBuf],
+        module_headers: bool,
     ) -> Self {
         Self {
             settings,
             platforms,
             extra_files: extra_files.to_vec(),
+            module_headers,
         }
     }
 
@@ -38,55 +42,47 @@ impl BindingBuilder for CBindingBuilder {
         "c"
     }
 
-    fn generate(&mut self, _is_packaging: bool, generate_doxygen: bool) {
+    fn generate(&mut self, _is_packaging: bool, generate_doxygen: bool) -> CliResult<()> {
         let config = crate::backend::c::CBindgenConfig {
             output_dir: self.output_dir(),
             ffi_name: self.settings.ffi_name,
             extra_files: self.extra_files.clone(),
             platform_locations: self.platforms.clone(),
             generate_doxygen,
+            module_headers: self.module_headers,
         };
 
         crate::backend::c::generate_c_package(&self.settings.library, &config)
-            .expect("failed to package C lib");
+            .map_err(|err| format!("Unable to generate the C package: {}", err).into())
     }
 
-    fn build(&mut self) {
+    fn build(&mut self) -> CliResult<()> {
         // Clear/create build directory
         let build_dir = self.build_dir();
-        if build_dir.exists() {
-            std::fs::remove_dir_all(&build_dir).unwrap();
-        }
-        std::fs::create_dir_all(&build_dir).unwrap();
+        recreate_dir(&build_dir)?;
 
         // CMake configure
-        let result = Command::new("cmake")
-            .current_dir(&build_dir)
-            .arg("..")
-            .status()
-            .expect("cmake failed");
-        assert!(result.success());
+        run_command(Command::new("cmake").current_dir(&build_dir).arg(".."))?;
 
         // CMake build
-        let result = Command::new("cmake")
-            .current_dir(&build_dir)
-            .args(&["--build", ".", "--config", "Debug"])
-            .status()
-            .expect("cmake failed");
-        assert!(result.success());
+        run_command(
+            Command::new("cmake")
+                .current_dir(&build_dir)
+                .args(&["--build", ".", "--config", "Debug"]),
+        )
     }
 
-    fn test(&mut self) {
+    fn test(&mut self) -> CliResult<()> {
         // Run unit tests
-        let result = Command::new("ctest")
-            .current_dir(&self.build_dir())
-            .args(&[".", "-C", "Debug"])
-            .status()
-            .unwrap();
-        assert!(result.success());
+        run_command(
+            Command::new("ctest")
+                .current_dir(&self.build_dir())
+                .args(&[".", "-C", "Debug"]),
+        )
     }
 
-    fn package(&mut self) {
+    fn package(&mut self) -> CliResult<()> {
         // Already done in generate
+        Ok(())
     }
 }
@@ -1,7 +1,8 @@
 use crate::backend::dotnet::TargetFramework;
-use crate::backend::{logged, PlatformLocations};
+use crate::backend::PlatformLocations;
 
-use crate::cli::{BindingBuilder, BindingBuilderSettings};
+use crate::cli::builders::{recreate_dir, run_command};
+use crate::cli::{BindingBuilder, BindingBuilderSettings, CliResult};
 
 use std::path::PathBuf;
 use std::process::Command;
@@ -37,6 +38,21 @@ impl DotnetBindingBuilder {
         output_dir.push(self.settings.library.settings.name.to_string());
         output_dir
     }
+
+    /// Project to build, test and pack instead of the solution of the output directory
+    ///
+    /// The test project of the solution cannot reference the .NET 8 bindings, so they are tested
+    /// by `<name>.Tests.Net8` and packed from their own project
+    fn project(&self, tests: bool) -> Option<String> {
+        match self.target_framework {
+            TargetFramework::Net8_0 if tests => Some(format!(
+                "{}.Tests.Net8",
+                self.settings.library.settings.name
+            )),
+            TargetFramework::Net8_0 => Some(self.settings.library.settings.name.to_string()),
+            _ => None,
+        }
+    }
 }
 
 impl BindingBuilder for DotnetBindingBuilder {
@@ -44,13 +60,10 @@ impl BindingBuilder for DotnetBindingBuilder {
         "dotnet"
     }
 
-    fn generate(&mut self, _is_packaging: bool, generate_doxygen: bool) {
+    fn generate(&mut self, _is_packaging: bool, generate_doxygen: bool) -> CliResult<()> {
         // Clear/create generated files
         let build_dir = self.build_dir();
-        if build_dir.exists() {
-            logged::remove_dir_all(&build_dir).unwrap();
-        }
-        logged::create_dir_all(&build_dir).unwrap();
+        recreate_dir(&build_dir)?;
 
         let config = crate::backend::dotnet::DotnetBindgenConfig {
             output_dir: build_dir,
@@ -61,44 +74,45 @@ impl BindingBuilder for DotnetBindingBuilder {
             target_framework: self.target_framework,
         };
 
-        crate::backend::dotnet::generate_dotnet_bindings(&self.settings.library, &config).unwrap();
+        crate::backend::dotnet::generate_dotnet_bindings(&self.settings.library, &config)
+            .map_err(|err| format!("Unable to generate the .NET bindings: {}", err).into())
     }
 
-    fn build(&mut self) {
-        let result = Command::new("dotnet")
-            .current_dir(&self.output_dir())
-            .arg("build")
-            .arg("--configuration")
-            .arg("Release")
-            .status()
-            .unwrap();
-        assert!(result.success());
+    fn build(&mut self) -> CliResult<()> {
+        run_command(
+            Command::new("dotnet")
+                .current_dir(&self.output_dir())
+                .arg("build")
+                .args(self.project(true))
+                .arg("--configuration")
+                .arg("Release"),
+        )
     }
 
-    fn test(&mut self) {
+    fn test(&mut self) -> CliResult<()> {
         // Run unit tests
-        let result = Command::new("dotnet")
-            .current_dir(&self.output_dir())
-            .arg("test")
-            .arg("--configuration")
-            .arg("Release")
-            .status()
-            .unwrap();
-        assert!(result.success());
+        run_command(
+            Command::new("dotnet")
+                .current_dir(&self.output_dir())
+                .arg("test")
+                .args(self.project(true))
+                .arg("--configuration")
+                .arg("Release"),
+        )
     }
 
-    fn package(&mut self) {
+    fn package(&mut self) -> CliResult<()> {
         // Produce a nupkg
-        let result = Command::new("dotnet")
-            .current_dir(&self.output_dir())
-            .arg("pack")
-            .arg("--configuration")
-            .arg("Release")
-            .arg("--include-symbols")
-            .arg("--output")
-            .arg("nupkg")
-            .status()
-            .unwrap();
-        assert!(result.success());
+        run_command(
+            Command::new("dotnet")
+                .current_dir(&self.output_dir())
+                .arg("pack")
+                .args(self.project(false))
+                .arg("--configuration")
+                .arg("Release")
+                .arg("--include-symbols")
+                .arg("--output")
+                .arg("nupkg"),
+        )
     }
 }
@@ -0,0 +1,76 @@
+use crate::backend::PlatformLocations;
+use crate::cli::builders::{remove_dir, run_command};
+use crate::cli::{BindingBuilder, BindingBuilderSettings, CliResult};
+
+use std::path::PathBuf;
+use std::process::Command;
+
+pub(crate) struct GoBindingBuilder {
+    settings: BindingBuilderSettings,
+    platforms: PlatformLocations,
+    extra_files: Vec<PathBuf>,
+}
+
+impl GoBindingBuilder {
+    pub(crate) fn new(
+        settings: BindingBuilderSettings,
+        platforms: PlatformLocations,
+        extra_files: &[PathBuf],
+    ) -> Self {
+        Self {
+            settings,
+            platforms,
+            extra_files: extra_files.to_vec(),
+        }
+    }
+
+    fn output_dir(&self) -> PathBuf {
+        self.settings.destination_path.join("go/generated")
+    }
+
+    fn go(&self, dir: PathBuf) -> Command {
+        let mut command = Command::new("go");
+        command.current_dir(dir);
+        command
+    }
+}
+
+impl BindingBuilder for GoBindingBuilder {
+    fn name() -> &'static str {
+        "go"
+    }
+
+    fn generate(&mut self, _is_packaging: bool, _generate_doxygen: bool) -> CliResult<()> {
+        let config = crate::backend::go::GoBindgenConfig {
+            output_dir: self.output_dir(),
+            ffi_name: self.settings.ffi_name,
+            extra_files: self.extra_files.clone(),
+            platform_locations: self.platforms.clone(),
+        };
+
+        // Clear/create the generated module
+        remove_dir(&self.output_dir())?;
+
+        crate::backend::go::generate_go_package(&self.settings.library, &config)
+            .map_err(|err| format!("Unable to generate the Go module: {}", err).into())
+    }
+
+    fn build(&mut self) -> CliResult<()> {
+        // the generated code is formatted by the Go toolchain instead of by hand
+        run_command(self.go(self.output_dir()).args(["fmt", "./..."]))?;
+
+        run_command(self.go(self.output_dir()).args(["build", "./..."]))
+    }
+
+    fn test(&mut self) -> CliResult<()> {
+        run_command(
+            self.go(self.settings.destination_path.join("go"))
+                .args(["test", "./..."]),
+        )
+    }
+
+    fn package(&mut self) -> CliResult<()> {
+        // The generated directory is the module
+        Ok(())
+    }
+}
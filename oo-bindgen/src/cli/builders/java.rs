@@ -1,11 +1,15 @@
-use crate::backend::{logged, PlatformLocations};
-use crate::cli::{BindingBuilder, BindingBuilderSettings};
+use crate::backend::java::api::JavaInterop;
+use crate::backend::PlatformLocations;
+use crate::cli::builders::{recreate_dir, run_command};
+use crate::cli::{BindingBuilder, BindingBuilderSettings, CliResult};
 
 use std::path::PathBuf;
 use std::process::Command;
 
 pub(crate) struct JavaBindingBuilder {
     settings: BindingBuilderSettings,
+    interop: JavaInterop,
+    kotlin: bool,
     platforms: PlatformLocations,
     extra_files: Vec<PathBuf>,
 }
@@ -13,11 +17,15 @@ pub(crate) struct JavaBindingBuilder {
 impl JavaBindingBuilder {
     pub(crate) fn new(
         settings: BindingBuilderSettings,
+        interop: JavaInterop,
+        kotlin: bool,
         platforms: PlatformLocations,
         extra_files: &[PathBuf],
     ) -> Self {
         Self {
             settings,
+            interop,
+            kotlin,
             platforms,
             extra_files: extra_files.to_vec(),
         }
@@ -44,6 +52,12 @@ impl JavaBindingBuilder {
 
         command.current_dir(self.output_dir());
         command.arg("-B"); // No progress on CI
+        if self.interop == JavaInterop::Panama {
+            command.arg("-Ppanama"); // Tests compiled for and run on JDK 22
+        }
+        if self.kotlin {
+            command.arg("-Pkotlin"); // Tests of the Kotlin extensions
+        }
 
         command
     }
@@ -54,43 +68,35 @@ impl BindingBuilder for JavaBindingBuilder {
         "java"
     }
 
-    fn generate(&mut self, _is_packaging: bool, _generate_doxygen: bool) {
+    fn generate(&mut self, _is_packaging: bool, _generate_doxygen: bool) -> CliResult<()> {
         let config = crate::backend::java::api::JavaBindgenConfig {
             java_output_dir: self.java_build_dir(),
             ffi_name: self.settings.ffi_name,
             group_id: self.settings.java_group_id.to_owned(),
             extra_files: self.extra_files.clone(),
             platforms: self.platforms.clone(),
+            interop: self.interop,
+            kotlin: self.kotlin,
         };
 
         // Clear/create Java generated files
         let build_dir = self.java_build_dir();
-        if build_dir.exists() {
-            logged::remove_dir_all(&build_dir).unwrap();
-        }
-        logged::create_dir_all(&build_dir).unwrap();
+        recreate_dir(&build_dir)?;
 
         // Generate the Java code
-        crate::backend::java::api::generate_java_bindings(&self.settings.library, &config).unwrap();
+        crate::backend::java::api::generate_java_bindings(&self.settings.library, &config)
+            .map_err(|err| format!("Unable to generate the Java bindings: {}", err).into())
     }
 
-    fn build(&mut self) {
-        let result = self.maven().arg("compile").status().unwrap();
-        assert!(result.success());
+    fn build(&mut self) -> CliResult<()> {
+        run_command(self.maven().arg("compile"))
     }
 
-    fn test(&mut self) {
-        let result = self.maven().arg("verify").status().unwrap();
-        assert!(result.success());
+    fn test(&mut self) -> CliResult<()> {
+        run_command(self.maven().arg("verify"))
     }
 
-    fn package(&mut self) {
-        let result = self
-            .maven()
-            .arg("package")
-            .arg("-DskipTests")
-            .status()
-            .unwrap();
-        assert!(result.success());
+    fn package(&mut self) -> CliResult<()> {
+        run_command(self.maven().arg("package").arg("-DskipTests"))
     }
 }
@@ -1,3 +1,42 @@
 pub(crate) mod c;
 pub(crate) mod dotnet;
+pub(crate) mod go;
 pub(crate) mod java;
+pub(crate) mod node;
+pub(crate) mod swift;
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::backend::logged;
+use crate::cli::CliResult;
+
+/// Run a build tool, failing if it cannot be started or does not succeed
+pub(crate) fn run_command(command: &mut Command) -> CliResult<()> {
+    let program = command.get_program().to_string_lossy().to_string();
+    let status = command
+        .status()
+        .map_err(|err| format!("Unable to run {}: {}", program, err))?;
+    if !status.success() {
+        let args: Vec<_> = command
+            .get_args()
+            .map(|x| x.to_string_lossy().to_string())
+            .collect();
+        return Err(format!("{} {} failed with {}", program, args.join(" "), status).into());
+    }
+    Ok(())
+}
+
+/// Remove a generated directory if it exists
+pub(crate) fn remove_dir(dir: &Path) -> CliResult<()> {
+    if dir.exists() {
+        logged::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+/// Remove a generated directory if it exists and create it empty
+pub(crate) fn recreate_dir(dir: &Path) -> CliResult<()> {
+    remove_dir(dir)?;
+    Ok(logged::create_dir_all(dir)?)
+}
@@ -0,0 +1,74 @@
+use crate::backend::PlatformLocations;
+use crate::cli::builders::{remove_dir, run_command};
+use crate::cli::{BindingBuilder, BindingBuilderSettings, CliResult};
+
+use std::path::PathBuf;
+use std::process::Command;
+
+pub(crate) struct NodeBindingBuilder {
+    settings: BindingBuilderSettings,
+    platforms: PlatformLocations,
+    extra_files: Vec<PathBuf>,
+}
+
+impl NodeBindingBuilder {
+    pub(crate) fn new(
+        settings: BindingBuilderSettings,
+        platforms: PlatformLocations,
+        extra_files: &[PathBuf],
+    ) -> Self {
+        Self {
+            settings,
+            platforms,
+            extra_files: extra_files.to_vec(),
+        }
+    }
+
+    fn output_dir(&self) -> PathBuf {
+        self.settings.destination_path.join("node/generated")
+    }
+}
+
+impl BindingBuilder for NodeBindingBuilder {
+    fn name() -> &'static str {
+        "node"
+    }
+
+    fn generate(&mut self, _is_packaging: bool, _generate_doxygen: bool) -> CliResult<()> {
+        let config = crate::backend::node::NodeBindgenConfig {
+            output_dir: self.output_dir(),
+            ffi_name: self.settings.ffi_name,
+            extra_files: self.extra_files.clone(),
+            platform_locations: self.platforms.clone(),
+        };
+
+        // Clear/create the generated package
+        remove_dir(&self.output_dir())?;
+
+        crate::backend::node::generate_node_package(&self.settings.library, &config)
+            .map_err(|err| format!("Unable to generate the Node.js package: {}", err).into())
+    }
+
+    fn build(&mut self) -> CliResult<()> {
+        // npx is a script on Windows
+        let npx = if cfg!(windows) { "npx.cmd" } else { "npx" };
+        run_command(
+            Command::new(npx)
+                .current_dir(self.output_dir())
+                .args(["node-gyp", "rebuild"]),
+        )
+    }
+
+    fn test(&mut self) -> CliResult<()> {
+        run_command(
+            Command::new("node")
+                .current_dir(self.settings.destination_path.join("node"))
+                .arg("--test"),
+        )
+    }
+
+    fn package(&mut self) -> CliResult<()> {
+        // The generated directory is the package
+        Ok(())
+    }
+}
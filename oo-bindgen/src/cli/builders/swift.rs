@@ -0,0 +1,70 @@
+use crate::backend::PlatformLocations;
+use crate::cli::builders::{remove_dir, run_command};
+use crate::cli::{BindingBuilder, BindingBuilderSettings, CliResult};
+
+use std::path::PathBuf;
+use std::process::Command;
+
+pub(crate) struct SwiftBindingBuilder {
+    settings: BindingBuilderSettings,
+    platforms: PlatformLocations,
+    extra_files: Vec<PathBuf>,
+}
+
+impl SwiftBindingBuilder {
+    pub(crate) fn new(
+        settings: BindingBuilderSettings,
+        platforms: PlatformLocations,
+        extra_files: &[PathBuf],
+    ) -> Self {
+        Self {
+            settings,
+            platforms,
+            extra_files: extra_files.to_vec(),
+        }
+    }
+
+    fn output_dir(&self) -> PathBuf {
+        self.settings.destination_path.join("swift/generated")
+    }
+
+    fn swift(&self) -> Command {
+        let mut command = Command::new("swift");
+        command.current_dir(self.settings.destination_path.join("swift"));
+        command
+    }
+}
+
+impl BindingBuilder for SwiftBindingBuilder {
+    fn name() -> &'static str {
+        "swift"
+    }
+
+    fn generate(&mut self, _is_packaging: bool, _generate_doxygen: bool) -> CliResult<()> {
+        let config = crate::backend::swift::SwiftBindgenConfig {
+            output_dir: self.output_dir(),
+            ffi_name: self.settings.ffi_name,
+            extra_files: self.extra_files.clone(),
+            platform_locations: self.platforms.clone(),
+        };
+
+        // Clear/create the generated package
+        remove_dir(&self.output_dir())?;
+
+        crate::backend::swift::generate_swift_package(&self.settings.library, &config)
+            .map_err(|err| format!("Unable to generate the Swift package: {}", err).into())
+    }
+
+    fn build(&mut self) -> CliResult<()> {
+        run_command(self.swift().arg("build"))
+    }
+
+    fn test(&mut self) -> CliResult<()> {
+        run_command(self.swift().arg("test"))
+    }
+
+    fn package(&mut self) -> CliResult<()> {
+        // The generated directory is the package
+        Ok(())
+    }
+}
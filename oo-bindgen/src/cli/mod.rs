@@ -3,27 +3,122 @@ pub(crate) mod builders;
 
 use std::fs;
 use std::fs::File;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use crate::backend::*;
 use crate::model::Library;
 
-use crate::cli::args::{Args, PackageOptions};
+use crate::cli::args::{Args, Command, PackageOptions, StandaloneArgs};
+use crate::schema::{BindingsSchema, Schema};
+
+/// Error that stops the generator, e.g. a file that cannot be written or a failing build tool
+pub(crate) type CliResult<T> = Result<T, Box<dyn std::error::Error>>;
 
 /// Run the binding generator
 pub fn run(settings: BindingBuilderSettings) {
-    let args = Args::get();
+    exit_on_error(generate(settings, Args::get()));
+}
+
+/// Report an error of the generator and exit with a non-zero code
+///
+/// The errors are caused by the inputs or the tools of the generator, so there is no backtrace.
+fn exit_on_error(result: CliResult<()>) {
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        std::process::exit(-1);
+    }
+}
+
+/// Run the binding generator on a library described by a schema file
+///
+/// This is the entry point of the `oo-bindgen` binary, e.g. `oo-bindgen generate --schema api.json --c --java`
+pub fn run_schema() {
+    use clap::Parser;
+
+    let Command::Generate { schema, args } = StandaloneArgs::parse().command;
+
+    let schema = match Schema::read(&schema) {
+        Ok(x) => x,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(-1);
+        }
+    };
+    let library = match schema.build() {
+        Ok(x) => x,
+        Err(err) => {
+            eprintln!("Invalid schema: {}", err);
+            std::process::exit(-1);
+        }
+    };
+    let bindings = match schema.bindings.as_ref() {
+        Some(x) => x,
+        None => {
+            eprintln!("The schema must have a 'bindings' section to generate the bindings");
+            std::process::exit(-1);
+        }
+    };
+
+    // the settings borrow these for the lifetime of the program
+    fn leak(value: &str) -> &'static str {
+        Box::leak(value.to_string().into_boxed_str())
+    }
+
+    let settings = BindingBuilderSettings {
+        ffi_target_name: leak(&bindings.ffi_target_name),
+        jni_target_name: leak(&bindings.jni_target_name),
+        ffi_name: leak(
+            &bindings
+                .ffi_name
+                .clone()
+                .unwrap_or_else(|| bindings.ffi_target_name.replace('-', "_")),
+        ),
+        ffi_path: schema.resolve(&bindings.ffi_path),
+        java_group_id: leak(&bindings.java_group_id),
+        destination_path: schema.resolve(&bindings.destination_path),
+        library: Rc::new(library),
+    };
+
+    exit_on_error(generate(settings, args.resolve()));
+}
+
+fn generate(settings: BindingBuilderSettings, args: Args) -> CliResult<()> {
+    if let Some(path) = &args.export_schema {
+        return export_schema(path, &settings);
+    }
+    if let Some(dir) = &args.conformance {
+        return conformance(dir, &settings);
+    }
+    if let Some(dir) = &args.mock_api {
+        return mock_api(dir, &settings);
+    }
+    if let Some(dir) = &args.extract_examples {
+        return examples::extract(&settings.library, settings.java_group_id, dir).map_err(|err| {
+            format!("Unable to extract the documentation examples: {}", err).into()
+        });
+    }
+    if let Some(dir) = &args.doc_site {
+        return site::generate(&settings.library, dir)
+            .map_err(|err| format!("Unable to generate the documentation site: {}", err).into());
+    }
+    if args.lint {
+        return lint(&args, &settings);
+    }
 
     let (options, platforms) = {
         let span = tracing::info_span!("configure()");
-        span.in_scope(|| get_platforms(&args))
+        span.in_scope(|| get_platforms(&args))?
     };
 
     if args.build_c {
-        let mut builder =
-            builders::c::CBindingBuilder::new(settings.clone(), platforms.cpp, &args.extra_files);
-        builder.run(options);
+        let mut builder = builders::c::CBindingBuilder::new(
+            settings.clone(),
+            platforms.cpp,
+            &args.extra_files,
+            args.c_module_headers,
+        );
+        builder.run(options)?;
     }
     if args.build_dotnet {
         let mut builder = builders::dotnet::DotnetBindingBuilder::new(
@@ -32,19 +127,123 @@ pub fn run(settings: BindingBuilderSettings) {
             platforms.dotnet,
             &args.extra_files,
         );
-        builder.run(options);
+        builder.run(options)?;
     }
     if args.build_java {
+        let mut builder = builders::java::JavaBindingBuilder::new(
+            settings.clone(),
+            args.java_interop,
+            args.build_kotlin,
+            platforms.java,
+            &args.extra_files,
+        );
+        builder.run(options)?;
+    }
+    if args.build_swift {
+        let mut builder = builders::swift::SwiftBindingBuilder::new(
+            settings.clone(),
+            platforms.swift,
+            &args.extra_files,
+        );
+        builder.run(options)?;
+    }
+    if args.build_go {
+        let mut builder =
+            builders::go::GoBindingBuilder::new(settings.clone(), platforms.go, &args.extra_files);
+        builder.run(options)?;
+    }
+    if args.build_node {
         let mut builder =
-            builders::java::JavaBindingBuilder::new(settings, platforms.java, &args.extra_files);
-        builder.run(options);
+            builders::node::NodeBindingBuilder::new(settings, platforms.node, &args.extra_files);
+        builder.run(options)?;
     }
+    Ok(())
+}
+
+fn export_schema(path: &Path, settings: &BindingBuilderSettings) -> CliResult<()> {
+    let bindings = BindingsSchema {
+        ffi_target_name: settings.ffi_target_name.to_string(),
+        jni_target_name: settings.jni_target_name.to_string(),
+        ffi_name: Some(settings.ffi_name.to_string())
+            .filter(|x| *x != settings.ffi_target_name.replace('-', "_")),
+        ffi_path: settings.ffi_path.clone(),
+        java_group_id: settings.java_group_id.to_string(),
+        destination_path: settings.destination_path.clone(),
+    };
+
+    crate::schema::export(path, &settings.library, Some(&bindings))
+        .map_err(|err| format!("Unable to export the schema: {}", err).into())
+}
+
+fn lint(args: &Args, settings: &BindingBuilderSettings) -> CliResult<()> {
+    let report = crate::model::lint(&settings.library);
+    print!("{}", report);
+
+    if let Some(path) = &args.lint_report {
+        let json = serde_json::to_string_pretty(&report).expect("Unable to serialize the lints");
+        fs::write(path, json).map_err(|err| format!("Unable to write the lint report: {}", err))?;
+    }
+
+    if report.fails(args.lint_fail_on) {
+        return Err(format!(
+            "The documentation has lints with a severity of {} or more",
+            args.lint_fail_on
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn conformance(dir: &Path, settings: &BindingBuilderSettings) -> CliResult<()> {
+    let lib = &settings.library;
+    let suite = crate::conformance::Suite::find(lib)
+        .ok_or("The library does not define the conformance functions")?;
+
+    let result = c::generate_conformance_tests(lib, &suite, dir)
+        .and_then(|_| {
+            let java_dir = dir.join("java");
+            logged::create_dir_all(&java_dir)?;
+            java::api::conformance::generate(
+                lib,
+                &suite,
+                settings.java_group_id,
+                &java_dir.join("ConformanceTest.java"),
+            )
+        })
+        .and_then(|_| {
+            let dotnet_dir = dir.join("dotnet");
+            logged::create_dir_all(&dotnet_dir)?;
+            dotnet::conformance::generate(lib, &suite, &dotnet_dir.join("ConformanceTest.cs"))
+        });
+
+    result.map_err(|err| format!("Unable to write the conformance tests: {}", err).into())
+}
+
+fn mock_api(dir: &Path, settings: &BindingBuilderSettings) -> CliResult<()> {
+    let lib = &settings.library;
+
+    let result = c::generate_mock_header(lib, &dir.join("c"))
+        .and_then(|_| {
+            let java_dir = dir.join("java");
+            logged::create_dir_all(&java_dir)?;
+            java::api::mock::generate(lib, settings.java_group_id, &java_dir.join("Mock.java"))
+        })
+        .and_then(|_| {
+            let dotnet_dir = dir.join("dotnet");
+            logged::create_dir_all(&dotnet_dir)?;
+            dotnet::mock::generate(lib, settings.ffi_name, &dotnet_dir.join("Mock.cs"))
+        });
+
+    result.map_err(|err| format!("Unable to write the mock control API: {}", err).into())
 }
 
 struct LanguagePlatforms {
     cpp: PlatformLocations,
     dotnet: PlatformLocations,
     java: PlatformLocations,
+    swift: PlatformLocations,
+    go: PlatformLocations,
+    node: PlatformLocations,
 }
 
 impl LanguagePlatforms {
@@ -52,12 +251,15 @@ impl LanguagePlatforms {
         Self {
             cpp: locations.clone(),
             dotnet: locations.clone(),
-            java: locations,
+            java: locations.clone(),
+            swift: locations.clone(),
+            go: locations.clone(),
+            node: locations,
         }
     }
 }
 
-fn get_single_platform(args: &Args) -> (RunOptions, LanguagePlatforms) {
+fn get_single_platform(args: &Args) -> CliResult<(RunOptions, LanguagePlatforms)> {
     let artifact_dir = match &args.artifact_dir {
         Some(x) => {
             tracing::info!("Artifact dir is {}", x.display());
@@ -72,7 +274,8 @@ fn get_single_platform(args: &Args) -> (RunOptions, LanguagePlatforms) {
 
     let platform = match &args.target_triple {
         None => {
-            let platform = Platform::guess_current().expect("Could not determine current platform");
+            let platform =
+                Platform::guess_current().ok_or("Could not determine current platform")?;
             tracing::info!(
                 "No target platform specified assuming target is the host platform: {}",
                 platform
@@ -80,7 +283,11 @@ fn get_single_platform(args: &Args) -> (RunOptions, LanguagePlatforms) {
             platform
         }
         Some(tt) => match Platform::find(tt) {
-            None => panic!("Unable to determine Platform from target triple: {}", tt),
+            None => {
+                return Err(
+                    format!("Unable to determine Platform from target triple: {}", tt).into(),
+                )
+            }
             Some(x) => x,
         },
     };
@@ -93,16 +300,17 @@ fn get_single_platform(args: &Args) -> (RunOptions, LanguagePlatforms) {
         package: false,
         docs: args.generate_doxygen,
     };
-    (options, LanguagePlatforms::same(platforms))
+    Ok((options, LanguagePlatforms::same(platforms)))
 }
 
 fn get_packaging_platforms(
     dir: &PathBuf,
     options: PackageOptions,
-) -> (RunOptions, LanguagePlatforms) {
+) -> CliResult<(RunOptions, LanguagePlatforms)> {
+    let error = |err: std::io::Error| format!("Unable to read {}: {}", dir.display(), err);
     let mut platforms = PlatformLocations::new();
-    for entry in fs::read_dir(dir).unwrap() {
-        let entry = entry.unwrap();
+    for entry in fs::read_dir(dir).map_err(error)? {
+        let entry = entry.map_err(error)?;
         let path = entry.path();
         if path.is_dir() {
             if let Some(p) = Platform::find(&entry.file_name().to_string_lossy()) {
@@ -111,7 +319,9 @@ fn get_packaging_platforms(
         }
     }
 
-    assert!(!platforms.is_empty(), "No platforms found!");
+    if platforms.is_empty() {
+        return Err(format!("No platforms found in {}", dir.display()).into());
+    }
 
     for p in platforms.iter() {
         tracing::info!("Platform {} in {}", p.platform, p.location.display());
@@ -153,24 +363,72 @@ fn get_packaging_platforms(
         java
     };
 
+    let swift = {
+        let mut swift = PlatformLocations::new();
+        for p in platforms.iter() {
+            if options.package_swift(&p.platform) {
+                swift.locations.push(p.clone());
+            } else {
+                tracing::warn!("Ignoring available Swift package {}", p.platform)
+            }
+        }
+        swift
+    };
+
+    let go = {
+        let mut go = PlatformLocations::new();
+        for p in platforms.iter() {
+            if options.package_go(&p.platform) {
+                go.locations.push(p.clone());
+            } else {
+                tracing::warn!("Ignoring available Go package {}", p.platform)
+            }
+        }
+        go
+    };
+
+    let node = {
+        let mut node = PlatformLocations::new();
+        for p in platforms.iter() {
+            if options.package_node(&p.platform) {
+                node.locations.push(p.clone());
+            } else {
+                tracing::warn!("Ignoring available Node.js package {}", p.platform)
+            }
+        }
+        node
+    };
+
     let options = RunOptions {
         test: false,
         package: true,
         docs: false,
     };
 
-    (options, LanguagePlatforms { cpp, dotnet, java })
+    Ok((
+        options,
+        LanguagePlatforms {
+            cpp,
+            dotnet,
+            java,
+            swift,
+            go,
+            node,
+        },
+    ))
 }
 
-fn get_platforms(args: &Args) -> (RunOptions, LanguagePlatforms) {
+fn get_platforms(args: &Args) -> CliResult<(RunOptions, LanguagePlatforms)> {
     if let Some(dir) = &args.package_dir {
         let config_path = args
             .package_options
             .as_ref()
-            .expect("You must specify the options file when packaging");
+            .ok_or("You must specify the options file when packaging")?;
         let options: PackageOptions = {
-            let file = File::open(config_path).expect("Error opening package options file");
-            serde_json::from_reader(file).expect("Error reading package options JSON")
+            let file = File::open(config_path)
+                .map_err(|err| format!("Error opening package options file: {}", err))?;
+            serde_json::from_reader(file)
+                .map_err(|err| format!("Error reading package options JSON: {}", err))?
         };
 
         get_packaging_platforms(dir, options)
@@ -227,39 +485,44 @@ impl Default for RunOptions {
 
 trait BindingBuilder: Sized {
     fn name() -> &'static str;
-    fn generate(&mut self, is_packaging: bool, generate_docs: bool);
-    fn build(&mut self);
-    fn test(&mut self);
-    fn package(&mut self);
+    fn generate(&mut self, is_packaging: bool, generate_docs: bool) -> CliResult<()>;
+    fn build(&mut self) -> CliResult<()>;
+    fn test(&mut self) -> CliResult<()>;
+    fn package(&mut self) -> CliResult<()>;
 
-    fn run(&mut self, options: RunOptions) {
+    fn run(&mut self, options: RunOptions) -> CliResult<()> {
         let span = tracing::info_span!("generate()", lang = Self::name());
         span.in_scope(|| {
             tracing::info!("begin");
-            self.generate(options.package, options.docs);
+            self.generate(options.package, options.docs)?;
             tracing::info!("end");
-        });
+            CliResult::Ok(())
+        })?;
 
         if options.package {
             let span = tracing::info_span!("package()", lang = Self::name());
             span.in_scope(|| {
                 tracing::info!("begin");
-                self.package();
+                self.package()?;
                 tracing::info!("end");
-            });
+                CliResult::Ok(())
+            })?;
         } else if options.test {
             let span = tracing::info_span!("build()", lang = Self::name());
             span.in_scope(|| {
                 tracing::info!("begin");
-                self.build();
+                self.build()?;
                 tracing::info!("end");
-            });
+                CliResult::Ok(())
+            })?;
             let span = tracing::info_span!("test()", lang = Self::name());
             span.in_scope(|| {
                 tracing::info!("begin");
-                self.test();
+                self.test()?;
                 tracing::info!("end");
-            });
+                CliResult::Ok(())
+            })?;
         }
+        Ok(())
     }
 }
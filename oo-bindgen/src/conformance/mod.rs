@@ -0,0 +1,400 @@
+//! Conformance suite checking that values survive the round trip through every backend
+//!
+//! [`define`] adds a `conformance` static class to a library with functions that return their
+//! argument unchanged. The Rust implementation of these functions is written by
+//! `oo_bindgen::backend::rust::generate_conformance` and the tests calling them in each language
+//! by the `--conformance` mode of the CLI.
+//!
+//! Iterators cannot be returned by native functions, so they are passed to an interface instead.
+
+use crate::model::*;
+
+/// Name of the static class holding the conformance functions
+const CLASS_NAME: &str = "conformance";
+
+const PRIMITIVES: [Primitive; 11] = [
+    Primitive::Bool,
+    Primitive::U8,
+    Primitive::S8,
+    Primitive::U16,
+    Primitive::S16,
+    Primitive::U32,
+    Primitive::S32,
+    Primitive::U64,
+    Primitive::S64,
+    Primitive::Float,
+    Primitive::Double,
+];
+
+/// Define the conformance functions for every type of the library defined so far
+///
+/// This is meant to be called last in the definition of a library:
+///
+/// - `echo_{type}` for each primitive, duration, string, enum and universal struct with a default
+///   initializer
+/// - `raise_{error}` for each error type, failing with the variant whose value is passed
+/// - a `conformance_{item}_list` collection with its `{item}_list_size` and `{item}_list_get`
+///   functions for the string and primitive items of the collections
+/// - `echo_{item}_iterator` for the primitive items, invoking a `conformance_{item}_receiver`
+///   with a `conformance_{item}_iterator` over the values of the list
+///
+/// The class and the collections are defined in the root module.
+pub fn define(lib: &mut LibraryBuilder) -> BindResult<()> {
+    let mut enums = Vec::new();
+    let mut structs = Vec::new();
+    let mut errors = Vec::new();
+    let mut items: Vec<(String, FunctionArgument)> = Vec::new();
+
+    for statement in lib.statements() {
+        match statement {
+            Statement::EnumDefinition(x) => enums.push(x.clone()),
+            Statement::StructDefinition(StructType::Universal(x))
+                if x.visibility == Visibility::Public && x.has_default_initializer() =>
+            {
+                structs.push(x.clone())
+            }
+            Statement::ErrorType(x) => errors.push(x.clone()),
+            Statement::CollectionDeclaration(x) => {
+                let name = match &x.item_type {
+                    FunctionArgument::String(_) => "string",
+                    FunctionArgument::Basic(BasicType::Primitive(x)) => primitive_name(*x),
+                    _ => continue,
+                };
+                if !items.iter().any(|(x, _)| x == name) {
+                    items.push((name.to_string(), x.item_type.clone()));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    lib.set_module(&Module::root())?;
+
+    let mut methods = Vec::new();
+    for primitive in PRIMITIVES {
+        methods.push(echo(lib, primitive_name(primitive), primitive)?);
+    }
+    methods.push(echo(lib, "duration_ms", DurationType::Milliseconds)?);
+    methods.push(echo(lib, "duration_s", DurationType::Seconds)?);
+    methods.push(echo(lib, "string", StringType)?);
+    // the enum of an error type is tested by raising its errors
+    enums.retain(|x| !errors.iter().any(|err| err.inner.name == x.name));
+    for handle in enums {
+        let name = handle.name.as_ref().to_string();
+        methods.push(echo(lib, &name, handle)?);
+    }
+    for handle in structs {
+        let name = handle.name().as_ref().to_string();
+        methods.push(echo(lib, &name, handle)?);
+    }
+    for error in errors {
+        methods.push(raise(lib, error)?);
+    }
+    for (name, item) in items {
+        let collection =
+            lib.define_collection(format!("{}_{}_list", CLASS_NAME, name), item.clone(), false)?;
+        methods.extend(list(lib, &name, &collection, item.clone())?);
+        if let FunctionArgument::Basic(BasicType::Primitive(x)) = item {
+            methods.push(iterate(lib, &name, collection, x)?);
+        }
+    }
+
+    let mut builder = lib
+        .define_static_class(CLASS_NAME)?
+        .doc("Functions returning their argument unchanged to check the round trip of values")?;
+    for method in methods {
+        builder = builder.static_method(method)?;
+    }
+    builder.build()?;
+
+    Ok(())
+}
+
+fn primitive_name(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::U8 => "u8",
+        Primitive::S8 => "s8",
+        Primitive::U16 => "u16",
+        Primitive::S16 => "s16",
+        Primitive::U32 => "u32",
+        Primitive::S32 => "s32",
+        Primitive::U64 => "u64",
+        Primitive::S64 => "s64",
+        Primitive::Float => "float",
+        Primitive::Double => "double",
+    }
+}
+
+fn echo<T>(
+    lib: &mut LibraryBuilder,
+    name: &str,
+    value_type: T,
+) -> BindResult<StaticMethod<Unvalidated>>
+where
+    T: Into<FunctionArgument> + Into<FunctionReturnValue> + Clone,
+{
+    let method = format!("echo_{}", name);
+    lib.define_function(format!("{}_{}", CLASS_NAME, method))?
+        .param("value", value_type.clone(), "Value to echo")?
+        .returns(value_type, "Value passed as argument")?
+        .doc("Return the value passed as argument")?
+        .build_static(method)
+}
+
+fn raise(
+    lib: &mut LibraryBuilder,
+    error: ErrorType<Unvalidated>,
+) -> BindResult<StaticMethod<Unvalidated>> {
+    let method = format!("raise_{}", error.inner.name.as_ref());
+    lib.define_function(format!("{}_{}", CLASS_NAME, method))?
+        .param("code", Primitive::U32, "Value of the error to fail with")?
+        .fails_with(error)?
+        .doc("Fail with the error whose value is passed as argument, or succeed if it has none")?
+        .build_static(method)
+}
+
+fn list(
+    lib: &mut LibraryBuilder,
+    name: &str,
+    collection: &CollectionHandle,
+    item: FunctionArgument,
+) -> BindResult<Vec<StaticMethod<Unvalidated>>> {
+    let method = format!("{}_list", name);
+
+    let size = format!("{}_size", method);
+    let size = lib
+        .define_function(format!("{}_{}", CLASS_NAME, size))?
+        .param("list", collection.clone(), "List of values")?
+        .returns(Primitive::U32, "Number of values")?
+        .doc("Number of values in the list")?
+        .build_static(size)?;
+
+    let item: FunctionReturnValue = match item {
+        FunctionArgument::Basic(x) => x.into(),
+        _ => StringType.into(),
+    };
+    let get = format!("{}_get", method);
+    let get = lib
+        .define_function(format!("{}_{}", CLASS_NAME, get))?
+        .param("list", collection.clone(), "List of values")?
+        .param("index", Primitive::U32, "Index of the value")?
+        .returns(item, "Value at the index")?
+        .doc("Value of the list at an index")?
+        .build_static(get)?;
+
+    Ok(vec![size, get])
+}
+
+fn iterate(
+    lib: &mut LibraryBuilder,
+    name: &str,
+    collection: CollectionHandle,
+    item: Primitive,
+) -> BindResult<StaticMethod<Unvalidated>> {
+    let iterator = lib.define_iterator(format!("{}_{}_iterator", CLASS_NAME, name), item)?;
+
+    let receiver = lib
+        .define_interface(
+            format!("{}_{}_receiver", CLASS_NAME, name),
+            "Receives the values of an iterator",
+        )?
+        .begin_callback("on_values", "Receive the values of the list")?
+        .param("values", iterator, "Iterator over the values")?
+        .enable_functional_transform()
+        .end_callback()?
+        .build_sync()?;
+
+    let method = format!("echo_{}_iterator", name);
+    lib.define_function(format!("{}_{}", CLASS_NAME, method))?
+        .param("list", collection, "List of values")?
+        .param(
+            "receiver",
+            receiver,
+            "Receiver of an iterator over the values",
+        )?
+        .doc("Invoke the receiver with an iterator over the values of the list")?
+        .build_static(method)
+}
+
+/// Value passed to a conformance function, interpreted according to the type of the function
+#[derive(Debug, Clone)]
+pub(crate) enum Value {
+    Bool(bool),
+    /// minimum value of a number
+    Min,
+    Zero,
+    /// maximum value of a number
+    Max,
+    Duration(u64),
+    Text(&'static str),
+    /// variant of an enum
+    Variant(Name),
+    /// struct built by its default initializer
+    Default,
+}
+
+/// Values exercising the edges of a type
+pub(crate) fn values(value_type: &FunctionArgument) -> Vec<Value> {
+    match value_type {
+        FunctionArgument::Basic(BasicType::Primitive(x)) => match x {
+            Primitive::Bool => vec![Value::Bool(false), Value::Bool(true)],
+            Primitive::U8 | Primitive::U16 | Primitive::U32 | Primitive::U64 => {
+                vec![Value::Zero, Value::Max]
+            }
+            _ => vec![Value::Min, Value::Zero, Value::Max],
+        },
+        // beyond the range of a u32 but within the range of every host duration type
+        FunctionArgument::Basic(BasicType::Duration(_)) => vec![
+            Value::Duration(0),
+            Value::Duration(1),
+            Value::Duration(4_000_000_000),
+        ],
+        FunctionArgument::Basic(BasicType::Enum(x)) => x
+            .variants
+            .iter()
+            .map(|x| Value::Variant(x.name.clone()))
+            .collect(),
+        FunctionArgument::String(_) => vec![
+            Value::Text(""),
+            Value::Text("hello"),
+            Value::Text("gr\u{fc}\u{df}e, \u{4e16}\u{754c} \u{1f980}"),
+        ],
+        _ => vec![Value::Default],
+    }
+}
+
+/// Struct echoed by a conformance function, if the value is one
+pub(crate) fn echoed_struct(value_type: &FunctionArgument) -> Option<&UniversalStructHandle> {
+    match value_type {
+        FunctionArgument::Struct(UniversalOr::Universal(x)) => Some(x),
+        _ => None,
+    }
+}
+
+/// Path of every field of a struct holding a value, descending into the nested structs
+pub(crate) fn leaf_fields(handle: &UniversalStructHandle) -> Vec<Vec<Name>> {
+    let mut paths = Vec::new();
+    for field in &handle.fields {
+        match &field.field_type {
+            UniversalStructField::Basic(_) => paths.push(vec![field.name.clone()]),
+            UniversalStructField::Struct(inner) => {
+                for mut path in leaf_fields(inner) {
+                    path.insert(0, field.name.clone());
+                    paths.push(path);
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// Static method of the conformance class
+pub(crate) struct Method {
+    pub(crate) name: Name,
+    pub(crate) function: Handle<Function<Validated>>,
+}
+
+impl Method {
+    fn new(method: &StaticMethod<Validated>) -> Self {
+        Self {
+            name: method.name.clone(),
+            function: method.native_function.clone(),
+        }
+    }
+}
+
+/// Test of a conformance function
+pub(crate) enum Case {
+    /// a function returning its argument
+    Echo {
+        method: Method,
+        value_type: FunctionArgument,
+    },
+    /// a function failing with the error whose value is passed
+    Raise {
+        method: Method,
+        error: ErrorType<Validated>,
+    },
+    /// a collection whose values are read back
+    List {
+        collection: Handle<Collection<Validated>>,
+        size: Method,
+        get: Method,
+    },
+    /// a receiver invoked with an iterator over the values of a collection
+    Iterate {
+        method: Method,
+        collection: Handle<Collection<Validated>>,
+        receiver: InterfaceHandle,
+        iterator: Handle<AbstractIterator<Validated>>,
+    },
+}
+
+/// Conformance functions of a library
+pub(crate) struct Suite {
+    pub(crate) class: Handle<StaticClass<Validated>>,
+    pub(crate) cases: Vec<Case>,
+}
+
+impl Suite {
+    /// Find the conformance functions of a library, if it defines them
+    pub(crate) fn find(lib: &Library) -> Option<Self> {
+        let class = lib.static_classes().find(|x| x.name == CLASS_NAME)?.clone();
+
+        let get_method = |collection: &Name| {
+            class.static_methods.iter().find(|x| {
+                let args = &x.native_function.arguments;
+                args.len() == 2
+                    && matches!(&args[0].arg_type, FunctionArgument::Collection(c) if c.name() == collection)
+                    && matches!(&args[1].arg_type, FunctionArgument::Basic(_))
+            })
+        };
+
+        let mut cases = Vec::new();
+        for method in &class.static_methods {
+            let function = &method.native_function;
+            if let Some(error) = function.error_type.get() {
+                cases.push(Case::Raise {
+                    method: Method::new(method),
+                    error: error.clone(),
+                });
+                continue;
+            }
+            match &function.arguments[0].arg_type {
+                // a list is tested once, from its size function
+                FunctionArgument::Collection(x) if function.arguments.len() == 1 => {
+                    cases.push(Case::List {
+                        collection: lib.collections().find(|c| c.name() == x.name())?.clone(),
+                        size: Method::new(method),
+                        get: Method::new(get_method(x.name())?),
+                    });
+                }
+                FunctionArgument::Collection(x) => {
+                    if let FunctionArgument::Interface(receiver) = &function.arguments[1].arg_type {
+                        let iterator =
+                            match &receiver.callbacks.first()?.arguments.first()?.arg_type {
+                                CallbackArgument::Iterator(x) => lib
+                                    .iterators()
+                                    .find(|i| i.iter_class.name == x.iter_class.name)?
+                                    .clone(),
+                                _ => return None,
+                            };
+                        cases.push(Case::Iterate {
+                            method: Method::new(method),
+                            collection: lib.collections().find(|c| c.name() == x.name())?.clone(),
+                            receiver: receiver.clone(),
+                            iterator,
+                        });
+                    }
+                }
+                value_type => cases.push(Case::Echo {
+                    method: Method::new(method),
+                    value_type: value_type.clone(),
+                }),
+            }
+        }
+
+        Some(Self { class, cases })
+    }
+}
@@ -46,8 +46,11 @@ clippy::all
 
 /// Command-line interface for generating things locally and in CI
 pub mod cli;
+pub mod conformance;
 /// Backend generators for target languages
 pub mod model;
 
 /// API specific to backend binding generators
 pub mod backend;
+/// Libraries described in JSON or TOML instead of Rust code
+pub mod schema;
@@ -131,6 +131,23 @@ impl<'a> ClassBuilder<'a> {
     }
 
     pub fn build(self) -> BindResult<ClassHandle> {
+        let candidates: Vec<OverloadCandidate> = self
+            .methods
+            .iter()
+            .map(OverloadCandidate::method)
+            .chain(
+                self.static_methods
+                    .iter()
+                    .map(OverloadCandidate::static_method),
+            )
+            .chain(
+                self.async_methods
+                    .iter()
+                    .map(OverloadCandidate::future_method),
+            )
+            .collect();
+        check_overloads(&self.declaration.name, &candidates)?;
+
         let doc = match self.doc {
             Some(doc) => doc,
             None => {
@@ -188,8 +205,16 @@ impl<'a> StaticClassBuilder<'a> {
     }
 
     pub fn build(self) -> BindResult<StaticClassHandle> {
+        let candidates: Vec<OverloadCandidate> = self
+            .static_methods
+            .iter()
+            .map(OverloadCandidate::static_method)
+            .collect();
+        check_overloads(&self.name, &candidates)?;
+
         let handle = Handle::new(StaticClass {
             name: self.name,
+            module: self.lib.module().clone(),
             static_methods: self.static_methods,
             doc: self.doc.extract()?,
         });
@@ -51,6 +51,7 @@ impl<'a> ConstantSetBuilder<'a> {
     pub fn build(self) -> BindResult<()> {
         let handle = Handle::new(ConstantSet {
             name: self.name,
+            module: self.lib.module().clone(),
             settings: self.lib.clone_settings(),
             values: self.values,
             doc: self.doc.extract()?,
@@ -62,6 +62,26 @@ impl<'a> EnumBuilder<'a> {
         self.variant(name.into_name()?, value, doc)
     }
 
+    /// Mark the most recently added variant as deprecated since a version of the library
+    pub fn deprecated<D: Into<DocString<Unvalidated>>>(
+        mut self,
+        since: Version,
+        note: D,
+    ) -> BindResult<Self> {
+        let variant = match self.variants.last_mut() {
+            Some(x) => x,
+            None => {
+                return Err(
+                    BindingErrorVariant::DeprecationWithoutTarget { parent: self.name }.into(),
+                )
+            }
+        };
+        variant
+            .doc
+            .set_deprecated(&variant.name, Deprecation::new(since, note))?;
+        Ok(self)
+    }
+
     pub fn doc<D: Into<Doc<Unvalidated>>>(mut self, doc: D) -> BindResult<Self> {
         self.doc.set(doc.into())?;
         Ok(self)
@@ -72,6 +92,7 @@ impl<'a> EnumBuilder<'a> {
     ) -> BindResult<(Handle<Enum<Unvalidated>>, &'a mut LibraryBuilder)> {
         let handle = Handle::new(Enum {
             name: self.name,
+            module: self.lib.module().clone(),
             settings: self.lib.clone_settings(),
             variants: self.variants,
             doc: self.doc.extract()?,
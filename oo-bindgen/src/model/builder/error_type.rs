@@ -3,6 +3,7 @@ use crate::model::*;
 pub struct ErrorTypeBuilder<'a> {
     exception_name: Name,
     exception_type: ExceptionType,
+    invalid_argument: Option<Name>,
     inner: EnumBuilder<'a>,
 }
 
@@ -15,6 +16,7 @@ impl<'a> ErrorTypeBuilder<'a> {
         Self {
             exception_name,
             exception_type,
+            invalid_argument: None,
             inner,
         }
     }
@@ -30,6 +32,28 @@ impl<'a> ErrorTypeBuilder<'a> {
         })
     }
 
+    /// Add the variant returned by the FFI when an argument violates one of its constraints
+    ///
+    /// Only one such variant may be defined per error type
+    pub fn add_invalid_argument_error<T: IntoName, D: Into<Doc<Unvalidated>>>(
+        self,
+        name: T,
+        doc: D,
+    ) -> BindResult<Self> {
+        let name = name.into_name()?;
+        if self.invalid_argument.is_some() {
+            return Err(BindingErrorVariant::InvalidArgumentErrorAlreadyDefined {
+                name: self.exception_name,
+            }
+            .into());
+        }
+        Ok(Self {
+            inner: self.inner.push(name.clone(), doc)?,
+            invalid_argument: Some(name),
+            ..self
+        })
+    }
+
     pub fn doc<D: Into<Doc<Unvalidated>>>(self, doc: D) -> BindResult<Self> {
         Ok(Self {
             inner: self.inner.doc(doc)?,
@@ -44,6 +68,7 @@ impl<'a> ErrorTypeBuilder<'a> {
             exception_name: self.exception_name,
             exception_type: self.exception_type,
             inner,
+            invalid_argument: self.invalid_argument,
         };
 
         lib.add_statement(Statement::ErrorType(err.clone()))?;
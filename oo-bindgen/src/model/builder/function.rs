@@ -7,6 +7,7 @@ pub struct FunctionBuilder<'a> {
     return_type: OptionalReturnType<FunctionReturnValue, Unvalidated>,
     params: Vec<Arg<FunctionArgument, Unvalidated>>,
     doc: OptionalDoc,
+    deprecated: Option<Deprecation<Unvalidated>>,
     error_type: OptionalErrorType<Unvalidated>,
 }
 
@@ -23,6 +24,7 @@ impl<'a> FunctionBuilder<'a> {
             return_type: OptionalReturnType::new(),
             params: Vec::new(),
             doc: OptionalDoc::new(name),
+            deprecated: None,
             error_type: OptionalErrorType::new(),
         }
     }
@@ -35,14 +37,78 @@ impl<'a> FunctionBuilder<'a> {
     ) -> BindResult<Self> {
         let param_type = param_type.into();
         let name = name.into_name()?;
+        if self.params.iter().any(|x| x.default.is_some()) {
+            return Err(BindingErrorVariant::ParamWithoutDefaultAfterDefault {
+                function: self.name,
+                param: name,
+            }
+            .into());
+        }
         self.params.push(Arg {
             name,
             arg_type: param_type,
+            constraints: Vec::new(),
+            default: None,
             doc: doc.into(),
         });
         Ok(self)
     }
 
+    /// Add a parameter that host languages may omit, in which case the default value is used
+    ///
+    /// Parameters with default values must come after all of the required parameters. The C API
+    /// always requires every parameter to be specified.
+    pub fn param_with_default<
+        T: IntoName,
+        D: Into<DocString<Unvalidated>>,
+        P: Into<FunctionArgument>,
+        V: Into<InitializerDefault>,
+    >(
+        mut self,
+        name: T,
+        param_type: P,
+        doc: D,
+        default: V,
+    ) -> BindResult<Self> {
+        let param_type = param_type.into();
+        let default = param_type.validate_default_value(&default.into())?;
+        self.params.push(Arg {
+            name: name.into_name()?,
+            arg_type: param_type,
+            constraints: Vec::new(),
+            default: Some(default),
+            doc: doc.into(),
+        });
+        Ok(self)
+    }
+
+    /// Constrain the value of the most recently added parameter
+    ///
+    /// The function must fail with an error type that defines an invalid argument variant
+    pub fn constrain(mut self, constraint: Constraint) -> BindResult<Self> {
+        let param = match self.params.last_mut() {
+            Some(x) => x,
+            None => {
+                return Err(
+                    BindingErrorVariant::ConstraintWithoutTarget { parent: self.name }.into(),
+                )
+            }
+        };
+        constraint.check_applies_to(&param.name, param.arg_type.constrained_type())?;
+        if let Some(default) = &param.default {
+            if !constraint.allows_default(default) {
+                return Err(BindingErrorVariant::ParamDefaultViolatesConstraint {
+                    function: self.name,
+                    param: param.name.clone(),
+                    constraint,
+                }
+                .into());
+            }
+        }
+        param.constraints.push(constraint);
+        Ok(self)
+    }
+
     pub fn returns<D: Into<DocString<Unvalidated>>, T: Into<FunctionReturnValue>>(
         mut self,
         return_type: T,
@@ -63,15 +129,51 @@ impl<'a> FunctionBuilder<'a> {
         Ok(self)
     }
 
+    /// Mark the function as deprecated since a version of the library
+    ///
+    /// The note usually refers to the replacement, e.g. `use {class:foo.bar()} instead`
+    pub fn deprecated<D: Into<DocString<Unvalidated>>>(
+        mut self,
+        since: Version,
+        note: D,
+    ) -> BindResult<Self> {
+        if self.deprecated.is_some() {
+            return Err(BindingErrorVariant::DeprecationAlreadyDefined {
+                symbol_name: self.name,
+            }
+            .into());
+        }
+        self.deprecated = Some(Deprecation::new(since, note));
+        Ok(self)
+    }
+
     pub fn build(self) -> BindResult<FunctionHandle> {
+        let has_invalid_argument_error = self
+            .error_type
+            .get()
+            .and_then(|x| x.invalid_argument.as_ref())
+            .is_some();
+        if !has_invalid_argument_error && self.params.iter().any(|x| x.has_constraints()) {
+            return Err(
+                BindingErrorVariant::ConstrainedFunctionWithoutInvalidArgumentError {
+                    function: self.name,
+                }
+                .into(),
+            );
+        }
+
+        let mut doc = self.doc.extract()?;
+        doc.deprecated = self.deprecated;
+
         let handle = Handle::new(Function {
             name: self.name,
             category: self.function_type,
+            module: self.lib.module().clone(),
             return_type: self.return_type,
             arguments: self.params,
             error_type: self.error_type,
             settings: self.lib.clone_settings(),
-            doc: self.doc.extract()?,
+            doc,
         });
 
         self.lib
@@ -122,7 +224,7 @@ impl<'a> ClassMethodBuilder<'a> {
             .param(
                 instance_arg_name,
                 class.clone(),
-                format!("Instance of {{class:{}}}", class.name),
+                format!("Instance of {{class:{}}}", class.name.as_ref()),
             )?;
 
         Ok(Self {
@@ -145,6 +247,35 @@ impl<'a> ClassMethodBuilder<'a> {
         })
     }
 
+    pub fn param_with_default<
+        T: IntoName,
+        D: Into<DocString<Unvalidated>>,
+        P: Into<FunctionArgument>,
+        V: Into<InitializerDefault>,
+    >(
+        self,
+        name: T,
+        param_type: P,
+        doc: D,
+        default: V,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            method_name: self.method_name,
+            class: self.class,
+            inner: self
+                .inner
+                .param_with_default(name, param_type, doc, default)?,
+        })
+    }
+
+    pub fn constrain(self, constraint: Constraint) -> BindResult<Self> {
+        Ok(Self {
+            method_name: self.method_name,
+            class: self.class,
+            inner: self.inner.constrain(constraint)?,
+        })
+    }
+
     pub fn returns<D: Into<DocString<Unvalidated>>, T: Into<FunctionReturnValue>>(
         self,
         return_type: T,
@@ -175,10 +306,39 @@ impl<'a> ClassMethodBuilder<'a> {
         })
     }
 
+    pub fn deprecated<D: Into<DocString<Unvalidated>>>(
+        self,
+        since: Version,
+        note: D,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            method_name: self.method_name,
+            class: self.class,
+            inner: self.inner.deprecated(since, note)?,
+        })
+    }
+
     pub fn build(self) -> BindResult<Method<Unvalidated>> {
         let function = self.inner.build()?;
         Ok(Method::new(self.method_name, self.class, function))
     }
+
+    /// Build a method that host languages expose under a different name than the native function
+    ///
+    /// Several methods of a class may share the same name as long as their parameters can be
+    /// distinguished in every host language.
+    pub fn build_overload<N: IntoName>(self, name: N) -> BindResult<Method<Unvalidated>> {
+        let name = name.into_name()?;
+        if name.contains(self.class.name.as_ref()) {
+            return Err(BindingErrorVariant::BadMethodName {
+                class: self.class,
+                method_name: name,
+            }
+            .into());
+        }
+        let function = self.inner.build()?;
+        Ok(Method::new(name, self.class, function))
+    }
 }
 
 pub struct ClassConstructorBuilder<'a> {
@@ -199,7 +359,7 @@ impl<'a> ClassConstructorBuilder<'a> {
             )?
             .returns(
                 class.clone(),
-                format!("Instance of {{class:{}}}", class.name),
+                format!("Instance of {{class:{}}}", class.name.as_ref()),
             )?;
 
         Ok(Self {
@@ -220,6 +380,33 @@ impl<'a> ClassConstructorBuilder<'a> {
         })
     }
 
+    pub fn param_with_default<
+        T: IntoName,
+        D: Into<DocString<Unvalidated>>,
+        P: Into<FunctionArgument>,
+        V: Into<InitializerDefault>,
+    >(
+        self,
+        name: T,
+        param_type: P,
+        doc: D,
+        default: V,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            class: self.class,
+            inner: self
+                .inner
+                .param_with_default(name, param_type, doc, default)?,
+        })
+    }
+
+    pub fn constrain(self, constraint: Constraint) -> BindResult<Self> {
+        Ok(Self {
+            class: self.class,
+            inner: self.inner.constrain(constraint)?,
+        })
+    }
+
     pub fn fails_with(self, err: ErrorType<Unvalidated>) -> BindResult<Self> {
         Ok(Self {
             class: self.class,
@@ -234,6 +421,17 @@ impl<'a> ClassConstructorBuilder<'a> {
         })
     }
 
+    pub fn deprecated<D: Into<DocString<Unvalidated>>>(
+        self,
+        since: Version,
+        note: D,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            class: self.class,
+            inner: self.inner.deprecated(since, note)?,
+        })
+    }
+
     pub fn build(self) -> BindResult<ClassConstructor<Unvalidated>> {
         Ok(ClassConstructor::new(self.class, self.inner.build()?))
     }
@@ -274,6 +472,13 @@ impl<'a> FutureMethodBuilder<'a> {
         })
     }
 
+    pub fn constrain(self, constraint: Constraint) -> BindResult<Self> {
+        Ok(Self {
+            future: self.future,
+            inner: self.inner.constrain(constraint)?,
+        })
+    }
+
     pub fn fails_with(self, err: ErrorType<Unvalidated>) -> BindResult<Self> {
         Ok(Self {
             future: self.future,
@@ -288,6 +493,17 @@ impl<'a> FutureMethodBuilder<'a> {
         })
     }
 
+    pub fn deprecated<D: Into<DocString<Unvalidated>>>(
+        self,
+        since: Version,
+        note: D,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            future: self.future,
+            inner: self.inner.deprecated(since, note)?,
+        })
+    }
+
     pub fn build(self) -> BindResult<FutureMethod<Unvalidated>> {
         let future = self.future.clone();
         let callback_parameter_name = self
@@ -315,3 +531,75 @@ impl<'a> FutureMethodBuilder<'a> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_param_without_default_after_default() {
+        let mut lib = LibraryBuilder::for_tests();
+        let result = lib
+            .define_function("add")
+            .unwrap()
+            .param_with_default("value", Primitive::U32, "Value", NumberValue::U32(1))
+            .unwrap()
+            .param("factor", Primitive::U32, "Factor");
+        assert!(matches!(
+            result.err().unwrap().variant(),
+            BindingErrorVariant::ParamWithoutDefaultAfterDefault { .. }
+        ));
+    }
+
+    #[test]
+    fn rejects_default_that_violates_constraint() {
+        let mut lib = LibraryBuilder::for_tests();
+        let result = lib
+            .define_function("connect")
+            .unwrap()
+            .param_with_default("port", Primitive::U16, "Port", NumberValue::U16(0))
+            .unwrap()
+            .constrain(Constraint::range(
+                NumberValue::U16(1),
+                NumberValue::U16(65535),
+            ));
+        assert!(matches!(
+            result.err().unwrap().variant(),
+            BindingErrorVariant::ParamDefaultViolatesConstraint { .. }
+        ));
+    }
+
+    #[test]
+    fn rejects_constrained_function_without_invalid_argument_error() {
+        let mut lib = LibraryBuilder::for_tests();
+        let error_type = lib
+            .define_error_type(
+                "connect_error",
+                "connect_exception",
+                ExceptionType::UncheckedException,
+            )
+            .unwrap()
+            .add_error("refused", "Connection refused")
+            .unwrap()
+            .doc("Errors returned when connecting")
+            .unwrap()
+            .build()
+            .unwrap();
+        let result = lib
+            .define_function("connect")
+            .unwrap()
+            .param("name", StringType, "Name of the host")
+            .unwrap()
+            .constrain(Constraint::NonEmpty)
+            .unwrap()
+            .fails_with(error_type)
+            .unwrap()
+            .doc("Connect to a host")
+            .unwrap()
+            .build();
+        assert!(matches!(
+            result.err().unwrap().variant(),
+            BindingErrorVariant::ConstrainedFunctionWithoutInvalidArgumentError { .. }
+        ));
+    }
+}
@@ -64,6 +64,7 @@ impl<'a> InterfaceBuilder<'a> {
     ) -> (InterfaceHandle, &'a mut LibraryBuilder) {
         let handle = Handle::new(Interface {
             name: self.name,
+            module: self.lib.module().clone(),
             mode,
             callbacks: self.callbacks,
             doc: self.doc,
@@ -202,6 +203,17 @@ impl<'a> CallbackFunctionBuilder<'a> {
         Ok(self)
     }
 
+    /// Mark the callback as deprecated since a version of the library
+    pub fn deprecated<D: Into<DocString<Unvalidated>>>(
+        mut self,
+        since: Version,
+        note: D,
+    ) -> BindResult<Self> {
+        self.doc
+            .set_deprecated(&self.name, Deprecation::new(since, note))?;
+        Ok(self)
+    }
+
     pub fn end_callback(mut self) -> BindResult<InterfaceBuilder<'a>> {
         let cb = CallbackFunction {
             name: self.name,
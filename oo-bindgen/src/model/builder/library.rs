@@ -4,6 +4,9 @@ use std::rc::Rc;
 use crate::model::*;
 
 pub(crate) struct LibraryFields {
+    // version of the library, documented items cannot be introduced after it
+    pub(crate) version: Version,
+
     // a record of statements preserved in order
     pub(crate) statements: Vec<Statement<Unvalidated>>,
 
@@ -53,8 +56,9 @@ impl LibraryFields {
         self.interfaces.iter().find(|x| x.name == name.as_ref())
     }
 
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(version: Version) -> Self {
         Self {
+            version,
             statements: Vec::new(),
 
             structs_declarations: HashSet::new(),
@@ -81,6 +85,11 @@ pub struct LibraryBuilder {
     info: Rc<LibraryInfo>,
 
     settings: Rc<LibrarySettings>,
+    naming: NamingRules,
+
+    // modules defined so far, and the one of the items being defined
+    modules: Vec<Module>,
+    module: Module,
 
     // names of symbols used in the library
     symbol_names: HashSet<String>,
@@ -90,11 +99,14 @@ pub struct LibraryBuilder {
 impl LibraryBuilder {
     pub fn new(version: Version, info: LibraryInfo, settings: Rc<LibrarySettings>) -> Self {
         Self {
-            version,
+            version: version.clone(),
             info: Rc::new(info),
             settings,
+            naming: NamingRules::default(),
+            modules: Vec::new(),
+            module: Module::root(),
             symbol_names: HashSet::new(),
-            fields: LibraryFields::new(),
+            fields: LibraryFields::new(version),
         }
     }
 
@@ -106,6 +118,16 @@ impl LibraryBuilder {
         self.settings.clone()
     }
 
+    /// module in which the items are defined
+    pub(crate) fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// statements defined so far, in order
+    pub(crate) fn statements(&self) -> impl Iterator<Item = &Statement<Unvalidated>> {
+        self.fields.statements.iter()
+    }
+
     pub(crate) fn add_statement(&mut self, statement: Statement<Unvalidated>) -> BindResult<()> {
         // verify that all the pieces of the statement are from this library
         self.check_statement(&statement)?;
@@ -192,6 +214,7 @@ impl LibraryBuilder {
 
     pub fn build(mut self) -> BindResult<Library> {
         // Add the version function
+        self.module = Module::root();
         self.define_function("version")?
             .returns(StringType, "Version number")?
             .doc("Get the version of the library as a string")?
@@ -208,10 +231,90 @@ impl LibraryBuilder {
             self.version,
             self.info,
             self.settings,
+            Rc::new(self.naming),
+            self.modules,
             statements?,
         ))
     }
 
+    /// Spell a word in capital letters in CamelCase and mixedCase identifiers, e.g. with the
+    /// acronym `tls`, the name `tls_config` becomes `TLSConfig` in C#
+    pub fn define_acronym<T: IntoName>(&mut self, acronym: T) -> BindResult<()> {
+        let acronym = acronym.into_name()?;
+        if acronym.contains('_') {
+            return Err(BindingErrorVariant::AcronymNotSingleWord { acronym }.into());
+        }
+        self.naming.acronyms.insert(acronym.to_string());
+        Ok(())
+    }
+
+    /// Use another name for every symbol named `name` in the bindings of a language
+    ///
+    /// The replacement is spelled like the original name, i.e. in CamelCase or mixedCase depending
+    /// on the kind of symbol. CAPITAL_SNAKE_CASE identifiers are shared with C and keep the name.
+    /// The Kotlin extensions call the Java API, so renaming a symbol in Kotlin renames it in Java.
+    /// The names of the C API are the names of the FFI and cannot be renamed.
+    pub fn rename<T: IntoName, R: IntoName>(
+        &mut self,
+        name: T,
+        language: TargetLanguage,
+        replacement: R,
+    ) -> BindResult<()> {
+        let name = name.into_name()?;
+        let replacement = replacement.into_name()?;
+        if language == TargetLanguage::C {
+            return Err(BindingErrorVariant::RenameInC { name }.into());
+        }
+        let language = language.renamed_as();
+        if self.naming.renames.contains_key(&(language, name.clone())) {
+            return Err(BindingErrorVariant::NameAlreadyRenamed { name, language }.into());
+        }
+        self.naming.renames.insert((language, name), replacement);
+        Ok(())
+    }
+
+    /// Define a module at the root of the library, see [`Module`]
+    pub fn define_module<T: IntoName>(&mut self, name: T) -> BindResult<Module> {
+        self.define_submodule(&Module::root(), name)
+    }
+
+    /// Define a module nested in another one
+    ///
+    /// The names of the modules share the namespace of the other symbols of the library, e.g. a
+    /// module and a class cannot both be named `master`.
+    pub fn define_submodule<T: IntoName>(
+        &mut self,
+        parent: &Module,
+        name: T,
+    ) -> BindResult<Module> {
+        self.check_module(parent)?;
+        let name = name.into_name()?;
+        if name == self.settings.name {
+            return Err(BindingErrorVariant::ModuleNamedAfterLibrary { name }.into());
+        }
+        self.check_unique_symbol(&name)?;
+
+        let module = parent.child(name);
+        self.modules.push(module.clone());
+        Ok(module)
+    }
+
+    /// Define the following items in a module, until another one is set
+    ///
+    /// The items are defined in [`Module::root()`] until this is first called.
+    pub fn set_module(&mut self, module: &Module) -> BindResult<()> {
+        self.check_module(module)?;
+        self.module = module.clone();
+        Ok(())
+    }
+
+    /// Build the library and run the documentation lints on it
+    pub fn build_with_lints(self) -> BindResult<(Library, LintReport)> {
+        let lib = self.build()?;
+        let report = lint(&lib);
+        Ok((lib, report))
+    }
+
     pub fn define_error_type<T: IntoName>(
         &mut self,
         error_name: T,
@@ -239,7 +342,11 @@ impl LibraryBuilder {
         name: T,
     ) -> BindResult<StructDeclarationHandle> {
         let name = name.into_name()?;
-        let handle = Handle::new(StructDeclaration::new(name, self.settings.clone()));
+        let handle = Handle::new(StructDeclaration::new(
+            name,
+            self.module.clone(),
+            self.settings.clone(),
+        ));
         self.add_statement(Statement::StructDeclaration(handle.clone()))?;
         Ok(handle)
     }
@@ -448,6 +555,7 @@ impl LibraryBuilder {
         let handle = ClassDeclarationHandle::new(ClassDeclaration::new(
             name,
             class_type,
+            self.module.clone(),
             self.settings.clone(),
         ));
         self.add_statement(Statement::ClassDeclaration(handle.clone()))?;
@@ -667,6 +775,17 @@ impl LibraryBuilder {
         Ok(collection)
     }
 
+    fn check_module(&self, module: &Module) -> BindResult<()> {
+        if module.is_root() || self.modules.contains(module) {
+            Ok(())
+        } else {
+            Err(BindingErrorVariant::ModuleNotDefined {
+                module: module.clone(),
+            }
+            .into())
+        }
+    }
+
     fn check_unique_symbol(&mut self, name: &Name) -> BindResult<()> {
         if self.symbol_names.insert(name.to_string()) {
             Ok(())
@@ -880,3 +999,31 @@ impl LibraryBuilder {
         }
     }
 }
+
+#[cfg(test)]
+impl LibraryBuilder {
+    /// Empty library used to exercise the builders
+    pub(crate) fn for_tests() -> Self {
+        let info = LibraryInfo {
+            description: "Test library".to_string(),
+            project_url: "https://example.com".to_string(),
+            repository: "example/test".to_string(),
+            license_name: "MIT".to_string(),
+            license_description: Vec::new(),
+            license_path: std::path::PathBuf::from("LICENSE.txt"),
+            developers: Vec::new(),
+            logo_png: &[],
+        };
+        let settings = LibrarySettings::create(
+            "test",
+            "test",
+            ClassSettings::default(),
+            IteratorSettings::default(),
+            CollectionSettings::default(),
+            FutureSettings::default(),
+            InterfaceSettings::default(),
+        )
+        .unwrap();
+        Self::new(Version::new(1, 0, 0), info, settings)
+    }
+}
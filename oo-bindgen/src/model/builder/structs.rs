@@ -14,6 +14,8 @@ where
 {
     lib: &'a mut LibraryBuilder,
     visibility: Visibility,
+    value_semantics: bool,
+    immutable: bool,
     declaration: TypedStructDeclaration<F>,
     fields: Vec<StructField<F, Unvalidated>>,
     field_names: HashSet<String>,
@@ -43,6 +45,8 @@ where
         Self {
             lib,
             visibility,
+            value_semantics: false,
+            immutable: false,
             declaration,
             fields: Vec::new(),
             field_names: HashSet::new(),
@@ -63,6 +67,7 @@ where
             self.fields.push(StructField {
                 name,
                 field_type,
+                constraints: Vec::new(),
                 doc: doc.into(),
             });
             Ok(self)
@@ -75,6 +80,46 @@ where
         }
     }
 
+    /// Constrain the value of the most recently added field
+    ///
+    /// Functions that accept the struct as an argument must fail with an error type
+    /// that defines an invalid argument variant
+    pub fn constrain(mut self, constraint: Constraint) -> BindResult<Self> {
+        let field = match self.fields.last_mut() {
+            Some(x) => x,
+            None => {
+                return Err(BindingErrorVariant::ConstraintWithoutTarget {
+                    parent: self.declaration.name().clone(),
+                }
+                .into())
+            }
+        };
+        constraint.check_applies_to(&field.name, field.field_type.constrained_type())?;
+        field.constraints.push(constraint);
+        Ok(self)
+    }
+
+    /// Mark the most recently added field as deprecated since a version of the library
+    pub fn deprecated<D: Into<DocString<Unvalidated>>>(
+        mut self,
+        since: Version,
+        note: D,
+    ) -> BindResult<Self> {
+        let field = match self.fields.last_mut() {
+            Some(x) => x,
+            None => {
+                return Err(BindingErrorVariant::DeprecationWithoutTarget {
+                    parent: self.declaration.name().clone(),
+                }
+                .into())
+            }
+        };
+        field
+            .doc
+            .set_deprecated(&field.name, Deprecation::new(since, note))?;
+        Ok(self)
+    }
+
     pub fn doc<D: Into<Doc<Unvalidated>>>(mut self, doc: D) -> BindResult<Self> {
         match self.doc {
             None => {
@@ -88,6 +133,28 @@ where
         }
     }
 
+    /// Generate equality, hashing and a string representation for the struct in each language
+    ///
+    /// Every field must be comparable by value, i.e. nested structs must also enable value semantics
+    #[must_use]
+    pub fn enable_value_semantics(mut self) -> Self {
+        self.value_semantics = true;
+        self
+    }
+
+    /// Generate fields that can only be set at construction in languages that support it
+    ///
+    /// Instead of setters, the bindings provide `with_x` methods that return a modified copy.
+    /// Java also gets a builder whose values start from the default initializer (if any).
+    /// .NET keeps a class with `readonly` fields rather than a `readonly struct` or a `record`:
+    /// optional struct arguments are `null` references and records need C# 9.
+    /// C and C++ structs are unaffected.
+    #[must_use]
+    pub fn make_immutable(mut self) -> Self {
+        self.immutable = true;
+        self
+    }
+
     pub fn end_fields(self) -> BindResult<StructMethodBuilder<'a, F>> {
         let doc = match self.doc {
             Some(doc) => doc,
@@ -99,9 +166,25 @@ where
             }
         };
 
+        if self.value_semantics {
+            if let Some(field) = self
+                .fields
+                .iter()
+                .find(|f| !f.field_type.has_value_semantics())
+            {
+                return Err(BindingErrorVariant::StructValueSemanticsUnsupportedField {
+                    struct_name: self.declaration.name().clone(),
+                    field_name: field.name.clone(),
+                }
+                .into());
+            }
+        }
+
         Ok(StructMethodBuilder {
             lib: self.lib,
             visibility: self.visibility,
+            value_semantics: self.value_semantics,
+            immutable: self.immutable,
             declaration: self.declaration,
             fields: self.fields,
             initializers: Vec::new(),
@@ -127,6 +210,8 @@ where
 {
     lib: &'a mut LibraryBuilder,
     visibility: Visibility,
+    value_semantics: bool,
+    immutable: bool,
     declaration: TypedStructDeclaration<F>,
     fields: Vec<StructField<F, Unvalidated>>,
     initializers: Vec<Handle<Initializer<Unvalidated>>>,
@@ -173,7 +258,7 @@ where
             InitializerType::Normal,
             format!(
                 "Fully construct {{struct:{}}} specifying the value of each field",
-                struct_name
+                struct_name.as_ref()
             ),
         )?
         .end_initializer()
@@ -182,6 +267,8 @@ where
     pub fn build(self) -> BindResult<Handle<Struct<F, Unvalidated>>> {
         let handle = Handle::new(Struct {
             visibility: self.visibility,
+            value_semantics: self.value_semantics,
+            immutable: self.immutable,
             declaration: self.declaration.clone(),
             fields: self.fields,
             initializers: self.initializers,
@@ -218,8 +305,8 @@ where
         }
 
         // find the field and validate it
-        let value = match self.builder.fields.iter().find(|f| f.name == *name) {
-            Some(x) => x.field_type.validate_default_value(&value)?,
+        let field = match self.builder.fields.iter().find(|f| f.name == *name) {
+            Some(x) => x,
             None => {
                 return Err(BindingErrorVariant::StructInitializerUnknownField {
                     struct_name: self.builder.declaration.name().clone(),
@@ -228,6 +315,19 @@ where
                 .into());
             }
         };
+        let value = field.field_type.validate_default_value(&value)?;
+
+        // the default may not violate any of the field's constraints
+        if let Some(constraint) = field.constraints.iter().find(|c| !c.allows_default(&value)) {
+            return Err(
+                BindingErrorVariant::StructInitializerDefaultViolatesConstraint {
+                    struct_name: self.builder.declaration.name().clone(),
+                    field_name: name.clone(),
+                    constraint: constraint.clone(),
+                }
+                .into(),
+            );
+        }
 
         self.fields.push(InitializedValue {
             name: name.clone(),
@@ -18,6 +18,7 @@ pub(crate) enum ClassType {
 pub struct ClassDeclaration {
     pub(crate) name: Name,
     pub(crate) class_type: ClassType,
+    pub(crate) module: Module,
     pub(crate) settings: Rc<LibrarySettings>,
 }
 
@@ -44,10 +45,16 @@ impl CollectionClassDeclaration {
 }
 
 impl ClassDeclaration {
-    pub(crate) fn new(name: Name, class_type: ClassType, settings: Rc<LibrarySettings>) -> Self {
+    pub(crate) fn new(
+        name: Name,
+        class_type: ClassType,
+        module: Module,
+        settings: Rc<LibrarySettings>,
+    ) -> Self {
         Self {
             name,
             class_type,
+            module,
             settings,
         }
     }
@@ -247,6 +254,7 @@ where
     T: DocReference,
 {
     pub(crate) name: Name,
+    pub(crate) module: Module,
     pub(crate) static_methods: Vec<StaticMethod<T>>,
     pub(crate) doc: Doc<T>,
 }
@@ -263,6 +271,7 @@ impl StaticClass<Unvalidated> {
             .collect();
         Ok(Handle::new(StaticClass {
             name: self.name.clone(),
+            module: self.module.clone(),
             static_methods: methods?,
             doc: self.doc.validate(&self.name, lib)?,
         }))
@@ -45,6 +45,8 @@ where
 {
     /// name of the set
     pub(crate) name: Name,
+    /// module of the set
+    pub(crate) module: Module,
     /// common library settings
     pub(crate) settings: Rc<LibrarySettings>,
     /// values
@@ -63,6 +65,7 @@ impl ConstantSet<Unvalidated> {
 
         Ok(Handle::new(ConstantSet {
             name: self.name.clone(),
+            module: self.module.clone(),
             settings: self.settings.clone(),
             values: values?,
             doc: self.doc.validate(&self.name, lib)?,
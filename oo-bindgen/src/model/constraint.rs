@@ -0,0 +1,396 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use crate::model::*;
+
+/// Declarative constraint on the value of a struct field or a function parameter
+///
+/// Constraints are enforced by the generated Rust FFI shim which returns the invalid argument
+/// variant of the function's error type. Host languages pre-check them and throw their idiomatic
+/// argument exception before crossing the FFI boundary.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    /// numeric value must be within the inclusive range [min, max]
+    Range { min: NumberValue, max: NumberValue },
+    /// numeric value or duration must not be zero
+    NonZero,
+    /// string must not be empty
+    NonEmpty,
+    /// string encoded as UTF-8 may not exceed this number of bytes
+    MaxLength(usize),
+    /// entire string must match the regular expression
+    ///
+    /// Patterns are evaluated by a different engine in each language, so they are limited to the
+    /// constructs that have the same meaning in all of them:
+    ///
+    /// - literal characters, `.` and the metacharacters escaped with a backslash, e.g. `\.`
+    /// - character classes with ranges, optionally negated, e.g. `[a-z_]` or `[^0-9]`
+    /// - capturing `(...)` and non-capturing `(?:...)` groups, and alternation with `|`
+    /// - the quantifiers `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}`, optionally lazy
+    ///
+    /// Other constructs, e.g. `\d`, `\p{L}`, named groups, lookarounds, flags or anchors, are
+    /// rejected when the library is built. The pattern always has to match the entire string.
+    Regex(String),
+}
+
+/// Types that constraints may be applied to
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConstrainedType {
+    Primitive(Primitive),
+    Duration(DurationType),
+    String,
+}
+
+impl Constraint {
+    /// Construct an inclusive range constraint
+    pub fn range(min: NumberValue, max: NumberValue) -> Self {
+        Self::Range { min, max }
+    }
+
+    /// Construct a regular expression constraint
+    pub fn regex<S: Into<String>>(pattern: S) -> Self {
+        Self::Regex(pattern.into())
+    }
+
+    /// Lower bound of a range, unless it is the minimum value of the type and can't be violated
+    pub(crate) fn lower_bound(&self) -> Option<NumberValue> {
+        match self {
+            Self::Range { min, .. } if !is_type_min(min) => Some(*min),
+            _ => None,
+        }
+    }
+
+    /// Upper bound of a range, unless it is the maximum value of the type and can't be violated
+    pub(crate) fn upper_bound(&self) -> Option<NumberValue> {
+        match self {
+            Self::Range { max, .. } if !is_type_max(max) => Some(*max),
+            _ => None,
+        }
+    }
+
+    /// Regular expression anchored so that a partial match is never accepted
+    ///
+    /// The end is `\z` because `$` also matches before a trailing newline in .NET.
+    pub(crate) fn anchored_regex(&self) -> Option<String> {
+        match self {
+            Self::Regex(pattern) => Some(format!("\\A(?:{})\\z", pattern)),
+            _ => None,
+        }
+    }
+
+    /// Message used when the constraint is violated, escaped for use in a double-quoted literal
+    pub(crate) fn violation_message(&self, name: &str) -> String {
+        escape_string_literal(&format!("{} {}", name, self))
+    }
+
+    pub(crate) fn check_applies_to(
+        &self,
+        name: &Name,
+        target: Option<ConstrainedType>,
+    ) -> BindResult<()> {
+        let error = |reason: &'static str| -> BindResult<()> {
+            Err(BindingErrorVariant::InvalidConstraint {
+                name: name.clone(),
+                constraint: self.clone(),
+                reason,
+            }
+            .into())
+        };
+
+        match self {
+            Self::Range { min, max } => {
+                let primitive = match target {
+                    Some(ConstrainedType::Primitive(x)) if x != Primitive::Bool => x,
+                    _ => return error("ranges only apply to numeric types"),
+                };
+                if primitive_of(min) != primitive || primitive_of(max) != primitive {
+                    return error("range bounds must have the same type as the value");
+                }
+                if compare(min, max) == Some(Ordering::Greater) {
+                    return error("range minimum is greater than the maximum");
+                }
+                Ok(())
+            }
+            Self::NonZero => match target {
+                Some(ConstrainedType::Primitive(x)) if x != Primitive::Bool => Ok(()),
+                Some(ConstrainedType::Duration(_)) => Ok(()),
+                _ => error("only numeric types and durations can be non-zero"),
+            },
+            Self::NonEmpty | Self::MaxLength(_) | Self::Regex(_) => {
+                if target != Some(ConstrainedType::String) {
+                    return error("only strings can be constrained by their content");
+                }
+                match self {
+                    Self::MaxLength(0) => {
+                        error("a maximum length of zero only allows empty strings")
+                    }
+                    Self::Regex(pattern) => {
+                        if regex::Regex::new(pattern).is_err() {
+                            return error("regular expression does not compile");
+                        }
+                        match check_portable_regex(pattern) {
+                            Ok(()) => Ok(()),
+                            Err(reason) => error(reason),
+                        }
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+
+    /// check that a struct initializer default doesn't violate the constraint
+    pub(crate) fn allows_default(&self, value: &ValidatedDefaultValue) -> bool {
+        match (self, value) {
+            (Self::Range { min, max }, ValidatedDefaultValue::Number(x)) => {
+                compare(x, min) != Some(Ordering::Less)
+                    && compare(x, max) != Some(Ordering::Greater)
+            }
+            (Self::NonZero, ValidatedDefaultValue::Number(x)) => !is_zero(x),
+            (Self::NonZero, ValidatedDefaultValue::Duration(_, x)) => !x.is_zero(),
+            (Self::NonEmpty, ValidatedDefaultValue::String(x)) => !x.is_empty(),
+            (Self::MaxLength(max), ValidatedDefaultValue::String(x)) => x.len() <= *max,
+            (Self::Regex(_), ValidatedDefaultValue::String(x)) => {
+                match regex::Regex::new(&self.anchored_regex().unwrap()) {
+                    Ok(pattern) => pattern.is_match(x),
+                    Err(_) => false,
+                }
+            }
+            _ => true,
+        }
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Range { min, max } => write!(f, "must be in the range [{}, {}]", min, max),
+            Self::NonZero => write!(f, "must not be zero"),
+            Self::NonEmpty => write!(f, "must not be empty"),
+            Self::MaxLength(x) => write!(f, "must not exceed {} bytes when encoded as UTF-8", x),
+            Self::Regex(x) => write!(f, "must match the regular expression '{}'", x),
+        }
+    }
+}
+
+/// Describe a list of constraints in a sentence suitable for documentation
+pub(crate) fn describe_constraints(constraints: &[Constraint]) -> String {
+    constraints
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(" and ")
+}
+
+/// Escape a string for use in a double-quoted literal in C-family languages
+pub(crate) fn escape_string_literal(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Check that a regular expression only uses the portable subset documented on [`Constraint::Regex`]
+fn check_portable_regex(pattern: &str) -> Result<(), &'static str> {
+    const METACHARACTERS: &str = "\\.*+?()[]{}|^$-/";
+    const ESCAPE: &str =
+        "only metacharacters can be escaped, use a class such as [0-9] instead of \\d";
+    const GROUP: &str =
+        "only (...) and (?:...) groups are portable, not named groups, lookarounds or flags";
+    const ANCHOR: &str = "anchors are not portable, the pattern always matches the entire string";
+    const BRACES: &str =
+        "braces are only portable as quantifiers, escape them to match them literally";
+    const CLASS: &str = "nested or empty classes and class operations are not portable";
+
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(x) if METACHARACTERS.contains(x) => {}
+                _ => return Err(ESCAPE),
+            },
+            '(' if chars.peek() == Some(&'?') => {
+                chars.next();
+                if chars.next() != Some(':') {
+                    return Err(GROUP);
+                }
+            }
+            '^' | '$' => return Err(ANCHOR),
+            '{' => {
+                let mut quantifier = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(x) => quantifier.push(x),
+                        None => return Err(BRACES),
+                    }
+                }
+                let (min, max) = quantifier.split_once(',').unwrap_or((&quantifier, "0"));
+                let is_number = |x: &str| x.chars().all(|x| x.is_ascii_digit());
+                if min.is_empty() || !is_number(min) || !is_number(max) {
+                    return Err(BRACES);
+                }
+            }
+            '[' => {
+                if chars.peek() == Some(&'^') {
+                    chars.next();
+                }
+                let mut empty = true;
+                loop {
+                    match chars.next() {
+                        Some(']') if !empty => break,
+                        Some('\\') => match chars.next() {
+                            Some(x) if METACHARACTERS.contains(x) => {}
+                            _ => return Err(ESCAPE),
+                        },
+                        // Java and Rust nest classes and combine them with `&&`, `--` or `~~`
+                        Some('[') | Some(']') | None => return Err(CLASS),
+                        Some(x @ ('&' | '-' | '~')) if chars.peek() == Some(&x) => {
+                            return Err(CLASS)
+                        }
+                        Some(_) => {}
+                    }
+                    empty = false;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn primitive_of(value: &NumberValue) -> Primitive {
+    match value {
+        NumberValue::U8(_) => Primitive::U8,
+        NumberValue::S8(_) => Primitive::S8,
+        NumberValue::U16(_) => Primitive::U16,
+        NumberValue::S16(_) => Primitive::S16,
+        NumberValue::U32(_) => Primitive::U32,
+        NumberValue::S32(_) => Primitive::S32,
+        NumberValue::U64(_) => Primitive::U64,
+        NumberValue::S64(_) => Primitive::S64,
+        NumberValue::Float(_) => Primitive::Float,
+        NumberValue::Double(_) => Primitive::Double,
+    }
+}
+
+fn compare(lhs: &NumberValue, rhs: &NumberValue) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (NumberValue::U8(x), NumberValue::U8(y)) => x.partial_cmp(y),
+        (NumberValue::S8(x), NumberValue::S8(y)) => x.partial_cmp(y),
+        (NumberValue::U16(x), NumberValue::U16(y)) => x.partial_cmp(y),
+        (NumberValue::S16(x), NumberValue::S16(y)) => x.partial_cmp(y),
+        (NumberValue::U32(x), NumberValue::U32(y)) => x.partial_cmp(y),
+        (NumberValue::S32(x), NumberValue::S32(y)) => x.partial_cmp(y),
+        (NumberValue::U64(x), NumberValue::U64(y)) => x.partial_cmp(y),
+        (NumberValue::S64(x), NumberValue::S64(y)) => x.partial_cmp(y),
+        (NumberValue::Float(x), NumberValue::Float(y)) => x.partial_cmp(y),
+        (NumberValue::Double(x), NumberValue::Double(y)) => x.partial_cmp(y),
+        _ => None,
+    }
+}
+
+fn is_zero(value: &NumberValue) -> bool {
+    match value {
+        NumberValue::U8(x) => *x == 0,
+        NumberValue::S8(x) => *x == 0,
+        NumberValue::U16(x) => *x == 0,
+        NumberValue::S16(x) => *x == 0,
+        NumberValue::U32(x) => *x == 0,
+        NumberValue::S32(x) => *x == 0,
+        NumberValue::U64(x) => *x == 0,
+        NumberValue::S64(x) => *x == 0,
+        NumberValue::Float(x) => *x == 0.0,
+        NumberValue::Double(x) => *x == 0.0,
+    }
+}
+
+fn is_type_min(value: &NumberValue) -> bool {
+    match value {
+        NumberValue::U8(x) => *x == u8::MIN,
+        NumberValue::S8(x) => *x == i8::MIN,
+        NumberValue::U16(x) => *x == u16::MIN,
+        NumberValue::S16(x) => *x == i16::MIN,
+        NumberValue::U32(x) => *x == u32::MIN,
+        NumberValue::S32(x) => *x == i32::MIN,
+        NumberValue::U64(x) => *x == u64::MIN,
+        NumberValue::S64(x) => *x == i64::MIN,
+        NumberValue::Float(_) => false,
+        NumberValue::Double(_) => false,
+    }
+}
+
+fn is_type_max(value: &NumberValue) -> bool {
+    match value {
+        NumberValue::U8(x) => *x == u8::MAX,
+        NumberValue::S8(x) => *x == i8::MAX,
+        NumberValue::U16(x) => *x == u16::MAX,
+        NumberValue::S16(x) => *x == i16::MAX,
+        NumberValue::U32(x) => *x == u32::MAX,
+        NumberValue::S32(x) => *x == i32::MAX,
+        NumberValue::U64(x) => *x == u64::MAX,
+        NumberValue::S64(x) => *x == i64::MAX,
+        NumberValue::Float(_) => false,
+        NumberValue::Double(_) => false,
+    }
+}
+
+impl BasicType {
+    pub(crate) fn constrained_type(&self) -> Option<ConstrainedType> {
+        match self {
+            BasicType::Primitive(x) => Some(ConstrainedType::Primitive(*x)),
+            BasicType::Duration(x) => Some(ConstrainedType::Duration(*x)),
+            BasicType::Enum(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_regex(pattern: &str) -> BindResult<()> {
+        Constraint::regex(pattern).check_applies_to(
+            &Name::create("value").unwrap(),
+            Some(ConstrainedType::String),
+        )
+    }
+
+    #[test]
+    fn accepts_portable_regular_expressions() {
+        for pattern in [
+            "[a-z_][a-z0-9_]*",
+            "(?:ab|c)+\\.[0-9]{2,4}",
+            "(x|y){3}z{1,}?",
+            "[^\\]-]*\\$",
+        ] {
+            assert!(check_regex(pattern).is_ok(), "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn rejects_regular_expressions_outside_of_the_portable_subset() {
+        for pattern in [
+            "(?P<n>a)",
+            "(?i)a",
+            "(?=a)b",
+            "\\p{L}+",
+            "\\d+",
+            "^a",
+            "a$",
+            "a{,2}",
+            "[[:alpha:]]",
+            "[a-z&&[^x]]",
+            "[]a]",
+        ] {
+            assert!(check_regex(pattern).is_err(), "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn anchored_regex_does_not_accept_a_trailing_newline() {
+        let constraint = Constraint::regex("[a-z]+");
+        assert_eq!(constraint.anchored_regex().unwrap(), "\\A(?:[a-z]+)\\z");
+        assert!(constraint.allows_default(&ValidatedDefaultValue::String("abc".to_string())));
+        assert!(!constraint.allows_default(&ValidatedDefaultValue::String("abc\n".to_string())));
+    }
+}
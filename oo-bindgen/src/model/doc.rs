@@ -10,6 +10,14 @@
 //! build a more complex documentation. The `doc()` functions takes the brief string as a parameter, then can be
 //! chained with `details()` to add details paragraph or with `warning()` to add a warning paragraph.
 //!
+//! The version of the library that introduced an item is recorded with `since()`, it cannot be after the version
+//! of the library.
+//!
+//! Code examples are added with `example()`, which takes an `Example` holding a snippet for each target language.
+//! Each generator only prints the snippet of its own language, and skips the example if there is none. The snippets
+//! can also be extracted into source files with the `--extract-examples` option of the generator, so that they are
+//! compiled along with the tests of the bindings.
+//!
 //! For parameters, only a `DocString` is accepted, because some generators do not support paragraphs for parameters.
 //!
 //! ### References
@@ -38,6 +46,16 @@
 //! There other miscellaneous tag that can be used:
 //! - `{null}`: prints `NULL` in C, or `null` in C# and Java.
 //! - `{iterator}`: prints `iterator` in C, or `collection` in C# and Java.
+//!
+//! ### Markup
+//!
+//! A `DocString` also accepts a small subset of Markdown that each generator renders with the native syntax of its
+//! documentation tool:
+//! - `` `code` ``: inline code.
+//! - `**text**`: bold text.
+//! - `[text](https://example.com)`: hyperlink to an external URL. The URL must be an absolute `http` or `https` URL,
+//!   it is checked when creating the library.
+//! - Lines starting with `- `: bullet list, each line is an item that can contain references and inline markup.
 
 use std::convert::TryFrom;
 use std::fmt::Debug;
@@ -53,6 +71,8 @@ pub fn doc<D: Into<DocString<Unvalidated>>>(brief: D) -> Doc<Unvalidated> {
     Doc {
         brief: brief.into(),
         details: Vec::new(),
+        deprecated: None,
+        since: None,
     }
 }
 
@@ -68,6 +88,8 @@ pub fn brief(text: &str) -> Doc<Validated> {
             elements: vec![DocStringElement::Text(text.to_string())],
         },
         details: Vec::new(),
+        deprecated: None,
+        since: None,
     }
 }
 
@@ -78,6 +100,9 @@ where
 {
     pub(crate) brief: DocString<T>,
     pub(crate) details: Vec<DocParagraph<T>>,
+    pub(crate) deprecated: Option<Deprecation<T>>,
+    /// version of the library that introduced the documented item
+    pub(crate) since: Option<Version>,
 }
 
 impl Doc<Validated> {
@@ -108,9 +133,25 @@ impl Doc<Unvalidated> {
             .iter()
             .map(|x| x.validate_with_args(symbol_name, lib, args))
             .collect();
+        let deprecated = match &self.deprecated {
+            Some(x) => Some(x.validate(symbol_name, lib, args)?),
+            None => None,
+        };
+        if let Some(since) = &self.since {
+            if *since > lib.version {
+                return Err(BindingErrorVariant::DocSinceAfterLibraryVersion {
+                    symbol_name: symbol_name.clone(),
+                    since: since.to_string(),
+                    version: lib.version.to_string(),
+                }
+                .into());
+            }
+        }
         Ok(Doc {
             brief: self.brief.validate_with_args(symbol_name, lib, args)?,
             details: details?,
+            deprecated,
+            since: self.since.clone(),
         })
     }
 
@@ -125,6 +166,48 @@ impl Doc<Unvalidated> {
         self.details.push(DocParagraph::Details(details.into()));
         self
     }
+
+    #[must_use]
+    pub fn example(mut self, example: Example) -> Self {
+        self.details.push(DocParagraph::Example(example));
+        self
+    }
+
+    /// Version of the library that introduced the documented item
+    #[must_use]
+    pub fn since(mut self, version: Version) -> Self {
+        self.since = Some(version);
+        self
+    }
+
+    /// Used by the builders to attach the deprecation of the documented item
+    pub(crate) fn set_deprecated(
+        &mut self,
+        symbol_name: &Name,
+        deprecation: Deprecation<Unvalidated>,
+    ) -> BindResult<()> {
+        if self.deprecated.is_some() {
+            return Err(BindingErrorVariant::DeprecationAlreadyDefined {
+                symbol_name: symbol_name.clone(),
+            }
+            .into());
+        }
+        self.deprecated = Some(deprecation);
+        Ok(())
+    }
+}
+
+impl<T> Doc<T>
+where
+    T: DocReference,
+{
+    /// Code examples of the documentation
+    pub(crate) fn examples(&self) -> impl Iterator<Item = &Example> {
+        self.details.iter().filter_map(|x| match x {
+            DocParagraph::Example(x) => Some(x),
+            _ => None,
+        })
+    }
 }
 
 impl<T: AsRef<str>> From<T> for Doc<Unvalidated> {
@@ -171,6 +254,59 @@ impl OptionalDoc {
     }
 }
 
+/// Deprecation of a function, method, struct field, enum variant or callback
+///
+/// The note usually refers to the replacement with a reference, e.g. `use {class:foo.bar()} instead`.
+/// Each generator prints it in the documentation with the reference resolved, and as the message
+/// of the deprecation attribute of its language with the reference named as in that language.
+#[derive(Debug, Clone)]
+pub(crate) struct Deprecation<T>
+where
+    T: DocReference,
+{
+    pub(crate) since: Version,
+    pub(crate) note: DocString<T>,
+}
+
+impl Deprecation<Unvalidated> {
+    pub(crate) fn new<D: Into<DocString<Unvalidated>>>(since: Version, note: D) -> Self {
+        Self {
+            since,
+            note: note.into(),
+        }
+    }
+
+    fn validate(
+        &self,
+        symbol_name: &Name,
+        lib: &LibraryFields,
+        args: Option<&[Name]>,
+    ) -> BindResult<Deprecation<Validated>> {
+        Ok(Deprecation {
+            since: self.since.clone(),
+            note: self.note.validate_with_args(symbol_name, lib, args)?,
+        })
+    }
+}
+
+impl Deprecation<Validated> {
+    /// Note as plain text for the deprecation attributes, which cannot hold links
+    pub(crate) fn plain_note(&self) -> String {
+        self.note.plain_text()
+    }
+
+    /// Plain text message for the deprecation attributes that do not have a version field
+    ///
+    /// The references are named by the `reference` function of the language of the attribute.
+    pub(crate) fn message(&self, reference: &dyn Fn(&Validated) -> String) -> String {
+        format!(
+            "Deprecated since {}: {}",
+            self.since,
+            self.note.plain_text_with(reference)
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum DocParagraph<T>
 where
@@ -178,6 +314,7 @@ where
 {
     Details(DocString<T>),
     Warning(DocString<T>),
+    Example(Example),
 }
 
 impl DocParagraph<Unvalidated> {
@@ -194,11 +331,124 @@ impl DocParagraph<Unvalidated> {
             DocParagraph::Warning(x) => {
                 DocParagraph::Warning(x.validate_with_args(symbol_name, lib, args)?)
             }
+            DocParagraph::Example(x) => {
+                if x.snippets.is_empty() {
+                    return Err(BindingErrorVariant::DocEmptyExample {
+                        symbol_name: symbol_name.clone(),
+                    }
+                    .into());
+                }
+                DocParagraph::Example(x.clone())
+            }
         })
     }
 }
 
+impl<T> DocParagraph<T>
+where
+    T: DocReference,
+{
+    /// Examples are only printed in the documentation of the languages they have a snippet for
+    pub(crate) fn is_printed_in(&self, language: Option<ExampleLanguage>) -> bool {
+        match self {
+            DocParagraph::Example(x) => match language {
+                Some(language) => x.get(language).is_some(),
+                None => false,
+            },
+            _ => true,
+        }
+    }
+}
+
+/// Target language of a code snippet
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExampleLanguage {
+    C,
+    Cpp,
+    Java,
+    DotNet,
+}
+
+/// Create an empty code example, the snippets are added with the builder methods
+pub fn example() -> Example {
+    Example {
+        snippets: Vec::new(),
+    }
+}
+
+/// Code example with a snippet for some of the target languages
 #[derive(Debug, Clone)]
+pub struct Example {
+    pub(crate) snippets: Vec<(ExampleLanguage, String)>,
+}
+
+impl Example {
+    /// Set the snippet of a language, replacing the previous one
+    ///
+    /// The common indentation of the lines and the leading and trailing blank lines are removed, so the code
+    /// may be written in an indented raw string.
+    #[must_use]
+    pub fn snippet<S: AsRef<str>>(mut self, language: ExampleLanguage, code: S) -> Self {
+        let code = dedent(code.as_ref());
+        match self.snippets.iter_mut().find(|(x, _)| *x == language) {
+            Some((_, existing)) => *existing = code,
+            None => self.snippets.push((language, code)),
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn c<S: AsRef<str>>(self, code: S) -> Self {
+        self.snippet(ExampleLanguage::C, code)
+    }
+
+    #[must_use]
+    pub fn cpp<S: AsRef<str>>(self, code: S) -> Self {
+        self.snippet(ExampleLanguage::Cpp, code)
+    }
+
+    #[must_use]
+    pub fn java<S: AsRef<str>>(self, code: S) -> Self {
+        self.snippet(ExampleLanguage::Java, code)
+    }
+
+    #[must_use]
+    pub fn dotnet<S: AsRef<str>>(self, code: S) -> Self {
+        self.snippet(ExampleLanguage::DotNet, code)
+    }
+
+    /// Snippet of a language, if the example has one
+    pub(crate) fn get(&self, language: ExampleLanguage) -> Option<&str> {
+        self.snippets
+            .iter()
+            .find(|(x, _)| *x == language)
+            .map(|(_, code)| code.as_str())
+    }
+}
+
+fn dedent(code: &str) -> String {
+    let lines: Vec<&str> = code.lines().skip_while(|x| x.trim().is_empty()).collect();
+    let end = lines
+        .iter()
+        .rposition(|x| !x.trim().is_empty())
+        .map_or(0, |x| x + 1);
+    let lines = &lines[..end];
+
+    let indent = lines
+        .iter()
+        .filter(|x| !x.trim().is_empty())
+        .map(|x| x.len() - x.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|x| x.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DocString<T>
 where
     T: DocReference,
@@ -232,6 +482,36 @@ impl DocString<Unvalidated> {
     }
 }
 
+impl DocString<Validated> {
+    /// Text without any markup, references are spelled as in the schema
+    pub(crate) fn plain_text(&self) -> String {
+        self.plain_text_with(&Validated::schema_name)
+    }
+
+    /// Text without any markup, references are named by the `reference` function
+    pub(crate) fn plain_text_with(&self, reference: &dyn Fn(&Validated) -> String) -> String {
+        let mut result = String::new();
+        for element in self.elements() {
+            match element {
+                DocStringElement::Text(x) => result.push_str(x),
+                DocStringElement::Null => result.push_str("null"),
+                DocStringElement::Iterator => result.push_str("iterator"),
+                DocStringElement::Reference(x) => result.push_str(&reference(x)),
+                DocStringElement::Code(x) | DocStringElement::Bold(x) => result.push_str(x),
+                DocStringElement::Link { text, url } => {
+                    result.push_str(&format!("{} ({})", text, url))
+                }
+                DocStringElement::List(items) => {
+                    let items: Vec<String> =
+                        items.iter().map(|x| x.plain_text_with(reference)).collect();
+                    result.push_str(&items.join(", "))
+                }
+            }
+        }
+        result
+    }
+}
+
 impl<T> DocString<T>
 where
     T: DocReference,
@@ -262,34 +542,109 @@ where
 
 impl<U: AsRef<str>> From<U> for DocString<Unvalidated> {
     fn from(from: U) -> DocString<Unvalidated> {
-        let mut from = from.as_ref();
         let mut result = DocString::new();
-        while let Some(start_idx) = from.find('{') {
-            let (before_str, current_str) = from.split_at(start_idx);
-            if let Some(end_idx) = current_str.find('}') {
-                let (element_str, current_str) = current_str.split_at(end_idx + 1);
-                let element = DocStringElement::try_from(element_str)
-                    .expect("Invalid docstring: ill-formatted docstring element");
-
-                if !before_str.is_empty() {
-                    result.push(DocStringElement::Text(before_str.to_owned()));
+        let mut text: Vec<&str> = Vec::new();
+        let mut items: Vec<DocString<Unvalidated>> = Vec::new();
+
+        // consecutive lines starting with a dash form a bullet list
+        for line in from.as_ref().split('\n') {
+            match line.trim_start().strip_prefix("- ") {
+                Some(item) => {
+                    if !text.is_empty() {
+                        parse_inline(&text.join("\n"), &mut result);
+                        text.clear();
+                    }
+                    let mut item_doc = DocString::new();
+                    parse_inline(item, &mut item_doc);
+                    items.push(item_doc);
+                }
+                None => {
+                    if !items.is_empty() {
+                        result.push(DocStringElement::List(std::mem::take(&mut items)));
+                    }
+                    text.push(line);
                 }
-                result.push(element);
-                from = current_str;
-            } else {
-                panic!("Invalid docstring: no end bracket");
             }
         }
 
-        // Add remaining string
-        if !from.is_empty() {
-            result.push(DocStringElement::Text(from.to_owned()));
+        if !text.is_empty() {
+            parse_inline(&text.join("\n"), &mut result);
+        }
+        if !items.is_empty() {
+            result.push(DocStringElement::List(items));
         }
 
         result
     }
 }
 
+/// Parse the references and the inline markup of some text
+fn parse_inline(from: &str, result: &mut DocString<Unvalidated>) {
+    let mut text = String::new();
+    let mut current = from;
+    while let Some(c) = current.chars().next() {
+        match parse_markup(current) {
+            Some((element, len)) => {
+                if !text.is_empty() {
+                    result.push(DocStringElement::Text(std::mem::take(&mut text)));
+                }
+                result.push(element);
+                current = &current[len..];
+            }
+            None => {
+                text.push(c);
+                current = &current[c.len_utf8()..];
+            }
+        }
+    }
+
+    // Add remaining string
+    if !text.is_empty() {
+        result.push(DocStringElement::Text(text));
+    }
+}
+
+/// Parse the markup at the start of the text, returning the element and its length
+///
+/// A backtick or double asterisk without a closing one is not markup, e.g. `2**n`, so it stays plain text.
+fn parse_markup(from: &str) -> Option<(DocStringElement<Unvalidated>, usize)> {
+    lazy_static! {
+        static ref RE_LINK: Regex = Regex::new(r"^\[([^\]]+)\]\(([^)\s]+)\)").unwrap();
+    }
+
+    if from.starts_with('{') {
+        let end_idx = from.find('}').expect("Invalid docstring: no end bracket");
+        let element = DocStringElement::try_from(&from[..=end_idx])
+            .expect("Invalid docstring: ill-formatted docstring element");
+        return Some((element, end_idx + 1));
+    }
+    if let Some(code) = from.strip_prefix('`') {
+        let end_idx = code.find('`')?;
+        return Some((
+            DocStringElement::Code(code[..end_idx].to_owned()),
+            end_idx + 2,
+        ));
+    }
+    if let Some(bold) = from.strip_prefix("**") {
+        let end_idx = bold.find("**")?;
+        return Some((
+            DocStringElement::Bold(bold[..end_idx].to_owned()),
+            end_idx + 4,
+        ));
+    }
+    if let Some(capture) = RE_LINK.captures(from) {
+        return Some((
+            DocStringElement::Link {
+                text: capture[1].to_owned(),
+                url: capture[2].to_owned(),
+            },
+            capture[0].len(),
+        ));
+    }
+
+    None
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum DocStringElement<T>
 where
@@ -299,6 +654,17 @@ where
     Null,
     Iterator,
     Reference(T),
+    /// Inline code, written between backticks
+    Code(String),
+    /// Bold text, written between double asterisks
+    Bold(String),
+    /// Hyperlink to an external URL, written as `[text](url)`
+    Link {
+        text: String,
+        url: String,
+    },
+    /// Bullet list, each item is written on its own line starting with `- `
+    List(Vec<DocString<T>>),
 }
 
 impl DocStringElement<Unvalidated> {
@@ -315,6 +681,34 @@ impl DocStringElement<Unvalidated> {
             DocStringElement::Reference(x) => {
                 DocStringElement::Reference(x.validate(symbol_name, lib, args)?)
             }
+            DocStringElement::Code(x) => DocStringElement::Code(x.clone()),
+            DocStringElement::Bold(x) => DocStringElement::Bold(x.clone()),
+            DocStringElement::Link { text, url } => {
+                lazy_static! {
+                    static ref RE_URL: Regex = Regex::new(
+                        r"^https?://[[:alnum:]-]+(\.[[:alnum:]-]+)*(:[0-9]+)?([/?#][^\s]*)?$"
+                    )
+                    .unwrap();
+                }
+                if !RE_URL.is_match(url) {
+                    return Err(BindingErrorVariant::DocInvalidUrl {
+                        symbol_name: symbol_name.clone(),
+                        url: url.clone(),
+                    }
+                    .into());
+                }
+                DocStringElement::Link {
+                    text: text.clone(),
+                    url: url.clone(),
+                }
+            }
+            DocStringElement::List(items) => {
+                let items: BindResult<Vec<DocString<Validated>>> = items
+                    .iter()
+                    .map(|x| x.validate_with_args(symbol_name, lib, args))
+                    .collect();
+                DocStringElement::List(items?)
+            }
         })
     }
 }
@@ -543,6 +937,35 @@ pub enum Validated {
 
 impl DocReference for Validated {}
 
+impl Validated {
+    /// Name of the referenced item as written in the schema
+    pub(crate) fn schema_name(&self) -> String {
+        match self {
+            Validated::Argument(name) => name.as_ref().to_string(),
+            Validated::Class(class) => class.name.as_ref().to_string(),
+            Validated::ClassMethod(class, method, _) => {
+                format!("{}.{}()", class.name().as_ref(), method.as_ref())
+            }
+            Validated::ClassConstructor(class, _) => {
+                format!("{} constructor", class.name().as_ref())
+            }
+            Validated::ClassDestructor(class, _) => format!("{} destructor", class.name().as_ref()),
+            Validated::Struct(st) => st.name().as_ref().to_string(),
+            Validated::StructField(st, field) => {
+                format!("{}.{}", st.name().as_ref(), field.as_ref())
+            }
+            Validated::Enum(handle) => handle.name.as_ref().to_string(),
+            Validated::EnumVariant(handle, variant) => {
+                format!("{}.{}", handle.name.as_ref(), variant.as_ref())
+            }
+            Validated::Interface(handle) => handle.name.as_ref().to_string(),
+            Validated::InterfaceMethod(handle, callback) => {
+                format!("{}.{}()", handle.name.as_ref(), callback.as_ref())
+            }
+        }
+    }
+}
+
 impl TryFrom<&str> for DocStringElement<Unvalidated> {
     type Error = BindingError;
 
@@ -843,8 +1266,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_inline_markup() {
+        let doc: DocString<Unvalidated> =
+            "Call `run()` **once**, see [the guide](https://example.com/guide).".into();
+        assert_eq!(
+            [
+                DocStringElement::Text("Call ".to_owned()),
+                DocStringElement::Code("run()".to_owned()),
+                DocStringElement::Text(" ".to_owned()),
+                DocStringElement::Bold("once".to_owned()),
+                DocStringElement::Text(", see ".to_owned()),
+                DocStringElement::Link {
+                    text: "the guide".to_owned(),
+                    url: "https://example.com/guide".to_owned()
+                },
+                DocStringElement::Text(".".to_owned()),
+            ]
+            .as_ref(),
+            doc.elements.as_slice()
+        );
+    }
+
+    #[test]
+    fn brackets_without_url_are_text() {
+        let doc: DocString<Unvalidated> = "Value in [0, 10] * 2".into();
+        assert_eq!(
+            [DocStringElement::Text("Value in [0, 10] * 2".to_owned())].as_ref(),
+            doc.elements.as_slice()
+        );
+    }
+
+    #[test]
+    fn unmatched_markup_is_text() {
+        let doc: DocString<Unvalidated> = "Holds up to 2**n values, see `size".into();
+        assert_eq!(
+            [DocStringElement::Text(
+                "Holds up to 2**n values, see `size".to_owned()
+            )]
+            .as_ref(),
+            doc.elements.as_slice()
+        );
+    }
+
+    #[test]
+    fn parse_list() {
+        let doc: DocString<Unvalidated> = "Modes:\n- `fast`\n- {null} to disable\nDone".into();
+        assert_eq!(
+            [
+                DocStringElement::Text("Modes:".to_owned()),
+                DocStringElement::List(vec![
+                    DocString {
+                        elements: vec![DocStringElement::Code("fast".to_owned())]
+                    },
+                    DocString {
+                        elements: vec![
+                            DocStringElement::Null,
+                            DocStringElement::Text(" to disable".to_owned())
+                        ]
+                    },
+                ]),
+                DocStringElement::Text("Done".to_owned()),
+            ]
+            .as_ref(),
+            doc.elements.as_slice()
+        );
+    }
+
+    #[test]
+    fn validates_link_urls() {
+        let lib = LibraryFields::new(Version::new(1, 0, 0));
+        let name = Name::create("foo").unwrap();
+        let valid: DocString<Unvalidated> = "[docs](https://example.com:8080/a?b=c#d)".into();
+        assert!(valid.validate(&name, &lib).is_ok());
+        let invalid: DocString<Unvalidated> = "[docs](example.com)".into();
+        assert_eq!(
+            invalid.validate(&name, &lib).err().unwrap().to_string(),
+            "Documentation of 'foo' links to 'example.com' which is not a valid URL"
+        );
+    }
+
     #[test]
     fn parse_from_owned_string() {
         doc(format!("{{null}} this is a {}", "test"));
     }
+
+    #[test]
+    fn example_snippets_are_dedented() {
+        let example = example()
+            .c("\n        if (x) {\n            run();\n        }\n    ")
+            .java("first();")
+            .java("second();");
+        assert_eq!(
+            example.get(ExampleLanguage::C),
+            Some("if (x) {\n    run();\n}")
+        );
+        assert_eq!(example.get(ExampleLanguage::Java), Some("second();"));
+        assert_eq!(example.get(ExampleLanguage::Cpp), None);
+    }
 }
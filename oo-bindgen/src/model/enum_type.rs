@@ -25,7 +25,7 @@ impl EnumVariant<Unvalidated> {
 pub type EnumHandle = Handle<Enum<Unvalidated>>;
 
 impl Handle<Enum<Unvalidated>> {
-    pub fn value(&self, name: &'static str) -> BindResult<EnumValue> {
+    pub fn value(&self, name: &str) -> BindResult<EnumValue> {
         EnumValue::new(self.clone(), name)
     }
 }
@@ -36,6 +36,7 @@ where
     T: DocReference,
 {
     pub(crate) name: Name,
+    pub(crate) module: Module,
     pub(crate) settings: Rc<LibrarySettings>,
     pub(crate) variants: Vec<EnumVariant<T>>,
     pub(crate) doc: Doc<T>,
@@ -48,6 +49,7 @@ impl Enum<Unvalidated> {
 
         Ok(Handle::new(Enum {
             name: self.name.clone(),
+            module: self.module.clone(),
             settings: self.settings.clone(),
             variants: variants?,
             doc: self.doc.validate(&self.name, lib)?,
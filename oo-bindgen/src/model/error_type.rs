@@ -21,6 +21,8 @@ where
     pub(crate) exception_name: Name,
     pub(crate) exception_type: ExceptionType,
     pub(crate) inner: Handle<Enum<D>>,
+    /// variant reserved for arguments that violate their constraints
+    pub(crate) invalid_argument: Option<Name>,
 }
 
 impl ErrorType<Unvalidated> {
@@ -34,10 +36,21 @@ impl ErrorType<Unvalidated> {
             exception_name: self.exception_name.clone(),
             exception_type: self.exception_type,
             inner: self.inner.validate(lib)?,
+            invalid_argument: self.invalid_argument.clone(),
         })
     }
 }
 
+impl<D> ErrorType<D>
+where
+    D: DocReference,
+{
+    /// Variant returned when an argument violates one of its constraints
+    pub fn invalid_argument_variant(&self) -> Option<&Name> {
+        self.invalid_argument.as_ref()
+    }
+}
+
 pub type ErrorTypeHandle = ErrorType<Unvalidated>;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
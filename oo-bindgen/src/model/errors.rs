@@ -24,6 +24,42 @@ impl std::fmt::Display for BindingError {
 
 impl std::error::Error for BindingError {}
 
+impl BindingError {
+    #[cfg(test)]
+    pub(crate) fn variant(&self) -> &BindingErrorVariant {
+        &self.inner
+    }
+
+    /// Prefix the location of the error within a schema file
+    pub(crate) fn within(self, keys: &[crate::schema::location::SchemaKey]) -> Self {
+        match self.inner {
+            BindingErrorVariant::SchemaAt { path, inner } => BindingErrorVariant::SchemaAt {
+                path: path.prefixed(keys),
+                inner,
+            },
+            inner => BindingErrorVariant::SchemaAt {
+                path: crate::schema::location::SchemaPath::new(keys),
+                inner: Box::new(inner.into()),
+            },
+        }
+        .into()
+    }
+
+    /// Replace the location of the error with the source it points to
+    pub(crate) fn with_source<F>(self, snippet: F) -> Self
+    where
+        F: FnOnce(&crate::schema::location::SchemaPath) -> Option<String>,
+    {
+        match self.inner {
+            BindingErrorVariant::SchemaAt { path, inner } => match snippet(&path) {
+                Some(snippet) => BindingErrorVariant::SchemaSource { inner, snippet }.into(),
+                None => BindingErrorVariant::SchemaAt { path, inner }.into(),
+            },
+            inner => inner.into(),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub(crate) enum BindingErrorVariant {
     // ---------------- global errors -----------------------------------
@@ -34,6 +70,23 @@ pub(crate) enum BindingErrorVariant {
     // ---------------- name errors -----------------------------------
     #[error("'{}'", err)]
     BadName { err: BadName },
+    #[error("Acronym '{}' must be a single word", acronym)]
+    AcronymNotSingleWord { acronym: Name },
+    #[error("'{}' was already renamed in {}", name, language)]
+    NameAlreadyRenamed {
+        name: Name,
+        language: TargetLanguage,
+    },
+    #[error(
+        "'{}' cannot be renamed in C, whose names are the names of the FFI",
+        name
+    )]
+    RenameInC { name: Name },
+    // ---------------- module errors ---------------------------------
+    #[error("Module '{}' is not defined in this library", module)]
+    ModuleNotDefined { module: Module },
+    #[error("Module '{}' has the name of the library", name)]
+    ModuleNamedAfterLibrary { name: Name },
     // ---------------- documentation errors --------------------------
     #[error("Documentation of '{}' was already defined", symbol_name)]
     DocAlreadyDefined { symbol_name: Name },
@@ -57,9 +110,35 @@ pub(crate) enum BindingErrorVariant {
         symbol_name: String,
         ref_name: String,
     },
+    #[error(
+        "Documentation of '{}' contains an example without any code",
+        symbol_name
+    )]
+    DocEmptyExample { symbol_name: Name },
+    #[error("Deprecation of '{}' was already defined", symbol_name)]
+    DeprecationAlreadyDefined { symbol_name: Name },
+    #[error("Deprecation added to '{}' before any field or variant", parent)]
+    DeprecationWithoutTarget { parent: Name },
+    #[error(
+        "Documentation of '{}' says it was introduced in {} which is after the library version {}",
+        symbol_name,
+        since,
+        version
+    )]
+    DocSinceAfterLibraryVersion {
+        symbol_name: Name,
+        since: String,
+        version: String,
+    },
     // Documentation error
     #[error("Invalid documentation string")]
     InvalidDocString,
+    #[error(
+        "Documentation of '{}' links to '{}' which is not a valid URL",
+        symbol_name,
+        url
+    )]
+    DocInvalidUrl { symbol_name: Name, url: String },
     // ---------------- class definition errors -----------------------
     #[error("Class '{}' was already defined", handle.name)]
     ClassAlreadyDefined { handle: ClassDeclarationHandle },
@@ -80,6 +159,17 @@ pub(crate) enum BindingErrorVariant {
     },
     #[error("No destructor defined for class '{}', but asking for manual/disposable destruction", handle.name)]
     NoDestructorForManualDestruction { handle: ClassDeclarationHandle },
+    #[error(
+        "Overloads of method '{}' in '{}' cannot be distinguished by their parameters in {}",
+        method,
+        parent,
+        language
+    )]
+    OverloadNotDistinguishable {
+        parent: Name,
+        method: Name,
+        language: OverloadLanguage,
+    },
     // ----------------- constant definition errors -------------------
     #[error(
         "ConstantSet '{}' already contains constant name  '{}'",
@@ -111,6 +201,23 @@ pub(crate) enum BindingErrorVariant {
         error_type
     )]
     ErrorTypeAlreadyDefined { function: Name, error_type: Name },
+    #[error(
+        "Parameter '{}' of function '{}' must have a default value because it follows a parameter with a default value",
+        param,
+        function
+    )]
+    ParamWithoutDefaultAfterDefault { function: Name, param: Name },
+    #[error(
+        "Default value of parameter '{}' in function '{}' violates constraint: {}",
+        param,
+        function,
+        constraint
+    )]
+    ParamDefaultViolatesConstraint {
+        function: Name,
+        param: Name,
+        constraint: Constraint,
+    },
     // ----------------- interface errors -------------------
     #[error(
         "Symbol '{}' is reserved and cannot be used as an interface method name",
@@ -136,19 +243,13 @@ pub(crate) enum BindingErrorVariant {
         name,
         struct_name
     )]
-    InitializerDoesNotExist {
-        name: &'static str,
-        struct_name: Name,
-    },
+    InitializerDoesNotExist { name: String, struct_name: Name },
     #[error(
         "Initializer '{}' within struct '{}' is not parameterless",
         name,
         struct_name
     )]
-    InitializerNotParameterless {
-        name: &'static str,
-        struct_name: Name,
-    },
+    InitializerNotParameterless { name: String, struct_name: Name },
 
     // ----------------- struct errors -------------------
     #[error("Native struct '{}' was already defined", handle.name)]
@@ -201,6 +302,68 @@ pub(crate) enum BindingErrorVariant {
         this_initializer: Name,
         other_initializer: Name,
     },
+    #[error(
+        "Struct '{}' has value semantics but field '{}' cannot be compared by value",
+        struct_name,
+        field_name
+    )]
+    StructValueSemanticsUnsupportedField { struct_name: Name, field_name: Name },
+    #[error(
+        "Default value of field '{}' in struct '{}' violates constraint: {}",
+        field_name,
+        struct_name,
+        constraint
+    )]
+    StructInitializerDefaultViolatesConstraint {
+        struct_name: Name,
+        field_name: Name,
+        constraint: Constraint,
+    },
+    // ----------------- constraint errors -------------------
+    #[error(
+        "Constraint {:?} cannot be applied to '{}': {}",
+        constraint,
+        name,
+        reason
+    )]
+    InvalidConstraint {
+        name: Name,
+        constraint: Constraint,
+        reason: &'static str,
+    },
+    #[error("Constraint added to '{}' before any field or parameter", parent)]
+    ConstraintWithoutTarget { parent: Name },
+    #[error("Error type '{}' already defines an invalid argument variant", name)]
+    InvalidArgumentErrorAlreadyDefined { name: Name },
+    #[error(
+        "Function '{}' has constrained arguments but no error type with an invalid argument variant",
+        function
+    )]
+    ConstrainedFunctionWithoutInvalidArgumentError { function: Name },
+    // ----------------- schema errors -------------------
+    #[error("Unable to read schema '{}': {}", path.display(), message)]
+    SchemaRead {
+        path: std::path::PathBuf,
+        message: String,
+    },
+    #[error("Schema version '{}' is not a valid semantic version", version)]
+    SchemaBadVersion { version: String },
+    #[error("Schema type '{}' cannot be used as {}", name, usage)]
+    SchemaUnknownType { name: String, usage: &'static str },
+    #[error("Schema value {} is not valid for type '{}'", value, type_name)]
+    SchemaBadValue { value: String, type_name: String },
+    #[error("Schema item '{}' is invalid: {}", name, reason)]
+    SchemaInvalidItem { name: String, reason: &'static str },
+    #[error("{} (at {})", inner, path)]
+    SchemaAt {
+        path: crate::schema::location::SchemaPath,
+        inner: Box<BindingError>,
+    },
+    #[error("{}\n{}", inner, snippet)]
+    SchemaSource {
+        inner: Box<BindingError>,
+        snippet: String,
+    },
 }
 
 impl From<BadName> for BindingError {
@@ -203,6 +203,58 @@ impl From<CollectionClassDeclaration> for FunctionArgument {
     }
 }
 
+impl FunctionArgument {
+    /// type used to check if a constraint may be applied to the argument
+    pub(crate) fn constrained_type(&self) -> Option<ConstrainedType> {
+        match self {
+            FunctionArgument::Basic(x) => x.constrained_type(),
+            FunctionArgument::String(_) => Some(ConstrainedType::String),
+            FunctionArgument::Collection(_) => None,
+            FunctionArgument::Struct(_) => None,
+            FunctionArgument::StructRef(_) => None,
+            FunctionArgument::ClassRef(_) => None,
+            FunctionArgument::Interface(_) => None,
+        }
+    }
+
+    /// true if the argument is a struct passed by value with constrained fields
+    pub(crate) fn has_nested_constraints(&self) -> bool {
+        match self {
+            FunctionArgument::Struct(x) => x.has_constraints(),
+            _ => false,
+        }
+    }
+}
+
+impl InitializerValidator for FunctionArgument {
+    fn validate_default_value(
+        &self,
+        value: &InitializerDefault,
+    ) -> BindResult<ValidatedDefaultValue> {
+        match self {
+            FunctionArgument::Basic(x) => x.validate_default_value(value),
+            FunctionArgument::String(x) => x.validate_default_value(value),
+            FunctionArgument::Collection(x) => x.validate_default_value(value),
+            FunctionArgument::Struct(x) => x.validate_default_value(value),
+            FunctionArgument::StructRef(_) => {
+                Self::bad_initializer_value("StructRef".to_string(), value)
+            }
+            FunctionArgument::ClassRef(x) => x.validate_default_value(value),
+            FunctionArgument::Interface(x) => x.validate_default_value(value),
+        }
+    }
+}
+
+impl<D> Arg<FunctionArgument, D>
+where
+    D: DocReference,
+{
+    /// true if the argument or the fields of a struct argument have constraints
+    pub(crate) fn has_constraints(&self) -> bool {
+        !self.constraints.is_empty() || self.arg_type.has_nested_constraints()
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum FunctionCategory {
     Native,
@@ -220,6 +272,7 @@ where
 {
     pub(crate) name: Name,
     pub(crate) category: FunctionCategory,
+    pub(crate) module: Module,
     pub(crate) return_type: OptionalReturnType<FunctionReturnValue, T>,
     pub(crate) arguments: Vec<Arg<FunctionArgument, T>>,
     pub(crate) error_type: OptionalErrorType<T>,
@@ -237,6 +290,7 @@ impl Function<Unvalidated> {
         Ok(Handle::new(Function {
             name: self.name.clone(),
             category: self.category,
+            module: self.module.clone(),
             return_type: self.return_type.validate(&self.name, lib)?,
             arguments: parameters?,
             error_type: self.error_type.validate(lib)?,
@@ -350,7 +404,7 @@ impl ClassDestructor<Unvalidated> {
             .param(
                 instance_name,
                 class.clone(),
-                format!("Instance of {{class:{}}} to destroy", class.name),
+                format!("Instance of {{class:{}}} to destroy", class.name.as_ref()),
             )?
             .doc(doc)?
             .build()?;
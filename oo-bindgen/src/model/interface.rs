@@ -75,7 +75,7 @@ pub struct EnumValue {
 }
 
 impl EnumValue {
-    pub(crate) fn new(handle: EnumHandle, variant: &'static str) -> BindResult<Self> {
+    pub(crate) fn new(handle: EnumHandle, variant: &str) -> BindResult<Self> {
         let variant = handle.validate_contains_variant_name(variant)?.clone();
         Ok(Self { handle, variant })
     }
@@ -90,11 +90,11 @@ pub struct ZeroParameterStructInitializer {
 }
 
 impl ZeroParameterStructInitializer {
-    fn try_create(handle: UniversalStructHandle, name: &'static str) -> BindResult<Self> {
+    fn try_create(handle: UniversalStructHandle, name: &str) -> BindResult<Self> {
         let initializer = match handle.initializers.iter().find(|x| x.name == name) {
             None => {
                 return Err(BindingErrorVariant::InitializerDoesNotExist {
-                    name,
+                    name: name.to_string(),
                     struct_name: handle.declaration.name().clone(),
                 }
                 .into())
@@ -105,7 +105,7 @@ impl ZeroParameterStructInitializer {
         // all values must be initialized
         if initializer.values.len() != handle.fields.len() {
             return Err(BindingErrorVariant::InitializerNotParameterless {
-                name,
+                name: name.to_string(),
                 struct_name: handle.declaration.name().clone(),
             }
             .into());
@@ -121,7 +121,7 @@ impl ZeroParameterStructInitializer {
 impl UniversalStructHandle {
     pub fn zero_parameter_initializer(
         &self,
-        name: &'static str,
+        name: &str,
     ) -> BindResult<ZeroParameterStructInitializer> {
         ZeroParameterStructInitializer::try_create(self.clone(), name)
     }
@@ -373,6 +373,7 @@ where
     D: DocReference,
 {
     pub(crate) name: Name,
+    pub(crate) module: Module,
     pub(crate) mode: InterfaceCategory,
     pub(crate) callbacks: Vec<CallbackFunction<D>>,
     pub(crate) doc: Doc<D>,
@@ -386,6 +387,7 @@ impl Interface<Unvalidated> {
 
         Ok(Handle::new(Interface {
             name: self.name.clone(),
+            module: self.module.clone(),
             mode: self.mode,
             callbacks: callbacks?,
             doc: self.doc.validate(&self.name, lib)?,
@@ -22,6 +22,34 @@ where
     FunctionDefinition(Handle<Function<D>>),
 }
 
+impl<D> Statement<D>
+where
+    D: DocReference,
+{
+    /// Module in which the statement was defined
+    pub(crate) fn module(&self) -> &Module {
+        match self {
+            Statement::Constants(x) => &x.module,
+            Statement::StructDeclaration(x) => &x.module,
+            Statement::StructDefinition(x) => x.module(),
+            Statement::EnumDefinition(x) => &x.module,
+            Statement::ErrorType(x) => &x.inner.module,
+            Statement::ClassDeclaration(x) => &x.module,
+            Statement::ClassDefinition(x) => &x.declaration.module,
+            Statement::StaticClassDefinition(x) => &x.module,
+            Statement::InterfaceDefinition(x) => &x.untyped().module,
+            Statement::IteratorDeclaration(x) => &x.iter_class.module,
+            Statement::CollectionDeclaration(x) => &x.collection_class.module,
+            Statement::FunctionDefinition(x) => &x.module,
+        }
+    }
+
+    /// true if the statement defines a type of the bindings, i.e. anything but a native function
+    pub(crate) fn is_type(&self) -> bool {
+        !matches!(self, Statement::FunctionDefinition(_))
+    }
+}
+
 impl Statement<Unvalidated> {
     pub(crate) fn unique_name(&self) -> Option<&Name> {
         match self {
@@ -310,6 +338,9 @@ pub struct Library {
     pub(crate) version: Version,
     pub(crate) info: Rc<LibraryInfo>,
     pub(crate) settings: Rc<LibrarySettings>,
+    pub(crate) naming_rules: Rc<NamingRules>,
+    /// modules, in the order of their definition
+    modules: Vec<Module>,
     /// history of statements from which we can find other types
     statements: Vec<Statement<Validated>>,
 }
@@ -319,20 +350,54 @@ impl Library {
         version: Version,
         info: Rc<LibraryInfo>,
         settings: Rc<LibrarySettings>,
+        naming_rules: Rc<NamingRules>,
+        modules: Vec<Module>,
         statements: Vec<Statement<Validated>>,
     ) -> Self {
         Self {
             version,
             info,
             settings,
+            naming_rules,
+            modules,
             statements,
         }
     }
 
+    /// Spelling of the names in the bindings of a language
+    pub(crate) fn naming(&self, language: TargetLanguage) -> Naming {
+        Naming::new(Some(language), self.naming_rules.clone())
+    }
+
     pub(crate) fn statements(&self) -> impl Iterator<Item = &Statement<Validated>> {
         self.statements.iter()
     }
 
+    /// true if some items are defined in another module than the root one
+    pub(crate) fn has_modules(&self) -> bool {
+        !self.modules.is_empty()
+    }
+
+    /// Modules other than the root one, in the order of their definition
+    pub(crate) fn modules(&self) -> impl Iterator<Item = &Module> {
+        self.modules.iter()
+    }
+
+    /// Modules with types, the root one first, i.e. the packages or namespaces of the bindings
+    ///
+    /// A module that only contains other modules, or only functions, has no package of its own.
+    pub(crate) fn type_modules(&self) -> Vec<&Module> {
+        let root = Module::root();
+        std::iter::once(&root)
+            .chain(self.modules.iter())
+            .filter_map(|module| {
+                self.statements()
+                    .find(|x| x.is_type() && x.module() == module)
+                    .map(|x| x.module())
+            })
+            .collect()
+    }
+
     pub(crate) fn functions(&self) -> impl Iterator<Item = &Handle<Function<Validated>>> {
         self.statements().filter_map(|statement| match statement {
             Statement::FunctionDefinition(handle) => Some(handle),
@@ -410,6 +475,84 @@ impl Library {
             _ => None,
         })
     }
+
+    /// true if a function, struct field, enum variant or callback is deprecated
+    pub(crate) fn has_deprecated_items(&self) -> bool {
+        self.functions().any(|x| x.doc.deprecated.is_some())
+            || self.structs().any(|x| {
+                x.field_docs()
+                    .iter()
+                    .any(|(_, doc)| doc.deprecated.is_some())
+            })
+            || self
+                .enums()
+                .any(|x| x.variants.iter().any(|x| x.doc.deprecated.is_some()))
+            || self
+                .untyped_interfaces()
+                .any(|x| x.callbacks.iter().any(|x| x.doc.deprecated.is_some()))
+    }
+
+    /// Code examples of the documentation, named after the documented symbol
+    ///
+    /// The index of the example is appended to the name when a symbol has several.
+    pub(crate) fn examples(&self) -> Vec<(String, &Example)> {
+        let mut docs: Vec<(Name, &Doc<Validated>)> = Vec::new();
+        for statement in self.statements() {
+            match statement {
+                Statement::Constants(x) => {
+                    docs.push((x.name.clone(), &x.doc));
+                    for value in &x.values {
+                        docs.push((x.name.append(&value.name), &value.doc));
+                    }
+                }
+                Statement::StructDefinition(x) => {
+                    docs.push((x.name().clone(), x.doc()));
+                    for (name, doc) in x.field_docs() {
+                        docs.push((x.name().append(name), doc));
+                    }
+                    for initializer in x.initializers() {
+                        docs.push((x.name().append(&initializer.name), &initializer.doc));
+                    }
+                }
+                Statement::EnumDefinition(x) => {
+                    docs.push((x.name.clone(), &x.doc));
+                    for variant in &x.variants {
+                        docs.push((x.name.append(&variant.name), &variant.doc));
+                    }
+                }
+                Statement::ClassDefinition(x) => docs.push((x.name().clone(), &x.doc)),
+                Statement::StaticClassDefinition(x) => docs.push((x.name.clone(), &x.doc)),
+                Statement::InterfaceDefinition(x) => {
+                    let x = x.untyped();
+                    docs.push((x.name.clone(), &x.doc));
+                    for callback in &x.callbacks {
+                        docs.push((x.name.append(&callback.name), &callback.doc));
+                    }
+                }
+                Statement::FunctionDefinition(x) => docs.push((x.name.clone(), &x.doc)),
+                // the remaining statements have no documentation of their own
+                Statement::StructDeclaration(_)
+                | Statement::ErrorType(_)
+                | Statement::ClassDeclaration(_)
+                | Statement::IteratorDeclaration(_)
+                | Statement::CollectionDeclaration(_) => (),
+            }
+        }
+
+        let mut examples = Vec::new();
+        for (name, doc) in docs {
+            let found: Vec<&Example> = doc.examples().collect();
+            for (index, example) in found.iter().enumerate() {
+                let name = if found.len() > 1 {
+                    format!("{}_{}", name, index + 1)
+                } else {
+                    name.to_string()
+                };
+                examples.push((name, *example));
+            }
+        }
+        examples
+    }
 }
 
 impl From<UniversalStructDeclaration> for FunctionReturnStructDeclaration {
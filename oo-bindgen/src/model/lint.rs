@@ -0,0 +1,393 @@
+//! Documentation lints
+//!
+//! Validation rejects documentation that cannot be generated, e.g. a reference to a class that
+//! does not exist. The lints report documentation that is valid but does not help the users of
+//! the bindings, e.g. a brief that only repeats the name of the symbol. Each lint has a
+//! [`Severity`] so that a CI build can decide which ones make it fail.
+
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use crate::model::*;
+
+/// Severity of a lint, from the least to the most severe
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Often intended, but worth a look
+    Info,
+    /// Documentation that is most likely unhelpful
+    Warning,
+    /// Documentation that is missing
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Problem found in the documentation of a symbol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Lint {
+    pub severity: Severity,
+    /// Identifier of the check, e.g. `brief-repeats-name`
+    pub id: &'static str,
+    /// Dotted path of the symbol in the library, e.g. `widget.fetch.timeout`
+    pub symbol: String,
+    pub message: String,
+}
+
+/// Lints of a library, in the order of the statements
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LintReport {
+    pub lints: Vec<Lint>,
+}
+
+impl LintReport {
+    /// Number of lints with a severity
+    pub fn count(&self, severity: Severity) -> usize {
+        self.lints.iter().filter(|x| x.severity == severity).count()
+    }
+
+    /// Check if a lint is at least as severe as the threshold, e.g. to fail a CI build
+    pub fn fails(&self, threshold: Severity) -> bool {
+        self.lints.iter().any(|x| x.severity >= threshold)
+    }
+}
+
+impl Display for LintReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for lint in &self.lints {
+            writeln!(
+                f,
+                "{}[{}] {}: {}",
+                lint.severity, lint.id, lint.symbol, lint.message
+            )?;
+        }
+        writeln!(
+            f,
+            "{} error(s), {} warning(s), {} info",
+            self.count(Severity::Error),
+            self.count(Severity::Warning),
+            self.count(Severity::Info)
+        )
+    }
+}
+
+/// Run every lint on a library
+pub(crate) fn lint(lib: &Library) -> LintReport {
+    let mut linter = Linter::default();
+
+    for statement in lib.statements() {
+        match statement {
+            Statement::Constants(x) => {
+                linter.doc(x.name.as_ref(), &x.name, &x.doc);
+                for value in &x.values {
+                    linter.doc(&path(&[&x.name, &value.name]), &value.name, &value.doc);
+                }
+            }
+            Statement::StructDefinition(x) => {
+                linter.doc(x.name().as_ref(), x.name(), x.doc());
+                for (name, doc) in x.field_docs() {
+                    linter.doc(&path(&[x.name(), name]), name, doc);
+                }
+                for initializer in x.initializers() {
+                    let symbol = path(&[x.name(), &initializer.name]);
+                    linter.doc(&symbol, &initializer.name, &initializer.doc);
+                }
+            }
+            Statement::EnumDefinition(x) => linter.enumeration(x),
+            Statement::InterfaceDefinition(InterfaceType::Synchronous(x))
+            | Statement::InterfaceDefinition(InterfaceType::Asynchronous(x)) => linter.interface(x),
+            Statement::ClassDefinition(x) => linter.class(x),
+            Statement::StaticClassDefinition(x) => {
+                linter.doc(x.name.as_ref(), &x.name, &x.doc);
+                for method in &x.static_methods {
+                    let symbol = path(&[&x.name, &method.name]);
+                    linter.function(&symbol, &method.name, &method.native_function);
+                }
+            }
+            // the documentation of future interfaces, iterators and collections is generated,
+            // the functions are linted with their class
+            Statement::InterfaceDefinition(InterfaceType::Future(_))
+            | Statement::FunctionDefinition(_)
+            | Statement::StructDeclaration(_)
+            | Statement::ErrorType(_)
+            | Statement::ClassDeclaration(_)
+            | Statement::IteratorDeclaration(_)
+            | Statement::CollectionDeclaration(_) => (),
+        }
+    }
+
+    linter.report
+}
+
+#[derive(Default)]
+struct Linter {
+    report: LintReport,
+}
+
+impl Linter {
+    fn push(&mut self, severity: Severity, id: &'static str, symbol: &str, message: String) {
+        self.report.lints.push(Lint {
+            severity,
+            id,
+            symbol: symbol.to_string(),
+            message,
+        });
+    }
+
+    fn doc(&mut self, symbol: &str, name: &Name, doc: &Doc<Validated>) {
+        let brief = doc.brief.plain_text();
+        if !brief.is_empty() && normalize(&brief) == normalize(name.as_ref()) {
+            self.push(
+                Severity::Warning,
+                "brief-repeats-name",
+                symbol,
+                format!("the brief '{}' only repeats the name", brief.trim()),
+            );
+        }
+    }
+
+    fn arguments<'a, T, I>(&mut self, symbol: &str, arguments: I)
+    where
+        T: Clone + 'a,
+        I: Iterator<Item = &'a Arg<T, Validated>>,
+    {
+        for arg in arguments {
+            if arg.doc.plain_text().trim().is_empty() {
+                self.push(
+                    Severity::Error,
+                    "empty-parameter-doc",
+                    &path(&[symbol, arg.name.as_ref()]),
+                    "the parameter is not documented".to_string(),
+                );
+            }
+        }
+    }
+
+    fn function(&mut self, symbol: &str, name: &Name, function: &Handle<Function<Validated>>) {
+        self.doc(symbol, name, &function.doc);
+        self.arguments(symbol, function.arguments.iter());
+    }
+
+    fn enumeration(&mut self, handle: &Handle<Enum<Validated>>) {
+        self.doc(handle.name.as_ref(), &handle.name, &handle.doc);
+        for variant in &handle.variants {
+            let symbol = path(&[&handle.name, &variant.name]);
+            if variant.doc.brief.plain_text().trim().is_empty() {
+                self.push(
+                    Severity::Warning,
+                    "undocumented-variant",
+                    &symbol,
+                    "the variant is not documented".to_string(),
+                );
+            } else {
+                self.doc(&symbol, &variant.name, &variant.doc);
+            }
+        }
+    }
+
+    fn interface(&mut self, handle: &Handle<Interface<Validated>>) {
+        self.doc(handle.name.as_ref(), &handle.name, &handle.doc);
+        for callback in &handle.callbacks {
+            let symbol = path(&[&handle.name, &callback.name]);
+            self.doc(&symbol, &callback.name, &callback.doc);
+            self.arguments(&symbol, callback.arguments.iter());
+
+            let referenced = referenced_arguments(&callback.doc);
+            for arg in &callback.arguments {
+                if !referenced.contains(&&arg.name) {
+                    self.push(
+                        Severity::Info,
+                        "unreferenced-callback-param",
+                        &path(&[symbol.as_str(), arg.name.as_ref()]),
+                        format!(
+                            "the documentation of the callback never mentions {{param:{}}}",
+                            arg.name.as_ref()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn class(&mut self, handle: &Handle<Class<Validated>>) {
+        let name = handle.name();
+        self.doc(name.as_ref(), name, &handle.doc);
+
+        if let Some(constructor) = &handle.constructor {
+            let symbol = path(&[name.as_ref(), "constructor"]);
+            self.arguments(&symbol, constructor.function.arguments.iter());
+        }
+        for method in &handle.methods {
+            let symbol = path(&[name, &method.name]);
+            self.function(&symbol, &method.name, &method.native_function);
+        }
+        for method in &handle.static_methods {
+            let symbol = path(&[name, &method.name]);
+            self.function(&symbol, &method.name, &method.native_function);
+        }
+        for method in &handle.future_methods {
+            let symbol = path(&[name, &method.name]);
+            self.function(&symbol, &method.name, &method.native_function);
+            if method.future.error_type.get().is_none() {
+                self.push(
+                    Severity::Warning,
+                    "future-without-error",
+                    &symbol,
+                    format!(
+                        "the future '{}' has no error type, the failures of the method are not documented",
+                        method.future.interface.name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Dotted path of a symbol
+fn path<T: AsRef<str>>(names: &[T]) -> String {
+    names
+        .iter()
+        .map(|x| x.as_ref())
+        .collect::<Vec<&str>>()
+        .join(".")
+}
+
+/// Letters and digits of a name or a sentence in lowercase, e.g. `string_class` and `StringClass.` are equal
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|x| x.is_alphanumeric())
+        .flat_map(|x| x.to_lowercase())
+        .collect()
+}
+
+fn referenced_arguments(doc: &Doc<Validated>) -> Vec<&Name> {
+    fn visit<'a>(docstring: &'a DocString<Validated>, names: &mut Vec<&'a Name>) {
+        for element in docstring.elements() {
+            match element {
+                DocStringElement::Reference(Validated::Argument(name)) => names.push(name),
+                DocStringElement::List(items) => {
+                    for item in items {
+                        visit(item, names);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let mut names = Vec::new();
+    visit(&doc.brief, &mut names);
+    for paragraph in &doc.details {
+        match paragraph {
+            DocParagraph::Details(x) | DocParagraph::Warning(x) => visit(x, &mut names),
+            DocParagraph::Example(_) => (),
+        }
+    }
+    if let Some(deprecated) = &doc.deprecated {
+        visit(&deprecated.note, &mut names);
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::Schema;
+
+    const JSON: &str = r#"{
+        "name": "demo",
+        "version": "1.0.0",
+        "info": {
+            "description": "demo library",
+            "project_url": "https://example.com",
+            "repository": "example/demo",
+            "license_name": "MIT",
+            "license_path": "LICENSE.txt"
+        },
+        "items": [
+            {
+                "type": "enum",
+                "name": "color",
+                "doc": "Color of a widget",
+                "variants": [{ "name": "red", "doc": "Red" }, { "name": "blue", "doc": "" }]
+            },
+            {
+                "type": "interface",
+                "name": "listener",
+                "doc": "Receives events",
+                "callbacks": [
+                    {
+                        "name": "on_value",
+                        "doc": "Receives {param:value}",
+                        "params": [
+                            { "name": "value", "type": "u32", "doc": "Value" },
+                            { "name": "count", "type": "u32", "doc": "Count" }
+                        ]
+                    }
+                ]
+            },
+            { "type": "future_interface", "name": "u32_callback", "doc": "Receives a number", "value": "u32", "value_doc": "The number" },
+            {
+                "type": "class",
+                "name": "widget",
+                "doc": "A widget",
+                "constructor": { "doc": "Create a widget" },
+                "destructor": { "doc": "Destroy a widget" },
+                "methods": [
+                    {
+                        "name": "resize",
+                        "doc": "Change the size",
+                        "params": [{ "name": "size", "type": "u32", "doc": " " }]
+                    }
+                ],
+                "future_methods": [{ "name": "fetch", "doc": "Fetch a number", "future": "u32_callback" }]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn reports_documentation_problems() {
+        let schema: Schema = serde_json::from_str(JSON).unwrap();
+        let report = lint(&schema.build().unwrap());
+
+        let found: Vec<(Severity, &str, &str)> = report
+            .lints
+            .iter()
+            .map(|x| (x.severity, x.id, x.symbol.as_str()))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                (Severity::Warning, "brief-repeats-name", "color.red"),
+                (Severity::Warning, "undocumented-variant", "color.blue"),
+                (
+                    Severity::Info,
+                    "unreferenced-callback-param",
+                    "listener.on_value.count"
+                ),
+                (Severity::Error, "empty-parameter-doc", "widget.resize.size"),
+                (Severity::Warning, "future-without-error", "widget.fetch"),
+            ]
+        );
+        assert!(report.fails(Severity::Error));
+        assert!(report.fails(Severity::Info));
+        assert_eq!(report.count(Severity::Warning), 3);
+    }
+
+    #[test]
+    fn severities_are_ordered() {
+        assert!(Severity::Info < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+    }
+}
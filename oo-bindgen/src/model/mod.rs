@@ -13,6 +13,7 @@ pub use builder::structs::*;
 pub use class::*;
 pub use collection::*;
 pub use constants::*;
+pub use constraint::*;
 pub use doc::*;
 pub use enum_type::*;
 pub use error_type::*;
@@ -22,7 +23,10 @@ pub use handle::*;
 pub use interface::*;
 pub use iterator::*;
 pub use library::*;
+pub use lint::*;
+pub use module::*;
 pub use name::*;
+pub use overload::*;
 pub use return_type::*;
 pub use structs::callback_argument_struct::*;
 pub use structs::common::*;
@@ -45,6 +49,7 @@ mod builder {
 mod class;
 mod collection;
 mod constants;
+mod constraint;
 mod enum_type;
 mod error_type;
 mod errors;
@@ -53,7 +58,10 @@ mod handle;
 mod interface;
 mod iterator;
 mod library;
+mod lint;
+mod module;
 mod name;
+mod overload;
 mod return_type;
 mod structs {
     pub(crate) mod callback_argument_struct;
@@ -0,0 +1,56 @@
+use std::fmt::{Display, Formatter};
+
+use crate::model::*;
+
+/// Module of a library, e.g. `master` or `master.sessions`
+///
+/// The types of a module are generated in a nested C++ or C# namespace and in a Java sub-package
+/// of the ones of the library. The C API keeps the prefix of the library, so the names of the
+/// symbols are unique across all the modules. The root module is the namespace of the library.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Module {
+    path: Vec<Name>,
+}
+
+impl Module {
+    /// Module of the items that are not defined in any other module
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn child(&self, name: Name) -> Self {
+        let mut path = self.path.clone();
+        path.push(name);
+        Self { path }
+    }
+
+    pub(crate) fn is_root(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Names of the module and of its parents, from the outermost one
+    pub(crate) fn path(&self) -> &[Name] {
+        &self.path
+    }
+
+    /// Names of the module relative to another one, i.e. without their common parents
+    pub(crate) fn relative_to(&self, other: &Module) -> &[Name] {
+        let common = self
+            .path
+            .iter()
+            .zip(other.path.iter())
+            .take_while(|(x, y)| x == y)
+            .count();
+        &self.path[common..]
+    }
+}
+
+impl Display for Module {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_root() {
+            return write!(f, "<root>");
+        }
+        let names: Vec<&str> = self.path.iter().map(|x| x.as_ref()).collect();
+        write!(f, "{}", names.join("."))
+    }
+}
@@ -1,11 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{Display, Formatter};
 use std::rc::Rc;
 
 use heck::{CamelCase, KebabCase, MixedCase, ShoutySnakeCase};
 use lazy_static::lazy_static;
+use serde::Deserialize;
 use thiserror::Error;
 
+use crate::model::Module;
+
 /// Names in oo_bindgen are subset of allowed C-style identifiers. They are
 /// enforce that names are a limited snake case.
 ///
@@ -22,9 +25,12 @@ use thiserror::Error;
 /// - They MUST begin with lowercase ascii
 /// - They CANNOT contain double underscores, e.g. foo__bar
 /// - They CANNOT end with an underscore, e.g. foo_bar_
-/// - They cannot equal certain reserved identifiers in C and other languages
+/// - They cannot equal a reserved identifier in Rust, which uses the names verbatim
+///
+/// Identifiers reserved in the other languages are escaped when the names are spelled for them,
+/// e.g. `default_` in C and C++, see [`Naming`].
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Name {
     validated: Rc<String>,
 }
@@ -148,7 +154,12 @@ impl AsRef<str> for Name {
 
 impl Name {
     /// convert to CamelCase
-    pub(crate) fn camel_case(&self) -> String {
+    pub(crate) fn camel_case(&self, naming: &Naming) -> String {
+        naming.escape(naming.spell(self, true))
+    }
+
+    /// convert to CamelCase without the naming rules, i.e. the spelling of the types of the Rust FFI
+    pub(crate) fn ffi_camel_case(&self) -> String {
         self.validated.to_camel_case()
     }
 
@@ -158,8 +169,21 @@ impl Name {
     }
 
     /// convert to mixedCase
-    pub(crate) fn mixed_case(&self) -> String {
-        self.validated.to_mixed_case()
+    pub(crate) fn mixed_case(&self, naming: &Naming) -> String {
+        naming.escape(naming.spell(self, false))
+    }
+
+    /// convert to mixedCase without escaping reserved identifiers, e.g. to build a longer identifier
+    pub(crate) fn unescaped_mixed_case(&self, naming: &Naming) -> String {
+        naming.spell(self, false)
+    }
+
+    /// spell in snake_case, escaping the identifiers reserved in the language of the naming
+    pub(crate) fn snake_case(&self, naming: &Naming) -> String {
+        match naming.language {
+            Some(language) => language.escape(self.to_string()),
+            None => self.to_string(),
+        }
     }
 
     /// convert to kebab-case
@@ -244,6 +268,184 @@ impl Name {
     }
 }
 
+/// Language of the identifiers spelled by the case conversions of a [`Name`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TargetLanguage {
+    /// C API of the library, which the names are never renamed in
+    #[serde(skip_deserializing)]
+    C,
+    Cpp,
+    Java,
+    DotNet,
+    Swift,
+    Go,
+    Node,
+    /// Kotlin extensions of the Java API, which share the renames of Java
+    #[serde(skip_deserializing)]
+    Kotlin,
+}
+
+impl TargetLanguage {
+    /// escape an identifier reserved in the language
+    pub(crate) fn escape(self, identifier: String) -> String {
+        let reserved = |keywords: &[&str]| keywords.contains(&identifier.as_str());
+        match self {
+            TargetLanguage::Java if reserved(JAVA_KEYWORDS) => format!("{}_", identifier),
+            TargetLanguage::DotNet if reserved(CSHARP_KEYWORDS) => format!("@{}", identifier),
+            // the C headers are also compiled as C++
+            TargetLanguage::C | TargetLanguage::Cpp
+                if reserved(C_KEYWORDS) || reserved(CPP_KEYWORDS) =>
+            {
+                format!("{}_", identifier)
+            }
+            TargetLanguage::Swift if reserved(SWIFT_KEYWORDS) => format!("`{}`", identifier),
+            TargetLanguage::Go if reserved(GO_KEYWORDS) || reserved(GO_RESERVED) => {
+                format!("{}_", identifier)
+            }
+            TargetLanguage::Node if reserved(NODE_RESERVED) => format!("{}_", identifier),
+            TargetLanguage::Kotlin => {
+                // the members keep their Java spelling, the hard keywords of Kotlin are quoted
+                let identifier = TargetLanguage::Java.escape(identifier);
+                if KOTLIN_KEYWORDS.contains(&identifier.as_str()) {
+                    format!("`{}`", identifier)
+                } else {
+                    identifier
+                }
+            }
+            _ => identifier,
+        }
+    }
+
+    /// language whose renames apply to the identifiers
+    pub(crate) fn renamed_as(self) -> TargetLanguage {
+        match self {
+            TargetLanguage::Kotlin => TargetLanguage::Java,
+            x => x,
+        }
+    }
+}
+
+impl Display for TargetLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetLanguage::C => write!(f, "c"),
+            TargetLanguage::Cpp => write!(f, "cpp"),
+            TargetLanguage::Java => write!(f, "java"),
+            TargetLanguage::DotNet => write!(f, "dotnet"),
+            TargetLanguage::Swift => write!(f, "swift"),
+            TargetLanguage::Go => write!(f, "go"),
+            TargetLanguage::Node => write!(f, "node"),
+            TargetLanguage::Kotlin => write!(f, "kotlin"),
+        }
+    }
+}
+
+/// Acronyms and per-language renames applied when names are spelled in the bindings
+///
+/// They do not apply to C and Rust, the names of the FFI are always the snake_case names.
+#[derive(Debug, Clone, Default)]
+pub struct NamingRules {
+    pub(crate) acronyms: BTreeSet<String>,
+    pub(crate) renames: BTreeMap<(TargetLanguage, Name), Name>,
+}
+
+impl NamingRules {
+    fn renamed(&self, language: TargetLanguage, name: &Name) -> Name {
+        self.renames
+            .get(&(language, name.clone()))
+            .unwrap_or(name)
+            .clone()
+    }
+}
+
+/// Spelling of the CamelCase and mixedCase identifiers of the bindings of a language
+///
+/// Backends get it from [`crate::model::Library::naming`] and hand it to their printers, so that
+/// every identifier follows the rules of the library. The names are spelled without the rules in
+/// C or without a language, e.g. in the Rust FFI.
+#[derive(Clone, Default)]
+pub(crate) struct Naming {
+    language: Option<TargetLanguage>,
+    rules: Rc<NamingRules>,
+    /// module of the code being written, e.g. the C++ namespace
+    module: Module,
+}
+
+impl Naming {
+    pub(crate) fn new(language: Option<TargetLanguage>, rules: Rc<NamingRules>) -> Self {
+        Self {
+            language,
+            rules,
+            module: Module::root(),
+        }
+    }
+
+    /// Spell the names for another language with the same rules
+    pub(crate) fn switch(&self, language: Option<TargetLanguage>) -> Self {
+        Self {
+            language,
+            ..self.clone()
+        }
+    }
+
+    /// Spell the names in the code of a module, the types of the other modules are qualified
+    /// relative to it
+    pub(crate) fn within(&self, module: &Module) -> Self {
+        Self {
+            module: module.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Module of the code being written
+    pub(crate) fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// escape an identifier reserved in the language of the naming
+    fn escape(&self, identifier: String) -> String {
+        match self.language {
+            Some(language) => language.escape(identifier),
+            None => identifier,
+        }
+    }
+
+    /// spell in CamelCase or mixedCase, without escaping the reserved identifiers
+    fn spell(&self, name: &Name, capitalize_first: bool) -> String {
+        let case = |name: &str| {
+            if capitalize_first {
+                name.to_camel_case()
+            } else {
+                name.to_mixed_case()
+            }
+        };
+
+        let language = match self.language {
+            Some(language) => language,
+            None | Some(TargetLanguage::C) => return case(name),
+        };
+
+        let name = self.rules.renamed(language.renamed_as(), name);
+        if name.split('_').any(|x| self.rules.acronyms.contains(x)) {
+            name.split('_')
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 && !capitalize_first {
+                        word.to_string()
+                    } else if self.rules.acronyms.contains(word) {
+                        word.to_uppercase()
+                    } else {
+                        word.to_camel_case()
+                    }
+                })
+                .collect()
+        } else {
+            case(&name)
+        }
+    }
+}
+
 lazy_static! {
     static ref KEYWORD_MAP: KeywordMap = KeywordMap::new();
 }
@@ -379,6 +581,7 @@ const JAVA_KEYWORDS: &[&str] = &[
     "else",
     "enum",
     "extends",
+    "false",
     "final",
     "finally",
     "float",
@@ -393,6 +596,7 @@ const JAVA_KEYWORDS: &[&str] = &[
     "long",
     "native",
     "new",
+    "null",
     "package",
     "private",
     "protected",
@@ -408,6 +612,7 @@ const JAVA_KEYWORDS: &[&str] = &[
     "throw",
     "throws",
     "transient",
+    "true",
     "try",
     "void",
     "volatile",
@@ -494,6 +699,178 @@ const CSHARP_KEYWORDS: &[&str] = &[
     "while",
 ];
 
+/// Keywords of Swift, which can be used as identifiers when quoted with backticks
+const SWIFT_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "operator",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "var",
+    "where",
+    "while",
+];
+
+/// Keywords of Go, the fields of C structs named after them are prefixed with an underscore by cgo
+pub(crate) const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Predeclared identifiers and imported packages used by the generated code
+const GO_RESERVED: &[&str] = &[
+    "any", "append", "bool", "byte", "error", "false", "instance", "len", "new", "nil", "runtime",
+    "string", "time", "true", "unsafe",
+];
+
+/// Reserved words of JavaScript and identifiers used by the generated code
+const NODE_RESERVED: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "dispose",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Hard keywords of Kotlin, which can't be used as identifiers unless quoted with backticks
+const KOTLIN_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
 /// keywords reserved by oo_bindgen itself
 /// these strings cannot show up anywhere in a Name
 ///
@@ -515,22 +892,6 @@ impl KeywordMap {
             map.insert(x, (x, "Rust"));
         }
 
-        for x in C_KEYWORDS {
-            map.insert(x, (x, "C"));
-        }
-
-        for x in CPP_KEYWORDS {
-            map.insert(x, (x, "C++"));
-        }
-
-        for x in JAVA_KEYWORDS {
-            map.insert(x, (x, "Java"));
-        }
-
-        for x in CSHARP_KEYWORDS {
-            map.insert(x, (x, "C#"));
-        }
-
         Self { map }
     }
 
@@ -553,21 +914,112 @@ mod tests {
     #[test]
     fn cannot_equal_reserved_identifiers() {
         assert_eq!(
-            Name::create("alignas").err().unwrap().error,
-            NameError::reserved_identifier("alignas", "C++")
+            Name::create("box").err().unwrap().error,
+            NameError::reserved_identifier("box", "Rust")
         );
+    }
+
+    #[test]
+    fn escapes_identifiers_reserved_in_c_and_cpp() {
+        let default = Name::create("default").unwrap();
+        let rules = Rc::new(NamingRules::default());
+        let c = Naming::new(Some(TargetLanguage::C), rules.clone());
+        let cpp = Naming::new(Some(TargetLanguage::Cpp), rules);
+
+        assert_eq!(default.to_string(), "default");
+        assert_eq!(default.snake_case(&Naming::default()), "default");
+        assert_eq!(default.snake_case(&c), "default_");
+        assert_eq!(default.snake_case(&cpp), "default_");
+        assert_eq!(default.camel_case(&cpp), "Default");
+        assert_eq!(Name::create("alignas").unwrap().snake_case(&c), "alignas_");
         assert_eq!(
-            Name::create("implements").err().unwrap().error,
-            NameError::reserved_identifier("implements", "Java")
+            Name::create("default_value").unwrap().snake_case(&c),
+            "default_value"
         );
+    }
+
+    #[test]
+    fn escapes_identifiers_reserved_in_java_and_csharp() {
+        let event = Name::create("event").unwrap();
+        let object = Name::create("object").unwrap();
+        let default = Name::create("default").unwrap();
+        let rules = Rc::new(NamingRules::default());
+
+        assert_eq!(event.mixed_case(&Naming::default()), "event");
+
+        let dotnet = Naming::new(Some(TargetLanguage::DotNet), rules.clone());
+        assert_eq!(event.mixed_case(&dotnet), "@event");
+        assert_eq!(event.unescaped_mixed_case(&dotnet), "event");
+        assert_eq!(default.snake_case(&dotnet), "@default");
+        assert_eq!(event.camel_case(&dotnet), "Event");
+
+        let java = Naming::new(Some(TargetLanguage::Java), rules);
+        assert_eq!(Name::create("native").unwrap().mixed_case(&java), "native_");
+        assert_eq!(default.snake_case(&java), "default_");
+        assert_eq!(object.mixed_case(&java), "object");
+    }
+
+    #[test]
+    fn escapes_identifiers_reserved_in_swift_go_node_and_kotlin() {
+        let rules = Rc::new(NamingRules::default());
+        let naming = |language| Naming::new(Some(language), rules.clone());
+        let repeat = Name::create("repeat").unwrap();
+        let default = Name::create("default").unwrap();
+        let object = Name::create("object").unwrap();
+
         assert_eq!(
-            Name::create("delegate").err().unwrap().error,
-            NameError::reserved_identifier("delegate", "C#")
+            repeat.mixed_case(&naming(TargetLanguage::Swift)),
+            "`repeat`"
         );
+        assert_eq!(repeat.camel_case(&naming(TargetLanguage::Swift)), "Repeat");
         assert_eq!(
-            Name::create("box").err().unwrap().error,
-            NameError::reserved_identifier("box", "Rust")
+            Name::create("len")
+                .unwrap()
+                .mixed_case(&naming(TargetLanguage::Go)),
+            "len_"
+        );
+        assert_eq!(
+            default.mixed_case(&naming(TargetLanguage::Node)),
+            "default_"
         );
+        assert_eq!(repeat.mixed_case(&naming(TargetLanguage::Node)), "repeat");
+
+        let kotlin = naming(TargetLanguage::Kotlin);
+        assert_eq!(object.mixed_case(&kotlin), "`object`");
+        assert_eq!(object.unescaped_mixed_case(&kotlin), "object");
+        assert_eq!(default.mixed_case(&kotlin), "default_");
+    }
+
+    #[test]
+    fn spells_acronyms_and_renames() {
+        let tls_config = Name::create("tls_config").unwrap();
+        let object = Name::create("object").unwrap();
+        let mut rules = NamingRules::default();
+        rules.acronyms.insert("tls".to_string());
+        rules.renames.insert(
+            (TargetLanguage::Java, object.clone()),
+            Name::create("value_object").unwrap(),
+        );
+        let rules = Rc::new(rules);
+
+        assert_eq!(tls_config.camel_case(&Naming::default()), "TlsConfig");
+        let dotnet = Naming::new(Some(TargetLanguage::DotNet), rules);
+        assert_eq!(tls_config.camel_case(&dotnet), "TLSConfig");
+        assert_eq!(tls_config.mixed_case(&dotnet), "tlsConfig");
+        assert_eq!(
+            Name::create("enable_tls").unwrap().mixed_case(&dotnet),
+            "enableTLS"
+        );
+        assert_eq!(tls_config.ffi_camel_case(), "TlsConfig");
+        assert_eq!(tls_config.capital_snake_case(), "TLS_CONFIG");
+        assert_eq!(object.camel_case(&dotnet), "Object");
+
+        let java = dotnet.switch(Some(TargetLanguage::Java));
+        assert_eq!(object.camel_case(&java), "ValueObject");
+        assert_eq!(object.mixed_case(&java), "valueObject");
+
+        let kotlin = java.switch(Some(TargetLanguage::Kotlin));
+        assert_eq!(object.mixed_case(&kotlin), "valueObject");
     }
 
     #[test]
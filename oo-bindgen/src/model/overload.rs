@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::model::*;
+
+/// Host languages in which the members of an overload group must be distinguishable
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OverloadLanguage {
+    Cpp,
+    Java,
+    DotNet,
+    Swift,
+}
+
+impl OverloadLanguage {
+    const ALL: [OverloadLanguage; 4] = [Self::Cpp, Self::Java, Self::DotNet, Self::Swift];
+}
+
+impl Display for OverloadLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cpp => write!(f, "C++"),
+            Self::Java => write!(f, "Java"),
+            Self::DotNet => write!(f, ".NET"),
+            Self::Swift => write!(f, "Swift"),
+        }
+    }
+}
+
+/// Member of a class as it is seen by host languages, i.e. without the instance
+/// or the callback parameters
+pub(crate) struct OverloadCandidate<'a> {
+    name: &'a Name,
+    params: Vec<&'a Arg<FunctionArgument, Unvalidated>>,
+}
+
+impl<'a> OverloadCandidate<'a> {
+    pub(crate) fn method(method: &'a Method<Unvalidated>) -> Self {
+        Self {
+            name: &method.name,
+            params: method.native_function.arguments.iter().skip(1).collect(),
+        }
+    }
+
+    pub(crate) fn static_method(method: &'a StaticMethod<Unvalidated>) -> Self {
+        Self {
+            name: &method.name,
+            params: method.native_function.arguments.iter().collect(),
+        }
+    }
+
+    pub(crate) fn future_method(method: &'a FutureMethod<Unvalidated>) -> Self {
+        let args = &method.native_function.arguments;
+        Self {
+            name: &method.name,
+            params: args
+                .iter()
+                .take(args.len().saturating_sub(1))
+                .skip(1)
+                .collect(),
+        }
+    }
+
+    /// Parameter lists that may be used to invoke the member in the language
+    ///
+    /// Trailing parameters with default values may be omitted
+    fn signatures(&self, language: OverloadLanguage) -> Vec<Vec<String>> {
+        let keys: Vec<String> = self
+            .params
+            .iter()
+            .map(|x| type_key(&x.arg_type, language))
+            .collect();
+        let num_defaults = self
+            .params
+            .iter()
+            .rev()
+            .take_while(|x| x.default.is_some())
+            .count();
+
+        (0..=num_defaults)
+            .map(|omitted| keys[..keys.len() - omitted].to_vec())
+            .collect()
+    }
+}
+
+/// Check that members sharing a name can be told apart by their parameters in every language
+pub(crate) fn check_overloads(
+    parent: &Name,
+    candidates: &[OverloadCandidate<'_>],
+) -> BindResult<()> {
+    let mut groups: HashMap<&str, Vec<&OverloadCandidate>> = HashMap::new();
+    for candidate in candidates {
+        groups
+            .entry(candidate.name.as_ref())
+            .or_default()
+            .push(candidate);
+    }
+
+    for group in groups.values().filter(|x| x.len() > 1) {
+        for language in OverloadLanguage::ALL {
+            let mut seen: Vec<Vec<String>> = Vec::new();
+            for candidate in group {
+                let signatures = candidate.signatures(language);
+                if signatures.iter().any(|x| seen.contains(x)) {
+                    return Err(BindingErrorVariant::OverloadNotDistinguishable {
+                        parent: parent.clone(),
+                        method: candidate.name.clone(),
+                        language,
+                    }
+                    .into());
+                }
+                seen.extend(signatures);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Identifies the type of a parameter after it is mapped to the language
+///
+/// All durations share a type, which Swift maps to `TimeInterval`, i.e. a `Double`. Structs are
+/// the same type whether passed by value or by reference, and Java erases the item type of
+/// collections.
+fn type_key(arg: &FunctionArgument, language: OverloadLanguage) -> String {
+    match arg {
+        FunctionArgument::Basic(BasicType::Primitive(x)) => format!("{:?}", x),
+        FunctionArgument::Basic(BasicType::Duration(_)) => match language {
+            OverloadLanguage::Swift => format!("{:?}", Primitive::Double),
+            _ => "duration".to_string(),
+        },
+        FunctionArgument::Basic(BasicType::Enum(x)) => format!("enum {}", x.name),
+        FunctionArgument::String(_) => "string".to_string(),
+        FunctionArgument::Collection(x) => match language {
+            OverloadLanguage::Java => "collection".to_string(),
+            _ => format!("collection<{}>", type_key(&x.item_type, language)),
+        },
+        FunctionArgument::Struct(x) => format!("struct {}", x.name()),
+        FunctionArgument::StructRef(x) => format!("struct {}", x.inner.name),
+        FunctionArgument::ClassRef(x) => format!("class {}", x.name),
+        FunctionArgument::Interface(x) => format!("interface {}", x.name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(
+        lib: &mut LibraryBuilder,
+        name: &str,
+        param_type: FunctionArgument,
+    ) -> StaticMethod<Unvalidated> {
+        lib.define_function(name)
+            .unwrap()
+            .param("value", param_type, "Value to describe")
+            .unwrap()
+            .doc("Describe a value")
+            .unwrap()
+            .build_static("describe")
+            .unwrap()
+    }
+
+    fn build_static_class(
+        lib: &mut LibraryBuilder,
+        methods: Vec<StaticMethod<Unvalidated>>,
+    ) -> BindResult<StaticClassHandle> {
+        let mut builder = lib
+            .define_static_class("functions")
+            .unwrap()
+            .doc("Overloaded functions")
+            .unwrap();
+        for method in methods {
+            builder = builder.static_method(method).unwrap();
+        }
+        builder.build()
+    }
+
+    fn not_distinguishable_in(result: BindResult<StaticClassHandle>) -> Option<OverloadLanguage> {
+        match result.err()?.variant() {
+            BindingErrorVariant::OverloadNotDistinguishable { language, .. } => Some(*language),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn accepts_overloads_with_different_types() {
+        let mut lib = LibraryBuilder::for_tests();
+        let methods = vec![
+            describe(&mut lib, "describe_u8", Primitive::U8.into()),
+            describe(&mut lib, "describe_u32", Primitive::U32.into()),
+            describe(&mut lib, "describe_string", StringType.into()),
+        ];
+        assert!(build_static_class(&mut lib, methods).is_ok());
+    }
+
+    #[test]
+    fn rejects_overloads_with_the_same_types() {
+        let mut lib = LibraryBuilder::for_tests();
+        let methods = vec![
+            describe(&mut lib, "describe_ms", DurationType::Milliseconds.into()),
+            describe(&mut lib, "describe_s", DurationType::Seconds.into()),
+        ];
+        assert_eq!(
+            not_distinguishable_in(build_static_class(&mut lib, methods)),
+            Some(OverloadLanguage::Cpp)
+        );
+    }
+
+    #[test]
+    fn rejects_durations_and_doubles_in_swift() {
+        let mut lib = LibraryBuilder::for_tests();
+        let methods = vec![
+            describe(
+                &mut lib,
+                "describe_duration",
+                DurationType::Milliseconds.into(),
+            ),
+            describe(&mut lib, "describe_double", Primitive::Double.into()),
+        ];
+        assert_eq!(
+            not_distinguishable_in(build_static_class(&mut lib, methods)),
+            Some(OverloadLanguage::Swift)
+        );
+    }
+
+    #[test]
+    fn rejects_overloads_that_only_differ_by_parameters_with_defaults() {
+        let mut lib = LibraryBuilder::for_tests();
+        let scaled = lib
+            .define_function("describe_scaled")
+            .unwrap()
+            .param("value", Primitive::U32, "Value to describe")
+            .unwrap()
+            .param_with_default("factor", Primitive::U32, "Factor", NumberValue::U32(1))
+            .unwrap()
+            .doc("Describe a scaled value")
+            .unwrap()
+            .build_static("describe")
+            .unwrap();
+        let methods = vec![
+            describe(&mut lib, "describe_u32", Primitive::U32.into()),
+            scaled,
+        ];
+        assert_eq!(
+            not_distinguishable_in(build_static_class(&mut lib, methods)),
+            Some(OverloadLanguage::Cpp)
+        );
+    }
+}
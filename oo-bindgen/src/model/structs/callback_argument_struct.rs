@@ -17,6 +17,29 @@ impl StructFieldType for CallbackArgStructField {
     ) -> StructType<Unvalidated> {
         StructType::CallbackArg(v)
     }
+
+    fn has_value_semantics(&self) -> bool {
+        match self {
+            CallbackArgStructField::Basic(_) => true,
+            CallbackArgStructField::Iterator(_) => false,
+            CallbackArgStructField::Struct(x) => x.has_value_semantics(),
+        }
+    }
+
+    fn constrained_type(&self) -> Option<ConstrainedType> {
+        match self {
+            CallbackArgStructField::Basic(x) => x.constrained_type(),
+            CallbackArgStructField::Iterator(_) => None,
+            CallbackArgStructField::Struct(_) => None,
+        }
+    }
+
+    fn has_nested_constraints(&self) -> bool {
+        match self {
+            CallbackArgStructField::Struct(x) => x.has_constraints(),
+            _ => false,
+        }
+    }
 }
 
 impl InitializerValidator for CallbackArgStructField {
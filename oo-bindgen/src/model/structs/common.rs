@@ -166,12 +166,17 @@ pub(crate) enum Visibility {
 #[derive(Debug)]
 pub struct StructDeclaration {
     pub(crate) name: Name,
+    pub(crate) module: Module,
     pub(crate) settings: Rc<LibrarySettings>,
 }
 
 impl StructDeclaration {
-    pub(crate) fn new(name: Name, settings: Rc<LibrarySettings>) -> Self {
-        Self { name, settings }
+    pub(crate) fn new(name: Name, module: Module, settings: Rc<LibrarySettings>) -> Self {
+        Self {
+            name,
+            module,
+            settings,
+        }
     }
 }
 
@@ -284,6 +289,15 @@ impl InitializerValidator for StringType {
 pub trait StructFieldType: Clone + Sized + InitializerValidator {
     /// convert a structure to a StructType
     fn create_struct_type(v: Handle<Struct<Self, Unvalidated>>) -> StructType<Unvalidated>;
+
+    /// true if the field can be compared, hashed and printed by value
+    fn has_value_semantics(&self) -> bool;
+
+    /// type used to check if a constraint may be applied to the field
+    fn constrained_type(&self) -> Option<ConstrainedType>;
+
+    /// true if the field is a struct with constraints on any of its fields
+    fn has_nested_constraints(&self) -> bool;
 }
 
 #[derive(Debug)]
@@ -294,6 +308,7 @@ where
 {
     pub(crate) name: Name,
     pub(crate) field_type: F,
+    pub(crate) constraints: Vec<Constraint>,
     pub(crate) doc: Doc<D>,
 }
 
@@ -302,14 +317,34 @@ where
     F: StructFieldType,
 {
     pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<StructField<F, Validated>> {
+        let mut doc = self.doc.validate(&self.name, lib)?;
+        if !self.constraints.is_empty() {
+            doc.details.push(DocParagraph::Details(text(&format!(
+                "Value {}.",
+                describe_constraints(&self.constraints)
+            ))));
+        }
+
         Ok(StructField {
             name: self.name.clone(),
             field_type: self.field_type.clone(),
-            doc: self.doc.validate(&self.name, lib)?,
+            constraints: self.constraints.clone(),
+            doc,
         })
     }
 }
 
+impl<F, D> StructField<F, D>
+where
+    F: StructFieldType,
+    D: DocReference,
+{
+    /// true if the field or any nested struct field has constraints
+    pub fn has_constraints(&self) -> bool {
+        !self.constraints.is_empty() || self.field_type.has_nested_constraints()
+    }
+}
+
 /// C-style structure definition
 #[derive(Debug)]
 pub struct Struct<F, D>
@@ -318,6 +353,8 @@ where
     D: DocReference,
 {
     pub(crate) visibility: Visibility,
+    pub(crate) value_semantics: bool,
+    pub(crate) immutable: bool,
     pub(crate) declaration: TypedStructDeclaration<F>,
     pub(crate) fields: Vec<StructField<F, D>>,
     pub(crate) initializers: Vec<Handle<Initializer<D>>>,
@@ -340,6 +377,8 @@ where
 
         Ok(Handle::new(Struct {
             visibility: self.visibility,
+            value_semantics: self.value_semantics,
+            immutable: self.immutable,
             declaration: self.declaration.clone(),
             fields: fields?,
             initializers,
@@ -399,6 +438,10 @@ where
         &self.declaration.inner.name
     }
 
+    pub(crate) fn module(&self) -> &Module {
+        &self.declaration.inner.module
+    }
+
     pub fn declaration(&self) -> StructDeclarationHandle {
         self.declaration.inner.clone()
     }
@@ -416,6 +459,21 @@ where
         self.fields.iter()
     }
 
+    /// true if the bindings generate equality, hashing and a string representation
+    pub fn has_value_semantics(&self) -> bool {
+        self.value_semantics
+    }
+
+    /// true if the bindings only allow the fields to be set at construction
+    pub fn is_immutable(&self) -> bool {
+        self.immutable
+    }
+
+    /// true if any field, including the fields of nested structs, has constraints
+    pub fn has_constraints(&self) -> bool {
+        self.fields.iter().any(|x| x.has_constraints())
+    }
+
     pub fn has_default_initializer(&self) -> bool {
         self.get_default_initializer().is_some()
     }
@@ -647,6 +705,13 @@ where
         }
     }
 
+    pub(crate) fn module(&self) -> &Module {
+        match self {
+            UniversalOr::Specific(x) => x.module(),
+            UniversalOr::Universal(x) => x.module(),
+        }
+    }
+
     pub fn declaration(&self) -> StructDeclarationHandle {
         match self {
             UniversalOr::Specific(x) => x.declaration.inner.clone(),
@@ -667,6 +732,20 @@ where
             UniversalOr::Universal(x) => StructType::Universal(x.clone()),
         }
     }
+
+    pub fn has_value_semantics(&self) -> bool {
+        match self {
+            UniversalOr::Specific(x) => x.has_value_semantics(),
+            UniversalOr::Universal(x) => x.has_value_semantics(),
+        }
+    }
+
+    pub fn has_constraints(&self) -> bool {
+        match self {
+            UniversalOr::Specific(x) => x.has_constraints(),
+            UniversalOr::Universal(x) => x.has_constraints(),
+        }
+    }
 }
 
 impl<T> InitializerValidator for UniversalOr<T>
@@ -715,6 +794,15 @@ where
         }
     }
 
+    pub(crate) fn module(&self) -> &Module {
+        match self {
+            StructType::FunctionArg(x) => x.module(),
+            StructType::CallbackArg(x) => x.module(),
+            StructType::FunctionReturn(x) => x.module(),
+            StructType::Universal(x) => x.module(),
+        }
+    }
+
     pub fn doc(&self) -> &Doc<D> {
         match self {
             StructType::FunctionArg(x) => &x.doc,
@@ -724,6 +812,16 @@ where
         }
     }
 
+    /// name and documentation of each field
+    pub(crate) fn field_docs(&self) -> Vec<(&Name, &Doc<D>)> {
+        match self {
+            StructType::FunctionArg(x) => x.fields.iter().map(|x| (&x.name, &x.doc)).collect(),
+            StructType::FunctionReturn(x) => x.fields.iter().map(|x| (&x.name, &x.doc)).collect(),
+            StructType::CallbackArg(x) => x.fields.iter().map(|x| (&x.name, &x.doc)).collect(),
+            StructType::Universal(x) => x.fields.iter().map(|x| (&x.name, &x.doc)).collect(),
+        }
+    }
+
     pub fn settings(&self) -> &LibrarySettings {
         match self {
             StructType::FunctionArg(x) => &x.declaration.inner.settings,
@@ -742,6 +840,24 @@ where
         }
     }
 
+    pub fn has_value_semantics(&self) -> bool {
+        match self {
+            StructType::FunctionArg(x) => x.has_value_semantics(),
+            StructType::CallbackArg(x) => x.has_value_semantics(),
+            StructType::FunctionReturn(x) => x.has_value_semantics(),
+            StructType::Universal(x) => x.has_value_semantics(),
+        }
+    }
+
+    pub fn has_constraints(&self) -> bool {
+        match self {
+            StructType::FunctionArg(x) => x.has_constraints(),
+            StructType::CallbackArg(x) => x.has_constraints(),
+            StructType::FunctionReturn(x) => x.has_constraints(),
+            StructType::Universal(x) => x.has_constraints(),
+        }
+    }
+
     pub fn find_field_name(&self, name: &str) -> Option<Name> {
         match self {
             StructType::FunctionArg(x) => x.find_field_name(name),
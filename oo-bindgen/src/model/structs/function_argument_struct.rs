@@ -18,6 +18,31 @@ impl StructFieldType for FunctionArgStructField {
     ) -> StructType<Unvalidated> {
         StructType::FunctionArg(v)
     }
+
+    fn has_value_semantics(&self) -> bool {
+        match self {
+            FunctionArgStructField::Basic(_) => true,
+            FunctionArgStructField::String(_) => true,
+            FunctionArgStructField::Interface(_) => false,
+            FunctionArgStructField::Struct(x) => x.has_value_semantics(),
+        }
+    }
+
+    fn constrained_type(&self) -> Option<ConstrainedType> {
+        match self {
+            FunctionArgStructField::Basic(x) => x.constrained_type(),
+            FunctionArgStructField::String(_) => Some(ConstrainedType::String),
+            FunctionArgStructField::Interface(_) => None,
+            FunctionArgStructField::Struct(_) => None,
+        }
+    }
+
+    fn has_nested_constraints(&self) -> bool {
+        match self {
+            FunctionArgStructField::Struct(x) => x.has_constraints(),
+            _ => false,
+        }
+    }
 }
 
 impl InitializerValidator for FunctionArgStructField {
@@ -17,6 +17,31 @@ impl StructFieldType for FunctionReturnStructField {
     fn create_struct_type(v: Handle<Struct<Self, Unvalidated>>) -> StructType<Unvalidated> {
         StructType::FunctionReturn(v)
     }
+
+    fn has_value_semantics(&self) -> bool {
+        match self {
+            FunctionReturnStructField::Basic(_) => true,
+            FunctionReturnStructField::ClassRef(_) => false,
+            FunctionReturnStructField::Iterator(_) => false,
+            FunctionReturnStructField::Struct(x) => x.has_value_semantics(),
+        }
+    }
+
+    fn constrained_type(&self) -> Option<ConstrainedType> {
+        match self {
+            FunctionReturnStructField::Basic(x) => x.constrained_type(),
+            FunctionReturnStructField::ClassRef(_) => None,
+            FunctionReturnStructField::Iterator(_) => None,
+            FunctionReturnStructField::Struct(_) => None,
+        }
+    }
+
+    fn has_nested_constraints(&self) -> bool {
+        match self {
+            FunctionReturnStructField::Struct(x) => x.has_constraints(),
+            _ => false,
+        }
+    }
 }
 
 impl InitializerValidator for FunctionReturnStructField {
@@ -16,6 +16,27 @@ impl StructFieldType for UniversalStructField {
     ) -> StructType<Unvalidated> {
         StructType::Universal(v)
     }
+
+    fn has_value_semantics(&self) -> bool {
+        match self {
+            UniversalStructField::Basic(_) => true,
+            UniversalStructField::Struct(x) => x.has_value_semantics(),
+        }
+    }
+
+    fn constrained_type(&self) -> Option<ConstrainedType> {
+        match self {
+            UniversalStructField::Basic(x) => x.constrained_type(),
+            UniversalStructField::Struct(_) => None,
+        }
+    }
+
+    fn has_nested_constraints(&self) -> bool {
+        match self {
+            UniversalStructField::Basic(_) => false,
+            UniversalStructField::Struct(x) => x.has_constraints(),
+        }
+    }
 }
 
 impl InitializerValidator for UniversalStructField {
@@ -69,6 +69,8 @@ where
 {
     pub(crate) arg_type: T,
     pub(crate) name: Name,
+    pub(crate) constraints: Vec<Constraint>,
+    pub(crate) default: Option<ValidatedDefaultValue>,
     pub(crate) doc: DocString<D>,
 }
 
@@ -77,10 +79,23 @@ where
     T: Clone,
 {
     pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<Arg<T, Validated>> {
+        let mut doc = self.doc.validate(&self.name, lib)?;
+        if !self.constraints.is_empty() {
+            doc.push(DocStringElement::Text(format!(
+                " (value {})",
+                describe_constraints(&self.constraints)
+            )));
+        }
+        if let Some(default) = &self.default {
+            doc.push(DocStringElement::Text(format!(" (default: {})", default)));
+        }
+
         Ok(Arg {
             arg_type: self.arg_type.clone(),
             name: self.name.clone(),
-            doc: self.doc.validate(&self.name, lib)?,
+            constraints: self.constraints.clone(),
+            default: self.default.clone(),
+            doc,
         })
     }
 }
@@ -94,6 +109,8 @@ where
         Self {
             arg_type,
             name,
+            constraints: Vec::new(),
+            default: None,
             doc,
         }
     }
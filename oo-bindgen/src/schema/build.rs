@@ -0,0 +1,889 @@
+use crate::model::*;
+use crate::schema::location::at;
+use crate::schema::types::*;
+use crate::schema::*;
+
+fn invalid(name: &str, reason: &'static str) -> BindingError {
+    BindingErrorVariant::SchemaInvalidItem {
+        name: name.to_string(),
+        reason,
+    }
+    .into()
+}
+
+fn doc(schema: &DocSchema) -> BindResult<Doc<Unvalidated>> {
+    match schema {
+        DocSchema::Brief(x) => Ok(crate::model::doc(x)),
+        DocSchema::Full {
+            brief,
+            details,
+            since,
+        } => {
+            let doc =
+                details
+                    .iter()
+                    .fold(crate::model::doc(brief), |doc, paragraph| match paragraph {
+                        ParagraphSchema::Details(x) => doc.details(x),
+                        ParagraphSchema::Warning { warning } => doc.warning(warning),
+                        ParagraphSchema::Example { example } => doc.example(
+                            example
+                                .snippets()
+                                .fold(crate::model::example(), |example, (language, code)| {
+                                    example.snippet(language, code)
+                                }),
+                        ),
+                    });
+            match since {
+                Some(since) => Ok(doc.since(at!(parse_version(since), "doc", "since")?)),
+                None => Ok(doc),
+            }
+        }
+    }
+}
+
+fn parse_version(version: &str) -> BindResult<Version> {
+    Version::parse(version).map_err(|_| {
+        BindingErrorVariant::SchemaBadVersion {
+            version: version.to_string(),
+        }
+        .into()
+    })
+}
+
+fn deprecated_since(schema: &DeprecatedSchema) -> BindResult<Version> {
+    at!(parse_version(&schema.since), "deprecated", "since")
+}
+
+fn constraint(types: &Types, type_name: &str, schema: &ConstraintSchema) -> BindResult<Constraint> {
+    Ok(match schema {
+        ConstraintSchema::Range { min, max } => Constraint::range(
+            types.constraint_value(type_name, min)?,
+            types.constraint_value(type_name, max)?,
+        ),
+        ConstraintSchema::NonZero => Constraint::NonZero,
+        ConstraintSchema::NonEmpty => Constraint::NonEmpty,
+        ConstraintSchema::MaxLength(x) => Constraint::MaxLength(*x),
+        ConstraintSchema::Regex(x) => Constraint::regex(x),
+    })
+}
+
+pub(crate) fn build_library(schema: &Schema) -> BindResult<Library> {
+    let version = at!(parse_version(&schema.version), "version")?;
+
+    let logo_png: &'static [u8] = match &schema.info.logo_png {
+        None => &[],
+        Some(path) => {
+            let path = schema.resolve(path);
+            let data = at!(
+                std::fs::read(&path).map_err(|err| {
+                    BindingError::from(BindingErrorVariant::SchemaRead {
+                        path,
+                        message: err.to_string(),
+                    })
+                }),
+                "info",
+                "logo_png"
+            )?;
+            // the logo is normally embedded with include_bytes!
+            Box::leak(data.into_boxed_slice())
+        }
+    };
+
+    let info = LibraryInfo {
+        description: schema.info.description.clone(),
+        project_url: schema.info.project_url.clone(),
+        repository: schema.info.repository.clone(),
+        license_name: schema.info.license_name.clone(),
+        license_description: schema.info.license_description.clone(),
+        license_path: schema.resolve(&schema.info.license_path),
+        developers: schema
+            .info
+            .developers
+            .iter()
+            .map(|x| DeveloperInfo {
+                name: x.name.clone(),
+                email: x.email.clone(),
+                organization: x.organization.clone(),
+                organization_url: x.organization_url.clone(),
+            })
+            .collect(),
+        logo_png,
+    };
+
+    let settings = at!(
+        LibrarySettings::create(
+            &schema.name,
+            schema.c_ffi_prefix.as_ref().unwrap_or(&schema.name),
+            ClassSettings::default(),
+            IteratorSettings::default(),
+            CollectionSettings::default(),
+            FutureSettings::default(),
+            InterfaceSettings::default(),
+        ),
+        "name"
+    )?;
+
+    let mut lib = LibraryBuilder::new(version, info, settings);
+    let mut types = Types::default();
+
+    for (i, acronym) in schema.naming.acronyms.iter().enumerate() {
+        at!(lib.define_acronym(acronym), "naming", "acronyms", i)?;
+    }
+    for (i, rename) in schema.naming.renames.iter().enumerate() {
+        at!(
+            lib.rename(&rename.name, rename.language, &rename.to),
+            "naming",
+            "renames",
+            i
+        )?;
+    }
+
+    // structs and classes may be referenced before they are defined
+    for (i, item) in schema.items.iter().enumerate() {
+        match item {
+            ItemSchema::Module { name } => {
+                at!(enter_module(&mut lib, &mut types, name), "items", i, "name")?
+            }
+            ItemSchema::Struct(x) => {
+                at!(declare_struct(&mut lib, &mut types, x), "items", i, "name")?
+            }
+            ItemSchema::Class(x) => {
+                let declaration = at!(lib.declare_class(&x.name), "items", i, "name")?;
+                types.classes.insert(x.name.clone(), declaration);
+            }
+            _ => {}
+        }
+    }
+
+    lib.set_module(&Module::root())?;
+    for (i, item) in schema.items.iter().enumerate() {
+        at!(define_item(&mut lib, &mut types, item), "items", i)?;
+    }
+
+    lib.build()
+}
+
+/// Define the following items in a module, defining the module and its parents the first time
+fn enter_module(
+    lib: &mut LibraryBuilder,
+    types: &mut Types,
+    name: &Option<String>,
+) -> BindResult<()> {
+    let mut module = Module::root();
+    if let Some(name) = name {
+        let mut path = String::new();
+        for part in name.split('.') {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(part);
+            module = match types.modules.get(&path) {
+                Some(x) => x.clone(),
+                None => {
+                    let child = lib.define_submodule(&module, part)?;
+                    types.modules.insert(path.clone(), child.clone());
+                    child
+                }
+            };
+        }
+    }
+    lib.set_module(&module)
+}
+
+fn declare_struct(
+    lib: &mut LibraryBuilder,
+    types: &mut Types,
+    schema: &StructSchema,
+) -> BindResult<()> {
+    let declaration = match schema.kind {
+        StructKind::Universal => {
+            Declaration::Universal(lib.declare_universal_struct(&schema.name)?)
+        }
+        StructKind::FunctionArgument => {
+            Declaration::FunctionArg(lib.declare_function_argument_struct(&schema.name)?)
+        }
+        StructKind::FunctionReturn => {
+            Declaration::FunctionReturn(lib.declare_function_return_struct(&schema.name)?)
+        }
+        StructKind::CallbackArgument => {
+            Declaration::CallbackArg(lib.declare_callback_argument_struct(&schema.name)?)
+        }
+    };
+    types.declarations.insert(schema.name.clone(), declaration);
+    Ok(())
+}
+
+fn define_item(lib: &mut LibraryBuilder, types: &mut Types, item: &ItemSchema) -> BindResult<()> {
+    match item {
+        ItemSchema::Constants {
+            name,
+            doc: d,
+            values,
+        } => {
+            let mut builder = lib.define_constants(name)?.doc(doc(d)?)?;
+            for (i, value) in values.iter().enumerate() {
+                builder = at!(
+                    builder.add(
+                        &value.name,
+                        ConstantValue::U8(value.value, Representation::Hex),
+                        doc(&value.doc)?,
+                    ),
+                    "values",
+                    i
+                )?;
+            }
+            builder.build()?;
+        }
+        ItemSchema::Enum {
+            name,
+            doc: d,
+            variants,
+        } => {
+            let mut builder = lib.define_enum(name)?.doc(doc(d)?)?;
+            for (i, variant) in variants.iter().enumerate() {
+                builder = at!(
+                    match variant.value {
+                        Some(value) => builder.variant(&variant.name, value, doc(&variant.doc)?),
+                        None => builder.push(&variant.name, doc(&variant.doc)?),
+                    },
+                    "variants",
+                    i
+                )?;
+                if let Some(x) = &variant.deprecated {
+                    builder = at!(
+                        deprecated_since(x)
+                            .and_then(|since| builder.deprecated(since, x.note.as_str())),
+                        "variants",
+                        i
+                    )?;
+                }
+            }
+            let handle = builder.build()?;
+            types.enums.insert(name.clone(), handle);
+        }
+        ItemSchema::ErrorType {
+            name,
+            exception_name,
+            checked,
+            doc: d,
+            errors,
+        } => {
+            let exception_type = if *checked {
+                ExceptionType::CheckedException
+            } else {
+                ExceptionType::UncheckedException
+            };
+            let mut builder = lib
+                .define_error_type(name.as_str(), exception_name.as_str(), exception_type)?
+                .doc(doc(d)?)?;
+            for (i, error) in errors.iter().enumerate() {
+                builder = at!(
+                    if error.invalid_argument {
+                        builder.add_invalid_argument_error(&error.name, doc(&error.doc)?)
+                    } else {
+                        builder.add_error(&error.name, doc(&error.doc)?)
+                    },
+                    "errors",
+                    i
+                )?;
+            }
+            let error_type = builder.build()?;
+            // the underlying enum may be used like any other
+            types.enums.insert(name.clone(), error_type.clone_enum());
+            types.errors.insert(name.clone(), error_type);
+        }
+        ItemSchema::Struct(x) => define_struct(lib, types, x)?,
+        ItemSchema::Interface {
+            name,
+            doc: d,
+            asynchronous,
+            callbacks,
+        } => {
+            let mut builder = lib.define_interface(name, doc(d)?)?;
+            for (i, callback) in callbacks.iter().enumerate() {
+                builder = at!(define_callback(builder, types, callback), "callbacks", i)?;
+            }
+            let interface = if *asynchronous {
+                InterfaceDefinition::Asynchronous(builder.build_async()?)
+            } else {
+                InterfaceDefinition::Synchronous(builder.build_sync()?)
+            };
+            types.interfaces.insert(name.clone(), interface);
+        }
+        ItemSchema::FutureInterface {
+            name,
+            doc: d,
+            value,
+            value_doc,
+            error_type,
+        } => {
+            let error_type = match error_type {
+                Some(x) => Some(at!(types.error_type(x), "error_type")?),
+                None => None,
+            };
+            let value = at!(types.callback_argument(value), "value")?;
+            let future =
+                lib.define_future_interface(name, doc(d)?, value, value_doc, error_type)?;
+            types.futures.insert(name.clone(), future);
+        }
+        ItemSchema::Iterator {
+            name,
+            item,
+            lifetime,
+        } => {
+            let item = at!(types.iterator_item(item), "item")?;
+            let iterator = if *lifetime {
+                lib.define_iterator_with_lifetime(name, item)?
+            } else {
+                lib.define_iterator(name, item)?
+            };
+            types.iterators.insert(name.clone(), iterator);
+        }
+        ItemSchema::Collection {
+            name,
+            item,
+            reserve,
+        } => {
+            let item = at!(types.function_argument(item), "item")?;
+            let collection = lib.define_collection(name, item, *reserve)?;
+            types.collections.insert(name.clone(), collection);
+        }
+        ItemSchema::Function(x) => {
+            if x.host_name.is_some() {
+                return at!(
+                    Err(invalid(&x.name, "only methods have a host name")),
+                    "host_name"
+                );
+            }
+            let builder = lib.define_function(&x.name)?;
+            define_function(builder, types, x)?.build()?;
+        }
+        ItemSchema::Class(x) => define_class(lib, types, x)?,
+        ItemSchema::StaticClass {
+            name,
+            doc: d,
+            methods,
+        } => {
+            let methods = methods
+                .iter()
+                .enumerate()
+                .map(|(i, x)| at!(define_static_method(lib, types, x), "methods", i))
+                .collect::<BindResult<Vec<StaticMethod<Unvalidated>>>>()?;
+            let mut builder = lib.define_static_class(name)?.doc(doc(d)?)?;
+            for method in methods {
+                builder = builder.static_method(method)?;
+            }
+            builder.build()?;
+        }
+        ItemSchema::Module { name } => at!(enter_module(lib, types, name), "name")?,
+    }
+
+    Ok(())
+}
+
+fn define_struct(
+    lib: &mut LibraryBuilder,
+    types: &mut Types,
+    schema: &StructSchema,
+) -> BindResult<()> {
+    if schema.opaque && !matches!(schema.kind, StructKind::Universal) {
+        return at!(
+            Err(invalid(
+                &schema.name,
+                "only universal structs may be opaque"
+            )),
+            "opaque"
+        );
+    }
+
+    let definition = match types.declaration(&schema.name)? {
+        Declaration::Universal(x) => {
+            let builder = if schema.opaque {
+                lib.define_opaque_struct(x)?
+            } else {
+                lib.define_universal_struct(x)?
+            };
+            Definition::Universal(define_fields(
+                builder,
+                types,
+                schema,
+                Types::universal_field,
+            )?)
+        }
+        Declaration::FunctionArg(x) => Definition::FunctionArg(define_fields(
+            lib.define_function_argument_struct(x)?,
+            types,
+            schema,
+            Types::function_arg_field,
+        )?),
+        Declaration::FunctionReturn(x) => Definition::FunctionReturn(define_fields(
+            lib.define_function_return_struct(x)?,
+            types,
+            schema,
+            Types::function_return_field,
+        )?),
+        Declaration::CallbackArg(x) => Definition::CallbackArg(define_fields(
+            lib.define_callback_argument_struct(x)?,
+            types,
+            schema,
+            Types::callback_arg_field,
+        )?),
+    };
+
+    types.structs.insert(schema.name.clone(), definition);
+    Ok(())
+}
+
+fn define_fields<F>(
+    mut builder: StructFieldBuilder<F>,
+    types: &Types,
+    schema: &StructSchema,
+    field_type: fn(&Types, &str) -> BindResult<F>,
+) -> BindResult<Handle<Struct<F, Unvalidated>>>
+where
+    F: StructFieldType,
+{
+    builder = builder.doc(doc(&schema.doc)?)?;
+    if schema.value_semantics {
+        builder = builder.enable_value_semantics();
+    }
+    if schema.immutable {
+        builder = builder.make_immutable();
+    }
+    for (i, field) in schema.fields.iter().enumerate() {
+        let field_type = at!(field_type(types, &field.field_type), "fields", i, "type")?;
+        builder = at!(
+            builder.add(&field.name, field_type, doc(&field.doc)?),
+            "fields",
+            i
+        )?;
+        for (j, x) in field.constraints.iter().enumerate() {
+            builder = at!(
+                constraint(types, &field.field_type, x).and_then(|x| builder.constrain(x)),
+                "fields",
+                i,
+                "constraints",
+                j
+            )?;
+        }
+        if let Some(x) = &field.deprecated {
+            builder = at!(
+                deprecated_since(x).and_then(|since| builder.deprecated(since, x.note.as_str())),
+                "fields",
+                i
+            )?;
+        }
+    }
+
+    let mut builder = builder.end_fields()?;
+    for (i, initializer) in schema.initializers.iter().enumerate() {
+        let initializer_type = if initializer.is_static {
+            InitializerType::Static
+        } else {
+            InitializerType::Normal
+        };
+        let mut init = at!(
+            builder.begin_initializer(&initializer.name, initializer_type, doc(&initializer.doc)?),
+            "initializers",
+            i
+        )?;
+        // values are applied in the order of the fields
+        for field in &schema.fields {
+            if let Some(value) = initializer.values.get(&field.name) {
+                init = at!(
+                    types
+                        .initializer_default(&field.field_type, value)
+                        .and_then(|value| init.default(&Name::create(&field.name)?, value)),
+                    "initializers",
+                    i,
+                    "values",
+                    &field.name
+                )?;
+            }
+        }
+        for name in initializer.values.keys() {
+            if !schema.fields.iter().any(|x| x.name == *name) {
+                // let the builder report the unknown field
+                init = at!(
+                    Name::create(name)
+                        .map_err(BindingError::from)
+                        .and_then(|x| init.default(&x, InitializerDefault::DefaultStruct)),
+                    "initializers",
+                    i,
+                    "values",
+                    name
+                )?;
+            }
+        }
+        builder = at!(init.end_initializer(), "initializers", i)?;
+    }
+    if let Some(name) = &schema.full_initializer {
+        builder = at!(builder.add_full_initializer(name), "full_initializer")?;
+    }
+
+    builder.build()
+}
+
+fn define_callback<'a>(
+    builder: InterfaceBuilder<'a>,
+    types: &Types,
+    schema: &CallbackSchema,
+) -> BindResult<InterfaceBuilder<'a>> {
+    let mut builder = builder.begin_callback(&schema.name, doc(&schema.doc)?)?;
+    for (i, param) in schema.params.iter().enumerate() {
+        let arg = at!(
+            types.callback_argument(&param.param_type),
+            "params",
+            i,
+            "type"
+        )?;
+        builder = at!(
+            builder.param(&param.name, arg, param.doc.as_str()),
+            "params",
+            i
+        )?;
+    }
+    if let Some(returns) = &schema.returns {
+        builder = match &returns.default {
+            Some(value) => {
+                let value = at!(
+                    types.callback_default(&returns.return_type, value),
+                    "returns",
+                    "default"
+                )?;
+                at!(
+                    builder.returns_with_default(value, returns.doc.as_str()),
+                    "returns"
+                )?
+            }
+            None => {
+                let value = at!(
+                    types.callback_return(&returns.return_type),
+                    "returns",
+                    "type"
+                )?;
+                at!(builder.returns(value, returns.doc.as_str()), "returns")?
+            }
+        };
+    }
+    if schema.optional {
+        builder = at!(builder.returns_nothing_by_default(), "optional")?;
+    }
+    if schema.functional {
+        builder = builder.enable_functional_transform();
+    }
+    if let Some(x) = &schema.deprecated {
+        builder = builder.deprecated(deprecated_since(x)?, x.note.as_str())?;
+    }
+    builder.end_callback()
+}
+
+/// The function builders don't share a trait, but their parameters work the same way
+trait ParamBuilder: Sized {
+    fn add_param(self, name: &str, arg: FunctionArgument, doc: &str) -> BindResult<Self>;
+    fn add_param_with_default(
+        self,
+        name: &str,
+        arg: FunctionArgument,
+        doc: &str,
+        default: InitializerDefault,
+    ) -> BindResult<Self>;
+    fn add_constraint(self, constraint: Constraint) -> BindResult<Self>;
+    fn add_deprecation(self, since: Version, note: &str) -> BindResult<Self>;
+}
+
+macro_rules! impl_param_builder {
+    ($type:ty) => {
+        impl<'a> ParamBuilder for $type {
+            fn add_param(self, name: &str, arg: FunctionArgument, doc: &str) -> BindResult<Self> {
+                self.param(name, arg, doc)
+            }
+
+            fn add_param_with_default(
+                self,
+                name: &str,
+                arg: FunctionArgument,
+                doc: &str,
+                default: InitializerDefault,
+            ) -> BindResult<Self> {
+                self.param_with_default(name, arg, doc, default)
+            }
+
+            fn add_constraint(self, constraint: Constraint) -> BindResult<Self> {
+                self.constrain(constraint)
+            }
+
+            fn add_deprecation(self, since: Version, note: &str) -> BindResult<Self> {
+                self.deprecated(since, note)
+            }
+        }
+    };
+}
+
+impl_param_builder!(FunctionBuilder<'a>);
+impl_param_builder!(ClassMethodBuilder<'a>);
+impl_param_builder!(ClassConstructorBuilder<'a>);
+
+fn add_params<B: ParamBuilder>(
+    mut builder: B,
+    types: &Types,
+    params: &[ParamSchema],
+) -> BindResult<B> {
+    for (i, param) in params.iter().enumerate() {
+        let arg = at!(
+            types.function_argument(&param.param_type),
+            "params",
+            i,
+            "type"
+        )?;
+        builder = match &param.default {
+            None => at!(builder.add_param(&param.name, arg, &param.doc), "params", i)?,
+            Some(value) => {
+                let value = at!(
+                    types.initializer_default(&param.param_type, value),
+                    "params",
+                    i,
+                    "default"
+                )?;
+                at!(
+                    builder.add_param_with_default(&param.name, arg, &param.doc, value),
+                    "params",
+                    i
+                )?
+            }
+        };
+        for (j, x) in param.constraints.iter().enumerate() {
+            builder = at!(
+                constraint(types, &param.param_type, x).and_then(|x| builder.add_constraint(x)),
+                "params",
+                i,
+                "constraints",
+                j
+            )?;
+        }
+    }
+    Ok(builder)
+}
+
+fn add_deprecation<B: ParamBuilder>(
+    builder: B,
+    deprecated: &Option<DeprecatedSchema>,
+) -> BindResult<B> {
+    match deprecated {
+        Some(x) => builder.add_deprecation(deprecated_since(x)?, &x.note),
+        None => Ok(builder),
+    }
+}
+
+fn define_function<'a>(
+    builder: FunctionBuilder<'a>,
+    types: &Types,
+    schema: &FunctionSchema,
+) -> BindResult<FunctionBuilder<'a>> {
+    let mut builder = add_params(builder, types, &schema.params)?;
+    if let Some(returns) = &schema.returns {
+        let value = at!(
+            types.function_return(&returns.return_type),
+            "returns",
+            "type"
+        )?;
+        builder = builder.returns(value, returns.doc.as_str())?;
+    }
+    if let Some(error) = &schema.fails_with {
+        builder = at!(
+            types.error_type(error).and_then(|x| builder.fails_with(x)),
+            "fails_with"
+        )?;
+    }
+    add_deprecation(builder, &schema.deprecated)?.doc(doc(&schema.doc)?)
+}
+
+fn define_static_method(
+    lib: &mut LibraryBuilder,
+    types: &Types,
+    schema: &FunctionSchema,
+) -> BindResult<StaticMethod<Unvalidated>> {
+    let builder = at!(lib.define_function(&schema.name), "name")?;
+    let builder = define_function(builder, types, schema)?;
+    match &schema.host_name {
+        Some(name) => at!(builder.build_static(name), "host_name"),
+        None => builder.build_static_with_same_name(),
+    }
+}
+
+fn define_constructor(
+    lib: &mut LibraryBuilder,
+    types: &Types,
+    class: ClassDeclarationHandle,
+    schema: &ConstructorSchema,
+) -> BindResult<ClassConstructor<Unvalidated>> {
+    let mut builder = add_params(lib.define_constructor(class)?, types, &schema.params)?;
+    if let Some(error) = &schema.fails_with {
+        builder = at!(
+            types.error_type(error).and_then(|x| builder.fails_with(x)),
+            "fails_with"
+        )?;
+    }
+    add_deprecation(builder, &schema.deprecated)?
+        .doc(doc(&schema.doc)?)?
+        .build()
+}
+
+fn define_method(
+    lib: &mut LibraryBuilder,
+    types: &Types,
+    class: ClassDeclarationHandle,
+    schema: &FunctionSchema,
+) -> BindResult<Method<Unvalidated>> {
+    let builder = at!(lib.define_method(&schema.name, class), "name")?;
+    let mut builder = add_params(builder, types, &schema.params)?;
+    if let Some(returns) = &schema.returns {
+        let value = at!(
+            types.function_return(&returns.return_type),
+            "returns",
+            "type"
+        )?;
+        builder = builder.returns(value, returns.doc.as_str())?;
+    }
+    if let Some(error) = &schema.fails_with {
+        builder = at!(
+            types.error_type(error).and_then(|x| builder.fails_with(x)),
+            "fails_with"
+        )?;
+    }
+    let builder = add_deprecation(builder, &schema.deprecated)?.doc(doc(&schema.doc)?)?;
+    match &schema.host_name {
+        Some(name) => at!(builder.build_overload(name), "host_name"),
+        None => builder.build(),
+    }
+}
+
+fn define_future_method(
+    lib: &mut LibraryBuilder,
+    types: &Types,
+    class: ClassDeclarationHandle,
+    schema: &FutureMethodSchema,
+) -> BindResult<FutureMethod<Unvalidated>> {
+    let future = at!(types.future(&schema.future), "future")?;
+    let mut builder = at!(
+        lib.define_future_method(&schema.name, class, future),
+        "name"
+    )?;
+    for (i, param) in schema.params.iter().enumerate() {
+        if param.default.is_some() {
+            return at!(
+                Err(invalid(
+                    &schema.name,
+                    "parameters of future methods can't have a default value",
+                )),
+                "params",
+                i,
+                "default"
+            );
+        }
+        let arg = at!(
+            types.function_argument(&param.param_type),
+            "params",
+            i,
+            "type"
+        )?;
+        builder = at!(
+            builder.param(&param.name, arg, param.doc.as_str()),
+            "params",
+            i
+        )?;
+        for (j, x) in param.constraints.iter().enumerate() {
+            builder = at!(
+                constraint(types, &param.param_type, x).and_then(|x| builder.constrain(x)),
+                "params",
+                i,
+                "constraints",
+                j
+            )?;
+        }
+    }
+    if let Some(error) = &schema.fails_with {
+        builder = at!(
+            types.error_type(error).and_then(|x| builder.fails_with(x)),
+            "fails_with"
+        )?;
+    }
+    if let Some(x) = &schema.deprecated {
+        builder = builder.deprecated(deprecated_since(x)?, x.note.as_str())?;
+    }
+    builder.doc(doc(&schema.doc)?)?.build()
+}
+
+fn define_class(lib: &mut LibraryBuilder, types: &Types, schema: &ClassSchema) -> BindResult<()> {
+    let class = types.classes[&schema.name].clone();
+
+    let constructor = match &schema.constructor {
+        None => None,
+        Some(x) => Some(at!(
+            define_constructor(lib, types, class.clone(), x),
+            "constructor"
+        )?),
+    };
+
+    let destructor = match &schema.destructor {
+        None => None,
+        Some(x) => Some(at!(
+            lib.define_destructor(class.clone(), doc(&x.doc)?),
+            "destructor"
+        )?),
+    };
+
+    let methods = schema
+        .methods
+        .iter()
+        .enumerate()
+        .map(|(i, x)| at!(define_method(lib, types, class.clone(), x), "methods", i))
+        .collect::<BindResult<Vec<Method<Unvalidated>>>>()?;
+
+    let static_methods = schema
+        .static_methods
+        .iter()
+        .enumerate()
+        .map(|(i, x)| at!(define_static_method(lib, types, x), "static_methods", i))
+        .collect::<BindResult<Vec<StaticMethod<Unvalidated>>>>()?;
+
+    let future_methods = schema
+        .future_methods
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            at!(
+                define_future_method(lib, types, class.clone(), x),
+                "future_methods",
+                i
+            )
+        })
+        .collect::<BindResult<Vec<FutureMethod<Unvalidated>>>>()?;
+
+    let mut builder = lib.define_class(&class)?.doc(doc(&schema.doc)?)?;
+    if let Some(x) = constructor {
+        builder = builder.constructor(x)?;
+    }
+    if let Some(x) = destructor {
+        builder = builder.destructor(x)?;
+    }
+    for x in methods {
+        builder = builder.method(x)?;
+    }
+    for x in static_methods {
+        builder = builder.static_method(x)?;
+    }
+    for x in future_methods {
+        builder = builder.async_method(x)?;
+    }
+    if let Some(x) = &schema.destructor {
+        if x.disposable {
+            builder = at!(builder.disposable_destroy(), "destructor", "disposable")?;
+        } else if let Some(method) = &x.method {
+            builder = at!(builder.custom_destroy(method), "destructor", "method")?;
+        }
+    }
+    builder.build()?;
+
+    Ok(())
+}
@@ -0,0 +1,986 @@
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+
+use crate::backend::*;
+use crate::model::*;
+use crate::schema::BindingsSchema;
+
+/// Write a library as a TOML schema that builds the same library
+///
+/// Items are written in the order of the statements of the library. Documentation that the
+/// builders generate, e.g. the description of the constraints of an argument, is omitted
+/// since it is generated again when the schema is built.
+pub(crate) fn export(
+    path: &Path,
+    lib: &Library,
+    bindings: Option<&BindingsSchema>,
+) -> FormattingResult<()> {
+    let mut f = FilePrinter::new(path, Naming::default())?;
+
+    f.writeln(&format!("name = {}", quote(&lib.settings.name)))?;
+    if lib.settings.c_ffi_prefix != lib.settings.name {
+        f.writeln(&format!(
+            "c_ffi_prefix = {}",
+            quote(&lib.settings.c_ffi_prefix)
+        ))?;
+    }
+    f.writeln(&format!("version = {}", quote(&lib.version.to_string())))?;
+    f.newline()?;
+
+    write_info(&mut f, path, &lib.info)?;
+    if let Some(bindings) = bindings {
+        f.newline()?;
+        write_bindings(&mut f, path, bindings)?;
+    }
+    write_naming(&mut f, &lib.naming_rules)?;
+
+    // functions that are exported as part of a class and enums that are part of an error type
+    let mut owned = HashSet::new();
+    // added when the library is built
+    owned.insert(Name::create("version")?);
+    for statement in lib.statements() {
+        match statement {
+            Statement::ErrorType(x) => {
+                owned.insert(x.inner.name.clone());
+            }
+            Statement::ClassDefinition(x) => {
+                owned.extend(x.constructor.iter().map(|x| x.function.name.clone()));
+                owned.extend(x.destructor.iter().map(|x| x.function.name.clone()));
+                owned.extend(x.methods.iter().map(|x| x.native_function.name.clone()));
+                owned.extend(
+                    x.static_methods
+                        .iter()
+                        .map(|x| x.native_function.name.clone()),
+                );
+                owned.extend(
+                    x.future_methods
+                        .iter()
+                        .map(|x| x.native_function.name.clone()),
+                );
+            }
+            Statement::StaticClassDefinition(x) => {
+                owned.extend(
+                    x.static_methods
+                        .iter()
+                        .map(|x| x.native_function.name.clone()),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let mut module = Module::root();
+    for statement in lib.statements().filter(|x| is_item(x, &owned)) {
+        if statement.module() != &module {
+            module = statement.module().clone();
+            write_module(&mut f, &module)?;
+        }
+        match statement {
+            Statement::Constants(x) => write_constants(&mut f, x)?,
+            Statement::StructDefinition(x) => write_struct(&mut f, x)?,
+            Statement::EnumDefinition(x) => write_enum(&mut f, x)?,
+            Statement::ErrorType(x) => write_error_type(&mut f, x)?,
+            Statement::ClassDefinition(x) => write_class(&mut f, x)?,
+            Statement::StaticClassDefinition(x) => write_static_class(&mut f, x)?,
+            Statement::InterfaceDefinition(x) => write_interface(&mut f, x)?,
+            Statement::IteratorDeclaration(x) => write_iterator(&mut f, x)?,
+            Statement::CollectionDeclaration(x) => write_collection(&mut f, x)?,
+            Statement::FunctionDefinition(x) => {
+                f.newline()?;
+                f.writeln("[[items]]")?;
+                f.writeln("type = \"function\"")?;
+                write_function(&mut f, &x.name, None, x, 0)?;
+            }
+            Statement::StructDeclaration(_) | Statement::ClassDeclaration(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// true if the statement is written as an item, the others are implicit or part of another item
+fn is_item(statement: &Statement<Validated>, owned: &HashSet<Name>) -> bool {
+    match statement {
+        Statement::EnumDefinition(x) => !owned.contains(&x.name),
+        Statement::FunctionDefinition(x) => {
+            x.category == FunctionCategory::Native && !owned.contains(&x.name)
+        }
+        // structs and classes are declared implicitly
+        Statement::StructDeclaration(_) | Statement::ClassDeclaration(_) => false,
+        _ => true,
+    }
+}
+
+fn write_module(f: &mut dyn Printer, module: &Module) -> FormattingResult<()> {
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"module\"")?;
+    if !module.is_root() {
+        f.writeln(&format!("name = {}", quote(&module.to_string())))?;
+    }
+    Ok(())
+}
+
+fn quote(value: &str) -> String {
+    // JSON strings are valid TOML basic strings
+    serde_json::Value::from(value).to_string()
+}
+
+fn write_info(f: &mut dyn Printer, path: &Path, info: &LibraryInfo) -> FormattingResult<()> {
+    f.writeln("[info]")?;
+    f.writeln(&format!("description = {}", quote(&info.description)))?;
+    f.writeln(&format!("project_url = {}", quote(&info.project_url)))?;
+    f.writeln(&format!("repository = {}", quote(&info.repository)))?;
+    f.writeln(&format!("license_name = {}", quote(&info.license_name)))?;
+    if !info.license_description.is_empty() {
+        f.writeln("license_description = [")?;
+        for line in &info.license_description {
+            f.writeln(&format!("    {},", quote(line)))?;
+        }
+        f.writeln("]")?;
+    }
+    f.writeln(&format!(
+        "license_path = {}",
+        quote(&relative_to_schema(path, &info.license_path).to_string_lossy())
+    ))?;
+    if !info.logo_png.is_empty() {
+        // the logo is embedded in the library, so it is written next to the schema
+        let logo = path.with_file_name("logo.png");
+        std::fs::write(&logo, info.logo_png)?;
+        f.writeln("logo_png = \"logo.png\"")?;
+    }
+    if !info.developers.is_empty() {
+        f.writeln("developers = [")?;
+        for x in &info.developers {
+            f.writeln(&format!(
+                "    {{ name = {}, email = {}, organization = {}, organization_url = {} }},",
+                quote(&x.name),
+                quote(&x.email),
+                quote(&x.organization),
+                quote(&x.organization_url)
+            ))?;
+        }
+        f.writeln("]")?;
+    }
+    Ok(())
+}
+
+fn write_naming(f: &mut dyn Printer, naming: &NamingRules) -> FormattingResult<()> {
+    if naming.acronyms.is_empty() && naming.renames.is_empty() {
+        return Ok(());
+    }
+
+    f.newline()?;
+    f.writeln("[naming]")?;
+    if !naming.acronyms.is_empty() {
+        let acronyms: Vec<String> = naming.acronyms.iter().map(|x| quote(x)).collect();
+        f.writeln(&format!("acronyms = [{}]", acronyms.join(", ")))?;
+    }
+    for ((language, name), replacement) in &naming.renames {
+        f.newline()?;
+        f.writeln("[[naming.renames]]")?;
+        f.writeln(&format!("name = {}", quote(name)))?;
+        f.writeln(&format!("language = {}", quote(&language.to_string())))?;
+        f.writeln(&format!("to = {}", quote(replacement)))?;
+    }
+    Ok(())
+}
+
+fn write_bindings(
+    f: &mut dyn Printer,
+    path: &Path,
+    bindings: &BindingsSchema,
+) -> FormattingResult<()> {
+    f.writeln("[bindings]")?;
+    f.writeln(&format!(
+        "ffi_target_name = {}",
+        quote(&bindings.ffi_target_name)
+    ))?;
+    f.writeln(&format!(
+        "jni_target_name = {}",
+        quote(&bindings.jni_target_name)
+    ))?;
+    if let Some(x) = &bindings.ffi_name {
+        f.writeln(&format!("ffi_name = {}", quote(x)))?;
+    }
+    f.writeln(&format!(
+        "ffi_path = {}",
+        quote(&relative_to_schema(path, &bindings.ffi_path).to_string_lossy())
+    ))?;
+    f.writeln(&format!(
+        "java_group_id = {}",
+        quote(&bindings.java_group_id)
+    ))?;
+    f.writeln(&format!(
+        "destination_path = {}",
+        quote(&relative_to_schema(path, &bindings.destination_path).to_string_lossy())
+    ))
+}
+
+fn reference(x: &Validated) -> String {
+    match x {
+        Validated::Argument(x) => format!("{{param:{}}}", x.as_ref()),
+        Validated::Class(x) => format!("{{class:{}}}", x.name.as_ref()),
+        Validated::ClassMethod(class, name, _) => {
+            format!("{{class:{}.{}()}}", class.name().as_ref(), name.as_ref())
+        }
+        Validated::ClassConstructor(class, _) => {
+            format!("{{class:{}.[constructor]}}", class.name().as_ref())
+        }
+        Validated::ClassDestructor(class, _) => {
+            format!("{{class:{}.[destructor]}}", class.name().as_ref())
+        }
+        Validated::Struct(x) => format!("{{struct:{}}}", x.name().as_ref()),
+        Validated::StructField(x, field) => {
+            format!("{{struct:{}.{}}}", x.name().as_ref(), field.as_ref())
+        }
+        Validated::Enum(x) => format!("{{enum:{}}}", x.name.as_ref()),
+        Validated::EnumVariant(x, variant) => {
+            format!("{{enum:{}.{}}}", x.name.as_ref(), variant.as_ref())
+        }
+        Validated::Interface(x) => format!("{{interface:{}}}", x.name.as_ref()),
+        Validated::InterfaceMethod(x, method) => {
+            format!("{{interface:{}.{}()}}", x.name.as_ref(), method.as_ref())
+        }
+    }
+}
+
+/// Source of a doc string, skipping the generated elements at the end
+fn doc_string(doc: &DocString<Validated>, generated: usize) -> String {
+    let elements: Vec<&DocStringElement<Validated>> = doc.elements().collect();
+    let elements = &elements[..elements.len().saturating_sub(generated)];
+    elements
+        .iter()
+        .enumerate()
+        .map(|(idx, x)| match x {
+            DocStringElement::Text(x) => x.clone(),
+            DocStringElement::Null => "{null}".to_string(),
+            DocStringElement::Iterator => "{iterator}".to_string(),
+            DocStringElement::Reference(x) => reference(x),
+            DocStringElement::Code(x) => format!("`{}`", x),
+            DocStringElement::Bold(x) => format!("**{}**", x),
+            DocStringElement::Link { text, url } => format!("[{}]({})", text, url),
+            DocStringElement::List(items) => {
+                // each item on its own line
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| format!("- {}", doc_string(item, 0)))
+                    .collect();
+                let before = if idx == 0 { "" } else { "\n" };
+                let after = if idx + 1 < elements.len() { "\n" } else { "" };
+                format!("{}{}{}", before, items.join("\n"), after)
+            }
+        })
+        .collect()
+}
+
+/// Doc as either a string or an inline table, skipping the generated paragraphs at the end
+fn doc(doc: &Doc<Validated>, generated: usize) -> String {
+    let brief = quote(&doc_string(&doc.brief, 0));
+    let details = &doc.details[..doc.details.len().saturating_sub(generated)];
+    if details.is_empty() && doc.since.is_none() {
+        return brief;
+    }
+
+    let details: Vec<String> = details
+        .iter()
+        .map(|x| match x {
+            DocParagraph::Details(x) => quote(&doc_string(x, 0)),
+            DocParagraph::Warning(x) => format!("{{ warning = {} }}", quote(&doc_string(x, 0))),
+            DocParagraph::Example(x) => {
+                let snippets: Vec<String> = x
+                    .snippets
+                    .iter()
+                    .map(|(language, code)| {
+                        let key = match language {
+                            ExampleLanguage::C => "c",
+                            ExampleLanguage::Cpp => "cpp",
+                            ExampleLanguage::Java => "java",
+                            ExampleLanguage::DotNet => "dotnet",
+                        };
+                        format!("{} = {}", key, quote(code))
+                    })
+                    .collect();
+                format!("{{ example = {{ {} }} }}", snippets.join(", "))
+            }
+        })
+        .collect();
+    let since = match &doc.since {
+        Some(x) => format!(", since = {}", quote(&x.to_string())),
+        None => String::new(),
+    };
+    format!(
+        "{{ brief = {}, details = [{}]{} }}",
+        brief,
+        details.join(", "),
+        since
+    )
+}
+
+/// Deprecation of an item as an inline table
+fn deprecated(doc: &Doc<Validated>) -> Option<String> {
+    doc.deprecated.as_ref().map(|x| {
+        format!(
+            "{{ since = {}, note = {} }}",
+            quote(&x.since.to_string()),
+            quote(&doc_string(&x.note, 0))
+        )
+    })
+}
+
+/// Type expressions of the schema, see `schema::types`
+trait TypeName {
+    fn type_name(&self) -> String;
+}
+
+impl TypeName for Primitive {
+    fn type_name(&self) -> String {
+        match self {
+            Primitive::Bool => "bool",
+            Primitive::U8 => "u8",
+            Primitive::S8 => "s8",
+            Primitive::U16 => "u16",
+            Primitive::S16 => "s16",
+            Primitive::U32 => "u32",
+            Primitive::S32 => "s32",
+            Primitive::U64 => "u64",
+            Primitive::S64 => "s64",
+            Primitive::Float => "float",
+            Primitive::Double => "double",
+        }
+        .to_string()
+    }
+}
+
+impl TypeName for BasicType {
+    fn type_name(&self) -> String {
+        match self {
+            BasicType::Primitive(x) => x.type_name(),
+            BasicType::Duration(DurationType::Milliseconds) => "duration_ms".to_string(),
+            BasicType::Duration(DurationType::Seconds) => "duration_s".to_string(),
+            BasicType::Enum(x) => x.name.as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for UniversalStructField {
+    fn type_name(&self) -> String {
+        match self {
+            UniversalStructField::Basic(x) => x.type_name(),
+            UniversalStructField::Struct(x) => x.name().as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for FunctionArgStructField {
+    fn type_name(&self) -> String {
+        match self {
+            FunctionArgStructField::Basic(x) => x.type_name(),
+            FunctionArgStructField::String(_) => "string".to_string(),
+            FunctionArgStructField::Interface(x) => x.inner.name.as_ref().to_string(),
+            FunctionArgStructField::Struct(x) => x.name().as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for FunctionReturnStructField {
+    fn type_name(&self) -> String {
+        match self {
+            FunctionReturnStructField::Basic(x) => x.type_name(),
+            FunctionReturnStructField::ClassRef(x) => x.name.as_ref().to_string(),
+            FunctionReturnStructField::Iterator(x) => x.name().as_ref().to_string(),
+            FunctionReturnStructField::Struct(x) => x.name().as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for CallbackArgStructField {
+    fn type_name(&self) -> String {
+        match self {
+            CallbackArgStructField::Basic(x) => x.type_name(),
+            CallbackArgStructField::Iterator(x) => x.name().as_ref().to_string(),
+            CallbackArgStructField::Struct(x) => x.name().as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for FunctionArgument {
+    fn type_name(&self) -> String {
+        match self {
+            FunctionArgument::Basic(x) => x.type_name(),
+            FunctionArgument::String(_) => "string".to_string(),
+            FunctionArgument::Collection(x) => x.name().as_ref().to_string(),
+            FunctionArgument::Struct(x) => x.name().as_ref().to_string(),
+            FunctionArgument::StructRef(x) => format!("&{}", x.name().as_ref()),
+            FunctionArgument::ClassRef(x) => x.name.as_ref().to_string(),
+            FunctionArgument::Interface(x) => x.name.as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for FunctionReturnValue {
+    fn type_name(&self) -> String {
+        match self {
+            FunctionReturnValue::Basic(x) => x.type_name(),
+            FunctionReturnValue::PrimitiveRef(x) => format!("&{}", x.inner.type_name()),
+            FunctionReturnValue::String(_) => "string".to_string(),
+            FunctionReturnValue::ClassRef(x) => x.name.as_ref().to_string(),
+            FunctionReturnValue::Struct(x) => x.name().as_ref().to_string(),
+            FunctionReturnValue::StructRef(x) => format!("&{}", x.untyped().name.as_ref()),
+        }
+    }
+}
+
+impl TypeName for CallbackArgument {
+    fn type_name(&self) -> String {
+        match self {
+            CallbackArgument::Basic(x) => x.type_name(),
+            CallbackArgument::String(_) => "string".to_string(),
+            CallbackArgument::Iterator(x) => x.name().as_ref().to_string(),
+            CallbackArgument::Class(x) => x.name.as_ref().to_string(),
+            CallbackArgument::Struct(x) => x.name().as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for CallbackReturnValue {
+    fn type_name(&self) -> String {
+        match self {
+            CallbackReturnValue::Basic(x) => x.type_name(),
+            CallbackReturnValue::Struct(x) => x.name().as_ref().to_string(),
+        }
+    }
+}
+
+impl TypeName for IteratorItemType {
+    fn type_name(&self) -> String {
+        match self {
+            IteratorItemType::Primitive(x) => x.type_name(),
+            IteratorItemType::Struct(x) => x.name().as_ref().to_string(),
+        }
+    }
+}
+
+fn default_value(value: &ValidatedDefaultValue) -> String {
+    match value {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => x.to_string(),
+        ValidatedDefaultValue::Duration(DurationType::Milliseconds, x) => x.as_millis().to_string(),
+        ValidatedDefaultValue::Duration(DurationType::Seconds, x) => x.as_secs().to_string(),
+        ValidatedDefaultValue::Enum(_, x) => quote(x),
+        ValidatedDefaultValue::String(x) => quote(x),
+        ValidatedDefaultValue::DefaultStruct(..) => quote("default"),
+    }
+}
+
+fn callback_default(value: &DefaultCallbackReturnValue) -> Option<String> {
+    match value {
+        DefaultCallbackReturnValue::Void => None,
+        DefaultCallbackReturnValue::Basic(BasicValue::Primitive(x)) => Some(match x {
+            PrimitiveValue::Bool(x) => x.to_string(),
+            PrimitiveValue::U8(x) => x.to_string(),
+            PrimitiveValue::S8(x) => x.to_string(),
+            PrimitiveValue::U16(x) => x.to_string(),
+            PrimitiveValue::S16(x) => x.to_string(),
+            PrimitiveValue::U32(x) => x.to_string(),
+            PrimitiveValue::S32(x) => x.to_string(),
+            PrimitiveValue::U64(x) => x.to_string(),
+            PrimitiveValue::S64(x) => x.to_string(),
+            PrimitiveValue::Float(x) => x.to_string(),
+            PrimitiveValue::Double(x) => x.to_string(),
+        }),
+        DefaultCallbackReturnValue::Basic(BasicValue::Duration(x)) => Some(match x {
+            DurationValue::Milliseconds(x) => x.to_string(),
+            DurationValue::Seconds(x) => x.to_string(),
+        }),
+        DefaultCallbackReturnValue::Basic(BasicValue::Enum(x)) => Some(quote(&x.variant.name)),
+        DefaultCallbackReturnValue::InitializedStruct(x) => Some(quote(&x.initializer.name)),
+    }
+}
+
+fn constraint(x: &Constraint) -> String {
+    match x {
+        Constraint::Range { min, max } => {
+            format!("{{ range = {{ min = {}, max = {} }} }}", min, max)
+        }
+        Constraint::NonZero => quote("non_zero"),
+        Constraint::NonEmpty => quote("non_empty"),
+        Constraint::MaxLength(x) => format!("{{ max_length = {} }}", x),
+        Constraint::Regex(x) => format!("{{ regex = {} }}", quote(x)),
+    }
+}
+
+/// Write an array of inline tables, one per line
+fn write_array(f: &mut dyn Printer, key: &str, values: &[String]) -> FormattingResult<()> {
+    if values.is_empty() {
+        return Ok(());
+    }
+    f.writeln(&format!("{} = [", key))?;
+    for value in values {
+        f.writeln(&format!("    {},", value))?;
+    }
+    f.writeln("]")
+}
+
+fn param<T: TypeName + Clone>(arg: &Arg<T, Validated>) -> String {
+    // the builders describe the constraints and default value in the doc
+    let generated = usize::from(!arg.constraints.is_empty()) + usize::from(arg.default.is_some());
+    let mut result = format!(
+        "{{ name = {}, type = {}, doc = {}",
+        quote(&arg.name),
+        quote(&arg.arg_type.type_name()),
+        quote(&doc_string(&arg.doc, generated))
+    );
+    if let Some(x) = &arg.default {
+        result.push_str(&format!(", default = {}", default_value(x)));
+    }
+    if !arg.constraints.is_empty() {
+        let constraints: Vec<String> = arg.constraints.iter().map(constraint).collect();
+        result.push_str(&format!(", constraints = [{}]", constraints.join(", ")));
+    }
+    result.push_str(" }");
+    result
+}
+
+fn params<T: TypeName + Clone>(args: &[Arg<T, Validated>]) -> Vec<String> {
+    args.iter().map(param).collect()
+}
+
+/// Write the keys of a function, skipping the parameters that the builders add implicitly
+fn write_function(
+    f: &mut dyn Printer,
+    name: &str,
+    host_name: Option<&Name>,
+    function: &Function<Validated>,
+    instance: usize,
+) -> FormattingResult<()> {
+    f.writeln(&format!("name = {}", quote(name)))?;
+    if let Some(x) = host_name {
+        f.writeln(&format!("host_name = {}", quote(x)))?;
+    }
+    f.writeln(&format!("doc = {}", doc(&function.doc, 0)))?;
+    write_array(f, "params", &params(&function.arguments[instance..]))?;
+    if let Some(x) = function.return_type.get() {
+        f.writeln(&format!(
+            "returns = {{ type = {}, doc = {} }}",
+            quote(&x.value.type_name()),
+            quote(&doc_string(&x.doc, 0))
+        ))?;
+    }
+    if let Some(x) = function.error_type.get() {
+        f.writeln(&format!("fails_with = {}", quote(&x.inner.name)))?;
+    }
+    if let Some(x) = deprecated(&function.doc) {
+        f.writeln(&format!("deprecated = {}", x))?;
+    }
+    Ok(())
+}
+
+fn write_constants(f: &mut dyn Printer, x: &ConstantSet<Validated>) -> FormattingResult<()> {
+    let values: Vec<String> = x
+        .values
+        .iter()
+        .map(|x| {
+            let ConstantValue::U8(value, _) = x.value;
+            format!(
+                "{{ name = {}, value = {}, doc = {} }}",
+                quote(&x.name),
+                value,
+                doc(&x.doc, 0)
+            )
+        })
+        .collect();
+
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"constants\"")?;
+    f.writeln(&format!("name = {}", quote(&x.name)))?;
+    f.writeln(&format!("doc = {}", doc(&x.doc, 0)))?;
+    write_array(f, "values", &values)
+}
+
+fn write_enum(f: &mut dyn Printer, x: &Enum<Validated>) -> FormattingResult<()> {
+    let variants: Vec<String> = x
+        .variants
+        .iter()
+        .map(|x| {
+            let mut result = format!(
+                "{{ name = {}, value = {}, doc = {}",
+                quote(&x.name),
+                x.value,
+                doc(&x.doc, 0)
+            );
+            if let Some(x) = deprecated(&x.doc) {
+                result.push_str(&format!(", deprecated = {}", x));
+            }
+            result.push_str(" }");
+            result
+        })
+        .collect();
+
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"enum\"")?;
+    f.writeln(&format!("name = {}", quote(&x.name)))?;
+    f.writeln(&format!("doc = {}", doc(&x.doc, 0)))?;
+    write_array(f, "variants", &variants)
+}
+
+fn write_error_type(f: &mut dyn Printer, x: &ErrorType<Validated>) -> FormattingResult<()> {
+    // the builder adds the "ok" variant
+    let errors: Vec<String> = x
+        .inner
+        .variants
+        .iter()
+        .skip(1)
+        .map(|v| {
+            let invalid_argument = if x.invalid_argument.as_ref() == Some(&v.name) {
+                ", invalid_argument = true"
+            } else {
+                ""
+            };
+            format!(
+                "{{ name = {}, doc = {}{} }}",
+                quote(&v.name),
+                doc(&v.doc, 0),
+                invalid_argument
+            )
+        })
+        .collect();
+
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"error_type\"")?;
+    f.writeln(&format!("name = {}", quote(&x.inner.name)))?;
+    f.writeln(&format!("exception_name = {}", quote(&x.exception_name)))?;
+    if x.exception_type == ExceptionType::CheckedException {
+        f.writeln("checked = true")?;
+    }
+    f.writeln(&format!("doc = {}", doc(&x.inner.doc, 0)))?;
+    write_array(f, "errors", &errors)
+}
+
+fn write_struct(f: &mut dyn Printer, x: &StructType<Validated>) -> FormattingResult<()> {
+    match x {
+        StructType::FunctionArg(x) => write_struct_fields(f, "function_argument", x),
+        StructType::FunctionReturn(x) => write_struct_fields(f, "function_return", x),
+        StructType::CallbackArg(x) => write_struct_fields(f, "callback_argument", x),
+        StructType::Universal(x) => write_struct_fields(f, "universal", x),
+    }
+}
+
+fn write_struct_fields<F>(
+    f: &mut dyn Printer,
+    kind: &str,
+    x: &Struct<F, Validated>,
+) -> FormattingResult<()>
+where
+    F: StructFieldType + TypeName,
+{
+    let fields: Vec<String> = x
+        .fields
+        .iter()
+        .map(|field| {
+            // the builder describes the constraints in a paragraph
+            let generated = usize::from(!field.constraints.is_empty());
+            let mut result = format!(
+                "{{ name = {}, type = {}, doc = {}",
+                quote(&field.name),
+                quote(&field.field_type.type_name()),
+                doc(&field.doc, generated)
+            );
+            if !field.constraints.is_empty() {
+                let constraints: Vec<String> = field.constraints.iter().map(constraint).collect();
+                result.push_str(&format!(", constraints = [{}]", constraints.join(", ")));
+            }
+            if let Some(x) = deprecated(&field.doc) {
+                result.push_str(&format!(", deprecated = {}", x));
+            }
+            result.push_str(" }");
+            result
+        })
+        .collect();
+
+    let initializers: Vec<String> = x
+        .initializers
+        .iter()
+        .map(|init| {
+            let mut result = format!(
+                "{{ name = {}, doc = {}",
+                quote(&init.name),
+                doc(&init.doc, 0)
+            );
+            if init.initializer_type == InitializerType::Static {
+                result.push_str(", static = true");
+            }
+            if !init.values.is_empty() {
+                let values: Vec<String> = init
+                    .values
+                    .iter()
+                    .map(|x| format!("{} = {}", x.name.as_ref(), default_value(&x.value)))
+                    .collect();
+                result.push_str(&format!(", values = {{ {} }}", values.join(", ")));
+            }
+            result.push_str(" }");
+            result
+        })
+        .collect();
+
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"struct\"")?;
+    f.writeln(&format!("name = {}", quote(x.name())))?;
+    f.writeln(&format!("kind = \"{}\"", kind))?;
+    f.writeln(&format!("doc = {}", doc(&x.doc, 0)))?;
+    if x.visibility == Visibility::Private {
+        f.writeln("opaque = true")?;
+    }
+    if x.value_semantics {
+        f.writeln("value_semantics = true")?;
+    }
+    if x.immutable {
+        f.writeln("immutable = true")?;
+    }
+    // the schema requires the fields even if there are none
+    if fields.is_empty() {
+        f.writeln("fields = []")?;
+    }
+    write_array(f, "fields", &fields)?;
+    write_array(f, "initializers", &initializers)
+}
+
+fn write_interface(f: &mut dyn Printer, x: &InterfaceType<Validated>) -> FormattingResult<()> {
+    let (interface, asynchronous) = match x {
+        InterfaceType::Synchronous(x) => (x, false),
+        InterfaceType::Asynchronous(x) => (x, true),
+        InterfaceType::Future(x) => {
+            f.newline()?;
+            f.writeln("[[items]]")?;
+            f.writeln("type = \"future_interface\"")?;
+            f.writeln(&format!("name = {}", quote(&x.interface.name)))?;
+            f.writeln(&format!("doc = {}", doc(&x.interface.doc, 0)))?;
+            f.writeln(&format!("value = {}", quote(&x.value_type.type_name())))?;
+            f.writeln(&format!(
+                "value_doc = {}",
+                quote(&doc_string(&x.value_type_doc, 0))
+            ))?;
+            if let Some(x) = x.error_type.get() {
+                f.writeln(&format!("error_type = {}", quote(&x.inner.name)))?;
+            }
+            return Ok(());
+        }
+    };
+
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"interface\"")?;
+    f.writeln(&format!("name = {}", quote(&interface.name)))?;
+    f.writeln(&format!("doc = {}", doc(&interface.doc, 0)))?;
+    if asynchronous {
+        f.writeln("asynchronous = true")?;
+    }
+    if interface.callbacks.is_empty() {
+        f.writeln("callbacks = []")?;
+    }
+
+    for cb in &interface.callbacks {
+        f.newline()?;
+        f.writeln("[[items.callbacks]]")?;
+        f.writeln(&format!("name = {}", quote(&cb.name)))?;
+        f.writeln(&format!("doc = {}", doc(&cb.doc, 0)))?;
+        write_array(f, "params", &params(&cb.arguments))?;
+        if let Some(x) = cb.return_type.get() {
+            let default = match cb
+                .default_implementation
+                .as_ref()
+                .and_then(callback_default)
+            {
+                Some(x) => format!(", default = {}", x),
+                None => String::new(),
+            };
+            f.writeln(&format!(
+                "returns = {{ type = {}, doc = {}{} }}",
+                quote(&x.value.type_name()),
+                quote(&doc_string(&x.doc, 0)),
+                default
+            ))?;
+        }
+        if let Some(DefaultCallbackReturnValue::Void) = cb.default_implementation {
+            f.writeln("optional = true")?;
+        }
+        if cb.functional_transform.enabled() {
+            f.writeln("functional = true")?;
+        }
+        if let Some(x) = deprecated(&cb.doc) {
+            f.writeln(&format!("deprecated = {}", x))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_iterator(f: &mut dyn Printer, x: &AbstractIterator<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"iterator\"")?;
+    f.writeln(&format!("name = {}", quote(x.name())))?;
+    f.writeln(&format!("item = {}", quote(&x.item_type.type_name())))?;
+    if x.has_lifetime_annotation {
+        f.writeln("lifetime = true")?;
+    }
+    Ok(())
+}
+
+fn write_collection(f: &mut dyn Printer, x: &Collection<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"collection\"")?;
+    f.writeln(&format!("name = {}", quote(x.name())))?;
+    f.writeln(&format!("item = {}", quote(&x.item_type.type_name())))?;
+    if x.has_reserve {
+        f.writeln("reserve = true")?;
+    }
+    Ok(())
+}
+
+/// Write static methods, the host name is omitted if it's the name of the native function
+fn write_static_methods(
+    f: &mut dyn Printer,
+    key: &str,
+    methods: &[StaticMethod<Validated>],
+) -> FormattingResult<()> {
+    for method in methods {
+        let function = &method.native_function;
+        let host_name = Some(&method.name).filter(|x| **x != function.name);
+        f.newline()?;
+        f.writeln(&format!("[[items.{}]]", key))?;
+        write_function(f, &function.name, host_name, function, 0)?;
+    }
+    Ok(())
+}
+
+fn write_class(f: &mut dyn Printer, x: &Class<Validated>) -> FormattingResult<()> {
+    let class_name = x.name();
+    // methods are defined by the name that follows the class name in the native function
+    let method_name = |function: &Function<Validated>| {
+        function
+            .name
+            .strip_prefix(class_name.as_ref())
+            .and_then(|x| x.strip_prefix('_'))
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| function.name.as_ref().to_string())
+    };
+
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"class\"")?;
+    f.writeln(&format!("name = {}", quote(class_name)))?;
+    f.writeln(&format!("doc = {}", doc(&x.doc, 0)))?;
+    if let Some(constructor) = &x.constructor {
+        let function = &constructor.function;
+        let mut result = format!("constructor = {{ doc = {}", doc(&function.doc, 0));
+        let params = params(&function.arguments);
+        if !params.is_empty() {
+            result.push_str(&format!(", params = [{}]", params.join(", ")));
+        }
+        if let Some(x) = function.error_type.get() {
+            result.push_str(&format!(", fails_with = {}", quote(&x.inner.name)));
+        }
+        if let Some(x) = deprecated(&function.doc) {
+            result.push_str(&format!(", deprecated = {}", x));
+        }
+        result.push_str(" }");
+        f.writeln(&result)?;
+    }
+    if let Some(destructor) = &x.destructor {
+        let mode = match &x.destruction_mode {
+            DestructionMode::Automatic => String::new(),
+            DestructionMode::Custom(x) => format!(", method = {}", quote(x)),
+            DestructionMode::Dispose => ", disposable = true".to_string(),
+        };
+        f.writeln(&format!(
+            "destructor = {{ doc = {}{} }}",
+            doc(&destructor.function.doc, 0),
+            mode
+        ))?;
+    }
+
+    for method in &x.methods {
+        let function = &method.native_function;
+        let name = method_name(function);
+        let host_name = Some(&method.name).filter(|x| x.as_ref() != name);
+        f.newline()?;
+        f.writeln("[[items.methods]]")?;
+        // skip the instance parameter
+        write_function(f, &name, host_name, function, 1)?;
+    }
+
+    write_static_methods(f, "static_methods", &x.static_methods)?;
+
+    for method in &x.future_methods {
+        let function = &method.native_function;
+        // skip the instance and callback parameters
+        let args = &function.arguments[1..function.arguments.len() - 1];
+        f.newline()?;
+        f.writeln("[[items.future_methods]]")?;
+        f.writeln(&format!("name = {}", quote(&method.name)))?;
+        f.writeln(&format!("doc = {}", doc(&function.doc, 0)))?;
+        f.writeln(&format!(
+            "future = {}",
+            quote(&method.future.interface.name)
+        ))?;
+        write_array(f, "params", &params(args))?;
+        if let Some(x) = function.error_type.get() {
+            f.writeln(&format!("fails_with = {}", quote(&x.inner.name)))?;
+        }
+        if let Some(x) = deprecated(&function.doc) {
+            f.writeln(&format!("deprecated = {}", x))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_static_class(f: &mut dyn Printer, x: &StaticClass<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    f.writeln("[[items]]")?;
+    f.writeln("type = \"static_class\"")?;
+    f.writeln(&format!("name = {}", quote(&x.name)))?;
+    f.writeln(&format!("doc = {}", doc(&x.doc, 0)))?;
+    write_static_methods(f, "methods", &x.static_methods)
+}
+
+/// Path as written in a schema, where it is relative to the schema file
+///
+/// The paths of a library are relative to the working directory of the generator. A path on
+/// another drive than the schema is kept absolute.
+fn relative_to_schema(schema: &Path, path: &Path) -> PathBuf {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let schema_dir = cwd.join(schema.parent().unwrap_or_else(|| Path::new("")));
+    let path = cwd.join(path);
+
+    let from: Vec<Component> = schema_dir
+        .components()
+        .filter(|x| *x != Component::CurDir)
+        .collect();
+    let to: Vec<Component> = path
+        .components()
+        .filter(|x| *x != Component::CurDir)
+        .collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    if common == 0 {
+        return path;
+    }
+
+    let mut result = PathBuf::new();
+    for _ in common..from.len() {
+        result.push("..");
+    }
+    for x in &to[common..] {
+        result.push(x);
+    }
+    if result.as_os_str().is_empty() {
+        result.push(".");
+    }
+    result
+}
@@ -0,0 +1,162 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::path::Path;
+
+/// Prefix the schema path of the error of an expression
+///
+/// `at!(types.function_argument(x), "params", i, "type")` locates an error at `params[i].type`
+macro_rules! at {
+    ($result:expr, $($key:expr),+) => {
+        $result.map_err(|err: $crate::model::BindingError| {
+            err.within(&[$($crate::schema::location::SchemaKey::from($key)),+])
+        })
+    };
+}
+
+pub(crate) use at;
+
+/// Key of a table or index of an array
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SchemaKey {
+    Key(String),
+    Index(usize),
+}
+
+impl From<&str> for SchemaKey {
+    fn from(x: &str) -> Self {
+        Self::Key(x.to_string())
+    }
+}
+
+impl From<&String> for SchemaKey {
+    fn from(x: &String) -> Self {
+        Self::Key(x.clone())
+    }
+}
+
+impl From<usize> for SchemaKey {
+    fn from(x: usize) -> Self {
+        Self::Index(x)
+    }
+}
+
+/// Location of a value within a schema, e.g. `items[2].fields[0].type`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct SchemaPath {
+    keys: Vec<SchemaKey>,
+}
+
+impl SchemaPath {
+    pub(crate) fn new(keys: &[SchemaKey]) -> Self {
+        Self {
+            keys: keys.to_vec(),
+        }
+    }
+
+    pub(crate) fn prefixed(self, keys: &[SchemaKey]) -> Self {
+        Self {
+            keys: keys.iter().cloned().chain(self.keys).collect(),
+        }
+    }
+}
+
+impl Display for SchemaPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, key) in self.keys.iter().enumerate() {
+            match key {
+                SchemaKey::Key(x) if i == 0 => write!(f, "{}", x)?,
+                SchemaKey::Key(x) => write!(f, ".{}", x)?,
+                SchemaKey::Index(x) => write!(f, "[{}]", x)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A node of the TOML document, array elements and inline values aren't items
+#[derive(Copy, Clone)]
+enum Node<'a> {
+    Item(&'a toml_edit::Item),
+    Table(&'a toml_edit::Table),
+    Value(&'a toml_edit::Value),
+}
+
+impl<'a> Node<'a> {
+    fn span(&self) -> Option<Range<usize>> {
+        match self {
+            Node::Item(x) => x.span(),
+            Node::Table(x) => x.span(),
+            Node::Value(x) => x.span(),
+        }
+    }
+
+    fn child(&self, key: &SchemaKey) -> Option<Node<'a>> {
+        match (self, key) {
+            (Node::Item(x), SchemaKey::Key(key)) => x.as_table_like()?.get(key).map(Node::Item),
+            (Node::Table(x), SchemaKey::Key(key)) => x.get(key).map(Node::Item),
+            (Node::Value(x), SchemaKey::Key(key)) => x.as_inline_table()?.get(key).map(Node::Value),
+            (Node::Item(toml_edit::Item::ArrayOfTables(x)), SchemaKey::Index(i)) => {
+                x.get(*i).map(Node::Table)
+            }
+            (Node::Item(toml_edit::Item::Value(x)), SchemaKey::Index(i)) => {
+                x.as_array()?.get(*i).map(Node::Value)
+            }
+            (Node::Value(x), SchemaKey::Index(i)) => x.as_array()?.get(*i).map(Node::Value),
+            _ => None,
+        }
+    }
+}
+
+/// Span of the deepest value of the path present in a TOML document
+fn find_span(document: &toml_edit::ImDocument<&str>, path: &SchemaPath) -> Option<Range<usize>> {
+    let mut node = Node::Table(document.as_table());
+    let mut span = None;
+    for key in &path.keys {
+        node = match node.child(key) {
+            Some(x) => x,
+            // e.g. a default value that should have been provided
+            None => break,
+        };
+        span = node.span().or(span);
+    }
+    span
+}
+
+/// Render the line of a TOML schema that contains the value at the path
+///
+/// ```text
+///   --> api.toml:12:20 (items[1].fields[0].type)
+///    |
+/// 12 |     { name = "x", type = "u42", doc = "X" },
+///    |                          ^^^^^
+/// ```
+pub(crate) fn snippet(file: &Path, source: &str, path: &SchemaPath) -> Option<String> {
+    let document = toml_edit::ImDocument::parse(source).ok()?;
+    let span = find_span(&document, path)?;
+
+    let line_start = source[..span.start].rfind('\n').map_or(0, |x| x + 1);
+    let line_end = source[span.start..]
+        .find('\n')
+        .map_or(source.len(), |x| span.start + x);
+    let line = &source[line_start..line_end];
+    let line_number = source[..span.start].matches('\n').count() + 1;
+    let column = source[line_start..span.start].chars().count();
+    let width = source[span.start..span.end.min(line_end)]
+        .chars()
+        .count()
+        .max(1);
+    let margin = " ".repeat(line_number.to_string().len());
+
+    Some(format!(
+        "{margin}--> {}:{}:{} ({})\n{margin} |\n{} | {}\n{margin} | {}{}",
+        file.display(),
+        line_number,
+        column + 1,
+        path,
+        line_number,
+        line.trim_end(),
+        " ".repeat(column),
+        "^".repeat(width),
+        margin = margin,
+    ))
+}
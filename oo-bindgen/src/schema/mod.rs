@@ -0,0 +1,839 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::model::*;
+
+mod build;
+mod export;
+pub(crate) mod location;
+mod types;
+
+pub(crate) use export::export;
+
+/// Library described in a JSON or TOML file instead of Rust code
+///
+/// Items are replayed through [`LibraryBuilder`] in the order they appear in the file, so a
+/// schema is subject to the same validation as a library defined in a build script. Types are
+/// referenced by name, see the crate README for the syntax of type expressions.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Schema {
+    pub(crate) name: String,
+    /// defaults to the name of the library
+    pub(crate) c_ffi_prefix: Option<String>,
+    pub(crate) version: String,
+    pub(crate) info: InfoSchema,
+    /// how the CLI locates the FFI crate and where it writes the bindings
+    pub(crate) bindings: Option<BindingsSchema>,
+    /// acronyms and per-language renames of the bindings
+    #[serde(default)]
+    pub(crate) naming: NamingSchema,
+    #[serde(default)]
+    pub(crate) items: Vec<ItemSchema>,
+    /// directory of the schema file, other files are relative to it
+    #[serde(skip)]
+    pub(crate) base_dir: PathBuf,
+    /// path and content of a TOML schema, errors point to the value that caused them
+    #[serde(skip)]
+    pub(crate) source: Option<(PathBuf, String)>,
+}
+
+impl Schema {
+    /// Read a schema, the format is determined by the extension (`.json` or `.toml`)
+    pub fn read(path: &Path) -> BindResult<Self> {
+        let error = |message: String| BindingErrorVariant::SchemaRead {
+            path: path.to_owned(),
+            message,
+        };
+
+        let content = std::fs::read_to_string(path).map_err(|err| error(err.to_string()))?;
+        let mut schema: Schema = match path.extension().and_then(|x| x.to_str()) {
+            Some("json") => serde_json::from_str(&content).map_err(|err| error(err.to_string()))?,
+            Some("toml") => return Self::parse_toml(path, content),
+            _ => {
+                return Err(
+                    error("the extension must be either '.json' or '.toml'".to_string()).into(),
+                )
+            }
+        };
+        schema.base_dir = path.parent().map(|x| x.to_owned()).unwrap_or_default();
+        Ok(schema)
+    }
+
+    /// Parse a TOML schema, keeping the content to locate errors
+    pub(crate) fn parse_toml(path: &Path, content: String) -> BindResult<Self> {
+        let mut schema: Schema =
+            toml::from_str(&content).map_err(|err| BindingErrorVariant::SchemaRead {
+                path: path.to_owned(),
+                message: err.to_string(),
+            })?;
+        schema.base_dir = path.parent().map(|x| x.to_owned()).unwrap_or_default();
+        schema.source = Some((path.to_owned(), content));
+        Ok(schema)
+    }
+
+    /// Path of a file named in the schema, which is relative to the schema file
+    pub(crate) fn resolve(&self, path: &Path) -> PathBuf {
+        self.base_dir.join(path)
+    }
+
+    /// Build and validate the library
+    pub fn build(&self) -> BindResult<Library> {
+        build::build_library(self).map_err(|err| match &self.source {
+            Some((path, content)) => {
+                err.with_source(|location| location::snippet(path, content, location))
+            }
+            None => err,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct InfoSchema {
+    pub(crate) description: String,
+    pub(crate) project_url: String,
+    pub(crate) repository: String,
+    pub(crate) license_name: String,
+    #[serde(default)]
+    pub(crate) license_description: Vec<String>,
+    pub(crate) license_path: PathBuf,
+    #[serde(default)]
+    pub(crate) developers: Vec<DeveloperSchema>,
+    /// PNG file relative to the schema
+    pub(crate) logo_png: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DeveloperSchema {
+    pub(crate) name: String,
+    pub(crate) email: String,
+    pub(crate) organization: String,
+    pub(crate) organization_url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BindingsSchema {
+    pub(crate) ffi_target_name: String,
+    pub(crate) jni_target_name: String,
+    /// defaults to `ffi_target_name` with hyphens replaced by underscores
+    pub(crate) ffi_name: Option<String>,
+    pub(crate) ffi_path: PathBuf,
+    pub(crate) java_group_id: String,
+    pub(crate) destination_path: PathBuf,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct NamingSchema {
+    #[serde(default)]
+    pub(crate) acronyms: Vec<String>,
+    #[serde(default)]
+    pub(crate) renames: Vec<RenameSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RenameSchema {
+    pub(crate) name: String,
+    pub(crate) language: TargetLanguage,
+    pub(crate) to: String,
+}
+
+/// Documentation is either a brief or a brief followed by paragraphs
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum DocSchema {
+    Brief(String),
+    Full {
+        brief: String,
+        #[serde(default)]
+        details: Vec<ParagraphSchema>,
+        since: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ParagraphSchema {
+    Details(String),
+    Warning { warning: String },
+    Example { example: ExampleSchema },
+}
+
+/// Code snippet of an example for each target language
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ExampleSchema {
+    c: Option<String>,
+    cpp: Option<String>,
+    java: Option<String>,
+    dotnet: Option<String>,
+}
+
+impl ExampleSchema {
+    pub(crate) fn snippets(&self) -> impl Iterator<Item = (ExampleLanguage, &String)> {
+        [
+            (ExampleLanguage::C, &self.c),
+            (ExampleLanguage::Cpp, &self.cpp),
+            (ExampleLanguage::Java, &self.java),
+            (ExampleLanguage::DotNet, &self.dotnet),
+        ]
+        .into_iter()
+        .filter_map(|(language, code)| code.as_ref().map(|x| (language, x)))
+    }
+}
+
+/// Version since which an item is deprecated and what to use instead
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DeprecatedSchema {
+    pub(crate) since: String,
+    pub(crate) note: String,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub(crate) enum ItemSchema {
+    Constants {
+        name: String,
+        doc: DocSchema,
+        values: Vec<ConstantSchema>,
+    },
+    Enum {
+        name: String,
+        doc: DocSchema,
+        variants: Vec<VariantSchema>,
+    },
+    ErrorType {
+        name: String,
+        exception_name: String,
+        #[serde(default)]
+        checked: bool,
+        doc: DocSchema,
+        errors: Vec<ErrorSchema>,
+    },
+    Struct(StructSchema),
+    Interface {
+        name: String,
+        doc: DocSchema,
+        #[serde(default)]
+        asynchronous: bool,
+        callbacks: Vec<CallbackSchema>,
+    },
+    FutureInterface {
+        name: String,
+        doc: DocSchema,
+        value: String,
+        value_doc: String,
+        error_type: Option<String>,
+    },
+    Iterator {
+        name: String,
+        item: String,
+        #[serde(default)]
+        lifetime: bool,
+    },
+    Collection {
+        name: String,
+        item: String,
+        #[serde(default)]
+        reserve: bool,
+    },
+    Function(FunctionSchema),
+    Class(ClassSchema),
+    StaticClass {
+        name: String,
+        doc: DocSchema,
+        #[serde(default)]
+        methods: Vec<FunctionSchema>,
+    },
+    /// the following items are defined in this module, e.g. `master.sessions`, or the root one
+    Module {
+        name: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ConstantSchema {
+    pub(crate) name: String,
+    pub(crate) value: u8,
+    pub(crate) doc: DocSchema,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct VariantSchema {
+    pub(crate) name: String,
+    /// defaults to the value of the previous variant plus one
+    pub(crate) value: Option<i32>,
+    pub(crate) doc: DocSchema,
+    pub(crate) deprecated: Option<DeprecatedSchema>,
+}
+
+/// error variants are numbered automatically after `ok`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ErrorSchema {
+    pub(crate) name: String,
+    pub(crate) doc: DocSchema,
+    /// variant reported when a constrained argument is rejected
+    #[serde(default)]
+    pub(crate) invalid_argument: bool,
+}
+
+#[derive(Copy, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StructKind {
+    Universal,
+    FunctionArgument,
+    FunctionReturn,
+    CallbackArgument,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct StructSchema {
+    pub(crate) name: String,
+    pub(crate) kind: StructKind,
+    pub(crate) doc: DocSchema,
+    /// only universal structs may be opaque
+    #[serde(default)]
+    pub(crate) opaque: bool,
+    #[serde(default)]
+    pub(crate) value_semantics: bool,
+    #[serde(default)]
+    pub(crate) immutable: bool,
+    pub(crate) fields: Vec<FieldSchema>,
+    #[serde(default)]
+    pub(crate) initializers: Vec<InitializerSchema>,
+    /// name of an initializer that takes every field as a parameter
+    pub(crate) full_initializer: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FieldSchema {
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    pub(crate) field_type: String,
+    pub(crate) doc: DocSchema,
+    #[serde(default)]
+    pub(crate) constraints: Vec<ConstraintSchema>,
+    pub(crate) deprecated: Option<DeprecatedSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct InitializerSchema {
+    pub(crate) name: String,
+    pub(crate) doc: DocSchema,
+    /// static initializers are named methods instead of constructors
+    #[serde(default, rename = "static")]
+    pub(crate) is_static: bool,
+    /// default values of the fields that aren't parameters of the initializer
+    #[serde(default)]
+    pub(crate) values: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub(crate) enum ConstraintSchema {
+    Range {
+        min: serde_json::Value,
+        max: serde_json::Value,
+    },
+    NonZero,
+    NonEmpty,
+    MaxLength(usize),
+    Regex(String),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ParamSchema {
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    pub(crate) param_type: String,
+    pub(crate) doc: String,
+    /// value used when host languages omit the parameter
+    pub(crate) default: Option<serde_json::Value>,
+    #[serde(default)]
+    pub(crate) constraints: Vec<ConstraintSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ReturnSchema {
+    #[serde(rename = "type")]
+    pub(crate) return_type: String,
+    pub(crate) doc: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FunctionSchema {
+    /// name of the native function
+    pub(crate) name: String,
+    /// name of the method in host languages if different from the native function
+    pub(crate) host_name: Option<String>,
+    pub(crate) doc: DocSchema,
+    #[serde(default)]
+    pub(crate) params: Vec<ParamSchema>,
+    pub(crate) returns: Option<ReturnSchema>,
+    pub(crate) fails_with: Option<String>,
+    pub(crate) deprecated: Option<DeprecatedSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ConstructorSchema {
+    pub(crate) doc: DocSchema,
+    #[serde(default)]
+    pub(crate) params: Vec<ParamSchema>,
+    pub(crate) fails_with: Option<String>,
+    pub(crate) deprecated: Option<DeprecatedSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DestructorSchema {
+    pub(crate) doc: DocSchema,
+    /// name of the method that destroys the instance
+    pub(crate) method: Option<String>,
+    /// use the disposable pattern of each language, e.g. `IDisposable` in C#
+    #[serde(default)]
+    pub(crate) disposable: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FutureMethodSchema {
+    pub(crate) name: String,
+    pub(crate) doc: DocSchema,
+    pub(crate) future: String,
+    #[serde(default)]
+    pub(crate) params: Vec<ParamSchema>,
+    pub(crate) fails_with: Option<String>,
+    pub(crate) deprecated: Option<DeprecatedSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ClassSchema {
+    pub(crate) name: String,
+    pub(crate) doc: DocSchema,
+    pub(crate) constructor: Option<ConstructorSchema>,
+    pub(crate) destructor: Option<DestructorSchema>,
+    #[serde(default)]
+    pub(crate) methods: Vec<FunctionSchema>,
+    #[serde(default)]
+    pub(crate) static_methods: Vec<FunctionSchema>,
+    #[serde(default)]
+    pub(crate) future_methods: Vec<FutureMethodSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CallbackSchema {
+    pub(crate) name: String,
+    pub(crate) doc: DocSchema,
+    #[serde(default)]
+    pub(crate) params: Vec<CallbackParamSchema>,
+    pub(crate) returns: Option<CallbackReturnSchema>,
+    /// a callback without a return value may be omitted by implementations
+    #[serde(default)]
+    pub(crate) optional: bool,
+    #[serde(default)]
+    pub(crate) functional: bool,
+    pub(crate) deprecated: Option<DeprecatedSchema>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CallbackParamSchema {
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    pub(crate) param_type: String,
+    pub(crate) doc: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CallbackReturnSchema {
+    #[serde(rename = "type")]
+    pub(crate) return_type: String,
+    pub(crate) doc: String,
+    /// value returned when implementations omit the callback
+    ///
+    /// Structs are returned by naming one of their parameterless initializers
+    pub(crate) default: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "name": "demo",
+        "version": "1.2.3",
+        "info": {
+            "description": "demo library",
+            "project_url": "https://example.com",
+            "repository": "example/demo",
+            "license_name": "MIT",
+            "license_path": "LICENSE.txt"
+        },
+        "naming": { "acronyms": ["tls"], "renames": [{ "name": "widget", "language": "dotnet", "to": "gadget" }] },
+        "items": [
+            {
+                "type": "enum",
+                "name": "color",
+                "doc": "Color of a widget",
+                "variants": [
+                    { "name": "red", "doc": "Red" },
+                    { "name": "blue", "value": 4, "doc": "Blue", "deprecated": { "since": "1.1.0", "note": "Use {enum:color.red}" } }
+                ]
+            },
+            {
+                "type": "error_type",
+                "name": "demo_error",
+                "exception_name": "demo_exception",
+                "doc": "Errors of the library",
+                "errors": [{ "name": "bad_thing", "doc": "Something bad" }]
+            },
+            {
+                "type": "struct",
+                "name": "settings",
+                "kind": "universal",
+                "doc": { "brief": "Settings of a widget", "details": ["More with `code`:\n- a\n- b", { "warning": "Careful" }], "since": "1.1.0" },
+                "fields": [
+                    { "name": "color", "type": "color", "doc": "Color" },
+                    { "name": "timeout", "type": "duration_ms", "doc": "Timeout" }
+                ],
+                "initializers": [
+                    { "name": "defaults", "doc": "Default settings", "values": { "color": "blue", "timeout": 500 } }
+                ]
+            },
+            {
+                "type": "interface",
+                "name": "listener",
+                "doc": "Receives events",
+                "callbacks": [
+                    {
+                        "name": "on_settings",
+                        "doc": "Settings were requested",
+                        "returns": { "type": "settings", "doc": "Settings", "default": "defaults" }
+                    }
+                ]
+            },
+            {
+                "type": "future_interface",
+                "name": "string_callback",
+                "doc": "Receives a string",
+                "value": "string",
+                "value_doc": "The string",
+                "error_type": "demo_error"
+            },
+            {
+                "type": "class",
+                "name": "widget",
+                "doc": { "brief": "A widget", "details": [{ "example": { "c": "demo_widget_t* widget = NULL;", "java": "Widget widget = null;" } }] },
+                "constructor": {
+                    "doc": "Create a widget",
+                    "params": [{ "name": "settings", "type": "settings", "doc": "Settings" }],
+                    "fails_with": "demo_error"
+                },
+                "destructor": { "doc": "Destroy a widget" },
+                "methods": [
+                    {
+                        "name": "set_listener",
+                        "doc": "Set the listener",
+                        "params": [{ "name": "listener", "type": "listener", "doc": "Listener" }],
+                        "deprecated": { "since": "1.2.0", "note": "Set it in the settings" }
+                    }
+                ],
+                "future_methods": [
+                    { "name": "fetch", "doc": "Fetch a string", "future": "string_callback" }
+                ]
+            }
+        ]
+    }"#;
+
+    const TOML: &str = r#"
+        name = "demo"
+        version = "1.2.3"
+
+        [info]
+        description = "demo library"
+        project_url = "https://example.com"
+        repository = "example/demo"
+        license_name = "MIT"
+        license_path = "LICENSE.txt"
+
+        [naming]
+        acronyms = ["tls"]
+        renames = [{ name = "widget", language = "dotnet", to = "gadget" }]
+
+        [[items]]
+        type = "enum"
+        name = "color"
+        doc = "Color of a widget"
+        variants = [
+            { name = "red", doc = "Red" },
+            { name = "blue", value = 4, doc = "Blue", deprecated = { since = "1.1.0", note = "Use {enum:color.red}" } },
+        ]
+
+        [[items]]
+        type = "error_type"
+        name = "demo_error"
+        exception_name = "demo_exception"
+        doc = "Errors of the library"
+        errors = [{ name = "bad_thing", doc = "Something bad" }]
+
+        [[items]]
+        type = "struct"
+        name = "settings"
+        kind = "universal"
+        doc = { brief = "Settings of a widget", details = ["More with `code`:\n- a\n- b", { warning = "Careful" }], since = "1.1.0" }
+        fields = [
+            { name = "color", type = "color", doc = "Color" },
+            { name = "timeout", type = "duration_ms", doc = "Timeout" },
+        ]
+        initializers = [
+            { name = "defaults", doc = "Default settings", values = { color = "blue", timeout = 500 } },
+        ]
+
+        [[items]]
+        type = "interface"
+        name = "listener"
+        doc = "Receives events"
+
+        [[items.callbacks]]
+        name = "on_settings"
+        doc = "Settings were requested"
+        returns = { type = "settings", doc = "Settings", default = "defaults" }
+
+        [[items]]
+        type = "future_interface"
+        name = "string_callback"
+        doc = "Receives a string"
+        value = "string"
+        value_doc = "The string"
+        error_type = "demo_error"
+
+        [[items]]
+        type = "class"
+        name = "widget"
+        doc = { brief = "A widget", details = [{ example = { c = "demo_widget_t* widget = NULL;", java = "Widget widget = null;" } }] }
+        constructor = { doc = "Create a widget", params = [{ name = "settings", type = "settings", doc = "Settings" }], fails_with = "demo_error" }
+        destructor = { doc = "Destroy a widget" }
+
+        [[items.methods]]
+        name = "set_listener"
+        doc = "Set the listener"
+        params = [{ name = "listener", type = "listener", doc = "Listener" }]
+        deprecated = { since = "1.2.0", note = "Set it in the settings" }
+
+        [[items.future_methods]]
+        name = "fetch"
+        doc = "Fetch a string"
+        future = "string_callback"
+    "#;
+
+    fn check(lib: &Library) {
+        assert_eq!(lib.version, Version::new(1, 2, 3));
+        assert_eq!(lib.enums().count(), 2);
+        assert_eq!(lib.structs().count(), 1);
+        assert_eq!(lib.interfaces().count(), 2);
+        let class = lib.classes().next().unwrap();
+        assert_eq!(class.name().as_ref(), "widget");
+        assert_eq!(class.methods.len(), 1);
+        assert_eq!(class.future_methods.len(), 1);
+        assert_eq!(
+            class.methods[0]
+                .native_function
+                .doc
+                .deprecated
+                .as_ref()
+                .map(|x| x.message(&Validated::schema_name)),
+            Some("Deprecated since 1.2.0: Set it in the settings".to_string())
+        );
+        let color = lib.enums().next().unwrap();
+        assert_eq!(
+            color.variants[1]
+                .doc
+                .deprecated
+                .as_ref()
+                .map(|x| x.message(&Validated::schema_name)),
+            Some("Deprecated since 1.1.0: Use color.red".to_string())
+        );
+        let settings = lib.structs().next().unwrap();
+        assert_eq!(settings.doc().since, Some(Version::new(1, 1, 0)));
+        assert!(matches!(
+            &settings.doc().details[0],
+            DocParagraph::Details(x) if x.plain_text() == "More with code:a, b"
+        ));
+        let example = class.doc.examples().next().unwrap();
+        assert_eq!(
+            example.get(ExampleLanguage::Java),
+            Some("Widget widget = null;")
+        );
+        assert_eq!(example.get(ExampleLanguage::DotNet), None);
+        assert!(lib.naming_rules.acronyms.contains("tls"));
+        assert_eq!(
+            class.name().camel_case(&lib.naming(TargetLanguage::DotNet)),
+            "Gadget"
+        );
+    }
+
+    #[test]
+    fn builds_json_schema() {
+        let schema: Schema = serde_json::from_str(JSON).unwrap();
+        check(&schema.build().unwrap());
+    }
+
+    #[test]
+    fn builds_toml_schema() {
+        let schema: Schema = toml::from_str(TOML).unwrap();
+        check(&schema.build().unwrap());
+    }
+
+    #[test]
+    fn rejects_unknown_type() {
+        let json = JSON.replace(r#""type": "duration_ms""#, r#""type": "duration_us""#);
+        let schema: Schema = serde_json::from_str(&json).unwrap();
+        let err = schema.build().err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Schema type 'duration_us' cannot be used as a field of a universal struct (at items[2].fields[1].type)"
+        );
+    }
+
+    #[test]
+    fn rejects_since_after_library_version() {
+        let json = JSON.replace(r#""since": "1.1.0" }"#, r#""since": "1.3.0" }"#);
+        let schema: Schema = serde_json::from_str(&json).unwrap();
+        let err = schema.build().err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Documentation of 'settings' says it was introduced in 1.3.0 which is after the library version 1.2.3"
+        );
+    }
+
+    #[test]
+    fn locates_errors_in_toml_schema() {
+        let toml = TOML.replace(r#"type = "duration_ms""#, r#"type = "duration_us""#);
+        let schema = Schema::parse_toml(Path::new("api.toml"), toml).unwrap();
+        let err = schema.build().err().unwrap().to_string();
+        assert!(err.starts_with(
+            "Schema type 'duration_us' cannot be used as a field of a universal struct\n"
+        ));
+        assert!(
+            err.contains("--> api.toml:39:40 (items[2].fields[1].type)"),
+            "{}",
+            err
+        );
+        assert!(err.contains("^^^^^^^^^^^^^"));
+    }
+
+    #[test]
+    fn exports_library_to_toml() {
+        let schema: Schema = toml::from_str(TOML).unwrap();
+        let path = std::env::temp_dir().join(format!("oo-bindgen-{}.toml", std::process::id()));
+        export(&path, &schema.build().unwrap(), None).unwrap();
+        let exported = Schema::read(&path);
+        std::fs::remove_file(&path).unwrap();
+        check(&exported.unwrap().build().unwrap());
+    }
+
+    #[test]
+    fn resolves_paths_against_the_schema_directory() {
+        // the tests run in the directory of the crate, not in the directory of the schema
+        let dir = std::env::temp_dir().join(format!("oo-bindgen-{}-paths", std::process::id()));
+        std::fs::create_dir_all(dir.join("exported")).unwrap();
+        let toml = TOML.replace(
+            "\n        [naming]",
+            r#"
+        [bindings]
+        ffi_target_name = "demo-ffi"
+        jni_target_name = "demo-ffi-java"
+        ffi_path = "ffi"
+        java_group_id = "io.demo"
+        destination_path = "bindings"
+
+        [naming]"#,
+        );
+        let path = dir.join("api.toml");
+        std::fs::write(&path, toml).unwrap();
+
+        let schema = Schema::read(&path).unwrap();
+        let lib = schema.build().unwrap();
+        let bindings = schema.bindings.as_ref().unwrap();
+        assert_eq!(lib.info.license_path, dir.join("LICENSE.txt"));
+
+        // the exported paths are relative to the exported schema
+        let exported_path = dir.join("exported").join("api.toml");
+        let resolved = BindingsSchema {
+            ffi_target_name: bindings.ffi_target_name.clone(),
+            jni_target_name: bindings.jni_target_name.clone(),
+            ffi_name: None,
+            ffi_path: schema.resolve(&bindings.ffi_path),
+            java_group_id: bindings.java_group_id.clone(),
+            destination_path: schema.resolve(&bindings.destination_path),
+        };
+        assert_eq!(resolved.ffi_path, dir.join("ffi"));
+        assert_eq!(resolved.destination_path, dir.join("bindings"));
+        export(&exported_path, &lib, Some(&resolved)).unwrap();
+        let exported = Schema::read(&exported_path);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let exported = exported.unwrap();
+        let parent = Path::new("..");
+        assert_eq!(exported.info.license_path, parent.join("LICENSE.txt"));
+        let bindings = exported.bindings.as_ref().unwrap();
+        assert_eq!(bindings.ffi_path, parent.join("ffi"));
+        assert_eq!(bindings.destination_path, parent.join("bindings"));
+    }
+
+    #[test]
+    fn places_items_in_modules() {
+        let json = JSON.replace(
+            r#"            {
+                "type": "class","#,
+            r#"            { "type": "module", "name": "devices.widgets" },
+            {
+                "type": "class","#,
+        );
+        let schema: Schema = serde_json::from_str(&json).unwrap();
+        let lib = schema.build().unwrap();
+        check(&lib);
+        let module = &lib.classes().next().unwrap().declaration.module;
+        assert_eq!(module.to_string(), "devices.widgets");
+        assert!(lib.structs().next().unwrap().module().is_root());
+
+        let path =
+            std::env::temp_dir().join(format!("oo-bindgen-{}-modules.toml", std::process::id()));
+        export(&path, &lib, None).unwrap();
+        let exported = Schema::read(&path);
+        std::fs::remove_file(&path).unwrap();
+        let exported = exported.unwrap().build().unwrap();
+        assert_eq!(
+            exported.classes().next().unwrap().declaration.module,
+            *module
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let json = JSON.replace(
+            r#""kind": "universal""#,
+            r#""kind": "universal", "extra": 1"#,
+        );
+        assert!(serde_json::from_str::<Schema>(&json).is_err());
+    }
+}
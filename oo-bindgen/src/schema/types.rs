@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::model::*;
+
+/// Struct declared by a schema item, definitions may appear later in the file
+#[derive(Clone)]
+pub(crate) enum Declaration {
+    Universal(UniversalStructDeclaration),
+    FunctionArg(FunctionArgStructDeclaration),
+    FunctionReturn(FunctionReturnStructDeclaration),
+    CallbackArg(CallbackArgStructDeclaration),
+}
+
+#[derive(Clone)]
+pub(crate) enum Definition {
+    Universal(UniversalStructHandle),
+    FunctionArg(FunctionArgStructHandle),
+    FunctionReturn(FunctionReturnStructHandle),
+    CallbackArg(CallbackArgStructHandle),
+}
+
+#[derive(Clone)]
+pub(crate) enum InterfaceDefinition {
+    Synchronous(SynchronousInterface),
+    Asynchronous(AsynchronousInterface),
+}
+
+/// Everything a schema has defined so far, indexed by name
+#[derive(Default)]
+pub(crate) struct Types {
+    pub(crate) enums: HashMap<String, EnumHandle>,
+    pub(crate) errors: HashMap<String, ErrorType<Unvalidated>>,
+    pub(crate) declarations: HashMap<String, Declaration>,
+    pub(crate) structs: HashMap<String, Definition>,
+    pub(crate) classes: HashMap<String, ClassDeclarationHandle>,
+    pub(crate) interfaces: HashMap<String, InterfaceDefinition>,
+    pub(crate) futures: HashMap<String, FutureInterface<Unvalidated>>,
+    pub(crate) iterators: HashMap<String, AbstractIteratorHandle>,
+    pub(crate) collections: HashMap<String, CollectionHandle>,
+    /// modules by their dotted path
+    pub(crate) modules: HashMap<String, Module>,
+}
+
+fn unknown(name: &str, usage: &'static str) -> BindingError {
+    BindingErrorVariant::SchemaUnknownType {
+        name: name.to_string(),
+        usage,
+    }
+    .into()
+}
+
+fn bad_value(value: &serde_json::Value, type_name: &str) -> BindingError {
+    BindingErrorVariant::SchemaBadValue {
+        value: value.to_string(),
+        type_name: type_name.to_string(),
+    }
+    .into()
+}
+
+fn primitive(name: &str) -> Option<Primitive> {
+    match name {
+        "bool" => Some(Primitive::Bool),
+        "u8" => Some(Primitive::U8),
+        "s8" => Some(Primitive::S8),
+        "u16" => Some(Primitive::U16),
+        "s16" => Some(Primitive::S16),
+        "u32" => Some(Primitive::U32),
+        "s32" => Some(Primitive::S32),
+        "u64" => Some(Primitive::U64),
+        "s64" => Some(Primitive::S64),
+        "float" => Some(Primitive::Float),
+        "double" => Some(Primitive::Double),
+        _ => None,
+    }
+}
+
+fn duration(name: &str) -> Option<DurationType> {
+    match name {
+        "duration_ms" => Some(DurationType::Milliseconds),
+        "duration_s" => Some(DurationType::Seconds),
+        _ => None,
+    }
+}
+
+/// Number of a primitive type, JSON and TOML don't distinguish the integer types
+pub(crate) fn number_value(value: &serde_json::Value, primitive: Primitive) -> Option<NumberValue> {
+    fn int<T: TryFrom<i64>>(value: &serde_json::Value) -> Option<T> {
+        value.as_i64().and_then(|x| T::try_from(x).ok())
+    }
+
+    match primitive {
+        Primitive::Bool => None,
+        Primitive::U8 => int(value).map(NumberValue::U8),
+        Primitive::S8 => int(value).map(NumberValue::S8),
+        Primitive::U16 => int(value).map(NumberValue::U16),
+        Primitive::S16 => int(value).map(NumberValue::S16),
+        Primitive::U32 => int(value).map(NumberValue::U32),
+        Primitive::S32 => int(value).map(NumberValue::S32),
+        Primitive::U64 => value.as_u64().map(NumberValue::U64),
+        Primitive::S64 => value.as_i64().map(NumberValue::S64),
+        Primitive::Float => value.as_f64().map(|x| NumberValue::Float(x as f32)),
+        Primitive::Double => value.as_f64().map(NumberValue::Double),
+    }
+}
+
+impl Types {
+    fn basic(&self, name: &str) -> Option<BasicType> {
+        if let Some(x) = primitive(name) {
+            return Some(x.into());
+        }
+        if let Some(x) = duration(name) {
+            return Some(x.into());
+        }
+        self.enums.get(name).map(|x| BasicType::Enum(x.clone()))
+    }
+
+    pub(crate) fn error_type(&self, name: &str) -> BindResult<ErrorType<Unvalidated>> {
+        self.errors
+            .get(name)
+            .cloned()
+            .ok_or_else(|| unknown(name, "an error type"))
+    }
+
+    pub(crate) fn future(&self, name: &str) -> BindResult<FutureInterface<Unvalidated>> {
+        self.futures
+            .get(name)
+            .cloned()
+            .ok_or_else(|| unknown(name, "a future interface"))
+    }
+
+    pub(crate) fn declaration(&self, name: &str) -> BindResult<Declaration> {
+        self.declarations
+            .get(name)
+            .cloned()
+            .ok_or_else(|| unknown(name, "a struct"))
+    }
+
+    pub(crate) fn universal_struct(&self, name: &str) -> BindResult<UniversalStructHandle> {
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone()),
+            _ => Err(unknown(name, "a universal struct")),
+        }
+    }
+
+    pub(crate) fn function_argument(&self, name: &str) -> BindResult<FunctionArgument> {
+        const USAGE: &str = "a function argument";
+
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        if name == "string" {
+            return Ok(StringType.into());
+        }
+        if let Some(name) = name.strip_prefix('&') {
+            return match self.declarations.get(name) {
+                Some(Declaration::Universal(x)) => Ok(x.clone().into()),
+                Some(Declaration::FunctionArg(x)) => Ok(x.clone().into()),
+                _ => Err(unknown(name, USAGE)),
+            };
+        }
+        if let Some(x) = self.collections.get(name) {
+            return Ok(x.clone().into());
+        }
+        if let Some(x) = self.classes.get(name) {
+            return Ok(x.clone().into());
+        }
+        if let Some(x) = self.interfaces.get(name) {
+            return Ok(match x {
+                InterfaceDefinition::Synchronous(x) => x.clone().into(),
+                InterfaceDefinition::Asynchronous(x) => x.clone().into(),
+            });
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone().into()),
+            Some(Definition::FunctionArg(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, USAGE)),
+        }
+    }
+
+    pub(crate) fn function_return(&self, name: &str) -> BindResult<FunctionReturnValue> {
+        const USAGE: &str = "a function return value";
+
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        if name == "string" {
+            return Ok(StringType.into());
+        }
+        if let Some(name) = name.strip_prefix('&') {
+            if let Some(x) = primitive(name) {
+                return Ok(PrimitiveRef::new(x).into());
+            }
+            return match self.declarations.get(name) {
+                Some(Declaration::Universal(x)) => Ok(x.clone().into()),
+                Some(Declaration::FunctionReturn(x)) => Ok(x.clone().into()),
+                _ => Err(unknown(name, USAGE)),
+            };
+        }
+        if let Some(x) = self.classes.get(name) {
+            return Ok(x.clone().into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone().into()),
+            Some(Definition::FunctionReturn(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, USAGE)),
+        }
+    }
+
+    pub(crate) fn callback_argument(&self, name: &str) -> BindResult<CallbackArgument> {
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        if name == "string" {
+            return Ok(StringType.into());
+        }
+        if let Some(x) = self.iterators.get(name) {
+            return Ok(x.clone().into());
+        }
+        if let Some(x) = self.classes.get(name) {
+            return Ok(x.clone().into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone().into()),
+            Some(Definition::CallbackArg(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, "a callback argument")),
+        }
+    }
+
+    pub(crate) fn callback_return(&self, name: &str) -> BindResult<CallbackReturnValue> {
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, "a callback return value")),
+        }
+    }
+
+    pub(crate) fn iterator_item(&self, name: &str) -> BindResult<IteratorItemType> {
+        if let Some(x) = primitive(name) {
+            return Ok(x.into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone().into()),
+            Some(Definition::FunctionReturn(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, "an iterator item")),
+        }
+    }
+
+    pub(crate) fn universal_field(&self, name: &str) -> BindResult<UniversalStructField> {
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, "a field of a universal struct")),
+        }
+    }
+
+    pub(crate) fn function_arg_field(&self, name: &str) -> BindResult<FunctionArgStructField> {
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        if name == "string" {
+            return Ok(StringType.into());
+        }
+        if let Some(InterfaceDefinition::Asynchronous(x)) = self.interfaces.get(name) {
+            return Ok(x.clone().into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(x.clone().into()),
+            Some(Definition::FunctionArg(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, "a field of a function argument struct")),
+        }
+    }
+
+    pub(crate) fn function_return_field(
+        &self,
+        name: &str,
+    ) -> BindResult<FunctionReturnStructField> {
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        if let Some(x) = self.iterators.get(name) {
+            return Ok(x.clone().into());
+        }
+        if let Some(x) = self.classes.get(name) {
+            return Ok(x.clone().into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(FunctionReturnStructField::Struct(
+                UniversalOr::Universal(x.clone()),
+            )),
+            Some(Definition::FunctionReturn(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, "a field of a function return struct")),
+        }
+    }
+
+    pub(crate) fn callback_arg_field(&self, name: &str) -> BindResult<CallbackArgStructField> {
+        if let Some(x) = self.basic(name) {
+            return Ok(x.into());
+        }
+        if let Some(x) = self.iterators.get(name) {
+            return Ok(x.clone().into());
+        }
+        match self.structs.get(name) {
+            Some(Definition::Universal(x)) => Ok(CallbackArgStructField::Struct(
+                UniversalOr::Universal(x.clone()),
+            )),
+            Some(Definition::CallbackArg(x)) => Ok(x.clone().into()),
+            _ => Err(unknown(name, "a field of a callback argument struct")),
+        }
+    }
+
+    /// Default value of a field or parameter, interpreted according to its type
+    ///
+    /// Durations are expressed in the unit of the type and nested structs use the string
+    /// `"default"` to select their default initializer
+    pub(crate) fn initializer_default(
+        &self,
+        type_name: &str,
+        value: &serde_json::Value,
+    ) -> BindResult<InitializerDefault> {
+        let error = || bad_value(value, type_name);
+
+        if type_name == "bool" {
+            return value
+                .as_bool()
+                .map(InitializerDefault::Bool)
+                .ok_or_else(error);
+        }
+        if let Some(x) = primitive(type_name) {
+            return number_value(value, x)
+                .map(InitializerDefault::Numeric)
+                .ok_or_else(error);
+        }
+        if let Some(x) = duration(type_name) {
+            let value = value.as_u64().ok_or_else(error)?;
+            return Ok(InitializerDefault::Duration(match x {
+                DurationType::Milliseconds => Duration::from_millis(value),
+                DurationType::Seconds => Duration::from_secs(value),
+            }));
+        }
+        let text = value.as_str().ok_or_else(error)?;
+        if type_name == "string" {
+            return Ok(InitializerDefault::String(text.to_string()));
+        }
+        if self.enums.contains_key(type_name) {
+            return Ok(InitializerDefault::Enum(text.to_string()));
+        }
+        if text == "default" {
+            return Ok(InitializerDefault::DefaultStruct);
+        }
+        Err(error())
+    }
+
+    /// Value returned by a callback that implementations may omit
+    pub(crate) fn callback_default(
+        &self,
+        type_name: &str,
+        value: &serde_json::Value,
+    ) -> BindResult<DefaultCallbackReturnValue> {
+        let error = || bad_value(value, type_name);
+
+        if type_name == "bool" {
+            return value
+                .as_bool()
+                .map(|x| PrimitiveValue::Bool(x).into())
+                .ok_or_else(error);
+        }
+        if let Some(x) = primitive(type_name) {
+            let value = match number_value(value, x).ok_or_else(error)? {
+                NumberValue::U8(x) => PrimitiveValue::U8(x),
+                NumberValue::S8(x) => PrimitiveValue::S8(x),
+                NumberValue::U16(x) => PrimitiveValue::U16(x),
+                NumberValue::S16(x) => PrimitiveValue::S16(x),
+                NumberValue::U32(x) => PrimitiveValue::U32(x),
+                NumberValue::S32(x) => PrimitiveValue::S32(x),
+                NumberValue::U64(x) => PrimitiveValue::U64(x),
+                NumberValue::S64(x) => PrimitiveValue::S64(x),
+                NumberValue::Float(x) => PrimitiveValue::Float(x),
+                NumberValue::Double(x) => PrimitiveValue::Double(x),
+            };
+            return Ok(value.into());
+        }
+        if let Some(x) = duration(type_name) {
+            let value = value.as_u64().ok_or_else(error)?;
+            return Ok(match x {
+                DurationType::Milliseconds => DurationValue::Milliseconds(value),
+                DurationType::Seconds => DurationValue::Seconds(value),
+            }
+            .into());
+        }
+        let text = value.as_str().ok_or_else(error)?;
+        if let Some(x) = self.enums.get(type_name) {
+            return Ok(x.value(text)?.into());
+        }
+        Ok(self
+            .universal_struct(type_name)?
+            .zero_parameter_initializer(text)?
+            .into())
+    }
+
+    pub(crate) fn constraint_value(
+        &self,
+        type_name: &str,
+        value: &serde_json::Value,
+    ) -> BindResult<NumberValue> {
+        primitive(type_name)
+            .and_then(|x| number_value(value, x))
+            .ok_or_else(|| bad_value(value, type_name))
+    }
+}
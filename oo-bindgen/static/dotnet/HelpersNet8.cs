@@ -0,0 +1,104 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace Helpers
+{
+    internal static class RustString
+    {
+        internal static unsafe IntPtr ToNative(string value)
+        {
+            var length = System.Text.Encoding.UTF8.GetByteCount(value);
+            var handle = Marshal.AllocHGlobal(length + 1);
+            var buffer = new Span<byte>((void*)handle, length + 1);
+            // copy the bytes of the string
+            System.Text.Encoding.UTF8.GetBytes(value, buffer);
+            // null terminator
+            buffer[length] = 0;
+            return handle;
+        }
+
+        internal static void Destroy(IntPtr value)
+        {
+            Marshal.FreeHGlobal(value);
+        }
+
+        internal static unsafe string FromNative(IntPtr value)
+        {
+            var buffer = MemoryMarshal.CreateReadOnlySpanFromNullTerminated((byte*)value);
+            return System.Text.Encoding.UTF8.GetString(buffer);
+        }
+    }
+
+    internal static class PrimitivePointer
+    {
+        private static unsafe T Read<T>(IntPtr x) where T : unmanaged
+        {
+            if (x == IntPtr.Zero)
+            {
+                throw new ArgumentException("IntPtr cannot be zero");
+            }
+            return MemoryMarshal.Read<T>(new ReadOnlySpan<byte>((void*)x, sizeof(T)));
+        }
+
+        internal static bool ReadBool(IntPtr x)
+        {
+            return Read<byte>(x) != 0;
+        }
+
+        internal static float ReadFloat(IntPtr x)
+        {
+            return Read<float>(x);
+        }
+
+        internal static double ReadDouble(IntPtr x)
+        {
+            return Read<double>(x);
+        }
+
+        internal static class Signed
+        {
+            internal static sbyte ReadByte(IntPtr x)
+            {
+                return Read<sbyte>(x);
+            }
+
+            internal static short ReadShort(IntPtr x)
+            {
+                return Read<short>(x);
+            }
+
+            internal static int ReadInt(IntPtr x)
+            {
+                return Read<int>(x);
+            }
+
+            internal static long ReadLong(IntPtr x)
+            {
+                return Read<long>(x);
+            }
+        }
+
+        internal static class Unsigned
+        {
+            internal static byte ReadByte(IntPtr x)
+            {
+                return Read<byte>(x);
+            }
+
+            internal static ushort ReadShort(IntPtr x)
+            {
+                return Read<ushort>(x);
+            }
+
+            internal static uint ReadInt(IntPtr x)
+            {
+                return Read<uint>(x);
+            }
+
+            internal static ulong ReadLong(IntPtr x)
+            {
+                return Read<ulong>(x);
+            }
+        }
+    }
+}
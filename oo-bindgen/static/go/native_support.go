@@ -0,0 +1,129 @@
+/*
+#include <stdlib.h>
+#include <stdint.h>
+*/
+import "C"
+
+import (
+	"errors"
+	"sync"
+	"sync/atomic"
+	"unsafe"
+)
+
+// nativeScope owns the native memory borrowed by the arguments of a single call into the C API
+type nativeScope struct {
+	releases []func()
+}
+
+func newNativeScope() *nativeScope {
+	return &nativeScope{}
+}
+
+// release frees everything allocated by the scope, in reverse order of allocation
+func (scope *nativeScope) release() {
+	for i := len(scope.releases) - 1; i >= 0; i-- {
+		scope.releases[i]()
+	}
+	scope.releases = nil
+}
+
+func (scope *nativeScope) cString(value string) *C.char {
+	native := C.CString(value)
+	scope.releases = append(scope.releases, func() { C.free(unsafe.Pointer(native)) })
+	return native
+}
+
+func (scope *nativeScope) onRelease(release func()) {
+	scope.releases = append(scope.releases, release)
+}
+
+// nativeCollection builds a native collection that lives as long as the scope
+func nativeCollection[T any, P any](scope *nativeScope, items []T, create func() P, add func(P, T), destroy func(P)) P {
+	collection := create()
+	for _, item := range items {
+		add(collection, item)
+	}
+	scope.onRelease(func() { destroy(collection) })
+	return collection
+}
+
+// collectIterator drains a native iterator into a slice
+func collectIterator[P any, T any](next func() *P, convert func(P) T) []T {
+	items := []T{}
+	for item := next(); item != nil; item = next() {
+		items = append(items, convert(*item))
+	}
+	return items
+}
+
+// the C API can't hold Go pointers, so Go values handed to it are referenced by an id
+// stored in native memory and resolved through this registry
+var (
+	handleLock sync.Mutex
+	handleNext uintptr
+	handles    = map[uintptr]any{}
+)
+
+func registerHandle(value any) unsafe.Pointer {
+	handleLock.Lock()
+	defer handleLock.Unlock()
+	handleNext++
+	handles[handleNext] = value
+	ctx := C.malloc(C.size_t(unsafe.Sizeof(C.uintptr_t(0))))
+	*(*C.uintptr_t)(ctx) = C.uintptr_t(handleNext)
+	return ctx
+}
+
+func lookupHandle(ctx unsafe.Pointer) any {
+	handleLock.Lock()
+	defer handleLock.Unlock()
+	return handles[uintptr(*(*C.uintptr_t)(ctx))]
+}
+
+func releaseHandle(ctx unsafe.Pointer) any {
+	handleLock.Lock()
+	defer handleLock.Unlock()
+	id := uintptr(*(*C.uintptr_t)(ctx))
+	value := handles[id]
+	delete(handles, id)
+	C.free(ctx)
+	return value
+}
+
+// ErrDropped is received when the library drops an asynchronous operation without completing it
+var ErrDropped = errors.New("the operation was dropped without completing")
+
+// Result is the outcome of an asynchronous operation
+type Result[T any] struct {
+	Value T
+	Err   error
+}
+
+// promise completes the channel returned by an asynchronous method exactly once
+//
+// It is shared by the caller and the native callback, it fails with ErrDropped if both
+// release it before it completes.
+type promise[T any] struct {
+	results chan Result[T]
+	once    sync.Once
+	refs    int32
+}
+
+func newPromise[T any]() *promise[T] {
+	return &promise[T]{results: make(chan Result[T], 1), refs: 2}
+}
+
+func (p *promise[T]) complete(value T) {
+	p.once.Do(func() { p.results <- Result[T]{Value: value} })
+}
+
+func (p *promise[T]) fail(err error) {
+	p.once.Do(func() { p.results <- Result[T]{Err: err} })
+}
+
+func (p *promise[T]) release() {
+	if atomic.AddInt32(&p.refs, -1) == 0 {
+		p.fail(ErrDropped)
+	}
+}
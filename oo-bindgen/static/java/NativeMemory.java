@@ -0,0 +1,109 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.lang.invoke.MethodHandles;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.concurrent.atomic.AtomicLong;
+
+// helpers shared by the Foreign Function & Memory glue in NativeFunctions
+final class NativeMemory
+{
+    static final Linker LINKER = Linker.nativeLinker();
+
+    // Java objects referenced by the context pointer of native interfaces
+    private static final ConcurrentHashMap<Long, Object> OBJECTS = new ConcurrentHashMap<>();
+    private static final AtomicLong NEXT_ID = new AtomicLong(1);
+
+    // native function pointer that releases a registered object, used as the destroy callback of every interface
+    static final MemorySegment RELEASE = upcall(NativeMemory.class, "release", FunctionDescriptor.ofVoid(ValueLayout.ADDRESS));
+
+    private NativeMemory() {}
+
+    static MethodHandle downcall(SymbolLookup library, String name, FunctionDescriptor descriptor)
+    {
+        MemorySegment symbol = library.find(name).orElseThrow(() -> new UnsatisfiedLinkError("Unable to find native symbol: " + name));
+        return LINKER.downcallHandle(symbol, descriptor);
+    }
+
+    static MemorySegment upcall(Class<?> owner, String name, FunctionDescriptor descriptor)
+    {
+        try
+        {
+            MethodHandle target = MethodHandles.lookup().findStatic(owner, name, descriptor.toMethodType());
+            return LINKER.upcallStub(target, descriptor, Arena.global());
+        }
+        catch(ReflectiveOperationException e)
+        {
+            throw new IllegalStateException("Unable to create upcall stub for " + name, e);
+        }
+    }
+
+    // build a struct layout with the same padding as the C compiler
+    static StructLayout struct(MemoryLayout... members)
+    {
+        List<MemoryLayout> padded = new ArrayList<>();
+        long offset = 0;
+        long alignment = 1;
+        for (MemoryLayout member : members)
+        {
+            long padding = (member.byteAlignment() - (offset % member.byteAlignment())) % member.byteAlignment();
+            if (padding > 0)
+            {
+                padded.add(MemoryLayout.paddingLayout(padding));
+                offset += padding;
+            }
+            padded.add(member);
+            offset += member.byteSize();
+            alignment = Math.max(alignment, member.byteAlignment());
+        }
+        long trailing = (alignment - (offset % alignment)) % alignment;
+        if (trailing > 0)
+        {
+            padded.add(MemoryLayout.paddingLayout(trailing));
+        }
+        return MemoryLayout.structLayout(padded.toArray(new MemoryLayout[0]));
+    }
+
+    // slice of a struct segment that holds a named field
+    static MemorySegment field(MemorySegment segment, StructLayout layout, String name)
+    {
+        MemoryLayout.PathElement path = MemoryLayout.PathElement.groupElement(name);
+        return segment.asSlice(layout.byteOffset(path), layout.select(path).byteSize());
+    }
+
+    static String toJavaString(MemorySegment value)
+    {
+        return value.reinterpret(Long.MAX_VALUE).getString(0);
+    }
+
+    static long register(Object value)
+    {
+        long id = NEXT_ID.getAndIncrement();
+        OBJECTS.put(id, value);
+        return id;
+    }
+
+    static Object lookup(MemorySegment ctx)
+    {
+        return OBJECTS.get(ctx.address());
+    }
+
+    private static void release(MemorySegment ctx)
+    {
+        OBJECTS.remove(ctx.address());
+    }
+
+    // rethrow without wrapping, checked exceptions propagate undeclared just like they do from JNI native methods
+    static RuntimeException rethrow(Throwable t)
+    {
+        NativeMemory.<RuntimeException>sneakyThrow(t);
+        return null;
+    }
+
+    @SuppressWarnings("unchecked")
+    private static <T extends Throwable> void sneakyThrow(Throwable t) throws T
+    {
+        throw (T) t;
+    }
+}
@@ -0,0 +1,804 @@
+#ifndef NAPI_SUPPORT_H
+#define NAPI_SUPPORT_H
+
+#define NAPI_VERSION 8
+
+#include <node_api.h>
+#include <uv.h>
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+/* ---------- scope of the native memory borrowed by the arguments of a call ---------- */
+
+typedef struct napi_support_release_t
+{
+    void (*release)(void*);
+    void* value;
+    struct napi_support_release_t* next;
+} napi_support_release_t;
+
+typedef struct napi_support_scope_t
+{
+    napi_support_release_t* releases;
+} napi_support_scope_t;
+
+static bool napi_support_on_release(napi_support_scope_t* scope, void (*release)(void*), void* value)
+{
+    napi_support_release_t* node = malloc(sizeof(napi_support_release_t));
+    if (node == NULL)
+    {
+        return false;
+    }
+    node->release = release;
+    node->value = value;
+    node->next = scope->releases;
+    scope->releases = node;
+    return true;
+}
+
+/* release everything in reverse order of allocation */
+static void napi_support_scope_release(napi_support_scope_t* scope)
+{
+    while (scope->releases != NULL)
+    {
+        napi_support_release_t* node = scope->releases;
+        scope->releases = node->next;
+        node->release(node->value);
+        free(node);
+    }
+}
+
+static void* napi_support_alloc(napi_env env, napi_support_scope_t* scope, size_t size)
+{
+    void* value = calloc(1, size);
+    if (value == NULL || !napi_support_on_release(scope, free, value))
+    {
+        free(value);
+        napi_throw_error(env, NULL, "out of memory");
+        return NULL;
+    }
+    return value;
+}
+
+/* ---------- functions registered by the JavaScript layer ---------- */
+
+typedef struct napi_support_instance_t
+{
+    /* (name, handle) => instance of the class */
+    napi_ref wrap;
+    /* (name, error) => exception */
+    napi_ref exception;
+} napi_support_instance_t;
+
+static void napi_support_instance_finalize(napi_env env, void* data, void* hint)
+{
+    napi_support_instance_t* instance = data;
+    (void)hint;
+    if (instance->wrap != NULL)
+    {
+        napi_delete_reference(env, instance->wrap);
+    }
+    if (instance->exception != NULL)
+    {
+        napi_delete_reference(env, instance->exception);
+    }
+    free(instance);
+}
+
+static napi_value napi_support_init(napi_env env, napi_callback_info info)
+{
+    size_t argc = 2;
+    napi_value argv[2];
+    napi_support_instance_t* instance;
+
+    if (napi_get_cb_info(env, info, &argc, argv, NULL, NULL) != napi_ok)
+    {
+        return NULL;
+    }
+
+    instance = calloc(1, sizeof(napi_support_instance_t));
+    if (instance == NULL)
+    {
+        napi_throw_error(env, NULL, "out of memory");
+        return NULL;
+    }
+    if (napi_create_reference(env, argv[0], 1, &instance->wrap) != napi_ok
+        || napi_create_reference(env, argv[1], 1, &instance->exception) != napi_ok
+        || napi_set_instance_data(env, instance, napi_support_instance_finalize, NULL) != napi_ok)
+    {
+        napi_support_instance_finalize(env, instance, NULL);
+        return NULL;
+    }
+
+    return NULL;
+}
+
+static napi_value napi_support_call_registered(napi_env env, bool exception, size_t argc, const napi_value* argv)
+{
+    napi_support_instance_t* instance;
+    napi_value function;
+    napi_value global;
+    napi_value result;
+
+    if (napi_get_instance_data(env, (void**)&instance) != napi_ok || instance == NULL)
+    {
+        napi_throw_error(env, NULL, "the module was not initialized");
+        return NULL;
+    }
+    if (napi_get_reference_value(env, exception ? instance->exception : instance->wrap, &function) != napi_ok
+        || napi_get_global(env, &global) != napi_ok
+        || napi_call_function(env, global, function, argc, argv, &result) != napi_ok)
+    {
+        return NULL;
+    }
+    return result;
+}
+
+/* ---------- values ---------- */
+
+static napi_value napi_support_undefined(napi_env env)
+{
+    napi_value value;
+    return napi_get_undefined(env, &value) == napi_ok ? value : NULL;
+}
+
+static napi_value napi_support_null(napi_env env)
+{
+    napi_value value;
+    return napi_get_null(env, &value) == napi_ok ? value : NULL;
+}
+
+static bool napi_support_is_nullish(napi_env env, napi_value value)
+{
+    napi_valuetype type;
+    return napi_typeof(env, value, &type) == napi_ok && (type == napi_undefined || type == napi_null);
+}
+
+static bool napi_support_type_error(napi_env env, const char* message)
+{
+    napi_throw_type_error(env, NULL, message);
+    return false;
+}
+
+static bool napi_support_get_bool(napi_env env, napi_value value, bool* out)
+{
+    if (napi_get_value_bool(env, value, out) != napi_ok)
+    {
+        return napi_support_type_error(env, "expected a boolean");
+    }
+    return true;
+}
+
+/* integers of 32 bits or less are numbers that must be within the range of the C type */
+static bool napi_support_get_integer(napi_env env, napi_value value, int64_t min, int64_t max, int64_t* out)
+{
+    double number;
+    if (napi_get_value_double(env, value, &number) != napi_ok)
+    {
+        return napi_support_type_error(env, "expected a number");
+    }
+    if (number != (double)(int64_t)number || number < (double)min || number > (double)max)
+    {
+        napi_throw_range_error(env, NULL, "expected an integer in the range of the native type");
+        return false;
+    }
+    *out = (int64_t)number;
+    return true;
+}
+
+#define NAPI_SUPPORT_INTEGER(NAME, TYPE, MIN, MAX)                                  \
+    static bool napi_support_get_##NAME(napi_env env, napi_value value, TYPE* out)  \
+    {                                                                               \
+        int64_t number;                                                             \
+        if (!napi_support_get_integer(env, value, MIN, MAX, &number))               \
+        {                                                                           \
+            return false;                                                           \
+        }                                                                           \
+        *out = (TYPE)number;                                                        \
+        return true;                                                                \
+    }
+
+NAPI_SUPPORT_INTEGER(u8, uint8_t, 0, UINT8_MAX)
+NAPI_SUPPORT_INTEGER(s8, int8_t, INT8_MIN, INT8_MAX)
+NAPI_SUPPORT_INTEGER(u16, uint16_t, 0, UINT16_MAX)
+NAPI_SUPPORT_INTEGER(s16, int16_t, INT16_MIN, INT16_MAX)
+NAPI_SUPPORT_INTEGER(u32, uint32_t, 0, UINT32_MAX)
+NAPI_SUPPORT_INTEGER(s32, int32_t, INT32_MIN, INT32_MAX)
+
+/* 64-bit integers are exchanged as BigInt to avoid losing precision */
+static bool napi_support_get_u64(napi_env env, napi_value value, uint64_t* out)
+{
+    bool lossless;
+    if (napi_get_value_bigint_uint64(env, value, out, &lossless) != napi_ok)
+    {
+        return napi_support_type_error(env, "expected a bigint");
+    }
+    if (!lossless)
+    {
+        napi_throw_range_error(env, NULL, "expected a bigint in the range of uint64");
+        return false;
+    }
+    return true;
+}
+
+static bool napi_support_get_s64(napi_env env, napi_value value, int64_t* out)
+{
+    bool lossless;
+    if (napi_get_value_bigint_int64(env, value, out, &lossless) != napi_ok)
+    {
+        return napi_support_type_error(env, "expected a bigint");
+    }
+    if (!lossless)
+    {
+        napi_throw_range_error(env, NULL, "expected a bigint in the range of int64");
+        return false;
+    }
+    return true;
+}
+
+static bool napi_support_get_double(napi_env env, napi_value value, double* out)
+{
+    if (napi_get_value_double(env, value, out) != napi_ok)
+    {
+        return napi_support_type_error(env, "expected a number");
+    }
+    return true;
+}
+
+static bool napi_support_get_float(napi_env env, napi_value value, float* out)
+{
+    double number;
+    if (!napi_support_get_double(env, value, &number))
+    {
+        return false;
+    }
+    *out = (float)number;
+    return true;
+}
+
+/* durations are numbers of milliseconds */
+static bool napi_support_get_duration(napi_env env, napi_value value, uint64_t divisor, uint64_t* out)
+{
+    double number;
+    if (!napi_support_get_double(env, value, &number))
+    {
+        return false;
+    }
+    if (number < 0)
+    {
+        napi_throw_range_error(env, NULL, "expected a positive duration");
+        return false;
+    }
+    *out = (uint64_t)number / divisor;
+    return true;
+}
+
+static bool napi_support_get_duration_ms(napi_env env, napi_value value, uint64_t* out)
+{
+    return napi_support_get_duration(env, value, 1, out);
+}
+
+static bool napi_support_get_duration_s(napi_env env, napi_value value, uint64_t* out)
+{
+    return napi_support_get_duration(env, value, 1000, out);
+}
+
+static bool napi_support_get_string(napi_env env, napi_support_scope_t* scope, napi_value value, const char** out)
+{
+    size_t length;
+    char* buffer;
+    if (napi_get_value_string_utf8(env, value, NULL, 0, &length) != napi_ok)
+    {
+        return napi_support_type_error(env, "expected a string");
+    }
+    buffer = napi_support_alloc(env, scope, length + 1);
+    if (buffer == NULL)
+    {
+        return false;
+    }
+    if (napi_get_value_string_utf8(env, value, buffer, length + 1, &length) != napi_ok)
+    {
+        return false;
+    }
+    *out = buffer;
+    return true;
+}
+
+/* classes are passed either as instances of the JavaScript class or as their handle */
+static bool napi_support_get_handle(napi_env env, napi_value value, void** out)
+{
+    napi_valuetype type;
+    if (napi_typeof(env, value, &type) != napi_ok)
+    {
+        return false;
+    }
+    if (type == napi_object && napi_get_named_property(env, value, "_handle", &value) != napi_ok)
+    {
+        return false;
+    }
+    if (napi_get_value_external(env, value, out) != napi_ok || *out == NULL)
+    {
+        return napi_support_type_error(env, "expected an instance that was not disposed");
+    }
+    return true;
+}
+
+static bool napi_support_get_property(napi_env env, napi_value object, const char* name, napi_value* out)
+{
+    napi_valuetype type;
+    if (napi_typeof(env, object, &type) != napi_ok)
+    {
+        return false;
+    }
+    if (type != napi_object)
+    {
+        return napi_support_type_error(env, "expected an object");
+    }
+    return napi_get_named_property(env, object, name, out) == napi_ok;
+}
+
+static bool napi_support_get_array_length(napi_env env, napi_value value, uint32_t* out)
+{
+    bool is_array;
+    if (napi_is_array(env, value, &is_array) != napi_ok || !is_array)
+    {
+        return napi_support_type_error(env, "expected an array");
+    }
+    return napi_get_array_length(env, value, out) == napi_ok;
+}
+
+static napi_value napi_support_bool(napi_env env, bool value)
+{
+    napi_value result;
+    return napi_get_boolean(env, value, &result) == napi_ok ? result : NULL;
+}
+
+static napi_value napi_support_u32(napi_env env, uint32_t value)
+{
+    napi_value result;
+    return napi_create_uint32(env, value, &result) == napi_ok ? result : NULL;
+}
+
+static napi_value napi_support_s32(napi_env env, int32_t value)
+{
+    napi_value result;
+    return napi_create_int32(env, value, &result) == napi_ok ? result : NULL;
+}
+
+static napi_value napi_support_u64(napi_env env, uint64_t value)
+{
+    napi_value result;
+    return napi_create_bigint_uint64(env, value, &result) == napi_ok ? result : NULL;
+}
+
+static napi_value napi_support_s64(napi_env env, int64_t value)
+{
+    napi_value result;
+    return napi_create_bigint_int64(env, value, &result) == napi_ok ? result : NULL;
+}
+
+static napi_value napi_support_double(napi_env env, double value)
+{
+    napi_value result;
+    return napi_create_double(env, value, &result) == napi_ok ? result : NULL;
+}
+
+static napi_value napi_support_duration_ms(napi_env env, uint64_t value)
+{
+    return napi_support_double(env, (double)value);
+}
+
+static napi_value napi_support_duration_s(napi_env env, uint64_t value)
+{
+    return napi_support_double(env, (double)value * 1000.0);
+}
+
+static napi_value napi_support_string(napi_env env, const char* value)
+{
+    napi_value result;
+    if (value == NULL)
+    {
+        return napi_support_null(env);
+    }
+    return napi_create_string_utf8(env, value, NAPI_AUTO_LENGTH, &result) == napi_ok ? result : NULL;
+}
+
+/* create an instance of a class around a native handle */
+static napi_value napi_support_wrap(napi_env env, const char* name, void* handle)
+{
+    napi_value argv[2];
+    if (handle == NULL)
+    {
+        return napi_support_null(env);
+    }
+    if (napi_create_string_utf8(env, name, NAPI_AUTO_LENGTH, &argv[0]) != napi_ok
+        || napi_create_external(env, handle, NULL, NULL, &argv[1]) != napi_ok)
+    {
+        return NULL;
+    }
+    return napi_support_call_registered(env, false, 2, argv);
+}
+
+static bool napi_support_set_property(napi_env env, napi_value object, const char* name, napi_value value)
+{
+    return value != NULL && napi_set_named_property(env, object, name, value) == napi_ok;
+}
+
+static napi_value napi_support_exception(napi_env env, const char* name, int32_t error)
+{
+    napi_value argv[2];
+    if (napi_create_string_utf8(env, name, NAPI_AUTO_LENGTH, &argv[0]) != napi_ok
+        || napi_create_int32(env, error, &argv[1]) != napi_ok)
+    {
+        return NULL;
+    }
+    return napi_support_call_registered(env, true, 2, argv);
+}
+
+static void napi_support_throw(napi_env env, const char* name, int32_t error)
+{
+    napi_value exception = napi_support_exception(env, name, error);
+    if (exception != NULL)
+    {
+        napi_throw(env, exception);
+    }
+}
+
+/* ---------- callbacks from the native library ---------- */
+
+/* context of an interface implemented in JavaScript or of a pending promise */
+typedef struct napi_support_ctx_t
+{
+    napi_env env;
+    napi_ref target;
+    napi_deferred deferred;
+    bool settled;
+    napi_threadsafe_function tsfn;
+    uv_thread_t thread;
+} napi_support_ctx_t;
+
+typedef void (*napi_support_call_t)(napi_env env, napi_support_ctx_t* ctx, void* data);
+
+typedef struct napi_support_job_t
+{
+    napi_support_call_t call;
+    napi_support_ctx_t* ctx;
+    void* data;
+    /* posted jobs own a copy of the arguments instead of signaling the calling thread */
+    bool posted;
+    void (*release)(void*);
+    uv_sem_t done;
+} napi_support_job_t;
+
+/* report an exception thrown by JavaScript code that can't be propagated to the native library */
+static void napi_support_report(napi_env env)
+{
+    bool pending;
+    napi_value exception;
+    if (napi_is_exception_pending(env, &pending) == napi_ok && pending
+        && napi_get_and_clear_last_exception(env, &exception) == napi_ok)
+    {
+        napi_fatal_exception(env, exception);
+    }
+}
+
+static void napi_support_run_job(napi_env env, napi_value js_callback, void* context, void* data)
+{
+    napi_support_job_t* job = data;
+    (void)js_callback;
+    (void)context;
+    /* env is NULL if the function is being torn down */
+    if (env != NULL)
+    {
+        job->call(env, job->ctx, job->data);
+        napi_support_report(env);
+    }
+    if (job->posted)
+    {
+        if (job->release != NULL)
+        {
+            job->release(job->data);
+        }
+        free(job);
+    }
+    else
+    {
+        uv_sem_post(&job->done);
+    }
+}
+
+static void napi_support_settle(napi_env env, napi_support_ctx_t* ctx, bool resolve, napi_value value)
+{
+    if (ctx->deferred == NULL || ctx->settled || value == NULL)
+    {
+        return;
+    }
+    ctx->settled = true;
+    if (resolve)
+    {
+        napi_resolve_deferred(env, ctx->deferred, value);
+    }
+    else
+    {
+        napi_reject_deferred(env, ctx->deferred, value);
+    }
+}
+
+static void napi_support_ctx_finalize(napi_env env, void* data, void* hint)
+{
+    napi_support_ctx_t* ctx = data;
+    napi_value error;
+    napi_value message;
+    (void)hint;
+
+    if (ctx->deferred != NULL && !ctx->settled
+        && napi_create_string_utf8(env, "the operation was dropped without completing", NAPI_AUTO_LENGTH, &message) == napi_ok
+        && napi_create_error(env, NULL, message, &error) == napi_ok)
+    {
+        napi_support_settle(env, ctx, false, error);
+    }
+    if (ctx->target != NULL)
+    {
+        napi_delete_reference(env, ctx->target);
+    }
+    free(ctx);
+}
+
+/* `target` is the JavaScript implementation of an interface, `deferred` the promise completed by a future */
+static napi_support_ctx_t* napi_support_ctx_new(napi_env env, napi_value target, napi_deferred deferred)
+{
+    napi_value name;
+    napi_support_ctx_t* ctx = calloc(1, sizeof(napi_support_ctx_t));
+    if (ctx == NULL)
+    {
+        napi_throw_error(env, NULL, "out of memory");
+        return NULL;
+    }
+    ctx->env = env;
+    ctx->deferred = deferred;
+    ctx->thread = uv_thread_self();
+
+    if ((target != NULL && napi_create_reference(env, target, 1, &ctx->target) != napi_ok)
+        || napi_create_string_utf8(env, "native callback", NAPI_AUTO_LENGTH, &name) != napi_ok
+        || napi_create_threadsafe_function(env, NULL, NULL, name, 0, 1, ctx, napi_support_ctx_finalize, NULL, napi_support_run_job, &ctx->tsfn) != napi_ok)
+    {
+        if (ctx->target != NULL)
+        {
+            napi_delete_reference(env, ctx->target);
+        }
+        free(ctx);
+        return NULL;
+    }
+
+    /* only pending promises keep the event loop alive */
+    if (deferred == NULL)
+    {
+        napi_unref_threadsafe_function(env, ctx->tsfn);
+    }
+
+    return ctx;
+}
+
+/* called by the native library when it drops the interface */
+static void napi_support_ctx_release(void* ctx)
+{
+    napi_release_threadsafe_function(((napi_support_ctx_t*)ctx)->tsfn, napi_tsfn_release);
+}
+
+/* run a callback on the JavaScript thread
+ *
+ * Callbacks invoked from another thread block until the event loop has run them, so they must
+ * only be used for callbacks that return a value or borrow native memory
+ */
+static void napi_support_invoke(void* ctx, napi_support_call_t call, void* data)
+{
+    napi_support_ctx_t* context = ctx;
+    uv_thread_t self = uv_thread_self();
+    napi_support_job_t job;
+
+    if (uv_thread_equal(&self, &context->thread))
+    {
+        napi_handle_scope scope;
+        if (napi_open_handle_scope(context->env, &scope) == napi_ok)
+        {
+            call(context->env, context, data);
+            napi_support_report(context->env);
+            napi_close_handle_scope(context->env, scope);
+        }
+        return;
+    }
+
+    job.call = call;
+    job.ctx = context;
+    job.data = data;
+    job.posted = false;
+    job.release = NULL;
+    if (uv_sem_init(&job.done, 0) != 0)
+    {
+        return;
+    }
+    if (napi_call_threadsafe_function(context->tsfn, &job, napi_tsfn_blocking) == napi_ok)
+    {
+        uv_sem_wait(&job.done);
+    }
+    uv_sem_destroy(&job.done);
+}
+
+/* run a callback without a return value on the JavaScript thread
+ *
+ * Callbacks invoked from another thread don't wait for the event loop, which might itself be waiting
+ * for that thread. `data` is a copy of the arguments allocated with malloc, it's freed by `release`
+ */
+static void napi_support_post(void* ctx, napi_support_call_t call, void* data, void (*release)(void*))
+{
+    napi_support_ctx_t* context = ctx;
+    uv_thread_t self = uv_thread_self();
+    napi_support_job_t* job;
+
+    if (uv_thread_equal(&self, &context->thread))
+    {
+        napi_handle_scope scope;
+        if (napi_open_handle_scope(context->env, &scope) == napi_ok)
+        {
+            call(context->env, context, data);
+            napi_support_report(context->env);
+            napi_close_handle_scope(context->env, scope);
+        }
+    }
+    else
+    {
+        job = malloc(sizeof(napi_support_job_t));
+        if (job != NULL)
+        {
+            job->call = call;
+            job->ctx = context;
+            job->data = data;
+            job->posted = true;
+            job->release = release;
+            if (napi_call_threadsafe_function(context->tsfn, job, napi_tsfn_nonblocking) == napi_ok)
+            {
+                return;
+            }
+            free(job);
+        }
+    }
+
+    if (release != NULL)
+    {
+        release(data);
+    }
+}
+
+/* copy a string borrowed from the native library, NULL is converted to null */
+static char* napi_support_copy_string(const char* value)
+{
+    size_t length;
+    char* copy;
+    if (value == NULL)
+    {
+        return NULL;
+    }
+    length = strlen(value);
+    copy = malloc(length + 1);
+    if (copy != NULL)
+    {
+        memcpy(copy, value, length + 1);
+    }
+    return copy;
+}
+
+/* ---------- native functions run on the thread pool ---------- */
+
+typedef struct napi_support_work_t
+{
+    napi_async_work work;
+    void (*execute)(void*);
+    void* data;
+} napi_support_work_t;
+
+static void napi_support_work_execute(napi_env env, void* data)
+{
+    napi_support_work_t* work = data;
+    (void)env;
+    work->execute(work->data);
+}
+
+static void napi_support_work_complete(napi_env env, napi_status status, void* data)
+{
+    napi_support_work_t* work = data;
+    (void)status;
+    napi_delete_async_work(env, work->work);
+    free(work);
+}
+
+/* run a function off the JavaScript thread, e.g. a destructor that joins threads which call back into JavaScript
+ *
+ * The pending work keeps the event loop alive until the function returns
+ */
+static bool napi_support_queue_work(napi_env env, void (*execute)(void*), void* data)
+{
+    napi_value name;
+    napi_support_work_t* work = calloc(1, sizeof(napi_support_work_t));
+    if (work == NULL)
+    {
+        napi_throw_error(env, NULL, "out of memory");
+        return false;
+    }
+    work->execute = execute;
+    work->data = data;
+    if (napi_create_string_utf8(env, "native destructor", NAPI_AUTO_LENGTH, &name) != napi_ok
+        || napi_create_async_work(env, NULL, name, napi_support_work_execute, napi_support_work_complete, work, &work->work) != napi_ok)
+    {
+        free(work);
+        return false;
+    }
+    if (napi_queue_async_work(env, work->work) != napi_ok)
+    {
+        napi_delete_async_work(env, work->work);
+        free(work);
+        return false;
+    }
+    return true;
+}
+
+typedef enum napi_support_call_result_t
+{
+    NAPI_SUPPORT_CALLED,
+    /* the implementation doesn't define the method and it has a default */
+    NAPI_SUPPORT_MISSING,
+    NAPI_SUPPORT_FAILED,
+} napi_support_call_result_t;
+
+/* call a method of the implementation, functional interfaces may be implemented by a function */
+static napi_support_call_result_t napi_support_call_method(napi_env env, napi_support_ctx_t* ctx, const char* name, bool optional, size_t argc, const napi_value* argv, napi_value* result)
+{
+    napi_value target;
+    napi_value method;
+    napi_valuetype type;
+
+    if (napi_get_reference_value(env, ctx->target, &target) != napi_ok || napi_typeof(env, target, &type) != napi_ok)
+    {
+        return NAPI_SUPPORT_FAILED;
+    }
+
+    if (type == napi_function)
+    {
+        method = target;
+    }
+    else if (napi_get_named_property(env, target, name, &method) != napi_ok || napi_typeof(env, method, &type) != napi_ok)
+    {
+        return NAPI_SUPPORT_FAILED;
+    }
+
+    if (type != napi_function)
+    {
+        if (optional)
+        {
+            return NAPI_SUPPORT_MISSING;
+        }
+        napi_throw_type_error(env, NULL, "the implementation of the interface is missing a method");
+        return NAPI_SUPPORT_FAILED;
+    }
+
+    return napi_call_function(env, target, method, argc, argv, result) == napi_ok ? NAPI_SUPPORT_CALLED : NAPI_SUPPORT_FAILED;
+}
+
+/* interfaces are implemented by an object, or by a function if they have a single method */
+static bool napi_support_check_implementation(napi_env env, napi_value value, bool functional)
+{
+    napi_valuetype type;
+    if (napi_typeof(env, value, &type) != napi_ok)
+    {
+        return false;
+    }
+    if (type == napi_object || (functional && type == napi_function))
+    {
+        return true;
+    }
+    return napi_support_type_error(env, functional ? "expected an object or a function" : "expected an object");
+}
+
+#endif
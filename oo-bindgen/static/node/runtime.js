@@ -0,0 +1,48 @@
+// Symbol used by `using` declarations, older runtimes don't define it
+const disposeSymbol = Symbol.dispose ?? Symbol.for("Symbol.dispose");
+
+// prototype and destructor of each class, by name
+const classes = new Map();
+
+// constructor of each exception, by name
+const exceptions = new Map();
+
+// destroys the native handle of instances that were not disposed
+const finalizer = new FinalizationRegistry(({ destroy, handle }) => destroy(handle));
+
+function registerClass(type, destroy) {
+  classes.set(type.name, { prototype: type.prototype, destroy });
+}
+
+function registerException(type) {
+  exceptions.set(type.name, type);
+}
+
+// called by the addon to create an instance around a native handle
+function wrap(name, handle) {
+  const { prototype, destroy } = classes.get(name);
+  const instance = Object.create(prototype);
+  Object.defineProperty(instance, "_handle", { value: handle, writable: true });
+  if (destroy !== undefined) {
+    finalizer.register(instance, { destroy, handle }, instance);
+  }
+  return instance;
+}
+
+// called by the addon when a native function returns an error
+function createException(name, error) {
+  const type = exceptions.get(name);
+  return new type(error);
+}
+
+// destroy the native handle of an instance, it is safe to call more than once
+function dispose(instance, destroy) {
+  if (instance._handle === null) {
+    return;
+  }
+  finalizer.unregister(instance);
+  destroy(instance._handle);
+  instance._handle = null;
+}
+
+native.__init(wrap, createException);
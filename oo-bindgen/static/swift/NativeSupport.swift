@@ -0,0 +1,119 @@
+import Foundation
+
+// native memory borrowed by the arguments of a single call into the C API
+final class NativeScope {
+    private var releases: [() -> Void] = []
+
+    init() {}
+
+    // must be called once the native call has returned, ARC may otherwise release the scope early
+    func release() {
+        for release in releases.reversed() {
+            release()
+        }
+        releases.removeAll()
+    }
+
+    func string(_ value: String) -> UnsafePointer<CChar> {
+        let copy = strdup(value)!
+        releases.append { free(copy) }
+        return UnsafePointer(copy)
+    }
+
+    func pointer<T>(_ value: T?) -> UnsafePointer<T>? {
+        guard let value = value else {
+            return nil
+        }
+        let pointer = UnsafeMutablePointer<T>.allocate(capacity: 1)
+        pointer.initialize(to: value)
+        releases.append {
+            pointer.deinitialize(count: 1)
+            pointer.deallocate()
+        }
+        return UnsafePointer(pointer)
+    }
+
+    func collection<T, C>(_ items: [T], create: () -> C, add: (C, T) -> Void, destroy: @escaping (C) -> Void) -> C {
+        let collection = create()
+        for item in items {
+            add(collection, item)
+        }
+        releases.append { destroy(collection) }
+        return collection
+    }
+}
+
+// Swift implementation of an interface referenced by the context pointer handed to the C API
+final class InterfaceBox<T> {
+    let value: T
+
+    private init(_ value: T) {
+        self.value = value
+    }
+
+    static func retain(_ value: T) -> UnsafeMutableRawPointer {
+        Unmanaged.passRetained(InterfaceBox(value)).toOpaque()
+    }
+
+    static func get(_ ctx: UnsafeMutableRawPointer?) -> T {
+        Unmanaged<InterfaceBox<T>>.fromOpaque(ctx!).takeUnretainedValue().value
+    }
+
+    static func release(_ ctx: UnsafeMutableRawPointer?) {
+        Unmanaged<InterfaceBox<T>>.fromOpaque(ctx!).release()
+    }
+}
+
+// continuation of an async method completed by the callbacks of a future interface
+final class FutureBox<T> {
+    private let lock = NSLock()
+    private var continuation: CheckedContinuation<T, Error>?
+
+    init(_ continuation: CheckedContinuation<T, Error>) {
+        self.continuation = continuation
+    }
+
+    deinit {
+        // the native side dropped the future without completing it
+        continuation?.resume(throwing: CancellationError())
+    }
+
+    func retain() -> UnsafeMutableRawPointer {
+        Unmanaged.passRetained(self).toOpaque()
+    }
+
+    func resume(with result: Result<T, Error>) {
+        lock.lock()
+        let continuation = self.continuation
+        self.continuation = nil
+        lock.unlock()
+        continuation?.resume(with: result)
+    }
+
+    static func get(_ ctx: UnsafeMutableRawPointer?) -> FutureBox<T> {
+        Unmanaged<FutureBox<T>>.fromOpaque(ctx!).takeUnretainedValue()
+    }
+
+    static func release(_ ctx: UnsafeMutableRawPointer?) {
+        Unmanaged<FutureBox<T>>.fromOpaque(ctx!).release()
+    }
+}
+
+// call a C function that writes its value to an out parameter, the parameter is zeroed if the call fails
+func withOutParameter<T, R>(_ body: (UnsafeMutablePointer<T>) -> R) -> (R, T) {
+    let raw = UnsafeMutableRawPointer.allocate(byteCount: MemoryLayout<T>.stride, alignment: MemoryLayout<T>.alignment)
+    defer { raw.deallocate() }
+    raw.initializeMemory(as: UInt8.self, repeating: 0, count: MemoryLayout<T>.stride)
+    let out = raw.bindMemory(to: T.self, capacity: 1)
+    let result = body(out)
+    return (result, out.pointee)
+}
+
+// drain a native iterator into an array
+func collectIterator<P, T>(_ iterator: OpaquePointer?, next: (OpaquePointer?) -> UnsafeMutablePointer<P>?, convert: (P) -> T) -> [T] {
+    var items: [T] = []
+    while let item = next(iterator) {
+        items.append(convert(item.pointee))
+    }
+    return items
+}
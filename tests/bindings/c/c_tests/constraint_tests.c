@@ -0,0 +1,47 @@
+#include <assert.h>
+#include <stddef.h>
+
+#include "foo.h"
+
+static void test_range_constraint()
+{
+    int32_t result = 0;
+    assert(foo_scale(200, 1, &result) == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+    assert(foo_scale(-50, 0, &result) == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+    assert(result == 0);
+    assert(foo_scale(-50, 2, &result) == FOO_CONSTRAINT_ERROR_OK);
+    assert(result == -100);
+}
+
+static void test_string_constraints()
+{
+    assert(foo_check_identifier("") == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+    assert(foo_check_identifier("this_identifier_is_too_long") == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+    assert(foo_check_identifier("9lives") == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+    assert(foo_check_identifier("my_identifier") == FOO_CONSTRAINT_ERROR_OK);
+}
+
+static void test_struct_constraints()
+{
+    uint16_t port = 0;
+    foo_endpoint_t endpoint = foo_endpoint_init("localhost", 0);
+    assert(foo_endpoint_port(endpoint, &port) == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+
+    endpoint = foo_endpoint_init("", 20000);
+    assert(foo_endpoint_port(endpoint, &port) == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+
+    endpoint = foo_endpoint_init("localhost", 20000);
+    endpoint.timeout = 0;
+    assert(foo_endpoint_port(endpoint, &port) == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+
+    endpoint = foo_endpoint_init("localhost", 20000);
+    assert(foo_endpoint_port(endpoint, &port) == FOO_CONSTRAINT_ERROR_OK);
+    assert(port == 20000);
+}
+
+void constraint_tests()
+{
+    test_range_constraint();
+    test_string_constraints();
+    test_struct_constraints();
+}
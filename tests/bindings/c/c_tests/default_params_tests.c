@@ -0,0 +1,22 @@
+#include <assert.h>
+#include <stddef.h>
+
+#include "foo.h"
+
+static void test_all_parameters_are_explicit()
+{
+    assert(foo_add_offset(5, 10, 1) == 15);
+    assert(foo_add_offset(5, 1, 3) == 18);
+    assert(foo_duration_to_millis(1500) == 1500);
+    assert(foo_string_byte_length("hello") == 5);
+    assert(foo_speed_value(FOO_SPEED_SLOW) == 0);
+
+    foo_counter_t* counter = foo_counter_create(4);
+    assert(foo_counter_increment(counter, 2) == 6);
+    foo_counter_destroy(counter);
+}
+
+void default_params_tests()
+{
+    test_all_parameters_are_explicit();
+}
@@ -2,6 +2,9 @@ void version_tests();
 void constant_tests();
 void enum_tests();
 void error_tests();
+void constraint_tests();
+void default_params_tests();
+void overload_tests();
 void duration_tests();
 void primitive_iterator_tests();
 void string_tests();
@@ -9,6 +12,8 @@ void structure_tests();
 void callback_tests();
 void iterator_tests();
 void universal_tests();
+void naming_tests();
+void conformance_tests();
 
 int main()
 {
@@ -16,6 +21,9 @@ int main()
     constant_tests();
     enum_tests();
     error_tests();
+    constraint_tests();
+    default_params_tests();
+    overload_tests();
     duration_tests();
     primitive_iterator_tests();
     string_tests();
@@ -23,6 +31,8 @@ int main()
     callback_tests();
     iterator_tests();
     universal_tests();
+    naming_tests();
+    conformance_tests();
 
     return 0;
 }
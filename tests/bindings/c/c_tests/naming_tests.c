@@ -0,0 +1,28 @@
+#include <assert.h>
+#include <stddef.h>
+
+#include "foo.h"
+
+static void test_reserved_names_are_escaped()
+{
+    foo_url_registry_t* registry = foo_url_registry_create(42);
+    foo_url_registry_insert(registry, 1, 7);
+
+    foo_registry_entry_t entry = foo_url_registry_get(registry, 1);
+    assert(entry.value == 7);
+    assert(!entry.default_);
+
+    assert(foo_url_registry_delete(registry, 1));
+    assert(!foo_url_registry_delete(registry, 1));
+
+    entry = foo_url_registry_get(registry, 1);
+    assert(entry.value == 42);
+    assert(entry.default_);
+
+    foo_url_registry_destroy(registry);
+}
+
+void naming_tests()
+{
+    test_reserved_names_are_escaped();
+}
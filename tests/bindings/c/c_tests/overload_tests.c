@@ -0,0 +1,23 @@
+#include <assert.h>
+#include <stddef.h>
+
+#include "foo.h"
+
+static void test_overloads_have_distinct_c_names()
+{
+    foo_accumulator_t* accumulator = foo_accumulator_create();
+    foo_accumulator_add_u8(accumulator, 1);
+    foo_accumulator_add_u32(accumulator, 100000);
+    foo_accumulator_add_string(accumulator, "four");
+    foo_accumulator_add_scaled(accumulator, 3, 5);
+    assert(foo_accumulator_total(accumulator) == 100020);
+    foo_accumulator_destroy(accumulator);
+
+    assert(foo_describe_bool(true) == 1);
+    assert(foo_describe_double(1.5) == 2);
+}
+
+void overload_tests()
+{
+    test_overloads_have_distinct_c_names();
+}
@@ -9,7 +9,7 @@ struct Data {
 };
 
 
-class CallbackInterface final : public foo::CallbackInterface {
+class CallbackInterface final : public foo::callbacks::CallbackInterface {
     std::shared_ptr<Data> data;
 
 public:
@@ -37,7 +37,7 @@ static void simple_callback_test()
     auto data = std::make_shared<Data>();
 
     {
-        foo::CallbackSource cb_source;    
+        foo::callbacks::CallbackSource cb_source;    
         cb_source.set_interface(std::make_unique<CallbackInterface>(data));
 
         {
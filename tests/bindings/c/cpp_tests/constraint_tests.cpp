@@ -0,0 +1,50 @@
+#include <cassert>
+#include <chrono>
+#include <stdexcept>
+
+#include "foo.hpp"
+
+template <class F>
+static void assert_invalid_argument(F f)
+{
+    try {
+        f();
+        assert(false);
+    }
+    catch (const std::invalid_argument&) {
+    }
+}
+
+static void test_range_constraint()
+{
+    assert_invalid_argument([]() { foo::ConstraintFunctions::scale(200, 1); });
+    assert_invalid_argument([]() { foo::ConstraintFunctions::scale(-50, 0); });
+    assert(foo::ConstraintFunctions::scale(-50, 2) == -100);
+}
+
+static void test_string_constraints()
+{
+    assert_invalid_argument([]() { foo::ConstraintFunctions::check_identifier(""); });
+    assert_invalid_argument([]() { foo::ConstraintFunctions::check_identifier("this_identifier_is_too_long"); });
+    assert_invalid_argument([]() { foo::ConstraintFunctions::check_identifier("9lives"); });
+    foo::ConstraintFunctions::check_identifier("my_identifier");
+}
+
+static void test_struct_constraints()
+{
+    assert_invalid_argument([]() { foo::ConstraintFunctions::endpoint_port(foo::Endpoint("localhost", 0)); });
+    assert_invalid_argument([]() { foo::ConstraintFunctions::endpoint_port(foo::Endpoint("", 20000)); });
+    assert_invalid_argument([]() {
+        foo::Endpoint endpoint("localhost", 20000);
+        endpoint.timeout = std::chrono::milliseconds(0);
+        foo::ConstraintFunctions::endpoint_port(endpoint);
+    });
+    assert(foo::ConstraintFunctions::endpoint_port(foo::Endpoint("localhost", 20000)) == 20000);
+}
+
+void constraint_tests()
+{
+    test_range_constraint();
+    test_string_constraints();
+    test_struct_constraints();
+}
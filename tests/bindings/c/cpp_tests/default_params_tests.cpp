@@ -0,0 +1,33 @@
+#include <cassert>
+#include <chrono>
+
+#include "foo.hpp"
+
+static void test_static_method_defaults()
+{
+    assert(foo::DefaultParamFunctions::add_offset(5) == 15);
+    assert(foo::DefaultParamFunctions::add_offset(5, 1) == 6);
+    assert(foo::DefaultParamFunctions::add_offset(5, 1, 3) == 18);
+    assert(foo::DefaultParamFunctions::duration_to_millis() == 1500);
+    assert(foo::DefaultParamFunctions::duration_to_millis(std::chrono::seconds(2)) == 2000);
+    assert(foo::DefaultParamFunctions::string_byte_length() == 5);
+    assert(foo::DefaultParamFunctions::string_byte_length("hi") == 2);
+    assert(foo::DefaultParamFunctions::speed_value() == 1);
+    assert(foo::DefaultParamFunctions::speed_value(foo::Speed::slow) == 0);
+}
+
+static void test_class_defaults()
+{
+    foo::Counter counter;
+    assert(counter.increment() == 1);
+    assert(counter.increment(5) == 6);
+
+    foo::Counter other(10);
+    assert(other.increment() == 11);
+}
+
+void default_params_tests()
+{
+    test_static_method_defaults();
+    test_class_defaults();
+}
@@ -3,6 +3,9 @@ void constant_tests();
 void defaulted_interface_tests();
 void enum_tests();
 void error_tests();
+void constraint_tests();
+void default_params_tests();
+void overload_tests();
 void duration_tests();
 void string_tests();
 void structure_tests();
@@ -12,6 +15,8 @@ void primitive_iterator_tests();
 void universal_tests();
 void collection_tests();
 void thread_tests();
+void naming_tests();
+void conformance_tests();
 
 int main()
 {
@@ -20,6 +25,9 @@ int main()
     defaulted_interface_tests();
     enum_tests();
     error_tests();
+    constraint_tests();
+    default_params_tests();
+    overload_tests();
     iterator_tests();
     primitive_iterator_tests();
     duration_tests();
@@ -29,6 +37,8 @@ int main()
     universal_tests();
     collection_tests();
     thread_tests();
+    naming_tests();
+    conformance_tests();
 
     return 0;
 }
\ No newline at end of file
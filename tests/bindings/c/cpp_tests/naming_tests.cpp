@@ -0,0 +1,25 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+static void test_reserved_names_are_escaped()
+{
+    foo::URLRegistry registry(42);
+    registry.insert(1, 7);
+
+    auto entry = registry.get(1);
+    assert(entry.value == 7);
+    assert(!entry.default_);
+
+    assert(registry.delete_(1));
+    assert(!registry.delete_(1));
+
+    entry = registry.get(1);
+    assert(entry.value == 42);
+    assert(entry.default_);
+}
+
+void naming_tests()
+{
+    test_reserved_names_are_escaped();
+}
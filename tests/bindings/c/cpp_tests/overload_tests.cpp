@@ -0,0 +1,25 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+static void test_method_overloads()
+{
+    foo::Accumulator accumulator;
+    accumulator.add(static_cast<uint8_t>(1));
+    accumulator.add(static_cast<uint32_t>(100000));
+    accumulator.add(std::string("four"));
+    accumulator.add(3u, 5u);
+    assert(accumulator.total() == 100020);
+}
+
+static void test_static_method_overloads()
+{
+    assert(foo::OverloadFunctions::describe(true) == 1);
+    assert(foo::OverloadFunctions::describe(1.5) == 2);
+}
+
+void overload_tests()
+{
+    test_method_overloads();
+    test_static_method_overloads();
+}
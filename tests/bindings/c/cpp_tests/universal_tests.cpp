@@ -1,4 +1,5 @@
 #include <cassert>
+#include <sstream>
 
 #include "foo.hpp"
 
@@ -27,7 +28,27 @@ static void test_universal_interface()
     assert(output.delay == std::chrono::milliseconds(78));
 }
 
+static void test_universal_value_semantics()
+{
+    foo::UniversalOuterStruct x;
+    foo::UniversalOuterStruct y;
+
+    assert(x == y);
+
+    y.inner.value = 7;
+    assert(x != y);
+
+    y.inner.value = x.inner.value;
+    y.delay = std::chrono::seconds(6);
+    assert(x != y);
+
+    std::ostringstream os;
+    os << y;
+    assert(os.str() == "UniversalOuterStruct { inner: UniversalInnerStruct { value: -42 }, delay: 6000ms }");
+}
+
 void universal_tests()
 {
     test_universal_interface();
+    test_universal_value_semantics();
 }
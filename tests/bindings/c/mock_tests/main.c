@@ -0,0 +1,74 @@
+#include <assert.h>
+#include <stddef.h>
+#include <string.h>
+
+#include "foo.h"
+#include "foo_mock.h"
+
+static uint32_t double_value(uint32_t value, void* context)
+{
+    return 2 * value;
+}
+
+static void count_destroy(void* context)
+{
+    ++*(int*)context;
+}
+
+static void test_records_calls()
+{
+    foo_mock_reset();
+
+    assert(foo_uint32_echo(42) == 0);
+    assert(foo_uint32_echo(43) == 0);
+
+    assert(foo_mock_call_count("uint32_echo") == 2);
+    assert(strcmp(foo_mock_last_arguments("uint32_echo"), "43") == 0);
+    assert(foo_mock_call_count("uint8_echo") == 0);
+
+    foo_mock_reset();
+    assert(foo_mock_call_count("uint32_echo") == 0);
+}
+
+static void test_returns_configured_values()
+{
+    foo_mock_reset();
+
+    foo_mock_set_return("uint32_echo", "7");
+    assert(foo_uint32_echo(1) == 7);
+
+    foo_mock_set_error("validate_password", "bad_password");
+    assert(foo_validate_password("12345") == FOO_MY_ERROR_BAD_PASSWORD);
+
+    foo_mock_clear("uint32_echo");
+    foo_mock_clear("validate_password");
+    assert(foo_uint32_echo(1) == 0);
+    assert(foo_validate_password("12345") == FOO_MY_ERROR_OK);
+}
+
+static void test_invokes_interface_callbacks()
+{
+    foo_mock_reset();
+
+    int destroyed = 0;
+    foo_callback_source_t* source = foo_callback_source_create();
+    foo_callback_source_set_interface(source, foo_callback_interface_init(double_value, NULL, count_destroy, &destroyed));
+    foo_callback_source_destroy(source);
+
+    const char* arguments[] = { "21" };
+    assert(strcmp(foo_mock_invoke("callback_source_set_interface", "on_value", arguments, 1), "42") == 0);
+    assert(foo_mock_invoke("uint32_echo", "on_value", arguments, 1) == NULL);
+
+    assert(destroyed == 0);
+    foo_mock_reset();
+    assert(destroyed == 1);
+}
+
+int main()
+{
+    test_records_calls();
+    test_returns_configured_values();
+    test_invokes_interface_callbacks();
+
+    return 0;
+}
@@ -0,0 +1,285 @@
+// Conformance tests of the foo C bindings generated by oo-bindgen
+
+#include <assert.h>
+#include <float.h>
+#include <stdint.h>
+#include <string.h>
+
+#include "foo.h"
+
+static void test_echo_bool()
+{
+    assert(foo_conformance_echo_bool(false) == false);
+    assert(foo_conformance_echo_bool(true) == true);
+}
+
+static void test_echo_u8()
+{
+    assert(foo_conformance_echo_u8(0) == 0);
+    assert(foo_conformance_echo_u8(UINT8_MAX) == UINT8_MAX);
+}
+
+static void test_echo_s8()
+{
+    assert(foo_conformance_echo_s8(INT8_MIN) == INT8_MIN);
+    assert(foo_conformance_echo_s8(0) == 0);
+    assert(foo_conformance_echo_s8(INT8_MAX) == INT8_MAX);
+}
+
+static void test_echo_u16()
+{
+    assert(foo_conformance_echo_u16(0) == 0);
+    assert(foo_conformance_echo_u16(UINT16_MAX) == UINT16_MAX);
+}
+
+static void test_echo_s16()
+{
+    assert(foo_conformance_echo_s16(INT16_MIN) == INT16_MIN);
+    assert(foo_conformance_echo_s16(0) == 0);
+    assert(foo_conformance_echo_s16(INT16_MAX) == INT16_MAX);
+}
+
+static void test_echo_u32()
+{
+    assert(foo_conformance_echo_u32(0) == 0);
+    assert(foo_conformance_echo_u32(UINT32_MAX) == UINT32_MAX);
+}
+
+static void test_echo_s32()
+{
+    assert(foo_conformance_echo_s32(INT32_MIN) == INT32_MIN);
+    assert(foo_conformance_echo_s32(0) == 0);
+    assert(foo_conformance_echo_s32(INT32_MAX) == INT32_MAX);
+}
+
+static void test_echo_u64()
+{
+    assert(foo_conformance_echo_u64(0) == 0);
+    assert(foo_conformance_echo_u64(UINT64_MAX) == UINT64_MAX);
+}
+
+static void test_echo_s64()
+{
+    assert(foo_conformance_echo_s64(INT64_MIN) == INT64_MIN);
+    assert(foo_conformance_echo_s64(0) == 0);
+    assert(foo_conformance_echo_s64(INT64_MAX) == INT64_MAX);
+}
+
+static void test_echo_float()
+{
+    assert(foo_conformance_echo_float(-FLT_MAX) == -FLT_MAX);
+    assert(foo_conformance_echo_float(0) == 0);
+    assert(foo_conformance_echo_float(FLT_MAX) == FLT_MAX);
+}
+
+static void test_echo_double()
+{
+    assert(foo_conformance_echo_double(-DBL_MAX) == -DBL_MAX);
+    assert(foo_conformance_echo_double(0) == 0);
+    assert(foo_conformance_echo_double(DBL_MAX) == DBL_MAX);
+}
+
+static void test_echo_duration_ms()
+{
+    assert(foo_conformance_echo_duration_ms(0) == 0);
+    assert(foo_conformance_echo_duration_ms(1) == 1);
+    assert(foo_conformance_echo_duration_ms(4000000000) == 4000000000);
+}
+
+static void test_echo_duration_s()
+{
+    assert(foo_conformance_echo_duration_s(0) == 0);
+    assert(foo_conformance_echo_duration_s(1) == 1);
+    assert(foo_conformance_echo_duration_s(4000000000) == 4000000000);
+}
+
+static void test_echo_string()
+{
+    assert(strcmp(foo_conformance_echo_string(""), "") == 0);
+    assert(strcmp(foo_conformance_echo_string("hello"), "hello") == 0);
+    assert(strcmp(foo_conformance_echo_string("gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200"), "gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200") == 0);
+}
+
+static void test_echo_structure_enum()
+{
+    assert(foo_conformance_echo_structure_enum(FOO_STRUCTURE_ENUM_VAR1) == FOO_STRUCTURE_ENUM_VAR1);
+    assert(foo_conformance_echo_structure_enum(FOO_STRUCTURE_ENUM_VAR2) == FOO_STRUCTURE_ENUM_VAR2);
+    assert(foo_conformance_echo_structure_enum(FOO_STRUCTURE_ENUM_VAR3) == FOO_STRUCTURE_ENUM_VAR3);
+}
+
+static void test_echo_speed()
+{
+    assert(foo_conformance_echo_speed(FOO_SPEED_SLOW) == FOO_SPEED_SLOW);
+    assert(foo_conformance_echo_speed(FOO_SPEED_FAST) == FOO_SPEED_FAST);
+}
+
+static void test_echo_enum_zero_to_five()
+{
+    assert(foo_conformance_echo_enum_zero_to_five(FOO_ENUM_ZERO_TO_FIVE_ZERO) == FOO_ENUM_ZERO_TO_FIVE_ZERO);
+    assert(foo_conformance_echo_enum_zero_to_five(FOO_ENUM_ZERO_TO_FIVE_ONE) == FOO_ENUM_ZERO_TO_FIVE_ONE);
+    assert(foo_conformance_echo_enum_zero_to_five(FOO_ENUM_ZERO_TO_FIVE_TWO) == FOO_ENUM_ZERO_TO_FIVE_TWO);
+    assert(foo_conformance_echo_enum_zero_to_five(FOO_ENUM_ZERO_TO_FIVE_THREE) == FOO_ENUM_ZERO_TO_FIVE_THREE);
+    assert(foo_conformance_echo_enum_zero_to_five(FOO_ENUM_ZERO_TO_FIVE_FOUR) == FOO_ENUM_ZERO_TO_FIVE_FOUR);
+    assert(foo_conformance_echo_enum_zero_to_five(FOO_ENUM_ZERO_TO_FIVE_FIVE) == FOO_ENUM_ZERO_TO_FIVE_FIVE);
+}
+
+static void test_echo_enum_one_to_six()
+{
+    assert(foo_conformance_echo_enum_one_to_six(FOO_ENUM_ONE_TO_SIX_ONE) == FOO_ENUM_ONE_TO_SIX_ONE);
+    assert(foo_conformance_echo_enum_one_to_six(FOO_ENUM_ONE_TO_SIX_TWO) == FOO_ENUM_ONE_TO_SIX_TWO);
+    assert(foo_conformance_echo_enum_one_to_six(FOO_ENUM_ONE_TO_SIX_THREE) == FOO_ENUM_ONE_TO_SIX_THREE);
+    assert(foo_conformance_echo_enum_one_to_six(FOO_ENUM_ONE_TO_SIX_FOUR) == FOO_ENUM_ONE_TO_SIX_FOUR);
+    assert(foo_conformance_echo_enum_one_to_six(FOO_ENUM_ONE_TO_SIX_FIVE) == FOO_ENUM_ONE_TO_SIX_FIVE);
+    assert(foo_conformance_echo_enum_one_to_six(FOO_ENUM_ONE_TO_SIX_SIX) == FOO_ENUM_ONE_TO_SIX_SIX);
+}
+
+static void test_echo_enum_disjoint()
+{
+    assert(foo_conformance_echo_enum_disjoint(FOO_ENUM_DISJOINT_FIVE) == FOO_ENUM_DISJOINT_FIVE);
+    assert(foo_conformance_echo_enum_disjoint(FOO_ENUM_DISJOINT_ONE) == FOO_ENUM_DISJOINT_ONE);
+    assert(foo_conformance_echo_enum_disjoint(FOO_ENUM_DISJOINT_TWENTY) == FOO_ENUM_DISJOINT_TWENTY);
+    assert(foo_conformance_echo_enum_disjoint(FOO_ENUM_DISJOINT_FOUR) == FOO_ENUM_DISJOINT_FOUR);
+    assert(foo_conformance_echo_enum_disjoint(FOO_ENUM_DISJOINT_SEVEN) == FOO_ENUM_DISJOINT_SEVEN);
+    assert(foo_conformance_echo_enum_disjoint(FOO_ENUM_DISJOINT_TWO) == FOO_ENUM_DISJOINT_TWO);
+}
+
+static void test_echo_enum_single()
+{
+    assert(foo_conformance_echo_enum_single(FOO_ENUM_SINGLE_SINGLE) == FOO_ENUM_SINGLE_SINGLE);
+}
+
+static void test_echo_switch_position()
+{
+    assert(foo_conformance_echo_switch_position(FOO_SWITCH_POSITION_ON) == FOO_SWITCH_POSITION_ON);
+    assert(foo_conformance_echo_switch_position(FOO_SWITCH_POSITION_OFF) == FOO_SWITCH_POSITION_OFF);
+}
+
+static void test_echo_numbers()
+{
+    foo_numbers_t value = foo_numbers_init();
+    foo_numbers_t result = foo_conformance_echo_numbers(value);
+    assert(result.uint8_value == value.uint8_value);
+    assert(result.int8_value == value.int8_value);
+    assert(result.uint16_value == value.uint16_value);
+    assert(result.int16_value == value.int16_value);
+    assert(result.uint32_value == value.uint32_value);
+    assert(result.int32_value == value.int32_value);
+    assert(result.uint64_value == value.uint64_value);
+    assert(result.int64_value == value.int64_value);
+    assert(result.float_value == value.float_value);
+    assert(result.double_value == value.double_value);
+}
+
+static void test_echo_universal_inner_struct()
+{
+    foo_universal_inner_struct_t value = foo_universal_inner_struct_init();
+    foo_universal_inner_struct_t result = foo_conformance_echo_universal_inner_struct(value);
+    assert(result.value == value.value);
+}
+
+static void test_echo_universal_outer_struct()
+{
+    foo_universal_outer_struct_t value = foo_universal_outer_struct_init();
+    foo_universal_outer_struct_t result = foo_conformance_echo_universal_outer_struct(value);
+    assert(result.inner.value == value.inner.value);
+    assert(result.delay == value.delay);
+}
+
+static void test_raise_constraint_error()
+{
+    assert(foo_conformance_raise_constraint_error(0) == FOO_CONSTRAINT_ERROR_OK);
+    assert(foo_conformance_raise_constraint_error(1) == FOO_CONSTRAINT_ERROR_INVALID_ARGUMENT);
+}
+
+static void test_raise_my_error()
+{
+    assert(foo_conformance_raise_my_error(0) == FOO_MY_ERROR_OK);
+    assert(foo_conformance_raise_my_error(1) == FOO_MY_ERROR_BAD_PASSWORD);
+    assert(foo_conformance_raise_my_error(2) == FOO_MY_ERROR_NULL_ARGUMENT);
+}
+
+static void test_raise_math_is_broken()
+{
+    assert(foo_conformance_raise_math_is_broken(0) == FOO_MATH_IS_BROKEN_OK);
+    assert(foo_conformance_raise_math_is_broken(1) == FOO_MATH_IS_BROKEN_MATH_IS_BROKE);
+}
+
+static void test_conformance_string_list()
+{
+    foo_conformance_string_list_t* list = foo_conformance_string_list_create();
+    foo_conformance_string_list_add(list, "");
+    foo_conformance_string_list_add(list, "hello");
+    foo_conformance_string_list_add(list, "gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200");
+    assert(foo_conformance_string_list_size(list) == 3);
+    assert(strcmp(foo_conformance_string_list_get(list, 0), "") == 0);
+    assert(strcmp(foo_conformance_string_list_get(list, 1), "hello") == 0);
+    assert(strcmp(foo_conformance_string_list_get(list, 2), "gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200") == 0);
+    foo_conformance_string_list_destroy(list);
+}
+
+static void test_conformance_u32_list()
+{
+    foo_conformance_u32_list_t* list = foo_conformance_u32_list_create();
+    foo_conformance_u32_list_add(list, 0);
+    foo_conformance_u32_list_add(list, UINT32_MAX);
+    assert(foo_conformance_u32_list_size(list) == 2);
+    assert(foo_conformance_u32_list_get(list, 0) == 0);
+    assert(foo_conformance_u32_list_get(list, 1) == UINT32_MAX);
+    foo_conformance_u32_list_destroy(list);
+}
+
+static void receive_conformance_u32_iterator(foo_conformance_u32_iterator_t* values, void* ctx)
+{
+    uint32_t* value = NULL;
+    value = foo_conformance_u32_iterator_next(values);
+    assert(value && *value == 0);
+    value = foo_conformance_u32_iterator_next(values);
+    assert(value && *value == UINT32_MAX);
+    assert(!foo_conformance_u32_iterator_next(values));
+    ++*(int*)ctx;
+}
+
+static void test_echo_u32_iterator()
+{
+    foo_conformance_u32_list_t* list = foo_conformance_u32_list_create();
+    foo_conformance_u32_list_add(list, 0);
+    foo_conformance_u32_list_add(list, UINT32_MAX);
+    int calls = 0;
+    foo_conformance_echo_u32_iterator(list, foo_conformance_u32_receiver_init(receive_conformance_u32_iterator, NULL, &calls));
+    assert(calls == 1);
+    foo_conformance_u32_list_destroy(list);
+}
+
+void conformance_tests()
+{
+    test_echo_bool();
+    test_echo_u8();
+    test_echo_s8();
+    test_echo_u16();
+    test_echo_s16();
+    test_echo_u32();
+    test_echo_s32();
+    test_echo_u64();
+    test_echo_s64();
+    test_echo_float();
+    test_echo_double();
+    test_echo_duration_ms();
+    test_echo_duration_s();
+    test_echo_string();
+    test_echo_structure_enum();
+    test_echo_speed();
+    test_echo_enum_zero_to_five();
+    test_echo_enum_one_to_six();
+    test_echo_enum_disjoint();
+    test_echo_enum_single();
+    test_echo_switch_position();
+    test_echo_numbers();
+    test_echo_universal_inner_struct();
+    test_echo_universal_outer_struct();
+    test_raise_constraint_error();
+    test_raise_my_error();
+    test_raise_math_is_broken();
+    test_conformance_string_list();
+    test_conformance_u32_list();
+    test_echo_u32_iterator();
+}
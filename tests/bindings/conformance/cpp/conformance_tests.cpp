@@ -0,0 +1,314 @@
+// Conformance tests of the foo C++ bindings generated by oo-bindgen
+
+#include <cassert>
+#include <chrono>
+#include <limits>
+#include <string>
+#include <vector>
+
+#include "foo.hpp"
+
+static void test_echo_bool()
+{
+    assert(foo::Conformance::echo_bool(false) == false);
+    assert(foo::Conformance::echo_bool(true) == true);
+}
+
+static void test_echo_u8()
+{
+    assert(foo::Conformance::echo_u8(0) == 0);
+    assert(foo::Conformance::echo_u8(std::numeric_limits<uint8_t>::max()) == std::numeric_limits<uint8_t>::max());
+}
+
+static void test_echo_s8()
+{
+    assert(foo::Conformance::echo_s8(std::numeric_limits<int8_t>::lowest()) == std::numeric_limits<int8_t>::lowest());
+    assert(foo::Conformance::echo_s8(0) == 0);
+    assert(foo::Conformance::echo_s8(std::numeric_limits<int8_t>::max()) == std::numeric_limits<int8_t>::max());
+}
+
+static void test_echo_u16()
+{
+    assert(foo::Conformance::echo_u16(0) == 0);
+    assert(foo::Conformance::echo_u16(std::numeric_limits<uint16_t>::max()) == std::numeric_limits<uint16_t>::max());
+}
+
+static void test_echo_s16()
+{
+    assert(foo::Conformance::echo_s16(std::numeric_limits<int16_t>::lowest()) == std::numeric_limits<int16_t>::lowest());
+    assert(foo::Conformance::echo_s16(0) == 0);
+    assert(foo::Conformance::echo_s16(std::numeric_limits<int16_t>::max()) == std::numeric_limits<int16_t>::max());
+}
+
+static void test_echo_u32()
+{
+    assert(foo::Conformance::echo_u32(0) == 0);
+    assert(foo::Conformance::echo_u32(std::numeric_limits<uint32_t>::max()) == std::numeric_limits<uint32_t>::max());
+}
+
+static void test_echo_s32()
+{
+    assert(foo::Conformance::echo_s32(std::numeric_limits<int32_t>::lowest()) == std::numeric_limits<int32_t>::lowest());
+    assert(foo::Conformance::echo_s32(0) == 0);
+    assert(foo::Conformance::echo_s32(std::numeric_limits<int32_t>::max()) == std::numeric_limits<int32_t>::max());
+}
+
+static void test_echo_u64()
+{
+    assert(foo::Conformance::echo_u64(0) == 0);
+    assert(foo::Conformance::echo_u64(std::numeric_limits<uint64_t>::max()) == std::numeric_limits<uint64_t>::max());
+}
+
+static void test_echo_s64()
+{
+    assert(foo::Conformance::echo_s64(std::numeric_limits<int64_t>::lowest()) == std::numeric_limits<int64_t>::lowest());
+    assert(foo::Conformance::echo_s64(0) == 0);
+    assert(foo::Conformance::echo_s64(std::numeric_limits<int64_t>::max()) == std::numeric_limits<int64_t>::max());
+}
+
+static void test_echo_float()
+{
+    assert(foo::Conformance::echo_float(std::numeric_limits<float>::lowest()) == std::numeric_limits<float>::lowest());
+    assert(foo::Conformance::echo_float(0) == 0);
+    assert(foo::Conformance::echo_float(std::numeric_limits<float>::max()) == std::numeric_limits<float>::max());
+}
+
+static void test_echo_double()
+{
+    assert(foo::Conformance::echo_double(std::numeric_limits<double>::lowest()) == std::numeric_limits<double>::lowest());
+    assert(foo::Conformance::echo_double(0) == 0);
+    assert(foo::Conformance::echo_double(std::numeric_limits<double>::max()) == std::numeric_limits<double>::max());
+}
+
+static void test_echo_duration_ms()
+{
+    assert(foo::Conformance::echo_duration_ms(std::chrono::milliseconds(0)) == std::chrono::milliseconds(0));
+    assert(foo::Conformance::echo_duration_ms(std::chrono::milliseconds(1)) == std::chrono::milliseconds(1));
+    assert(foo::Conformance::echo_duration_ms(std::chrono::milliseconds(4000000000)) == std::chrono::milliseconds(4000000000));
+}
+
+static void test_echo_duration_s()
+{
+    assert(foo::Conformance::echo_duration_s(std::chrono::seconds(0)) == std::chrono::seconds(0));
+    assert(foo::Conformance::echo_duration_s(std::chrono::seconds(1)) == std::chrono::seconds(1));
+    assert(foo::Conformance::echo_duration_s(std::chrono::seconds(4000000000)) == std::chrono::seconds(4000000000));
+}
+
+static void test_echo_string()
+{
+    assert(foo::Conformance::echo_string(std::string("")) == std::string(""));
+    assert(foo::Conformance::echo_string(std::string("hello")) == std::string("hello"));
+    assert(foo::Conformance::echo_string(std::string("gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200")) == std::string("gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200"));
+}
+
+static void test_echo_structure_enum()
+{
+    assert(foo::Conformance::echo_structure_enum(foo::StructureEnum::var1) == foo::StructureEnum::var1);
+    assert(foo::Conformance::echo_structure_enum(foo::StructureEnum::var2) == foo::StructureEnum::var2);
+    assert(foo::Conformance::echo_structure_enum(foo::StructureEnum::var3) == foo::StructureEnum::var3);
+}
+
+static void test_echo_speed()
+{
+    assert(foo::Conformance::echo_speed(foo::Speed::slow) == foo::Speed::slow);
+    assert(foo::Conformance::echo_speed(foo::Speed::fast) == foo::Speed::fast);
+}
+
+static void test_echo_enum_zero_to_five()
+{
+    assert(foo::Conformance::echo_enum_zero_to_five(foo::EnumZeroToFive::zero) == foo::EnumZeroToFive::zero);
+    assert(foo::Conformance::echo_enum_zero_to_five(foo::EnumZeroToFive::one) == foo::EnumZeroToFive::one);
+    assert(foo::Conformance::echo_enum_zero_to_five(foo::EnumZeroToFive::two) == foo::EnumZeroToFive::two);
+    assert(foo::Conformance::echo_enum_zero_to_five(foo::EnumZeroToFive::three) == foo::EnumZeroToFive::three);
+    assert(foo::Conformance::echo_enum_zero_to_five(foo::EnumZeroToFive::four) == foo::EnumZeroToFive::four);
+    assert(foo::Conformance::echo_enum_zero_to_five(foo::EnumZeroToFive::five) == foo::EnumZeroToFive::five);
+}
+
+static void test_echo_enum_one_to_six()
+{
+    assert(foo::Conformance::echo_enum_one_to_six(foo::EnumOneToSix::one) == foo::EnumOneToSix::one);
+    assert(foo::Conformance::echo_enum_one_to_six(foo::EnumOneToSix::two) == foo::EnumOneToSix::two);
+    assert(foo::Conformance::echo_enum_one_to_six(foo::EnumOneToSix::three) == foo::EnumOneToSix::three);
+    assert(foo::Conformance::echo_enum_one_to_six(foo::EnumOneToSix::four) == foo::EnumOneToSix::four);
+    assert(foo::Conformance::echo_enum_one_to_six(foo::EnumOneToSix::five) == foo::EnumOneToSix::five);
+    assert(foo::Conformance::echo_enum_one_to_six(foo::EnumOneToSix::six) == foo::EnumOneToSix::six);
+}
+
+static void test_echo_enum_disjoint()
+{
+    assert(foo::Conformance::echo_enum_disjoint(foo::EnumDisjoint::five) == foo::EnumDisjoint::five);
+    assert(foo::Conformance::echo_enum_disjoint(foo::EnumDisjoint::one) == foo::EnumDisjoint::one);
+    assert(foo::Conformance::echo_enum_disjoint(foo::EnumDisjoint::twenty) == foo::EnumDisjoint::twenty);
+    assert(foo::Conformance::echo_enum_disjoint(foo::EnumDisjoint::four) == foo::EnumDisjoint::four);
+    assert(foo::Conformance::echo_enum_disjoint(foo::EnumDisjoint::seven) == foo::EnumDisjoint::seven);
+    assert(foo::Conformance::echo_enum_disjoint(foo::EnumDisjoint::two) == foo::EnumDisjoint::two);
+}
+
+static void test_echo_enum_single()
+{
+    assert(foo::Conformance::echo_enum_single(foo::EnumSingle::single) == foo::EnumSingle::single);
+}
+
+static void test_echo_switch_position()
+{
+    assert(foo::Conformance::echo_switch_position(foo::SwitchPosition::on) == foo::SwitchPosition::on);
+    assert(foo::Conformance::echo_switch_position(foo::SwitchPosition::off) == foo::SwitchPosition::off);
+}
+
+static void test_echo_numbers()
+{
+    const foo::Numbers value;
+    const auto result = foo::Conformance::echo_numbers(value);
+    assert(result.uint8_value == value.uint8_value);
+    assert(result.int8_value == value.int8_value);
+    assert(result.uint16_value == value.uint16_value);
+    assert(result.int16_value == value.int16_value);
+    assert(result.uint32_value == value.uint32_value);
+    assert(result.int32_value == value.int32_value);
+    assert(result.uint64_value == value.uint64_value);
+    assert(result.int64_value == value.int64_value);
+    assert(result.float_value == value.float_value);
+    assert(result.double_value == value.double_value);
+}
+
+static void test_echo_universal_inner_struct()
+{
+    const foo::UniversalInnerStruct value;
+    const auto result = foo::Conformance::echo_universal_inner_struct(value);
+    assert(result.value == value.value);
+}
+
+static void test_echo_universal_outer_struct()
+{
+    const foo::UniversalOuterStruct value;
+    const auto result = foo::Conformance::echo_universal_outer_struct(value);
+    assert(result.inner.value == value.inner.value);
+    assert(result.delay == value.delay);
+}
+
+static void test_raise_constraint_error()
+{
+    foo::Conformance::raise_constraint_error(0);
+    try
+    {
+        foo::Conformance::raise_constraint_error(1);
+        assert(false);
+    }
+    catch (const foo::ConstraintException& ex)
+    {
+        assert(ex.error == foo::ConstraintError::invalid_argument);
+    }
+}
+
+static void test_raise_my_error()
+{
+    foo::Conformance::raise_my_error(0);
+    try
+    {
+        foo::Conformance::raise_my_error(1);
+        assert(false);
+    }
+    catch (const foo::MyException& ex)
+    {
+        assert(ex.error == foo::MyError::bad_password);
+    }
+    try
+    {
+        foo::Conformance::raise_my_error(2);
+        assert(false);
+    }
+    catch (const foo::MyException& ex)
+    {
+        assert(ex.error == foo::MyError::null_argument);
+    }
+}
+
+static void test_raise_math_is_broken()
+{
+    foo::Conformance::raise_math_is_broken(0);
+    try
+    {
+        foo::Conformance::raise_math_is_broken(1);
+        assert(false);
+    }
+    catch (const foo::BrokenMathException& ex)
+    {
+        assert(ex.error == foo::MathIsBroken::math_is_broke);
+    }
+}
+
+static void test_conformance_string_list()
+{
+    const std::vector<std::string> list = {
+        std::string(""),
+        std::string("hello"),
+        std::string("gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200"),
+    };
+    assert(foo::Conformance::string_list_size(list) == 3);
+    assert(foo::Conformance::string_list_get(list, 0) == std::string(""));
+    assert(foo::Conformance::string_list_get(list, 1) == std::string("hello"));
+    assert(foo::Conformance::string_list_get(list, 2) == std::string("gr\303\274\303\237e, \344\270\226\347\225\214 \360\237\246\200"));
+}
+
+static void test_conformance_u32_list()
+{
+    const std::vector<uint32_t> list = {
+        0,
+        std::numeric_limits<uint32_t>::max(),
+    };
+    assert(foo::Conformance::u32_list_size(list) == 2);
+    assert(foo::Conformance::u32_list_get(list, 0) == 0);
+    assert(foo::Conformance::u32_list_get(list, 1) == std::numeric_limits<uint32_t>::max());
+}
+
+static void test_echo_u32_iterator()
+{
+    const std::vector<uint32_t> list = {
+        0,
+        std::numeric_limits<uint32_t>::max(),
+    };
+    std::vector<uint32_t> received;
+    auto receiver = foo::functional::conformance_u32_receiver([&](foo::ConformanceU32Iterator& values)
+    {
+        while (values.next())
+        {
+            received.push_back(values.get());
+        }
+    });
+    foo::Conformance::echo_u32_iterator(list, receiver);
+    assert(received == list);
+}
+
+void conformance_tests()
+{
+    test_echo_bool();
+    test_echo_u8();
+    test_echo_s8();
+    test_echo_u16();
+    test_echo_s16();
+    test_echo_u32();
+    test_echo_s32();
+    test_echo_u64();
+    test_echo_s64();
+    test_echo_float();
+    test_echo_double();
+    test_echo_duration_ms();
+    test_echo_duration_s();
+    test_echo_string();
+    test_echo_structure_enum();
+    test_echo_speed();
+    test_echo_enum_zero_to_five();
+    test_echo_enum_one_to_six();
+    test_echo_enum_disjoint();
+    test_echo_enum_single();
+    test_echo_switch_position();
+    test_echo_numbers();
+    test_echo_universal_inner_struct();
+    test_echo_universal_outer_struct();
+    test_raise_constraint_error();
+    test_raise_my_error();
+    test_raise_math_is_broken();
+    test_conformance_string_list();
+    test_conformance_u32_list();
+    test_echo_u32_iterator();
+}
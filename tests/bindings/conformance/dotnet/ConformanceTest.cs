@@ -0,0 +1,264 @@
+// Conformance tests of the foo .NET bindings generated by oo-bindgen
+using System;
+using System.Collections.Generic;
+using Xunit;
+using foo;
+using foo.callbacks;
+
+namespace foo.Tests
+{
+    public class ConformanceTest
+    {
+        [Fact]
+        public void EchoBool()
+        {
+            Assert.Equal(false, Conformance.EchoBool(false));
+            Assert.Equal(true, Conformance.EchoBool(true));
+        }
+        
+        [Fact]
+        public void EchoU8()
+        {
+            Assert.Equal((byte)0, Conformance.EchoU8((byte)0));
+            Assert.Equal(byte.MaxValue, Conformance.EchoU8(byte.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoS8()
+        {
+            Assert.Equal(sbyte.MinValue, Conformance.EchoS8(sbyte.MinValue));
+            Assert.Equal((sbyte)0, Conformance.EchoS8((sbyte)0));
+            Assert.Equal(sbyte.MaxValue, Conformance.EchoS8(sbyte.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoU16()
+        {
+            Assert.Equal((ushort)0, Conformance.EchoU16((ushort)0));
+            Assert.Equal(ushort.MaxValue, Conformance.EchoU16(ushort.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoS16()
+        {
+            Assert.Equal(short.MinValue, Conformance.EchoS16(short.MinValue));
+            Assert.Equal((short)0, Conformance.EchoS16((short)0));
+            Assert.Equal(short.MaxValue, Conformance.EchoS16(short.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoU32()
+        {
+            Assert.Equal((uint)0, Conformance.EchoU32((uint)0));
+            Assert.Equal(uint.MaxValue, Conformance.EchoU32(uint.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoS32()
+        {
+            Assert.Equal(int.MinValue, Conformance.EchoS32(int.MinValue));
+            Assert.Equal((int)0, Conformance.EchoS32((int)0));
+            Assert.Equal(int.MaxValue, Conformance.EchoS32(int.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoU64()
+        {
+            Assert.Equal((ulong)0, Conformance.EchoU64((ulong)0));
+            Assert.Equal(ulong.MaxValue, Conformance.EchoU64(ulong.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoS64()
+        {
+            Assert.Equal(long.MinValue, Conformance.EchoS64(long.MinValue));
+            Assert.Equal((long)0, Conformance.EchoS64((long)0));
+            Assert.Equal(long.MaxValue, Conformance.EchoS64(long.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoFloat()
+        {
+            Assert.Equal(float.MinValue, Conformance.EchoFloat(float.MinValue));
+            Assert.Equal((float)0, Conformance.EchoFloat((float)0));
+            Assert.Equal(float.MaxValue, Conformance.EchoFloat(float.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoDouble()
+        {
+            Assert.Equal(double.MinValue, Conformance.EchoDouble(double.MinValue));
+            Assert.Equal((double)0, Conformance.EchoDouble((double)0));
+            Assert.Equal(double.MaxValue, Conformance.EchoDouble(double.MaxValue));
+        }
+        
+        [Fact]
+        public void EchoDurationMs()
+        {
+            Assert.Equal(TimeSpan.FromMilliseconds(0), Conformance.EchoDurationMs(TimeSpan.FromMilliseconds(0)));
+            Assert.Equal(TimeSpan.FromMilliseconds(1), Conformance.EchoDurationMs(TimeSpan.FromMilliseconds(1)));
+            Assert.Equal(TimeSpan.FromMilliseconds(4000000000), Conformance.EchoDurationMs(TimeSpan.FromMilliseconds(4000000000)));
+        }
+        
+        [Fact]
+        public void EchoDurationS()
+        {
+            Assert.Equal(TimeSpan.FromSeconds(0), Conformance.EchoDurationS(TimeSpan.FromSeconds(0)));
+            Assert.Equal(TimeSpan.FromSeconds(1), Conformance.EchoDurationS(TimeSpan.FromSeconds(1)));
+            Assert.Equal(TimeSpan.FromSeconds(4000000000), Conformance.EchoDurationS(TimeSpan.FromSeconds(4000000000)));
+        }
+        
+        [Fact]
+        public void EchoString()
+        {
+            Assert.Equal("", Conformance.EchoString(""));
+            Assert.Equal("hello", Conformance.EchoString("hello"));
+            Assert.Equal("gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80", Conformance.EchoString("gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80"));
+        }
+        
+        [Fact]
+        public void EchoStructureEnum()
+        {
+            Assert.Equal(StructureEnum.Var1, Conformance.EchoStructureEnum(StructureEnum.Var1));
+            Assert.Equal(StructureEnum.Var2, Conformance.EchoStructureEnum(StructureEnum.Var2));
+            Assert.Equal(StructureEnum.Var3, Conformance.EchoStructureEnum(StructureEnum.Var3));
+        }
+        
+        [Fact]
+        public void EchoSpeed()
+        {
+            Assert.Equal(Speed.Slow, Conformance.EchoSpeed(Speed.Slow));
+            Assert.Equal(Speed.Fast, Conformance.EchoSpeed(Speed.Fast));
+        }
+        
+        [Fact]
+        public void EchoEnumZeroToFive()
+        {
+            Assert.Equal(EnumZeroToFive.Zero, Conformance.EchoEnumZeroToFive(EnumZeroToFive.Zero));
+            Assert.Equal(EnumZeroToFive.One, Conformance.EchoEnumZeroToFive(EnumZeroToFive.One));
+            Assert.Equal(EnumZeroToFive.Two, Conformance.EchoEnumZeroToFive(EnumZeroToFive.Two));
+            Assert.Equal(EnumZeroToFive.Three, Conformance.EchoEnumZeroToFive(EnumZeroToFive.Three));
+            Assert.Equal(EnumZeroToFive.Four, Conformance.EchoEnumZeroToFive(EnumZeroToFive.Four));
+            Assert.Equal(EnumZeroToFive.Five, Conformance.EchoEnumZeroToFive(EnumZeroToFive.Five));
+        }
+        
+        [Fact]
+        public void EchoEnumOneToSix()
+        {
+            Assert.Equal(EnumOneToSix.One, Conformance.EchoEnumOneToSix(EnumOneToSix.One));
+            Assert.Equal(EnumOneToSix.Two, Conformance.EchoEnumOneToSix(EnumOneToSix.Two));
+            Assert.Equal(EnumOneToSix.Three, Conformance.EchoEnumOneToSix(EnumOneToSix.Three));
+            Assert.Equal(EnumOneToSix.Four, Conformance.EchoEnumOneToSix(EnumOneToSix.Four));
+            Assert.Equal(EnumOneToSix.Five, Conformance.EchoEnumOneToSix(EnumOneToSix.Five));
+            Assert.Equal(EnumOneToSix.Six, Conformance.EchoEnumOneToSix(EnumOneToSix.Six));
+        }
+        
+        [Fact]
+        public void EchoEnumDisjoint()
+        {
+            Assert.Equal(EnumDisjoint.Five, Conformance.EchoEnumDisjoint(EnumDisjoint.Five));
+            Assert.Equal(EnumDisjoint.One, Conformance.EchoEnumDisjoint(EnumDisjoint.One));
+            Assert.Equal(EnumDisjoint.Twenty, Conformance.EchoEnumDisjoint(EnumDisjoint.Twenty));
+            Assert.Equal(EnumDisjoint.Four, Conformance.EchoEnumDisjoint(EnumDisjoint.Four));
+            Assert.Equal(EnumDisjoint.Seven, Conformance.EchoEnumDisjoint(EnumDisjoint.Seven));
+            Assert.Equal(EnumDisjoint.Two, Conformance.EchoEnumDisjoint(EnumDisjoint.Two));
+        }
+        
+        [Fact]
+        public void EchoEnumSingle()
+        {
+            Assert.Equal(EnumSingle.Single, Conformance.EchoEnumSingle(EnumSingle.Single));
+        }
+        
+        [Fact]
+        public void EchoSwitchPosition()
+        {
+            Assert.Equal(SwitchPosition.On, Conformance.EchoSwitchPosition(SwitchPosition.On));
+            Assert.Equal(SwitchPosition.Off, Conformance.EchoSwitchPosition(SwitchPosition.Off));
+        }
+        
+        [Fact]
+        public void EchoNumbers()
+        {
+            var value = new Numbers();
+            var result = Conformance.EchoNumbers(value);
+            Assert.Equal(value.Uint8Value, result.Uint8Value);
+            Assert.Equal(value.Int8Value, result.Int8Value);
+            Assert.Equal(value.Uint16Value, result.Uint16Value);
+            Assert.Equal(value.Int16Value, result.Int16Value);
+            Assert.Equal(value.Uint32Value, result.Uint32Value);
+            Assert.Equal(value.Int32Value, result.Int32Value);
+            Assert.Equal(value.Uint64Value, result.Uint64Value);
+            Assert.Equal(value.Int64Value, result.Int64Value);
+            Assert.Equal(value.FloatValue, result.FloatValue);
+            Assert.Equal(value.DoubleValue, result.DoubleValue);
+        }
+        
+        [Fact]
+        public void EchoUniversalInnerStruct()
+        {
+            var value = new UniversalInnerStruct();
+            var result = Conformance.EchoUniversalInnerStruct(value);
+            Assert.Equal(value.Value, result.Value);
+        }
+        
+        [Fact]
+        public void EchoUniversalOuterStruct()
+        {
+            var value = new UniversalOuterStruct();
+            var result = Conformance.EchoUniversalOuterStruct(value);
+            Assert.Equal(value.Inner.Value, result.Inner.Value);
+            Assert.Equal(value.Delay, result.Delay);
+        }
+        
+        [Fact]
+        public void RaiseConstraintError()
+        {
+            Conformance.RaiseConstraintError(0);
+            Assert.Equal(ConstraintError.InvalidArgument, Assert.Throws<ConstraintException>(() => Conformance.RaiseConstraintError(1)).error);
+        }
+        
+        [Fact]
+        public void RaiseMyError()
+        {
+            Conformance.RaiseMyError(0);
+            Assert.Equal(MyError.BadPassword, Assert.Throws<MyException>(() => Conformance.RaiseMyError(1)).error);
+            Assert.Equal(MyError.NullArgument, Assert.Throws<MyException>(() => Conformance.RaiseMyError(2)).error);
+        }
+        
+        [Fact]
+        public void RaiseMathIsBroken()
+        {
+            Conformance.RaiseMathIsBroken(0);
+            Assert.Equal(MathIsBroken.MathIsBroke, Assert.Throws<BrokenMathException>(() => Conformance.RaiseMathIsBroken(1)).error);
+        }
+        
+        [Fact]
+        public void ConformanceStringList()
+        {
+            var list = new string[] { "", "hello", "gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80" };
+            Assert.Equal(3u, Conformance.StringListSize(list));
+            Assert.Equal("", Conformance.StringListGet(list, 0));
+            Assert.Equal("hello", Conformance.StringListGet(list, 1));
+            Assert.Equal("gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80", Conformance.StringListGet(list, 2));
+        }
+        
+        [Fact]
+        public void ConformanceU32List()
+        {
+            var list = new uint[] { (uint)0, uint.MaxValue };
+            Assert.Equal(2u, Conformance.U32ListSize(list));
+            Assert.Equal((uint)0, Conformance.U32ListGet(list, 0));
+            Assert.Equal(uint.MaxValue, Conformance.U32ListGet(list, 1));
+        }
+        
+        [Fact]
+        public void EchoU32Iterator()
+        {
+            var list = new uint[] { (uint)0, uint.MaxValue };
+            var received = new List<uint>();
+            Conformance.EchoU32Iterator(list, values => received.AddRange(values));
+            Assert.Equal(list, received);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+// Conformance tests of the foo Java bindings generated by oo-bindgen
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import io.stepfunc.foo.callbacks.*;
+import org.joou.*;
+import org.junit.jupiter.api.Test;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.joou.Unsigned.*;
+
+public class ConformanceTest
+{
+    @Test
+    public void EchoBool()
+    {
+        assertThat(Conformance.echoBool(false)).isEqualTo(false);
+        assertThat(Conformance.echoBool(true)).isEqualTo(true);
+    }
+    
+    @Test
+    public void EchoU8()
+    {
+        assertThat(Conformance.echoU8(UByte.MIN)).isEqualTo(UByte.MIN);
+        assertThat(Conformance.echoU8(UByte.MAX)).isEqualTo(UByte.MAX);
+    }
+    
+    @Test
+    public void EchoS8()
+    {
+        assertThat(Conformance.echoS8(Byte.MIN_VALUE)).isEqualTo(Byte.MIN_VALUE);
+        assertThat(Conformance.echoS8((byte) 0)).isEqualTo((byte) 0);
+        assertThat(Conformance.echoS8(Byte.MAX_VALUE)).isEqualTo(Byte.MAX_VALUE);
+    }
+    
+    @Test
+    public void EchoU16()
+    {
+        assertThat(Conformance.echoU16(UShort.MIN)).isEqualTo(UShort.MIN);
+        assertThat(Conformance.echoU16(UShort.MAX)).isEqualTo(UShort.MAX);
+    }
+    
+    @Test
+    public void EchoS16()
+    {
+        assertThat(Conformance.echoS16(Short.MIN_VALUE)).isEqualTo(Short.MIN_VALUE);
+        assertThat(Conformance.echoS16((short) 0)).isEqualTo((short) 0);
+        assertThat(Conformance.echoS16(Short.MAX_VALUE)).isEqualTo(Short.MAX_VALUE);
+    }
+    
+    @Test
+    public void EchoU32()
+    {
+        assertThat(Conformance.echoU32(UInteger.MIN)).isEqualTo(UInteger.MIN);
+        assertThat(Conformance.echoU32(UInteger.MAX)).isEqualTo(UInteger.MAX);
+    }
+    
+    @Test
+    public void EchoS32()
+    {
+        assertThat(Conformance.echoS32(Integer.MIN_VALUE)).isEqualTo(Integer.MIN_VALUE);
+        assertThat(Conformance.echoS32(0)).isEqualTo(0);
+        assertThat(Conformance.echoS32(Integer.MAX_VALUE)).isEqualTo(Integer.MAX_VALUE);
+    }
+    
+    @Test
+    public void EchoU64()
+    {
+        assertThat(Conformance.echoU64(ULong.MIN)).isEqualTo(ULong.MIN);
+        assertThat(Conformance.echoU64(ULong.MAX)).isEqualTo(ULong.MAX);
+    }
+    
+    @Test
+    public void EchoS64()
+    {
+        assertThat(Conformance.echoS64(Long.MIN_VALUE)).isEqualTo(Long.MIN_VALUE);
+        assertThat(Conformance.echoS64(0L)).isEqualTo(0L);
+        assertThat(Conformance.echoS64(Long.MAX_VALUE)).isEqualTo(Long.MAX_VALUE);
+    }
+    
+    @Test
+    public void EchoFloat()
+    {
+        assertThat(Conformance.echoFloat(-Float.MAX_VALUE)).isEqualTo(-Float.MAX_VALUE);
+        assertThat(Conformance.echoFloat(0.0f)).isEqualTo(0.0f);
+        assertThat(Conformance.echoFloat(Float.MAX_VALUE)).isEqualTo(Float.MAX_VALUE);
+    }
+    
+    @Test
+    public void EchoDouble()
+    {
+        assertThat(Conformance.echoDouble(-Double.MAX_VALUE)).isEqualTo(-Double.MAX_VALUE);
+        assertThat(Conformance.echoDouble(0.0)).isEqualTo(0.0);
+        assertThat(Conformance.echoDouble(Double.MAX_VALUE)).isEqualTo(Double.MAX_VALUE);
+    }
+    
+    @Test
+    public void EchoDurationMs()
+    {
+        assertThat(Conformance.echoDurationMs(java.time.Duration.ofMillis(0L))).isEqualTo(java.time.Duration.ofMillis(0L));
+        assertThat(Conformance.echoDurationMs(java.time.Duration.ofMillis(1L))).isEqualTo(java.time.Duration.ofMillis(1L));
+        assertThat(Conformance.echoDurationMs(java.time.Duration.ofMillis(4000000000L))).isEqualTo(java.time.Duration.ofMillis(4000000000L));
+    }
+    
+    @Test
+    public void EchoDurationS()
+    {
+        assertThat(Conformance.echoDurationS(java.time.Duration.ofSeconds(0L))).isEqualTo(java.time.Duration.ofSeconds(0L));
+        assertThat(Conformance.echoDurationS(java.time.Duration.ofSeconds(1L))).isEqualTo(java.time.Duration.ofSeconds(1L));
+        assertThat(Conformance.echoDurationS(java.time.Duration.ofSeconds(4000000000L))).isEqualTo(java.time.Duration.ofSeconds(4000000000L));
+    }
+    
+    @Test
+    public void EchoString()
+    {
+        assertThat(Conformance.echoString("")).isEqualTo("");
+        assertThat(Conformance.echoString("hello")).isEqualTo("hello");
+        assertThat(Conformance.echoString("gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80")).isEqualTo("gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80");
+    }
+    
+    @Test
+    public void EchoStructureEnum()
+    {
+        assertThat(Conformance.echoStructureEnum(StructureEnum.VAR1)).isEqualTo(StructureEnum.VAR1);
+        assertThat(Conformance.echoStructureEnum(StructureEnum.VAR2)).isEqualTo(StructureEnum.VAR2);
+        assertThat(Conformance.echoStructureEnum(StructureEnum.VAR3)).isEqualTo(StructureEnum.VAR3);
+    }
+    
+    @Test
+    public void EchoSpeed()
+    {
+        assertThat(Conformance.echoSpeed(Speed.SLOW)).isEqualTo(Speed.SLOW);
+        assertThat(Conformance.echoSpeed(Speed.FAST)).isEqualTo(Speed.FAST);
+    }
+    
+    @Test
+    public void EchoEnumZeroToFive()
+    {
+        assertThat(Conformance.echoEnumZeroToFive(EnumZeroToFive.ZERO)).isEqualTo(EnumZeroToFive.ZERO);
+        assertThat(Conformance.echoEnumZeroToFive(EnumZeroToFive.ONE)).isEqualTo(EnumZeroToFive.ONE);
+        assertThat(Conformance.echoEnumZeroToFive(EnumZeroToFive.TWO)).isEqualTo(EnumZeroToFive.TWO);
+        assertThat(Conformance.echoEnumZeroToFive(EnumZeroToFive.THREE)).isEqualTo(EnumZeroToFive.THREE);
+        assertThat(Conformance.echoEnumZeroToFive(EnumZeroToFive.FOUR)).isEqualTo(EnumZeroToFive.FOUR);
+        assertThat(Conformance.echoEnumZeroToFive(EnumZeroToFive.FIVE)).isEqualTo(EnumZeroToFive.FIVE);
+    }
+    
+    @Test
+    public void EchoEnumOneToSix()
+    {
+        assertThat(Conformance.echoEnumOneToSix(EnumOneToSix.ONE)).isEqualTo(EnumOneToSix.ONE);
+        assertThat(Conformance.echoEnumOneToSix(EnumOneToSix.TWO)).isEqualTo(EnumOneToSix.TWO);
+        assertThat(Conformance.echoEnumOneToSix(EnumOneToSix.THREE)).isEqualTo(EnumOneToSix.THREE);
+        assertThat(Conformance.echoEnumOneToSix(EnumOneToSix.FOUR)).isEqualTo(EnumOneToSix.FOUR);
+        assertThat(Conformance.echoEnumOneToSix(EnumOneToSix.FIVE)).isEqualTo(EnumOneToSix.FIVE);
+        assertThat(Conformance.echoEnumOneToSix(EnumOneToSix.SIX)).isEqualTo(EnumOneToSix.SIX);
+    }
+    
+    @Test
+    public void EchoEnumDisjoint()
+    {
+        assertThat(Conformance.echoEnumDisjoint(EnumDisjoint.FIVE)).isEqualTo(EnumDisjoint.FIVE);
+        assertThat(Conformance.echoEnumDisjoint(EnumDisjoint.ONE)).isEqualTo(EnumDisjoint.ONE);
+        assertThat(Conformance.echoEnumDisjoint(EnumDisjoint.TWENTY)).isEqualTo(EnumDisjoint.TWENTY);
+        assertThat(Conformance.echoEnumDisjoint(EnumDisjoint.FOUR)).isEqualTo(EnumDisjoint.FOUR);
+        assertThat(Conformance.echoEnumDisjoint(EnumDisjoint.SEVEN)).isEqualTo(EnumDisjoint.SEVEN);
+        assertThat(Conformance.echoEnumDisjoint(EnumDisjoint.TWO)).isEqualTo(EnumDisjoint.TWO);
+    }
+    
+    @Test
+    public void EchoEnumSingle()
+    {
+        assertThat(Conformance.echoEnumSingle(EnumSingle.SINGLE)).isEqualTo(EnumSingle.SINGLE);
+    }
+    
+    @Test
+    public void EchoSwitchPosition()
+    {
+        assertThat(Conformance.echoSwitchPosition(SwitchPosition.ON)).isEqualTo(SwitchPosition.ON);
+        assertThat(Conformance.echoSwitchPosition(SwitchPosition.OFF)).isEqualTo(SwitchPosition.OFF);
+    }
+    
+    @Test
+    public void EchoNumbers()
+    {
+        Numbers value = new Numbers();
+        Numbers result = Conformance.echoNumbers(value);
+        assertThat(result.uint8Value).isEqualTo(value.uint8Value);
+        assertThat(result.int8Value).isEqualTo(value.int8Value);
+        assertThat(result.uint16Value).isEqualTo(value.uint16Value);
+        assertThat(result.int16Value).isEqualTo(value.int16Value);
+        assertThat(result.uint32Value).isEqualTo(value.uint32Value);
+        assertThat(result.int32Value).isEqualTo(value.int32Value);
+        assertThat(result.uint64Value).isEqualTo(value.uint64Value);
+        assertThat(result.int64Value).isEqualTo(value.int64Value);
+        assertThat(result.floatValue).isEqualTo(value.floatValue);
+        assertThat(result.doubleValue).isEqualTo(value.doubleValue);
+    }
+    
+    @Test
+    public void EchoUniversalInnerStruct()
+    {
+        UniversalInnerStruct value = new UniversalInnerStruct();
+        UniversalInnerStruct result = Conformance.echoUniversalInnerStruct(value);
+        assertThat(result.value).isEqualTo(value.value);
+    }
+    
+    @Test
+    public void EchoUniversalOuterStruct()
+    {
+        UniversalOuterStruct value = new UniversalOuterStruct();
+        UniversalOuterStruct result = Conformance.echoUniversalOuterStruct(value);
+        assertThat(result.inner.value).isEqualTo(value.inner.value);
+        assertThat(result.delay).isEqualTo(value.delay);
+    }
+    
+    @Test
+    public void RaiseConstraintError() throws Exception
+    {
+        Conformance.raiseConstraintError(uint(0));
+        assertThatThrownBy(() -> Conformance.raiseConstraintError(uint(1)))
+            .isInstanceOf(ConstraintException.class)
+            .hasFieldOrPropertyWithValue("error", ConstraintError.INVALID_ARGUMENT);
+    }
+    
+    @Test
+    public void RaiseMyError() throws Exception
+    {
+        Conformance.raiseMyError(uint(0));
+        assertThatThrownBy(() -> Conformance.raiseMyError(uint(1)))
+            .isInstanceOf(MyException.class)
+            .hasFieldOrPropertyWithValue("error", MyError.BAD_PASSWORD);
+        assertThatThrownBy(() -> Conformance.raiseMyError(uint(2)))
+            .isInstanceOf(MyException.class)
+            .hasFieldOrPropertyWithValue("error", MyError.NULL_ARGUMENT);
+    }
+    
+    @Test
+    public void RaiseMathIsBroken() throws Exception
+    {
+        Conformance.raiseMathIsBroken(uint(0));
+        assertThatThrownBy(() -> Conformance.raiseMathIsBroken(uint(1)))
+            .isInstanceOf(BrokenMathException.class)
+            .hasFieldOrPropertyWithValue("error", MathIsBroken.MATH_IS_BROKE);
+    }
+    
+    @Test
+    public void ConformanceStringList()
+    {
+        java.util.List<String> list = java.util.Arrays.asList("", "hello", "gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80");
+        assertThat(Conformance.stringListSize(list)).isEqualTo(uint(3));
+        assertThat(Conformance.stringListGet(list, uint(0))).isEqualTo("");
+        assertThat(Conformance.stringListGet(list, uint(1))).isEqualTo("hello");
+        assertThat(Conformance.stringListGet(list, uint(2))).isEqualTo("gr\u00fc\u00dfe, \u4e16\u754c \ud83e\udd80");
+    }
+    
+    @Test
+    public void ConformanceU32List()
+    {
+        java.util.List<UInteger> list = java.util.Arrays.asList(UInteger.MIN, UInteger.MAX);
+        assertThat(Conformance.u32ListSize(list)).isEqualTo(uint(2));
+        assertThat(Conformance.u32ListGet(list, uint(0))).isEqualTo(UInteger.MIN);
+        assertThat(Conformance.u32ListGet(list, uint(1))).isEqualTo(UInteger.MAX);
+    }
+    
+    @Test
+    public void EchoU32Iterator()
+    {
+        java.util.List<UInteger> list = java.util.Arrays.asList(UInteger.MIN, UInteger.MAX);
+        java.util.List<UInteger> received = new java.util.ArrayList<>();
+        Conformance.echoU32Iterator(list, values -> received.addAll(values));
+        assertThat(received).isEqualTo(list);
+    }
+}
@@ -1,6 +1,7 @@
 using System;
 using Xunit;
 using foo;
+using foo.callbacks;
 
 namespace foo.Tests
 {
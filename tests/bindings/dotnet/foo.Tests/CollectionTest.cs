@@ -35,5 +35,14 @@ namespace foo.Tests
             Assert.Equal("World!", StringCollectionTestMethods.GetValueWithReserve(strings, 1));
             Assert.Equal("Émile", StringCollectionTestMethods.GetValueWithReserve(strings, 2));
         }
+
+        [Fact]
+        public void PrimitiveCollectionTest()
+        {
+            var values = new uint[] { 1, 2, uint.MaxValue };
+
+            Assert.Equal(3ul + uint.MaxValue, U32CollectionTestMethods.GetSum(values));
+            Assert.Equal(0ul, U32CollectionTestMethods.GetSum(new uint[0]));
+        }
     }
 }
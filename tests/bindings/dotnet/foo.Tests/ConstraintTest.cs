@@ -0,0 +1,58 @@
+using System;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    public class ConstraintTest
+    {
+        [Fact]
+        public void RangeViolationThrows()
+        {
+            Assert.Throws<ArgumentOutOfRangeException>(() => ConstraintFunctions.Scale(200, 1));
+            Assert.Throws<ArgumentOutOfRangeException>(() => ConstraintFunctions.Scale(-50, 0));
+        }
+
+        [Fact]
+        public void ValueInRangeIsAccepted()
+        {
+            Assert.Equal(-100, ConstraintFunctions.Scale(-50, 2));
+        }
+
+        [Fact]
+        public void StringViolationsThrow()
+        {
+            Assert.Throws<ArgumentException>(() => ConstraintFunctions.CheckIdentifier(""));
+            Assert.Throws<ArgumentException>(() => ConstraintFunctions.CheckIdentifier("this_identifier_is_too_long"));
+            Assert.Throws<ArgumentException>(() => ConstraintFunctions.CheckIdentifier("9lives"));
+            Assert.Throws<ArgumentException>(() => ConstraintFunctions.CheckIdentifier("trailing\n"));
+        }
+
+        [Fact]
+        public void ValidIdentifierIsAccepted()
+        {
+            ConstraintFunctions.CheckIdentifier("my_identifier");
+        }
+
+        [Fact]
+        public void StructFieldViolationsThrow()
+        {
+            Assert.Throws<ArgumentOutOfRangeException>(() => ConstraintFunctions.EndpointPort(new Endpoint("localhost", 0)));
+            Assert.Throws<ArgumentException>(() => ConstraintFunctions.EndpointPort(new Endpoint("", 20000)));
+
+            var endpoint = new Endpoint("localhost", 20000);
+            endpoint.Timeout = TimeSpan.Zero;
+            Assert.Throws<ArgumentException>(() => ConstraintFunctions.EndpointPort(endpoint));
+
+            // rounds down to zero milliseconds
+            endpoint.Timeout = TimeSpan.FromTicks(5);
+            Assert.Throws<ArgumentException>(() => ConstraintFunctions.EndpointPort(endpoint));
+        }
+
+        [Fact]
+        public void ValidStructIsAccepted()
+        {
+            Assert.Equal(20000, ConstraintFunctions.EndpointPort(new Endpoint("localhost", 20000)));
+        }
+    }
+}
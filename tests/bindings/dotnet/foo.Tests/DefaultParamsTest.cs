@@ -0,0 +1,50 @@
+using System;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    public class DefaultParamsTest
+    {
+        [Fact]
+        public void OptionalParametersUseDefaultValues()
+        {
+            Assert.Equal(15, DefaultParamFunctions.AddOffset(5));
+            Assert.Equal(6, DefaultParamFunctions.AddOffset(5, 1));
+            Assert.Equal(18, DefaultParamFunctions.AddOffset(5, 1, 3));
+            Assert.Equal(50, DefaultParamFunctions.AddOffset(0, multiplier: 5));
+        }
+
+        [Fact]
+        public void DurationDefault()
+        {
+            Assert.Equal(1500u, DefaultParamFunctions.DurationToMillis());
+            Assert.Equal(2000u, DefaultParamFunctions.DurationToMillis(TimeSpan.FromSeconds(2)));
+        }
+
+        [Fact]
+        public void StringDefault()
+        {
+            Assert.Equal(5u, DefaultParamFunctions.StringByteLength());
+            Assert.Equal(2u, DefaultParamFunctions.StringByteLength("hi"));
+        }
+
+        [Fact]
+        public void EnumDefault()
+        {
+            Assert.Equal(1u, DefaultParamFunctions.SpeedValue());
+            Assert.Equal(0u, DefaultParamFunctions.SpeedValue(Speed.Slow));
+        }
+
+        [Fact]
+        public void ClassDefaults()
+        {
+            var counter = new Counter();
+            Assert.Equal(1u, counter.Increment());
+            Assert.Equal(6u, counter.Increment(5));
+
+            var other = new Counter(10);
+            Assert.Equal(11u, other.Increment());
+        }
+    }
+}
@@ -0,0 +1,27 @@
+using System;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    public class NamingTest
+    {
+        [Fact]
+        public void ReservedNamesAreEscaped()
+        {
+            var registry = new URLRegistry(@default: 42);
+            registry.Add(1, 7);
+
+            var entry = registry.Get(1);
+            Assert.Equal(7u, entry.Value);
+            Assert.False(entry.Default);
+
+            Assert.True(registry.Delete(1));
+            Assert.False(registry.Delete(1));
+
+            entry = registry.Get(1);
+            Assert.Equal(42u, entry.Value);
+            Assert.True(entry.Default);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+using System;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    public class OverloadTest
+    {
+        [Fact]
+        public void MethodOverloads()
+        {
+            var accumulator = new Accumulator();
+            accumulator.Add((byte)1);
+            accumulator.Add(100000u);
+            accumulator.Add("four");
+            accumulator.Add(3u, 5u);
+            Assert.Equal(100020u, accumulator.Total());
+        }
+
+        [Fact]
+        public void StaticMethodOverloads()
+        {
+            Assert.Equal(1u, OverloadFunctions.Describe(true));
+            Assert.Equal(2u, OverloadFunctions.Describe(1.5));
+        }
+    }
+}
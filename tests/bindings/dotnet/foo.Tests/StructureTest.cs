@@ -57,5 +57,53 @@ namespace foo.Tests
             Assert.True(x.BooleanFalse);
             Assert.False(x.BooleanTrue);
         }
+
+        [Fact]
+        public void NumbersValueSemanticsTest()
+        {
+            var x = new Numbers();
+            var y = new Numbers();
+
+            Assert.Equal(x, y);
+            Assert.True(x == y);
+            Assert.Equal(x.GetHashCode(), y.GetHashCode());
+            Assert.Equal(x.ToString(), y.ToString());
+
+            var z = y.WithUint8Value(2);
+            Assert.Equal(x, y);
+            Assert.NotEqual(z, y);
+            Assert.True(z != y);
+            Assert.Contains("Uint8Value = 2", z.ToString());
+        }
+
+        [Fact]
+        public void LabeledNumbersCopyMethodsTest()
+        {
+            var x = new LabeledNumbers();
+            var y = x.WithLabel("copy").WithNumbers(x.Numbers.WithInt32Value(42));
+
+            Assert.Equal("default", x.Label);
+            CheckNumbersDefaults(x.Numbers);
+            Assert.Equal("copy", y.Label);
+            Assert.Equal(42, y.Numbers.Int32Value);
+            CheckNumbersDefaults(y.Numbers.WithInt32Value(-3));
+        }
+
+        [Fact]
+        public void NestedValueSemanticsTest()
+        {
+            var x = new UniversalOuterStruct();
+            var y = new UniversalOuterStruct();
+
+            Assert.Equal(x, y);
+            Assert.Equal(x.GetHashCode(), y.GetHashCode());
+
+            y.Inner.Value = 7;
+            Assert.NotEqual(x, y);
+
+            y.Inner.Value = x.Inner.Value;
+            y.Delay = TimeSpan.FromSeconds(6);
+            Assert.NotEqual(x, y);
+        }
     }
 }
@@ -0,0 +1,16 @@
+// Documentation examples of the foo C bindings extracted by oo-bindgen
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "foo.h"
+
+void example_string_class()
+{
+    foo_string_class_t* instance = foo_string_class_create();
+    printf("%s\n", foo_string_class_echo(instance, "hello"));
+    foo_string_class_destroy(instance);
+}
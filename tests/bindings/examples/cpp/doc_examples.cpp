@@ -0,0 +1,15 @@
+// Documentation examples of the foo C++ bindings extracted by oo-bindgen
+
+#include <chrono>
+#include <iostream>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "foo.hpp"
+
+void example_string_class()
+{
+    foo::StringClass instance;
+    std::cout << instance.echo("hello") << std::endl;
+}
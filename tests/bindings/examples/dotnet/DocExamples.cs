@@ -0,0 +1,19 @@
+// Documentation examples of the foo .NET bindings extracted by oo-bindgen
+using System;
+using System.Collections.Generic;
+using foo;
+using foo.callbacks;
+
+namespace foo.Tests
+{
+    public static class DocExamples
+    {
+        public static void ExampleStringClass()
+        {
+            using (var instance = new StringClass())
+            {
+                Console.WriteLine(instance.Echo("hello"));
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// Documentation examples of the foo Java bindings extracted by oo-bindgen
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import io.stepfunc.foo.callbacks.*;
+import org.joou.*;
+
+import static org.joou.Unsigned.*;
+
+class DocExamples
+{
+    static void exampleStringClass() throws Exception
+    {
+        try (StringClass instance = new StringClass()) {
+            System.out.println(instance.echo("hello"));
+        }
+    }
+}
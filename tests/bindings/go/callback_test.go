@@ -0,0 +1,48 @@
+package footests
+
+import (
+	"testing"
+	"time"
+
+	"foo"
+)
+
+type callbackImpl struct {
+	lastValue    uint32
+	lastDuration time.Duration
+}
+
+func (impl *callbackImpl) OnValue(value uint32) uint32 {
+	impl.lastValue = value
+	return value
+}
+
+func (impl *callbackImpl) OnDuration(value time.Duration) time.Duration {
+	impl.lastDuration = value
+	return value
+}
+
+func TestCallback(t *testing.T) {
+	source := foo.NewCallbackSource()
+	defer source.Close()
+
+	callback := &callbackImpl{}
+	source.SetInterface(callback)
+
+	if callback.lastValue != 0 {
+		t.Fatalf("expected no value, got %d", callback.lastValue)
+	}
+	if value := source.SetValue(76); value != 76 {
+		t.Fatalf("expected 76, got %d", value)
+	}
+	if callback.lastValue != 76 {
+		t.Fatalf("expected 76, got %d", callback.lastValue)
+	}
+
+	if value := source.SetDuration(76 * time.Second); value != 76*time.Second {
+		t.Fatalf("expected 76s, got %v", value)
+	}
+	if callback.lastDuration != 76*time.Second {
+		t.Fatalf("expected 76s, got %v", callback.lastDuration)
+	}
+}
@@ -0,0 +1,47 @@
+package footests
+
+import (
+	"testing"
+
+	"foo"
+)
+
+func TestConstructionDestruction(t *testing.T) {
+	if count := foo.TestClassConstructionCounter(); count != 0 {
+		t.Fatalf("expected no instance, got %d", count)
+	}
+
+	testClass := foo.NewTestClass(41)
+	if count := foo.TestClassConstructionCounter(); count != 1 {
+		t.Fatalf("expected one instance, got %d", count)
+	}
+	if value := testClass.GetValue(); value != 41 {
+		t.Fatalf("expected 41, got %d", value)
+	}
+
+	testClass.IncrementValue()
+	if value := testClass.GetValue(); value != 42 {
+		t.Fatalf("expected 42, got %d", value)
+	}
+
+	testClass.Shutdown()
+	if count := foo.TestClassConstructionCounter(); count != 0 {
+		t.Fatalf("expected no instance, got %d", count)
+	}
+}
+
+func TestAsyncMethod(t *testing.T) {
+	testClass := foo.NewTestClass(41)
+	defer testClass.Shutdown()
+
+	first := <-testClass.AddAsync(1)
+	if first.Err != nil || first.Value != 42 {
+		t.Fatalf("expected 42, got %v", first)
+	}
+
+	testClass.IncrementValue()
+	second := <-testClass.AddAsync(1)
+	if second.Err != nil || second.Value != 43 {
+		t.Fatalf("expected 43, got %v", second)
+	}
+}
@@ -0,0 +1,40 @@
+package footests
+
+import (
+	"testing"
+
+	"foo"
+)
+
+var collectionValues = []string{"Hello", "World!", "Émile"}
+
+func TestStringCollection(t *testing.T) {
+	if size := foo.StringCollectionTestMethodsGetSize(collectionValues); size != 3 {
+		t.Fatalf("expected 3, got %d", size)
+	}
+	for i, expected := range collectionValues {
+		if value := foo.StringCollectionTestMethodsGetValue(collectionValues, uint32(i)); value != expected {
+			t.Fatalf("expected %q, got %q", expected, value)
+		}
+	}
+}
+
+func TestStringCollectionWithReserve(t *testing.T) {
+	if size := foo.StringCollectionTestMethodsGetSizeWithReserve(collectionValues); size != 3 {
+		t.Fatalf("expected 3, got %d", size)
+	}
+	for i, expected := range collectionValues {
+		if value := foo.StringCollectionTestMethodsGetValueWithReserve(collectionValues, uint32(i)); value != expected {
+			t.Fatalf("expected %q, got %q", expected, value)
+		}
+	}
+}
+
+func TestPrimitiveCollection(t *testing.T) {
+	if sum := foo.U32CollectionTestMethodsGetSum([]uint32{1, 2, ^uint32(0)}); sum != 3+uint64(^uint32(0)) {
+		t.Fatalf("expected %d, got %d", 3+uint64(^uint32(0)), sum)
+	}
+	if sum := foo.U32CollectionTestMethodsGetSum(nil); sum != 0 {
+		t.Fatalf("expected 0, got %d", sum)
+	}
+}
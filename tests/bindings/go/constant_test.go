@@ -0,0 +1,16 @@
+package footests
+
+import (
+	"testing"
+
+	"foo"
+)
+
+func TestSpecialValues(t *testing.T) {
+	if foo.SpecialValuesOne != 0x01 {
+		t.Fatalf("expected 0x01, got %#x", foo.SpecialValuesOne)
+	}
+	if foo.SpecialValuesTwo != 0x02 {
+		t.Fatalf("expected 0x02, got %#x", foo.SpecialValuesTwo)
+	}
+}
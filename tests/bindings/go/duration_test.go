@@ -0,0 +1,30 @@
+package footests
+
+import (
+	"testing"
+	"time"
+
+	"foo"
+)
+
+func TestDurationEcho(t *testing.T) {
+	cases := []struct {
+		value   time.Duration
+		seconds time.Duration
+	}{
+		{0, 0},
+		{5 * time.Second, 5 * time.Second},
+		// seconds are truncated
+		{250 * time.Millisecond, 0},
+		{41 * 24 * time.Hour, 41 * 24 * time.Hour},
+	}
+
+	for _, c := range cases {
+		if echo := foo.DurationEchoFunctionsMillisecondsEcho(c.value); echo != c.value {
+			t.Fatalf("expected %v, got %v", c.value, echo)
+		}
+		if echo := foo.DurationEchoFunctionsSecondsEcho(c.value); echo != c.seconds {
+			t.Fatalf("expected %v, got %v", c.seconds, echo)
+		}
+	}
+}
@@ -0,0 +1,59 @@
+package footests
+
+import (
+	"testing"
+
+	"foo"
+)
+
+func TestEnumZeroToFiveEcho(t *testing.T) {
+	for _, value := range []foo.EnumZeroToFive{
+		foo.EnumZeroToFiveZero,
+		foo.EnumZeroToFiveOne,
+		foo.EnumZeroToFiveTwo,
+		foo.EnumZeroToFiveThree,
+		foo.EnumZeroToFiveFour,
+		foo.EnumZeroToFiveFive,
+	} {
+		if result := foo.EnumEchoFunctionsEnumZeroToFiveEcho(value); result != value {
+			t.Fatalf("expected %v, got %v", value, result)
+		}
+	}
+}
+
+func TestEnumOneToSixEcho(t *testing.T) {
+	for _, value := range []foo.EnumOneToSix{
+		foo.EnumOneToSixOne,
+		foo.EnumOneToSixTwo,
+		foo.EnumOneToSixThree,
+		foo.EnumOneToSixFour,
+		foo.EnumOneToSixFive,
+		foo.EnumOneToSixSix,
+	} {
+		if result := foo.EnumEchoFunctionsEnumOneToSixEcho(value); result != value {
+			t.Fatalf("expected %v, got %v", value, result)
+		}
+	}
+}
+
+func TestEnumDisjointEcho(t *testing.T) {
+	for _, value := range []foo.EnumDisjoint{
+		foo.EnumDisjointFive,
+		foo.EnumDisjointOne,
+		foo.EnumDisjointTwenty,
+		foo.EnumDisjointFour,
+		foo.EnumDisjointSeven,
+		foo.EnumDisjointTwo,
+	} {
+		if result := foo.EnumEchoFunctionsEnumDisjointEcho(value); result != value {
+			t.Fatalf("expected %v, got %v", value, result)
+		}
+	}
+}
+
+func TestEnumSingleEcho(t *testing.T) {
+	value := foo.EnumSingleSingle
+	if result := foo.EnumEchoFunctionsEnumSingleEcho(value); result != value {
+		t.Fatalf("expected %v, got %v", value, result)
+	}
+}
@@ -0,0 +1,25 @@
+package footests
+
+import (
+	"errors"
+	"testing"
+
+	"foo"
+)
+
+func TestErrorOnBadPassword(t *testing.T) {
+	_, err := foo.ClassWithPasswordGetSpecialValue("hi!")
+	if !errors.Is(err, foo.MyErrorBadPassword) {
+		t.Fatalf("expected a bad password error, got %v", err)
+	}
+}
+
+func TestValueOnGoodPassword(t *testing.T) {
+	value, err := foo.ClassWithPasswordGetSpecialValue("12345")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if value != 42 {
+		t.Fatalf("expected 42, got %d", value)
+	}
+}
@@ -0,0 +1,7 @@
+module footests
+
+go 1.18
+
+require foo v0.0.0
+
+replace foo => ./generated
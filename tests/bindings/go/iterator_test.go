@@ -0,0 +1,67 @@
+package footests
+
+import (
+	"reflect"
+	"testing"
+
+	"foo"
+)
+
+type valuesReceiver struct {
+	values []uint8
+}
+
+func (receiver *valuesReceiver) OnCharacters(values []foo.StringIteratorItem) {
+	for _, item := range values {
+		receiver.values = append(receiver.values, item.Value)
+	}
+}
+
+type chunkReceiver struct {
+	values []string
+}
+
+func (receiver *chunkReceiver) OnChunk(values []foo.Chunk) {
+	for _, chunk := range values {
+		var chars []byte
+		for _, value := range chunk.Iter {
+			chars = append(chars, value.Value)
+		}
+		receiver.values = append(receiver.values, string(chars))
+	}
+}
+
+type rangeReceiver struct {
+	values []uint32
+}
+
+func (receiver *rangeReceiver) OnRange(values []uint32) {
+	receiver.values = append(receiver.values, values...)
+}
+
+func TestStringIterator(t *testing.T) {
+	receiver := &valuesReceiver{}
+	foo.IteratorTestHelperInvokeCallback("ABCDE", receiver)
+
+	if expected := []uint8{65, 66, 67, 68, 69}; !reflect.DeepEqual(receiver.values, expected) {
+		t.Fatalf("expected %v, got %v", expected, receiver.values)
+	}
+}
+
+func TestChunkIterator(t *testing.T) {
+	receiver := &chunkReceiver{}
+	foo.DoubleIteratorTestHelperIterateStringByChunks("Hello World!", 3, receiver)
+
+	if expected := []string{"Hel", "lo ", "Wor", "ld!"}; !reflect.DeepEqual(receiver.values, expected) {
+		t.Fatalf("expected %q, got %q", expected, receiver.values)
+	}
+}
+
+func TestPrimitiveIterator(t *testing.T) {
+	receiver := &rangeReceiver{}
+	foo.RangeIteratorTestHelperInvokeRangeCallback(1, 3, receiver)
+
+	if expected := []uint32{1, 2, 3}; !reflect.DeepEqual(receiver.values, expected) {
+		t.Fatalf("expected %v, got %v", expected, receiver.values)
+	}
+}
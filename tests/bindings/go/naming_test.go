@@ -0,0 +1,30 @@
+package footests
+
+import (
+	"testing"
+
+	"foo"
+)
+
+func TestReservedNamesAreEscaped(t *testing.T) {
+	registry := foo.NewURLRegistry(42)
+	defer registry.Close()
+	registry.Insert(1, 7)
+
+	entry := registry.Get(1)
+	if entry.Value != 7 || entry.Default {
+		t.Fatalf("expected the registered value, got %v", entry)
+	}
+
+	if !registry.Delete(1) {
+		t.Fatal("expected the value to be removed")
+	}
+	if registry.Delete(1) {
+		t.Fatal("expected no value to remove")
+	}
+
+	entry = registry.Get(1)
+	if entry.Value != 42 || !entry.Default {
+		t.Fatalf("expected the default value, got %v", entry)
+	}
+}
@@ -0,0 +1,29 @@
+package footests
+
+import (
+	"testing"
+
+	"foo"
+)
+
+const (
+	english = "I like to be home with a cup of tea"
+	french  = "Je suis parfaitement bilingue"
+)
+
+func TestEcho(t *testing.T) {
+	stringClass := foo.NewStringClass()
+	defer stringClass.Close()
+
+	for _, value := range []string{english, french} {
+		if echo := stringClass.Echo(value); echo != value {
+			t.Fatalf("expected %q, got %q", value, echo)
+		}
+	}
+}
+
+func TestLength(t *testing.T) {
+	if length := foo.StringClassGetLength(english); length != uint32(len(english)) {
+		t.Fatalf("expected %d, got %d", len(english), length)
+	}
+}
@@ -0,0 +1,98 @@
+package footests
+
+import (
+	"testing"
+	"time"
+
+	"foo"
+)
+
+type emptyInterfaceImpl struct{}
+
+func checkNumbersDefaults(t *testing.T, x foo.Numbers) {
+	t.Helper()
+	expected := foo.Numbers{
+		Uint8Value:  1,
+		Int8Value:   -1,
+		Uint16Value: 2,
+		Int16Value:  -2,
+		Uint32Value: 3,
+		Int32Value:  -3,
+		Uint64Value: 4,
+		Int64Value:  -4,
+		FloatValue:  12.34,
+		DoubleValue: -56.78,
+	}
+	if x != expected {
+		t.Fatalf("expected %+v, got %+v", expected, x)
+	}
+}
+
+func TestStructureConstructor(t *testing.T) {
+	x := foo.NewStructure(foo.NewInnerStructure(&emptyInterfaceImpl{}))
+
+	if !x.BooleanTrue || x.BooleanFalse {
+		t.Fatalf("expected true and false, got %v and %v", x.BooleanTrue, x.BooleanFalse)
+	}
+	if x.EnumVar1 != foo.StructureEnumVar1 || x.EnumVar2 != foo.StructureEnumVar2 {
+		t.Fatalf("expected var1 and var2, got %v and %v", x.EnumVar1, x.EnumVar2)
+	}
+	if x.DurationMillis != 4200*time.Millisecond {
+		t.Fatalf("expected 4.2s, got %v", x.DurationMillis)
+	}
+	if x.DurationSeconds != 76*time.Second {
+		t.Fatalf("expected 76s, got %v", x.DurationSeconds)
+	}
+	if x.StringHello != "Hello" {
+		t.Fatalf("expected %q, got %q", "Hello", x.StringHello)
+	}
+	if x.InnerStructure.InterfaceField == nil {
+		t.Fatal("expected an interface")
+	}
+	checkNumbersDefaults(t, x.InnerStructure.NumbersField)
+}
+
+func TestNumbersValueSemantics(t *testing.T) {
+	x := foo.NewNumbers()
+	y := foo.NewNumbers()
+	if x != y {
+		t.Fatalf("expected %+v, got %+v", x, y)
+	}
+
+	y.Uint8Value = 2
+	if x == y {
+		t.Fatal("expected different values")
+	}
+	checkNumbersDefaults(t, x)
+}
+
+func TestNumbersRoundTrip(t *testing.T) {
+	x := foo.NewNumbers()
+	x.Uint64Value = ^uint64(0)
+	x.Int8Value = -128
+	if echo := foo.ConformanceEchoNumbers(x); echo != x {
+		t.Fatalf("expected %+v, got %+v", x, echo)
+	}
+}
+
+func TestNestedValueSemantics(t *testing.T) {
+	x := foo.NewUniversalOuterStruct()
+	y := foo.NewUniversalOuterStruct()
+	if x != y {
+		t.Fatalf("expected %+v, got %+v", x, y)
+	}
+
+	y.Inner.Value = 7
+	if x == y {
+		t.Fatal("expected different inner values")
+	}
+	if echo := foo.ConformanceEchoUniversalOuterStruct(y); echo != y {
+		t.Fatalf("expected %+v, got %+v", y, echo)
+	}
+
+	y.Inner.Value = x.Inner.Value
+	y.Delay = 6 * time.Second
+	if x == y {
+		t.Fatal("expected different delays")
+	}
+}
@@ -0,0 +1,53 @@
+package footests
+
+import (
+	"errors"
+	"reflect"
+	"testing"
+
+	"foo"
+)
+
+type valueChangeListener struct {
+	values []uint32
+}
+
+func (listener *valueChangeListener) OnValueChange(value uint32) {
+	listener.values = append(listener.values, value)
+}
+
+type doubleOperation struct{}
+
+func (doubleOperation) Execute(value uint32) uint32 {
+	return 2 * value
+}
+
+func TestAsynchronousCallbacks(t *testing.T) {
+	listener := &valueChangeListener{}
+	threadClass := foo.NewThreadClass(42, listener)
+
+	result := <-threadClass.Add(4)
+	if result.Err != nil || result.Value != 46 {
+		t.Fatalf("expected 46, got %v", result)
+	}
+	threadClass.Update(43)
+	threadClass.Execute(doubleOperation{})
+
+	// explicitly shutdown the thread so that we can test post conditions
+	threadClass.Shutdown()
+
+	if expected := []uint32{46, 43, 86}; !reflect.DeepEqual(listener.values, expected) {
+		t.Fatalf("expected %v, got %v", expected, listener.values)
+	}
+}
+
+func TestAsynchronousErrors(t *testing.T) {
+	threadClass := foo.NewThreadClass(42, &valueChangeListener{})
+	defer threadClass.Shutdown()
+
+	threadClass.QueueError(foo.MathIsBrokenMathIsBroke)
+	result := <-threadClass.Add(4)
+	if !errors.Is(result.Err, foo.MathIsBrokenMathIsBroke) {
+		t.Fatalf("expected a math is broke error, got %v", result.Err)
+	}
+}
@@ -0,0 +1,33 @@
+package io.stepfunc.foo_test
+
+import io.stepfunc.foo.*
+import kotlinx.coroutines.runBlocking
+import org.joou.Unsigned.uint
+import org.junit.jupiter.api.Assertions.assertEquals
+import org.junit.jupiter.api.Assertions.assertThrows
+import org.junit.jupiter.api.Test
+
+class CoroutineTest {
+    @Test
+    fun awaitsTheValueOfTheFuture() {
+        runBlocking {
+            TestClass(uint(41)).use { testClass ->
+                assertEquals(uint(42), testClass.addAsyncAwait(uint(1)))
+                testClass.incrementValue()
+                assertEquals(uint(43), testClass.addAsyncAwait(uint(1)))
+            }
+        }
+    }
+
+    @Test
+    fun awaitThrowsTheErrorOfTheFuture() {
+        val listener: ValueChangeListenerFunction = {}
+        ThreadClass(uint(42), listener.toValueChangeListener()).use { threadClass ->
+            threadClass.queueError(MathIsBroken.MATH_IS_BROKE)
+            val ex = assertThrows(BrokenMathException::class.java) {
+                runBlocking { threadClass.addAwait(uint(4)) }
+            }
+            assertEquals(MathIsBrokenFailure.MathIsBroke, ex.failure)
+        }
+    }
+}
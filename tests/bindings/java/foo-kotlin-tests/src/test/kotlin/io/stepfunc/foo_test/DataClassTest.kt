@@ -0,0 +1,35 @@
+package io.stepfunc.foo_test
+
+import io.stepfunc.foo.*
+import org.junit.jupiter.api.Assertions.assertEquals
+import org.junit.jupiter.api.Test
+import java.time.Duration
+
+class DataClassTest {
+    @Test
+    fun mirrorsTheValuesOfTheStruct() {
+        val numbers = Numbers().toData()
+
+        assertEquals(1.toUByte(), numbers.uint8Value)
+        assertEquals((-1).toByte(), numbers.int8Value)
+        assertEquals(3u, numbers.uint32Value)
+        assertEquals(4uL, numbers.uint64Value)
+        assertEquals(-56.78, numbers.doubleValue)
+    }
+
+    @Test
+    fun copiesRoundTripThroughTheLibrary() {
+        val numbers = Numbers().toData().copy(uint8Value = UByte.MAX_VALUE, uint64Value = ULong.MAX_VALUE)
+
+        assertEquals(numbers, Conformance.echoNumbers(numbers.toJava()).toData())
+    }
+
+    @Test
+    fun nestedStructsAreDataClasses() {
+        val outer = UniversalOuterStruct().toData()
+
+        assertEquals(UniversalInnerStruct().toData(), outer.inner)
+        assertEquals(Duration.ofMillis(5000), outer.delay)
+        assertEquals(outer, outer.toJava().toData())
+    }
+}
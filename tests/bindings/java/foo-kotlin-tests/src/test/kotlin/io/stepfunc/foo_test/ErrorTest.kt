@@ -0,0 +1,28 @@
+package io.stepfunc.foo_test
+
+import io.stepfunc.foo.*
+import org.junit.jupiter.api.Assertions.assertEquals
+import org.junit.jupiter.api.Assertions.assertThrows
+import org.junit.jupiter.api.Test
+
+class ErrorTest {
+    @Test
+    fun exceptionsMapToSealedFailures() {
+        val ex = assertThrows(MyException::class.java) {
+            ClassWithPassword.getSpecialValue("hi!")
+        }
+
+        val message = when (ex.failure) {
+            MyErrorFailure.BadPassword -> "bad password"
+            MyErrorFailure.NullArgument -> "null argument"
+        }
+        assertEquals("bad password", message)
+    }
+
+    @Test
+    fun okIsNotAFailure() {
+        assertThrows(IllegalArgumentException::class.java) {
+            MyErrorFailure.of(MyError.OK)
+        }
+    }
+}
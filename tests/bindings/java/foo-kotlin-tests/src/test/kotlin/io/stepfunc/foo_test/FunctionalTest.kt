@@ -0,0 +1,23 @@
+package io.stepfunc.foo_test
+
+import io.stepfunc.foo.*
+import org.joou.UInteger
+import org.joou.Unsigned.uint
+import org.junit.jupiter.api.Assertions.assertEquals
+import org.junit.jupiter.api.Test
+
+class FunctionalTest {
+    @Test
+    fun functionsAreConvertedToInterfaces() {
+        val values = mutableListOf<UInteger>()
+        val listener: ValueChangeListenerFunction = { values.add(it) }
+        val double: OperationFunction = { uint(2 * it.toInt()) }
+
+        ThreadClass(uint(42), listener.toValueChangeListener()).use { threadClass ->
+            threadClass.update(uint(43))
+            threadClass.execute(double.toOperation())
+        }
+
+        assertEquals(listOf(uint(43), uint(86)), values)
+    }
+}
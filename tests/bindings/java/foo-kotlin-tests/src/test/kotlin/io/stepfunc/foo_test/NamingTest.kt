@@ -0,0 +1,27 @@
+package io.stepfunc.foo_test
+
+import io.stepfunc.foo.*
+import org.joou.Unsigned.uint
+import org.junit.jupiter.api.Assertions.assertEquals
+import org.junit.jupiter.api.Assertions.assertFalse
+import org.junit.jupiter.api.Assertions.assertTrue
+import org.junit.jupiter.api.Test
+
+class NamingTest {
+    @Test
+    fun reservedNamesAreEscaped() {
+        val registry = URLRegistry(uint(42))
+        registry.insert(uint(1), uint(7))
+
+        val entry = registry.get(uint(1))
+        assertEquals(uint(7), entry.value)
+        assertFalse(entry.default_)
+
+        assertTrue(registry.delete(uint(1)))
+        assertFalse(registry.delete(uint(1)))
+
+        val missing = registry.get(uint(1))
+        assertEquals(uint(42), missing.value)
+        assertTrue(missing.default_)
+    }
+}
@@ -0,0 +1,30 @@
+package io.stepfunc.foo_test
+
+import io.stepfunc.foo.*
+import org.joou.Unsigned.uint
+import org.junit.jupiter.api.Assertions.assertEquals
+import org.junit.jupiter.api.Assertions.assertThrows
+import org.junit.jupiter.api.Test
+
+class ResourcesTest {
+    @Test
+    fun useShutsDownTheInstance() {
+        val value = TestClass(uint(41)).use { testClass ->
+            assertEquals(uint(1), TestClass.constructionCounter())
+            testClass.incrementValue()
+            testClass.value
+        }
+
+        assertEquals(uint(42), value)
+        assertEquals(uint(0), TestClass.constructionCounter())
+    }
+
+    @Test
+    fun useShutsDownTheInstanceWhenTheBlockThrows() {
+        assertThrows(IllegalStateException::class.java) {
+            TestClass(uint(41)).use { throw IllegalStateException() }
+        }
+
+        assertEquals(uint(0), TestClass.constructionCounter())
+    }
+}
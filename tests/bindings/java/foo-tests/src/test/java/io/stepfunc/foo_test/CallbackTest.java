@@ -1,7 +1,7 @@
 package io.stepfunc.foo_test;
 
-import io.stepfunc.foo.CallbackInterface;
-import io.stepfunc.foo.CallbackSource;
+import io.stepfunc.foo.callbacks.CallbackInterface;
+import io.stepfunc.foo.callbacks.CallbackSource;
 import org.assertj.core.data.Percentage;
 import org.joou.UInteger;
 import org.junit.jupiter.api.Test;
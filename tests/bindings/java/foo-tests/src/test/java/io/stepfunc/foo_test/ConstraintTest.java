@@ -0,0 +1,73 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import org.junit.jupiter.api.Test;
+
+import java.time.Duration;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.joou.Unsigned.*;
+
+public class ConstraintTest {
+    @Test
+    public void RangeViolationThrows() {
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.scale(200, ubyte(1));
+        }).isInstanceOf(IllegalArgumentException.class);
+
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.scale(-50, ubyte(0));
+        }).isInstanceOf(IllegalArgumentException.class);
+    }
+
+    @Test
+    public void ValueInRangeIsAccepted() {
+        assertThat(ConstraintFunctions.scale(-50, ubyte(2))).isEqualTo(-100);
+    }
+
+    @Test
+    public void StringViolationsThrow() {
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.checkIdentifier("");
+        }).isInstanceOf(IllegalArgumentException.class);
+
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.checkIdentifier("this_identifier_is_too_long");
+        }).isInstanceOf(IllegalArgumentException.class);
+
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.checkIdentifier("9lives");
+        }).isInstanceOf(IllegalArgumentException.class);
+    }
+
+    @Test
+    public void ValidIdentifierIsAccepted() {
+        ConstraintFunctions.checkIdentifier("my_identifier");
+    }
+
+    @Test
+    public void StructFieldViolationsThrow() {
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.endpointPort(new Endpoint("localhost", ushort(0)));
+        }).isInstanceOf(IllegalArgumentException.class);
+
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.endpointPort(new Endpoint("", ushort(20000)));
+        }).isInstanceOf(IllegalArgumentException.class);
+
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.endpointPort(new Endpoint("localhost", ushort(20000)).withTimeout(Duration.ZERO));
+        }).isInstanceOf(IllegalArgumentException.class);
+
+        // rounds down to zero milliseconds
+        assertThatThrownBy(() -> {
+            ConstraintFunctions.endpointPort(new Endpoint("localhost", ushort(20000)).withTimeout(Duration.ofNanos(500)));
+        }).isInstanceOf(IllegalArgumentException.class);
+    }
+
+    @Test
+    public void ValidStructIsAccepted() {
+        assertThat(ConstraintFunctions.endpointPort(new Endpoint("localhost", ushort(20000)))).isEqualTo(ushort(20000));
+    }
+}
@@ -0,0 +1,46 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import org.junit.jupiter.api.Test;
+
+import java.time.Duration;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.joou.Unsigned.*;
+
+public class DefaultParamsTest {
+    @Test
+    public void OverloadsUseDefaultValues() {
+        assertThat(DefaultParamFunctions.addOffset(5)).isEqualTo(15);
+        assertThat(DefaultParamFunctions.addOffset(5, 1)).isEqualTo(6);
+        assertThat(DefaultParamFunctions.addOffset(5, 1, 3)).isEqualTo(18);
+    }
+
+    @Test
+    public void DurationDefault() {
+        assertThat(DefaultParamFunctions.durationToMillis()).isEqualTo(ulong(1500));
+        assertThat(DefaultParamFunctions.durationToMillis(Duration.ofSeconds(2))).isEqualTo(ulong(2000));
+    }
+
+    @Test
+    public void StringDefault() {
+        assertThat(DefaultParamFunctions.stringByteLength()).isEqualTo(uint(5));
+        assertThat(DefaultParamFunctions.stringByteLength("hi")).isEqualTo(uint(2));
+    }
+
+    @Test
+    public void EnumDefault() {
+        assertThat(DefaultParamFunctions.speedValue()).isEqualTo(uint(1));
+        assertThat(DefaultParamFunctions.speedValue(Speed.SLOW)).isEqualTo(uint(0));
+    }
+
+    @Test
+    public void ClassDefaults() {
+        Counter counter = new Counter();
+        assertThat(counter.increment()).isEqualTo(uint(1));
+        assertThat(counter.increment(uint(5))).isEqualTo(uint(6));
+
+        Counter other = new Counter(uint(10));
+        assertThat(other.increment()).isEqualTo(uint(11));
+    }
+}
@@ -0,0 +1,26 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import org.junit.jupiter.api.Test;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.joou.Unsigned.*;
+
+public class NamingTest {
+    @Test
+    public void ReservedNamesAreEscaped() {
+        URLRegistry registry = new URLRegistry(uint(42));
+        registry.insert(uint(1), uint(7));
+
+        RegistryEntry entry = registry.get(uint(1));
+        assertThat(entry.value).isEqualTo(uint(7));
+        assertThat(entry.default_).isFalse();
+
+        assertThat(registry.delete(uint(1))).isTrue();
+        assertThat(registry.delete(uint(1))).isFalse();
+
+        entry = registry.get(uint(1));
+        assertThat(entry.value).isEqualTo(uint(42));
+        assertThat(entry.default_).isTrue();
+    }
+}
@@ -1,6 +1,7 @@
 package io.stepfunc.foo_test;
 
 import io.stepfunc.foo.*;
+import io.stepfunc.foo.callbacks.*;
 import org.junit.jupiter.api.Test;
 
 import static org.assertj.core.api.Assertions.assertThatNullPointerException;
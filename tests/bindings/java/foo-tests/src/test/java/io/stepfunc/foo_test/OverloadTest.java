@@ -0,0 +1,25 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import org.junit.jupiter.api.Test;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.joou.Unsigned.*;
+
+public class OverloadTest {
+    @Test
+    public void MethodOverloads() {
+        Accumulator accumulator = new Accumulator();
+        accumulator.add(ubyte(1));
+        accumulator.add(uint(100000));
+        accumulator.add("four");
+        accumulator.add(uint(3), uint(5));
+        assertThat(accumulator.total()).isEqualTo(ulong(100020));
+    }
+
+    @Test
+    public void StaticMethodOverloads() {
+        assertThat(OverloadFunctions.describe(true)).isEqualTo(uint(1));
+        assertThat(OverloadFunctions.describe(1.5)).isEqualTo(uint(2));
+    }
+}
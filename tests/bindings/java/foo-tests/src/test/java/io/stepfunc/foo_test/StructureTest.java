@@ -56,4 +56,67 @@ public class StructureTest {
         assertThat(x.booleanTrue).isFalse();
         assertThat(x.booleanFalse).isTrue();
     }
+
+    @Test
+    public void testNumbersValueSemantics() {
+        Numbers x = new Numbers();
+        Numbers y = new Numbers();
+
+        assertThat(x).isEqualTo(y);
+        assertThat(x.hashCode()).isEqualTo(y.hashCode());
+        assertThat(x.toString()).isEqualTo(y.toString());
+
+        Numbers z = y.withUint8Value(ubyte(2));
+        assertThat(y).isEqualTo(x);
+        assertThat(z).isNotEqualTo(y);
+        assertThat(z.toString()).contains("uint8Value=2");
+    }
+
+    @Test
+    public void testNumbersBuilder() {
+        Numbers x = Numbers.builder().withInt32Value(42).build();
+        checkNumbersDefaults(x.withInt32Value(-3));
+        assertThat(x.int32Value).isEqualTo(42);
+    }
+
+    @Test
+    public void testLabeledNumbersCopyMethods() {
+        LabeledNumbers x = new LabeledNumbers();
+        LabeledNumbers y = x.withLabel("copy").withNumbers(x.numbers.withInt32Value(42));
+
+        assertThat(x.label).isEqualTo("default");
+        checkNumbersDefaults(x.numbers);
+        assertThat(y.label).isEqualTo("copy");
+        assertThat(y.numbers.int32Value).isEqualTo(42);
+        checkNumbersDefaults(y.numbers.withInt32Value(-3));
+    }
+
+    @Test
+    public void testLabeledNumbersBuilder() {
+        LabeledNumbers x = LabeledNumbers.builder().withLabel("built").build();
+        assertThat(x.label).isEqualTo("built");
+        checkNumbersDefaults(x.numbers);
+
+        LabeledNumbers y = LabeledNumbers.builder()
+            .withNumbers(Numbers.builder().withInt32Value(42).build())
+            .build();
+        assertThat(y.label).isEqualTo("default");
+        assertThat(y.numbers.int32Value).isEqualTo(42);
+    }
+
+    @Test
+    public void testNestedValueSemantics() {
+        UniversalOuterStruct x = new UniversalOuterStruct();
+        UniversalOuterStruct y = new UniversalOuterStruct();
+
+        assertThat(x).isEqualTo(y);
+        assertThat(x.hashCode()).isEqualTo(y.hashCode());
+
+        y.inner.value = 7;
+        assertThat(x).isNotEqualTo(y);
+
+        y.inner.value = x.inner.value;
+        y.delay = Duration.ofSeconds(6);
+        assertThat(x).isNotEqualTo(y);
+    }
 }
@@ -0,0 +1,54 @@
+// foo v1.2.3
+// Copyright (C) 2020-2021 Step Function I/O
+// 
+// This is my custom license.
+// These views are not even my own. They belong to nobody.
+//   - Frumious Scadateer (@scadateer)
+#pragma once
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+
+/// @file foo_mock.h Control API of the mock implementation of the foo library
+
+/// @brief Forget every call, configured value and interface
+void foo_mock_reset(void);
+
+/// @brief Number of times a function was called since the last reset
+/// @param function Name of the native function without the prefix
+/// @return Number of calls
+uint32_t foo_mock_call_count(const char* function);
+
+/// @brief Arguments of the most recent call to a function, separated by commas
+/// @param function Name of the native function without the prefix
+/// @return Text of the arguments, valid until the next call returning a string
+const char* foo_mock_last_arguments(const char* function);
+
+/// @brief Configure the value returned by a function, e.g. `42`, `true` or `enum_variant`
+/// @param function Name of the native function without the prefix
+/// @param value Text of the value
+void foo_mock_set_return(const char* function, const char* value);
+
+/// @brief Configure the error returned by a function, e.g. `bad_password`
+/// @param function Name of the native function without the prefix
+/// @param error Name of the error variant
+void foo_mock_set_error(const char* function, const char* error);
+
+/// @brief Clear the configured return value and error of a function
+/// @param function Name of the native function without the prefix
+void foo_mock_clear(const char* function);
+
+/// @brief Invoke a callback of an interface passed to a function
+/// @param function Name of the native function without the prefix
+/// @param callback Name of the callback
+/// @param arguments Text of the arguments of the callback
+/// @param count Number of arguments
+/// @return Text of the value returned by the callback, or null if it was not invoked
+const char* foo_mock_invoke(const char* function, const char* callback, const char* const* arguments, uint32_t count);
+
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,129 @@
+// Control API of the mock implementation of the foo library generated by oo-bindgen
+using System;
+using System.Runtime.InteropServices;
+using System.Text;
+
+namespace foo.Tests
+{
+    /// <summary>
+    /// Control API of the mock implementation, loaded in place of the native library
+    /// </summary>
+    public static class Mock
+    {
+        /// <summary>Forget every call, configured value and interface</summary>
+        public static void Reset()
+        {
+            PInvoke.Reset();
+        }
+        
+        /// <summary>Number of times a function was called since the last reset</summary>
+        /// <param name="function">Name of the native function without the prefix</param>
+        /// <returns>Number of calls</returns>
+        public static uint CallCount(string function)
+        {
+            return PInvoke.CallCount(ToNative(function));
+        }
+        
+        /// <summary>Arguments of the most recent call to a function, separated by commas</summary>
+        /// <param name="function">Name of the native function without the prefix</param>
+        /// <returns>Text of the arguments, valid until the next call returning a string</returns>
+        public static string LastArguments(string function)
+        {
+            return FromNative(PInvoke.LastArguments(ToNative(function)));
+        }
+        
+        /// <summary>Configure the value returned by a function, e.g. <c>42</c>, <c>true</c> or <c>enum_variant</c></summary>
+        /// <param name="function">Name of the native function without the prefix</param>
+        /// <param name="value">Text of the value</param>
+        public static void SetReturn(string function, string value)
+        {
+            PInvoke.SetReturn(ToNative(function), ToNative(value));
+        }
+        
+        /// <summary>Configure the error returned by a function, e.g. <c>bad_password</c></summary>
+        /// <param name="function">Name of the native function without the prefix</param>
+        /// <param name="error">Name of the error variant</param>
+        public static void SetError(string function, string error)
+        {
+            PInvoke.SetError(ToNative(function), ToNative(error));
+        }
+        
+        /// <summary>Clear the configured return value and error of a function</summary>
+        /// <param name="function">Name of the native function without the prefix</param>
+        public static void Clear(string function)
+        {
+            PInvoke.Clear(ToNative(function));
+        }
+        
+        /// <summary>Invoke a callback of an interface passed to a function</summary>
+        /// <param name="function">Name of the native function without the prefix</param>
+        /// <param name="callback">Name of the callback</param>
+        /// <param name="arguments">Text of the arguments of the callback</param>
+        /// <returns>Text of the value returned by the callback, or null if it was not invoked</returns>
+        public static string Invoke(string function, string callback, params string[] arguments)
+        {
+            var _arguments = Array.ConvertAll(arguments, ToUnmanaged);
+            try
+            {
+                return FromNative(PInvoke.Invoke(ToNative(function), ToNative(callback), _arguments, (uint)_arguments.Length));
+            }
+            finally
+            {
+                Array.ForEach(_arguments, Marshal.FreeHGlobal);
+            }
+        }
+        
+        private static byte[] ToNative(string value)
+        {
+            return Encoding.UTF8.GetBytes(value + "\0");
+        }
+        
+        private static IntPtr ToUnmanaged(string value)
+        {
+            var bytes = ToNative(value);
+            var result = Marshal.AllocHGlobal(bytes.Length);
+            Marshal.Copy(bytes, 0, result, bytes.Length);
+            return result;
+        }
+        
+        private static string FromNative(IntPtr value)
+        {
+            if (value == IntPtr.Zero)
+            {
+                return null;
+            }
+            var length = 0;
+            while (Marshal.ReadByte(value, length) != 0)
+            {
+                ++length;
+            }
+            var bytes = new byte[length];
+            Marshal.Copy(value, bytes, 0, length);
+            return Encoding.UTF8.GetString(bytes);
+        }
+        
+        private static class PInvoke
+        {
+            [DllImport("foo_ffi", CallingConvention = CallingConvention.Cdecl, EntryPoint = "foo_mock_reset")]
+            internal static extern void Reset();
+            
+            [DllImport("foo_ffi", CallingConvention = CallingConvention.Cdecl, EntryPoint = "foo_mock_call_count")]
+            internal static extern uint CallCount(byte[] function);
+            
+            [DllImport("foo_ffi", CallingConvention = CallingConvention.Cdecl, EntryPoint = "foo_mock_last_arguments")]
+            internal static extern IntPtr LastArguments(byte[] function);
+            
+            [DllImport("foo_ffi", CallingConvention = CallingConvention.Cdecl, EntryPoint = "foo_mock_set_return")]
+            internal static extern void SetReturn(byte[] function, byte[] value);
+            
+            [DllImport("foo_ffi", CallingConvention = CallingConvention.Cdecl, EntryPoint = "foo_mock_set_error")]
+            internal static extern void SetError(byte[] function, byte[] error);
+            
+            [DllImport("foo_ffi", CallingConvention = CallingConvention.Cdecl, EntryPoint = "foo_mock_clear")]
+            internal static extern void Clear(byte[] function);
+            
+            [DllImport("foo_ffi", CallingConvention = CallingConvention.Cdecl, EntryPoint = "foo_mock_invoke")]
+            internal static extern IntPtr Invoke(byte[] function, byte[] callback, IntPtr[] arguments, uint count);
+        }
+    }
+}
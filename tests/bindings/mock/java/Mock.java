@@ -0,0 +1,167 @@
+// Control API of the mock implementation of the foo library generated by oo-bindgen
+package io.stepfunc.foo_test;
+
+import org.joou.*;
+
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+
+/**
+ * Control API of the mock implementation, loaded in place of the native library
+ *
+ * <p>The mock is found at the path in {@code FOO_NATIVE_LIB_LOCATION}, like the Panama bindings do.
+ */
+public class Mock
+{
+    private static final SymbolLookup LIBRARY = SymbolLookup.libraryLookup(java.nio.file.Path.of(System.getenv("FOO_NATIVE_LIB_LOCATION")), Arena.global());
+    
+    private static final MethodHandle reset = downcall("foo_mock_reset", FunctionDescriptor.ofVoid());
+    private static final MethodHandle call_count = downcall("foo_mock_call_count", FunctionDescriptor.of(ValueLayout.JAVA_INT, ValueLayout.ADDRESS));
+    private static final MethodHandle last_arguments = downcall("foo_mock_last_arguments", FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS));
+    private static final MethodHandle set_return = downcall("foo_mock_set_return", FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS));
+    private static final MethodHandle set_error = downcall("foo_mock_set_error", FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS));
+    private static final MethodHandle clear = downcall("foo_mock_clear", FunctionDescriptor.ofVoid(ValueLayout.ADDRESS));
+    private static final MethodHandle invoke = downcall("foo_mock_invoke", FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_INT));
+    
+    private Mock() {}
+    
+    /**
+     * Forget every call, configured value and interface
+     */
+    public static void reset()
+    {
+        try (Arena _arena = Arena.ofConfined())
+        {
+            reset.invoke();
+        }
+        catch (Throwable _t)
+        {
+            throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);
+        }
+    }
+    
+    /**
+     * Number of times a function was called since the last reset
+     *
+     * @param function Name of the native function without the prefix
+     * @return Number of calls
+     */
+    public static UInteger callCount(String function)
+    {
+        try (Arena _arena = Arena.ofConfined())
+        {
+            return UInteger.valueOf(Integer.toUnsignedLong((int) call_count.invoke(_arena.allocateFrom(function))));
+        }
+        catch (Throwable _t)
+        {
+            throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);
+        }
+    }
+    
+    /**
+     * Arguments of the most recent call to a function, separated by commas
+     *
+     * @param function Name of the native function without the prefix
+     * @return Text of the arguments, valid until the next call returning a string
+     */
+    public static String lastArguments(String function)
+    {
+        try (Arena _arena = Arena.ofConfined())
+        {
+            return fromNative((MemorySegment) last_arguments.invoke(_arena.allocateFrom(function)));
+        }
+        catch (Throwable _t)
+        {
+            throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);
+        }
+    }
+    
+    /**
+     * Configure the value returned by a function, e.g. {@code 42}, {@code true} or {@code enum_variant}
+     *
+     * @param function Name of the native function without the prefix
+     * @param value Text of the value
+     */
+    public static void setReturn(String function, String value)
+    {
+        try (Arena _arena = Arena.ofConfined())
+        {
+            set_return.invoke(_arena.allocateFrom(function), _arena.allocateFrom(value));
+        }
+        catch (Throwable _t)
+        {
+            throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);
+        }
+    }
+    
+    /**
+     * Configure the error returned by a function, e.g. {@code bad_password}
+     *
+     * @param function Name of the native function without the prefix
+     * @param error Name of the error variant
+     */
+    public static void setError(String function, String error)
+    {
+        try (Arena _arena = Arena.ofConfined())
+        {
+            set_error.invoke(_arena.allocateFrom(function), _arena.allocateFrom(error));
+        }
+        catch (Throwable _t)
+        {
+            throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);
+        }
+    }
+    
+    /**
+     * Clear the configured return value and error of a function
+     *
+     * @param function Name of the native function without the prefix
+     */
+    public static void clear(String function)
+    {
+        try (Arena _arena = Arena.ofConfined())
+        {
+            clear.invoke(_arena.allocateFrom(function));
+        }
+        catch (Throwable _t)
+        {
+            throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);
+        }
+    }
+    
+    /**
+     * Invoke a callback of an interface passed to a function
+     *
+     * @param function Name of the native function without the prefix
+     * @param callback Name of the callback
+     * @param arguments Text of the arguments of the callback
+     * @return Text of the value returned by the callback, or null if it was not invoked
+     */
+    public static String invoke(String function, String callback, String... arguments)
+    {
+        try (Arena _arena = Arena.ofConfined())
+        {
+            MemorySegment _arguments = _arena.allocate(ValueLayout.ADDRESS, Math.max(arguments.length, 1));
+            for (int i = 0; i < arguments.length; ++i)
+            {
+                _arguments.setAtIndex(ValueLayout.ADDRESS, i, _arena.allocateFrom(arguments[i]));
+            }
+            return fromNative((MemorySegment) invoke.invoke(_arena.allocateFrom(function), _arena.allocateFrom(callback), _arguments, arguments.length));
+        }
+        catch (Throwable _t)
+        {
+            throw _t instanceof RuntimeException ? (RuntimeException) _t : new RuntimeException(_t);
+        }
+    }
+    
+    private static MethodHandle downcall(String name, FunctionDescriptor descriptor)
+    {
+        MemorySegment symbol = LIBRARY.find(name).orElseThrow(() -> new UnsatisfiedLinkError("Unable to find native symbol: " + name));
+        return Linker.nativeLinker().downcallHandle(symbol, descriptor);
+    }
+    
+    private static String fromNative(MemorySegment value)
+    {
+        return value.equals(MemorySegment.NULL) ? null : value.reinterpret(Long.MAX_VALUE).getString(0);
+    }
+}
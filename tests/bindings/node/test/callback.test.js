@@ -0,0 +1,34 @@
+"use strict";
+
+const test = require("node:test");
+const assert = require("node:assert");
+
+const foo = require("../generated");
+
+test("callback", () => {
+    const source = new foo.CallbackSource();
+    try {
+        const callback = {
+            lastValue: 0,
+            lastDuration: 0,
+            onValue(value) {
+                this.lastValue = value;
+                return value;
+            },
+            onDuration(value) {
+                this.lastDuration = value;
+                return value;
+            },
+        };
+        source.setInterface(callback);
+
+        assert.strictEqual(callback.lastValue, 0);
+        assert.strictEqual(source.setValue(76), 76);
+        assert.strictEqual(callback.lastValue, 76);
+
+        assert.strictEqual(source.setDuration(76000), 76000);
+        assert.strictEqual(callback.lastDuration, 76000);
+    } finally {
+        source.dispose();
+    }
+});
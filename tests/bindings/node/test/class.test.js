@@ -0,0 +1,35 @@
+"use strict";
+
+const test = require("node:test");
+const assert = require("node:assert");
+
+const foo = require("../generated");
+
+test("construction and destruction", () => {
+    assert.strictEqual(foo.TestClass.constructionCounter(), 0);
+
+    const testClass = new foo.TestClass(41);
+    assert.strictEqual(foo.TestClass.constructionCounter(), 1);
+    assert.strictEqual(testClass.getValue(), 41);
+
+    testClass.incrementValue();
+    assert.strictEqual(testClass.getValue(), 42);
+
+    testClass.shutdown();
+    assert.strictEqual(foo.TestClass.constructionCounter(), 0);
+
+    // the instance can't be used once destroyed
+    assert.throws(() => testClass.getValue(), TypeError);
+});
+
+test("async method", async () => {
+    const testClass = new foo.TestClass(41);
+    try {
+        assert.strictEqual(await testClass.addAsync(1), 42);
+
+        testClass.incrementValue();
+        assert.strictEqual(await testClass.addAsync(1), 43);
+    } finally {
+        testClass.shutdown();
+    }
+});
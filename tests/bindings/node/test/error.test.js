@@ -0,0 +1,17 @@
+"use strict";
+
+const test = require("node:test");
+const assert = require("node:assert");
+
+const foo = require("../generated");
+
+test("error on bad password", () => {
+    assert.throws(
+        () => foo.ClassWithPassword.getSpecialValue("hi!"),
+        (err) => err instanceof foo.MyException && err.error === foo.MyError.BadPassword
+    );
+});
+
+test("value on good password", () => {
+    assert.strictEqual(foo.ClassWithPassword.getSpecialValue("12345"), 42);
+});
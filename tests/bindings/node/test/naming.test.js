@@ -0,0 +1,21 @@
+"use strict";
+
+const test = require("node:test");
+const assert = require("node:assert");
+
+const foo = require("../generated");
+
+test("reserved names are escaped", () => {
+    const registry = new foo.URLRegistry(42);
+    try {
+        registry.insert(1, 7);
+        assert.deepStrictEqual(registry.get(1), { value: 7, default_: false });
+
+        assert.strictEqual(registry.delete_(1), true);
+        assert.strictEqual(registry.delete_(1), false);
+
+        assert.deepStrictEqual(registry.get(1), { value: 42, default_: true });
+    } finally {
+        registry.dispose();
+    }
+});
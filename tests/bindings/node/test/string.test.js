@@ -0,0 +1,24 @@
+"use strict";
+
+const test = require("node:test");
+const assert = require("node:assert");
+
+const foo = require("../generated");
+
+const ENGLISH = "I like to be home with a cup of tea";
+const FRENCH = "Je suis parfaitement bilingue";
+
+test("echo", () => {
+    const stringClass = new foo.StringClass();
+    try {
+        for (const value of [ENGLISH, FRENCH]) {
+            assert.strictEqual(stringClass.echo(value), value);
+        }
+    } finally {
+        stringClass.dispose();
+    }
+});
+
+test("length", () => {
+    assert.strictEqual(foo.StringClass.getLength(ENGLISH), ENGLISH.length);
+});
@@ -0,0 +1,60 @@
+"use strict";
+
+const test = require("node:test");
+const assert = require("node:assert");
+
+const foo = require("../generated");
+
+// values are delivered by the event loop, so they are awaited instead of checked after each call
+function listener(count) {
+    const values = [];
+    let resolve;
+    const done = new Promise((x) => (resolve = x));
+    const onValueChange = (value) => {
+        values.push(value);
+        if (values.length === count) {
+            resolve(values);
+        }
+    };
+    return { onValueChange, done, values };
+}
+
+test("async callbacks", async () => {
+    const { onValueChange, done } = listener(3);
+    const threadClass = new foo.ThreadClass(42, onValueChange);
+    try {
+        assert.strictEqual(await threadClass.add(4), 46);
+        threadClass.update(43);
+        threadClass.execute((x) => 2 * x);
+    } finally {
+        // the worker may be waiting for the event loop to run the operation
+        threadClass.shutdown();
+    }
+    assert.deepStrictEqual(await done, [46, 43, 86]);
+});
+
+test("shutdown while a callback is pending", async () => {
+    const { onValueChange, done } = listener(1);
+    const threadClass = new foo.ThreadClass(1, onValueChange);
+    threadClass.update(7);
+    threadClass.shutdown();
+    assert.deepStrictEqual(await done, [7]);
+
+    // the instance can't be used once destroyed
+    assert.throws(() => threadClass.update(8), TypeError);
+});
+
+test("task failures reject the promise", async () => {
+    const { onValueChange, values } = listener(1);
+    const threadClass = new foo.ThreadClass(42, onValueChange);
+    try {
+        threadClass.queueError(foo.MathIsBroken.MathIsBroke);
+        await assert.rejects(
+            threadClass.add(43),
+            (err) => err instanceof foo.BrokenMathException && err.error === foo.MathIsBroken.MathIsBroke
+        );
+    } finally {
+        threadClass.shutdown();
+    }
+    assert.deepStrictEqual(values, []);
+});
@@ -0,0 +1,17 @@
+// swift-tools-version:5.7
+
+import PackageDescription
+
+let package = Package(
+    name: "FooTests",
+    platforms: [.macOS(.v10_15)],
+    dependencies: [
+        .package(path: "generated"),
+    ],
+    targets: [
+        .testTarget(
+            name: "FooTests",
+            dependencies: [.product(name: "Foo", package: "generated")]
+        ),
+    ]
+)
@@ -0,0 +1,34 @@
+import XCTest
+import Foo
+
+private final class CallbackImpl: CallbackInterface {
+    var lastValue: UInt32 = 0
+    var lastDuration: TimeInterval = 0
+
+    func onValue(value: UInt32) -> UInt32 {
+        lastValue = value
+        return value
+    }
+
+    func onDuration(value: TimeInterval) -> TimeInterval {
+        lastDuration = value
+        return value
+    }
+}
+
+final class CallbackTests: XCTestCase {
+    func testCallback() {
+        let source = CallbackSource()
+        let callback = CallbackImpl()
+        source.setInterface(cb: callback)
+
+        XCTAssertEqual(callback.lastValue, 0)
+        XCTAssertEqual(source.setValue(value: 76), 76)
+        XCTAssertEqual(callback.lastValue, 76)
+
+        XCTAssertEqual(source.setDuration(value: 76), 76)
+        XCTAssertEqual(callback.lastDuration, 76)
+
+        source.close()
+    }
+}
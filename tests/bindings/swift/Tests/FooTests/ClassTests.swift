@@ -0,0 +1,30 @@
+import XCTest
+import Foo
+
+final class ClassTests: XCTestCase {
+    func testConstructionDestruction() {
+        XCTAssertEqual(TestClass.constructionCounter(), 0)
+
+        let testClass = TestClass(value: 41)
+        XCTAssertEqual(TestClass.constructionCounter(), 1)
+        XCTAssertEqual(testClass.getValue(), 41)
+
+        testClass.incrementValue()
+        XCTAssertEqual(testClass.getValue(), 42)
+
+        testClass.shutdown()
+        XCTAssertEqual(TestClass.constructionCounter(), 0)
+    }
+
+    func testAsyncMethod() async throws {
+        let testClass = TestClass(value: 41)
+        let first = try await testClass.addAsync(value: 1)
+        XCTAssertEqual(first, 42)
+
+        testClass.incrementValue()
+        let second = try await testClass.addAsync(value: 1)
+        XCTAssertEqual(second, 43)
+
+        testClass.shutdown()
+    }
+}
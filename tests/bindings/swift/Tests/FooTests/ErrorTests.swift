@@ -0,0 +1,14 @@
+import XCTest
+import Foo
+
+final class ErrorTests: XCTestCase {
+    func testThrowsOnBadPassword() {
+        XCTAssertThrowsError(try ClassWithPassword.getSpecialValue(password: "hi!")) { error in
+            XCTAssertEqual(error as? MyError, .badPassword)
+        }
+    }
+
+    func testReturnsValueOnGoodPassword() throws {
+        XCTAssertEqual(try ClassWithPassword.getSpecialValue(password: "12345"), 42)
+    }
+}
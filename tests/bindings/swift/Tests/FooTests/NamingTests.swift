@@ -0,0 +1,20 @@
+import XCTest
+import Foo
+
+final class NamingTests: XCTestCase {
+    func testReservedNamesAreEscaped() {
+        let registry = URLRegistry(default: 42)
+        registry.insert(key: 1, value: 7)
+
+        var entry = registry.get(key: 1)
+        XCTAssertEqual(entry.value, 7)
+        XCTAssertFalse(entry.default)
+
+        XCTAssertTrue(registry.delete(key: 1))
+        XCTAssertFalse(registry.delete(key: 1))
+
+        entry = registry.get(key: 1)
+        XCTAssertEqual(entry.value, 42)
+        XCTAssertTrue(entry.default)
+    }
+}
@@ -0,0 +1,18 @@
+import XCTest
+import Foo
+
+final class StringTests: XCTestCase {
+    private let english = "I like to be home with a cup of tea"
+    private let french = "Je suis parfaitement bilingue"
+
+    func testEcho() {
+        let stringClass = StringClass()
+        XCTAssertEqual(stringClass.echo(value: english), english)
+        XCTAssertEqual(stringClass.echo(value: french), french)
+        stringClass.close()
+    }
+
+    func testLength() {
+        XCTAssertEqual(StringClass.getLength(value: english), UInt32(english.utf8.count))
+    }
+}
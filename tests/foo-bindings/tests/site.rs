@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Values of an attribute, e.g. `href`, in the order they appear in the page
+fn attributes<'a>(html: &'a str, name: &str) -> Vec<&'a str> {
+    let prefix = format!(" {}=\"", name);
+    html.split(prefix.as_str())
+        .skip(1)
+        .filter_map(|x| x.split('"').next())
+        .collect()
+}
+
+fn generate_site() -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("site");
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    let status = Command::new(env!("CARGO_BIN_EXE_foo-bindings"))
+        .arg("--doc-site")
+        .arg(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    dir
+}
+
+/// Contents of each page of the site, by file name
+fn read_pages(dir: &Path) -> HashMap<String, String> {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|x| x == "html"))
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            (name, std::fs::read_to_string(&path).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn cross_references_resolve() {
+    let pages = read_pages(&generate_site());
+    let anchors: HashMap<&str, HashSet<&str>> = pages
+        .iter()
+        .map(|(name, html)| {
+            (
+                name.as_str(),
+                attributes(html, "id").into_iter().collect::<HashSet<_>>(),
+            )
+        })
+        .collect();
+
+    let mut links = 0;
+    for (name, html) in &pages {
+        for href in attributes(html, "href") {
+            if href.contains("://") || !(href.contains(".html") || href.starts_with('#')) {
+                continue;
+            }
+            let (page, anchor) = match href.split_once('#') {
+                Some(("", anchor)) => (name.as_str(), Some(anchor)),
+                Some((page, anchor)) => (page, Some(anchor)),
+                None => (href, None),
+            };
+            let ids = anchors
+                .get(page)
+                .unwrap_or_else(|| panic!("{} links to the missing page {}", name, page));
+            if let Some(anchor) = anchor {
+                assert!(
+                    ids.contains(anchor),
+                    "{} links to the missing anchor {}",
+                    name,
+                    href
+                );
+            }
+            links += 1;
+        }
+    }
+    assert!(links > 0);
+
+    // references across modules, `callback_interface` is in the `callbacks` module
+    let links = attributes(&pages["class.test_class.html"], "href");
+    assert!(links.contains(&"interface.callback_interface.html#callback.on_value"));
+    let links = attributes(&pages["class.callback_source.html"], "href");
+    assert!(links.contains(&"class.test_class.html#method.add_async"));
+}
@@ -0,0 +1,20 @@
+//! WebAssembly bindings of the foo library
+//!
+//! Build and test with `wasm-pack build` and `wasm-pack test --node`
+
+#![allow(
+    clippy::unused_unit,
+    clippy::let_unit_value,
+    clippy::useless_conversion,
+    clippy::redundant_closure,
+    clippy::needless_borrow,
+    clippy::needless_return,
+    clippy::not_unsafe_ptr_arg_deref,
+    clippy::redundant_static_lifetimes,
+    clippy::too_many_arguments,
+    unused_variables,
+    dead_code
+)]
+// ^ these lints don't matter in the generated code
+
+include!(concat!(env!("OUT_DIR"), "/wasm.rs"));
@@ -0,0 +1,58 @@
+use foo_ffi_wasm::CallbackSource;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(inline_js = "
+export function callback() {
+    return {
+        lastValue: 0,
+        lastDuration: 0,
+        onValue(value) {
+            this.lastValue = value;
+            return value;
+        },
+        onDuration(value) {
+            this.lastDuration = value;
+            return value;
+        },
+    };
+}
+")]
+extern "C" {
+    fn callback() -> JsValue;
+}
+
+fn get(target: &JsValue, key: &str) -> Option<f64> {
+    js_sys::Reflect::get(target, &JsValue::from_str(key))
+        .unwrap()
+        .as_f64()
+}
+
+#[wasm_bindgen_test]
+fn callback_invocation() {
+    let mut source = CallbackSource::callback_source_create().unwrap();
+    let callback = callback();
+    source
+        .callback_source_set_interface(callback.clone())
+        .unwrap();
+
+    assert_eq!(get(&callback, "lastValue"), Some(0.0));
+    assert_eq!(source.callback_source_set_value(76).unwrap(), 76);
+    assert_eq!(get(&callback, "lastValue"), Some(76.0));
+
+    assert_eq!(
+        source.callback_source_set_duration(76000.0).unwrap(),
+        76000.0
+    );
+    assert_eq!(get(&callback, "lastDuration"), Some(76000.0));
+
+    source.callback_source_destroy();
+}
+
+#[wasm_bindgen_test]
+fn missing_method_is_rejected() {
+    let source = CallbackSource::callback_source_create().unwrap();
+    assert!(source
+        .callback_source_set_interface(js_sys::Object::new().into())
+        .is_err());
+}
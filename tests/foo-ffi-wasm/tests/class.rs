@@ -0,0 +1,39 @@
+use foo_ffi_wasm::TestClass;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn construction_and_destruction() {
+    assert_eq!(TestClass::construction_counter().unwrap(), 0);
+
+    let mut test_class = TestClass::test_class_create(41).unwrap();
+    assert_eq!(TestClass::construction_counter().unwrap(), 1);
+    assert_eq!(test_class.test_class_get_value().unwrap(), 41);
+
+    test_class.test_class_increment_value().unwrap();
+    assert_eq!(test_class.test_class_get_value().unwrap(), 42);
+
+    test_class.test_class_destroy();
+    assert_eq!(TestClass::construction_counter().unwrap(), 0);
+
+    // the instance can't be used once destroyed
+    assert!(test_class.test_class_get_value().is_err());
+}
+
+#[wasm_bindgen_test]
+async fn async_method() {
+    let mut test_class = TestClass::test_class_create(41).unwrap();
+
+    let result = JsFuture::from(test_class.test_class_add_async(1).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.as_f64(), Some(42.0));
+
+    test_class.test_class_increment_value().unwrap();
+    let result = JsFuture::from(test_class.test_class_add_async(1).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.as_f64(), Some(43.0));
+
+    test_class.test_class_destroy();
+}
@@ -0,0 +1,19 @@
+use foo_ffi_wasm::{ClassWithPassword, MyError};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn error_on_bad_password() {
+    let error = ClassWithPassword::get_special_value("hi!").unwrap_err();
+    let value = js_sys::Reflect::get(&error, &JsValue::from_str("error")).unwrap();
+    assert_eq!(value.as_f64(), Some(MyError::BadPassword as i32 as f64));
+    assert_eq!(
+        js_sys::Error::from(error).name().as_string().unwrap(),
+        "MyException"
+    );
+}
+
+#[wasm_bindgen_test]
+fn value_on_good_password() {
+    assert_eq!(ClassWithPassword::get_special_value("12345").unwrap(), 42);
+}
@@ -0,0 +1,22 @@
+use foo_ffi_wasm::StringClass;
+use wasm_bindgen_test::*;
+
+const ENGLISH: &str = "I like to be home with a cup of tea";
+const FRENCH: &str = "Je suis parfaitement bilingue";
+
+#[wasm_bindgen_test]
+fn echo() {
+    let mut string_class = StringClass::string_class_create().unwrap();
+    for value in [ENGLISH, FRENCH].iter() {
+        assert_eq!(string_class.string_class_echo(value).unwrap(), *value);
+    }
+    string_class.string_class_destroy();
+}
+
+#[wasm_bindgen_test]
+fn length() {
+    assert_eq!(
+        StringClass::string_length(ENGLISH).unwrap(),
+        ENGLISH.len() as u32
+    );
+}
@@ -7,7 +7,13 @@ fn main() {
             std::process::exit(-1);
         }
         Ok(lib) => {
-            oo_bindgen::backend::rust::generate_ffi(&lib).unwrap();
+            let config = oo_bindgen::backend::rust::FfiBindgenConfig {
+                class_traits: true,
+                // these return references to their state, which requires exclusive access
+                free_function_classes: vec!["string_class".into(), "primitive_pointers".into()],
+            };
+            oo_bindgen::backend::rust::generate_ffi_with_config(&lib, &config).unwrap();
+            oo_bindgen::backend::rust::generate_conformance(&lib).unwrap();
         }
     }
 }
@@ -1,33 +1,35 @@
+use std::sync::Mutex;
 use std::time::Duration;
 
 use crate::ffi;
 
 pub struct CallbackSource {
-    callback: Option<ffi::CallbackInterface>,
-    _value: u32,
+    callback: Mutex<Option<ffi::CallbackInterface>>,
 }
 
-impl CallbackSource {
-    fn new() -> Self {
+impl ffi::CallbackSourceApi for CallbackSource {
+    fn create() -> Self {
         Self {
-            callback: None,
-            _value: 0,
+            callback: Mutex::new(None),
         }
     }
 
-    fn set(&mut self, cb: ffi::CallbackInterface) {
-        self.callback = Some(cb);
+    fn set_interface(&self, cb: ffi::CallbackInterface) {
+        *self.callback.lock().unwrap() = Some(cb);
     }
 
-    fn set_value(&mut self, value: u32) -> u32 {
-        self._value = value;
+    fn set_value(&self, value: u32) -> u32 {
         self.callback
+            .lock()
+            .unwrap()
             .as_ref()
             .map_or(0, |cb| cb.on_value(value).unwrap_or(0))
     }
 
-    fn set_duration(&mut self, value: Duration) -> Duration {
+    fn set_duration(&self, value: Duration) -> Duration {
         self.callback
+            .lock()
+            .unwrap()
             .as_ref()
             .map_or(Duration::from_millis(0), |cb| {
                 cb.on_duration(value)
@@ -35,35 +37,3 @@ impl CallbackSource {
             })
     }
 }
-
-pub unsafe fn callback_source_create() -> *mut CallbackSource {
-    let cb_source = Box::new(CallbackSource::new());
-    Box::into_raw(cb_source)
-}
-
-pub unsafe fn callback_source_destroy(cb_source: *mut CallbackSource) {
-    if !cb_source.is_null() {
-        drop(Box::from_raw(cb_source));
-    }
-}
-
-pub unsafe fn callback_source_set_interface(
-    cb_source: *mut CallbackSource,
-    cb: ffi::CallbackInterface,
-) {
-    let cb_source = cb_source.as_mut().unwrap();
-    cb_source.set(cb);
-}
-
-pub unsafe fn callback_source_set_value(cb_source: *mut CallbackSource, value: u32) -> u32 {
-    let cb_source = cb_source.as_mut().unwrap();
-    cb_source.set_value(value)
-}
-
-pub unsafe fn callback_source_set_duration(
-    cb_source: *mut CallbackSource,
-    value: Duration,
-) -> Duration {
-    let cb_source = cb_source.as_mut().unwrap();
-    cb_source.set_duration(value)
-}
@@ -8,6 +8,10 @@ pub struct StringCollectionWithReserve {
     values: Vec<CString>,
 }
 
+pub struct U32Collection {
+    values: Vec<u32>,
+}
+
 impl StringCollection {
     fn new() -> Self {
         Self { values: Vec::new() }
@@ -113,3 +117,30 @@ pub unsafe fn collection_with_reserve_get<'a>(
         CStr::from_ptr(std::ptr::null())
     }
 }
+
+pub unsafe fn u32_collection_create(reserve: u32) -> *mut U32Collection {
+    let it = Box::new(U32Collection {
+        values: Vec::with_capacity(reserve as usize),
+    });
+    Box::into_raw(it)
+}
+
+pub unsafe fn u32_collection_destroy(col: *mut U32Collection) {
+    if !col.is_null() {
+        drop(Box::from_raw(col));
+    }
+}
+
+pub unsafe fn u32_collection_add(col: *mut U32Collection, value: u32) {
+    if let Some(col) = col.as_mut() {
+        col.values.push(value);
+    }
+}
+
+pub unsafe fn u32_collection_sum(col: *mut U32Collection) -> u64 {
+    if let Some(col) = col.as_ref() {
+        col.values.iter().map(|x| *x as u64).sum()
+    } else {
+        0
+    }
+}
@@ -0,0 +1,16 @@
+use std::ffi::CStr;
+
+use crate::ffi;
+
+pub(crate) fn endpoint_port(endpoint: ffi::Endpoint) -> Result<u16, ffi::ConstraintError> {
+    Ok(endpoint.port)
+}
+
+pub(crate) fn scale(value: i32, factor: u8) -> Result<i32, ffi::ConstraintError> {
+    Ok(value * factor as i32)
+}
+
+pub(crate) fn check_identifier(_identifier: &CStr) -> Result<(), ffi::ConstraintError> {
+    // the FFI layer already enforced the constraints
+    Ok(())
+}
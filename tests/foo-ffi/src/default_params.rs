@@ -0,0 +1,40 @@
+use std::ffi::CStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use crate::ffi;
+
+pub struct Counter {
+    value: AtomicU32,
+}
+
+impl ffi::CounterApi for Counter {
+    fn create(initial: u32) -> Self {
+        Counter {
+            value: AtomicU32::new(initial),
+        }
+    }
+
+    fn increment(&self, amount: u32) -> u32 {
+        self.value.fetch_add(amount, Ordering::Relaxed) + amount
+    }
+}
+
+pub(crate) fn add_offset(value: i32, offset: i32, multiplier: i32) -> i32 {
+    (value + offset) * multiplier
+}
+
+pub(crate) fn duration_to_millis(value: Duration) -> u64 {
+    value.as_millis() as u64
+}
+
+pub(crate) fn string_byte_length(value: &CStr) -> u32 {
+    value.to_bytes().len() as u32
+}
+
+pub(crate) fn speed_value(speed: ffi::Speed) -> u32 {
+    match speed {
+        ffi::Speed::Slow => 0,
+        ffi::Speed::Fast => 1,
+    }
+}
@@ -32,30 +32,19 @@ pub(crate) fn echo_password(password: &CStr) -> std::result::Result<&CStr, crate
     }
 }
 
-pub(crate) fn class_with_password_create(
-    password: &CStr,
-) -> std::result::Result<*mut crate::ClassWithPassword, crate::ffi::MyError> {
-    if password.to_str()? == PASSWORD {
-        Ok(Box::into_raw(Box::new(ClassWithPassword {
-            value: SPECIAL_VALUE,
-        })))
-    } else {
-        Err(crate::ffi::MyError::BadPassword)
+impl crate::ffi::ClassWithPasswordApi for ClassWithPassword {
+    fn create(password: &CStr) -> std::result::Result<Self, crate::ffi::MyError> {
+        if password.to_str()? == PASSWORD {
+            Ok(ClassWithPassword {
+                value: SPECIAL_VALUE,
+            })
+        } else {
+            Err(crate::ffi::MyError::BadPassword)
+        }
     }
-}
-
-pub(crate) unsafe fn class_with_password_get_special_value(
-    instance: *mut crate::ClassWithPassword,
-) -> Result<u32, crate::ffi::MyError> {
-    match instance.as_ref() {
-        Some(x) => Ok(x.value),
-        None => Err(crate::ffi::MyError::NullArgument),
-    }
-}
 
-pub(crate) unsafe fn class_with_password_destroy(instance: *mut crate::ClassWithPassword) {
-    if !instance.is_null() {
-        drop(Box::from_raw(instance));
+    fn get_special_value(&self) -> std::result::Result<u32, crate::ffi::MyError> {
+        Ok(self.value)
     }
 }
 
@@ -3,6 +3,9 @@
 pub use callback::*;
 pub use class::*;
 pub use collection::*;
+pub use conformance::*;
+pub(crate) use constraint::*;
+pub use default_params::*;
 pub use duration::*;
 pub use enums::*;
 pub use error::*;
@@ -10,7 +13,9 @@ pub use integer::*;
 pub(crate) use interface_defaults::*;
 pub use iterator::*;
 pub use lifetime::*;
+pub use naming::*;
 pub use opaque_struct::*;
+pub use overload::*;
 pub use primitive_iterator::*;
 pub use primitive_pointers::*;
 pub use strings::*;
@@ -20,6 +25,9 @@ use universal::*;
 mod callback;
 mod class;
 mod collection;
+mod conformance;
+mod constraint;
+mod default_params;
 mod duration;
 mod enums;
 mod error;
@@ -27,7 +35,9 @@ mod integer;
 mod interface_defaults;
 mod iterator;
 mod lifetime;
+mod naming;
 mod opaque_struct;
+mod overload;
 mod primitive_iterator;
 mod primitive_pointers;
 mod strings;
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::ffi;
+
+pub struct UrlRegistry {
+    default: u32,
+    values: Mutex<HashMap<u32, u32>>,
+}
+
+impl ffi::UrlRegistryApi for UrlRegistry {
+    fn create(default: u32) -> Self {
+        Self {
+            default,
+            values: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn insert(&self, key: u32, value: u32) {
+        self.values.lock().unwrap().insert(key, value);
+    }
+
+    fn get(&self, key: u32) -> ffi::RegistryEntry {
+        match self.values.lock().unwrap().get(&key) {
+            Some(value) => ffi::RegistryEntry {
+                value: *value,
+                default: false,
+            },
+            None => ffi::RegistryEntry {
+                value: self.default,
+                default: true,
+            },
+        }
+    }
+
+    fn delete(&self, key: u32) -> bool {
+        self.values.lock().unwrap().remove(&key).is_some()
+    }
+}
@@ -0,0 +1,44 @@
+use std::ffi::CStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub struct Accumulator {
+    total: AtomicU64,
+}
+
+impl crate::ffi::AccumulatorApi for Accumulator {
+    fn create() -> Self {
+        Accumulator {
+            total: AtomicU64::new(0),
+        }
+    }
+
+    fn add_u8(&self, value: u8) {
+        self.total.fetch_add(value as u64, Ordering::Relaxed);
+    }
+
+    fn add_u32(&self, value: u32) {
+        self.total.fetch_add(value as u64, Ordering::Relaxed);
+    }
+
+    fn add_string(&self, value: &CStr) {
+        self.total
+            .fetch_add(value.to_bytes().len() as u64, Ordering::Relaxed);
+    }
+
+    fn add_scaled(&self, value: u32, factor: u32) {
+        self.total
+            .fetch_add(value as u64 * factor as u64, Ordering::Relaxed);
+    }
+
+    fn total(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+}
+
+pub(crate) fn describe_bool(_value: bool) -> u32 {
+    1
+}
+
+pub(crate) fn describe_double(_value: f64) -> u32 {
+    2
+}
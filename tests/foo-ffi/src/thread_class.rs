@@ -62,58 +62,32 @@ fn run(mut data: ThreadData) {
     }
 }
 
-pub(crate) fn thread_class_create(
-    value: u32,
-    receiver: crate::ffi::ValueChangeListener,
-) -> *mut ThreadClass {
-    let (tx, rx) = std::sync::mpsc::channel();
-    let thread_data = ThreadData {
-        value,
-        error_queue: Default::default(),
-        receiver,
-        rx,
-    };
-    let join_handle = Some(std::thread::spawn(|| run(thread_data)));
-    let class = Box::new(ThreadClass { tx, join_handle });
-    Box::into_raw(class)
-}
-
-pub(crate) unsafe fn thread_class_destroy(instance: *mut ThreadClass) {
-    if !instance.is_null() {
-        drop(Box::from_raw(instance));
+impl crate::ffi::ThreadClassApi for ThreadClass {
+    fn create(value: u32, receiver: crate::ffi::ValueChangeListener) -> Self {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let thread_data = ThreadData {
+            value,
+            error_queue: Default::default(),
+            receiver,
+            rx,
+        };
+        let join_handle = Some(std::thread::spawn(|| run(thread_data)));
+        ThreadClass { tx, join_handle }
     }
-}
 
-pub(crate) unsafe fn thread_class_update(instance: *mut ThreadClass, value: u32) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::Update(value)).unwrap()
+    fn update(&self, value: u32) {
+        self.tx.send(Message::Update(value)).unwrap()
     }
-}
 
-pub(crate) unsafe fn thread_class_add(
-    instance: *mut ThreadClass,
-    value: u32,
-    callback: crate::ffi::AddHandler,
-) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::Add(value, callback)).unwrap()
+    fn add(&self, value: u32, callback: crate::ffi::AddHandler) {
+        self.tx.send(Message::Add(value, callback)).unwrap()
     }
-}
 
-pub(crate) unsafe fn thread_class_execute(
-    instance: *mut ThreadClass,
-    operation: crate::ffi::Operation,
-) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::Operation(operation)).unwrap()
+    fn execute(&self, operation: crate::ffi::Operation) {
+        self.tx.send(Message::Operation(operation)).unwrap()
     }
-}
 
-pub(crate) unsafe fn thread_class_queue_error(
-    instance: *mut ThreadClass,
-    err: crate::ffi::MathIsBroken,
-) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::QueueAddError(err)).unwrap()
+    fn queue_error(&self, err: crate::ffi::MathIsBroken) {
+        self.tx.send(Message::QueueAddError(err)).unwrap()
     }
 }
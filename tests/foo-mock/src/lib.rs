@@ -0,0 +1,5 @@
+#![allow(clippy::missing_safety_doc)]
+
+pub mod ffi;
+
+include!(concat!(env!("OUT_DIR"), "/mock.rs"));
@@ -0,0 +1,155 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::{Mutex, MutexGuard};
+
+use foo_mock::ffi;
+use foo_mock::mock;
+
+// the mock is global, so the tests must not run concurrently
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn setup() -> MutexGuard<'static, ()> {
+    let guard = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    mock::reset();
+    guard
+}
+
+extern "C" fn double_value(value: u32, _ctx: *mut c_void) -> u32 {
+    value * 2
+}
+
+extern "C" fn count_destroy(ctx: *mut c_void) {
+    unsafe { *(ctx as *mut u32) += 1 };
+}
+
+#[test]
+fn records_calls_and_arguments() {
+    let _guard = setup();
+
+    unsafe {
+        ffi::foo_uint8_echo(42);
+        ffi::foo_uint8_echo(43);
+        ffi::foo_enum_zero_to_five_echo(ffi::EnumZeroToFive::Three.into());
+        let host = CString::new("localhost").unwrap();
+        let endpoint = ffi::Endpoint {
+            host: host.as_ptr(),
+            port: 20000,
+            timeout: 1500,
+        };
+        let mut port = 0;
+        ffi::foo_endpoint_port(endpoint, &mut port);
+    }
+
+    assert_eq!(mock::call_count("uint8_echo"), 2);
+    assert_eq!(mock::last_arguments("uint8_echo").unwrap(), vec!["43"]);
+    assert_eq!(
+        mock::last_arguments("enum_zero_to_five_echo").unwrap(),
+        vec!["three"]
+    );
+    assert_eq!(
+        mock::last_arguments("endpoint_port").unwrap(),
+        vec!["{host: localhost, port: 20000, timeout: 1.5s}"]
+    );
+    assert_eq!(mock::calls().len(), 4);
+}
+
+#[test]
+fn returns_configured_values() {
+    let _guard = setup();
+
+    unsafe {
+        assert_eq!(ffi::foo_uint32_echo(1), 0);
+        mock::set_return("uint32_echo", "7");
+        assert_eq!(ffi::foo_uint32_echo(1), 7);
+
+        mock::set_return("enum_zero_to_five_echo", "five");
+        let value = ffi::foo_enum_zero_to_five_echo(ffi::EnumZeroToFive::Zero.into());
+        assert_eq!(value, ffi::EnumZeroToFive::Five.into());
+
+        mock::set_return("duration_ms_echo", "250");
+        assert_eq!(ffi::foo_duration_ms_echo(0), 250);
+
+        assert_eq!(CStr::from_ptr(ffi::foo_version()).to_str(), Ok("1.2.3"));
+
+        let password = CString::new("12345").unwrap();
+        let mut out: *const c_char = std::ptr::null();
+        mock::set_return("echo_password", "hello");
+        let result = ffi::foo_echo_password(password.as_ptr(), &mut out);
+        assert_eq!(result, ffi::MyError::Ok.into());
+        assert_eq!(CStr::from_ptr(out).to_str(), Ok("hello"));
+    }
+}
+
+#[test]
+fn returns_configured_errors() {
+    let _guard = setup();
+
+    let password = CString::new("12345").unwrap();
+    unsafe {
+        let result: c_int = ffi::foo_validate_password(password.as_ptr());
+        assert_eq!(result, ffi::MyError::Ok.into());
+
+        mock::set_error("validate_password", "bad_password");
+        let result: c_int = ffi::foo_validate_password(password.as_ptr());
+        assert_eq!(result, ffi::MyError::BadPassword.into());
+
+        mock::clear("validate_password");
+        let result: c_int = ffi::foo_validate_password(password.as_ptr());
+        assert_eq!(result, ffi::MyError::Ok.into());
+    }
+}
+
+#[test]
+fn invokes_interface_callbacks() {
+    let _guard = setup();
+
+    let mut destroyed = 0u32;
+    unsafe {
+        let source = ffi::foo_callback_source_create();
+        ffi::foo_callback_source_set_interface(
+            source,
+            ffi::CallbackInterface {
+                on_value: Some(double_value),
+                on_duration: None,
+                on_destroy: Some(count_destroy),
+                ctx: &mut destroyed as *mut u32 as *mut c_void,
+            },
+        );
+        ffi::foo_callback_source_destroy(source);
+    }
+
+    assert_eq!(
+        mock::invoke("callback_source_set_interface", "on_value", &["21"]),
+        Some("42".to_string())
+    );
+    assert_eq!(
+        mock::invoke("callback_source_set_interface", "on_duration", &["1"]),
+        Some("null".to_string())
+    );
+    assert_eq!(
+        mock::invoke("callback_source_set_interface", "on_value", &["x"]),
+        None
+    );
+    assert_eq!(mock::invoke("uint8_echo", "on_value", &["21"]), None);
+
+    assert_eq!(destroyed, 0);
+    mock::reset();
+    assert_eq!(destroyed, 1);
+}
+
+#[test]
+fn exports_control_functions() {
+    let _guard = setup();
+
+    let function = CString::new("sint32_echo").unwrap();
+    let value = CString::new("-5").unwrap();
+    unsafe {
+        foo_mock::foo_mock_set_return(function.as_ptr(), value.as_ptr());
+        assert_eq!(ffi::foo_sint32_echo(3), -5);
+        assert_eq!(foo_mock::foo_mock_call_count(function.as_ptr()), 1);
+        let arguments = foo_mock::foo_mock_last_arguments(function.as_ptr());
+        assert_eq!(CStr::from_ptr(arguments).to_str(), Ok("3"));
+        foo_mock::foo_mock_reset();
+        assert_eq!(foo_mock::foo_mock_call_count(function.as_ptr()), 0);
+    }
+}
@@ -1,6 +1,10 @@
 use oo_bindgen::model::*;
 
 pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    // Defined in a module to test the nested namespaces and the doc references across modules
+    let callbacks = lib.define_module("callbacks")?;
+    lib.set_module(&callbacks)?;
+
     // Declare interface
     let interface = lib
         .define_interface("callback_interface", "Test interface")?
@@ -56,8 +60,13 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .method(set_value)?
         .method(set_duration)?
         .disposable_destroy()?
-        .doc("Class that demonstrate the usage of an async interface")?
+        .doc(
+            doc("Class that demonstrate the usage of an async interface")
+                .details("Its interface is called synchronously, unlike the callback of {class:test_class.add_async()}."),
+        )?
         .build()?;
 
+    lib.set_module(&Module::root())?;
+
     Ok(())
 }
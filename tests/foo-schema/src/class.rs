@@ -15,6 +15,7 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
             .details("Here are some details about {enum:enum_zero_to_five}. It has the {enum:enum_zero_to_five.two} variant.")
             .details("Here are some details about {interface:callback_interface}. It has the {interface:callback_interface.on_value()} callback.")
             .details("Here's a {null}. Here's the {iterator}.")
+            .details("Text can be **bold**, contain `inline code` and [links](https://stepfunc.io).\n- a list item referencing {class:test_class.get_value()}\n- another item\nAnd some text after the list.")
             .warning("And here's a dangerous warning! Do not use {class:test_class.get_value()}"),
         )?
         .build()?;
@@ -30,6 +31,10 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
     let increment_value = lib
         .define_method("increment_value", test_class.clone())?
         .doc("Increment value")?
+        .deprecated(
+            Version::new(1, 2, 0),
+            "Use {class:test_class.add_async()} with a value of 1",
+        )?
         .build()?;
 
     let get_value_callback = lib.define_future_interface(
@@ -47,7 +52,10 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
             Primitive::U32,
             "value to add to the internal value",
         )?
-        .doc("add a number to the class's internal value asynchronously")?
+        .doc(
+            doc("add a number to the class's internal value asynchronously")
+                .since(Version::new(1, 2, 0)),
+        )?
         .build()?;
 
     let construction_counter = lib
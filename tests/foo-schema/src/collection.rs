@@ -46,5 +46,19 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .doc("Collection helper functions")?
         .build()?;
 
+    let u32_collection = lib.define_collection("u32_collection", Primitive::U32, true)?;
+
+    let u32_collection_sum_method = lib
+        .define_function("u32_collection_sum")?
+        .param("col", u32_collection, "Collection")?
+        .returns(Primitive::U64, "Sum of the values")?
+        .doc("Add the values of a collection of primitives")?
+        .build_static("get_sum")?;
+
+    lib.define_static_class("u32_collection_test_methods")?
+        .static_method(u32_collection_sum_method)?
+        .doc("Primitive collection helper functions")?
+        .build()?;
+
     Ok(())
 }
@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let error_type = lib
+        .define_error_type(
+            "constraint_error",
+            "constraint_exception",
+            ExceptionType::UncheckedException,
+        )?
+        .add_invalid_argument_error("invalid_argument", "An argument violated its constraints")?
+        .doc("Errors returned by functions with constrained arguments")?
+        .build()?;
+
+    let host = Name::create("host")?;
+    let port = Name::create("port")?;
+    let timeout = Name::create("timeout")?;
+
+    let endpoint = lib.declare_function_argument_struct("endpoint")?;
+    let endpoint = lib
+        .define_function_argument_struct(endpoint)?
+        .add(&host, StringType, "Host name or address")?
+        .constrain(Constraint::NonEmpty)?
+        .constrain(Constraint::MaxLength(32))?
+        .add(&port, Primitive::U16, "TCP port")?
+        .constrain(Constraint::range(
+            NumberValue::U16(1),
+            NumberValue::U16(65535),
+        ))?
+        .add(&timeout, DurationType::Milliseconds, "Connection timeout")?
+        .constrain(Constraint::NonZero)?
+        .doc("Remote endpoint with constrained fields")?
+        .end_fields()?
+        .begin_initializer(
+            "init",
+            InitializerType::Normal,
+            "Initialize the endpoint with a default timeout",
+        )?
+        .default(&timeout, Duration::from_secs(5))?
+        .end_initializer()?
+        .build()?;
+
+    let endpoint_port = lib
+        .define_function("endpoint_port")?
+        .param("endpoint", endpoint, "Endpoint to check")?
+        .returns(Primitive::U16, "Port of the endpoint")?
+        .fails_with(error_type.clone())?
+        .doc("Retrieve the port of an endpoint after its constraints are checked")?
+        .build_static_with_same_name()?;
+
+    let scale = lib
+        .define_function("scale")?
+        .param("value", Primitive::S32, "Value to scale")?
+        .constrain(Constraint::range(
+            NumberValue::S32(-100),
+            NumberValue::S32(100),
+        ))?
+        .param("factor", Primitive::U8, "Scaling factor")?
+        .constrain(Constraint::range(NumberValue::U8(1), NumberValue::U8(10)))?
+        .returns(Primitive::S32, "Scaled value")?
+        .fails_with(error_type.clone())?
+        .doc("Multiply a bounded value by a bounded factor")?
+        .build_static_with_same_name()?;
+
+    let check_identifier = lib
+        .define_function("check_identifier")?
+        .param("identifier", StringType, "Identifier to check")?
+        .constrain(Constraint::NonEmpty)?
+        .constrain(Constraint::MaxLength(16))?
+        .constrain(Constraint::regex("[a-z_][a-z0-9_]*"))?
+        .fails_with(error_type)?
+        .doc("Check that an identifier is well-formed")?
+        .build_static_with_same_name()?;
+
+    lib.define_static_class("constraint_functions")?
+        .static_method(endpoint_port)?
+        .static_method(scale)?
+        .static_method(check_identifier)?
+        .doc("Functions with constrained arguments")?
+        .build()?;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let speed = lib
+        .define_enum("speed")?
+        .push("slow", "Slow")?
+        .push("fast", "Fast")?
+        .doc("Speed used to test enum defaults")?
+        .build()?;
+
+    // class whose constructor and method have defaulted parameters
+    let counter = lib.declare_class("counter")?;
+
+    let constructor = lib
+        .define_constructor(counter.clone())?
+        .param_with_default(
+            "initial",
+            Primitive::U32,
+            "Initial value of the counter",
+            NumberValue::U32(0),
+        )?
+        .doc("Create a new {class:counter}")?
+        .build()?;
+
+    let destructor = lib.define_destructor(counter.clone(), "Destroy a {class:counter}")?;
+
+    let increment = lib
+        .define_method("increment", counter.clone())?
+        .param_with_default(
+            "amount",
+            Primitive::U32,
+            "Amount to add to the counter",
+            NumberValue::U32(1),
+        )?
+        .returns(Primitive::U32, "Value of the counter after the increment")?
+        .doc("Increment the counter")?
+        .build()?;
+
+    lib.define_class(&counter)?
+        .constructor(constructor)?
+        .destructor(destructor)?
+        .method(increment)?
+        .doc("Counter used to test defaulted parameters")?
+        .build()?;
+
+    // static functions covering each kind of default value
+    let add_offset = lib
+        .define_function("add_offset")?
+        .param("value", Primitive::S32, "Value to offset")?
+        .param_with_default(
+            "offset",
+            Primitive::S32,
+            "Offset added to the value",
+            NumberValue::S32(10),
+        )?
+        .param_with_default(
+            "multiplier",
+            Primitive::S32,
+            "Multiplier applied after the offset",
+            NumberValue::S32(1),
+        )?
+        .returns(Primitive::S32, "(value + offset) * multiplier")?
+        .doc("Offset and then multiply a value")?
+        .build_static_with_same_name()?;
+
+    let duration_to_millis = lib
+        .define_function("duration_to_millis")?
+        .param_with_default(
+            "value",
+            DurationType::Milliseconds,
+            "Duration to convert",
+            Duration::from_millis(1500),
+        )?
+        .returns(Primitive::U64, "Number of milliseconds")?
+        .doc("Convert a duration to milliseconds")?
+        .build_static_with_same_name()?;
+
+    let string_byte_length = lib
+        .define_function("string_byte_length")?
+        .param_with_default(
+            "value",
+            StringType,
+            "String to measure",
+            "hello".default_string(),
+        )?
+        .returns(Primitive::U32, "Length of the string in bytes")?
+        .doc("Measure the length of a string")?
+        .build_static_with_same_name()?;
+
+    let speed_value = lib
+        .define_function("speed_value")?
+        .param_with_default("speed", speed, "Speed to convert", "fast".default_variant())?
+        .returns(Primitive::U32, "Numeric value of the speed")?
+        .doc("Convert a speed to its numeric value")?
+        .build_static_with_same_name()?;
+
+    lib.define_static_class("default_param_functions")?
+        .static_method(add_offset)?
+        .static_method(duration_to_millis)?
+        .static_method(string_byte_length)?
+        .static_method(speed_value)?
+        .doc("Functions with defaulted parameters")?
+        .build()?;
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
             ExceptionType::UncheckedException,
         )?
         .add_error("bad_password", "Wrong password!")?
-        .add_error("null_argument", "Provided argument was NULL")?
+        .add_invalid_argument_error("null_argument", "Provided argument was NULL")?
         .doc("Errors returned by the various functions")?
         .build()?;
 
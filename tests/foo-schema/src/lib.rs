@@ -6,6 +6,8 @@ mod callback;
 mod class;
 mod collection;
 mod constants;
+mod constraint;
+mod default_params;
 mod duration;
 mod enums;
 mod error;
@@ -13,7 +15,9 @@ mod integer;
 mod interface_defaults;
 mod iterator;
 mod lifetime;
+mod naming;
 mod opaque_struct;
+mod overload;
 mod primitive_iterator;
 mod primitive_pointer;
 mod strings;
@@ -62,6 +66,8 @@ pub fn build_lib() -> BackTraced<Library> {
 
     structure::define(&mut builder)?;
     constants::define(&mut builder)?;
+    constraint::define(&mut builder)?;
+    default_params::define(&mut builder)?;
     callback::define(&mut builder)?;
     class::define(&mut builder)?;
     duration::define(&mut builder)?;
@@ -71,6 +77,7 @@ pub fn build_lib() -> BackTraced<Library> {
     interface_defaults::define(&mut builder)?;
     iterator::define(&mut builder)?;
     opaque_struct::define(&mut builder)?;
+    overload::define(&mut builder)?;
     primitive_iterator::define(&mut builder)?;
     primitive_pointer::define(&mut builder)?;
     strings::define(&mut builder)?;
@@ -78,6 +85,10 @@ pub fn build_lib() -> BackTraced<Library> {
     collection::define(&mut builder)?;
     universal_struct::define(&mut builder)?;
     thread_class::define(&mut builder)?;
+    naming::define(&mut builder)?;
+
+    // echo functions for the types defined above
+    oo_bindgen::conformance::define(&mut builder)?;
 
     let library = builder.build()?;
 
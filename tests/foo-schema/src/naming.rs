@@ -0,0 +1,77 @@
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    // spelled in capital letters in CamelCase, e.g. URLRegistry
+    lib.define_acronym("url")?;
+    // a collection adds its items in C#
+    lib.rename("insert", TargetLanguage::DotNet, "add")?;
+
+    let entry = lib.declare_function_return_struct("registry_entry")?;
+    let entry = lib
+        .define_function_return_struct(entry)?
+        .add(
+            "value",
+            Primitive::U32,
+            "Value registered for the key or the default value of the registry",
+        )?
+        .add(
+            "default",
+            Primitive::Bool,
+            "True if no value is registered for the key",
+        )?
+        .doc("Entry of a {class:url_registry}")?
+        .end_fields()?
+        .build()?;
+
+    let registry = lib.declare_class("url_registry")?;
+
+    let constructor = lib
+        .define_constructor(registry.clone())?
+        .param(
+            "default",
+            Primitive::U32,
+            "Value of the keys without a registered value",
+        )?
+        .doc("Create an empty {class:url_registry}")?
+        .build()?;
+
+    let destructor = lib.define_destructor(registry.clone(), "Destroy a {class:url_registry}")?;
+
+    let insert = lib
+        .define_method("insert", registry.clone())?
+        .param("key", Primitive::U32, "Key of the value")?
+        .param("value", Primitive::U32, "Value to register")?
+        .doc("Register a value, replacing the value already registered for the key")?
+        .build()?;
+
+    let get = lib
+        .define_method("get", registry.clone())?
+        .param("key", Primitive::U32, "Key of the value")?
+        .returns(entry, "Entry of the key")?
+        .doc("Get the value registered for a key")?
+        .build()?;
+
+    let delete = lib
+        .define_method("delete", registry.clone())?
+        .param("key", Primitive::U32, "Key of the value")?
+        .returns(
+            Primitive::Bool,
+            "True if a value was registered for the key",
+        )?
+        .doc("Remove the value registered for a key")?
+        .build()?;
+
+    lib.define_class(&registry)?
+        .constructor(constructor)?
+        .destructor(destructor)?
+        .method(insert)?
+        .method(get)?
+        .method(delete)?
+        .doc(
+            doc("Values registered by key")
+                .details("Its names are reserved in some languages, an acronym and renamed in C#."),
+        )?
+        .build()?;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let accumulator = lib.declare_class("accumulator")?;
+
+    let constructor = lib
+        .define_constructor(accumulator.clone())?
+        .doc("Create an {class:accumulator} with a total of zero")?
+        .build()?;
+
+    let destructor =
+        lib.define_destructor(accumulator.clone(), "Destroy an {class:accumulator}")?;
+
+    let add_u8 = lib
+        .define_method("add_u8", accumulator.clone())?
+        .param("value", Primitive::U8, "Value to add")?
+        .doc("Add an 8-bit value to the total")?
+        .build_overload("add")?;
+
+    let add_u32 = lib
+        .define_method("add_u32", accumulator.clone())?
+        .param("value", Primitive::U32, "Value to add")?
+        .doc("Add a 32-bit value to the total")?
+        .build_overload("add")?;
+
+    let add_string = lib
+        .define_method("add_string", accumulator.clone())?
+        .param("value", StringType, "String whose length is added")?
+        .doc("Add the length of a string to the total")?
+        .build_overload("add")?;
+
+    let add_scaled = lib
+        .define_method("add_scaled", accumulator.clone())?
+        .param("value", Primitive::U32, "Value to add")?
+        .param("factor", Primitive::U32, "Factor applied to the value")?
+        .doc("Add a scaled value to the total")?
+        .build_overload("add")?;
+
+    let total = lib
+        .define_method("total", accumulator.clone())?
+        .returns(Primitive::U64, "Sum of all the values added")?
+        .doc("Retrieve the total")?
+        .build()?;
+
+    lib.define_class(&accumulator)?
+        .constructor(constructor)?
+        .destructor(destructor)?
+        .method(add_u8)?
+        .method(add_u32)?
+        .method(add_string)?
+        .method(add_scaled)?
+        .method(total)?
+        .doc("Accumulator whose overloaded methods share a name")?
+        .build()?;
+
+    let describe_bool = lib
+        .define_function("describe_bool")?
+        .param("value", Primitive::Bool, "Value to describe")?
+        .returns(Primitive::U32, "1 for a boolean")?
+        .doc("Describe a boolean")?
+        .build_static("describe")?;
+
+    let describe_double = lib
+        .define_function("describe_double")?
+        .param("value", Primitive::Double, "Value to describe")?
+        .returns(Primitive::U32, "2 for a double")?
+        .doc("Describe a double")?
+        .build_static("describe")?;
+
+    lib.define_static_class("overload_functions")?
+        .static_method(describe_bool)?
+        .static_method(describe_double)?
+        .doc("Static methods that share a name")?
+        .build()?;
+
+    Ok(())
+}
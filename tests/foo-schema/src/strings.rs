@@ -33,7 +33,37 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .method(echo)?
         .static_method(string_length)?
         .disposable_destroy()?
-        .doc("StringClass")?
+        .doc(
+            doc("StringClass").example(
+                example()
+                    .c(r#"
+                        foo_string_class_t* instance = foo_string_class_create();
+                        printf("%s\n", foo_string_class_echo(instance, "hello"));
+                        foo_string_class_destroy(instance);
+                    "#)
+                    .cpp(
+                        r#"
+                        foo::StringClass instance;
+                        std::cout << instance.echo("hello") << std::endl;
+                    "#,
+                    )
+                    .java(
+                        r#"
+                        try (StringClass instance = new StringClass()) {
+                            System.out.println(instance.echo("hello"));
+                        }
+                    "#,
+                    )
+                    .dotnet(
+                        r#"
+                        using (var instance = new StringClass())
+                        {
+                            Console.WriteLine(instance.Echo("hello"));
+                        }
+                    "#,
+                    ),
+            ),
+        )?
         .build()?;
 
     Ok(())
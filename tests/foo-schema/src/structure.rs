@@ -18,6 +18,8 @@ pub fn define_numbers_structure(lib: &mut LibraryBuilder) -> BackTraced<Universa
     let numbers = lib
         .define_universal_struct(numbers)?
         .doc("structure containing all the numeric types")?
+        .enable_value_semantics()
+        .make_immutable()
         .add(uint8_value.clone(), Primitive::U8, "uint8 value")?
         .add(int8_value.clone(), Primitive::S8, "int8 value")?
         .add(uint16_value.clone(), Primitive::U16, "uint16 value")?
@@ -50,13 +52,14 @@ pub fn define_numbers_structure(lib: &mut LibraryBuilder) -> BackTraced<Universa
     Ok(numbers)
 }
 
-pub fn define_inner_structure(lib: &mut LibraryBuilder) -> BackTraced<FunctionArgStructHandle> {
+pub fn define_inner_structure(
+    lib: &mut LibraryBuilder,
+    numbers: UniversalStructHandle,
+) -> BackTraced<FunctionArgStructHandle> {
     let empty_interface = lib
         .define_interface("empty_interface", "Interface within a structure")?
         .build_async()?;
 
-    let numbers = define_numbers_structure(lib)?;
-
     let interface_field = Name::create("interface_field")?;
     let numbers_field = Name::create("numbers_field")?;
     let inner_structure = lib.declare_function_argument_struct("inner_structure")?;
@@ -89,7 +92,9 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .doc("Enum")?
         .build()?;
 
-    let inner_structure = define_inner_structure(lib)?;
+    let numbers = define_numbers_structure(lib)?;
+    let inner_structure = define_inner_structure(lib, numbers.clone())?;
+    define_labeled_numbers_structure(lib, numbers)?;
 
     let structure = lib.declare_function_argument_struct("structure")?;
 
@@ -163,3 +168,30 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     Ok(())
 }
+
+fn define_labeled_numbers_structure(
+    lib: &mut LibraryBuilder,
+    numbers: UniversalStructHandle,
+) -> BackTraced<()> {
+    let label_field = Name::create("label")?;
+    let numbers_field = Name::create("numbers")?;
+
+    let labeled_numbers = lib.declare_function_argument_struct("labeled_numbers")?;
+    lib.define_function_argument_struct(labeled_numbers)?
+        .doc("Immutable structure containing a string and a {struct:numbers}")?
+        .make_immutable()
+        .add(label_field.clone(), StringType, "label of the numbers")?
+        .add(numbers_field.clone(), numbers, "labeled numbers")?
+        .end_fields()?
+        .begin_initializer(
+            "init",
+            InitializerType::Normal,
+            "Initialize {struct:labeled_numbers} to default values",
+        )?
+        .default_string(&label_field, "default")?
+        .default_struct(&numbers_field)?
+        .end_initializer()?
+        .build()?;
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ fn define_inner_struct(lib: &mut LibraryBuilder) -> BackTraced<UniversalStructHa
     let inner = lib
         .define_universal_struct(inner)?
         .doc("Simple universal struct")?
+        .enable_value_semantics()
         .add(value_field.clone(), Primitive::S32, "integer value")?
         .end_fields()?
         .begin_initializer(
@@ -33,6 +34,7 @@ fn define_outer_struct(lib: &mut LibraryBuilder) -> BackTraced<UniversalStructHa
     let outer_struct = lib
         .define_universal_struct(outer_struct)?
         .doc("Simple universal struct with an inner structure")?
+        .enable_value_semantics()
         .add(inner_field.clone(), inner_struct, "An inner structure")?
         .add(
             delay_field.clone(),